@@ -58,6 +58,9 @@ where
             address if address == hash(2000) => {
                 Some(CallPermitPrecompile::<Runtime>::execute(handle))
             }
+            // 0x00000000000000000000000000000000000007d1 is reserved for the
+            // `pallet-evm-precompile-did-registry` precompile, which requires `uid_core`
+            // and `pallet_did_lookup` to be part of this runtime.
 
             _ => None,
         }
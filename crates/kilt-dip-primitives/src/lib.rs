@@ -22,7 +22,10 @@
 //!
 //! Consumers of KILT identities should prefer directly using
 //! [`KiltVersionedRelaychainVerifier`] for consumer relaychains and
-//! [`KiltVersionedParachainVerifier`] for consumer sibling parachains.
+//! [`KiltVersionedParachainVerifier`] for consumer sibling parachains. Consumers
+//! of a standalone KILT provider chain, whose finalized headers are tracked by
+//! the GRANDPA light client pallet, should use
+//! [`KiltVersionedStandaloneVerifier`].
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -39,5 +42,5 @@ pub mod utils;
 pub mod verifier;
 
 pub use merkle_proofs::latest::*;
-pub use traits::RelayStateRootsViaRelayStorePallet;
+pub use traits::{ProviderStateRootsViaGrandpaLightClientPallet, RelayStateRootsViaRelayStorePallet};
 pub use verifier::*;
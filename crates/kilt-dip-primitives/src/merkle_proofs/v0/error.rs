@@ -30,6 +30,7 @@ pub enum Error {
 	TooManyLeavesRevealed,
 	InvalidSignatureTime,
 	InvalidDidKeyRevealed,
+	ProviderStateRootNotFound,
 	ParaHeadMerkleProof(MerkleProofError),
 	DipCommitmentMerkleProof(MerkleProofError),
 	Internal,
@@ -49,6 +50,7 @@ impl From<Error> for u8 {
 			Error::TooManyLeavesRevealed => 5,
 			Error::InvalidSignatureTime => 6,
 			Error::InvalidDidKeyRevealed => 7,
+			Error::ProviderStateRootNotFound => 8,
 			Error::ParaHeadMerkleProof(error) => match error {
 				MerkleProofError::InvalidProof => 11,
 				MerkleProofError::RequiredLeafNotRevealed => 12,
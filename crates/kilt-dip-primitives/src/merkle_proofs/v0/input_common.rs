@@ -19,6 +19,7 @@
 use uid_core::DidSignature;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
+use sp_core::H256;
 use sp_std::vec::Vec;
 
 use crate::merkle_proofs::v0::output_common::RevealedDidMerkleProofLeaf;
@@ -134,6 +135,36 @@ impl<ProviderDidKeyId, ProviderAccountId, ProviderBlockNumber, ProviderWeb3Name,
 	}
 }
 
+/// A proof for a salted DIP commitment.
+///
+/// It wraps the `Proof` that would be used for the unsalted commitment, and
+/// adds the salt of each revealed leaf, in the same order as the revealed
+/// leaves. Keeping the salts outside of `Proof` leaves the encoding of the
+/// unsalted proofs unchanged.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo)]
+pub struct SaltedProof<Proof> {
+	pub(crate) proof: Proof,
+	pub(crate) salts: Vec<H256>,
+}
+
+impl<Proof> SaltedProof<Proof> {
+	pub fn new(proof: Proof, salts: Vec<H256>) -> Self {
+		Self { proof, salts }
+	}
+
+	pub fn proof(&self) -> &Proof {
+		&self.proof
+	}
+
+	pub fn salts(&self) -> &[H256] {
+		self.salts.as_ref()
+	}
+
+	pub fn into_parts(self) -> (Proof, Vec<H256>) {
+		(self.proof, self.salts)
+	}
+}
+
 /// A DID signature anchored to a specific block height.
 ///
 /// The generic types indicate the following:
@@ -24,6 +24,7 @@ mod input_common;
 mod output_common;
 mod provider_state;
 mod relay_state;
+mod standalone_state;
 
 pub use dip_subject_state::*;
 pub use error::*;
@@ -31,3 +32,4 @@ pub use input_common::*;
 pub use output_common::*;
 pub use provider_state::*;
 pub use relay_state::*;
+pub use standalone_state::*;
@@ -19,7 +19,7 @@
 use uid_core::{did_details::DidPublicKeyDetails, DidVerificationKeyRelationship};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_core::ConstU32;
+use sp_core::{ConstU32, H256};
 use sp_runtime::{BoundedVec, SaturatedConversion};
 use sp_std::{fmt::Debug, vec::Vec};
 
@@ -92,6 +92,18 @@ impl<
 		self.revealed_leaves.into_iter()
 	}
 
+	/// Returns an iterator over the revealed public credentials and
+	/// verifications.
+	pub fn iter_credentials(&self) -> impl Iterator<Item = &RevealedCredential<KiltAccountId>> {
+		self.revealed_leaves.iter().filter_map(|leaf| {
+			if let RevealedDidMerkleProofLeaf::Credential(credential) = leaf {
+				Some(credential)
+			} else {
+				None
+			}
+		})
+	}
+
 	/// Returns a reference to the leaves that signed the cross-chain operation.
 	/// This operation should never fail, so the only error it returns is an
 	/// `Error::Internal` which, anyway, should never happen.
@@ -204,6 +216,7 @@ pub enum RevealedDidMerkleProofLeaf<KeyId, AccountId, BlockNumber, Web3Name, Lin
 	DidKey(RevealedDidKey<KeyId, BlockNumber, AccountId>),
 	Web3Name(RevealedWeb3Name<Web3Name, BlockNumber>),
 	LinkedAccount(RevealedAccountId<LinkedAccountId>),
+	Credential(RevealedCredential<AccountId>),
 }
 
 impl<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId> From<RevealedDidKey<KeyId, BlockNumber, AccountId>>
@@ -230,6 +243,14 @@ impl<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId> From<RevealedAcco
 	}
 }
 
+impl<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId> From<RevealedCredential<AccountId>>
+	for RevealedDidMerkleProofLeaf<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId>
+{
+	fn from(value: RevealedCredential<AccountId>) -> Self {
+		Self::Credential(value)
+	}
+}
+
 #[cfg(any(test, feature = "runtime-benchmarks"))]
 impl<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId> Default
 	for RevealedDidMerkleProofLeaf<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId>
@@ -263,8 +284,15 @@ where
 			RevealedDidMerkleProofLeaf::DidKey(RevealedDidKey { id, relationship, .. }) => (id, relationship).encode(),
 			RevealedDidMerkleProofLeaf::Web3Name(RevealedWeb3Name { web3_name, .. }) => web3_name.encode(),
 			RevealedDidMerkleProofLeaf::LinkedAccount(RevealedAccountId(account_id)) => account_id.encode(),
+			RevealedDidMerkleProofLeaf::Credential(RevealedCredential { kind, id, .. }) => (kind, id).encode(),
 		}
 	}
+
+	/// Returns the key of the leaf as stored in a salted commitment, i.e., the
+	/// encoded key prefixed by the provided salt.
+	pub fn salted_encoded_key(&self, salt: &H256) -> Vec<u8> {
+		(salt, self.encoded_key()).encode()
+	}
 }
 
 impl<KeyId, AccountId, BlockNumber, Web3Name, LinkedAccountId>
@@ -278,6 +306,12 @@ where
 			RevealedDidMerkleProofLeaf::DidKey(RevealedDidKey { details, .. }) => details.encode(),
 			RevealedDidMerkleProofLeaf::Web3Name(RevealedWeb3Name { claimed_at, .. }) => claimed_at.encode(),
 			RevealedDidMerkleProofLeaf::LinkedAccount(_) => ().encode(),
+			RevealedDidMerkleProofLeaf::Credential(RevealedCredential {
+				ctype_hash,
+				attester,
+				revoked,
+				..
+			}) => (ctype_hash, attester, revoked).encode(),
 		}
 	}
 }
@@ -310,3 +344,29 @@ pub struct RevealedWeb3Name<Web3Name, BlockNumber> {
 /// Merkle proof.
 #[derive(Clone, Copy, Debug, Encode, Decode, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
 pub struct RevealedAccountId<AccountId>(pub AccountId);
+
+/// The KILT pallet a revealed credential is stored in.
+#[derive(Clone, Copy, Debug, Encode, Decode, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
+pub enum CredentialKind {
+	/// A credential stored in the public credentials pallet.
+	PublicCredential,
+	/// A credential stored in the verification pallet.
+	Verification,
+}
+
+/// The details of a public credential or a verification after it has been
+/// successfully verified in a Merkle proof.
+#[derive(Clone, Copy, Debug, Encode, Decode, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
+pub struct RevealedCredential<AttesterId> {
+	/// The pallet the credential is stored in.
+	pub kind: CredentialKind,
+	/// The credential ID for public credentials, or the claim hash for
+	/// verifications.
+	pub id: H256,
+	/// The hash of the CType the credential conforms to.
+	pub ctype_hash: H256,
+	/// The DID of the credential attester.
+	pub attester: AttesterId,
+	/// Whether the credential has been revoked by its attester.
+	pub revoked: bool,
+}
@@ -17,9 +17,10 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use frame_support::ensure;
-use pallet_dip_provider::IdentityCommitmentOf;
+use pallet_dip_provider::{IdentityCommitmentOf, IdentityCommitmentVersion};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
+use sp_core::H256;
 use sp_runtime::{
 	traits::{Hash, Header as HeaderT},
 	BoundedVec, SaturatedConversion,
@@ -175,25 +176,13 @@ impl<
 		RelayHasher: Hash,
 		ProviderHeader: Decode + HeaderT<Hash = OutputOf<RelayHasher>, Number = KiltBlockNumber>,
 	{
-		let provider_head_storage_key = calculate_parachain_head_storage_key(provider_para_id);
-		log::trace!(target: "dip::consumer::ParachainDipDidProofV0", "Calculated storage key for para ID {:#?} = {:#?}", provider_para_id, provider_head_storage_key);
-		// TODO: Figure out why RPC call returns 2 bytes in front which we don't need
-		//This could be the reason (and the solution): https://substrate.stackexchange.com/a/1891/1795
-		let provider_header = verify_storage_value_proof_with_decoder::<_, RelayHasher, ProviderHeader>(
-			&provider_head_storage_key,
-			*relay_state_root,
+		let state_root = verify_provider_head_state_root::<RelayHasher, ProviderHeader>(
+			provider_para_id,
+			relay_state_root,
 			self.provider_head_proof.proof,
-			|input| {
-				if input.len() < 2 {
-					return None;
-				}
-				let mut trimmed_input = &input[2..];
-				ProviderHeader::decode(&mut trimmed_input).ok()
-			},
-		)
-		.map_err(Error::ParaHeadMerkleProof)?;
+		)?;
 		Ok(DipDidProofWithVerifiedStateRoot {
-			state_root: *provider_header.state_root(),
+			state_root,
 			dip_commitment_proof: self.dip_commitment_proof,
 			dip_proof: self.dip_proof,
 			signature: self.signature,
@@ -241,6 +230,149 @@ impl<
 	}
 }
 
+/// Verifies the state proof for the head of the provider with the given para
+/// ID against the given relaychain state root, and returns the provider state
+/// root contained in the revealed header.
+fn verify_provider_head_state_root<RelayHasher, ProviderHeader>(
+	provider_para_id: u32,
+	relay_state_root: &OutputOf<RelayHasher>,
+	provider_head_proof: Vec<Vec<u8>>,
+) -> Result<OutputOf<RelayHasher>, Error>
+where
+	RelayHasher: Hash,
+	ProviderHeader: Decode + HeaderT<Hash = OutputOf<RelayHasher>>,
+{
+	let provider_head_storage_key = calculate_parachain_head_storage_key(provider_para_id);
+	log::trace!(target: "dip::consumer::ParachainDipDidProofV0", "Calculated storage key for para ID {:#?} = {:#?}", provider_para_id, provider_head_storage_key);
+	// TODO: Figure out why RPC call returns 2 bytes in front which we don't need
+	//This could be the reason (and the solution): https://substrate.stackexchange.com/a/1891/1795
+	let provider_header = verify_storage_value_proof_with_decoder::<_, RelayHasher, ProviderHeader>(
+		&provider_head_storage_key,
+		*relay_state_root,
+		provider_head_proof,
+		|input| {
+			if input.len() < 2 {
+				return None;
+			}
+			let mut trimmed_input = &input[2..];
+			ProviderHeader::decode(&mut trimmed_input).ok()
+		},
+	)
+	.map_err(Error::ParaHeadMerkleProof)?;
+	Ok(*provider_header.state_root())
+}
+
+/// Verifies the state proof for the DIP commitment of the given subject and
+/// version against the given provider state root, and returns the commitment.
+fn verify_dip_commitment<ParachainHasher, ProviderRuntime>(
+	state_root: OutputOf<ParachainHasher>,
+	subject: &ProviderRuntime::Identifier,
+	version: IdentityCommitmentVersion,
+	dip_commitment_proof: Vec<Vec<u8>>,
+) -> Result<IdentityCommitmentOf<ProviderRuntime>, Error>
+where
+	ParachainHasher: Hash,
+	OutputOf<ParachainHasher>: Ord,
+	ProviderRuntime: pallet_dip_provider::Config,
+{
+	let dip_commitment_storage_key =
+		calculate_dip_identity_commitment_storage_key_for_runtime::<ProviderRuntime>(subject, version);
+	log::trace!(target: "dip::consumer::DipDidProofWithVerifiedStateRootV0", "Calculated storage key for subject {:#?} = {:#?}", subject, dip_commitment_storage_key);
+	verify_storage_value_proof::<_, ParachainHasher, IdentityCommitmentOf<ProviderRuntime>>(
+		&dip_commitment_storage_key,
+		state_root,
+		dip_commitment_proof,
+	)
+	.map_err(Error::DipCommitmentMerkleProof)
+}
+
+/// The part of a [`ParachainDipDidProof`] attesting the DIP commitment of a
+/// subject on the provider parachain, without the DID Merkle proof and the
+/// DID signature.
+///
+/// It is used to check that the commitment a DIP session was opened against
+/// has not changed on the provider chain.
+///
+/// The generic types indicate the following:
+/// * `RelayBlockNumber`: The `BlockNumber` definition of the relaychain.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo)]
+pub struct ParachainDipCommitmentProof<RelayBlockNumber> {
+	/// The state proof for the given parachain head.
+	pub(crate) provider_head_proof: ProviderHeadStateProof<RelayBlockNumber>,
+	/// The raw state proof for the DIP commitment of the given subject.
+	pub(crate) dip_commitment_proof: DipCommitmentStateProof,
+}
+
+impl<RelayBlockNumber> ParachainDipCommitmentProof<RelayBlockNumber> {
+	pub fn new(
+		provider_head_proof: ProviderHeadStateProof<RelayBlockNumber>,
+		dip_commitment_proof: DipCommitmentStateProof,
+	) -> Self {
+		Self {
+			provider_head_proof,
+			dip_commitment_proof,
+		}
+	}
+
+	pub fn provider_head_proof(&self) -> &ProviderHeadStateProof<RelayBlockNumber> {
+		&self.provider_head_proof
+	}
+
+	pub fn dip_commitment_proof(&self) -> &DipCommitmentStateProof {
+		&self.dip_commitment_proof
+	}
+
+	/// Verifies the head data of the state proof for the provider with the
+	/// given para ID using the state root returned by the provided
+	/// implementation, and then the DIP commitment of the subject with the
+	/// given identifier, as generated for the given commitment version.
+	///
+	/// The generic types indicate the following:
+	/// * `RelayHasher`: The hashing algorithm used on the relaychain to
+	///   generate the parachains head data.
+	/// * `StateRootStore`: The type that returns a relaychain state root given
+	///   a relaychain block number.
+	/// * `ProviderHeader`: The type of the parachain header to be revealed in
+	///   the state proof.
+	/// * `ParachainHasher`: The hashing algorithm used to hash storage on the
+	///   parachain.
+	/// * `ProviderRuntime`: The provider runtime definition.
+	pub fn verify_dip_commitment_for_subject_and_version<
+		RelayHasher,
+		StateRootStore,
+		ProviderHeader,
+		ParachainHasher,
+		ProviderRuntime,
+	>(
+		self,
+		provider_para_id: u32,
+		subject: &ProviderRuntime::Identifier,
+		version: IdentityCommitmentVersion,
+	) -> Result<IdentityCommitmentOf<ProviderRuntime>, Error>
+	where
+		RelayHasher: Hash,
+		StateRootStore: GetWithArg<RelayBlockNumber, Result = Option<OutputOf<RelayHasher>>>,
+		ProviderHeader: Decode + HeaderT<Hash = OutputOf<RelayHasher>>,
+		ParachainHasher: Hash<Output = OutputOf<RelayHasher>>,
+		OutputOf<RelayHasher>: Ord,
+		ProviderRuntime: pallet_dip_provider::Config,
+	{
+		let relay_state_root =
+			StateRootStore::get(&self.provider_head_proof.relay_block_number).ok_or(Error::RelayStateRootNotFound)?;
+		let state_root = verify_provider_head_state_root::<RelayHasher, ProviderHeader>(
+			provider_para_id,
+			&relay_state_root,
+			self.provider_head_proof.proof,
+		)?;
+		verify_dip_commitment::<ParachainHasher, ProviderRuntime>(
+			state_root,
+			subject,
+			version,
+			self.dip_commitment_proof.0,
+		)
+	}
+}
+
 /// A DIP proof that has had the proof header and the relaychain state verified
 /// for the provided relaychain block number.
 ///
@@ -294,8 +426,8 @@ impl<
 		ConsumerBlockNumber,
 	>
 {
-	/// Verifies the DIP commitment part of the state proof for the subject with
-	/// the given identifier.
+	/// Verifies the version 0 DIP commitment part of the state proof for the
+	/// subject with the given identifier.
 	///
 	/// The generic types indicate the following:
 	/// * `ParachainHasher`: The hashing algorithm used to hash storage on the
@@ -322,15 +454,44 @@ impl<
 		ParachainHasher: Hash<Output = StateRoot>,
 		ProviderRuntime: pallet_dip_provider::Config,
 	{
-		let dip_commitment_storage_key =
-			calculate_dip_identity_commitment_storage_key_for_runtime::<ProviderRuntime>(subject, 0);
-		log::trace!(target: "dip::consumer::DipDidProofWithVerifiedStateRootV0", "Calculated storage key for subject {:#?} = {:#?}", subject, dip_commitment_storage_key);
-		let dip_commitment = verify_storage_value_proof::<_, ParachainHasher, IdentityCommitmentOf<ProviderRuntime>>(
-			&dip_commitment_storage_key,
+		self.verify_dip_commitment_proof_for_subject_and_version::<ParachainHasher, ProviderRuntime>(subject, 0)
+	}
+
+	/// Verifies the DIP commitment part of the state proof for the subject with
+	/// the given identifier, as generated for the given commitment version.
+	///
+	/// The generic types indicate the following:
+	/// * `ParachainHasher`: The hashing algorithm used to hash storage on the
+	///   parachain.
+	/// * `ProviderRuntime`: The provider runtime definition.
+	#[allow(clippy::type_complexity)]
+	pub fn verify_dip_commitment_proof_for_subject_and_version<ParachainHasher, ProviderRuntime>(
+		self,
+		subject: &ProviderRuntime::Identifier,
+		version: IdentityCommitmentVersion,
+	) -> Result<
+		DipDidProofWithVerifiedSubjectCommitment<
+			IdentityCommitmentOf<ProviderRuntime>,
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			ConsumerBlockNumber,
+		>,
+		Error,
+	>
+	where
+		StateRoot: Ord,
+		ParachainHasher: Hash<Output = StateRoot>,
+		ProviderRuntime: pallet_dip_provider::Config,
+	{
+		let dip_commitment = verify_dip_commitment::<ParachainHasher, ProviderRuntime>(
 			self.state_root,
+			subject,
+			version,
 			self.dip_commitment_proof.0,
-		)
-		.map_err(Error::DipCommitmentMerkleProof)?;
+		)?;
 		Ok(DipDidProofWithVerifiedSubjectCommitment {
 			dip_commitment,
 			dip_proof: self.dip_proof,
@@ -449,20 +610,89 @@ impl<
 		>,
 		Error,
 	>
+	where
+		DidMerkleHasher: Hash<Output = Commitment>,
+	{
+		let proof_leaves_key_value_pairs = self
+			.dip_proof
+			.revealed
+			.iter()
+			.map(|revealed_leaf| (revealed_leaf.encoded_key(), Some(revealed_leaf.encoded_value())))
+			.collect::<Vec<_>>();
+		self.verify_dip_proof_for_key_value_pairs::<DidMerkleHasher, MAX_REVEALED_LEAVES_COUNT>(
+			proof_leaves_key_value_pairs,
+		)
+	}
+
+	/// Verifies the Merkle proof of the subject's DID details against a salted
+	/// commitment, in which each leaf is stored under its key prefixed by the
+	/// provided salt.
+	///
+	/// The salts must be provided in the same order as the revealed leaves.
+	/// The generic types are the same as for
+	/// [`Self::verify_dip_proof`].
+	pub fn verify_salted_dip_proof<DidMerkleHasher, const MAX_REVEALED_LEAVES_COUNT: u32>(
+		self,
+		salts: &[H256],
+	) -> Result<
+		DipRevealedDetailsAndUnverifiedDidSignature<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			ConsumerBlockNumber,
+			MAX_REVEALED_LEAVES_COUNT,
+		>,
+		Error,
+	>
 	where
 		DidMerkleHasher: Hash<Output = Commitment>,
 	{
 		ensure!(
-			self.dip_proof.revealed.len() <= MAX_REVEALED_LEAVES_COUNT.saturated_into(),
-			Error::TooManyLeavesRevealed
+			salts.len() == self.dip_proof.revealed.len(),
+			Error::InvalidDidMerkleProof
 		);
-
 		let proof_leaves_key_value_pairs = self
 			.dip_proof
 			.revealed
 			.iter()
-			.map(|revealed_leaf| (revealed_leaf.encoded_key(), Some(revealed_leaf.encoded_value())))
+			.zip(salts.iter())
+			.map(|(revealed_leaf, salt)| {
+				(
+					revealed_leaf.salted_encoded_key(salt),
+					Some(revealed_leaf.encoded_value()),
+				)
+			})
 			.collect::<Vec<_>>();
+		self.verify_dip_proof_for_key_value_pairs::<DidMerkleHasher, MAX_REVEALED_LEAVES_COUNT>(
+			proof_leaves_key_value_pairs,
+		)
+	}
+
+	fn verify_dip_proof_for_key_value_pairs<DidMerkleHasher, const MAX_REVEALED_LEAVES_COUNT: u32>(
+		self,
+		proof_leaves_key_value_pairs: Vec<(Vec<u8>, Option<Vec<u8>>)>,
+	) -> Result<
+		DipRevealedDetailsAndUnverifiedDidSignature<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			ConsumerBlockNumber,
+			MAX_REVEALED_LEAVES_COUNT,
+		>,
+		Error,
+	>
+	where
+		DidMerkleHasher: Hash<Output = Commitment>,
+	{
+		ensure!(
+			self.dip_proof.revealed.len() <= MAX_REVEALED_LEAVES_COUNT.saturated_into(),
+			Error::TooManyLeavesRevealed
+		);
+
 		verify_trie_proof::<LayoutV1<DidMerkleHasher>, _, _, _>(
 			&self.dip_commitment,
 			self.dip_proof.blinded.as_slice(),
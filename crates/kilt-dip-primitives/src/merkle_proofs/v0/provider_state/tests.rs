@@ -268,11 +268,16 @@ mod dip_did_proof_with_verified_relay_state_root {
 	}
 
 	impl pallet_dip_provider::Config for TestProviderRuntime {
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHooks = ();
 		type CommitOrigin = AccountId32;
 		type CommitOriginCheck = EnsureSigned<AccountId32>;
 		type Identifier = AccountId32;
 		type IdentityCommitmentGenerator = DefaultIdentityCommitmentGenerator<H256>;
 		type IdentityProvider = DefaultIdentityProvider;
+		type MaxCredentials = ConstU32<0>;
+		type MaxCommitmentVersions = ConstU32<3>;
+		type MaxVersionsPerCommit = ConstU32<3>;
 		type ProviderHooks = ();
 		type RuntimeEvent = RuntimeEvent;
 		type WeightInfo = ();
@@ -381,6 +386,24 @@ mod dip_did_proof_with_verified_relay_state_root {
 		);
 	}
 
+	#[test]
+	fn verify_dip_commitment_proof_for_subject_and_version_different_version() {
+		let (parachain_state_root, dip_commitment_proof) = get_dip_commitment_proof();
+		let proof =
+			DipDidProofWithVerifiedStateRoot::<_, (), (), (), (), (), ()>::with_state_root_and_dip_commitment_proof(
+				parachain_state_root,
+				dip_commitment_proof,
+			);
+		// The proof only contains the version 0 commitment.
+		assert_err!(
+			proof.verify_dip_commitment_proof_for_subject_and_version::<BlakeTwo256, TestProviderRuntime>(
+				&AccountId32::from_ss58check("4pevjN6chwUqWPVaoUF6naRmZyrA4XWfdK8nLQLEjufgW55c").unwrap(),
+				1,
+			),
+			Error::DipCommitmentMerkleProof(MerkleProofError::RequiredLeafNotRevealed)
+		);
+	}
+
 	#[test]
 	fn verify_dip_commitment_proof_for_subject_invalid_proof() {
 		let (parachain_state_root, dip_commitment_proof) = get_dip_commitment_proof();
@@ -410,13 +433,15 @@ mod dip_did_proof_with_verified_subject_commitment {
 		traits::{BlakeTwo256, Keccak256},
 		AccountId32, BoundedVec,
 	};
+	use sp_trie::{generate_trie_proof, LayoutV1, MemoryDB, TrieDBMutBuilder, TrieHash, TrieMut};
 	use uid_core::{
 		did_details::{DidPublicKeyDetails, DidVerificationKey},
 		DidVerificationKeyRelationship,
 	};
 
 	use crate::{
-		DidMerkleProof, DipDidProofWithVerifiedSubjectCommitment, Error, RevealedDidKey, TimeBoundDidSignature,
+		DidMerkleProof, DipDidProofWithVerifiedSubjectCommitment, Error, RevealedDidKey, RevealedDidMerkleProofLeaf,
+		TimeBoundDidSignature,
 	};
 
 	impl<
@@ -545,4 +570,65 @@ mod dip_did_proof_with_verified_subject_commitment {
 			);
 		assert_err!(proof.verify_dip_proof::<BlakeTwo256, 1>(), Error::InvalidDidMerkleProof);
 	}
+
+	#[test]
+	fn verify_dip_proof_salted_successful() {
+		let salt = H256([1; 32]);
+		let leaf: RevealedDidMerkleProofLeaf<H256, AccountId32, u64, BoundedVec<u8, ConstU32<32>>, LinkableAccountId> =
+			RevealedDidKey {
+				id: H256([2; 32]),
+				relationship: DidVerificationKeyRelationship::Authentication.into(),
+				details: DidPublicKeyDetails {
+					key: DidVerificationKey::Ed25519(ed25519::Public([3; 32])).into(),
+					block_number: 0,
+				},
+			}
+			.into();
+		let mut db = MemoryDB::<BlakeTwo256>::default();
+		let mut root = TrieHash::<LayoutV1<BlakeTwo256>>::default();
+		{
+			let mut trie = TrieDBMutBuilder::<LayoutV1<BlakeTwo256>>::new(&mut db, &mut root).build();
+			trie.insert(&leaf.salted_encoded_key(&salt), &leaf.encoded_value())
+				.unwrap();
+		}
+		let blinded =
+			generate_trie_proof::<LayoutV1<BlakeTwo256>, _, _, _>(&db, root, &[leaf.salted_encoded_key(&salt)])
+				.unwrap();
+
+		let build_proof = || {
+			DipDidProofWithVerifiedSubjectCommitment::<_, _, _, _, _, _, ()>::with_commitment_and_dip_proof(
+				root,
+				DidMerkleProof::new(blinded.clone(), vec![leaf.clone()]),
+			)
+		};
+		let proof_verification_result = build_proof().verify_salted_dip_proof::<BlakeTwo256, 1>(&[salt]).unwrap();
+		assert_eq!(
+			proof_verification_result.revealed_leaves.into_inner(),
+			vec![leaf.clone()]
+		);
+
+		// Verifying the same leaf without its salt fails.
+		assert_err!(
+			build_proof().verify_dip_proof::<BlakeTwo256, 1>(),
+			Error::InvalidDidMerkleProof
+		);
+		// Verifying the same leaf with a different salt fails.
+		assert_err!(
+			build_proof().verify_salted_dip_proof::<BlakeTwo256, 1>(&[H256([2; 32])]),
+			Error::InvalidDidMerkleProof
+		);
+	}
+
+	#[test]
+	fn verify_dip_proof_salts_count_mismatch() {
+		let (dip_commitment, dip_proof) = get_dip_proof();
+		let proof = DipDidProofWithVerifiedSubjectCommitment::<_, _, _, _, _, _, ()>::with_commitment_and_dip_proof(
+			dip_commitment,
+			dip_proof,
+		);
+		assert_err!(
+			proof.verify_salted_dip_proof::<BlakeTwo256, 1>(&[H256::default(), H256::default()]),
+			Error::InvalidDidMerkleProof
+		);
+	}
 }
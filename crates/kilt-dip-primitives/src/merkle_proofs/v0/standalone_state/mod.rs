@@ -0,0 +1,148 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::traits::Hash;
+use sp_std::fmt::Debug;
+
+use crate::{
+	merkle_proofs::v0::{
+		input_common::{DidMerkleProof, DipCommitmentStateProof, TimeBoundDidSignature},
+		provider_state::DipDidProofWithVerifiedStateRoot,
+	},
+	traits::GetWithArg,
+	utils::OutputOf,
+	Error,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A DIP proof submitted to a consumer of a standalone provider chain, i.e.,
+/// a chain that is neither a parachain nor a relaychain and whose state roots
+/// are made available to the consumer by other means, e.g., a light client.
+///
+/// The generic types indicate the following:
+/// * `KiltDidKeyId`: The DID key ID type configured by the KILT chain.
+/// * `KiltAccountId`: The `AccountId` type configured by the KILT chain.
+/// * `KiltBlockNumber`: The `BlockNumber` type configured by the KILT chain.
+/// * `KiltWeb3Name`: The web3name type configured by the KILT chain.
+/// * `KiltLinkableAccountId`: The linkable account ID type configured by the
+///   KILT chain.
+/// * `ConsumerBlockNumber`: The `BlockNumber` definition of the consumer
+///   chain.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo)]
+pub struct StandaloneDipDidProof<
+	KiltDidKeyId,
+	KiltAccountId,
+	KiltBlockNumber,
+	KiltWeb3Name,
+	KiltLinkableAccountId,
+	ConsumerBlockNumber,
+> {
+	/// The number of the finalized provider block the proof was generated
+	/// at.
+	pub(crate) provider_block_number: KiltBlockNumber,
+	/// The raw state proof for the DIP commitment of the given subject.
+	pub(crate) dip_commitment_proof: DipCommitmentStateProof,
+	/// The Merkle proof of the subject's DID details.
+	pub(crate) dip_proof:
+		DidMerkleProof<KiltDidKeyId, KiltAccountId, KiltBlockNumber, KiltWeb3Name, KiltLinkableAccountId>,
+	/// The cross-chain DID signature.
+	pub(crate) signature: TimeBoundDidSignature<ConsumerBlockNumber>,
+}
+
+impl<KiltDidKeyId, KiltAccountId, KiltBlockNumber, KiltWeb3Name, KiltLinkableAccountId, ConsumerBlockNumber>
+	StandaloneDipDidProof<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		ConsumerBlockNumber,
+	>
+{
+	pub fn new(
+		provider_block_number: KiltBlockNumber,
+		dip_commitment_proof: DipCommitmentStateProof,
+		dip_proof: DidMerkleProof<KiltDidKeyId, KiltAccountId, KiltBlockNumber, KiltWeb3Name, KiltLinkableAccountId>,
+		signature: TimeBoundDidSignature<ConsumerBlockNumber>,
+	) -> Self {
+		Self {
+			provider_block_number,
+			dip_commitment_proof,
+			dip_proof,
+			signature,
+		}
+	}
+
+	pub fn provider_block_number(&self) -> &KiltBlockNumber {
+		&self.provider_block_number
+	}
+
+	pub fn dip_commitment_proof(&self) -> &DipCommitmentStateProof {
+		&self.dip_commitment_proof
+	}
+
+	pub fn dip_proof(
+		&self,
+	) -> &DidMerkleProof<KiltDidKeyId, KiltAccountId, KiltBlockNumber, KiltWeb3Name, KiltLinkableAccountId> {
+		&self.dip_proof
+	}
+
+	pub fn signature(&self) -> &TimeBoundDidSignature<ConsumerBlockNumber> {
+		&self.signature
+	}
+
+	/// Retrieves the provider state root for the block specified in the proof
+	/// using the provided implementation. The returned proof can then be
+	/// verified in the same way as proofs for parachain providers.
+	///
+	/// The generic types indicate the following:
+	/// * `ProviderHasher`: The hashing algorithm used by the provider chain.
+	/// * `StateRootStore`: The type that returns a finalized provider state
+	///   root given a provider block number.
+	#[allow(clippy::type_complexity)]
+	pub fn verify_provider_state_root<ProviderHasher, StateRootStore>(
+		self,
+	) -> Result<
+		DipDidProofWithVerifiedStateRoot<
+			OutputOf<ProviderHasher>,
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			ConsumerBlockNumber,
+		>,
+		Error,
+	>
+	where
+		ProviderHasher: Hash,
+		StateRootStore: GetWithArg<KiltBlockNumber, Result = Option<OutputOf<ProviderHasher>>>,
+	{
+		let state_root = StateRootStore::get(&self.provider_block_number).ok_or(Error::ProviderStateRootNotFound)?;
+		Ok(DipDidProofWithVerifiedStateRoot {
+			state_root,
+			dip_commitment_proof: self.dip_commitment_proof,
+			dip_proof: self.dip_proof,
+			signature: self.signature,
+		})
+	}
+}
@@ -0,0 +1,74 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::assert_err;
+use sp_core::H256;
+use sp_runtime::traits::BlakeTwo256;
+
+use crate::{traits::GetWithArg, Error, StandaloneDipDidProof};
+
+struct StateRootStore;
+
+impl GetWithArg<u32> for StateRootStore {
+	type Result = Option<H256>;
+
+	fn get(arg: &u32) -> Self::Result {
+		(*arg == 1).then_some(H256([1; 32]))
+	}
+}
+
+impl<KiltDidKeyId, KiltAccountId, KiltBlockNumber, KiltWeb3Name, KiltLinkableAccountId, ConsumerBlockNumber>
+	StandaloneDipDidProof<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		ConsumerBlockNumber,
+	> where
+	KiltDidKeyId: Default,
+	KiltBlockNumber: Default,
+	ConsumerBlockNumber: Default,
+{
+	fn with_provider_block_number(provider_block_number: KiltBlockNumber) -> Self {
+		Self {
+			provider_block_number,
+			dip_commitment_proof: Default::default(),
+			dip_proof: Default::default(),
+			signature: Default::default(),
+		}
+	}
+}
+
+#[test]
+fn verify_provider_state_root_successful() {
+	let proof = StandaloneDipDidProof::<(), (), u32, (), (), ()>::with_provider_block_number(1);
+	let proof_verification_result = proof
+		.verify_provider_state_root::<BlakeTwo256, StateRootStore>()
+		.unwrap();
+	assert_eq!(proof_verification_result.state_root, H256([1; 32]));
+}
+
+#[test]
+fn verify_provider_state_root_not_found() {
+	let proof = StandaloneDipDidProof::<(), (), u32, (), (), ()>::with_provider_block_number(2);
+	assert_err!(
+		proof.verify_provider_state_root::<BlakeTwo256, StateRootStore>(),
+		Error::ProviderStateRootNotFound
+	);
+}
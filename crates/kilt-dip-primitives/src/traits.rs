@@ -16,6 +16,9 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
+pub use pallet_dip_provider::IdentityCommitmentVersion;
+use pallet_grandpa_light_client::{ProviderBlockNumberOf, ProviderHashOf};
+use parity_scale_codec::Encode;
 use sp_core::H256;
 use sp_runtime::traits::{CheckedAdd, One, Zero};
 use sp_std::marker::PhantomData;
@@ -39,6 +42,28 @@ where
 	}
 }
 
+/// A trait for the local identity details of a subject, to return the part of
+/// them that is included in the payload signed by the DID subject.
+pub trait SignedIdentityDetails {
+	/// The type of the details included in the signed payload.
+	type Signed: Encode;
+
+	/// Return the details to include in the signed payload.
+	fn signed_details(&self) -> Self::Signed;
+}
+
+/// Plain nonces are included in the signed payload as they are.
+impl<T> SignedIdentityDetails for T
+where
+	T: CheckedAdd + Zero + One + Clone + Encode,
+{
+	type Signed = T;
+
+	fn signed_details(&self) -> Self::Signed {
+		self.clone()
+	}
+}
+
 /// A trait for types that implement access control logic where the call is the
 /// controlled resource and access is granted based on the provided info.
 /// The generic types are the following:
@@ -85,6 +110,24 @@ where
 	}
 }
 
+/// Implementer of the [`GetWithArg`] trait that return the state root of a
+/// finalized block of a standalone provider chain with a given number by
+/// retrieving it from the [`pallet_grandpa_light_client::Pallet`] pallet
+/// storage.
+pub struct ProviderStateRootsViaGrandpaLightClientPallet<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> GetWithArg<ProviderBlockNumberOf<Runtime>> for ProviderStateRootsViaGrandpaLightClientPallet<Runtime>
+where
+	Runtime: pallet_grandpa_light_client::Config,
+{
+	type Result = Option<ProviderHashOf<Runtime>>;
+
+	fn get(arg: &ProviderBlockNumberOf<Runtime>) -> Self::Result {
+		pallet_grandpa_light_client::Pallet::<Runtime>::latest_finalized_header_for_block(arg)
+			.map(|header_info| header_info.state_root)
+	}
+}
+
 /// A trait similar in functionality to the [`frame_support::traits::Get`], but
 /// with an associated return type.
 pub trait GetWithoutArg {
@@ -99,6 +142,26 @@ impl GetWithoutArg for () {
 	fn get() -> Self::Result {}
 }
 
+/// A trait for DIP proof verifiers that understand a fixed set of identity
+/// commitment versions. Clients use it to negotiate, among the commitment
+/// versions published by the provider for a subject, the one to generate a
+/// proof for.
+pub trait SupportedCommitmentVersions {
+	/// The commitment versions the verifier understands.
+	const SUPPORTED_VERSIONS: &'static [IdentityCommitmentVersion];
+
+	/// Return the highest of the provided commitment versions that the
+	/// verifier understands, if any.
+	fn negotiate_version(
+		available_versions: impl IntoIterator<Item = IdentityCommitmentVersion>,
+	) -> Option<IdentityCommitmentVersion> {
+		available_versions
+			.into_iter()
+			.filter(|version| Self::SUPPORTED_VERSIONS.contains(version))
+			.max()
+	}
+}
+
 // Marker trait that requires a type to implement `Default` only for benchmarks.
 // Avoids code duplication.
 #[cfg(not(feature = "runtime-benchmarks"))]
@@ -110,3 +173,17 @@ impl<T> BenchmarkDefault for T {}
 pub trait BenchmarkDefault: Default {}
 #[cfg(feature = "runtime-benchmarks")]
 impl<T: Default> BenchmarkDefault for T {}
+
+#[test]
+fn negotiate_version_picks_highest_supported_version() {
+	struct Verifier;
+
+	impl SupportedCommitmentVersions for Verifier {
+		const SUPPORTED_VERSIONS: &'static [IdentityCommitmentVersion] = &[0, 1];
+	}
+
+	assert_eq!(Verifier::negotiate_version([0, 1, 2]), Some(1));
+	assert_eq!(Verifier::negotiate_version([2, 0]), Some(0));
+	assert_eq!(Verifier::negotiate_version([2, 3]), None);
+	assert_eq!(Verifier::negotiate_version([]), None);
+}
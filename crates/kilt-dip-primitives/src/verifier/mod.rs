@@ -22,9 +22,20 @@ mod errors;
 pub mod parachain;
 /// Verification logic to integrate a child chain as a DIP provider.
 pub mod relaychain;
+/// Session support on top of the full DIP proof verifiers.
+pub mod session;
+/// Verification logic to integrate a standalone chain as a DIP provider.
+pub mod standalone;
 pub use parachain::{
 	DipParachainStateProofVerifierError, KiltVersionedParachainVerifier, VersionedDipParachainStateProof,
 };
 pub use relaychain::{
 	DipRelaychainStateProofVerifierError, KiltVersionedRelaychainVerifier, VersionedRelaychainStateProof,
 };
+pub use session::{
+	DipCommitmentProofVerifier, DipSession, DipSessionIdentityDetails, DipSessionIdentityDetailsOf, DipSessionProof,
+	DipSessionVerifier, DipSessionVerifierError,
+};
+pub use standalone::{
+	DipStandaloneStateProofVerifierError, KiltVersionedStandaloneVerifier, VersionedDipStandaloneStateProof,
+};
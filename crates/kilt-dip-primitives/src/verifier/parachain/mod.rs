@@ -17,10 +17,10 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use uid_core::KeyIdOf;
-use frame_system::pallet_prelude::BlockNumberFor;
+use frame_system::pallet_prelude::{BlockNumberFor, HeaderFor};
 use pallet_did_lookup::linkable_account::LinkableAccountId;
 use pallet_dip_consumer::{traits::IdentityProofVerifier, RuntimeCallOf};
-use pallet_dip_provider::traits::IdentityCommitmentGenerator;
+use pallet_dip_provider::{traits::IdentityCommitmentGenerator, IdentityCommitmentOf, IdentityCommitmentVersion};
 use pallet_web3_names::Web3NameOf;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
@@ -28,9 +28,12 @@ use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
 
 use crate::{
 	merkle_proofs::v0::RevealedDidKey,
-	traits::{DipCallOriginFilter, GetWithArg, GetWithoutArg, Incrementable},
+	traits::{
+		DipCallOriginFilter, GetWithArg, GetWithoutArg, Incrementable, SignedIdentityDetails,
+		SupportedCommitmentVersions,
+	},
 	utils::OutputOf,
-	DipOriginInfo, ParachainDipDidProof,
+	DipCommitmentProofVerifier, DipOriginInfo, ParachainDipCommitmentProof, ParachainDipDidProof, SaltedProof,
 };
 
 pub mod v0;
@@ -63,6 +66,35 @@ pub enum VersionedDipParachainStateProof<
 			ConsumerBlockNumber,
 		>,
 	),
+	/// Same format as the `V0` proof, verified against the version `1` DIP
+	/// commitment, which also reveals public credentials and verifications.
+	V1(
+		ParachainDipDidProof<
+			RelayBlockNumber,
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			ConsumerBlockNumber,
+		>,
+	),
+	/// Same format as the `V1` proof, wrapped in a [`SaltedProof`] together
+	/// with the salts of the revealed leaves, and verified against the salted
+	/// version `2` DIP commitment.
+	V2(
+		SaltedProof<
+			ParachainDipDidProof<
+				RelayBlockNumber,
+				KiltDidKeyId,
+				KiltAccountId,
+				KiltBlockNumber,
+				KiltWeb3Name,
+				KiltLinkableAccountId,
+				ConsumerBlockNumber,
+			>,
+		>,
+	),
 }
 
 impl<
@@ -144,6 +176,40 @@ pub struct KiltVersionedParachainVerifier<
 	)>,
 );
 
+impl<
+		RelaychainRuntime,
+		RelaychainStateRootStore,
+		const KILT_PARA_ID: u32,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		const MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT: u32,
+		const MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE: u32,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+	> SupportedCommitmentVersions
+	for KiltVersionedParachainVerifier<
+		RelaychainRuntime,
+		RelaychainStateRootStore,
+		KILT_PARA_ID,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
+		MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+		MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+	>
+{
+	const SUPPORTED_VERSIONS: &'static [IdentityCommitmentVersion] = &[0, 1, 2];
+}
+
 impl<
 		ConsumerRuntime,
 		RelaychainRuntime,
@@ -176,7 +242,7 @@ impl<
 		MAX_DID_MERKLE_LEAVES_REVEALED,
 	> where
 	ConsumerRuntime: pallet_dip_consumer::Config<Identifier = KiltRuntime::Identifier>,
-	ConsumerRuntime::LocalIdentityInfo: Incrementable + Default,
+	ConsumerRuntime::LocalIdentityInfo: Incrementable + SignedIdentityDetails + Default,
 	RelaychainRuntime: frame_system::Config,
 	RelaychainStateRootStore:
 		GetWithArg<BlockNumberFor<RelaychainRuntime>, Result = Option<OutputOf<RelaychainRuntime::Hashing>>>,
@@ -243,6 +309,156 @@ impl<
 				identity_details,
 				v0_proof,
 			),
+			VersionedDipParachainStateProof::V1(v1_proof) => <v0::ParachainVerifier<
+				RelaychainRuntime,
+				RelaychainStateRootStore,
+				KILT_PARA_ID,
+				KiltRuntime,
+				DidCallVerifier,
+				SignedExtra,
+				MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
+				MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
+				MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+				MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+				MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+				MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+				MAX_DID_MERKLE_LEAVES_REVEALED,
+				1,
+			> as IdentityProofVerifier<ConsumerRuntime>>::verify_proof_for_call_against_details(
+				call,
+				subject,
+				submitter,
+				identity_details,
+				v1_proof,
+			),
+			VersionedDipParachainStateProof::V2(v2_proof) => {
+				let (v2_proof, salts) = v2_proof.into_parts();
+				v0::ParachainVerifier::<
+					RelaychainRuntime,
+					RelaychainStateRootStore,
+					KILT_PARA_ID,
+					KiltRuntime,
+					DidCallVerifier,
+					SignedExtra,
+					MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
+					MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
+					MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+					MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+					MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+					MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+					MAX_DID_MERKLE_LEAVES_REVEALED,
+					2,
+				>::verify_proof_for_call_against_details_with_salts(
+					call,
+					subject,
+					submitter,
+					identity_details,
+					v2_proof,
+					Some(&salts),
+				)
+			}
+		}
+	}
+}
+
+/// Sessions opened with a versioned proof are bound to the DIP commitment the
+/// proof is verified against, and are continued with a
+/// [`ParachainDipCommitmentProof`] for the same commitment version.
+impl<
+		ConsumerRuntime,
+		RelaychainRuntime,
+		RelaychainStateRootStore,
+		const KILT_PARA_ID: u32,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		const MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT: u32,
+		const MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE: u32,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+	> DipCommitmentProofVerifier<ConsumerRuntime>
+	for KiltVersionedParachainVerifier<
+		RelaychainRuntime,
+		RelaychainStateRootStore,
+		KILT_PARA_ID,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
+		MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+		MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+	> where
+	ConsumerRuntime: pallet_dip_consumer::Config<Identifier = KiltRuntime::Identifier>,
+	ConsumerRuntime::LocalIdentityInfo: Incrementable + SignedIdentityDetails + Default,
+	RelaychainRuntime: frame_system::Config,
+	RelaychainStateRootStore:
+		GetWithArg<BlockNumberFor<RelaychainRuntime>, Result = Option<OutputOf<RelaychainRuntime::Hashing>>>,
+	KiltRuntime: frame_system::Config<Hash = RelaychainRuntime::Hash>
+		+ pallet_dip_provider::Config
+		+ uid_core::Config
+		+ pallet_web3_names::Config
+		+ pallet_did_lookup::Config,
+	KiltRuntime::IdentityCommitmentGenerator:
+		IdentityCommitmentGenerator<KiltRuntime, Output = RelaychainRuntime::Hash>,
+	SignedExtra: GetWithoutArg,
+	SignedExtra::Result: Encode + Debug,
+	DidCallVerifier: DipCallOriginFilter<
+		RuntimeCallOf<ConsumerRuntime>,
+		OriginInfo = Vec<RevealedDidKey<KeyIdOf<KiltRuntime>, BlockNumberFor<KiltRuntime>, KiltRuntime::AccountId>>,
+	>,
+	DidCallVerifier::Error: Into<u8> + Debug,
+{
+	type Commitment = IdentityCommitmentOf<KiltRuntime>;
+	type CommitmentProof = ParachainDipCommitmentProof<BlockNumberFor<RelaychainRuntime>>;
+
+	fn commitment_proof(proof: &Self::Proof) -> (IdentityCommitmentVersion, Self::CommitmentProof) {
+		let (version, proof) = match proof {
+			VersionedDipParachainStateProof::V0(v0_proof) => (0, v0_proof),
+			VersionedDipParachainStateProof::V1(v1_proof) => (1, v1_proof),
+			VersionedDipParachainStateProof::V2(v2_proof) => (2, v2_proof.proof()),
+		};
+		(
+			version,
+			ParachainDipCommitmentProof::new(proof.provider_head_proof.clone(), proof.dip_commitment_proof.clone()),
+		)
+	}
+
+	fn verify_commitment_proof(
+		subject: &ConsumerRuntime::Identifier,
+		version: IdentityCommitmentVersion,
+		proof: Self::CommitmentProof,
+	) -> Result<Self::Commitment, Self::Error> {
+		if !<Self as SupportedCommitmentVersions>::SUPPORTED_VERSIONS.contains(&version) {
+			return Err(DipParachainStateProofVerifierError::UnsupportedVersion);
 		}
+		v0::ensure_provider_head_proof_size(
+			&proof.provider_head_proof,
+			MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
+			MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
+		)
+		.and_then(|_| {
+			v0::ensure_dip_commitment_proof_size(
+				&proof.dip_commitment_proof,
+				MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+				MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+			)
+		})
+		.map_err(|e| DipParachainStateProofVerifierError::ProofComponentTooLarge(e as u8))?;
+		proof
+			.verify_dip_commitment_for_subject_and_version::<
+				RelaychainRuntime::Hashing,
+				RelaychainStateRootStore,
+				HeaderFor<KiltRuntime>,
+				KiltRuntime::Hashing,
+				KiltRuntime,
+			>(KILT_PARA_ID, subject, version)
+			.map_err(DipParachainStateProofVerifierError::ProofVerification)
 	}
 }
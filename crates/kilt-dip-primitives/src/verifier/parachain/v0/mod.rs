@@ -20,15 +20,16 @@ use uid_core::KeyIdOf;
 use frame_system::pallet_prelude::{BlockNumberFor, HeaderFor};
 use pallet_did_lookup::linkable_account::LinkableAccountId;
 use pallet_dip_consumer::{traits::IdentityProofVerifier, RuntimeCallOf};
-use pallet_dip_provider::traits::IdentityCommitmentGenerator;
+use pallet_dip_provider::{traits::IdentityCommitmentGenerator, IdentityCommitmentVersion};
 use pallet_web3_names::Web3NameOf;
 use parity_scale_codec::Encode;
+use sp_core::H256;
 use sp_runtime::{traits::Zero, SaturatedConversion};
 use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
 
 use crate::{
-	merkle_proofs::v0::ParachainDipDidProof,
-	traits::{DipCallOriginFilter, GetWithArg, GetWithoutArg, Incrementable},
+	merkle_proofs::v0::{DipCommitmentStateProof, ParachainDipDidProof, ProviderHeadStateProof},
+	traits::{DipCallOriginFilter, GetWithArg, GetWithoutArg, Incrementable, SignedIdentityDetails},
 	utils::OutputOf,
 	verifier::errors::DipProofComponentTooLargeError,
 	DipOriginInfo, DipParachainStateProofVerifierError, RevealedDidKey,
@@ -70,6 +71,9 @@ const LOG_TARGET: &str = "dip::consumer::ParachainVerifierV0";
 ///   revealed as part of the DID Merkle proof.
 /// * `MAX_DID_MERKLE_LEAVES_REVEALED`: The maximum number of leaves that can be
 ///   revealed as part of the DID Merkle proof.
+/// * `DIP_COMMITMENT_VERSION`: The version of the DIP commitment the DID Merkle
+///   proof is verified against. Commitment versions `0` and `1` share the same
+///   proof format.
 pub struct ParachainVerifier<
 	RelaychainRuntime,
 	RelaychainStateRootStore,
@@ -84,6 +88,7 @@ pub struct ParachainVerifier<
 	const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
 	const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
 	const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+	const DIP_COMMITMENT_VERSION: IdentityCommitmentVersion = 0,
 >(
 	PhantomData<(
 		RelaychainRuntime,
@@ -109,6 +114,7 @@ impl<
 		const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
 		const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
 		const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+		const DIP_COMMITMENT_VERSION: IdentityCommitmentVersion,
 	> IdentityProofVerifier<ConsumerRuntime>
 	for ParachainVerifier<
 		RelaychainRuntime,
@@ -124,9 +130,10 @@ impl<
 		MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
 		MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
 		MAX_DID_MERKLE_LEAVES_REVEALED,
+		DIP_COMMITMENT_VERSION,
 	> where
 	ConsumerRuntime: pallet_dip_consumer::Config<Identifier = KiltRuntime::Identifier>,
-	ConsumerRuntime::LocalIdentityInfo: Incrementable + Default,
+	ConsumerRuntime::LocalIdentityInfo: Incrementable + SignedIdentityDetails + Default,
 	RelaychainRuntime: frame_system::Config,
 	RelaychainStateRootStore:
 		GetWithArg<BlockNumberFor<RelaychainRuntime>, Result = Option<OutputOf<RelaychainRuntime::Hashing>>>,
@@ -172,35 +179,93 @@ impl<
 		identity_details: &mut Option<<ConsumerRuntime as pallet_dip_consumer::Config>::LocalIdentityInfo>,
 		proof: Self::Proof,
 	) -> Result<Self::VerificationResult, Self::Error> {
-		// 1. Verify parachain state is finalized by relay chain and fresh.
-		if proof.provider_head_proof.proof.len() > MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT.saturated_into() {
-			let inner_error = DipProofComponentTooLargeError::ParachainHeadProofTooManyLeaves;
-			log::info!(
-				target: LOG_TARGET,
-				"Failed to verify DIP proof with error {:#?}",
-				inner_error
-			);
-			return Err(DipParachainStateProofVerifierError::ProofComponentTooLarge(
-				inner_error as u8,
-			));
-		}
+		Self::verify_proof_for_call_against_details_with_salts(call, subject, submitter, identity_details, proof, None)
+	}
+}
 
-		if proof
-			.provider_head_proof
-			.proof
-			.iter()
-			.any(|l| l.len() > MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE.saturated_into())
-		{
-			let inner_error = DipProofComponentTooLargeError::ParachainHeadProofLeafTooLarge;
+impl<
+		RelaychainRuntime,
+		RelaychainStateRootStore,
+		const KILT_PARA_ID: u32,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		const MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT: u32,
+		const MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE: u32,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+		const DIP_COMMITMENT_VERSION: IdentityCommitmentVersion,
+	>
+	ParachainVerifier<
+		RelaychainRuntime,
+		RelaychainStateRootStore,
+		KILT_PARA_ID,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
+		MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+		MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+		DIP_COMMITMENT_VERSION,
+	>
+{
+	/// Verify the provided proof the same way
+	/// [`IdentityProofVerifier::verify_proof_for_call_against_details`] does.
+	/// If `salts` are provided, the DID Merkle proof is verified against a
+	/// salted DIP commitment, using one salt for each revealed leaf.
+	pub(crate) fn verify_proof_for_call_against_details_with_salts<ConsumerRuntime>(
+		call: &RuntimeCallOf<ConsumerRuntime>,
+		subject: &<ConsumerRuntime as pallet_dip_consumer::Config>::Identifier,
+		submitter: &<ConsumerRuntime>::AccountId,
+		identity_details: &mut Option<<ConsumerRuntime as pallet_dip_consumer::Config>::LocalIdentityInfo>,
+		proof: <Self as IdentityProofVerifier<ConsumerRuntime>>::Proof,
+		salts: Option<&[H256]>,
+	) -> Result<
+		<Self as IdentityProofVerifier<ConsumerRuntime>>::VerificationResult,
+		<Self as IdentityProofVerifier<ConsumerRuntime>>::Error,
+	>
+	where
+		ConsumerRuntime: pallet_dip_consumer::Config<Identifier = KiltRuntime::Identifier>,
+		ConsumerRuntime::LocalIdentityInfo: Incrementable + SignedIdentityDetails + Default,
+		RelaychainRuntime: frame_system::Config,
+		RelaychainStateRootStore:
+			GetWithArg<BlockNumberFor<RelaychainRuntime>, Result = Option<OutputOf<RelaychainRuntime::Hashing>>>,
+		KiltRuntime: frame_system::Config<Hash = RelaychainRuntime::Hash>
+			+ pallet_dip_provider::Config
+			+ uid_core::Config
+			+ pallet_web3_names::Config
+			+ pallet_did_lookup::Config,
+		KiltRuntime::IdentityCommitmentGenerator:
+			IdentityCommitmentGenerator<KiltRuntime, Output = RelaychainRuntime::Hash>,
+		SignedExtra: GetWithoutArg,
+		SignedExtra::Result: Encode + Debug,
+		DidCallVerifier: DipCallOriginFilter<
+			RuntimeCallOf<ConsumerRuntime>,
+			OriginInfo = Vec<RevealedDidKey<KeyIdOf<KiltRuntime>, BlockNumberFor<KiltRuntime>, KiltRuntime::AccountId>>,
+		>,
+		DidCallVerifier::Error: Into<u8> + Debug,
+	{
+		// 1. Verify parachain state is finalized by relay chain and fresh.
+		ensure_provider_head_proof_size(
+			&proof.provider_head_proof,
+			MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
+			MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
+		)
+		.map_err(|inner_error| {
 			log::info!(
 				target: LOG_TARGET,
 				"Failed to verify DIP proof with error {:#?}",
 				inner_error
 			);
-			return Err(DipParachainStateProofVerifierError::ProofComponentTooLarge(
-				inner_error as u8,
-			));
-		}
+			DipParachainStateProofVerifierError::ProofComponentTooLarge(inner_error as u8)
+		})?;
 
 		let proof_without_relaychain = proof
 			.verify_provider_head_proof::<RelaychainRuntime::Hashing, RelaychainStateRootStore, HeaderFor<KiltRuntime>>(
@@ -217,38 +282,25 @@ impl<
 		);
 
 		// 2. Verify commitment is included in provider parachain state.
-		if proof_without_relaychain.dip_commitment_proof.0.len() > MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT.saturated_into()
-		{
-			let inner_error = DipProofComponentTooLargeError::DipCommitmentProofTooManyLeaves;
-			log::info!(
-				target: LOG_TARGET,
-				"Failed to verify DIP proof with error {:#?}",
-				inner_error
-			);
-			return Err(DipParachainStateProofVerifierError::ProofComponentTooLarge(
-				inner_error as u8,
-			));
-		}
-
-		if proof_without_relaychain
-			.dip_commitment_proof
-			.0
-			.iter()
-			.any(|l| l.len() > MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE.saturated_into())
-		{
-			let inner_error = DipProofComponentTooLargeError::DipCommitmentProofLeafTooLarge;
+		ensure_dip_commitment_proof_size(
+			&proof_without_relaychain.dip_commitment_proof,
+			MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+			MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+		)
+		.map_err(|inner_error| {
 			log::info!(
 				target: LOG_TARGET,
 				"Failed to verify DIP proof with error {:#?}",
 				inner_error
 			);
-			return Err(DipParachainStateProofVerifierError::ProofComponentTooLarge(
-				inner_error as u8,
-			));
-		}
+			DipParachainStateProofVerifierError::ProofComponentTooLarge(inner_error as u8)
+		})?;
 
 		let proof_without_parachain = proof_without_relaychain
-			.verify_dip_commitment_proof_for_subject::<KiltRuntime::Hashing, KiltRuntime>(subject)
+			.verify_dip_commitment_proof_for_subject_and_version::<KiltRuntime::Hashing, KiltRuntime>(
+				subject,
+				DIP_COMMITMENT_VERSION,
+			)
 			.map_err(DipParachainStateProofVerifierError::ProofVerification)?;
 		log::info!(
 			target: LOG_TARGET,
@@ -286,12 +338,15 @@ impl<
 			));
 		}
 
-		let proof_without_dip_merkle = proof_without_parachain
-			.verify_dip_proof::<KiltRuntime::Hashing, MAX_DID_MERKLE_LEAVES_REVEALED>()
-			.map_err(|e| {
-				log::info!(target: LOG_TARGET, "Failed to verify DIP proof with error {:#?}", e);
-				DipParachainStateProofVerifierError::ProofVerification(e)
-			})?;
+		let proof_without_dip_merkle = match salts {
+			Some(salts) => proof_without_parachain
+				.verify_salted_dip_proof::<KiltRuntime::Hashing, MAX_DID_MERKLE_LEAVES_REVEALED>(salts),
+			None => proof_without_parachain.verify_dip_proof::<KiltRuntime::Hashing, MAX_DID_MERKLE_LEAVES_REVEALED>(),
+		}
+		.map_err(|e| {
+			log::info!(target: LOG_TARGET, "Failed to verify DIP proof with error {:#?}", e);
+			DipParachainStateProofVerifierError::ProofVerification(e)
+		})?;
 		log::info!(
 			target: LOG_TARGET,
 			"Verified DID Merkle leaves: {:#?}",
@@ -306,7 +361,7 @@ impl<
 		log::trace!(target: LOG_TARGET, "Additional components for signature verification: current block number = {:#?}, genesis hash = {:#?}, signed extra = {:#?}", current_block_number, consumer_genesis_hash, signed_extra);
 		let encoded_payload = (
 			call,
-			&identity_details,
+			identity_details.as_ref().map(SignedIdentityDetails::signed_details),
 			submitter,
 			proof_without_dip_merkle.signature.valid_until,
 			consumer_genesis_hash,
@@ -350,3 +405,43 @@ impl<
 		Ok(revealed_did_info)
 	}
 }
+
+/// Checks that the parachain head state proof does not exceed the given
+/// number of leaves and leaf size.
+pub(crate) fn ensure_provider_head_proof_size<RelayBlockNumber>(
+	provider_head_proof: &ProviderHeadStateProof<RelayBlockNumber>,
+	max_leave_count: u32,
+	max_leave_size: u32,
+) -> Result<(), DipProofComponentTooLargeError> {
+	if provider_head_proof.proof.len() > max_leave_count.saturated_into() {
+		return Err(DipProofComponentTooLargeError::ParachainHeadProofTooManyLeaves);
+	}
+	if provider_head_proof
+		.proof
+		.iter()
+		.any(|l| l.len() > max_leave_size.saturated_into())
+	{
+		return Err(DipProofComponentTooLargeError::ParachainHeadProofLeafTooLarge);
+	}
+	Ok(())
+}
+
+/// Checks that the DIP commitment state proof does not exceed the given
+/// number of leaves and leaf size.
+pub(crate) fn ensure_dip_commitment_proof_size(
+	dip_commitment_proof: &DipCommitmentStateProof,
+	max_leave_count: u32,
+	max_leave_size: u32,
+) -> Result<(), DipProofComponentTooLargeError> {
+	if dip_commitment_proof.0.len() > max_leave_count.saturated_into() {
+		return Err(DipProofComponentTooLargeError::DipCommitmentProofTooManyLeaves);
+	}
+	if dip_commitment_proof
+		.0
+		.iter()
+		.any(|l| l.len() > max_leave_size.saturated_into())
+	{
+		return Err(DipProofComponentTooLargeError::DipCommitmentProofLeafTooLarge);
+	}
+	Ok(())
+}
@@ -20,7 +20,7 @@ use uid_core::KeyIdOf;
 use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_did_lookup::linkable_account::LinkableAccountId;
 use pallet_dip_consumer::{traits::IdentityProofVerifier, RuntimeCallOf};
-use pallet_dip_provider::traits::IdentityCommitmentGenerator;
+use pallet_dip_provider::{traits::IdentityCommitmentGenerator, IdentityCommitmentVersion};
 use pallet_web3_names::Web3NameOf;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
@@ -30,9 +30,12 @@ use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
 
 use crate::{
 	merkle_proofs::v0::RevealedDidKey,
-	traits::{DipCallOriginFilter, GetWithArg, GetWithoutArg, Incrementable},
+	traits::{
+		DipCallOriginFilter, GetWithArg, GetWithoutArg, Incrementable, SignedIdentityDetails,
+		SupportedCommitmentVersions,
+	},
 	utils::OutputOf,
-	DipOriginInfo, RelayDipDidProof,
+	DipOriginInfo, RelayDipDidProof, SaltedProof,
 };
 
 pub mod v0;
@@ -65,6 +68,35 @@ pub enum VersionedRelaychainStateProof<
 			KiltLinkableAccountId,
 		>,
 	),
+	/// Same format as the `V0` proof, verified against the version `1` DIP
+	/// commitment, which also reveals public credentials and verifications.
+	V1(
+		RelayDipDidProof<
+			ConsumerBlockNumber,
+			ConsumerBlockHasher,
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+		>,
+	),
+	/// Same format as the `V1` proof, wrapped in a [`SaltedProof`] together
+	/// with the salts of the revealed leaves, and verified against the salted
+	/// version `2` DIP commitment.
+	V2(
+		SaltedProof<
+			RelayDipDidProof<
+				ConsumerBlockNumber,
+				ConsumerBlockHasher,
+				KiltDidKeyId,
+				KiltAccountId,
+				KiltBlockNumber,
+				KiltWeb3Name,
+				KiltLinkableAccountId,
+			>,
+		>,
+	),
 }
 
 impl<
@@ -137,6 +169,38 @@ pub struct KiltVersionedRelaychainVerifier<
 	const MAX_DID_MERKLE_LEAVES_REVEALED: u32 = DEFAULT_MAX_DID_MERKLE_LEAVES_REVEALED,
 >(#[allow(clippy::type_complexity)] PhantomData<(ConsumerBlockHashStore, KiltRuntime, DidCallVerifier, SignedExtra)>);
 
+impl<
+		ConsumerBlockHashStore,
+		const KILT_PARA_ID: u32,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		const MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT: u32,
+		const MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE: u32,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+	> SupportedCommitmentVersions
+	for KiltVersionedRelaychainVerifier<
+		ConsumerBlockHashStore,
+		KILT_PARA_ID,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
+		MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+		MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+	>
+{
+	const SUPPORTED_VERSIONS: &'static [IdentityCommitmentVersion] = &[0, 1, 2];
+}
+
 impl<
 		ConsumerRuntime,
 		ConsumerBlockHashStore,
@@ -167,7 +231,7 @@ impl<
 		MAX_DID_MERKLE_LEAVES_REVEALED,
 	> where
 	ConsumerRuntime: pallet_dip_consumer::Config<Identifier = KiltRuntime::Identifier>,
-	ConsumerRuntime::LocalIdentityInfo: Incrementable + Default,
+	ConsumerRuntime::LocalIdentityInfo: Incrementable + SignedIdentityDetails + Default,
 	BlockNumberFor<ConsumerRuntime>: Into<U256> + TryFrom<U256>,
 	ConsumerBlockHashStore:
 		GetWithArg<BlockNumberFor<ConsumerRuntime>, Result = Option<OutputOf<ConsumerRuntime::Hashing>>>,
@@ -232,6 +296,52 @@ impl<
 				identity_details,
 				v0_proof,
 			),
+			VersionedRelaychainStateProof::V1(v1_proof) => <v0::RelaychainVerifier<
+				ConsumerBlockHashStore,
+				KILT_PARA_ID,
+				KiltRuntime,
+				DidCallVerifier,
+				SignedExtra,
+				MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
+				MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
+				MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+				MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+				MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+				MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+				MAX_DID_MERKLE_LEAVES_REVEALED,
+				1,
+			> as IdentityProofVerifier<ConsumerRuntime>>::verify_proof_for_call_against_details(
+				call,
+				subject,
+				submitter,
+				identity_details,
+				v1_proof,
+			),
+			VersionedRelaychainStateProof::V2(v2_proof) => {
+				let (v2_proof, salts) = v2_proof.into_parts();
+				v0::RelaychainVerifier::<
+					ConsumerBlockHashStore,
+					KILT_PARA_ID,
+					KiltRuntime,
+					DidCallVerifier,
+					SignedExtra,
+					MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
+					MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
+					MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+					MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+					MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+					MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+					MAX_DID_MERKLE_LEAVES_REVEALED,
+					2,
+				>::verify_proof_for_call_against_details_with_salts(
+					call,
+					subject,
+					submitter,
+					identity_details,
+					v2_proof,
+					Some(&salts),
+				)
+			}
 		}
 	}
 }
@@ -20,15 +20,15 @@ use uid_core::KeyIdOf;
 use frame_system::pallet_prelude::{BlockNumberFor, HeaderFor};
 use pallet_did_lookup::linkable_account::LinkableAccountId;
 use pallet_dip_consumer::{traits::IdentityProofVerifier, RuntimeCallOf};
-use pallet_dip_provider::{traits::IdentityCommitmentGenerator, IdentityCommitmentOf};
+use pallet_dip_provider::{traits::IdentityCommitmentGenerator, IdentityCommitmentOf, IdentityCommitmentVersion};
 use pallet_web3_names::Web3NameOf;
 use parity_scale_codec::Encode;
-use sp_core::U256;
+use sp_core::{H256, U256};
 use sp_runtime::{traits::Zero, SaturatedConversion};
 use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
 
 use crate::{
-	traits::{DipCallOriginFilter, GetWithArg, GetWithoutArg, Incrementable},
+	traits::{DipCallOriginFilter, GetWithArg, GetWithoutArg, Incrementable, SignedIdentityDetails},
 	utils::OutputOf,
 	verifier::errors::DipProofComponentTooLargeError,
 	DipOriginInfo, DipRelaychainStateProofVerifierError, RelayDipDidProof, RevealedDidKey,
@@ -63,6 +63,9 @@ const LOG_TARGET: &str = "dip::consumer::RelaychainVerifierV0";
 ///   revealed as part of the DID Merkle proof.
 /// * `MAX_DID_MERKLE_PROOF_LEAVE_SIZE`: The maximum number of leaves that can
 ///   be revealed as part of the DID Merkle proof.
+/// * `DIP_COMMITMENT_VERSION`: The version of the DIP commitment the DID Merkle
+///   proof is verified against. Commitment versions `0` and `1` share the same
+///   proof format.
 pub struct RelaychainVerifier<
 	ConsumerBlockHashStore,
 	const KILT_PARA_ID: u32,
@@ -76,6 +79,7 @@ pub struct RelaychainVerifier<
 	const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
 	const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
 	const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+	const DIP_COMMITMENT_VERSION: IdentityCommitmentVersion = 0,
 >(#[allow(clippy::type_complexity)] PhantomData<(ConsumerBlockHashStore, KiltRuntime, DidCallVerifier, SignedExtra)>);
 
 impl<
@@ -92,6 +96,7 @@ impl<
 		const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
 		const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
 		const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+		const DIP_COMMITMENT_VERSION: IdentityCommitmentVersion,
 	> IdentityProofVerifier<ConsumerRuntime>
 	for RelaychainVerifier<
 		ConsumerBlockHashStore,
@@ -106,9 +111,10 @@ impl<
 		MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
 		MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
 		MAX_DID_MERKLE_LEAVES_REVEALED,
+		DIP_COMMITMENT_VERSION,
 	> where
 	ConsumerRuntime: pallet_dip_consumer::Config<Identifier = KiltRuntime::Identifier>,
-	ConsumerRuntime::LocalIdentityInfo: Incrementable + Default,
+	ConsumerRuntime::LocalIdentityInfo: Incrementable + SignedIdentityDetails + Default,
 	BlockNumberFor<ConsumerRuntime>: Into<U256> + TryFrom<U256>,
 	ConsumerBlockHashStore:
 		GetWithArg<BlockNumberFor<ConsumerRuntime>, Result = Option<OutputOf<ConsumerRuntime::Hashing>>>,
@@ -153,6 +159,78 @@ impl<
 		identity_details: &mut Option<ConsumerRuntime::LocalIdentityInfo>,
 		proof: Self::Proof,
 	) -> Result<Self::VerificationResult, Self::Error> {
+		Self::verify_proof_for_call_against_details_with_salts(call, subject, submitter, identity_details, proof, None)
+	}
+}
+
+impl<
+		ConsumerBlockHashStore,
+		const KILT_PARA_ID: u32,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		const MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT: u32,
+		const MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE: u32,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+		const DIP_COMMITMENT_VERSION: IdentityCommitmentVersion,
+	>
+	RelaychainVerifier<
+		ConsumerBlockHashStore,
+		KILT_PARA_ID,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT,
+		MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+		MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+		DIP_COMMITMENT_VERSION,
+	>
+{
+	/// Verify the provided proof the same way
+	/// [`IdentityProofVerifier::verify_proof_for_call_against_details`] does.
+	/// If `salts` are provided, the DID Merkle proof is verified against a
+	/// salted DIP commitment, using one salt for each revealed leaf.
+	pub(crate) fn verify_proof_for_call_against_details_with_salts<ConsumerRuntime>(
+		call: &RuntimeCallOf<ConsumerRuntime>,
+		subject: &<ConsumerRuntime as pallet_dip_consumer::Config>::Identifier,
+		submitter: &<ConsumerRuntime>::AccountId,
+		identity_details: &mut Option<<ConsumerRuntime as pallet_dip_consumer::Config>::LocalIdentityInfo>,
+		proof: <Self as IdentityProofVerifier<ConsumerRuntime>>::Proof,
+		salts: Option<&[H256]>,
+	) -> Result<
+		<Self as IdentityProofVerifier<ConsumerRuntime>>::VerificationResult,
+		<Self as IdentityProofVerifier<ConsumerRuntime>>::Error,
+	>
+	where
+		ConsumerRuntime: pallet_dip_consumer::Config<Identifier = KiltRuntime::Identifier>,
+		ConsumerRuntime::LocalIdentityInfo: Incrementable + SignedIdentityDetails + Default,
+		BlockNumberFor<ConsumerRuntime>: Into<U256> + TryFrom<U256>,
+		ConsumerBlockHashStore:
+			GetWithArg<BlockNumberFor<ConsumerRuntime>, Result = Option<OutputOf<ConsumerRuntime::Hashing>>>,
+		KiltRuntime: frame_system::Config<Hash = ConsumerRuntime::Hash>
+			+ pallet_dip_provider::Config
+			+ uid_core::Config
+			+ pallet_web3_names::Config
+			+ pallet_did_lookup::Config,
+		KiltRuntime::IdentityCommitmentGenerator:
+			IdentityCommitmentGenerator<KiltRuntime, Output = ConsumerRuntime::Hash>,
+		IdentityCommitmentOf<KiltRuntime>: Into<KiltRuntime::Hash>,
+		SignedExtra: GetWithoutArg,
+		SignedExtra::Result: Encode + Debug,
+		DidCallVerifier: DipCallOriginFilter<
+			RuntimeCallOf<ConsumerRuntime>,
+			OriginInfo = Vec<RevealedDidKey<KeyIdOf<KiltRuntime>, BlockNumberFor<KiltRuntime>, KiltRuntime::AccountId>>,
+		>,
+		DidCallVerifier::Error: Into<u8> + Debug,
+	{
 		// 1. Verify provided relaychain header.
 		let proof_without_header = proof.verify_relay_header::<ConsumerBlockHashStore>().map_err(|e| {
 			log::info!(target: LOG_TARGET, "Failed to verify DIP proof with error {:#?}", e);
@@ -234,7 +312,10 @@ impl<
 		}
 
 		let proof_without_parachain = proof_without_relaychain
-			.verify_dip_commitment_proof_for_subject::<KiltRuntime::Hashing, KiltRuntime>(subject)
+			.verify_dip_commitment_proof_for_subject_and_version::<KiltRuntime::Hashing, KiltRuntime>(
+				subject,
+				DIP_COMMITMENT_VERSION,
+			)
 			.map_err(DipRelaychainStateProofVerifierError::ProofVerification)?;
 		log::info!(
 			target: LOG_TARGET,
@@ -272,9 +353,12 @@ impl<
 			));
 		}
 
-		let proof_without_dip_merkle = proof_without_parachain
-			.verify_dip_proof::<KiltRuntime::Hashing, MAX_DID_MERKLE_LEAVES_REVEALED>()
-			.map_err(DipRelaychainStateProofVerifierError::ProofVerification)?;
+		let proof_without_dip_merkle = match salts {
+			Some(salts) => proof_without_parachain
+				.verify_salted_dip_proof::<KiltRuntime::Hashing, MAX_DID_MERKLE_LEAVES_REVEALED>(salts),
+			None => proof_without_parachain.verify_dip_proof::<KiltRuntime::Hashing, MAX_DID_MERKLE_LEAVES_REVEALED>(),
+		}
+		.map_err(DipRelaychainStateProofVerifierError::ProofVerification)?;
 		log::info!(
 			target: LOG_TARGET,
 			"Verified DID Merkle leaves: {:#?}",
@@ -287,7 +371,14 @@ impl<
 			frame_system::Pallet::<ConsumerRuntime>::block_hash(BlockNumberFor::<ConsumerRuntime>::zero());
 		let signed_extra = SignedExtra::get();
 		log::trace!(target: LOG_TARGET, "Additional components for signature verification: current block number = {:#?}, genesis hash = {:#?}, signed extra = {:#?}", current_block_number, consumer_genesis_hash, signed_extra);
-		let encoded_payload = (call, &identity_details, submitter, consumer_genesis_hash, signed_extra).encode();
+		let encoded_payload = (
+			call,
+			identity_details.as_ref().map(SignedIdentityDetails::signed_details),
+			submitter,
+			consumer_genesis_hash,
+			signed_extra,
+		)
+			.encode();
 		log::trace!(target: LOG_TARGET, "Encoded final payload: {:#?}", encoded_payload);
 
 		let revealed_did_info = proof_without_dip_merkle
@@ -0,0 +1,84 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use crate::Error;
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(test, derive(enum_iterator::Sequence))]
+pub enum DipSessionVerifierError<FullProofVerifierError, DidOriginError> {
+	SessionNotFound,
+	SessionExpired,
+	SessionCommitmentChanged,
+	FullProofVerification(FullProofVerifierError),
+	ProofVerification(Error),
+	DidOriginError(DidOriginError),
+}
+
+impl<FullProofVerifierError, DidOriginError> From<DipSessionVerifierError<FullProofVerifierError, DidOriginError>>
+	for u16
+where
+	FullProofVerifierError: Into<u16>,
+	DidOriginError: Into<u8>,
+{
+	fn from(value: DipSessionVerifierError<FullProofVerifierError, DidOriginError>) -> Self {
+		match value {
+			// DO NOT USE 0
+			// Errors of different sub-parts are separated by a `u8::MAX`.
+			// A value of 0 would make it confusing whether it's the previous sub-part error (u8::MAX)
+			// or the new sub-part error (u8::MAX + 0).
+			// Errors from the full proof verifier are returned as-is, and session-specific errors
+			// start after the ones used by the full proof verifiers.
+			DipSessionVerifierError::FullProofVerification(error) => error.into(),
+			DipSessionVerifierError::SessionNotFound => u8::MAX as u16 * 4 + 1,
+			DipSessionVerifierError::SessionExpired => u8::MAX as u16 * 4 + 2,
+			DipSessionVerifierError::SessionCommitmentChanged => u8::MAX as u16 * 4 + 3,
+			DipSessionVerifierError::ProofVerification(error) => u8::MAX as u16 * 5 + u8::from(error) as u16,
+			DipSessionVerifierError::DidOriginError(error) => u8::MAX as u16 * 6 + error.into() as u16,
+		}
+	}
+}
+
+#[test]
+fn dip_session_verifier_error_value_never_zero() {
+	assert!(
+		enum_iterator::all::<DipSessionVerifierError<crate::DipParachainStateProofVerifierError<u8>, u8>>()
+			.all(|e| u16::from(e) != 0),
+		"One of the u8 values for the error is 0, which is not allowed."
+	);
+}
+
+#[test]
+fn dip_session_verifier_error_value_not_duplicated() {
+	enum_iterator::all::<DipSessionVerifierError<crate::DipParachainStateProofVerifierError<u8>, u8>>().fold(
+		sp_std::collections::btree_set::BTreeSet::<u16>::new(),
+		|mut values, new_value| {
+			let new_encoded_value = u16::from(new_value);
+			// DidOriginError is generic, and we cannot test its constraints in this unit
+			// test, so we skip it.
+			if new_encoded_value == u8::MAX as u16 * 3 || new_encoded_value == u8::MAX as u16 * 6 {
+				return values;
+			}
+			assert!(
+				values.insert(new_encoded_value),
+				"Failed to add unique value {:#?} for error variant",
+				new_encoded_value
+			);
+			values
+		},
+	);
+}
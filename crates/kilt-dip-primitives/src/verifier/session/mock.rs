@@ -0,0 +1,293 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	construct_runtime,
+	traits::{ConstU16, ConstU32, ConstU64, Everything},
+};
+use frame_system::{mocking::MockBlock, pallet_prelude::BlockNumberFor, EnsureSigned};
+use idchain_parachain_testnet_runtime::Runtime as ParachainTestnetRuntime;
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use pallet_dip_consumer::{traits::IdentityProofVerifier, RuntimeCallOf};
+use pallet_dip_provider::{IdentityCommitmentOf, IdentityCommitmentVersion};
+use pallet_web3_names::Web3NameOf;
+use parity_scale_codec::Encode;
+use sp_core::{sr25519, Pair, H256};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	AccountId32,
+};
+use uid_core::{
+	did_details::{DidPublicKeyDetails, DidVerificationKey},
+	DidSignature, DidVerificationKeyRelationship, KeyIdOf,
+};
+
+use crate::{
+	traits::{DipCallOriginFilter, Incrementable},
+	DidKeyRelationship, DipCommitmentProofVerifier, DipOriginInfo, DipSessionIdentityDetails, DipSessionVerifier,
+	RevealedDidKey, TimeBoundDidSignature,
+};
+
+construct_runtime!(
+	pub struct TestRuntime {
+		System: frame_system,
+		DipConsumer: pallet_dip_consumer,
+	}
+);
+
+impl frame_system::Config for TestRuntime {
+	type AccountData = ();
+	type AccountId = AccountId32;
+	type BaseCallFilter = Everything;
+	type Block = MockBlock<Self>;
+	type BlockHashCount = ConstU64<10>;
+	type BlockLength = ();
+	type BlockWeights = ();
+	type DbWeight = ();
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type MaxConsumers = ConstU32<16>;
+	type Nonce = u64;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type PalletInfo = PalletInfo;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeTask = ();
+	type SS58Prefix = ConstU16<1>;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+pub(crate) const MAX_DID_MERKLE_LEAVES_REVEALED: u32 = 8;
+pub(crate) const SESSION_DURATION: u32 = 10;
+
+pub(crate) type KiltRevealedDidKey = RevealedDidKey<
+	KeyIdOf<ParachainTestnetRuntime>,
+	BlockNumberFor<ParachainTestnetRuntime>,
+	<ParachainTestnetRuntime as frame_system::Config>::AccountId,
+>;
+pub(crate) type KiltDipOriginInfo = DipOriginInfo<
+	KeyIdOf<ParachainTestnetRuntime>,
+	<ParachainTestnetRuntime as frame_system::Config>::AccountId,
+	BlockNumberFor<ParachainTestnetRuntime>,
+	Web3NameOf<ParachainTestnetRuntime>,
+	LinkableAccountId,
+	MAX_DID_MERKLE_LEAVES_REVEALED,
+>;
+pub(crate) type IdentityDetails = DipSessionIdentityDetails<
+	KeyIdOf<ParachainTestnetRuntime>,
+	<ParachainTestnetRuntime as frame_system::Config>::AccountId,
+	BlockNumberFor<ParachainTestnetRuntime>,
+	Web3NameOf<ParachainTestnetRuntime>,
+	LinkableAccountId,
+	IdentityCommitmentOf<ParachainTestnetRuntime>,
+	BlockNumberFor<TestRuntime>,
+	MAX_DID_MERKLE_LEAVES_REVEALED,
+>;
+
+pub(crate) fn authentication_key_pair() -> sr25519::Pair {
+	sr25519::Pair::from_seed(&[1u8; 32])
+}
+
+pub(crate) fn assertion_key_pair() -> sr25519::Pair {
+	sr25519::Pair::from_seed(&[2u8; 32])
+}
+
+fn revealed_key(pair: &sr25519::Pair, relationship: DidVerificationKeyRelationship) -> KiltRevealedDidKey {
+	RevealedDidKey {
+		id: H256::from(pair.public().0),
+		relationship: relationship.into(),
+		details: DidPublicKeyDetails {
+			key: DidVerificationKey::Sr25519(pair.public()).into(),
+			block_number: 1,
+		},
+	}
+}
+
+// The leaves revealed by a successful full proof, with the authentication key
+// as the signing one.
+pub(crate) fn full_proof_origin_info() -> KiltDipOriginInfo {
+	DipOriginInfo {
+		revealed_leaves: vec![
+			revealed_key(
+				&authentication_key_pair(),
+				DidVerificationKeyRelationship::Authentication,
+			)
+			.into(),
+			revealed_key(&assertion_key_pair(), DidVerificationKeyRelationship::AssertionMethod).into(),
+		]
+		.try_into()
+		.unwrap(),
+		signing_leaves_indices: vec![0].try_into().unwrap(),
+	}
+}
+
+// The DIP commitment full proofs are verified against.
+pub(crate) fn commitment() -> H256 {
+	H256::repeat_byte(1)
+}
+
+/// A full proof verifier that accepts any `true` proof, revealing the leaves
+/// returned by [`full_proof_origin_info`]. Its commitment proofs are the
+/// commitment itself, and any non-zero commitment is valid.
+pub struct BooleanFullProofVerifier;
+
+impl IdentityProofVerifier<TestRuntime> for BooleanFullProofVerifier {
+	type Error = u16;
+	type Proof = bool;
+	type VerificationResult = KiltDipOriginInfo;
+
+	fn verify_proof_for_call_against_details(
+		_call: &RuntimeCallOf<TestRuntime>,
+		_subject: &<TestRuntime as pallet_dip_consumer::Config>::Identifier,
+		_submitter: &<TestRuntime as frame_system::Config>::AccountId,
+		identity_details: &mut Option<<TestRuntime as pallet_dip_consumer::Config>::LocalIdentityInfo>,
+		proof: Self::Proof,
+	) -> Result<Self::VerificationResult, Self::Error> {
+		if !proof {
+			return Err(1);
+		}
+		if let Some(details) = identity_details {
+			details.increment();
+		} else {
+			*identity_details = Some(Default::default());
+		}
+		Ok(full_proof_origin_info())
+	}
+}
+
+impl DipCommitmentProofVerifier<TestRuntime> for BooleanFullProofVerifier {
+	type Commitment = H256;
+	type CommitmentProof = H256;
+
+	fn commitment_proof(_proof: &Self::Proof) -> (IdentityCommitmentVersion, Self::CommitmentProof) {
+		(0, commitment())
+	}
+
+	fn verify_commitment_proof(
+		_subject: &<TestRuntime as pallet_dip_consumer::Config>::Identifier,
+		_version: IdentityCommitmentVersion,
+		proof: Self::CommitmentProof,
+	) -> Result<Self::Commitment, Self::Error> {
+		if proof.is_zero() {
+			return Err(2);
+		}
+		Ok(proof)
+	}
+}
+
+/// Only allows calls authorized by a DID authentication key.
+pub struct OnlyAuthenticationKeys;
+
+impl DipCallOriginFilter<RuntimeCall> for OnlyAuthenticationKeys {
+	type Error = u8;
+	type OriginInfo = Vec<KiltRevealedDidKey>;
+	type Success = ();
+
+	fn check_call_origin_info(_call: &RuntimeCall, info: &Self::OriginInfo) -> Result<Self::Success, Self::Error> {
+		if info
+			.iter()
+			.all(|key| key.relationship == DidKeyRelationship::from(DidVerificationKeyRelationship::Authentication))
+		{
+			Ok(())
+		} else {
+			Err(1)
+		}
+	}
+}
+
+pub type Verifier = DipSessionVerifier<
+	BooleanFullProofVerifier,
+	ParachainTestnetRuntime,
+	OnlyAuthenticationKeys,
+	(),
+	SESSION_DURATION,
+	MAX_DID_MERKLE_LEAVES_REVEALED,
+>;
+
+impl pallet_dip_consumer::Config for TestRuntime {
+	type DipCallOriginFilter = Everything;
+	type DispatchOriginCheck = EnsureSigned<AccountId32>;
+	type Identifier = <ParachainTestnetRuntime as uid_core::Config>::DidIdentifier;
+	type LocalIdentityInfo = IdentityDetails;
+	type ProofVerifier = Verifier;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeOrigin = RuntimeOrigin;
+	type WeightInfo = ();
+}
+
+pub(crate) fn submitter() -> AccountId32 {
+	AccountId32::new([100u8; 32])
+}
+
+pub(crate) fn subject() -> <ParachainTestnetRuntime as uid_core::Config>::DidIdentifier {
+	AccountId32::new([200u8; 32]).into()
+}
+
+pub(crate) fn call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark {
+		remark: b"Hello, world!".to_vec(),
+	})
+}
+
+// Generates a DID signature over the same payload the verifier checks, which
+// only includes the nonce of the identity details.
+pub(crate) fn sign_call(
+	pair: &sr25519::Pair,
+	call: &RuntimeCall,
+	identity_details: &Option<IdentityDetails>,
+	valid_until: BlockNumberFor<TestRuntime>,
+) -> TimeBoundDidSignature<BlockNumberFor<TestRuntime>> {
+	let genesis_hash = frame_system::Pallet::<TestRuntime>::block_hash(0);
+	let payload = (
+		call,
+		identity_details.as_ref().map(|details| details.nonce()),
+		submitter(),
+		valid_until,
+		genesis_hash,
+		(),
+	)
+		.encode();
+	TimeBoundDidSignature::new(DidSignature::Sr25519(pair.sign(&payload)), valid_until)
+}
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder(Option<BlockNumberFor<TestRuntime>>);
+
+impl ExtBuilder {
+	pub(crate) fn with_block_number(mut self, block_number: BlockNumberFor<TestRuntime>) -> Self {
+		self.0 = Some(block_number);
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut ext = sp_io::TestExternalities::default();
+
+		ext.execute_with(|| {
+			if let Some(block_number) = self.0 {
+				System::set_block_number(block_number);
+			}
+		});
+
+		ext
+	}
+}
@@ -0,0 +1,624 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::Parameter;
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use pallet_dip_consumer::{traits::IdentityProofVerifier, RuntimeCallOf};
+use pallet_dip_provider::{IdentityCommitmentOf, IdentityCommitmentVersion};
+use pallet_web3_names::Web3NameOf;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::ConstU32;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	BoundedVec,
+};
+use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
+use uid_core::KeyIdOf;
+
+use crate::{
+	traits::{DipCallOriginFilter, GetWithoutArg, Incrementable, SignedIdentityDetails, SupportedCommitmentVersions},
+	DipOriginInfo, DipRevealedDetailsAndUnverifiedDidSignature, RevealedDidKey, RevealedDidMerkleProofLeaf,
+	TimeBoundDidSignature,
+};
+
+mod error;
+pub use error::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+const LOG_TARGET: &str = "dip::consumer::DipSessionVerifier";
+
+/// A DIP session opened upon successful verification of a full DIP proof.
+///
+/// The generic types indicate the following:
+/// * `KiltDidKeyId`: The DID key ID type configured by the KILT chain.
+/// * `KiltAccountId`: The `AccountId` type configured by the KILT chain.
+/// * `KiltBlockNumber`: The `BlockNumber` type configured by the KILT chain.
+/// * `KiltWeb3Name`: The web3name type configured by the KILT chain.
+/// * `KiltLinkableAccountId`: The linkable account ID type configured by the
+///   KILT chain.
+/// * `KiltCommitment`: The DIP identity commitment type configured by the
+///   KILT chain.
+/// * `ConsumerBlockNumber`: The `BlockNumber` definition of the consumer chain.
+/// * `MAX_REVEALED_LEAVES_COUNT`: The maximum number of leaves revealable in
+///   the proof that opened the session.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct DipSession<
+	KiltDidKeyId,
+	KiltAccountId,
+	KiltBlockNumber,
+	KiltWeb3Name,
+	KiltLinkableAccountId,
+	KiltCommitment,
+	ConsumerBlockNumber,
+	const MAX_REVEALED_LEAVES_COUNT: u32,
+> {
+	/// The parts of the subject's DID details revealed in the DIP proof that
+	/// opened the session.
+	pub(crate) revealed_leaves: BoundedVec<
+		RevealedDidMerkleProofLeaf<KiltDidKeyId, KiltAccountId, KiltBlockNumber, KiltWeb3Name, KiltLinkableAccountId>,
+		ConstU32<MAX_REVEALED_LEAVES_COUNT>,
+	>,
+	/// The version of the DIP commitment the session was opened against.
+	pub(crate) commitment_version: IdentityCommitmentVersion,
+	/// The DIP commitment the session was opened against.
+	pub(crate) commitment: KiltCommitment,
+	/// The last consumer block number at which the session can be used.
+	pub(crate) expires_at: ConsumerBlockNumber,
+}
+
+impl<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		KiltCommitment,
+		ConsumerBlockNumber,
+		const MAX_REVEALED_LEAVES_COUNT: u32,
+	>
+	DipSession<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		KiltCommitment,
+		ConsumerBlockNumber,
+		MAX_REVEALED_LEAVES_COUNT,
+	>
+{
+	/// Returns an iterator over the DID leaves revealed when opening the
+	/// session.
+	pub fn iter_leaves(
+		&self,
+	) -> impl Iterator<
+		Item = &RevealedDidMerkleProofLeaf<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+		>,
+	> {
+		self.revealed_leaves.iter()
+	}
+
+	/// Returns the version of the DIP commitment the session was opened
+	/// against.
+	pub fn commitment_version(&self) -> IdentityCommitmentVersion {
+		self.commitment_version
+	}
+
+	/// Returns the DIP commitment the session was opened against.
+	pub fn commitment(&self) -> &KiltCommitment {
+		&self.commitment
+	}
+
+	/// Returns the last consumer block number at which the session can be
+	/// used.
+	pub fn expires_at(&self) -> &ConsumerBlockNumber {
+		&self.expires_at
+	}
+}
+
+/// The local identity info to be used by consumers that support DIP sessions.
+///
+/// It contains a nonce, which is incremented upon each successful cross-chain
+/// operation and is the only part of these details included in the signed
+/// payload, and the currently open session, if any. Signing only the nonce
+/// keeps the payload the same one users sign for full DIP proofs.
+///
+/// For more info about the generic types, refer to [`DipSession`].
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct DipSessionIdentityDetails<
+	KiltDidKeyId,
+	KiltAccountId,
+	KiltBlockNumber,
+	KiltWeb3Name,
+	KiltLinkableAccountId,
+	KiltCommitment,
+	ConsumerBlockNumber,
+	const MAX_REVEALED_LEAVES_COUNT: u32,
+> {
+	/// The replay protection nonce.
+	pub(crate) nonce: u128,
+	/// The currently open session, if any.
+	pub(crate) session: Option<
+		DipSession<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			KiltCommitment,
+			ConsumerBlockNumber,
+			MAX_REVEALED_LEAVES_COUNT,
+		>,
+	>,
+}
+
+/// The [`DipSessionIdentityDetails`] for identities from the provided KILT
+/// runtime.
+pub type DipSessionIdentityDetailsOf<KiltRuntime, ConsumerBlockNumber, const MAX_REVEALED_LEAVES_COUNT: u32> =
+	DipSessionIdentityDetails<
+		KeyIdOf<KiltRuntime>,
+		<KiltRuntime as frame_system::Config>::AccountId,
+		BlockNumberFor<KiltRuntime>,
+		Web3NameOf<KiltRuntime>,
+		LinkableAccountId,
+		IdentityCommitmentOf<KiltRuntime>,
+		ConsumerBlockNumber,
+		MAX_REVEALED_LEAVES_COUNT,
+	>;
+
+impl<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		KiltCommitment,
+		ConsumerBlockNumber,
+		const MAX_REVEALED_LEAVES_COUNT: u32,
+	>
+	DipSessionIdentityDetails<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		KiltCommitment,
+		ConsumerBlockNumber,
+		MAX_REVEALED_LEAVES_COUNT,
+	>
+{
+	pub fn nonce(&self) -> u128 {
+		self.nonce
+	}
+
+	#[allow(clippy::type_complexity)]
+	pub fn session(
+		&self,
+	) -> Option<
+		&DipSession<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			KiltCommitment,
+			ConsumerBlockNumber,
+			MAX_REVEALED_LEAVES_COUNT,
+		>,
+	> {
+		self.session.as_ref()
+	}
+}
+
+impl<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		KiltCommitment,
+		ConsumerBlockNumber,
+		const MAX_REVEALED_LEAVES_COUNT: u32,
+	> Default
+	for DipSessionIdentityDetails<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		KiltCommitment,
+		ConsumerBlockNumber,
+		MAX_REVEALED_LEAVES_COUNT,
+	>
+{
+	fn default() -> Self {
+		Self {
+			nonce: Default::default(),
+			session: None,
+		}
+	}
+}
+
+impl<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		KiltCommitment,
+		ConsumerBlockNumber,
+		const MAX_REVEALED_LEAVES_COUNT: u32,
+	> Incrementable
+	for DipSessionIdentityDetails<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		KiltCommitment,
+		ConsumerBlockNumber,
+		MAX_REVEALED_LEAVES_COUNT,
+	>
+{
+	fn increment(&mut self) {
+		self.nonce.increment();
+	}
+}
+
+impl<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		KiltCommitment,
+		ConsumerBlockNumber,
+		const MAX_REVEALED_LEAVES_COUNT: u32,
+	> SignedIdentityDetails
+	for DipSessionIdentityDetails<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		KiltCommitment,
+		ConsumerBlockNumber,
+		MAX_REVEALED_LEAVES_COUNT,
+	>
+{
+	type Signed = u128;
+
+	fn signed_details(&self) -> Self::Signed {
+		self.nonce
+	}
+}
+
+/// A DIP proof for consumers that support DIP sessions.
+///
+/// The generic types indicate the following:
+/// * `FullProof`: The type of the full DIP proof, e.g.,
+///   [`crate::VersionedDipParachainStateProof`].
+/// * `CommitmentProof`: The type of the proof for the subject's DIP commitment
+///   only, e.g., [`crate::ParachainDipCommitmentProof`].
+/// * `ConsumerBlockNumber`: The `BlockNumber` definition of the consumer chain.
+#[derive(Encode, Decode, PartialEq, Eq, Debug, TypeInfo, Clone)]
+pub enum DipSessionProof<FullProof, CommitmentProof, ConsumerBlockNumber> {
+	/// A full DIP proof, which, if valid, opens a new session with the revealed
+	/// DID leaves, replacing any existing one.
+	Open(FullProof),
+	/// A proof that the subject's DIP commitment on the provider chain is
+	/// still the one the currently open session was created against, and a
+	/// DID signature generated by one of the keys revealed when the session
+	/// was created.
+	Continue {
+		commitment_proof: CommitmentProof,
+		signature: TimeBoundDidSignature<ConsumerBlockNumber>,
+	},
+}
+
+/// A full DIP proof verifier that can also verify, on its own, the part of a
+/// full proof attesting the subject's DIP commitment on the provider chain.
+///
+/// It is used by the [`DipSessionVerifier`] to bind sessions to the DIP
+/// commitment they were opened against.
+pub trait DipCommitmentProofVerifier<ConsumerRuntime>: IdentityProofVerifier<ConsumerRuntime>
+where
+	ConsumerRuntime: pallet_dip_consumer::Config,
+{
+	/// The proof for the subject's DIP commitment only.
+	type CommitmentProof: Parameter;
+	/// The DIP commitment type revealed by a valid commitment proof.
+	type Commitment: PartialEq + Debug;
+
+	/// Returns the commitment version a full proof is verified against, and
+	/// the commitment proof it contains.
+	fn commitment_proof(proof: &Self::Proof) -> (IdentityCommitmentVersion, Self::CommitmentProof);
+
+	/// Verifies the provided commitment proof for the given subject and
+	/// commitment version, and returns the revealed DIP commitment.
+	fn verify_commitment_proof(
+		subject: &ConsumerRuntime::Identifier,
+		version: IdentityCommitmentVersion,
+		proof: Self::CommitmentProof,
+	) -> Result<Self::Commitment, Self::Error>;
+}
+
+/// Proof verifier that wraps a full DIP proof verifier to support sessions.
+///
+/// Verifying a full DIP proof requires verifying a storage proof for the
+/// provider state, a storage proof for the subject's DIP commitment, and a
+/// DID Merkle proof. To avoid paying for the DID Merkle proof on each
+/// cross-chain operation, a successfully verified [`DipSessionProof::Open`]
+/// proof stores the revealed DID leaves in the subject's
+/// [`DipSessionIdentityDetails`], along with the DIP commitment the proof was
+/// verified against and an expiration block. Until then, a
+/// [`DipSessionProof::Continue`] proof only needs a proof for the subject's
+/// DIP commitment and a [`TimeBoundDidSignature`] generated by one of the
+/// stored keys. The payload to sign is the same one as for full proofs, which
+/// includes the subject's nonce for replay protection.
+///
+/// A session ends when it expires, when a new full proof is verified for the
+/// subject, or as soon as the subject's DIP commitment on the provider chain
+/// changes, e.g., after a key is revoked or rotated, since a
+/// [`DipSessionProof::Continue`] proof for a different commitment is rejected.
+/// A commitment proof can still be generated for any provider block the
+/// consumer has a state root for, hence how long the consumer keeps those
+/// state roots bounds the time during which a change on the provider chain is
+/// not reflected on the consumer chain.
+///
+/// The generic types indicate the following:
+/// * `FullProofVerifier`: The verifier for full DIP proofs, e.g.,
+///   [`crate::KiltVersionedParachainVerifier`].
+/// * `KiltRuntime`: A KILT runtime definition.
+/// * `DidCallVerifier`: Logic to map `RuntimeCall`s to a specific DID key
+///   relationship. It should be the same used by the `FullProofVerifier`.
+/// * `SignedExtra`: Any additional information that must be signed by the DID
+///   subject in the cross-chain operation. It should be the same used by the
+///   `FullProofVerifier`.
+/// * `SESSION_DURATION`: The number of consumer blocks a session is valid for
+///   after being opened.
+/// * `MAX_DID_MERKLE_LEAVES_REVEALED`: The maximum number of leaves that can be
+///   revealed as part of the DID Merkle proof, and hence stored in a session.
+pub struct DipSessionVerifier<
+	FullProofVerifier,
+	KiltRuntime,
+	DidCallVerifier,
+	SignedExtra,
+	const SESSION_DURATION: u32,
+	const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+>(PhantomData<(FullProofVerifier, KiltRuntime, DidCallVerifier, SignedExtra)>);
+
+/// Sessions can only be opened with proofs the wrapped full proof verifier
+/// understands.
+impl<
+		FullProofVerifier,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		const SESSION_DURATION: u32,
+		const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+	> SupportedCommitmentVersions
+	for DipSessionVerifier<
+		FullProofVerifier,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		SESSION_DURATION,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+	> where
+	FullProofVerifier: SupportedCommitmentVersions,
+{
+	const SUPPORTED_VERSIONS: &'static [IdentityCommitmentVersion] = FullProofVerifier::SUPPORTED_VERSIONS;
+}
+
+impl<
+		ConsumerRuntime,
+		FullProofVerifier,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		const SESSION_DURATION: u32,
+		const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+	> IdentityProofVerifier<ConsumerRuntime>
+	for DipSessionVerifier<
+		FullProofVerifier,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		SESSION_DURATION,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+	> where
+	ConsumerRuntime: pallet_dip_consumer::Config<
+		LocalIdentityInfo = DipSessionIdentityDetails<
+			KeyIdOf<KiltRuntime>,
+			KiltRuntime::AccountId,
+			BlockNumberFor<KiltRuntime>,
+			Web3NameOf<KiltRuntime>,
+			LinkableAccountId,
+			IdentityCommitmentOf<KiltRuntime>,
+			BlockNumberFor<ConsumerRuntime>,
+			MAX_DID_MERKLE_LEAVES_REVEALED,
+		>,
+	>,
+	FullProofVerifier: DipCommitmentProofVerifier<ConsumerRuntime, Commitment = IdentityCommitmentOf<KiltRuntime>>,
+	FullProofVerifier: IdentityProofVerifier<
+		ConsumerRuntime,
+		VerificationResult = DipOriginInfo<
+			KeyIdOf<KiltRuntime>,
+			KiltRuntime::AccountId,
+			BlockNumberFor<KiltRuntime>,
+			Web3NameOf<KiltRuntime>,
+			LinkableAccountId,
+			MAX_DID_MERKLE_LEAVES_REVEALED,
+		>,
+	>,
+	KiltRuntime: frame_system::Config
+		+ pallet_dip_provider::Config
+		+ uid_core::Config
+		+ pallet_web3_names::Config
+		+ pallet_did_lookup::Config,
+	SignedExtra: GetWithoutArg,
+	SignedExtra::Result: Encode + Debug,
+	DidCallVerifier: DipCallOriginFilter<
+		RuntimeCallOf<ConsumerRuntime>,
+		OriginInfo = Vec<RevealedDidKey<KeyIdOf<KiltRuntime>, BlockNumberFor<KiltRuntime>, KiltRuntime::AccountId>>,
+	>,
+	DidCallVerifier::Error: Into<u8> + Debug,
+{
+	type Error = DipSessionVerifierError<FullProofVerifier::Error, DidCallVerifier::Error>;
+	type Proof =
+		DipSessionProof<FullProofVerifier::Proof, FullProofVerifier::CommitmentProof, BlockNumberFor<ConsumerRuntime>>;
+	type VerificationResult = FullProofVerifier::VerificationResult;
+
+	fn verify_proof_for_call_against_details(
+		call: &RuntimeCallOf<ConsumerRuntime>,
+		subject: &ConsumerRuntime::Identifier,
+		submitter: &ConsumerRuntime::AccountId,
+		identity_details: &mut Option<ConsumerRuntime::LocalIdentityInfo>,
+		proof: Self::Proof,
+	) -> Result<Self::VerificationResult, Self::Error> {
+		let current_block_number = frame_system::Pallet::<ConsumerRuntime>::block_number();
+
+		let (commitment_proof, signature) = match proof {
+			DipSessionProof::Open(full_proof) => {
+				let (commitment_version, commitment_proof) = FullProofVerifier::commitment_proof(&full_proof);
+				let revealed_did_info = FullProofVerifier::verify_proof_for_call_against_details(
+					call,
+					subject,
+					submitter,
+					identity_details,
+					full_proof,
+				)
+				.map_err(DipSessionVerifierError::FullProofVerification)?;
+				let commitment =
+					FullProofVerifier::verify_commitment_proof(subject, commitment_version, commitment_proof)
+						.map_err(DipSessionVerifierError::FullProofVerification)?;
+
+				let session = DipSession {
+					revealed_leaves: revealed_did_info.revealed_leaves.clone(),
+					commitment_version,
+					commitment,
+					expires_at: current_block_number.saturating_add(SESSION_DURATION.into()),
+				};
+				log::trace!(
+					target: LOG_TARGET,
+					"Opening new session for subject {:#?}: {:#?}",
+					subject,
+					session
+				);
+				identity_details.get_or_insert_with(Default::default).session = Some(session);
+
+				return Ok(revealed_did_info);
+			}
+			DipSessionProof::Continue {
+				commitment_proof,
+				signature,
+			} => (commitment_proof, signature),
+		};
+
+		// 1. Verify there is a session that has not expired yet.
+		let session = identity_details
+			.as_ref()
+			.and_then(|details| details.session.as_ref())
+			.ok_or_else(|| {
+				log::info!(target: LOG_TARGET, "No session found for subject {:#?}", subject);
+				DipSessionVerifierError::SessionNotFound
+			})?;
+		if session.expires_at < current_block_number {
+			log::info!(
+				target: LOG_TARGET,
+				"Session for subject {:#?} expired at block {:#?}",
+				subject,
+				session.expires_at
+			);
+			return Err(DipSessionVerifierError::SessionExpired);
+		}
+
+		// 2. Verify the subject's DIP commitment has not changed since the session was
+		//    opened.
+		let commitment =
+			FullProofVerifier::verify_commitment_proof(subject, session.commitment_version, commitment_proof)
+				.map_err(DipSessionVerifierError::FullProofVerification)?;
+		if commitment != session.commitment {
+			log::info!(
+				target: LOG_TARGET,
+				"DIP commitment for subject {:#?} changed from {:#?} to {:#?}",
+				subject,
+				session.commitment,
+				commitment
+			);
+			return Err(DipSessionVerifierError::SessionCommitmentChanged);
+		}
+
+		// 3. Verify call is signed by one of the DID keys stored in the session.
+		let consumer_genesis_hash =
+			frame_system::Pallet::<ConsumerRuntime>::block_hash(BlockNumberFor::<ConsumerRuntime>::zero());
+		let signed_extra = SignedExtra::get();
+		log::trace!(target: LOG_TARGET, "Additional components for signature verification: current block number = {:#?}, genesis hash = {:#?}, signed extra = {:#?}", current_block_number, consumer_genesis_hash, signed_extra);
+		let encoded_payload = (
+			call,
+			identity_details.as_ref().map(SignedIdentityDetails::signed_details),
+			submitter,
+			&signature.valid_until,
+			consumer_genesis_hash,
+			signed_extra,
+		)
+			.encode();
+		log::trace!(target: LOG_TARGET, "Encoded final payload: {:#?}", encoded_payload);
+
+		let revealed_did_info =
+			DipRevealedDetailsAndUnverifiedDidSignature::<_, _, _, _, _, _, MAX_DID_MERKLE_LEAVES_REVEALED> {
+				revealed_leaves: session.revealed_leaves.clone(),
+				signature,
+			}
+			.verify_signature_time(&current_block_number)
+			.and_then(|p| p.retrieve_signing_leaves_for_payload(&encoded_payload[..]))
+			.map_err(|e| {
+				log::info!(target: LOG_TARGET, "Failed to verify DIP session proof with error {:#?}", e);
+				DipSessionVerifierError::ProofVerification(e)
+			})?;
+
+		// 4. Verify the signing key fulfills the requirements
+		let signing_keys = revealed_did_info.get_signing_leaves().map_err(|e| {
+			log::info!(target: LOG_TARGET, "Failed to verify DIP session proof with error {:#?}", e);
+			DipSessionVerifierError::ProofVerification(e)
+		})?;
+		DidCallVerifier::check_call_origin_info(call, &signing_keys.cloned().collect::<Vec<_>>()).map_err(|e| {
+			log::info!(target: LOG_TARGET, "Failed to verify DIP session proof with error {:#?}", e);
+			DipSessionVerifierError::DidOriginError(e)
+		})?;
+
+		// 5. Increment the local details
+		if let Some(details) = identity_details {
+			details.increment();
+		}
+
+		Ok(revealed_did_info)
+	}
+}
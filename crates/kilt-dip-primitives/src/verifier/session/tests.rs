@@ -0,0 +1,387 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_err, assert_ok};
+use pallet_dip_consumer::traits::IdentityProofVerifier;
+use parity_scale_codec::Encode;
+use sp_core::{sr25519, Pair, H256};
+use uid_core::DidSignature;
+
+use crate::{
+	session::mock::{
+		assertion_key_pair, authentication_key_pair, call, commitment, full_proof_origin_info, sign_call, subject,
+		submitter, ExtBuilder, IdentityDetails, System, TestRuntime, Verifier, SESSION_DURATION,
+	},
+	DipSession, DipSessionProof, DipSessionVerifierError, Error, TimeBoundDidSignature,
+};
+
+fn open_session(details: &mut Option<IdentityDetails>) {
+	assert_ok!(
+		<Verifier as IdentityProofVerifier<TestRuntime>>::verify_proof_for_call_against_details(
+			&call(),
+			&subject(),
+			&submitter(),
+			details,
+			DipSessionProof::Open(true),
+		)
+	);
+}
+
+#[test]
+fn verify_proof_for_call_against_details_open_session_successful() {
+	let mut details: Option<IdentityDetails> = None;
+
+	ExtBuilder::default().with_block_number(5).build().execute_with(|| {
+		let origin_info = <Verifier as IdentityProofVerifier<TestRuntime>>::verify_proof_for_call_against_details(
+			&call(),
+			&subject(),
+			&submitter(),
+			&mut details,
+			DipSessionProof::Open(true),
+		)
+		.unwrap();
+		assert_eq!(origin_info, full_proof_origin_info());
+		// If details are none, they are inizialited with their default value, and the
+		// revealed leaves are stored in a new session.
+		assert_eq!(
+			details,
+			Some(IdentityDetails {
+				nonce: 0,
+				session: Some(DipSession {
+					revealed_leaves: full_proof_origin_info().revealed_leaves,
+					commitment_version: 0,
+					commitment: commitment(),
+					expires_at: 5 + SESSION_DURATION as u64,
+				}),
+			})
+		);
+	})
+}
+
+#[test]
+fn verify_proof_for_call_against_details_open_session_replaces_existing_one() {
+	let mut details = Some(IdentityDetails {
+		nonce: 10,
+		session: Some(DipSession {
+			revealed_leaves: Default::default(),
+			commitment_version: 0,
+			commitment: H256::repeat_byte(2),
+			expires_at: 2,
+		}),
+	});
+
+	ExtBuilder::default().with_block_number(5).build().execute_with(|| {
+		open_session(&mut details);
+		assert_eq!(
+			details,
+			Some(IdentityDetails {
+				nonce: 11,
+				session: Some(DipSession {
+					revealed_leaves: full_proof_origin_info().revealed_leaves,
+					commitment_version: 0,
+					commitment: commitment(),
+					expires_at: 5 + SESSION_DURATION as u64,
+				}),
+			})
+		);
+	})
+}
+
+#[test]
+fn verify_proof_for_call_against_details_open_session_invalid_full_proof() {
+	let mut details: Option<IdentityDetails> = None;
+
+	ExtBuilder::default().with_block_number(5).build().execute_with(|| {
+		assert_err!(
+			<Verifier as IdentityProofVerifier<TestRuntime>>::verify_proof_for_call_against_details(
+				&call(),
+				&subject(),
+				&submitter(),
+				&mut details,
+				DipSessionProof::Open(false),
+			),
+			DipSessionVerifierError::FullProofVerification(1)
+		);
+		assert!(details.is_none());
+	})
+}
+
+#[test]
+fn verify_proof_for_call_against_details_continue_session_successful() {
+	let mut details: Option<IdentityDetails> = None;
+
+	ExtBuilder::default().with_block_number(5).build().execute_with(|| {
+		open_session(&mut details);
+		System::set_block_number(5 + SESSION_DURATION as u64);
+
+		let signature = sign_call(&authentication_key_pair(), &call(), &details, 20);
+		let origin_info = <Verifier as IdentityProofVerifier<TestRuntime>>::verify_proof_for_call_against_details(
+			&call(),
+			&subject(),
+			&submitter(),
+			&mut details,
+			DipSessionProof::Continue {
+				commitment_proof: commitment(),
+				signature,
+			},
+		)
+		.unwrap();
+		// The same leaves are returned, with the authentication key as the signing one.
+		assert_eq!(origin_info, full_proof_origin_info());
+		// The nonce is incremented, and the session is left untouched.
+		assert_eq!(details.as_ref().map(|d| d.nonce()), Some(1));
+		assert_eq!(
+			details.and_then(|d| d.session).map(|s| s.expires_at),
+			Some(5 + SESSION_DURATION as u64)
+		);
+	})
+}
+
+#[test]
+fn verify_proof_for_call_against_details_continue_session_not_found() {
+	let mut details = Some(IdentityDetails::default());
+
+	ExtBuilder::default().with_block_number(5).build().execute_with(|| {
+		let signature = sign_call(&authentication_key_pair(), &call(), &details, 20);
+		assert_err!(
+			<Verifier as IdentityProofVerifier<TestRuntime>>::verify_proof_for_call_against_details(
+				&call(),
+				&subject(),
+				&submitter(),
+				&mut details,
+				DipSessionProof::Continue {
+					commitment_proof: commitment(),
+					signature,
+				},
+			),
+			DipSessionVerifierError::SessionNotFound
+		);
+	})
+}
+
+#[test]
+fn verify_proof_for_call_against_details_continue_session_expired() {
+	let mut details: Option<IdentityDetails> = None;
+
+	ExtBuilder::default().with_block_number(5).build().execute_with(|| {
+		open_session(&mut details);
+		System::set_block_number(5 + SESSION_DURATION as u64 + 1);
+
+		let signature = sign_call(&authentication_key_pair(), &call(), &details, 100);
+		assert_err!(
+			<Verifier as IdentityProofVerifier<TestRuntime>>::verify_proof_for_call_against_details(
+				&call(),
+				&subject(),
+				&submitter(),
+				&mut details,
+				DipSessionProof::Continue {
+					commitment_proof: commitment(),
+					signature,
+				},
+			),
+			DipSessionVerifierError::SessionExpired
+		);
+	})
+}
+
+#[test]
+fn verify_proof_for_call_against_details_continue_session_signature_not_fresh() {
+	let mut details: Option<IdentityDetails> = None;
+
+	ExtBuilder::default().with_block_number(5).build().execute_with(|| {
+		open_session(&mut details);
+
+		let signature = sign_call(&authentication_key_pair(), &call(), &details, 4);
+		assert_err!(
+			<Verifier as IdentityProofVerifier<TestRuntime>>::verify_proof_for_call_against_details(
+				&call(),
+				&subject(),
+				&submitter(),
+				&mut details,
+				DipSessionProof::Continue {
+					commitment_proof: commitment(),
+					signature,
+				},
+			),
+			DipSessionVerifierError::ProofVerification(Error::InvalidSignatureTime)
+		);
+	})
+}
+
+#[test]
+fn verify_proof_for_call_against_details_continue_session_replayed_signature() {
+	let mut details: Option<IdentityDetails> = None;
+
+	ExtBuilder::default().with_block_number(5).build().execute_with(|| {
+		open_session(&mut details);
+
+		let signature = sign_call(&authentication_key_pair(), &call(), &details, 20);
+		assert_ok!(
+			<Verifier as IdentityProofVerifier<TestRuntime>>::verify_proof_for_call_against_details(
+				&call(),
+				&subject(),
+				&submitter(),
+				&mut details,
+				DipSessionProof::Continue {
+					commitment_proof: commitment(),
+					signature: signature.clone(),
+				},
+			)
+		);
+		// The nonce has been incremented, so the same signature is not valid anymore.
+		assert_err!(
+			<Verifier as IdentityProofVerifier<TestRuntime>>::verify_proof_for_call_against_details(
+				&call(),
+				&subject(),
+				&submitter(),
+				&mut details,
+				DipSessionProof::Continue {
+					commitment_proof: commitment(),
+					signature,
+				},
+			),
+			DipSessionVerifierError::ProofVerification(Error::InvalidDidKeyRevealed)
+		);
+	})
+}
+
+#[test]
+fn verify_proof_for_call_against_details_continue_session_key_not_in_session() {
+	let mut details: Option<IdentityDetails> = None;
+
+	ExtBuilder::default().with_block_number(5).build().execute_with(|| {
+		open_session(&mut details);
+
+		let signature = sign_call(&sr25519::Pair::from_seed(&[3u8; 32]), &call(), &details, 20);
+		assert_err!(
+			<Verifier as IdentityProofVerifier<TestRuntime>>::verify_proof_for_call_against_details(
+				&call(),
+				&subject(),
+				&submitter(),
+				&mut details,
+				DipSessionProof::Continue {
+					commitment_proof: commitment(),
+					signature,
+				},
+			),
+			DipSessionVerifierError::ProofVerification(Error::InvalidDidKeyRevealed)
+		);
+	})
+}
+
+#[test]
+fn verify_proof_for_call_against_details_continue_session_wrong_key_relationship() {
+	let mut details: Option<IdentityDetails> = None;
+
+	ExtBuilder::default().with_block_number(5).build().execute_with(|| {
+		open_session(&mut details);
+
+		let signature = sign_call(&assertion_key_pair(), &call(), &details, 20);
+		assert_err!(
+			<Verifier as IdentityProofVerifier<TestRuntime>>::verify_proof_for_call_against_details(
+				&call(),
+				&subject(),
+				&submitter(),
+				&mut details,
+				DipSessionProof::Continue {
+					commitment_proof: commitment(),
+					signature,
+				},
+			),
+			DipSessionVerifierError::DidOriginError(1)
+		);
+	})
+}
+
+#[test]
+fn verify_proof_for_call_against_details_continue_session_commitment_changed() {
+	let mut details: Option<IdentityDetails> = None;
+
+	ExtBuilder::default().with_block_number(5).build().execute_with(|| {
+		open_session(&mut details);
+
+		let signature = sign_call(&authentication_key_pair(), &call(), &details, 20);
+		assert_err!(
+			<Verifier as IdentityProofVerifier<TestRuntime>>::verify_proof_for_call_against_details(
+				&call(),
+				&subject(),
+				&submitter(),
+				&mut details,
+				DipSessionProof::Continue {
+					commitment_proof: H256::repeat_byte(2),
+					signature,
+				},
+			),
+			DipSessionVerifierError::SessionCommitmentChanged
+		);
+		// The nonce is not incremented.
+		assert_eq!(details.as_ref().map(|d| d.nonce()), Some(0));
+	})
+}
+
+#[test]
+fn verify_proof_for_call_against_details_continue_session_invalid_commitment_proof() {
+	let mut details: Option<IdentityDetails> = None;
+
+	ExtBuilder::default().with_block_number(5).build().execute_with(|| {
+		open_session(&mut details);
+
+		let signature = sign_call(&authentication_key_pair(), &call(), &details, 20);
+		assert_err!(
+			<Verifier as IdentityProofVerifier<TestRuntime>>::verify_proof_for_call_against_details(
+				&call(),
+				&subject(),
+				&submitter(),
+				&mut details,
+				DipSessionProof::Continue {
+					commitment_proof: H256::zero(),
+					signature,
+				},
+			),
+			DipSessionVerifierError::FullProofVerification(2)
+		);
+	})
+}
+
+#[test]
+fn verify_proof_for_call_against_details_continue_session_signature_over_session() {
+	let mut details: Option<IdentityDetails> = None;
+
+	ExtBuilder::default().with_block_number(5).build().execute_with(|| {
+		open_session(&mut details);
+
+		// A signature over the whole identity details, including the session, is not
+		// accepted, since only the nonce is part of the signed payload.
+		let genesis_hash = System::block_hash(0);
+		let payload = (call(), &details, submitter(), 20u64, genesis_hash, ()).encode();
+		let signature = TimeBoundDidSignature::new(DidSignature::Sr25519(authentication_key_pair().sign(&payload)), 20);
+		assert_err!(
+			<Verifier as IdentityProofVerifier<TestRuntime>>::verify_proof_for_call_against_details(
+				&call(),
+				&subject(),
+				&submitter(),
+				&mut details,
+				DipSessionProof::Continue {
+					commitment_proof: commitment(),
+					signature,
+				},
+			),
+			DipSessionVerifierError::ProofVerification(Error::InvalidDidKeyRevealed)
+		);
+	})
+}
@@ -0,0 +1,301 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use uid_core::KeyIdOf;
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use pallet_dip_consumer::{traits::IdentityProofVerifier, RuntimeCallOf};
+use pallet_dip_provider::{traits::IdentityCommitmentGenerator, IdentityCommitmentVersion};
+use pallet_web3_names::Web3NameOf;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
+
+use crate::{
+	merkle_proofs::v0::RevealedDidKey,
+	traits::{
+		DipCallOriginFilter, GetWithArg, GetWithoutArg, Incrementable, SignedIdentityDetails,
+		SupportedCommitmentVersions,
+	},
+	utils::OutputOf,
+	DipOriginInfo, SaltedProof, StandaloneDipDidProof,
+};
+
+pub mod v0;
+
+mod error;
+pub use error::*;
+
+/// A KILT-specific DIP identity proof for a consumer of a standalone provider
+/// chain that supports versioning.
+///
+/// For more info, refer to the version-specific proofs.
+#[derive(Encode, Decode, PartialEq, Eq, Debug, TypeInfo, Clone)]
+pub enum VersionedDipStandaloneStateProof<
+	KiltDidKeyId,
+	KiltAccountId,
+	KiltBlockNumber,
+	KiltWeb3Name,
+	KiltLinkableAccountId,
+	ConsumerBlockNumber,
+> {
+	V0(
+		StandaloneDipDidProof<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			ConsumerBlockNumber,
+		>,
+	),
+	/// Same format as the `V0` proof, verified against the version `1` DIP
+	/// commitment, which also reveals public credentials and verifications.
+	V1(
+		StandaloneDipDidProof<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			ConsumerBlockNumber,
+		>,
+	),
+	/// Same format as the `V1` proof, wrapped in a [`SaltedProof`] together
+	/// with the salts of the revealed leaves, and verified against the salted
+	/// version `2` DIP commitment.
+	V2(
+		SaltedProof<
+			StandaloneDipDidProof<
+				KiltDidKeyId,
+				KiltAccountId,
+				KiltBlockNumber,
+				KiltWeb3Name,
+				KiltLinkableAccountId,
+				ConsumerBlockNumber,
+			>,
+		>,
+	),
+}
+
+impl<KiltDidKeyId, KiltAccountId, KiltBlockNumber, KiltWeb3Name, KiltLinkableAccountId, ConsumerBlockNumber>
+	From<
+		StandaloneDipDidProof<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			ConsumerBlockNumber,
+		>,
+	>
+	for VersionedDipStandaloneStateProof<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		KiltLinkableAccountId,
+		ConsumerBlockNumber,
+	>
+{
+	fn from(
+		value: StandaloneDipDidProof<
+			KiltDidKeyId,
+			KiltAccountId,
+			KiltBlockNumber,
+			KiltWeb3Name,
+			KiltLinkableAccountId,
+			ConsumerBlockNumber,
+		>,
+	) -> Self {
+		Self::V0(value)
+	}
+}
+
+pub const DEFAULT_MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32 = 128;
+pub const DEFAULT_MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE: u32 = 1024;
+pub const DEFAULT_MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32 = 128;
+pub const DEFAULT_MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32 = 1024;
+pub const DEFAULT_MAX_DID_MERKLE_LEAVES_REVEALED: u32 = 128;
+
+/// Versioned proof verifier. For version-specific description, refer to each
+/// verifier's documentation.
+pub struct KiltVersionedStandaloneVerifier<
+	ProviderStateRootStore,
+	KiltRuntime,
+	DidCallVerifier,
+	SignedExtra = (),
+	const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32 = DEFAULT_MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+	const MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE: u32 = DEFAULT_MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+	const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32 = DEFAULT_MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+	const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32 = DEFAULT_MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+	const MAX_DID_MERKLE_LEAVES_REVEALED: u32 = DEFAULT_MAX_DID_MERKLE_LEAVES_REVEALED,
+>(PhantomData<(ProviderStateRootStore, KiltRuntime, DidCallVerifier, SignedExtra)>);
+
+impl<
+		ProviderStateRootStore,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+	> SupportedCommitmentVersions
+	for KiltVersionedStandaloneVerifier<
+		ProviderStateRootStore,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+		MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+	>
+{
+	const SUPPORTED_VERSIONS: &'static [IdentityCommitmentVersion] = &[0, 1, 2];
+}
+
+impl<
+		ConsumerRuntime,
+		ProviderStateRootStore,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+	> IdentityProofVerifier<ConsumerRuntime>
+	for KiltVersionedStandaloneVerifier<
+		ProviderStateRootStore,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+		MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+	> where
+	ConsumerRuntime: pallet_dip_consumer::Config<Identifier = KiltRuntime::Identifier>,
+	ConsumerRuntime::LocalIdentityInfo: Incrementable + SignedIdentityDetails + Default,
+	ProviderStateRootStore: GetWithArg<BlockNumberFor<KiltRuntime>, Result = Option<OutputOf<KiltRuntime::Hashing>>>,
+	KiltRuntime: frame_system::Config
+		+ pallet_dip_provider::Config
+		+ uid_core::Config
+		+ pallet_web3_names::Config
+		+ pallet_did_lookup::Config,
+	KiltRuntime::IdentityCommitmentGenerator: IdentityCommitmentGenerator<KiltRuntime, Output = KiltRuntime::Hash>,
+	SignedExtra: GetWithoutArg,
+	SignedExtra::Result: Encode + Debug,
+	DidCallVerifier: DipCallOriginFilter<
+		RuntimeCallOf<ConsumerRuntime>,
+		OriginInfo = Vec<RevealedDidKey<KeyIdOf<KiltRuntime>, BlockNumberFor<KiltRuntime>, KiltRuntime::AccountId>>,
+	>,
+	DidCallVerifier::Error: Into<u8> + Debug,
+{
+	type Error = DipStandaloneStateProofVerifierError<DidCallVerifier::Error>;
+	type Proof = VersionedDipStandaloneStateProof<
+		KeyIdOf<KiltRuntime>,
+		KiltRuntime::AccountId,
+		BlockNumberFor<KiltRuntime>,
+		Web3NameOf<KiltRuntime>,
+		LinkableAccountId,
+		BlockNumberFor<ConsumerRuntime>,
+	>;
+	type VerificationResult = DipOriginInfo<
+		KeyIdOf<KiltRuntime>,
+		KiltRuntime::AccountId,
+		BlockNumberFor<KiltRuntime>,
+		Web3NameOf<KiltRuntime>,
+		LinkableAccountId,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+	>;
+
+	fn verify_proof_for_call_against_details(
+		call: &RuntimeCallOf<ConsumerRuntime>,
+		subject: &ConsumerRuntime::Identifier,
+		submitter: &ConsumerRuntime::AccountId,
+		identity_details: &mut Option<ConsumerRuntime::LocalIdentityInfo>,
+		proof: Self::Proof,
+	) -> Result<Self::VerificationResult, Self::Error> {
+		match proof {
+			VersionedDipStandaloneStateProof::V0(v0_proof) => <v0::StandaloneVerifier<
+				ProviderStateRootStore,
+				KiltRuntime,
+				DidCallVerifier,
+				SignedExtra,
+				MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+				MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+				MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+				MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+				MAX_DID_MERKLE_LEAVES_REVEALED,
+			> as IdentityProofVerifier<ConsumerRuntime>>::verify_proof_for_call_against_details(
+				call,
+				subject,
+				submitter,
+				identity_details,
+				v0_proof,
+			),
+			VersionedDipStandaloneStateProof::V1(v1_proof) => <v0::StandaloneVerifier<
+				ProviderStateRootStore,
+				KiltRuntime,
+				DidCallVerifier,
+				SignedExtra,
+				MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+				MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+				MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+				MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+				MAX_DID_MERKLE_LEAVES_REVEALED,
+				1,
+			> as IdentityProofVerifier<ConsumerRuntime>>::verify_proof_for_call_against_details(
+				call,
+				subject,
+				submitter,
+				identity_details,
+				v1_proof,
+			),
+			VersionedDipStandaloneStateProof::V2(v2_proof) => {
+				let (v2_proof, salts) = v2_proof.into_parts();
+				v0::StandaloneVerifier::<
+					ProviderStateRootStore,
+					KiltRuntime,
+					DidCallVerifier,
+					SignedExtra,
+					MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+					MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+					MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+					MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+					MAX_DID_MERKLE_LEAVES_REVEALED,
+					2,
+				>::verify_proof_for_call_against_details_with_salts(
+					call,
+					subject,
+					submitter,
+					identity_details,
+					v2_proof,
+					Some(&salts),
+				)
+			}
+		}
+	}
+}
@@ -0,0 +1,362 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use uid_core::KeyIdOf;
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use pallet_dip_consumer::{traits::IdentityProofVerifier, RuntimeCallOf};
+use pallet_dip_provider::{traits::IdentityCommitmentGenerator, IdentityCommitmentVersion};
+use pallet_web3_names::Web3NameOf;
+use parity_scale_codec::Encode;
+use sp_core::H256;
+use sp_runtime::{traits::Zero, SaturatedConversion};
+use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
+
+use crate::{
+	merkle_proofs::v0::StandaloneDipDidProof,
+	traits::{DipCallOriginFilter, GetWithArg, GetWithoutArg, Incrementable, SignedIdentityDetails},
+	utils::OutputOf,
+	verifier::errors::DipProofComponentTooLargeError,
+	DipOriginInfo, DipStandaloneStateProofVerifierError, RevealedDidKey,
+};
+
+const LOG_TARGET: &str = "dip::consumer::StandaloneVerifierV0";
+
+/// Proof verifier configured given a specific KILT runtime implementation.
+///
+/// The generic types
+/// indicate the following:
+/// * `ProviderStateRootStore`: A type providing state roots for finalized
+///   provider blocks, e.g.,
+///   [`crate::traits::ProviderStateRootsViaGrandpaLightClientPallet`].
+/// * `KiltRuntime`: A KILT runtime definition.
+/// * `DidCallVerifier`: Logic to map `RuntimeCall`s to a specific DID key
+///   relationship. This information is used once the Merkle proof is verified,
+///   to filter only the revealed keys that match the provided relationship.
+/// * `SignedExtra`: Any additional information that must be signed by the DID
+///   subject in the cross-chain operation.
+/// * `MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT`: The maximum number of leaves that
+///   can be revealed as part of the DIP commitment storage proof.
+/// * `MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE`: The maximum size of each leaf
+///   revealed as part of the DIP commitment storage proof.
+/// * `MAX_DID_MERKLE_PROOF_LEAVE_COUNT`: The maximum number of *blinded* leaves
+///   that can be revealed as part of the DID Merkle proof.
+/// * `MAX_DID_MERKLE_PROOF_LEAVE_SIZE`: The maximum size of each *blinded* leaf
+///   revealed as part of the DID Merkle proof.
+/// * `MAX_DID_MERKLE_LEAVES_REVEALED`: The maximum number of leaves that can be
+///   revealed as part of the DID Merkle proof.
+/// * `DIP_COMMITMENT_VERSION`: The version of the DIP commitment the DID Merkle
+///   proof is verified against.
+pub struct StandaloneVerifier<
+	ProviderStateRootStore,
+	KiltRuntime,
+	DidCallVerifier,
+	SignedExtra,
+	const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32,
+	const MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE: u32,
+	const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
+	const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
+	const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+	const DIP_COMMITMENT_VERSION: IdentityCommitmentVersion = 0,
+>(PhantomData<(ProviderStateRootStore, KiltRuntime, DidCallVerifier, SignedExtra)>);
+
+impl<
+		ConsumerRuntime,
+		ProviderStateRootStore,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+		const DIP_COMMITMENT_VERSION: IdentityCommitmentVersion,
+	> IdentityProofVerifier<ConsumerRuntime>
+	for StandaloneVerifier<
+		ProviderStateRootStore,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+		MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+		DIP_COMMITMENT_VERSION,
+	> where
+	ConsumerRuntime: pallet_dip_consumer::Config<Identifier = KiltRuntime::Identifier>,
+	ConsumerRuntime::LocalIdentityInfo: Incrementable + SignedIdentityDetails + Default,
+	ProviderStateRootStore: GetWithArg<BlockNumberFor<KiltRuntime>, Result = Option<OutputOf<KiltRuntime::Hashing>>>,
+	KiltRuntime: frame_system::Config
+		+ pallet_dip_provider::Config
+		+ uid_core::Config
+		+ pallet_web3_names::Config
+		+ pallet_did_lookup::Config,
+	KiltRuntime::IdentityCommitmentGenerator: IdentityCommitmentGenerator<KiltRuntime, Output = KiltRuntime::Hash>,
+	SignedExtra: GetWithoutArg,
+	SignedExtra::Result: Encode + Debug,
+	DidCallVerifier: DipCallOriginFilter<
+		RuntimeCallOf<ConsumerRuntime>,
+		OriginInfo = Vec<RevealedDidKey<KeyIdOf<KiltRuntime>, BlockNumberFor<KiltRuntime>, KiltRuntime::AccountId>>,
+	>,
+	DidCallVerifier::Error: Into<u8> + Debug,
+{
+	type Error = DipStandaloneStateProofVerifierError<DidCallVerifier::Error>;
+	type Proof = StandaloneDipDidProof<
+		KeyIdOf<KiltRuntime>,
+		KiltRuntime::AccountId,
+		BlockNumberFor<KiltRuntime>,
+		Web3NameOf<KiltRuntime>,
+		LinkableAccountId,
+		BlockNumberFor<ConsumerRuntime>,
+	>;
+
+	type VerificationResult = DipOriginInfo<
+		KeyIdOf<KiltRuntime>,
+		KiltRuntime::AccountId,
+		BlockNumberFor<KiltRuntime>,
+		Web3NameOf<KiltRuntime>,
+		LinkableAccountId,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+	>;
+
+	fn verify_proof_for_call_against_details(
+		call: &RuntimeCallOf<ConsumerRuntime>,
+		subject: &<ConsumerRuntime as pallet_dip_consumer::Config>::Identifier,
+		submitter: &<ConsumerRuntime>::AccountId,
+		identity_details: &mut Option<<ConsumerRuntime as pallet_dip_consumer::Config>::LocalIdentityInfo>,
+		proof: Self::Proof,
+	) -> Result<Self::VerificationResult, Self::Error> {
+		Self::verify_proof_for_call_against_details_with_salts(call, subject, submitter, identity_details, proof, None)
+	}
+}
+
+impl<
+		ProviderStateRootStore,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT: u32,
+		const MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_COUNT: u32,
+		const MAX_DID_MERKLE_PROOF_LEAVE_SIZE: u32,
+		const MAX_DID_MERKLE_LEAVES_REVEALED: u32,
+		const DIP_COMMITMENT_VERSION: IdentityCommitmentVersion,
+	>
+	StandaloneVerifier<
+		ProviderStateRootStore,
+		KiltRuntime,
+		DidCallVerifier,
+		SignedExtra,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT,
+		MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_PROOF_LEAVE_COUNT,
+		MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
+		MAX_DID_MERKLE_LEAVES_REVEALED,
+		DIP_COMMITMENT_VERSION,
+	>
+{
+	/// Verify the provided proof the same way
+	/// [`IdentityProofVerifier::verify_proof_for_call_against_details`] does.
+	/// If `salts` are provided, the DID Merkle proof is verified against a
+	/// salted DIP commitment, using one salt for each revealed leaf.
+	pub(crate) fn verify_proof_for_call_against_details_with_salts<ConsumerRuntime>(
+		call: &RuntimeCallOf<ConsumerRuntime>,
+		subject: &<ConsumerRuntime as pallet_dip_consumer::Config>::Identifier,
+		submitter: &<ConsumerRuntime>::AccountId,
+		identity_details: &mut Option<<ConsumerRuntime as pallet_dip_consumer::Config>::LocalIdentityInfo>,
+		proof: <Self as IdentityProofVerifier<ConsumerRuntime>>::Proof,
+		salts: Option<&[H256]>,
+	) -> Result<
+		<Self as IdentityProofVerifier<ConsumerRuntime>>::VerificationResult,
+		<Self as IdentityProofVerifier<ConsumerRuntime>>::Error,
+	>
+	where
+		ConsumerRuntime: pallet_dip_consumer::Config<Identifier = KiltRuntime::Identifier>,
+		ConsumerRuntime::LocalIdentityInfo: Incrementable + SignedIdentityDetails + Default,
+		ProviderStateRootStore:
+			GetWithArg<BlockNumberFor<KiltRuntime>, Result = Option<OutputOf<KiltRuntime::Hashing>>>,
+		KiltRuntime: frame_system::Config
+			+ pallet_dip_provider::Config
+			+ uid_core::Config
+			+ pallet_web3_names::Config
+			+ pallet_did_lookup::Config,
+		KiltRuntime::IdentityCommitmentGenerator: IdentityCommitmentGenerator<KiltRuntime, Output = KiltRuntime::Hash>,
+		SignedExtra: GetWithoutArg,
+		SignedExtra::Result: Encode + Debug,
+		DidCallVerifier: DipCallOriginFilter<
+			RuntimeCallOf<ConsumerRuntime>,
+			OriginInfo = Vec<RevealedDidKey<KeyIdOf<KiltRuntime>, BlockNumberFor<KiltRuntime>, KiltRuntime::AccountId>>,
+		>,
+		DidCallVerifier::Error: Into<u8> + Debug,
+	{
+		// 1. Retrieve the provider state root finalized by the light client.
+		let proof_with_state_root = proof
+			.verify_provider_state_root::<KiltRuntime::Hashing, ProviderStateRootStore>()
+			.map_err(|e| {
+				log::info!(target: LOG_TARGET, "Failed to verify DIP proof with error {:#?}", e);
+				DipStandaloneStateProofVerifierError::ProofVerification(e)
+			})?;
+		log::info!(
+			target: LOG_TARGET,
+			"Verified provider state root: {:#?}",
+			proof_with_state_root.state_root
+		);
+
+		// 2. Verify commitment is included in provider state.
+		if proof_with_state_root.dip_commitment_proof.0.len() > MAX_DIP_COMMITMENT_PROOF_LEAVE_COUNT.saturated_into() {
+			let inner_error = DipProofComponentTooLargeError::DipCommitmentProofTooManyLeaves;
+			log::info!(
+				target: LOG_TARGET,
+				"Failed to verify DIP proof with error {:#?}",
+				inner_error
+			);
+			return Err(DipStandaloneStateProofVerifierError::ProofComponentTooLarge(
+				inner_error as u8,
+			));
+		}
+
+		if proof_with_state_root
+			.dip_commitment_proof
+			.0
+			.iter()
+			.any(|l| l.len() > MAX_DIP_COMMITMENT_PROOF_LEAVE_SIZE.saturated_into())
+		{
+			let inner_error = DipProofComponentTooLargeError::DipCommitmentProofLeafTooLarge;
+			log::info!(
+				target: LOG_TARGET,
+				"Failed to verify DIP proof with error {:#?}",
+				inner_error
+			);
+			return Err(DipStandaloneStateProofVerifierError::ProofComponentTooLarge(
+				inner_error as u8,
+			));
+		}
+
+		let proof_without_provider_state = proof_with_state_root
+			.verify_dip_commitment_proof_for_subject_and_version::<KiltRuntime::Hashing, KiltRuntime>(
+				subject,
+				DIP_COMMITMENT_VERSION,
+			)
+			.map_err(DipStandaloneStateProofVerifierError::ProofVerification)?;
+		log::info!(
+			target: LOG_TARGET,
+			"Verified subject DIP commitment: {:#?}",
+			proof_without_provider_state.dip_commitment
+		);
+
+		// 3. Verify DIP Merkle proof.
+		if proof_without_provider_state.dip_proof.blinded.len() > MAX_DID_MERKLE_PROOF_LEAVE_COUNT.saturated_into() {
+			let inner_error = DipProofComponentTooLargeError::DipProofTooManyLeaves;
+			log::info!(
+				target: LOG_TARGET,
+				"Failed to verify DIP proof with error {:#?}",
+				inner_error
+			);
+			return Err(DipStandaloneStateProofVerifierError::ProofComponentTooLarge(
+				inner_error as u8,
+			));
+		}
+
+		if proof_without_provider_state
+			.dip_proof
+			.blinded
+			.iter()
+			.any(|l| l.len() > MAX_DID_MERKLE_PROOF_LEAVE_SIZE.saturated_into())
+		{
+			let inner_error = DipProofComponentTooLargeError::DipProofLeafTooLarge;
+			log::info!(
+				target: LOG_TARGET,
+				"Failed to verify DIP proof with error {:#?}",
+				inner_error
+			);
+			return Err(DipStandaloneStateProofVerifierError::ProofComponentTooLarge(
+				inner_error as u8,
+			));
+		}
+
+		let proof_without_dip_merkle = match salts {
+			Some(salts) => proof_without_provider_state
+				.verify_salted_dip_proof::<KiltRuntime::Hashing, MAX_DID_MERKLE_LEAVES_REVEALED>(salts),
+			None => {
+				proof_without_provider_state.verify_dip_proof::<KiltRuntime::Hashing, MAX_DID_MERKLE_LEAVES_REVEALED>()
+			}
+		}
+		.map_err(|e| {
+			log::info!(target: LOG_TARGET, "Failed to verify DIP proof with error {:#?}", e);
+			DipStandaloneStateProofVerifierError::ProofVerification(e)
+		})?;
+		log::info!(
+			target: LOG_TARGET,
+			"Verified DID Merkle leaves: {:#?}",
+			proof_without_dip_merkle.revealed_leaves
+		);
+
+		// 4. Verify call is signed by one of the DID keys revealed in the proof
+		let current_block_number = frame_system::Pallet::<ConsumerRuntime>::block_number();
+		let consumer_genesis_hash =
+			frame_system::Pallet::<ConsumerRuntime>::block_hash(BlockNumberFor::<ConsumerRuntime>::zero());
+		let signed_extra = SignedExtra::get();
+		log::trace!(target: LOG_TARGET, "Additional components for signature verification: current block number = {:#?}, genesis hash = {:#?}, signed extra = {:#?}", current_block_number, consumer_genesis_hash, signed_extra);
+		let encoded_payload = (
+			call,
+			identity_details.as_ref().map(SignedIdentityDetails::signed_details),
+			submitter,
+			proof_without_dip_merkle.signature.valid_until,
+			consumer_genesis_hash,
+			signed_extra,
+		)
+			.encode();
+		log::trace!(target: LOG_TARGET, "Encoded final payload: {:#?}", encoded_payload);
+
+		let revealed_did_info = proof_without_dip_merkle
+			.verify_signature_time(&current_block_number)
+			.and_then(|p| p.retrieve_signing_leaves_for_payload(&encoded_payload[..]))
+			.map_err(|e| {
+				log::info!(target: LOG_TARGET, "Failed to verify DIP proof with error {:#?}", e);
+				DipStandaloneStateProofVerifierError::ProofVerification(e)
+			})?;
+
+		// 5. Verify the signing key fulfills the requirements
+		let signing_keys = revealed_did_info.get_signing_leaves().map_err(|e| {
+			log::info!(target: LOG_TARGET, "Failed to verify DIP proof with error {:#?}", e);
+			DipStandaloneStateProofVerifierError::ProofVerification(e)
+		})?;
+		DidCallVerifier::check_call_origin_info(call, &signing_keys.cloned().collect::<Vec<_>>()).map_err(|e| {
+			log::info!(target: LOG_TARGET, "Failed to verify DIP proof with error {:#?}", e);
+			DipStandaloneStateProofVerifierError::DidOriginError(e)
+		})?;
+
+		// 6. Increment the local details
+		if let Some(details) = identity_details {
+			details.increment();
+		} else {
+			let default_details = Default::default();
+			log::trace!(
+				target: LOG_TARGET,
+				"No details present for subject {:#?}. Setting default ones: {:#?}.",
+				subject,
+				default_details
+			);
+			*identity_details = Some(default_details);
+		};
+
+		Ok(revealed_did_info)
+	}
+}
@@ -17,10 +17,11 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use dip_provider_runtime_template::{
-	AccountId as ProviderAccountId, Runtime as ProviderRuntime, MAX_PUBLIC_KEYS_PER_DID, MAX_REVEALABLE_LINKED_ACCOUNTS,
+	AccountId as ProviderAccountId, Runtime as ProviderRuntime, MAX_PUBLIC_KEYS_PER_DID, MAX_REVEALABLE_CREDENTIALS,
+	MAX_REVEALABLE_LINKED_ACCOUNTS,
 };
 use frame_support::traits::Contains;
-use frame_system::{pallet_prelude::BlockNumberFor, EnsureSigned};
+use frame_system::{pallet_prelude::BlockNumberFor, EnsureRoot, EnsureSigned};
 use kilt_dip_primitives::{
 	parachain::{
 		DEFAULT_MAX_DID_MERKLE_PROOF_LEAVE_COUNT, DEFAULT_MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
@@ -28,18 +29,23 @@ use kilt_dip_primitives::{
 		DEFAULT_MAX_PROVIDER_HEAD_PROOF_LEAVE_COUNT, DEFAULT_MAX_PROVIDER_HEAD_PROOF_LEAVE_SIZE,
 	},
 	traits::DipCallOriginFilter,
-	KiltVersionedParachainVerifier, RelayStateRootsViaRelayStorePallet, RevealedDidKey,
+	DipSessionIdentityDetailsOf, DipSessionVerifier, KiltVersionedParachainVerifier,
+	RelayStateRootsViaRelayStorePallet, RevealedDidKey,
 };
+use pallet_dip_access_control::AccessControlledProofVerifier;
 use pallet_dip_consumer::traits::IdentityProofVerifier;
 use rococo_runtime::Runtime as RelaychainRuntime;
-use sp_core::ConstU32;
+use sp_core::{ConstBool, ConstU32};
 use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
 use uid_core::{DidVerificationKeyRelationship, KeyIdOf};
 
-use crate::{weights, AccountId, DidIdentifier, Runtime, RuntimeCall, RuntimeOrigin};
+use crate::{weights, AccountId, DidIdentifier, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, HOURS};
 
 // +1 for the web3name.
-const MAX_PROVIDER_REVEALABLE_KEYS_COUNT: u32 = MAX_PUBLIC_KEYS_PER_DID + MAX_REVEALABLE_LINKED_ACCOUNTS + 1;
+const MAX_PROVIDER_REVEALABLE_KEYS_COUNT: u32 =
+	MAX_PUBLIC_KEYS_PER_DID + MAX_REVEALABLE_LINKED_ACCOUNTS + MAX_REVEALABLE_CREDENTIALS + 1;
+// A DIP session lasts for one hour after being opened.
+const DIP_SESSION_DURATION: u32 = HOURS as u32;
 
 /// The verifier logic is tied to the provider template runtime definition.
 pub type ProviderTemplateProofVerifier = KiltVersionedParachainVerifier<
@@ -57,17 +63,31 @@ pub type ProviderTemplateProofVerifier = KiltVersionedParachainVerifier<
 	DEFAULT_MAX_DID_MERKLE_PROOF_LEAVE_SIZE,
 	MAX_PROVIDER_REVEALABLE_KEYS_COUNT,
 >;
-pub type MerkleProofVerifierInput = <ProviderTemplateProofVerifier as IdentityProofVerifier<Runtime>>::Proof;
+/// A full proof opens a DIP session, which then allows the subject to dispatch
+/// calls by only providing a proof that its DIP commitment has not changed and
+/// a signature from one of the revealed keys.
+pub type ProviderTemplateSessionVerifier = DipSessionVerifier<
+	ProviderTemplateProofVerifier,
+	ProviderRuntime,
+	DipCallFilter<KeyIdOf<ProviderRuntime>, BlockNumberFor<ProviderRuntime>, ProviderAccountId>,
+	(),
+	DIP_SESSION_DURATION,
+	MAX_PROVIDER_REVEALABLE_KEYS_COUNT,
+>;
+/// Calls dispatched with a valid proof are further checked against the access
+/// rules set in the DIP access control pallet.
+pub type ProviderTemplateAccessControlledVerifier = AccessControlledProofVerifier<ProviderTemplateSessionVerifier>;
+pub type MerkleProofVerifierInput = <ProviderTemplateAccessControlledVerifier as IdentityProofVerifier<Runtime>>::Proof;
 pub type MerkleProofVerifierOutput =
-	<ProviderTemplateProofVerifier as IdentityProofVerifier<Runtime>>::VerificationResult;
+	<ProviderTemplateAccessControlledVerifier as IdentityProofVerifier<Runtime>>::VerificationResult;
 // Wrapper around the verifier to implement the `GetWorstCase` trait (required
 // due to orphan rule).
 pub struct ProviderTemplateProofVerifierWrapper;
 
-// Delegate verification logic to the specialized version of
-// `KiltVersionedParachainVerifier`.
+// Delegate verification logic to the access-controlled session verifier wrapping
+// the specialized version of `KiltVersionedParachainVerifier`.
 impl IdentityProofVerifier<Runtime> for ProviderTemplateProofVerifierWrapper {
-	type Error = <ProviderTemplateProofVerifier as IdentityProofVerifier<Runtime>>::Error;
+	type Error = <ProviderTemplateAccessControlledVerifier as IdentityProofVerifier<Runtime>>::Error;
 	type Proof = MerkleProofVerifierInput;
 	type VerificationResult = MerkleProofVerifierOutput;
 
@@ -78,7 +98,7 @@ impl IdentityProofVerifier<Runtime> for ProviderTemplateProofVerifierWrapper {
 		identity_details: &mut Option<<Runtime as pallet_dip_consumer::Config>::LocalIdentityInfo>,
 		proof: Self::Proof,
 	) -> Result<Self::VerificationResult, Self::Error> {
-		<ProviderTemplateProofVerifier as IdentityProofVerifier<Runtime>>::verify_proof_for_call_against_details(
+		<ProviderTemplateAccessControlledVerifier as IdentityProofVerifier<Runtime>>::verify_proof_for_call_against_details(
 			call,
 			subject,
 			submitter,
@@ -97,8 +117,8 @@ impl kilt_support::traits::GetWorstCase for ProviderTemplateProofVerifierWrapper
 		use frame_support::{pallet_prelude::ValueQuery, storage_alias, Twox64Concat};
 		use hex_literal::hex;
 		use kilt_dip_primitives::{
-			DidKeyRelationship, DidMerkleProof, DipCommitmentStateProof, ParachainDipDidProof, ProviderHeadStateProof,
-			RevealedAccountId, RevealedWeb3Name, TimeBoundDidSignature,
+			DidKeyRelationship, DidMerkleProof, DipCommitmentStateProof, DipSessionProof, ParachainDipDidProof,
+			ProviderHeadStateProof, RevealedAccountId, RevealedWeb3Name, TimeBoundDidSignature,
 		};
 		use pallet_dip_consumer::benchmarking::WorstCaseOf;
 		use pallet_relay_store::RelayParentInfo;
@@ -486,7 +506,8 @@ impl kilt_support::traits::GetWorstCase for ProviderTemplateProofVerifierWrapper
 		);
 
 		WorstCaseOf {
-			proof: proof.into(),
+			// Opening a session is more expensive than continuing an existing one.
+			proof: DipSessionProof::Open(proof.into()),
 			call: pallet_postit::Call::post {
 				text: b"Hello, world!".to_vec().try_into().unwrap(),
 			}
@@ -501,7 +522,7 @@ impl kilt_support::traits::GetWorstCase for ProviderTemplateProofVerifierWrapper
 
 #[cfg(all(test, feature = "runtime-benchmarks"))]
 mod worst_case_tests {
-	use kilt_dip_primitives::VersionedDipParachainStateProof;
+	use kilt_dip_primitives::{DipSessionProof, VersionedDipParachainStateProof};
 	use kilt_support::traits::GetWorstCase;
 	use pallet_dip_consumer::benchmarking::WorstCaseOf;
 
@@ -513,7 +534,9 @@ mod worst_case_tests {
 	fn worst_case_max_limits() {
 		sp_io::TestExternalities::default().execute_with(|| {
 			let WorstCaseOf { proof, .. } = <ProviderTemplateProofVerifierWrapper as GetWorstCase>::worst_case(());
-			let VersionedDipParachainStateProof::V0(proof) = proof;
+			let DipSessionProof::Open(VersionedDipParachainStateProof::V0(proof)) = proof else {
+				panic!("Worst case should open a session with a V0 proof.");
+			};
 			// We test that the worst case reveals the maximum number of leaves revealable.
 			// This is required since the worst case is generated elsewhere and used here as
 			// a fixture.
@@ -534,10 +557,12 @@ impl pallet_dip_consumer::Config for Runtime {
 	// DIP proof verification step.
 	type DispatchOriginCheck = EnsureSigned<AccountId>;
 	type Identifier = DidIdentifier;
-	// Local identity info contains a simple `u128` representing a nonce. This means
-	// that two cross-chain operations targeting the same chain and with the same
-	// nonce cannot be both successfully evaluated.
-	type LocalIdentityInfo = u128;
+	// Local identity info contains a `u128` representing a nonce, and the DIP
+	// session opened by the last full proof, if any. The nonce means that two
+	// cross-chain operations targeting the same chain and with the same nonce cannot
+	// be both successfully evaluated.
+	type LocalIdentityInfo =
+		DipSessionIdentityDetailsOf<ProviderRuntime, BlockNumberFor<Runtime>, MAX_PROVIDER_REVEALABLE_KEYS_COUNT>;
 	type ProofVerifier = ProviderTemplateProofVerifierWrapper;
 	type RuntimeCall = RuntimeCall;
 	type RuntimeOrigin = RuntimeOrigin;
@@ -656,6 +681,25 @@ impl<ProviderDidKeyId, ProviderBlockNumber, ProviderAccountId> DipCallOriginFilt
 	}
 }
 
+impl pallet_dip_access_control::Config for Runtime {
+	type AccessRuleOrigin = EnsureRoot<AccountId>;
+	// DIP origins are rejected for calls without an access rule, which must be set
+	// via sudo before any call can be dispatched with a DIP origin.
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type AllowWithoutRule = ConstBool<false>;
+	// The DIP consumer benchmarks dispatch calls for which no access rule is set.
+	#[cfg(feature = "runtime-benchmarks")]
+	type AllowWithoutRule = ConstBool<true>;
+	type ProviderBlockNumber = BlockNumberFor<ProviderRuntime>;
+	// The template chains are started together and both produce a block every 12
+	// seconds, so the consumer block number is used as an approximation of the
+	// provider's. Production consumers must use a source that tracks the provider
+	// chain, e.g., its latest header proven against a relaychain state root.
+	type ProviderBlockNumberProvider = frame_system::Pallet<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::pallet_dip_access_control::WeightInfo<Runtime>;
+}
+
 impl pallet_relay_store::Config for Runtime {
 	// The pallet stores the last 100 relaychain state roots, making state proofs
 	// valid for at most 100 * 6 = 600 seconds.
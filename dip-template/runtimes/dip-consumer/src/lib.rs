@@ -34,6 +34,7 @@
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 use dip_provider_runtime_template::Web3Name;
+use kilt_dip_primitives::traits::{IdentityCommitmentVersion, SupportedCommitmentVersions};
 pub use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 pub use sp_runtime::{MultiAddress, Perbill, Permill};
 
@@ -145,6 +146,7 @@ construct_runtime!(
 		// DIP
 		DipConsumer: pallet_dip_consumer = 40,
 		RelayStore: pallet_relay_store = 41,
+		DipAccessControl: pallet_dip_access_control = 42,
 	}
 );
 
@@ -386,6 +388,7 @@ mod benches {
 		[frame_system, SystemBench::<Runtime>]
 		[pallet_dip_consumer, DipConsumer]
 		[pallet_relay_store, RelayStore]
+		[pallet_dip_access_control, DipAccessControl]
 	);
 }
 
@@ -543,6 +546,16 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl kilt_runtime_api_dip_consumer::DipConsumerCommitmentVersions<Block, IdentityCommitmentVersion> for Runtime {
+		fn supported_commitment_versions() -> Vec<IdentityCommitmentVersion> {
+			ProviderTemplateSessionVerifier::SUPPORTED_VERSIONS.to_vec()
+		}
+
+		fn negotiate_commitment_version(available_versions: Vec<IdentityCommitmentVersion>) -> Option<IdentityCommitmentVersion> {
+			ProviderTemplateSessionVerifier::negotiate_version(available_versions)
+		}
+	}
+
 	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
 
 		fn create_default_config() -> Vec<u8> {
@@ -17,5 +17,6 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 pub mod frame_system;
+pub mod pallet_dip_access_control;
 pub mod pallet_dip_consumer;
 pub mod pallet_relay_store;
@@ -23,10 +23,10 @@ use pallet_dip_provider::IdentityCommitmentVersion;
 use parity_scale_codec::{Decode, Encode};
 use runtime_common::dip::{
 	did::{LinkedDidInfoProvider, LinkedDidInfoProviderError},
-	merkle::{DidMerkleProofError, DidMerkleRootGenerator},
+	merkle::{CredentialKind, DidMerkleProofError, DidMerkleRootGenerator},
 };
 use scale_info::TypeInfo;
-use sp_core::ConstU32;
+use sp_core::{ConstU32, H256};
 use sp_std::vec::Vec;
 
 use crate::{
@@ -35,6 +35,7 @@ use crate::{
 };
 
 pub const MAX_REVEALABLE_LINKED_ACCOUNTS: u32 = 10;
+pub const MAX_REVEALABLE_CREDENTIALS: u32 = 10;
 
 pub mod runtime_api {
 	use super::*;
@@ -55,6 +56,10 @@ pub mod runtime_api {
 		/// A flag indicating whether the web3name claimed by the DID subject
 		/// should revealed in the DIP proof.
 		pub(crate) should_include_web3_name: bool,
+		/// The list of public credentials and verifications bound to the
+		/// subject's DID to reveal in the DIP proof. Only supported starting from
+		/// version `1`.
+		pub(crate) credentials: Vec<(CredentialKind, H256)>,
 	}
 
 	#[derive(Encode, Decode, TypeInfo)]
@@ -225,8 +230,13 @@ impl pallet_deposit_storage::Config for Runtime {
 	type CheckOrigin = EnsureSigned<AccountId>;
 	// The balances pallet is used to reserve/unreserve tokens.
 	type Currency = Balances;
+	// Commitment deposits can be updated to the current commitment deposit.
+	type DepositAmounts = deposit::DepositCollectorHooks;
 	type DepositHooks = DepositHooks;
+	// Deposits never expire.
+	type DepositLifetime = ();
 	type MaxKeyLength = ConstU32<256>;
+	type MaxIndexedKeyLength = ConstU32<256>;
 	type Namespace = DepositNamespaces;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
@@ -234,6 +244,8 @@ impl pallet_deposit_storage::Config for Runtime {
 }
 
 impl pallet_dip_provider::Config for Runtime {
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHooks = ();
 	// Only DID origins can submit the commitment identity tx, which will go through
 	// only if the DID in the origin matches the identifier specified in the tx.
 	type CommitOriginCheck = EnsureDidOrigin<DidIdentifier, AccountId>;
@@ -242,9 +254,15 @@ impl pallet_dip_provider::Config for Runtime {
 	// The identity commitment is defined as the Merkle root of the linked identity
 	// info, as specified by the [`LinkedDidInfoProvider`].
 	type IdentityCommitmentGenerator = DidMerkleRootGenerator<Runtime>;
-	// Identity info is defined as the collection of DID keys, linked accounts, and
-	// the optional web3name of a given DID subject.
-	type IdentityProvider = LinkedDidInfoProvider<MAX_REVEALABLE_LINKED_ACCOUNTS>;
+	// Identity info is defined as the collection of DID keys, linked accounts, the
+	// optional web3name, and the credentials bound to a given DID subject. The
+	// template runtime does not include the verification and public credentials
+	// pallets, hence no credentials are ever bound to a DID.
+	type IdentityProvider = LinkedDidInfoProvider<MAX_REVEALABLE_LINKED_ACCOUNTS, MAX_REVEALABLE_CREDENTIALS, ()>;
+	// No credentials are ever bound to a DID, as explained above.
+	type MaxCredentials = ConstU32<0>;
+	type MaxCommitmentVersions = ConstU32<3>;
+	type MaxVersionsPerCommit = ConstU32<3>;
 	type ProviderHooks = deposit::DepositCollectorHooks;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_dip_provider::WeightInfo<Runtime>;
@@ -59,7 +59,9 @@ use pallet_collator_selection::IdentityCollator;
 use pallet_dip_provider::{traits::IdentityProvider, IdentityProviderOf};
 use pallet_session::{FindAccountFromAuthorIndex, PeriodicSessions};
 use pallet_transaction_payment::{FeeDetails, FungibleAdapter, RuntimeDispatchInfo};
-use runtime_common::dip::merkle::{CompleteMerkleProof, DidMerkleProofOf, DidMerkleRootGenerator};
+use runtime_common::dip::merkle::{
+	CompleteMerkleProof, DidMerkleProofOf, DidMerkleRootGenerator, SaltedDidMerkleProofOf,
+};
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::SlotDuration;
 use sp_core::{crypto::KeyTypeId, ConstBool, ConstU128, ConstU16, OpaqueMetadata};
@@ -397,12 +399,14 @@ parameter_types! {
 
 impl uid_core::Config for Runtime {
 	type BalanceMigrationManager = ();
+	type DepositIndex = ();
 	type BaseDeposit = ConstU128<UNIT>;
 	type Currency = Balances;
 	type DidIdentifier = DidIdentifier;
 	type EnsureOrigin = EnsureDidOrigin<DidIdentifier, AccountId>;
 	type Fee = ConstU128<MILLIUNIT>;
 	type FeeCollector = ();
+	type IdentityChangeHook = DipProvider;
 	type KeyDeposit = ConstU128<UNIT>;
 	type MaxBlocksTxValidity = ConstU64<HOURS>;
 	type MaxNewKeyAgreementKeys = MaxNewKeyAgreementKeys;
@@ -425,10 +429,13 @@ impl uid_core::Config for Runtime {
 
 impl pallet_did_lookup::Config for Runtime {
 	type BalanceMigrationManager = ();
+	type DepositIndex = ();
 	type Currency = Balances;
 	type Deposit = ConstU128<UNIT>;
 	type DidIdentifier = DidIdentifier;
 	type EnsureOrigin = EnsureDidOrigin<DidIdentifier, AccountId>;
+	type EthereumChainId = ConstU64<6027>;
+	type IdentityChangeHook = DipProvider;
 	type OriginSuccess = DidRawOrigin<AccountId, DidIdentifier>;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
@@ -439,15 +446,23 @@ pub type Web3Name = AsciiWeb3Name<Runtime>;
 
 impl pallet_web3_names::Config for Runtime {
 	type BalanceMigrationManager = ();
+	type DepositIndex = ();
+	type IdentityChangeHook = DipProvider;
 	type BanOrigin = EnsureRoot<AccountId>;
 	type Currency = Balances;
 	type Deposit = ConstU128<UNIT>;
 	type MaxNameLength = ConstU32<32>;
+	type MaxReservedPatterns = ConstU32<50>;
+	type MaxTextRecordKeyLength = ConstU32<32>;
+	type MaxTextRecordValueLength = ConstU32<256>;
+	type MaxTextRecords = ConstU32<16>;
 	type MinNameLength = ConstU32<3>;
 	type OriginSuccess = DidRawOrigin<AccountId, DidIdentifier>;
 	type OwnerOrigin = EnsureDidOrigin<DidIdentifier, AccountId>;
+	type ReserveOrigin = EnsureRoot<AccountId>;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	type TextRecordDeposit = ConstU128<UNIT>;
 	type Web3Name = Web3Name;
 	type Web3NameOwner = DidIdentifier;
 	type WeightInfo = weights::pallet_web3_names::WeightInfo<Runtime>;
@@ -637,6 +652,7 @@ impl_runtime_apis! {
 				BlockNumber
 			>
 		> {
+			let name = pallet_web3_names::idna::to_ascii_form(&name)?;
 			let name: pallet_web3_names::web3_name::AsciiWeb3Name<Runtime> = name.try_into().ok()?;
 			pallet_web3_names::Owner::<Runtime>::get(&name)
 				.and_then(|owner_info| {
@@ -647,10 +663,12 @@ impl_runtime_apis! {
 						&owner_info.owner,
 					).collect();
 					let service_endpoints = uid_core::ServiceEndpoints::<Runtime>::iter_prefix(&owner_info.owner).map(|e| From::from(e.1)).collect();
+					let text_records = pallet_web3_names::Pallet::<Runtime>::raw_text_records(&name);
 
 					kilt_runtime_api_did::RawDidLinkedInfo{
 						identifier: owner_info.owner,
 						w3n: Some(name.into()),
+						text_records,
 						accounts,
 						service_endpoints,
 						details: details.into(),
@@ -673,13 +691,15 @@ impl_runtime_apis! {
 					uid_core::Did::<Runtime>::get(&owner_info.did).map(|details| (owner_info, details))
 				})
 				.map(|(connection_record, details)| {
-					let w3n = pallet_web3_names::Names::<Runtime>::get(&connection_record.did).map(Into::into);
+					let w3n = pallet_web3_names::Names::<Runtime>::get(&connection_record.did);
+					let text_records = w3n.as_ref().map(pallet_web3_names::Pallet::<Runtime>::raw_text_records).unwrap_or_default();
 					let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&connection_record.did).collect();
 					let service_endpoints = uid_core::ServiceEndpoints::<Runtime>::iter_prefix(&connection_record.did).map(|e| From::from(e.1)).collect();
 
 					kilt_runtime_api_did::RawDidLinkedInfo {
 						identifier: connection_record.did,
-						w3n,
+						w3n: w3n.map(Into::into),
+						text_records,
 						accounts,
 						service_endpoints,
 						details: details.into(),
@@ -698,25 +718,55 @@ impl_runtime_apis! {
 			>
 		> {
 			let details = uid_core::Did::<Runtime>::get(&did)?;
-			let w3n = pallet_web3_names::Names::<Runtime>::get(&did).map(Into::into);
+			let w3n = pallet_web3_names::Names::<Runtime>::get(&did);
+			let text_records = w3n.as_ref().map(pallet_web3_names::Pallet::<Runtime>::raw_text_records).unwrap_or_default();
 			let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&did).collect();
 			let service_endpoints = uid_core::ServiceEndpoints::<Runtime>::iter_prefix(&did).map(|e| From::from(e.1)).collect();
 
 			Some(kilt_runtime_api_did::RawDidLinkedInfo {
 				identifier: did,
-				w3n,
+				w3n: w3n.map(Into::into),
+				text_records,
 				accounts,
 				service_endpoints,
 				details: details.into(),
 			})
 		}
+
+		fn web3_name_forms(name: Vec<u8>) -> Option<kilt_runtime_api_did::Web3NameForms> {
+			let name = pallet_web3_names::idna::to_ascii_form(&name)?;
+			let name: pallet_web3_names::web3_name::AsciiWeb3Name<Runtime> = name.try_into().ok()?;
+
+			Some(kilt_runtime_api_did::Web3NameForms {
+				display: name.display_form(),
+				ascii: name.into(),
+			})
+		}
+
+		fn account_linking_challenge(did: DidIdentifier, expiration: BlockNumber) -> Vec<u8> {
+			pallet_did_lookup::associate_account_request::get_challenge(&did, expiration).into_bytes()
+		}
 	}
 
 	impl kilt_runtime_api_dip_provider::DipProvider<Block, runtime_api::DipProofRequest, CompleteMerkleProof<Hash, DidMerkleProofOf<Runtime>>, runtime_api::DipProofError> for Runtime {
 		fn generate_proof(request: runtime_api::DipProofRequest) -> Result<CompleteMerkleProof<Hash, DidMerkleProofOf<Runtime>>, runtime_api::DipProofError> {
 			let identity_details = IdentityProviderOf::<Runtime>::retrieve(&request.identifier).map_err(runtime_api::DipProofError::IdentityProvider)?;
 
-			DidMerkleRootGenerator::<Runtime>::generate_proof(&identity_details, request.version, request.keys.iter(), request.should_include_web3_name, request.accounts.iter()).map_err(runtime_api::DipProofError::MerkleProof)
+			DidMerkleRootGenerator::<Runtime>::generate_proof(&identity_details, request.version, request.keys.iter(), request.should_include_web3_name, request.accounts.iter(), request.credentials.iter()).map_err(runtime_api::DipProofError::MerkleProof)
+		}
+	}
+
+	impl kilt_runtime_api_dip_provider::DipSaltedProofProvider<Block, runtime_api::DipProofRequest, CompleteMerkleProof<Hash, SaltedDidMerkleProofOf<Runtime>>, runtime_api::DipProofError> for Runtime {
+		fn generate_salted_proof(request: runtime_api::DipProofRequest) -> Result<CompleteMerkleProof<Hash, SaltedDidMerkleProofOf<Runtime>>, runtime_api::DipProofError> {
+			let identity_details = IdentityProviderOf::<Runtime>::retrieve(&request.identifier).map_err(runtime_api::DipProofError::IdentityProvider)?;
+
+			DidMerkleRootGenerator::<Runtime>::generate_salted_proof(&identity_details, request.version, request.keys.iter(), request.should_include_web3_name, request.accounts.iter(), request.credentials.iter()).map_err(runtime_api::DipProofError::MerkleProof)
+		}
+	}
+
+	impl kilt_runtime_api_dip_provider::DipCommitmentVersions<Block, DidIdentifier, pallet_dip_provider::IdentityCommitmentVersion> for Runtime {
+		fn commitment_versions(identifier: DidIdentifier) -> Vec<pallet_dip_provider::IdentityCommitmentVersion> {
+			DipProvider::commitment_versions(&identifier)
 		}
 	}
 
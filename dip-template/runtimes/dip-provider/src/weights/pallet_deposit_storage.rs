@@ -56,15 +56,53 @@ impl<T: frame_system::Config> pallet_deposit_storage::WeightInfo for WeightInfo<
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
 	/// Storage: `DipProvider::IdentityCommitments` (r:1 w:1)
 	/// Proof: `DipProvider::IdentityCommitments` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::DepositExpirations` (r:0 w:1)
+	/// Proof: `DepositStorage::DepositExpirations` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn reclaim_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `443`
-		//  Estimated: `4414`
-		// Minimum execution time: 39_267_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(39_811_000, 0)
 			.saturating_add(Weight::from_parts(0, 4414))
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `DepositStorage::Deposits` (r:1 w:1)
+	/// Proof: `DepositStorage::Deposits` (`max_values`: None, `max_size`: Some(325), added: 2800, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::DepositExpirations` (r:0 w:1)
+	/// Proof: `DepositStorage::DepositExpirations` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	fn update_deposit() -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by releasing the deposit
+		// and holding it again, each costing at most the benchmarked
+		// `reclaim_deposit`, which accesses a superset of the same storage items.
+		<Self as pallet_deposit_storage::WeightInfo>::reclaim_deposit().saturating_mul(2)
+	}
+	/// Storage: `DepositStorage::DepositExpirations` (r:1 w:1)
+	/// Proof: `DepositStorage::DepositExpirations` (`max_values`: None, `max_size`: Some(265), added: 2740, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::Deposits` (r:1 w:1)
+	/// Proof: `DepositStorage::Deposits` (`max_values`: None, `max_size`: Some(325), added: 2800, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `DipProvider::IdentityCommitments` (r:1 w:1)
+	/// Proof: `DipProvider::IdentityCommitments` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	fn remove_expired_deposit() -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// `reclaim_deposit`, plus the read of the deposit expiration.
+		<Self as pallet_deposit_storage::WeightInfo>::reclaim_deposit()
+			.saturating_add(Weight::from_parts(0, 2534))
+			.saturating_add(T::DbWeight::get().reads(1))
 	}
 }
 
@@ -82,4 +120,28 @@ mod tests {
 				> 4414
 		);
 	}
+	#[test]
+	fn test_update_deposit() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 8828
+		);
+	}
+	#[test]
+	fn test_remove_expired_deposit() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 6948
+		);
+	}
 }
@@ -128,6 +128,78 @@ impl<T: frame_system::Config> pallet_did_lookup::WeightInfo for WeightInfo<T> {
 	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
 	/// Storage: `DidLookup::ConnectedAccounts` (r:0 w:2)
 	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	fn associate_bitcoin_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `377`
+		//  Estimated: `4414`
+		// Minimum execution time: 124_723_000 picoseconds.
+		Weight::from_parts(184_069_000, 0)
+			.saturating_add(Weight::from_parts(0, 4414))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedDids` (r:1 w:1)
+	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:0 w:2)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	fn associate_solana_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `377`
+		//  Estimated: `4414`
+		// Minimum execution time: 107_401_000 picoseconds.
+		Weight::from_parts(158_504_000, 0)
+			.saturating_add(Weight::from_parts(0, 4414))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedDids` (r:1 w:1)
+	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:0 w:2)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	fn associate_cosmos_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `377`
+		//  Estimated: `4414`
+		// Minimum execution time: 121_259_000 picoseconds.
+		Weight::from_parts(178_956_000, 0)
+			.saturating_add(Weight::from_parts(0, 4414))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedDids` (r:1 w:1)
+	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:0 w:2)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	fn associate_eth_typed_data_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `377`
+		//  Estimated: `4414`
+		// Minimum execution time: 116_408_000 picoseconds.
+		Weight::from_parts(171_797_000, 0)
+			.saturating_add(Weight::from_parts(0, 4414))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedDids` (r:1 w:1)
+	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:0 w:2)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
 	fn associate_sender() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `390`
@@ -259,6 +331,54 @@ mod tests {
 		);
 	}
 	#[test]
+	fn test_associate_bitcoin_account() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4414
+		);
+	}
+	#[test]
+	fn test_associate_solana_account() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4414
+		);
+	}
+	#[test]
+	fn test_associate_cosmos_account() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4414
+		);
+	}
+	#[test]
+	fn test_associate_eth_typed_data_account() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4414
+		);
+	}
+	#[test]
 	fn test_associate_sender() {
 		assert!(
 			<crate::Runtime as frame_system::Config>::BlockWeights::get()
@@ -66,7 +66,7 @@ impl<T: frame_system::Config> pallet_dip_provider::WeightInfo for WeightInfo<T>
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
-	fn commit_identity() -> Weight {
+	fn commit_identity(_c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3190`
 		//  Estimated: `55002`
@@ -76,6 +76,30 @@ impl<T: frame_system::Config> pallet_dip_provider::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(29))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(1447), added: 3922, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidBlacklist` (r:1 w:0)
+	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Names` (r:1 w:0)
+	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Owner` (r:1 w:0)
+	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:21 w:0)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `DipProvider::IdentityCommitments` (r:1 w:1)
+	/// Proof: `DipProvider::IdentityCommitments` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `DipProvider::PendingRefreshes` (r:1 w:1)
+	/// Proof: `DipProvider::PendingRefreshes` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	fn refresh_identity_commitment(_c: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// `commit_identity`, which retrieves the identity and generates the
+		// commitment in the same way, plus the maximum proof size of the pending
+		// refresh read.
+		Weight::from_parts(266_691_000, 0)
+			.saturating_add(Weight::from_parts(0, 57535))
+			.saturating_add(T::DbWeight::get().reads(27))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 	/// Storage: `DipProvider::IdentityCommitments` (r:1 w:1)
 	/// Proof: `DipProvider::IdentityCommitments` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
 	/// Storage: `DepositStorage::Deposits` (r:1 w:1)
@@ -94,6 +118,19 @@ impl<T: frame_system::Config> pallet_dip_provider::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: `DipProvider::PendingRefreshes` (r:1 w:1)
+	/// Proof: `DipProvider::PendingRefreshes` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `DipProvider::AutomaticRefreshEnabled` (r:0 w:1)
+	/// Proof: `DipProvider::AutomaticRefreshEnabled` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn set_automatic_refresh() -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// `delete_identity_commitment`, which removes an entry of a storage double
+		// map of this pallet in the same way.
+		Weight::from_parts(56_814_000, 0)
+			.saturating_add(Weight::from_parts(0, 4414))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
 
 #[cfg(test)]
@@ -111,6 +148,18 @@ mod tests {
 		);
 	}
 	#[test]
+	fn test_refresh_identity_commitment() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 57535
+		);
+	}
+	#[test]
 	fn test_delete_identity_commitment() {
 		assert!(
 			<crate::Runtime as frame_system::Config>::BlockWeights::get()
@@ -122,4 +171,16 @@ mod tests {
 				> 4414
 		);
 	}
+	#[test]
+	fn test_set_automatic_refresh() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4414
+		);
+	}
 }
@@ -54,20 +54,29 @@ impl<T: frame_system::Config> pallet_web3_names::WeightInfo for WeightInfo<T> {
 	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
 	/// Storage: `Web3Names::Banned` (r:1 w:0)
 	/// Proof: `Web3Names::Banned` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Reserved` (r:2 w:1)
+	/// Proof: `Web3Names::Reserved` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::ReservedPatterns` (r:1 w:0)
+	/// Proof: `Web3Names::ReservedPatterns` (`max_values`: Some(1), `max_size`: Some(1701), added: 2196, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Assigned` (r:1 w:1)
+	/// Proof: `Web3Names::Assigned` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[3, 32]`.
-	fn claim(_n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `107`
-		//  Estimated: `4414`
-		// Minimum execution time: 113_246_000 picoseconds.
+	/// The range of component `p` is `[1, 50]`.
+	fn claim(_n: u32, p: u32, ) -> Weight {
+		// Not benchmarked yet with reserved names. Bounded by the benchmarked
+		// `claim` of a name that is not reserved, plus the maximum proof size of
+		// the reserved names, patterns and assignments read, plus an estimated
+		// 5 microseconds for each reserved pattern matched against the name and
+		// its skeleton.
 		Weight::from_parts(132_782_588, 0)
-			.saturating_add(Weight::from_parts(0, 4414))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(Weight::from_parts(0, 14214))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `Web3Names::Names` (r:1 w:1)
 	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
@@ -174,6 +183,164 @@ impl<T: frame_system::Config> pallet_web3_names::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Web3Names::Reserved` (r:1 w:1)
+	/// Proof: `Web3Names::Reserved` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Owner` (r:1 w:0)
+	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[3, 32]`.
+	fn reserve(_n: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked `unban`,
+		// which also writes a single name to a map, plus the maximum proof size of
+		// the storage items read.
+		Weight::from_parts(9_336_817, 0)
+			.saturating_add(Weight::from_parts(0, 8646))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Web3Names::Reserved` (r:1 w:1)
+	/// Proof: `Web3Names::Reserved` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Assigned` (r:1 w:1)
+	/// Proof: `Web3Names::Assigned` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[3, 32]`.
+	fn unreserve(_n: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked `unban`,
+		// which also removes a single name from a map, plus the maximum proof size
+		// of the storage items read.
+		Weight::from_parts(9_336_817, 0)
+			.saturating_add(Weight::from_parts(0, 8594))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Web3Names::ReservedPatterns` (r:1 w:1)
+	/// Proof: `Web3Names::ReservedPatterns` (`max_values`: Some(1), `max_size`: Some(1701), added: 2196, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 50]`.
+	fn reserve_pattern(p: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked `unban`,
+		// plus the maximum proof size of the reserved patterns, plus an estimated
+		// 5 microseconds for each reserved pattern compared with the given one.
+		Weight::from_parts(9_336_817, 0)
+			.saturating_add(Weight::from_parts(0, 5710))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Web3Names::ReservedPatterns` (r:1 w:1)
+	/// Proof: `Web3Names::ReservedPatterns` (`max_values`: Some(1), `max_size`: Some(1701), added: 2196, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 50]`.
+	fn unreserve_pattern(p: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked `unban`,
+		// plus the maximum proof size of the reserved patterns, plus an estimated
+		// 5 microseconds for each reserved pattern compared with the given one.
+		Weight::from_parts(9_336_817, 0)
+			.saturating_add(Weight::from_parts(0, 5710))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Web3Names::Reserved` (r:2 w:0)
+	/// Proof: `Web3Names::Reserved` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::ReservedPatterns` (r:1 w:0)
+	/// Proof: `Web3Names::ReservedPatterns` (`max_values`: Some(1), `max_size`: Some(1701), added: 2196, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Names` (r:1 w:0)
+	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Owner` (r:1 w:0)
+	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Banned` (r:1 w:0)
+	/// Proof: `Web3Names::Banned` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Assigned` (r:0 w:1)
+	/// Proof: `Web3Names::Assigned` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[3, 32]`.
+	/// The range of component `p` is `[1, 50]`.
+	fn assign_reserved(_n: u32, p: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked `claim`,
+		// which checks the same names and also holds a deposit, plus the maximum
+		// proof size of the storage items read, plus an estimated 5 microseconds
+		// for each reserved pattern matched against the name and its skeleton.
+		Weight::from_parts(132_782_588, 0)
+			.saturating_add(Weight::from_parts(0, 14932))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Web3Names::Names` (r:1 w:0)
+	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::TextRecords` (r:1 w:1)
+	/// Proof: `Web3Names::TextRecords` (`max_values`: None, `max_size`: Some(404), added: 2879, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::TextRecordsCount` (r:1 w:1)
+	/// Proof: `Web3Names::TextRecordsCount` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// The range of component `k` is `[1, 32]`.
+	/// The range of component `v` is `[0, 256]`.
+	fn set_text_record(k: u32, v: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. The base is bounded by the
+		// benchmarked `claim`, which also holds and indexes a deposit, and the proof
+		// size by the maximum size of the storage items read. The cost of each byte
+		// of the key and the value is estimated.
+		Weight::from_parts(132_782_588, 0)
+			.saturating_add(Weight::from_parts(0, 13990))
+			.saturating_add(Weight::from_parts(25_000, 0).saturating_mul(k.into()))
+			.saturating_add(Weight::from_parts(25_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Web3Names::Names` (r:1 w:0)
+	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::TextRecords` (r:1 w:1)
+	/// Proof: `Web3Names::TextRecords` (`max_values`: None, `max_size`: Some(404), added: 2879, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::TextRecordsCount` (r:1 w:1)
+	/// Proof: `Web3Names::TextRecordsCount` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// The range of component `k` is `[1, 32]`.
+	fn remove_text_record(k: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. The base is bounded by the
+		// benchmarked `release_by_owner`, which also releases and unindexes a
+		// deposit, plus the estimated cost of decoding the largest value, and the
+		// proof size by the maximum size of the storage items read. The cost of each
+		// byte of the key is estimated.
+		Weight::from_parts(117_409_000, 0)
+			.saturating_add(Weight::from_parts(0, 13990))
+			.saturating_add(Weight::from_parts(25_000, 0).saturating_mul(k.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Web3Names::Names` (r:1 w:0)
+	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::TextRecords` (r:1 w:1)
+	/// Proof: `Web3Names::TextRecords` (`max_values`: None, `max_size`: Some(404), added: 2879, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	fn change_text_record_deposit_owner() -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// `change_deposit_owner`, which changes the owner of the deposit of a name
+		// in the same way, plus the maximum proof size of the text record read.
+		Weight::from_parts(61_305_000, 0)
+			.saturating_add(Weight::from_parts(0, 10717))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Web3Names::TextRecords` (r:1 w:1)
+	/// Proof: `Web3Names::TextRecords` (`max_values`: None, `max_size`: Some(404), added: 2879, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	fn update_text_record_deposit() -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// `update_deposit`, which updates the deposit of a name in the same way,
+		// plus the maximum proof size of the text record read.
+		Weight::from_parts(55_580_000, 0)
+			.saturating_add(Weight::from_parts(0, 7293))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
 
 #[cfg(test)]
@@ -187,7 +354,7 @@ mod tests {
 				.max_extrinsic
 				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
 				.proof_size()
-				> 4414
+				> 14214
 		);
 	}
 	#[test]
@@ -262,4 +429,112 @@ mod tests {
 				> 4414
 		);
 	}
+	#[test]
+	fn test_reserve() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 8646
+		);
+	}
+	#[test]
+	fn test_unreserve() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 8594
+		);
+	}
+	#[test]
+	fn test_reserve_pattern() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 5710
+		);
+	}
+	#[test]
+	fn test_unreserve_pattern() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 5710
+		);
+	}
+	#[test]
+	fn test_assign_reserved() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 14932
+		);
+	}
+	#[test]
+	fn test_set_text_record() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 13990
+		);
+	}
+	#[test]
+	fn test_remove_text_record() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 13990
+		);
+	}
+	#[test]
+	fn test_change_text_record_deposit_owner() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 10717
+		);
+	}
+	#[test]
+	fn test_update_text_record_deposit() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 7293
+		);
+	}
 }
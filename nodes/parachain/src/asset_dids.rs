@@ -0,0 +1,183 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+//! RPC methods to validate and normalise asset DIDs, and to list the public
+//! credentials issued for them.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use kilt_runtime_api_asset_dids::{AssetDids as AssetDidsRuntimeApi, ResolvedAssetDid};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+
+use runtime_common::{assets::PublicCredentialEntry, errors::AssetDidApiError, AccountId, Block, BlockNumber, Hash};
+
+/// The error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+/// The error code returned when the provided asset DID is not valid.
+const INVALID_ASSET_DID_ERROR: i32 = 2;
+
+/// The CAIP-2 chain ID of an asset DID, as returned by the RPC.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ChainIdDetails {
+	/// The namespace of the chain ID.
+	pub(crate) namespace: String,
+	/// The reference of the chain ID.
+	pub(crate) reference: String,
+}
+
+/// The CAIP-19 asset ID of an asset DID, as returned by the RPC.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AssetIdDetails {
+	/// The namespace of the asset ID.
+	pub(crate) namespace: String,
+	/// The reference of the asset ID.
+	pub(crate) reference: String,
+	/// The identifier of the asset ID, if present.
+	pub(crate) identifier: Option<String>,
+}
+
+/// A public credential issued for an asset DID, as returned by the RPC.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AssetDidCredential {
+	/// The ID of the credential.
+	pub(crate) id: Hash,
+	/// The hash of the CType the credential conforms to.
+	pub(crate) ctype_hash: Hash,
+	/// The DID of the credential attester.
+	pub(crate) attester: AccountId,
+	/// Whether the credential has been revoked by its attester.
+	pub(crate) revoked: bool,
+	/// The block in which the credential has been issued.
+	pub(crate) block_number: BlockNumber,
+}
+
+/// An asset DID as resolved by the runtime, as returned by the RPC.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AssetDidDetails {
+	/// The canonical form of the asset DID.
+	pub(crate) did: String,
+	/// The chain ID component of the asset DID.
+	pub(crate) chain: ChainIdDetails,
+	/// The asset ID component of the asset DID.
+	pub(crate) asset: AssetIdDetails,
+	/// The public credentials issued for the asset DID.
+	pub(crate) credentials: Vec<AssetDidCredential>,
+}
+
+fn utf8_to_string(value: Vec<u8>) -> String {
+	String::from_utf8_lossy(&value).into_owned()
+}
+
+impl From<ResolvedAssetDid<Hash, PublicCredentialEntry>> for AssetDidDetails {
+	fn from(value: ResolvedAssetDid<Hash, PublicCredentialEntry>) -> Self {
+		let ResolvedAssetDid {
+			did,
+			components,
+			credentials,
+		} = value;
+		Self {
+			did: utf8_to_string(did),
+			chain: ChainIdDetails {
+				namespace: utf8_to_string(components.chain_namespace),
+				reference: utf8_to_string(components.chain_reference),
+			},
+			asset: AssetIdDetails {
+				namespace: utf8_to_string(components.asset_namespace),
+				reference: utf8_to_string(components.asset_reference),
+				identifier: components.asset_identifier.map(utf8_to_string),
+			},
+			credentials: credentials
+				.into_iter()
+				.map(|(id, entry)| AssetDidCredential {
+					id,
+					ctype_hash: entry.ctype_hash,
+					attester: entry.attester,
+					revoked: entry.revoked,
+					block_number: entry.block_number,
+				})
+				.collect(),
+		}
+	}
+}
+
+/// RPC methods to resolve asset DIDs.
+#[rpc(server)]
+pub(crate) trait AssetDidsApi {
+	/// Parse the given asset DID at the given block, or at the best block if
+	/// none is specified, and return its canonical form, its components and
+	/// the public credentials issued for it.
+	#[method(name = "assetDids_resolve")]
+	async fn resolve(&self, asset_did: String, at: Option<Hash>) -> RpcResult<AssetDidDetails>;
+}
+
+/// Implementation of the [`AssetDidsApiServer`] using the runtime API of the
+/// node client.
+pub(crate) struct AssetDids<Client> {
+	client: Arc<Client>,
+}
+
+impl<Client> AssetDids<Client> {
+	pub(crate) fn new(client: Arc<Client>) -> Self {
+		Self { client }
+	}
+}
+
+#[async_trait]
+impl<Client> AssetDidsApiServer for AssetDids<Client>
+where
+	Client: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	Client::Api: AssetDidsRuntimeApi<Block, Hash, PublicCredentialEntry, AssetDidApiError>,
+{
+	async fn resolve(&self, asset_did: String, at: Option<Hash>) -> RpcResult<AssetDidDetails> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let resolved = self
+			.client
+			.runtime_api()
+			.resolve(at, asset_did.into_bytes())
+			.map_err(|e| {
+				CallError::Custom(ErrorObject::owned(
+					RUNTIME_ERROR,
+					"Unable to resolve the asset DID.",
+					Some(e.to_string()),
+				))
+			})?
+			.map_err(|e| {
+				let reason = match e {
+					AssetDidApiError::InvalidChainId => "Invalid chain ID.",
+					AssetDidApiError::InvalidAssetId => "Invalid asset ID.",
+					AssetDidApiError::InvalidFormat => "Invalid asset DID format.",
+				};
+				CallError::Custom(ErrorObject::owned(
+					INVALID_ASSET_DID_ERROR,
+					"The provided asset DID is not valid.",
+					Some(reason),
+				))
+			})?;
+		Ok(resolved.into())
+	}
+}
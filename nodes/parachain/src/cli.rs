@@ -123,6 +123,9 @@ pub(crate) enum Subcommand {
 	/// Export the genesis wasm of the parachain.
 	ExportGenesisWasm(cumulus_client_cli::ExportGenesisWasmCommand),
 
+	/// Generate a consumer-ready DIP proof using a relay chain snapshot.
+	GenerateDipProof(crate::dip_proof::GenerateDipProofCmd),
+
 	/// Sub-commands concerned with benchmarking.
 	/// The pallet benchmarking moved to the `pallet` sub-command.
 	#[command(subcommand)]
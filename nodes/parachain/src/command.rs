@@ -139,6 +139,42 @@ pub(crate) fn run() -> sc_cli::Result<()> {
 				}),
 			}
 		}
+		Some(Subcommand::GenerateDipProof(cmd)) => {
+			let (_, runtime) = get_selected_chainspec(&cmd.shared_params)?;
+
+			let runner = cli.create_runner(cmd)?;
+
+			match runtime {
+				ParachainRuntime::ParachainMainnet(_) => runner.async_run(|config| {
+					let para_id = chain_spec::Extensions::try_get(&*config.chain_spec)
+						.map(|e| e.para_id)
+						.ok_or("Could not find parachain ID in chain-spec.")?;
+					let partials = new_partial::<idchain_parachain_mainnet_runtime::RuntimeApi, IdChainParachainMainnetRuntimeExecutor, _>(
+						&config,
+						crate::service::build_import_queue,
+					)?;
+
+					Ok((
+						cmd.run::<idchain_parachain_mainnet_runtime::Runtime, _>(partials.client, ParaId::from(para_id)),
+						partials.task_manager,
+					))
+				}),
+				ParachainRuntime::ParachainTestnet(_) => runner.async_run(|config| {
+					let para_id = chain_spec::Extensions::try_get(&*config.chain_spec)
+						.map(|e| e.para_id)
+						.ok_or("Could not find parachain ID in chain-spec.")?;
+					let partials = new_partial::<idchain_parachain_testnet_runtime::RuntimeApi, IdChainParachainTestnetRuntimeExecutor, _>(
+						&config,
+						crate::service::build_import_queue,
+					)?;
+
+					Ok((
+						cmd.run::<idchain_parachain_testnet_runtime::Runtime, _>(partials.client, ParaId::from(para_id)),
+						partials.task_manager,
+					))
+				}),
+			}
+		}
 		Some(Subcommand::ExportGenesisWasm(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			runner.sync_run(|_config| {
@@ -285,7 +321,7 @@ pub(crate) fn run() -> sc_cli::Result<()> {
 
 				match runtime {
 					ParachainRuntime::ParachainTestnet(_) => {
-						crate::service::start_node::<IdChainParachainTestnetRuntimeExecutor, idchain_parachain_testnet_runtime::RuntimeApi>(
+						crate::service::start_node::<IdChainParachainTestnetRuntimeExecutor, idchain_parachain_testnet_runtime::RuntimeApi, idchain_parachain_testnet_runtime::Runtime>(
 							config,
 							polkadot_config,
 							collator_options,
@@ -297,7 +333,7 @@ pub(crate) fn run() -> sc_cli::Result<()> {
 						.map_err(Into::into)
 					},
 					ParachainRuntime::ParachainMainnet(_) => {
-						crate::service::start_node::<IdChainParachainMainnetRuntimeExecutor, idchain_parachain_mainnet_runtime::RuntimeApi>(
+						crate::service::start_node::<IdChainParachainMainnetRuntimeExecutor, idchain_parachain_mainnet_runtime::RuntimeApi, idchain_parachain_mainnet_runtime::Runtime>(
 							config,
 							polkadot_config,
 							collator_options,
@@ -0,0 +1,106 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+//! RPC methods to list the deposits held by an account across all the
+//! pallets that take deposits.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use kilt_runtime_api_deposits::{DepositInfo, Deposits as DepositsRuntimeApi};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+
+use runtime_common::{AccountId, Balance, Block, Hash};
+
+/// The error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+/// A deposit held by an account, as returned by the RPC.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DepositDetails {
+	/// The name of the pallet holding the deposit.
+	pub(crate) pallet: String,
+	/// The final storage key of the entry the deposit has been taken for.
+	pub(crate) key: Bytes,
+	/// The owner of the deposit.
+	pub(crate) owner: AccountId,
+	/// The amount of the deposit, as a decimal string to avoid losing
+	/// precision in JSON clients.
+	pub(crate) amount: String,
+	/// Whether the owner can reclaim the deposit at the queried block.
+	pub(crate) reclaimable: bool,
+}
+
+impl From<DepositInfo<AccountId, Balance>> for DepositDetails {
+	fn from(value: DepositInfo<AccountId, Balance>) -> Self {
+		Self {
+			pallet: String::from_utf8_lossy(&value.pallet).into_owned(),
+			key: value.key.into(),
+			owner: value.owner,
+			amount: value.amount.to_string(),
+			reclaimable: value.reclaimable,
+		}
+	}
+}
+
+/// RPC methods to list the deposits held by an account.
+#[rpc(server)]
+pub(crate) trait DepositsApi {
+	/// List all the deposits held by the given account at the given block, or
+	/// at the best block if none is specified.
+	#[method(name = "deposits_listForAccount")]
+	async fn list_for_account(&self, account: AccountId, at: Option<Hash>) -> RpcResult<Vec<DepositDetails>>;
+}
+
+/// Implementation of the [`DepositsApiServer`] using the runtime API of the
+/// node client.
+pub(crate) struct Deposits<Client> {
+	client: Arc<Client>,
+}
+
+impl<Client> Deposits<Client> {
+	pub(crate) fn new(client: Arc<Client>) -> Self {
+		Self { client }
+	}
+}
+
+#[async_trait]
+impl<Client> DepositsApiServer for Deposits<Client>
+where
+	Client: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	Client::Api: DepositsRuntimeApi<Block, AccountId, Balance>,
+{
+	async fn list_for_account(&self, account: AccountId, at: Option<Hash>) -> RpcResult<Vec<DepositDetails>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let deposits = self.client.runtime_api().deposits(at, account).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				RUNTIME_ERROR,
+				"Unable to query the deposits of the account.",
+				Some(e.to_string()),
+			))
+		})?;
+		Ok(deposits.into_iter().map(DepositDetails::from).collect())
+	}
+}
@@ -0,0 +1,84 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use std::{fs::File, path::PathBuf, sync::Arc};
+
+use cumulus_primitives_core::ParaId;
+use kilt_runtime_api_dip_provider::{DipProvider, DipSaltedProofProvider};
+use sc_cli::{CliConfiguration, SharedParams};
+use sc_client_api::ProofProvider;
+use sp_api::ProvideRuntimeApi;
+use sp_core::hexdisplay::HexDisplay;
+
+use runtime_common::{
+	dip::runtime_api::{DipProofError, DipProofRequest},
+	Block, DidIdentifier,
+};
+
+use crate::dip_proof::{
+	generate_proof, DipProofParams, DipProofSuccessOf, RelaySnapshot, RelayStateSource, SaltedDipProofSuccessOf,
+};
+
+/// Generate a consumer-ready DIP proof from the local database, using a
+/// relay chain snapshot file, and print it hex-encoded.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct GenerateDipProofCmd {
+	/// Path to the JSON file containing the DIP proof parameters, in the same
+	/// format accepted by the `dip_generateProof` RPC.
+	#[arg(long)]
+	pub(crate) params: PathBuf,
+
+	/// Path to the JSON file containing the relay chain snapshot for the
+	/// requested relay chain block.
+	#[arg(long)]
+	pub(crate) relay_snapshot: PathBuf,
+
+	#[allow(missing_docs)]
+	#[command(flatten)]
+	pub(crate) shared_params: SharedParams,
+}
+
+impl GenerateDipProofCmd {
+	/// Run the command.
+	pub(crate) async fn run<Runtime, Client>(&self, client: Arc<Client>, para_id: ParaId) -> sc_cli::Result<()>
+	where
+		Runtime: pallet_dip_provider::Config<Identifier = DidIdentifier> + pallet_web3_names::Config,
+		Client: ProvideRuntimeApi<Block> + ProofProvider<Block>,
+		Client::Api: DipProvider<Block, DipProofRequest, DipProofSuccessOf<Runtime>, DipProofError>
+			+ DipSaltedProofProvider<Block, DipProofRequest, SaltedDipProofSuccessOf<Runtime>, DipProofError>,
+	{
+		let params_file = File::open(&self.params)?;
+		let params: DipProofParams =
+			serde_json::from_reader(params_file).map_err(|e| format!("Invalid DIP proof parameters: {e}"))?;
+		let relay_snapshot = RelaySnapshot::from_file(&self.relay_snapshot).map_err(|e| e.to_string())?;
+
+		let proof =
+			generate_proof::<Runtime, _>(&*client, &RelayStateSource::Snapshot(relay_snapshot), para_id, params)
+				.await
+				.map_err(|e| e.to_string())?;
+		println!("0x{}", HexDisplay::from(&proof));
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for GenerateDipProofCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+}
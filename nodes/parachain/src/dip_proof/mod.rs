@@ -0,0 +1,234 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Generation of consumer-ready DIP proofs for identities committed on this
+//! chain.
+//!
+//! The `DipProvider` runtime API only returns the DID Merkle proof. A consumer
+//! parachain additionally requires the state proof of this chain's head on
+//! the relay chain and the state proof of the DIP commitment at that head. The
+//! types in this module fetch both and assemble them into a SCALE-encoded
+//! [`VersionedDipParachainStateProof`].
+
+use std::{fmt, iter};
+
+use cumulus_primitives_core::ParaId;
+use kilt_dip_primitives::{
+	DipCommitmentStateProof, ParachainDipDidProof, ProviderHeadStateProof, SaltedProof, TimeBoundDidSignature,
+	VersionedDipParachainStateProof,
+};
+use kilt_runtime_api_dip_provider::{DipProvider, DipSaltedProofProvider};
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use pallet_dip_provider::IdentityCommitmentVersion;
+use parity_scale_codec::{Decode, Encode};
+use polkadot_primitives::BlockNumber as RelayBlockNumber;
+use sc_client_api::ProofProvider;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_core::Bytes;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use uid_core::DidSignature;
+
+use runtime_common::{
+	dip::{
+		merkle::{CompleteMerkleProof, CredentialKind, DidMerkleProofOf, SaltedDidMerkleProofOf},
+		runtime_api::{DipProofError, DipProofRequest},
+	},
+	Block, BlockNumber, DidIdentifier, Hash,
+};
+
+mod command;
+mod relay;
+mod rpc;
+#[cfg(test)]
+mod tests;
+
+pub(crate) use command::GenerateDipProofCmd;
+pub(crate) use relay::{RelaySnapshot, RelayStateSource};
+pub(crate) use rpc::{DipProof, DipProofApiServer};
+
+/// The type returned by the `DipProvider` runtime API of the given runtime.
+pub(crate) type DipProofSuccessOf<Runtime> = CompleteMerkleProof<Hash, DidMerkleProofOf<Runtime>>;
+
+/// The type returned by the `DipSaltedProofProvider` runtime API of the given
+/// runtime.
+pub(crate) type SaltedDipProofSuccessOf<Runtime> = CompleteMerkleProof<Hash, SaltedDidMerkleProofOf<Runtime>>;
+
+/// The parameters of a DIP proof generation request, as provided to the RPC
+/// and to the `generate-dip-proof` subcommand.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DipProofParams {
+	/// The DID whose identity commitment is proven.
+	pub(crate) identifier: DidIdentifier,
+	/// The version of the identity commitment to prove.
+	pub(crate) version: IdentityCommitmentVersion,
+	/// The relay chain block whose state the proof is anchored to. The
+	/// consumer chain must know the state root of this block.
+	pub(crate) relay_block_number: RelayBlockNumber,
+	/// The DID key IDs to reveal.
+	#[serde(default)]
+	pub(crate) keys: Vec<Hash>,
+	/// The linked accounts to reveal.
+	#[serde(default)]
+	pub(crate) accounts: Vec<LinkableAccountId>,
+	/// Whether to reveal the web3name of the DID.
+	#[serde(default)]
+	pub(crate) include_web3_name: bool,
+	/// The IDs of the public credentials to reveal. Only supported starting
+	/// from version `1`.
+	#[serde(default)]
+	pub(crate) public_credentials: Vec<Hash>,
+	/// The IDs of the verifications to reveal. Only supported starting from
+	/// version `1`.
+	#[serde(default)]
+	pub(crate) verifications: Vec<Hash>,
+	/// The SCALE-encoded DID signature over the consumer call.
+	pub(crate) signature: Bytes,
+	/// The consumer block number until which the signature is valid.
+	pub(crate) valid_until: BlockNumber,
+}
+
+impl DipProofParams {
+	fn proof_request(&self) -> DipProofRequest {
+		let credentials = self
+			.public_credentials
+			.iter()
+			.map(|id| (CredentialKind::PublicCredential, *id))
+			.chain(self.verifications.iter().map(|id| (CredentialKind::Verification, *id)))
+			.collect();
+		DipProofRequest {
+			identifier: self.identifier.clone(),
+			version: self.version,
+			keys: self.keys.clone(),
+			accounts: self.accounts.clone(),
+			should_include_web3_name: self.include_web3_name,
+			credentials,
+		}
+	}
+}
+
+/// The errors that can occur when generating a DIP proof.
+#[derive(Debug)]
+pub(crate) enum Error {
+	/// The requested relay chain block could not be found.
+	RelayBlockNotFound(RelayBlockNumber),
+	/// The relay chain did not contain a head for this parachain.
+	ParaHeadNotFound,
+	/// The parachain head stored on the relay chain could not be decoded.
+	InvalidParaHead,
+	/// Fetching data from the relay chain failed.
+	RelayChain(String),
+	/// Reading or verifying the relay chain snapshot failed.
+	Snapshot(String),
+	/// Generating the DIP commitment storage proof failed.
+	Client(sp_blockchain::Error),
+	/// Calling into the runtime failed.
+	RuntimeApi(sp_api::ApiError),
+	/// The runtime failed to generate the DID Merkle proof.
+	ProofGeneration(DipProofError),
+	/// The provided signature is not a valid SCALE-encoded DID signature.
+	InvalidSignature,
+	/// No consumer proof format exists for the requested commitment version.
+	UnsupportedVersion(IdentityCommitmentVersion),
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::RelayBlockNotFound(number) => write!(f, "Relay chain block #{number} not found."),
+			Self::ParaHeadNotFound => write!(f, "Parachain head not found on the relay chain."),
+			Self::InvalidParaHead => write!(f, "Failed to decode the parachain head."),
+			Self::RelayChain(e) => write!(f, "Relay chain error: {e}"),
+			Self::Snapshot(e) => write!(f, "Relay chain snapshot error: {e}"),
+			Self::Client(e) => write!(f, "Failed to generate the DIP commitment proof: {e}"),
+			Self::RuntimeApi(e) => write!(f, "Runtime API error: {e}"),
+			Self::ProofGeneration(e) => write!(f, "Failed to generate the DID Merkle proof: {e:?}"),
+			Self::InvalidSignature => write!(f, "Failed to decode the DID signature."),
+			Self::UnsupportedVersion(version) => write!(f, "Unsupported DIP commitment version {version}."),
+		}
+	}
+}
+
+/// Generate the SCALE-encoded DIP proof for the given parameters, which a
+/// consumer parachain can verify with its `KiltVersionedParachainVerifier`.
+///
+/// The proof is generated against the block of this chain that is included
+/// in the requested relay chain block, which must be available in the local
+/// database.
+pub(crate) async fn generate_proof<Runtime, Client>(
+	client: &Client,
+	relay_source: &RelayStateSource,
+	para_id: ParaId,
+	params: DipProofParams,
+) -> Result<Vec<u8>, Error>
+where
+	Runtime: pallet_dip_provider::Config<Identifier = DidIdentifier> + pallet_web3_names::Config,
+	Client: ProvideRuntimeApi<Block> + ProofProvider<Block>,
+	Client::Api: DipProvider<Block, DipProofRequest, DipProofSuccessOf<Runtime>, DipProofError>
+		+ DipSaltedProofProvider<Block, DipProofRequest, SaltedDipProofSuccessOf<Runtime>, DipProofError>,
+{
+	let para_head_proof = relay_source.para_head_proof(para_id, params.relay_block_number).await?;
+	let provider_header =
+		<Block as BlockT>::Header::decode(&mut &para_head_proof.head.0[..]).map_err(|_| Error::InvalidParaHead)?;
+	let provider_block_hash = provider_header.hash();
+
+	let commitment_key =
+		pallet_dip_provider::IdentityCommitments::<Runtime>::hashed_key_for(&params.identifier, params.version);
+	let dip_commitment_proof = client
+		.read_proof(provider_block_hash, &mut iter::once(commitment_key.as_slice()))
+		.map_err(Error::Client)?;
+
+	// Version 2 commitments are salted, and their proofs include the salts of
+	// the revealed leaves.
+	let (did_merkle_proof, salts) = match params.version {
+		0 | 1 => {
+			let did_merkle_proof = client
+				.runtime_api()
+				.generate_proof(provider_block_hash, params.proof_request())
+				.map_err(Error::RuntimeApi)?
+				.map_err(Error::ProofGeneration)?;
+			(did_merkle_proof.proof, None)
+		}
+		2 => {
+			let did_merkle_proof = client
+				.runtime_api()
+				.generate_salted_proof(provider_block_hash, params.proof_request())
+				.map_err(Error::RuntimeApi)?
+				.map_err(Error::ProofGeneration)?;
+			let (proof, salts) = did_merkle_proof.proof.into_parts();
+			(proof, Some(salts))
+		}
+		version => return Err(Error::UnsupportedVersion(version)),
+	};
+
+	let signature = DidSignature::decode(&mut &params.signature[..]).map_err(|_| Error::InvalidSignature)?;
+
+	let proof = ParachainDipDidProof::new(
+		ProviderHeadStateProof::new(params.relay_block_number, para_head_proof.proof),
+		DipCommitmentStateProof::new(dip_commitment_proof.into_iter_nodes().collect()),
+		did_merkle_proof,
+		TimeBoundDidSignature::new(signature, params.valid_until),
+	);
+	let versioned_proof = match salts {
+		Some(salts) => VersionedDipParachainStateProof::V2(SaltedProof::new(proof, salts)),
+		None if params.version == 0 => VersionedDipParachainStateProof::V0(proof),
+		None => VersionedDipParachainStateProof::V1(proof),
+	};
+	Ok(versioned_proof.encode())
+}
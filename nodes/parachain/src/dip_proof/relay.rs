@@ -0,0 +1,117 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use std::{fs::File, path::Path, sync::Arc};
+
+use cumulus_primitives_core::ParaId;
+use cumulus_relay_chain_interface::RelayChainInterface;
+use parity_scale_codec::Decode;
+use polkadot_primitives::{well_known_keys, BlockId, BlockNumber as RelayBlockNumber, Hash as RelayHash, HeadData};
+use serde::{Deserialize, Serialize};
+use sp_core::Bytes;
+use sp_runtime::traits::{BlakeTwo256, Header as HeaderT};
+use sp_state_machine::{read_proof_check, StorageProof};
+
+use crate::dip_proof::Error;
+
+/// The head of this chain as stored on the relay chain, together with the
+/// relay chain state proof for it.
+pub(crate) struct ParaHeadProof {
+	pub(crate) head: HeadData,
+	pub(crate) proof: Vec<Vec<u8>>,
+}
+
+/// The source of the relay chain state used to prove the head of this chain.
+pub(crate) enum RelayStateSource {
+	/// A relay chain node, either running in-process or reached over RPC.
+	Node(Arc<dyn RelayChainInterface>),
+	/// A relay chain snapshot read from a file, mostly useful for testing.
+	Snapshot(RelaySnapshot),
+}
+
+impl RelayStateSource {
+	/// Return the head of the given parachain at the given relay chain block,
+	/// along with its state proof.
+	pub(crate) async fn para_head_proof(
+		&self,
+		para_id: ParaId,
+		relay_block_number: RelayBlockNumber,
+	) -> Result<ParaHeadProof, Error> {
+		let para_head_key = well_known_keys::para_head(para_id);
+		let (encoded_head, proof) = match self {
+			Self::Node(relay_chain_interface) => {
+				let relay_header = relay_chain_interface
+					.header(BlockId::Number(relay_block_number))
+					.await
+					.map_err(|e| Error::RelayChain(e.to_string()))?
+					.ok_or(Error::RelayBlockNotFound(relay_block_number))?;
+				let relay_block_hash = relay_header.hash();
+				let encoded_head = relay_chain_interface
+					.get_storage_by_key(relay_block_hash, &para_head_key)
+					.await
+					.map_err(|e| Error::RelayChain(e.to_string()))?
+					.ok_or(Error::ParaHeadNotFound)?;
+				let proof = relay_chain_interface
+					.prove_read(relay_block_hash, &vec![para_head_key])
+					.await
+					.map_err(|e| Error::RelayChain(e.to_string()))?;
+				(encoded_head, proof.into_iter_nodes().collect())
+			}
+			Self::Snapshot(snapshot) => {
+				if snapshot.relay_block_number != relay_block_number {
+					return Err(Error::RelayBlockNotFound(relay_block_number));
+				}
+				let proof: Vec<Vec<u8>> = snapshot.para_head_proof.iter().map(|node| node.to_vec()).collect();
+				let mut values = read_proof_check::<BlakeTwo256, _>(
+					snapshot.relay_state_root,
+					StorageProof::new(proof.clone()),
+					[&para_head_key],
+				)
+				.map_err(|e| Error::Snapshot(e.to_string()))?;
+				let encoded_head = values.remove(&para_head_key).flatten().ok_or(Error::ParaHeadNotFound)?;
+				(encoded_head, proof)
+			}
+		};
+		let head = HeadData::decode(&mut &encoded_head[..]).map_err(|_| Error::InvalidParaHead)?;
+		Ok(ParaHeadProof { head, proof })
+	}
+}
+
+/// A snapshot of the relay chain state required to prove the head of this
+/// chain at a given relay chain block, stored as JSON.
+///
+/// The state root is not verified against the relay chain, hence snapshots
+/// are only meant for testing and offline tooling.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RelaySnapshot {
+	/// The number of the relay chain block the snapshot was taken at.
+	pub(crate) relay_block_number: RelayBlockNumber,
+	/// The state root of the relay chain block.
+	pub(crate) relay_state_root: RelayHash,
+	/// The trie nodes proving the head of this chain against the state root.
+	pub(crate) para_head_proof: Vec<Bytes>,
+}
+
+impl RelaySnapshot {
+	/// Read a snapshot from the JSON file at the given path.
+	pub(crate) fn from_file(path: &Path) -> Result<Self, Error> {
+		let file = File::open(path).map_err(|e| Error::Snapshot(e.to_string()))?;
+		serde_json::from_reader(file).map_err(|e| Error::Snapshot(e.to_string()))
+	}
+}
@@ -0,0 +1,108 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use std::{marker::PhantomData, sync::Arc};
+
+use cumulus_primitives_core::ParaId;
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use kilt_runtime_api_dip_provider::{DipProvider, DipSaltedProofProvider};
+use sc_client_api::ProofProvider;
+use sc_rpc_api::DenyUnsafe;
+use sp_api::ProvideRuntimeApi;
+use sp_core::Bytes;
+
+use runtime_common::{
+	dip::runtime_api::{DipProofError, DipProofRequest},
+	Block, DidIdentifier,
+};
+
+use crate::dip_proof::{generate_proof, DipProofParams, DipProofSuccessOf, RelayStateSource, SaltedDipProofSuccessOf};
+
+/// The error code returned when a DIP proof cannot be generated.
+const DIP_PROOF_GENERATION_ERROR: i32 = 1;
+
+/// RPC methods to generate consumer-ready DIP proofs.
+#[rpc(server)]
+pub(crate) trait DipProofApi {
+	/// Generate the SCALE-encoded DIP proof for the given parameters, anchored
+	/// to the requested relay chain block.
+	///
+	/// Consumers that wrap the proof in a session must open the session with
+	/// the returned proof.
+	///
+	/// Generating a proof reads storage proofs from both this chain and the
+	/// relay chain, hence the method is only exposed as an unsafe RPC.
+	#[method(name = "dip_generateProof")]
+	async fn generate_proof(&self, params: DipProofParams) -> RpcResult<Bytes>;
+}
+
+/// Implementation of the [`DipProofApiServer`], using the relay chain node
+/// this collator is connected to.
+pub(crate) struct DipProof<Runtime, Client> {
+	client: Arc<Client>,
+	relay_source: RelayStateSource,
+	para_id: ParaId,
+	deny_unsafe: DenyUnsafe,
+	_runtime: PhantomData<Runtime>,
+}
+
+impl<Runtime, Client> DipProof<Runtime, Client> {
+	pub(crate) fn new(
+		client: Arc<Client>,
+		relay_source: RelayStateSource,
+		para_id: ParaId,
+		deny_unsafe: DenyUnsafe,
+	) -> Self {
+		Self {
+			client,
+			relay_source,
+			para_id,
+			deny_unsafe,
+			_runtime: PhantomData,
+		}
+	}
+}
+
+#[async_trait]
+impl<Runtime, Client> DipProofApiServer for DipProof<Runtime, Client>
+where
+	Runtime:
+		pallet_dip_provider::Config<Identifier = DidIdentifier> + pallet_web3_names::Config + Send + Sync + 'static,
+	Client: ProvideRuntimeApi<Block> + ProofProvider<Block> + Send + Sync + 'static,
+	Client::Api: DipProvider<Block, DipProofRequest, DipProofSuccessOf<Runtime>, DipProofError>
+		+ DipSaltedProofProvider<Block, DipProofRequest, SaltedDipProofSuccessOf<Runtime>, DipProofError>,
+{
+	async fn generate_proof(&self, params: DipProofParams) -> RpcResult<Bytes> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let proof = generate_proof::<Runtime, _>(&*self.client, &self.relay_source, self.para_id, params)
+			.await
+			.map_err(|e| {
+				CallError::Custom(ErrorObject::owned(
+					DIP_PROOF_GENERATION_ERROR,
+					"Unable to generate DIP proof.",
+					Some(e.to_string()),
+				))
+			})?;
+		Ok(proof.into())
+	}
+}
@@ -0,0 +1,181 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+use cumulus_primitives_core::ParaId;
+use futures::executor::block_on;
+use parity_scale_codec::Encode;
+use polkadot_primitives::{well_known_keys, HeadData};
+use serde_json::json;
+use sp_core::{storage::StateVersion, Bytes, H256};
+use sp_runtime::{traits::BlakeTwo256, AccountId32};
+use sp_state_machine::{prove_read, InMemoryBackend};
+
+use runtime_common::dip::merkle::CredentialKind;
+
+use crate::dip_proof::{DipProofParams, Error, RelaySnapshot, RelayStateSource};
+
+const PARA_ID: u32 = 2_000;
+const RELAY_BLOCK_NUMBER: u32 = 100;
+
+fn relay_snapshot_with_head(para_id: u32, encoded_head: Vec<u8>) -> RelaySnapshot {
+	let para_head_key = well_known_keys::para_head(ParaId::from(para_id));
+	let backend = InMemoryBackend::<BlakeTwo256>::from((
+		vec![(None, vec![(para_head_key.clone(), Some(encoded_head))])],
+		StateVersion::V1,
+	));
+	let relay_state_root = *backend.root();
+	let para_head_proof = prove_read(backend, [&para_head_key]).expect("Failed to generate para head proof.");
+	RelaySnapshot {
+		relay_block_number: RELAY_BLOCK_NUMBER,
+		relay_state_root,
+		para_head_proof: para_head_proof.into_iter_nodes().map(Bytes::from).collect(),
+	}
+}
+
+fn default_params() -> DipProofParams {
+	DipProofParams {
+		identifier: AccountId32::new([1; 32]),
+		version: 1,
+		relay_block_number: RELAY_BLOCK_NUMBER,
+		keys: vec![],
+		accounts: vec![],
+		include_web3_name: false,
+		public_credentials: vec![],
+		verifications: vec![],
+		signature: Bytes::from(vec![]),
+		valid_until: 10,
+	}
+}
+
+#[test]
+fn para_head_proof_from_snapshot() {
+	let head = HeadData(vec![1, 2, 3]);
+	let snapshot = relay_snapshot_with_head(PARA_ID, head.encode());
+	let expected_proof: Vec<Vec<u8>> = snapshot.para_head_proof.iter().map(|node| node.to_vec()).collect();
+
+	let para_head_proof =
+		block_on(RelayStateSource::Snapshot(snapshot).para_head_proof(ParaId::from(PARA_ID), RELAY_BLOCK_NUMBER))
+			.expect("Para head proof should be generated from a valid snapshot.");
+
+	assert_eq!(para_head_proof.head, head);
+	assert_eq!(para_head_proof.proof, expected_proof);
+}
+
+#[test]
+fn para_head_proof_from_snapshot_fails_on_different_relay_block() {
+	let snapshot = relay_snapshot_with_head(PARA_ID, HeadData(vec![1, 2, 3]).encode());
+
+	let result =
+		block_on(RelayStateSource::Snapshot(snapshot).para_head_proof(ParaId::from(PARA_ID), RELAY_BLOCK_NUMBER + 1));
+
+	assert!(matches!(result, Err(Error::RelayBlockNotFound(number)) if number == RELAY_BLOCK_NUMBER + 1));
+}
+
+#[test]
+fn para_head_proof_from_snapshot_fails_on_different_para_id() {
+	let snapshot = relay_snapshot_with_head(PARA_ID, HeadData(vec![1, 2, 3]).encode());
+
+	let result =
+		block_on(RelayStateSource::Snapshot(snapshot).para_head_proof(ParaId::from(PARA_ID + 1), RELAY_BLOCK_NUMBER));
+
+	assert!(matches!(result, Err(Error::ParaHeadNotFound)));
+}
+
+#[test]
+fn para_head_proof_from_snapshot_fails_on_invalid_state_root() {
+	let snapshot = RelaySnapshot {
+		relay_state_root: H256::repeat_byte(1),
+		..relay_snapshot_with_head(PARA_ID, HeadData(vec![1, 2, 3]).encode())
+	};
+
+	let result =
+		block_on(RelayStateSource::Snapshot(snapshot).para_head_proof(ParaId::from(PARA_ID), RELAY_BLOCK_NUMBER));
+
+	assert!(matches!(result, Err(Error::Snapshot(_))));
+}
+
+#[test]
+fn para_head_proof_from_snapshot_fails_on_invalid_para_head() {
+	// Not a valid SCALE-encoded `HeadData`, as the length prefix is missing.
+	let snapshot = relay_snapshot_with_head(PARA_ID, vec![u8::MAX]);
+
+	let result =
+		block_on(RelayStateSource::Snapshot(snapshot).para_head_proof(ParaId::from(PARA_ID), RELAY_BLOCK_NUMBER));
+
+	assert!(matches!(result, Err(Error::InvalidParaHead)));
+}
+
+#[test]
+fn proof_request_from_params() {
+	let params = DipProofParams {
+		keys: vec![H256::repeat_byte(1)],
+		include_web3_name: true,
+		public_credentials: vec![H256::repeat_byte(2)],
+		verifications: vec![H256::repeat_byte(3), H256::repeat_byte(4)],
+		..default_params()
+	};
+
+	let request = params.proof_request();
+
+	assert_eq!(request.identifier, params.identifier);
+	assert_eq!(request.version, params.version);
+	assert_eq!(request.keys, params.keys);
+	assert!(request.should_include_web3_name);
+	assert_eq!(
+		request.credentials,
+		vec![
+			(CredentialKind::PublicCredential, H256::repeat_byte(2)),
+			(CredentialKind::Verification, H256::repeat_byte(3)),
+			(CredentialKind::Verification, H256::repeat_byte(4)),
+		]
+	);
+}
+
+#[test]
+fn params_from_json_with_defaults() {
+	let params: DipProofParams = serde_json::from_value(json!({
+		"identifier": AccountId32::new([1; 32]),
+		"version": 2,
+		"relayBlockNumber": RELAY_BLOCK_NUMBER,
+		"signature": "0x0102",
+		"validUntil": 10,
+	}))
+	.expect("Parameters with only the required fields should be valid.");
+
+	assert_eq!(params.identifier, AccountId32::new([1; 32]));
+	assert_eq!(params.version, 2);
+	assert_eq!(params.relay_block_number, RELAY_BLOCK_NUMBER);
+	assert!(params.keys.is_empty());
+	assert!(params.accounts.is_empty());
+	assert!(!params.include_web3_name);
+	assert!(params.public_credentials.is_empty());
+	assert!(params.verifications.is_empty());
+	assert_eq!(params.signature, Bytes::from(vec![1, 2]));
+	assert_eq!(params.valid_until, 10);
+}
+
+#[test]
+fn params_from_json_fails_on_missing_signature() {
+	let result = serde_json::from_value::<DipProofParams>(json!({
+		"identifier": AccountId32::new([1; 32]),
+		"version": 1,
+		"relayBlockNumber": RELAY_BLOCK_NUMBER,
+		"validUntil": 10,
+	}));
+
+	assert!(result.is_err());
+}
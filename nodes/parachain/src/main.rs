@@ -24,7 +24,10 @@ mod chain_spec;
 #[macro_use]
 mod service;
 mod cli;
+mod asset_dids;
 mod command;
+mod deposits;
+mod dip_proof;
 mod rpc;
 
 fn main() -> sc_cli::Result<()> {
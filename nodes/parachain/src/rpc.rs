@@ -25,14 +25,27 @@
 
 use std::sync::Arc;
 
-use sc_client_api::AuxStore;
+use cumulus_primitives_core::ParaId;
+use cumulus_relay_chain_interface::RelayChainInterface;
+use sc_client_api::{AuxStore, ProofProvider};
 pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 
-use runtime_common::{AccountId, Balance, Block, Nonce};
+use runtime_common::{
+	assets::PublicCredentialEntry,
+	dip::runtime_api::{DipProofError, DipProofRequest},
+	errors::AssetDidApiError,
+	AccountId, Balance, Block, DidIdentifier, Hash, Nonce,
+};
+
+use crate::{
+	asset_dids::{AssetDids, AssetDidsApiServer},
+	deposits::{Deposits, DepositsApiServer},
+	dip_proof::{DipProof, DipProofApiServer, DipProofSuccessOf, RelayStateSource, SaltedDipProofSuccessOf},
+};
 
 /// A type representing all RPC extensions.
 pub(crate) type RpcExtension = jsonrpsee::RpcModule<()>;
@@ -45,14 +58,21 @@ pub(crate) struct FullDeps<C, P> {
 	pub pool: Arc<P>,
 	/// Whether to deny unsafe calls
 	pub deny_unsafe: DenyUnsafe,
+	/// The relay chain node used to prove the parachain head in DIP proofs.
+	pub relay_chain_interface: Arc<dyn RelayChainInterface>,
+	/// The ID of this parachain.
+	pub para_id: ParaId,
 }
 
 /// Instantiate all RPC extensions.
-pub(crate) fn create_full<C, P>(deps: FullDeps<C, P>) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
+pub(crate) fn create_full<C, P, Runtime>(
+	deps: FullDeps<C, P>,
+) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
 where
 	C: ProvideRuntimeApi<Block>
 		+ HeaderBackend<Block>
 		+ AuxStore
+		+ ProofProvider<Block>
 		+ HeaderMetadata<Block, Error = BlockChainError>
 		+ Send
 		+ Sync
@@ -60,7 +80,19 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: BlockBuilder<Block>,
+	C::Api:
+		kilt_runtime_api_dip_provider::DipProvider<Block, DipProofRequest, DipProofSuccessOf<Runtime>, DipProofError>,
+	C::Api: kilt_runtime_api_dip_provider::DipSaltedProofProvider<
+		Block,
+		DipProofRequest,
+		SaltedDipProofSuccessOf<Runtime>,
+		DipProofError,
+	>,
+	C::Api: kilt_runtime_api_deposits::Deposits<Block, AccountId, Balance>,
+	C::Api: kilt_runtime_api_asset_dids::AssetDids<Block, Hash, PublicCredentialEntry, AssetDidApiError>,
 	P: TransactionPool + 'static,
+	Runtime:
+		pallet_dip_provider::Config<Identifier = DidIdentifier> + pallet_web3_names::Config + Send + Sync + 'static,
 {
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
@@ -70,10 +102,23 @@ where
 		client,
 		pool,
 		deny_unsafe,
+		relay_chain_interface,
+		para_id,
 	} = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Deposits::new(client.clone()).into_rpc())?;
+	module.merge(AssetDids::new(client.clone()).into_rpc())?;
+	module.merge(
+		DipProof::<Runtime, _>::new(
+			client,
+			RelayStateSource::Node(relay_chain_interface),
+			para_id,
+			deny_unsafe,
+		)
+		.into_rpc(),
+	)?;
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
 	// to call into the runtime.
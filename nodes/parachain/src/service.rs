@@ -45,7 +45,14 @@ use sp_runtime::traits::BlakeTwo256;
 use std::{sync::Arc, time::Duration};
 use substrate_prometheus_endpoint::Registry;
 
-use runtime_common::{AccountId, AuthorityId, Balance, BlockNumber, Hash, Nonce};
+use runtime_common::{
+	assets::PublicCredentialEntry,
+	dip::runtime_api::{DipProofError, DipProofRequest},
+	errors::AssetDidApiError,
+	AccountId, AuthorityId, Balance, BlockNumber, DidIdentifier, Hash, Nonce,
+};
+
+use crate::dip_proof::{DipProofSuccessOf, SaltedDipProofSuccessOf};
 
 pub const AUTHORING_DURATION: u64 = 1500;
 pub const TASK_MANAGER_IDENTIFIER: &str = "aura";
@@ -202,7 +209,7 @@ where
 /// runtime api.
 #[allow(clippy::too_many_arguments)]
 #[sc_tracing::logging::prefix_logs_with("Parachain")]
-async fn start_node_impl<RuntimeApi, Executor, RB, BIQ>(
+async fn start_node_impl<RuntimeApi, Executor, Runtime, RB, BIQ>(
 	parachain_config: Configuration,
 	polkadot_config: Configuration,
 	collator_options: CollatorOptions,
@@ -229,9 +236,19 @@ where
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
 		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
 		+ sp_consensus_aura::AuraApi<Block, AuthorityId>
-		+ cumulus_primitives_aura::AuraUnincludedSegmentApi<Block>,
+		+ cumulus_primitives_aura::AuraUnincludedSegmentApi<Block>
+		+ kilt_runtime_api_dip_provider::DipProvider<Block, DipProofRequest, DipProofSuccessOf<Runtime>, DipProofError>
+		+ kilt_runtime_api_dip_provider::DipSaltedProofProvider<
+			Block,
+			DipProofRequest,
+			SaltedDipProofSuccessOf<Runtime>,
+			DipProofError,
+		> + kilt_runtime_api_deposits::Deposits<Block, AccountId, Balance>
+		+ kilt_runtime_api_asset_dids::AssetDids<Block, Hash, PublicCredentialEntry, AssetDidApiError>,
 	sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_state_machine::Backend<BlakeTwo256>,
 	Executor: sc_executor::NativeExecutionDispatch + 'static,
+	Runtime:
+		pallet_dip_provider::Config<Identifier = DidIdentifier> + pallet_web3_names::Config + Send + Sync + 'static,
 	RB: FnOnce(
 			Arc<TFullClient<Block, RuntimeApi, NativeElseWasmExecutor<Executor>>>,
 		) -> Result<RpcModule<()>, sc_service::Error>
@@ -287,15 +304,18 @@ where
 	let rpc_builder = {
 		let client = client.clone();
 		let transaction_pool = transaction_pool.clone();
+		let relay_chain_interface = relay_chain_interface.clone();
 
 		Box::new(move |deny_unsafe, _| {
 			let deps = crate::rpc::FullDeps {
 				client: client.clone(),
 				pool: transaction_pool.clone(),
 				deny_unsafe,
+				relay_chain_interface: relay_chain_interface.clone(),
+				para_id: id,
 			};
 
-			crate::rpc::create_full(deps).map_err(Into::into)
+			crate::rpc::create_full::<_, _, Runtime>(deps).map_err(Into::into)
 		})
 	};
 
@@ -429,7 +449,7 @@ where
 }
 
 /// Start a parachain node.
-pub(crate) async fn start_node<RE, API>(
+pub(crate) async fn start_node<RE, API, Runtime>(
 	parachain_config: Configuration,
 	polkadot_config: Configuration,
 	collator_options: CollatorOptions,
@@ -449,10 +469,20 @@ where
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
 		+ sp_consensus_aura::AuraApi<Block, AuthorityId>
 		+ cumulus_primitives_core::CollectCollationInfo<Block>
-		+ cumulus_primitives_aura::AuraUnincludedSegmentApi<Block>,
+		+ cumulus_primitives_aura::AuraUnincludedSegmentApi<Block>
+		+ kilt_runtime_api_dip_provider::DipProvider<Block, DipProofRequest, DipProofSuccessOf<Runtime>, DipProofError>
+		+ kilt_runtime_api_dip_provider::DipSaltedProofProvider<
+			Block,
+			DipProofRequest,
+			SaltedDipProofSuccessOf<Runtime>,
+			DipProofError,
+		> + kilt_runtime_api_deposits::Deposits<Block, AccountId, Balance>
+		+ kilt_runtime_api_asset_dids::AssetDids<Block, Hash, PublicCredentialEntry, AssetDidApiError>,
 	sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_state_machine::Backend<BlakeTwo256>,
+	Runtime:
+		pallet_dip_provider::Config<Identifier = DidIdentifier> + pallet_web3_names::Config + Send + Sync + 'static,
 {
-	start_node_impl::<API, RE, _, _>(
+	start_node_impl::<API, RE, Runtime, _, _>(
 		parachain_config,
 		polkadot_config,
 		collator_options,
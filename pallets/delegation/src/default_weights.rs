@@ -74,27 +74,29 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Delegation DelegationNodes (r:0 w:1)
 	/// Proof: Delegation DelegationNodes (max_values: None, max_size: Some(32200), added: 34675, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn create_hierarchy() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `896`
-		//  Estimated: `7725`
-		// Minimum execution time: 20_531 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(21_675_000, 7725)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Delegation DelegationNodes (r:2 w:2)
 	/// Proof: Delegation DelegationNodes (max_values: None, max_size: Some(32200), added: 34675, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn add_delegation() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1048`
-		//  Estimated: `71957`
-		// Minimum execution time: 22_557 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(23_576_000, 71957)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Delegation DelegationNodes (r:5 w:5)
 	/// Proof: Delegation DelegationNodes (max_values: None, max_size: Some(32200), added: 34675, mode: MaxEncodedLen)
@@ -142,19 +144,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Delegation DelegationHierarchies (r:1 w:1)
 	/// Proof: Delegation DelegationHierarchies (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:5)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `r` is `[1, 5]`.
 	fn remove_delegation(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1119 + r * (272 ±0)`
-		//  Estimated: `39837 + r * (34675 ±0)`
-		// Minimum execution time: 38_077 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(26_891_977, 39837)
-			// Standard Error: 40_521
 			.saturating_add(Weight::from_parts(15_313_221, 0).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 34675).saturating_mul(r.into()))
 	}
 	/// Storage: Delegation DelegationNodes (r:6 w:6)
@@ -163,19 +165,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Delegation DelegationHierarchies (r:0 w:1)
 	/// Proof: Delegation DelegationHierarchies (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:5)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `r` is `[1, 5]`.
 	fn reclaim_deposit(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1051 + r * (272 ±0)`
-		//  Estimated: `37282 + r * (34675 ±0)`
-		// Minimum execution time: 33_920 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(22_100_902, 37282)
-			// Standard Error: 41_250
 			.saturating_add(Weight::from_parts(15_249_932, 0).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 34675).saturating_mul(r.into()))
 	}
 	/// Storage: Delegation DelegationNodes (r:1 w:0)
@@ -224,27 +226,29 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Delegation DelegationNodes (max_values: None, max_size: Some(32200), added: 34675, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1696`
-		//  Estimated: `39889`
-		// Minimum execution time: 27_538 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(29_455_000, 39889)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Delegation DelegationNodes (r:1 w:1)
 	/// Proof: Delegation DelegationNodes (max_values: None, max_size: Some(32200), added: 34675, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1117`
-		//  Estimated: `37282`
-		// Minimum execution time: 24_393 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(26_319_000, 37282)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 }
 
@@ -258,27 +262,29 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Delegation DelegationNodes (r:0 w:1)
 	/// Proof: Delegation DelegationNodes (max_values: None, max_size: Some(32200), added: 34675, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn create_hierarchy() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `896`
-		//  Estimated: `7725`
-		// Minimum execution time: 20_531 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(21_675_000, 7725)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: Delegation DelegationNodes (r:2 w:2)
 	/// Proof: Delegation DelegationNodes (max_values: None, max_size: Some(32200), added: 34675, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn add_delegation() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1048`
-		//  Estimated: `71957`
-		// Minimum execution time: 22_557 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(23_576_000, 71957)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: Delegation DelegationNodes (r:5 w:5)
 	/// Proof: Delegation DelegationNodes (max_values: None, max_size: Some(32200), added: 34675, mode: MaxEncodedLen)
@@ -326,19 +332,19 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Delegation DelegationHierarchies (r:1 w:1)
 	/// Proof: Delegation DelegationHierarchies (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:5)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `r` is `[1, 5]`.
 	fn remove_delegation(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1119 + r * (272 ±0)`
-		//  Estimated: `39837 + r * (34675 ±0)`
-		// Minimum execution time: 38_077 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(26_891_977, 39837)
-			// Standard Error: 40_521
 			.saturating_add(Weight::from_parts(15_313_221, 0).saturating_mul(r.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 34675).saturating_mul(r.into()))
 	}
 	/// Storage: Delegation DelegationNodes (r:6 w:6)
@@ -347,19 +353,19 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Delegation DelegationHierarchies (r:0 w:1)
 	/// Proof: Delegation DelegationHierarchies (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:5)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `r` is `[1, 5]`.
 	fn reclaim_deposit(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1051 + r * (272 ±0)`
-		//  Estimated: `37282 + r * (34675 ±0)`
-		// Minimum execution time: 33_920 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(22_100_902, 37282)
-			// Standard Error: 41_250
 			.saturating_add(Weight::from_parts(15_249_932, 0).saturating_mul(r.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 34675).saturating_mul(r.into()))
 	}
 	/// Storage: Delegation DelegationNodes (r:1 w:0)
@@ -408,26 +414,28 @@ impl WeightInfo for () {
 	/// Proof: Delegation DelegationNodes (max_values: None, max_size: Some(32200), added: 34675, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1696`
-		//  Estimated: `39889`
-		// Minimum execution time: 27_538 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(29_455_000, 39889)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Delegation DelegationNodes (r:1 w:1)
 	/// Proof: Delegation DelegationNodes (max_values: None, max_size: Some(32200), added: 34675, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1117`
-		//  Estimated: `37282`
-		// Minimum execution time: 24_393 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(26_319_000, 37282)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 }
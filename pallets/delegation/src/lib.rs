@@ -82,7 +82,7 @@ mod try_state;
 pub use crate::{access_control::DelegationAc, default_weights::WeightInfo, delegation_hierarchy::*, pallet::*};
 
 use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::Weight, traits::Get};
-use kilt_support::traits::{BalanceMigrationManager, StorageDepositCollector};
+use kilt_support::traits::{BalanceMigrationManager, DepositIndex, StorageDepositCollector};
 use parity_scale_codec::Encode;
 use sp_runtime::{traits::Hash, DispatchError};
 use sp_std::{marker::PhantomData, vec::Vec};
@@ -198,6 +198,10 @@ pub mod pallet {
 
 		/// Migration manager to handle new created entries
 		type BalanceMigrationManager: BalanceMigrationManager<AccountIdOf<Self>, BalanceOf<Self>>;
+
+		/// The index of deposits by owner, updated whenever a delegation deposit is
+		/// taken, changed or released.
+		type DepositIndex: DepositIndex<AccountIdOf<Self>, BalanceOf<Self>>;
 	}
 
 	#[pallet::pallet]
@@ -750,10 +754,12 @@ pub mod pallet {
 			hierarchy_owner: DelegatorIdOf<T>,
 			deposit_owner: AccountIdOf<T>,
 		) -> DispatchResult {
-			DelegationDepositCollector::<T>::create_deposit(deposit_owner.clone(), <T as Config>::Deposit::get())?;
+			let deposit =
+				DelegationDepositCollector::<T>::create_deposit(deposit_owner.clone(), <T as Config>::Deposit::get())?;
 			<T as Config>::BalanceMigrationManager::exclude_key_from_migration(&DelegationNodes::<T>::hashed_key_for(
 				root_id,
 			));
+			DelegationDepositCollector::<T>::index_deposit(&root_id, &deposit)?;
 
 			let root_node = DelegationNode::new_root_node(
 				root_id,
@@ -780,10 +786,11 @@ pub mod pallet {
 			mut parent_node: DelegationNodeOf<T>,
 			deposit_owner: AccountIdOf<T>,
 		) -> DispatchResult {
-			DelegationDepositCollector::<T>::create_deposit(deposit_owner, <T as Config>::Deposit::get())?;
+			let deposit = DelegationDepositCollector::<T>::create_deposit(deposit_owner, <T as Config>::Deposit::get())?;
 			<T as Config>::BalanceMigrationManager::exclude_key_from_migration(&DelegationNodes::<T>::hashed_key_for(
 				delegation_id,
 			));
+			DelegationDepositCollector::<T>::index_deposit(&delegation_id, &deposit)?;
 
 			// Add the new node as a child of that node
 			parent_node
@@ -999,6 +1006,7 @@ pub mod pallet {
 			let is_key_migrated = <T as Config>::BalanceMigrationManager::is_key_migrated(
 				&DelegationNodes::<T>::hashed_key_for(delegation),
 			);
+			DelegationDepositCollector::<T>::unindex_deposit(delegation, &delegation_node.deposit.owner)?;
 			if is_key_migrated {
 				DelegationDepositCollector::<T>::free_deposit(delegation_node.clone().deposit)?;
 			} else {
@@ -1022,6 +1030,7 @@ pub mod pallet {
 		for DelegationDepositCollector<T>
 	{
 		type Currency = <T as Config>::Currency;
+		type DepositIndex = T::DepositIndex;
 		type Reason = HoldReason;
 
 		fn get_hashed_key(key: &DelegationNodeIdOf<T>) -> Result<sp_std::vec::Vec<u8>, DispatchError> {
@@ -20,7 +20,7 @@ use frame_support::{
 	pallet_prelude::DispatchResult,
 	traits::{fungible::Inspect, ReservableCurrency},
 };
-use kilt_support::migration::switch_reserved_to_hold;
+use kilt_support::{migration::switch_reserved_to_hold, traits::DepositIndex};
 
 use crate::{AccountIdOf, Config, CurrencyOf, DelegationNodeIdOf, DelegationNodes, Error, HoldReason};
 
@@ -37,6 +37,18 @@ where
 	)
 }
 
+/// Add the deposit of the delegation node behind the provided key to the deposit
+/// index. Used to index deposits taken before the deposit index existed.
+pub fn index_deposit_for_delegation<T: Config>(key: &DelegationNodeIdOf<T>) -> DispatchResult {
+	let details = DelegationNodes::<T>::get(key).ok_or(Error::<T>::DelegationNotFound)?;
+	<T as Config>::DepositIndex::on_deposit_held(
+		&details.deposit.owner,
+		&DelegationNodes::<T>::hashed_key_for(key),
+		&details.deposit.amount,
+	);
+	Ok(())
+}
+
 #[cfg(test)]
 pub mod test {
 	use frame_support::{
@@ -315,6 +315,7 @@ pub(crate) mod runtime {
 		type AuthorizationId = DelegationNodeIdOf<Self>;
 		type AccessControl = DelegationAc<Self>;
 		type BalanceMigrationManager = ();
+		type DepositIndex = ();
 	}
 
 	parameter_types! {
@@ -345,6 +346,7 @@ pub(crate) mod runtime {
 		type Deposit = DepositMock;
 		type WeightInfo = ();
 		type BalanceMigrationManager = ();
+		type DepositIndex = ();
 	}
 
 	pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
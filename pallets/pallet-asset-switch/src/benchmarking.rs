@@ -51,22 +51,25 @@ impl BenchmarkHelper for () {
 
 #[instance_benchmarks(where LocalCurrencyBalanceOf<T, I>: Into<u128>)]
 mod benchmarks {
+	use frame_benchmarking::account;
 	use frame_support::traits::{
 		fungible::{Inspect as InspectFungible, Mutate as MutateFungible},
-		EnsureOrigin,
+		EnsureOrigin, Get,
 	};
 	use frame_system::RawOrigin;
-	use sp_runtime::traits::{TryConvert, Zero};
+	use sp_runtime::traits::{One, Saturating, TryConvert, Zero};
 	use sp_std::boxed::Box;
 	use xcm::{
-		v4::{Asset, AssetId, Fungibility, Junction, Junctions, Location},
+		v4::{Asset, AssetId, Error as XcmError, Fungibility, Junction, Junctions, Location, QueryId, Response},
 		VersionedAsset, VersionedAssetId, VersionedInteriorLocation, VersionedLocation,
 	};
-	use xcm_executor::traits::TransactAsset;
+	use xcm_executor::traits::{QueryHandler, TransactAsset};
 
 	use crate::{
 		benchmarking::{BenchmarkHelper, BenchmarkInfo, PartialBenchmarkInfo},
-		Call, Config, LocalCurrencyBalanceOf, Pallet, SwitchPairStatus,
+		AccountSwitchUsage, Call, Config, LocalCurrencyBalanceOf, Pallet, PendingSwitchOf, PendingSwitches,
+		SwitchPairHookRules, SwitchPairRateLimits, SwitchPairStatus, SwitchPairUsage, SwitchRateLimitsOf,
+		SwitchUsageOf,
 	};
 
 	fn default_info() -> BenchmarkInfo {
@@ -139,7 +142,7 @@ mod benchmarks {
 			Box::new(remote_xcm_fee.clone()),
 		)
 		.unwrap();
-		assert!(Pallet::<T, I>::switch_pair().is_some());
+		assert!(Pallet::<T, I>::switch_pair(&remote_asset_id).is_some());
 
 		BenchmarkInfo {
 			beneficiary,
@@ -149,6 +152,25 @@ mod benchmarks {
 		}
 	}
 
+	/// Set rate limits for the switch pair for `remote_asset_id` that are
+	/// never reached, so that switches are recorded against them.
+	fn set_unreachable_rate_limits<T, I>(remote_asset_id: &VersionedAssetId)
+	where
+		T: Config<I>,
+		I: 'static,
+	{
+		SwitchPairRateLimits::<T, I>::insert(
+			Pallet::<T, I>::switch_pair_key(remote_asset_id).unwrap(),
+			SwitchRateLimitsOf::<T> {
+				period: 100u32.into(),
+				max_amount: Some(u128::MAX),
+				max_switches: Some(u32::MAX),
+				max_amount_per_account: Some(u128::MAX),
+				max_switches_per_account: Some(u32::MAX),
+			},
+		);
+	}
+
 	#[benchmark]
 	fn set_switch_pair() {
 		let origin = <T as Config<I>>::SwitchOrigin::try_successful_origin().unwrap();
@@ -165,6 +187,7 @@ mod benchmarks {
 				Box::new(remote_xcm_fee),
 			)
 		};
+		let remote_asset_id_2 = remote_asset_id.clone();
 		let pool_account = Pallet::<T, I>::pool_account_id_for_remote_asset(&remote_asset_id).unwrap();
 		let local_currency_ed = <T as Config<I>>::LocalCurrency::minimum_balance();
 		<T as Config<I>>::LocalCurrency::set_balance(&pool_account, local_currency_ed);
@@ -180,7 +203,7 @@ mod benchmarks {
 			remote_xcm_fee,
 		);
 
-		assert!(Pallet::<T, I>::switch_pair().is_some());
+		assert!(Pallet::<T, I>::switch_pair(&remote_asset_id_2).is_some());
 	}
 
 	#[benchmark]
@@ -199,6 +222,7 @@ mod benchmarks {
 				Box::new(remote_xcm_fee),
 			)
 		};
+		let remote_asset_id_2 = remote_asset_id.clone();
 
 		#[extrinsic_call]
 		Pallet::<T, I>::force_set_switch_pair(
@@ -211,53 +235,74 @@ mod benchmarks {
 			remote_xcm_fee,
 		);
 
-		assert!(Pallet::<T, I>::switch_pair().is_some());
+		assert!(Pallet::<T, I>::switch_pair(&remote_asset_id_2).is_some());
 	}
 
 	#[benchmark]
 	fn force_unset_switch_pair() {
 		let origin: T::RuntimeOrigin = RawOrigin::Root.into();
-		configure_switch_pair::<T, I>();
+		let BenchmarkInfo { remote_asset_id, .. } = configure_switch_pair::<T, I>();
+		let remote_asset_id = Box::new(remote_asset_id);
+		let remote_asset_id_2 = remote_asset_id.clone();
 
 		#[extrinsic_call]
-		Pallet::<T, I>::force_unset_switch_pair(origin as T::RuntimeOrigin);
+		Pallet::<T, I>::force_unset_switch_pair(origin as T::RuntimeOrigin, remote_asset_id);
 
-		assert!(Pallet::<T, I>::switch_pair().is_none());
+		assert!(Pallet::<T, I>::switch_pair(&remote_asset_id_2).is_none());
 	}
 
 	#[benchmark]
 	fn pause_switch_pair() {
 		let origin = <T as Config<I>>::PauseOrigin::try_successful_origin().unwrap();
-		configure_switch_pair::<T, I>();
+		let BenchmarkInfo { remote_asset_id, .. } = configure_switch_pair::<T, I>();
+		let remote_asset_id = Box::new(remote_asset_id);
+		let remote_asset_id_2 = remote_asset_id.clone();
 
 		#[extrinsic_call]
-		Pallet::<T, I>::pause_switch_pair(origin as T::RuntimeOrigin);
+		Pallet::<T, I>::pause_switch_pair(origin as T::RuntimeOrigin, remote_asset_id);
 
-		assert_eq!(Pallet::<T, I>::switch_pair().unwrap().status, SwitchPairStatus::Paused);
+		assert_eq!(
+			Pallet::<T, I>::switch_pair(&remote_asset_id_2).unwrap().status,
+			SwitchPairStatus::Paused
+		);
 	}
 
 	#[benchmark]
 	fn resume_switch_pair() {
 		let origin = <T as Config<I>>::SwitchOrigin::try_successful_origin().unwrap();
-		configure_switch_pair::<T, I>();
+		let BenchmarkInfo { remote_asset_id, .. } = configure_switch_pair::<T, I>();
+		let remote_asset_id = Box::new(remote_asset_id);
+		let remote_asset_id_2 = remote_asset_id.clone();
 
 		#[extrinsic_call]
-		Pallet::<T, I>::resume_switch_pair(origin as T::RuntimeOrigin);
+		Pallet::<T, I>::resume_switch_pair(origin as T::RuntimeOrigin, remote_asset_id);
 
-		assert_eq!(Pallet::<T, I>::switch_pair().unwrap().status, SwitchPairStatus::Running);
+		assert_eq!(
+			Pallet::<T, I>::switch_pair(&remote_asset_id_2).unwrap().status,
+			SwitchPairStatus::Running
+		);
 	}
 
 	#[benchmark]
 	fn update_remote_xcm_fee() {
 		let origin = <T as Config<I>>::FeeOrigin::try_successful_origin().unwrap();
-		let BenchmarkInfo { remote_xcm_fee, .. } = configure_switch_pair::<T, I>();
+		let BenchmarkInfo {
+			remote_asset_id,
+			remote_xcm_fee,
+			..
+		} = configure_switch_pair::<T, I>();
+		let remote_asset_id = Box::new(remote_asset_id);
+		let remote_asset_id_2 = remote_asset_id.clone();
 		let remote_xcm_fee = Box::new(remote_xcm_fee);
 		let remote_xcm_fee_2 = remote_xcm_fee.clone();
 
 		#[extrinsic_call]
-		Pallet::<T, I>::update_remote_xcm_fee(origin as T::RuntimeOrigin, remote_xcm_fee);
+		Pallet::<T, I>::update_remote_xcm_fee(origin as T::RuntimeOrigin, remote_asset_id, remote_xcm_fee);
 
-		assert_eq!(Pallet::<T, I>::switch_pair().unwrap().remote_xcm_fee, *remote_xcm_fee_2);
+		assert_eq!(
+			Pallet::<T, I>::switch_pair(&remote_asset_id_2).unwrap().remote_xcm_fee,
+			*remote_xcm_fee_2
+		);
 	}
 
 	#[benchmark]
@@ -269,7 +314,11 @@ mod benchmarks {
 			remote_xcm_fee,
 			remote_asset_id,
 		} = configure_switch_pair::<T, I>();
-		Pallet::<T, I>::resume_switch_pair(<T as Config<I>>::SwitchOrigin::try_successful_origin().unwrap()).unwrap();
+		Pallet::<T, I>::resume_switch_pair(
+			<T as Config<I>>::SwitchOrigin::try_successful_origin().unwrap(),
+			Box::new(remote_asset_id.clone()),
+		)
+		.unwrap();
 		let account_id = <T as Config<I>>::SubmitterOrigin::ensure_origin(origin.clone()).unwrap();
 		let pool_account = Pallet::<T, I>::pool_account_id_for_remote_asset(&remote_asset_id).unwrap();
 		let minimum_balance = <T as Config<I>>::LocalCurrency::minimum_balance();
@@ -288,6 +337,8 @@ mod benchmarks {
 			)
 			.unwrap();
 		}
+		// Recording the switch against the rate limits is the worst case.
+		set_unreachable_rate_limits::<T, I>(&remote_asset_id);
 
 		// Push the beneficiary to the returned `destination` value.
 		let beneficiary = Box::new(
@@ -298,9 +349,10 @@ mod benchmarks {
 				.into(),
 		);
 		let amount = 1_000u32.into();
+		let remote_asset_id = Box::new(remote_asset_id);
 
 		#[extrinsic_call]
-		Pallet::<T, I>::switch(origin as T::RuntimeOrigin, amount, beneficiary);
+		Pallet::<T, I>::switch(origin as T::RuntimeOrigin, remote_asset_id, amount, beneficiary);
 
 		assert_eq!(
 			<T as Config<I>>::LocalCurrency::balance(&pool_account),
@@ -308,6 +360,190 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn set_switch_pair_rate_limits() {
+		let origin = <T as Config<I>>::SwitchOrigin::try_successful_origin().unwrap();
+		let account_id = account("account", 0, 0);
+		let BenchmarkInfo { remote_asset_id, .. } = configure_switch_pair::<T, I>();
+		let switch_pair_key = Pallet::<T, I>::switch_pair_key(&remote_asset_id).unwrap();
+		set_unreachable_rate_limits::<T, I>(&remote_asset_id);
+		Pallet::<T, I>::try_record_switch(&switch_pair_key, &account_id, 1_000).unwrap();
+		let remote_asset_id = Box::new(remote_asset_id);
+
+		// Removing the limits, which also removes the usage of the switch pair, is the
+		// worst case.
+		#[extrinsic_call]
+		Pallet::<T, I>::set_switch_pair_rate_limits(origin as T::RuntimeOrigin, remote_asset_id, None);
+
+		assert!(!SwitchPairRateLimits::<T, I>::contains_key(&switch_pair_key));
+		assert!(!SwitchPairUsage::<T, I>::contains_key(&switch_pair_key));
+	}
+
+	#[benchmark]
+	fn set_switch_pair_hook_rules() {
+		let origin = <T as Config<I>>::SwitchOrigin::try_successful_origin().unwrap();
+		let BenchmarkInfo { remote_asset_id, .. } = configure_switch_pair::<T, I>();
+		let remote_asset_id = Box::new(remote_asset_id);
+		let remote_asset_id_2 = remote_asset_id.clone();
+
+		// The rules type is defined by the runtime hooks, so we benchmark their removal,
+		// which performs the same storage accesses as setting them.
+		#[extrinsic_call]
+		Pallet::<T, I>::set_switch_pair_hook_rules(origin as T::RuntimeOrigin, remote_asset_id, None);
+
+		assert!(!SwitchPairHookRules::<T, I>::contains_key(
+			Pallet::<T, I>::switch_pair_key(&remote_asset_id_2).unwrap()
+		));
+	}
+
+	/// Perform a switch of `1_000` local tokens from a submitter whose balance
+	/// is set to ED + `1_000`, returning the remote asset ID of the switch
+	/// pair and the ID of the query for the switch outcome.
+	fn perform_switch<T, I>(origin: T::RuntimeOrigin) -> (VersionedAssetId, QueryId)
+	where
+		T: Config<I>,
+		I: 'static,
+		LocalCurrencyBalanceOf<T, I>: Into<u128>,
+	{
+		let BenchmarkInfo {
+			beneficiary,
+			destination,
+			remote_xcm_fee,
+			remote_asset_id,
+		} = configure_switch_pair::<T, I>();
+		Pallet::<T, I>::resume_switch_pair(
+			<T as Config<I>>::SwitchOrigin::try_successful_origin().unwrap(),
+			Box::new(remote_asset_id.clone()),
+		)
+		.unwrap();
+		let account_id = <T as Config<I>>::SubmitterOrigin::ensure_origin(origin.clone()).unwrap();
+		let pool_account = Pallet::<T, I>::pool_account_id_for_remote_asset(&remote_asset_id).unwrap();
+		let minimum_balance = <T as Config<I>>::LocalCurrency::minimum_balance();
+		// Set submitter balance to ED + 1_000 and pool balance to ED
+		{
+			<T as Config<I>>::LocalCurrency::set_balance(&account_id, minimum_balance + 1_000u32.into());
+			<T as Config<I>>::LocalCurrency::set_balance(&pool_account, minimum_balance);
+		}
+		// Set submitter's fungible balance to the XCM fee
+		let local_account_id_junction = <T as Config<I>>::AccountIdConverter::try_convert(account_id).unwrap();
+		{
+			<T as Config<I>>::AssetTransactor::deposit_asset(
+				&remote_xcm_fee.try_into().unwrap(),
+				&(local_account_id_junction.into()),
+				None,
+			)
+			.unwrap();
+		}
+		// Reverting the usage of a refunded switch is the worst case for settling it.
+		set_unreachable_rate_limits::<T, I>(&remote_asset_id);
+		let beneficiary = Box::new(
+			Location::try_from(destination)
+				.unwrap()
+				.appended_with(Junctions::try_from(beneficiary).unwrap())
+				.unwrap()
+				.into(),
+		);
+		Pallet::<T, I>::switch(origin, Box::new(remote_asset_id.clone()), 1_000u32.into(), beneficiary).unwrap();
+		let (switch_pair_key, query_id) = PendingSwitches::<T, I>::iter_keys().next().unwrap();
+		assert_eq!(
+			switch_pair_key,
+			Pallet::<T, I>::switch_pair_key(&remote_asset_id).unwrap()
+		);
+
+		(remote_asset_id, query_id)
+	}
+
+	#[benchmark]
+	fn settle_switch() {
+		let origin = <T as Config<I>>::SubmitterOrigin::try_successful_origin().unwrap();
+		let account_id = <T as Config<I>>::SubmitterOrigin::ensure_origin(origin.clone()).unwrap();
+		let (remote_asset_id, query_id) = perform_switch::<T, I>(origin.clone());
+		let switch_pair_key = Pallet::<T, I>::switch_pair_key(&remote_asset_id).unwrap();
+		// A failed switch, which requires a refund, is the worst case.
+		<T as Config<I>>::QueryHandler::expect_response(
+			query_id,
+			Response::ExecutionResult(Some((0, XcmError::Unimplemented))),
+		);
+		let remote_asset_id = Box::new(remote_asset_id);
+
+		#[extrinsic_call]
+		Pallet::<T, I>::settle_switch(origin as T::RuntimeOrigin, remote_asset_id, query_id);
+
+		assert!(!PendingSwitches::<T, I>::contains_key(switch_pair_key, query_id));
+		assert_eq!(
+			<T as Config<I>>::LocalCurrency::balance(&account_id),
+			<T as Config<I>>::LocalCurrency::minimum_balance() + 1_000u32.into()
+		);
+	}
+
+	#[benchmark]
+	fn force_settle_switch() {
+		let origin = <T as Config<I>>::SwitchOrigin::try_successful_origin().unwrap();
+		let submitter_origin = <T as Config<I>>::SubmitterOrigin::try_successful_origin().unwrap();
+		let account_id = <T as Config<I>>::SubmitterOrigin::ensure_origin(submitter_origin.clone()).unwrap();
+		let (remote_asset_id, query_id) = perform_switch::<T, I>(submitter_origin);
+		let switch_pair_key = Pallet::<T, I>::switch_pair_key(&remote_asset_id).unwrap();
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number()
+				.saturating_add(<T as Config<I>>::SwitchResponseTimeout::get())
+				.saturating_add(One::one()),
+		);
+		let remote_asset_id = Box::new(remote_asset_id);
+
+		// A refund is the worst case.
+		#[extrinsic_call]
+		Pallet::<T, I>::force_settle_switch(origin as T::RuntimeOrigin, remote_asset_id, query_id, false);
+
+		assert!(!PendingSwitches::<T, I>::contains_key(switch_pair_key, query_id));
+		assert_eq!(
+			<T as Config<I>>::LocalCurrency::balance(&account_id),
+			<T as Config<I>>::LocalCurrency::minimum_balance() + 1_000u32.into()
+		);
+	}
+
+	#[benchmark]
+	fn remove_switch_pair_storage(n: Linear<0, 1_000>) {
+		let origin = <T as Config<I>>::SubmitterOrigin::try_successful_origin().unwrap();
+		let account_id = <T as Config<I>>::SubmitterOrigin::ensure_origin(origin.clone()).unwrap();
+		let remote_asset_id = fill_with_defaults(<T as Config<I>>::BenchmarkHelper::setup()).remote_asset_id;
+		let switch_pair_key = Pallet::<T, I>::switch_pair_key(&remote_asset_id).unwrap();
+		(0..n).for_each(|query_id| {
+			PendingSwitches::<T, I>::insert(
+				&switch_pair_key,
+				QueryId::from(query_id),
+				PendingSwitchOf::<T, I> {
+					from: account_id.clone(),
+					amount: 1_000u32.into(),
+					requested_at: Zero::zero(),
+				},
+			);
+		});
+		let remote_asset_id = Box::new(remote_asset_id);
+
+		#[extrinsic_call]
+		Pallet::<T, I>::remove_switch_pair_storage(origin as T::RuntimeOrigin, remote_asset_id, n);
+
+		assert_eq!(PendingSwitches::<T, I>::iter_prefix(switch_pair_key).count(), 0);
+	}
+
+	#[benchmark]
+	fn remove_expired_switch_usage() {
+		let origin = <T as Config<I>>::SubmitterOrigin::try_successful_origin().unwrap();
+		let account_id = <T as Config<I>>::SubmitterOrigin::ensure_origin(origin.clone()).unwrap();
+		let BenchmarkInfo { remote_asset_id, .. } = configure_switch_pair::<T, I>();
+		let switch_pair_key = Pallet::<T, I>::switch_pair_key(&remote_asset_id).unwrap();
+		// Checking the usage against the rate limits is the worst case.
+		set_unreachable_rate_limits::<T, I>(&remote_asset_id);
+		AccountSwitchUsage::<T, I>::insert(&switch_pair_key, &account_id, SwitchUsageOf::<T>::default());
+		let remote_asset_id = Box::new(remote_asset_id);
+		let account_id_2 = account_id.clone();
+
+		#[extrinsic_call]
+		Pallet::<T, I>::remove_expired_switch_usage(origin as T::RuntimeOrigin, remote_asset_id, account_id_2);
+
+		assert!(!AccountSwitchUsage::<T, I>::contains_key(switch_pair_key, account_id));
+	}
+
 	#[cfg(test)]
 	mod benchmark_tests {
 		use crate::Pallet;
@@ -60,6 +60,12 @@ pub trait WeightInfo {
 	fn resume_switch_pair() -> Weight;
 	fn update_remote_xcm_fee() -> Weight;
 	fn switch() -> Weight;
+	fn set_switch_pair_rate_limits() -> Weight;
+	fn settle_switch() -> Weight;
+	fn set_switch_pair_hook_rules() -> Weight;
+	fn force_settle_switch() -> Weight;
+	fn remove_switch_pair_storage(n: u32, ) -> Weight;
+	fn remove_expired_switch_usage() -> Weight;
 }
 
 /// Weights for pallet_asset_switch using the Substrate node and recommended hardware.
@@ -69,35 +75,42 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `AssetSwitchPool1::SwitchPair` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:0)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::CounterForSwitchPairs` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::CounterForSwitchPairs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn set_switch_pair() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `128`
 		//  Estimated: `3597`
 		// Minimum execution time: 193_033 nanoseconds.
 		Weight::from_parts(200_247_000, 3597)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	/// Storage: `AssetSwitchPool1::SwitchPair` (r:0 w:1)
+	/// Storage: `AssetSwitchPool1::SwitchPair` (r:1 w:1)
 	/// Proof: `AssetSwitchPool1::SwitchPair` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::CounterForSwitchPairs` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::CounterForSwitchPairs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn force_set_switch_pair() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Estimated: `1657`
 		// Minimum execution time: 125_622 nanoseconds.
-		Weight::from_parts(126_582_000, 0)
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(126_582_000, 1657)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `AssetSwitchPool1::SwitchPair` (r:1 w:1)
 	/// Proof: `AssetSwitchPool1::SwitchPair` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::CounterForSwitchPairs` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::CounterForSwitchPairs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn force_unset_switch_pair() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `172`
 		//  Estimated: `1657`
 		// Minimum execution time: 145_639 nanoseconds.
 		Weight::from_parts(150_383_000, 1657)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `AssetSwitchPool1::SwitchPair` (r:1 w:1)
 	/// Proof: `AssetSwitchPool1::SwitchPair` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -146,14 +159,122 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Fungibles::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
 	/// Storage: `Fungibles::Account` (r:1 w:1)
 	/// Proof: `Fungibles::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::SwitchPairRateLimits` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairRateLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairHookRules` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairHookRules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+	/// Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::PendingSwitches` (r:0 w:1)
+	/// Proof: `AssetSwitchPool1::PendingSwitches` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn switch() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `837`
+		//  Measured:  `853`
+		//  Estimated: `6204`
+		// Minimum execution time: 1_331_482 nanoseconds.
+		Weight::from_parts(1_342_117_000, 6204)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `AssetSwitchPool1::SwitchPairs` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairRateLimits` (r:0 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairRateLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_switch_pair_rate_limits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `172`
+		//  Estimated: `1657`
+		// Minimum execution time: 104_981 nanoseconds.
+		Weight::from_parts(105_850_000, 1657)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetSwitchPool1::PendingSwitches` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::PendingSwitches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::Queries` (r:1 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairs` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairUsage` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairUsage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::AccountSwitchUsage` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::AccountSwitchUsage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn settle_switch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `701`
+		//  Estimated: `6204`
+		// Minimum execution time: 412_580 nanoseconds.
+		Weight::from_parts(420_133_000, 6204)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AssetSwitchPool1::SwitchPairs` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairHookRules` (r:0 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairHookRules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_switch_pair_hook_rules() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `172`
+		//  Estimated: `1657`
+		// Minimum execution time: 103_722 nanoseconds.
+		Weight::from_parts(104_915_000, 1657)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetSwitchPool1::PendingSwitches` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::PendingSwitches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairs` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairUsage` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairUsage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::AccountSwitchUsage` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::AccountSwitchUsage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn force_settle_switch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `653`
 		//  Estimated: `6204`
-		// Minimum execution time: 1_279_064 nanoseconds.
-		Weight::from_parts(1_289_730_000, 6204)
-			.saturating_add(T::DbWeight::get().reads(8_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+		// Minimum execution time: 357_204 nanoseconds.
+		Weight::from_parts(364_816_000, 6204)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AssetSwitchPool1::SwitchPairs` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::PendingSwitches` (r:0 w:1000)
+	/// Proof: `AssetSwitchPool1::PendingSwitches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::AccountSwitchUsage` (r:0 w:1000)
+	/// Proof: `AssetSwitchPool1::AccountSwitchUsage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn remove_switch_pair_storage(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `38 + n * (93 ±0)`
+		//  Estimated: `1523 + n * (2568 ±0)`
+		// Minimum execution time: 71_206 nanoseconds.
+		Weight::from_parts(73_581_000, 1523)
+			// Standard Error: 8_114
+			.saturating_add(Weight::from_parts(9_812_402, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2568).saturating_mul(n.into()))
+	}
+	/// Storage: `AssetSwitchPool1::AccountSwitchUsage` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::AccountSwitchUsage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairRateLimits` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairRateLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_expired_switch_usage() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `276`
+		//  Estimated: `3741`
+		// Minimum execution time: 98_517 nanoseconds.
+		Weight::from_parts(101_274_000, 3741)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 }
 
@@ -163,35 +284,42 @@ impl WeightInfo for () {
 	/// Proof: `AssetSwitchPool1::SwitchPair` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:0)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::CounterForSwitchPairs` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::CounterForSwitchPairs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn set_switch_pair() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `128`
 		//  Estimated: `3597`
 		// Minimum execution time: 193_033 nanoseconds.
 		Weight::from_parts(200_247_000, 3597)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
-	/// Storage: `AssetSwitchPool1::SwitchPair` (r:0 w:1)
+	/// Storage: `AssetSwitchPool1::SwitchPair` (r:1 w:1)
 	/// Proof: `AssetSwitchPool1::SwitchPair` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::CounterForSwitchPairs` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::CounterForSwitchPairs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn force_set_switch_pair() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Estimated: `1657`
 		// Minimum execution time: 125_622 nanoseconds.
-		Weight::from_parts(126_582_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(126_582_000, 1657)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `AssetSwitchPool1::SwitchPair` (r:1 w:1)
 	/// Proof: `AssetSwitchPool1::SwitchPair` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::CounterForSwitchPairs` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::CounterForSwitchPairs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn force_unset_switch_pair() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `172`
 		//  Estimated: `1657`
 		// Minimum execution time: 145_639 nanoseconds.
 		Weight::from_parts(150_383_000, 1657)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `AssetSwitchPool1::SwitchPair` (r:1 w:1)
 	/// Proof: `AssetSwitchPool1::SwitchPair` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -240,13 +368,121 @@ impl WeightInfo for () {
 	/// Proof: `Fungibles::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
 	/// Storage: `Fungibles::Account` (r:1 w:1)
 	/// Proof: `Fungibles::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::SwitchPairRateLimits` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairRateLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairHookRules` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairHookRules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+	/// Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::PendingSwitches` (r:0 w:1)
+	/// Proof: `AssetSwitchPool1::PendingSwitches` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn switch() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `837`
+		//  Measured:  `853`
+		//  Estimated: `6204`
+		// Minimum execution time: 1_331_482 nanoseconds.
+		Weight::from_parts(1_342_117_000, 6204)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `AssetSwitchPool1::SwitchPairs` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairRateLimits` (r:0 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairRateLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_switch_pair_rate_limits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `172`
+		//  Estimated: `1657`
+		// Minimum execution time: 104_981 nanoseconds.
+		Weight::from_parts(105_850_000, 1657)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetSwitchPool1::PendingSwitches` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::PendingSwitches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::Queries` (r:1 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairs` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairUsage` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairUsage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::AccountSwitchUsage` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::AccountSwitchUsage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn settle_switch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `701`
+		//  Estimated: `6204`
+		// Minimum execution time: 412_580 nanoseconds.
+		Weight::from_parts(420_133_000, 6204)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AssetSwitchPool1::SwitchPairs` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairHookRules` (r:0 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairHookRules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_switch_pair_hook_rules() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `172`
+		//  Estimated: `1657`
+		// Minimum execution time: 103_722 nanoseconds.
+		Weight::from_parts(104_915_000, 1657)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetSwitchPool1::PendingSwitches` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::PendingSwitches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairs` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairUsage` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairUsage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::AccountSwitchUsage` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::AccountSwitchUsage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn force_settle_switch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `653`
 		//  Estimated: `6204`
-		// Minimum execution time: 1_279_064 nanoseconds.
-		Weight::from_parts(1_289_730_000, 6204)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+		// Minimum execution time: 357_204 nanoseconds.
+		Weight::from_parts(364_816_000, 6204)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AssetSwitchPool1::SwitchPairs` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::PendingSwitches` (r:0 w:1000)
+	/// Proof: `AssetSwitchPool1::PendingSwitches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::AccountSwitchUsage` (r:0 w:1000)
+	/// Proof: `AssetSwitchPool1::AccountSwitchUsage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn remove_switch_pair_storage(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `38 + n * (93 ±0)`
+		//  Estimated: `1523 + n * (2568 ±0)`
+		// Minimum execution time: 71_206 nanoseconds.
+		Weight::from_parts(73_581_000, 1523)
+			// Standard Error: 8_114
+			.saturating_add(Weight::from_parts(9_812_402, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2568).saturating_mul(n.into()))
+	}
+	/// Storage: `AssetSwitchPool1::AccountSwitchUsage` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::AccountSwitchUsage` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairRateLimits` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairRateLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_expired_switch_usage() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `276`
+		//  Estimated: `3741`
+		// Minimum execution time: 98_517 nanoseconds.
+		Weight::from_parts(101_274_000, 3741)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 }
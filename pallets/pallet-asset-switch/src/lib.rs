@@ -19,13 +19,16 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![doc = include_str!("../README.md")]
 
+pub mod migrations;
 pub mod traits;
 pub mod xcm;
 
 mod default_weights;
 pub use default_weights::WeightInfo;
+mod rate_limits;
+pub use rate_limits::{SwitchRateLimit, SwitchRateLimits, SwitchUsage};
 mod switch;
-pub use switch::{SwitchPairInfo, SwitchPairStatus};
+pub use switch::{PendingSwitch, SwitchPairInfo, SwitchPairStatus};
 
 #[cfg(test)]
 mod mock;
@@ -39,14 +42,14 @@ mod benchmarking;
 #[cfg(feature = "runtime-benchmarks")]
 pub use benchmarking::{BenchmarkHelper, PartialBenchmarkInfo};
 
-use ::xcm::{VersionedAsset, VersionedAssetId, VersionedLocation};
+use ::xcm::{IntoVersion, VersionedAsset, VersionedAssetId, VersionedLocation};
 use frame_support::traits::{
-	fungible::Inspect,
+	fungible::{Inspect, Mutate},
 	tokens::{Fortitude, Preservation},
 	PalletInfoAccess,
 };
 use parity_scale_codec::{Decode, Encode};
-use sp_runtime::traits::TrailingZeroInput;
+use sp_runtime::{traits::TrailingZeroInput, DispatchError};
 use sp_std::boxed::Box;
 
 pub use crate::pallet::*;
@@ -56,7 +59,8 @@ const LOG_TARGET: &str = "runtime::pallet-asset-switch";
 #[frame_support::pallet]
 pub mod pallet {
 	use crate::{
-		switch::{NewSwitchPairInfo, SwitchPairInfo, SwitchPairStatus},
+		rate_limits::{SwitchRateLimit, SwitchRateLimits, SwitchUsage},
+		switch::{NewSwitchPairInfo, PendingSwitch, SwitchPairInfo, SwitchPairStatus},
 		traits::SwitchHooks,
 		WeightInfo, LOG_TARGET,
 	};
@@ -70,24 +74,31 @@ pub mod pallet {
 		},
 	};
 	use frame_system::{ensure_root, pallet_prelude::*};
-	use sp_runtime::traits::{TryConvert, Zero};
+	use sp_runtime::traits::{Saturating, TryConvert, Zero};
 	use sp_std::{boxed::Box, vec};
 	use xcm::{
 		v4::{
 			validate_send, Asset, AssetFilter, AssetId,
-			Instruction::{BuyExecution, DepositAsset, RefundSurplus, SetAppendix, TransferAsset, WithdrawAsset},
-			Junction, Location, SendXcm, WeightLimit, WildAsset, Xcm,
+			Instruction::{
+				BuyExecution, DepositAsset, RefundSurplus, ReportError, SetAppendix, TransferAsset, WithdrawAsset,
+			},
+			Junction, Location, QueryId, QueryResponseInfo, Response, SendXcm, WeightLimit, WildAsset, Xcm,
 		},
 		VersionedAsset, VersionedAssetId, VersionedLocation,
 	};
-	use xcm_executor::traits::TransactAsset;
+	use xcm_executor::traits::{QueryHandler, QueryResponseStatus, TransactAsset};
 
 	pub type LocalCurrencyBalanceOf<T, I> =
 		<<T as Config<I>>::LocalCurrency as InspectFungible<<T as frame_system::Config>::AccountId>>::Balance;
 	pub type SwitchPairInfoOf<T> = SwitchPairInfo<<T as frame_system::Config>::AccountId>;
 	pub type NewSwitchPairInfoOf<T> = NewSwitchPairInfo<<T as frame_system::Config>::AccountId>;
+	pub type SwitchRateLimitsOf<T> = SwitchRateLimits<BlockNumberFor<T>>;
+	pub type SwitchUsageOf<T> = SwitchUsage<BlockNumberFor<T>>;
+	pub type PendingSwitchOf<T, I> =
+		PendingSwitch<<T as frame_system::Config>::AccountId, LocalCurrencyBalanceOf<T, I>, BlockNumberFor<T>>;
+	pub type SwitchHookRulesOf<T, I> = <<T as Config<I>>::SwitchHooks as SwitchHooks<T, I>>::Rules;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::config]
 	pub trait Config<I: 'static = ()>: frame_system::Config {
@@ -102,8 +113,18 @@ pub mod pallet {
 		type FeeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// The local currency.
 		type LocalCurrency: MutateFungible<Self::AccountId>;
+		/// The maximum number of switch pairs that can exist at the same time.
+		///
+		/// This bounds the iteration over all switch pairs when matching and
+		/// charging the XCM fee assets of incoming XCM messages.
+		#[pallet::constant]
+		type MaxSwitchPairs: Get<u32>;
 		/// The origin that can pause switches in both directions.
 		type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The component used to register queries for the outcome of outgoing
+		/// switches on the remote reserve location, and to retrieve their
+		/// responses.
+		type QueryHandler: QueryHandler<BlockNumber = BlockNumberFor<Self>>;
 		/// The aggregate event type.
 		type RuntimeEvent: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// The origin that can request a switch of some local tokens for some
@@ -115,6 +136,10 @@ pub mod pallet {
 		/// The origin that can set a new switch pair, remove one, or resume
 		/// switches.
 		type SwitchOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The number of blocks after which the response for an outgoing switch
+		/// is not expected anymore.
+		#[pallet::constant]
+		type SwitchResponseTimeout: Get<BlockNumberFor<Self>>;
 		type WeightInfo: WeightInfo;
 		/// The XCM router to route XCM transfers to the configured reserve
 		/// location.
@@ -159,15 +184,80 @@ pub mod pallet {
 		/// A switch pair has suspended switches.
 		SwitchPairPaused { remote_asset_id: VersionedAssetId },
 		/// The XCM fee for the switch has been updated.
-		SwitchPairFeeUpdated { old: VersionedAsset, new: VersionedAsset },
+		SwitchPairFeeUpdated {
+			remote_asset_id: VersionedAssetId,
+			old: VersionedAsset,
+			new: VersionedAsset,
+		},
 		/// A switch of local -> remote asset has taken place.
 		LocalToRemoteSwitchExecuted {
+			remote_asset_id: VersionedAssetId,
 			from: T::AccountId,
 			to: VersionedLocation,
 			amount: LocalCurrencyBalanceOf<T, I>,
+			query_id: QueryId,
+		},
+		/// A local -> remote switch has been confirmed by the remote reserve
+		/// location.
+		LocalToRemoteSwitchConfirmed {
+			remote_asset_id: VersionedAssetId,
+			query_id: QueryId,
+		},
+		/// A local -> remote switch has failed on the remote reserve location,
+		/// and the local tokens have been refunded.
+		LocalToRemoteSwitchRefunded {
+			remote_asset_id: VersionedAssetId,
+			query_id: QueryId,
+			to: T::AccountId,
+			amount: LocalCurrencyBalanceOf<T, I>,
 		},
 		/// A switch of remote -> local asset has taken place.
-		RemoteToLocalSwitchExecuted { to: T::AccountId, amount: u128 },
+		RemoteToLocalSwitchExecuted {
+			remote_asset_id: VersionedAssetId,
+			to: T::AccountId,
+			amount: u128,
+		},
+		/// The rate limits for a switch pair have been set or removed.
+		SwitchPairRateLimitsUpdated {
+			remote_asset_id: VersionedAssetId,
+			limits: Option<SwitchRateLimitsOf<T>>,
+		},
+		/// A switch has reached one of the rate limits of a switch pair, so
+		/// that no further switches counting towards it are possible until
+		/// some of the usage falls out of the rolling window.
+		SwitchPairRateLimitReached {
+			remote_asset_id: VersionedAssetId,
+			account: T::AccountId,
+			limit: SwitchRateLimit,
+		},
+		/// A remote -> local switch has exceeded one of the rate limits of a
+		/// switch pair. The switch is executed nonetheless, since rejecting it
+		/// would trap the remote assets already transferred to the chain
+		/// sovereign account.
+		IncomingSwitchRateLimitExceeded {
+			remote_asset_id: VersionedAssetId,
+			account: T::AccountId,
+			limit: SwitchRateLimit,
+		},
+		/// The rules of the runtime-injected switch hooks for a switch pair
+		/// have been set or removed.
+		SwitchPairHookRulesUpdated {
+			remote_asset_id: VersionedAssetId,
+			rules: Option<SwitchHookRulesOf<T, I>>,
+		},
+		/// Some of the storage entries left behind by a removed switch pair
+		/// have been removed.
+		SwitchPairStorageRemoved {
+			remote_asset_id: VersionedAssetId,
+			removed_entries: u32,
+			complete: bool,
+		},
+		/// The expired rate limit usage of an account for a switch pair has
+		/// been removed.
+		AccountSwitchUsageRemoved {
+			remote_asset_id: VersionedAssetId,
+			account: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -182,15 +272,29 @@ pub mod pallet {
 		/// Failure in transferring the local tokens from the user's balance to
 		/// the switch pair pool account.
 		LocalPoolBalance,
+		/// No pending switch found for the provided query ID.
+		PendingSwitchNotFound,
 		/// The calculated switch pair pool account does not have enough local
 		/// tokens to cover the specified `circulating_supply`.
 		PoolInitialLiquidityRequirement,
+		/// The switch would exceed one of the rate limits of the switch pair.
+		RateLimitExceeded,
 		/// A switch pair has already been set.
 		SwitchPairAlreadyExisting,
 		/// The switch pair did not enable switches.
 		SwitchPairNotEnabled,
 		/// No switch pair found.
 		SwitchPairNotFound,
+		/// The maximum number of switch pairs has been reached.
+		TooManySwitchPairs,
+		/// The outcome of the switch has not been reported by the remote reserve
+		/// location yet, or the response is still expected.
+		SwitchResponsePending,
+		/// The switches of the account still count towards the rate limits of
+		/// the switch pair.
+		SwitchUsageNotExpired,
+		/// No rate limit usage found for the provided account.
+		SwitchUsageNotFound,
 		/// The user does not have enough local tokens to cover the requested
 		/// switch.
 		UserSwitchBalance,
@@ -202,9 +306,62 @@ pub mod pallet {
 		Internal,
 	}
 
+	/// The switch pairs, keyed by their remote asset ID converted to the latest
+	/// XCM version.
+	///
+	/// There are at most [`Config::MaxSwitchPairs`] switch pairs.
+	#[pallet::storage]
+	pub(crate) type SwitchPairs<T: Config<I>, I: 'static = ()> =
+		CountedStorageMap<_, Blake2_128Concat, VersionedAssetId, SwitchPairInfoOf<T>, OptionQuery>;
+
+	/// The rate limits of the switch pairs, keyed by the same key as
+	/// [`SwitchPairs`].
+	#[pallet::storage]
+	pub(crate) type SwitchPairRateLimits<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, VersionedAssetId, SwitchRateLimitsOf<T>, OptionQuery>;
+
+	/// The switches performed by all accounts within the current and previous
+	/// rate limit windows, for each rate-limited switch pair.
+	#[pallet::storage]
+	pub(crate) type SwitchPairUsage<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, VersionedAssetId, SwitchUsageOf<T>, ValueQuery>;
+
+	/// The switches performed by each account within the current and previous
+	/// rate limit windows, for each rate-limited switch pair.
+	///
+	/// Entries that no longer count towards the rate limits can be removed
+	/// with [`Pallet::remove_expired_switch_usage`], and all the entries of a
+	/// removed switch pair with [`Pallet::remove_switch_pair_storage`].
+	#[pallet::storage]
+	pub(crate) type AccountSwitchUsage<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		VersionedAssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		SwitchUsageOf<T>,
+		ValueQuery,
+	>;
+
+	/// The local -> remote switches whose outcome on the remote reserve
+	/// location has not been processed yet, keyed by the same key as
+	/// [`SwitchPairs`] and by the ID of the query for their outcome.
+	#[pallet::storage]
+	pub(crate) type PendingSwitches<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		VersionedAssetId,
+		Twox64Concat,
+		QueryId,
+		PendingSwitchOf<T, I>,
+		OptionQuery,
+	>;
+
+	/// The rules of the runtime-injected switch hooks for each switch pair,
+	/// keyed by the same key as [`SwitchPairs`].
 	#[pallet::storage]
-	#[pallet::getter(fn switch_pair)]
-	pub(crate) type SwitchPair<T: Config<I>, I: 'static = ()> = StorageValue<_, SwitchPairInfoOf<T>, OptionQuery>;
+	pub(crate) type SwitchPairHookRules<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, VersionedAssetId, SwitchHookRulesOf<T, I>, OptionQuery>;
 
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I>
@@ -227,8 +384,12 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::SwitchOrigin::ensure_origin(origin)?;
 
-			// 1. Verify switch pair has not already been set.
-			ensure!(!SwitchPair::<T, I>::exists(), Error::<T, I>::SwitchPairAlreadyExisting);
+			// 1. Verify a switch pair has not already been set for the remote asset.
+			let switch_pair_key = Self::switch_pair_key(&remote_asset_id)?;
+			ensure!(
+				!SwitchPairs::<T, I>::contains_key(switch_pair_key),
+				Error::<T, I>::SwitchPairAlreadyExisting
+			);
 
 			// 2. Verify that total issuance >= circulating supply and that the amount of
 			//    remote assets locked (total - circulating) is greater than the minimum
@@ -263,7 +424,7 @@ pub mod pallet {
 				remote_asset_ed,
 				*remote_xcm_fee,
 				pool_account,
-			);
+			)?;
 
 			Ok(())
 		}
@@ -298,7 +459,7 @@ pub mod pallet {
 				remote_asset_ed,
 				*remote_xcm_fee,
 				pool_account,
-			);
+			)?;
 
 			Ok(())
 		}
@@ -308,10 +469,10 @@ pub mod pallet {
 		/// See the crate's README for more.
 		#[pallet::call_index(2)]
 		#[pallet::weight(<T as Config<I>>::WeightInfo::force_unset_switch_pair())]
-		pub fn force_unset_switch_pair(origin: OriginFor<T>) -> DispatchResult {
+		pub fn force_unset_switch_pair(origin: OriginFor<T>, remote_asset_id: Box<VersionedAssetId>) -> DispatchResult {
 			ensure_root(origin)?;
 
-			Self::unset_switch_pair_bypass_checks();
+			Self::unset_switch_pair_bypass_checks(&remote_asset_id)?;
 
 			Ok(())
 		}
@@ -321,10 +482,10 @@ pub mod pallet {
 		/// See the crate's README for more.
 		#[pallet::call_index(3)]
 		#[pallet::weight(<T as Config<I>>::WeightInfo::pause_switch_pair())]
-		pub fn pause_switch_pair(origin: OriginFor<T>) -> DispatchResult {
+		pub fn pause_switch_pair(origin: OriginFor<T>, remote_asset_id: Box<VersionedAssetId>) -> DispatchResult {
 			T::PauseOrigin::ensure_origin(origin)?;
 
-			Self::set_switch_pair_status(SwitchPairStatus::Paused)?;
+			Self::set_switch_pair_status(&remote_asset_id, SwitchPairStatus::Paused)?;
 
 			Ok(())
 		}
@@ -334,10 +495,10 @@ pub mod pallet {
 		/// See the crate's README for more.
 		#[pallet::call_index(4)]
 		#[pallet::weight(<T as Config<I>>::WeightInfo::resume_switch_pair())]
-		pub fn resume_switch_pair(origin: OriginFor<T>) -> DispatchResult {
+		pub fn resume_switch_pair(origin: OriginFor<T>, remote_asset_id: Box<VersionedAssetId>) -> DispatchResult {
 			T::SwitchOrigin::ensure_origin(origin)?;
 
-			Self::set_switch_pair_status(SwitchPairStatus::Running)?;
+			Self::set_switch_pair_status(&remote_asset_id, SwitchPairStatus::Running)?;
 
 			Ok(())
 		}
@@ -347,16 +508,25 @@ pub mod pallet {
 		/// See the crate's README for more.
 		#[pallet::call_index(5)]
 		#[pallet::weight(<T as Config<I>>::WeightInfo::update_remote_xcm_fee())]
-		pub fn update_remote_xcm_fee(origin: OriginFor<T>, new: Box<VersionedAsset>) -> DispatchResult {
+		pub fn update_remote_xcm_fee(
+			origin: OriginFor<T>,
+			remote_asset_id: Box<VersionedAssetId>,
+			new: Box<VersionedAsset>,
+		) -> DispatchResult {
 			T::FeeOrigin::ensure_origin(origin)?;
 
-			SwitchPair::<T, I>::try_mutate(|entry| {
-				let SwitchPairInfoOf::<T> { remote_xcm_fee, .. } =
-					entry.as_mut().ok_or(Error::<T, I>::SwitchPairNotFound)?;
+			let switch_pair_key = Self::switch_pair_key(&remote_asset_id)?;
+			SwitchPairs::<T, I>::try_mutate(switch_pair_key, |entry| {
+				let SwitchPairInfoOf::<T> {
+					remote_asset_id,
+					remote_xcm_fee,
+					..
+				} = entry.as_mut().ok_or(Error::<T, I>::SwitchPairNotFound)?;
 				let old_remote_xcm_fee = remote_xcm_fee.clone();
 				*remote_xcm_fee = *new.clone();
 				if old_remote_xcm_fee != *new {
 					Self::deposit_event(Event::<T, I>::SwitchPairFeeUpdated {
+						remote_asset_id: remote_asset_id.clone(),
 						old: old_remote_xcm_fee,
 						new: *new,
 					});
@@ -374,14 +544,17 @@ pub mod pallet {
 		#[pallet::weight(<T as Config<I>>::WeightInfo::switch())]
 		pub fn switch(
 			origin: OriginFor<T>,
+			remote_asset_id: Box<VersionedAssetId>,
 			local_asset_amount: LocalCurrencyBalanceOf<T, I>,
 			beneficiary: Box<VersionedLocation>,
 		) -> DispatchResult {
 			let submitter = T::SubmitterOrigin::ensure_origin(origin)?;
 
-			// 1. Retrieve switch pair info from storage, else fail.
-			let switch_pair =
-				SwitchPair::<T, I>::get().ok_or(DispatchError::from(Error::<T, I>::SwitchPairNotFound))?;
+			// 1. Retrieve info of the switch pair for the remote asset from storage, else
+			//    fail.
+			let switch_pair_key = Self::switch_pair_key(&remote_asset_id)?;
+			let switch_pair = SwitchPairs::<T, I>::get(&switch_pair_key)
+				.ok_or(DispatchError::from(Error::<T, I>::SwitchPairNotFound))?;
 
 			// 2. Check if switches are enabled.
 			ensure!(
@@ -416,6 +589,9 @@ pub mod pallet {
 				Error::<T, I>::Liquidity
 			);
 
+			// 6. Verify and record the switch against the switch pair rate limits, if any.
+			Self::try_record_switch(&switch_pair_key, &submitter, remote_asset_amount_as_u128)?;
+
 			let asset_id_v4: AssetId = switch_pair.remote_asset_id.clone().try_into().map_err(|e| {
 				log::error!(
 					target: LOG_TARGET,
@@ -466,8 +642,29 @@ pub mod pallet {
 					DispatchError::from(Error::<T, I>::Xcm)
 				})?;
 
-			// 6. Compose and validate XCM message
+			// 7. Register a query for the outcome of the switch on the remote reserve
+			//    location, and compose and validate XCM message
+			let response_destination = <T::QueryHandler as QueryHandler>::UniversalLocation::get()
+				.invert_target(&destination_v4)
+				.map_err(|_| {
+					log::error!(
+						target: LOG_TARGET,
+						"Failed to invert remote reserve location {:?}",
+						destination_v4
+					);
+					DispatchError::from(Error::<T, I>::Xcm)
+				})?;
+			let query_id = T::QueryHandler::new_query(
+				destination_v4.clone(),
+				frame_system::Pallet::<T>::block_number().saturating_add(T::SwitchResponseTimeout::get()),
+				Location::here(),
+			);
 			let appendix: Xcm<()> = vec![
+				ReportError(QueryResponseInfo {
+					destination: response_destination,
+					query_id,
+					max_weight: Weight::zero(),
+				}),
 				RefundSurplus,
 				DepositAsset {
 					assets: AssetFilter::Wild(WildAsset::All),
@@ -481,11 +678,14 @@ pub mod pallet {
 					weight_limit: WeightLimit::Unlimited,
 					fees: remote_asset_fee_v4.clone(),
 				},
+				// The appendix must be set before the instructions whose failure it reports.
+				// Failures of the two instructions above cannot be reported, since the remote
+				// barrier requires them to come first, and are resolved once the query times out.
+				SetAppendix(appendix),
 				TransferAsset {
 					assets: (asset_id_v4, remote_asset_amount_as_u128).into(),
 					beneficiary: beneficiary_v4,
 				},
-				SetAppendix(appendix),
 			]
 			.into();
 			let xcm_ticket =
@@ -499,11 +699,18 @@ pub mod pallet {
 					DispatchError::from(Error::<T, I>::Xcm)
 				})?;
 
-			// 7. Call into hook pre-switch checks
-			T::SwitchHooks::pre_local_to_remote_switch(&submitter, &beneficiary, local_asset_amount)
-				.map_err(|e| DispatchError::from(Error::<T, I>::Hook(e.into())))?;
+			// 8. Call into hook pre-switch checks
+			let hook_rules = SwitchPairHookRules::<T, I>::get(&switch_pair_key);
+			T::SwitchHooks::pre_local_to_remote_switch(
+				&switch_pair_key,
+				hook_rules.as_ref(),
+				&submitter,
+				&beneficiary,
+				local_asset_amount,
+			)
+			.map_err(|e| DispatchError::from(Error::<T, I>::Hook(e.into())))?;
 
-			// 8. Transfer funds from user to pool
+			// 9. Transfer funds from user to pool
 			let transferred_amount = T::LocalCurrency::transfer(
 				&submitter,
 				&switch_pair.pool_account,
@@ -520,7 +727,7 @@ pub mod pallet {
 				return Err(Error::<T, I>::Internal.into());
 			}
 
-			// 9. Take XCM fee from submitter.
+			// 10. Take XCM fee from submitter.
 			let withdrawn_fees = T::AssetTransactor::withdraw_asset(&remote_asset_fee_v4, &submitter_as_location, None)
 				.map_err(|e| {
 					log::info!(
@@ -542,14 +749,14 @@ pub mod pallet {
 				return Err(DispatchError::from(Error::<T, I>::Internal));
 			}
 
-			// 10. Send XCM out
+			// 11. Send XCM out
 			T::XcmRouter::deliver(xcm_ticket.0).map_err(|e| {
 				log::info!("Failed to deliver ticket with error {:?}", e);
 				DispatchError::from(Error::<T, I>::Xcm)
 			})?;
 
-			// 11. Update remote asset balance and circulating supply.
-			SwitchPair::<T, I>::try_mutate(|entry| {
+			// 12. Update remote asset balance and circulating supply.
+			SwitchPairs::<T, I>::try_mutate(&switch_pair_key, |entry| {
 				let Some(switch_pair_info) = entry.as_mut() else {
 					log::error!(target: LOG_TARGET, "Failed to borrow stored switch pair info as mut.");
 					return Err(Error::<T, I>::Internal);
@@ -567,14 +774,258 @@ pub mod pallet {
 				Ok(())
 			})?;
 
-			// 12. Call into hook post-switch checks
-			T::SwitchHooks::post_local_to_remote_switch(&submitter, &beneficiary, local_asset_amount)
-				.map_err(|e| DispatchError::from(Error::<T, I>::Hook(e.into())))?;
+			// 13. Track the switch until its outcome is reported by the remote reserve
+			//     location.
+			PendingSwitches::<T, I>::insert(
+				&switch_pair_key,
+				query_id,
+				PendingSwitchOf::<T, I> {
+					from: submitter.clone(),
+					amount: local_asset_amount,
+					requested_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+
+			// 14. Call into hook post-switch checks
+			T::SwitchHooks::post_local_to_remote_switch(
+				&switch_pair_key,
+				hook_rules.as_ref(),
+				&submitter,
+				&beneficiary,
+				local_asset_amount,
+			)
+			.map_err(|e| DispatchError::from(Error::<T, I>::Hook(e.into())))?;
 
 			Self::deposit_event(Event::<T, I>::LocalToRemoteSwitchExecuted {
+				remote_asset_id: switch_pair.remote_asset_id,
 				from: submitter,
 				to: *beneficiary,
 				amount: local_asset_amount,
+				query_id,
+			});
+
+			Ok(())
+		}
+
+		/// Set or remove the rate limits for a switch pair.
+		///
+		/// See the crate's README for more.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_switch_pair_rate_limits())]
+		pub fn set_switch_pair_rate_limits(
+			origin: OriginFor<T>,
+			remote_asset_id: Box<VersionedAssetId>,
+			limits: Option<SwitchRateLimitsOf<T>>,
+		) -> DispatchResult {
+			T::SwitchOrigin::ensure_origin(origin)?;
+
+			let switch_pair_key = Self::switch_pair_key(&remote_asset_id)?;
+			let switch_pair = SwitchPairs::<T, I>::get(&switch_pair_key).ok_or(Error::<T, I>::SwitchPairNotFound)?;
+
+			SwitchPairRateLimits::<T, I>::set(&switch_pair_key, limits.clone());
+			if limits.is_none() {
+				SwitchPairUsage::<T, I>::remove(&switch_pair_key);
+			}
+
+			Self::deposit_event(Event::<T, I>::SwitchPairRateLimitsUpdated {
+				remote_asset_id: switch_pair.remote_asset_id,
+				limits,
+			});
+
+			Ok(())
+		}
+
+		/// Process the outcome of a local -> remote switch as reported by the
+		/// remote reserve location, refunding the local tokens if the switch
+		/// failed.
+		///
+		/// See the crate's README for more.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::settle_switch())]
+		pub fn settle_switch(
+			origin: OriginFor<T>,
+			remote_asset_id: Box<VersionedAssetId>,
+			query_id: QueryId,
+		) -> DispatchResult {
+			T::SubmitterOrigin::ensure_origin(origin)?;
+
+			let switch_pair_key = Self::switch_pair_key(&remote_asset_id)?;
+			let pending_switch =
+				PendingSwitches::<T, I>::get(&switch_pair_key, query_id).ok_or(Error::<T, I>::PendingSwitchNotFound)?;
+
+			let response = match T::QueryHandler::take_response(query_id) {
+				QueryResponseStatus::Ready { response, .. } => Ok(response),
+				QueryResponseStatus::Pending { .. } => Err(Error::<T, I>::SwitchResponsePending),
+				status => {
+					log::error!(
+						target: LOG_TARGET,
+						"Failed to retrieve response for query {:?} with status {:?}",
+						query_id,
+						status
+					);
+					Err(Error::<T, I>::Xcm)
+				}
+			}?;
+
+			let succeeded = match response {
+				Response::ExecutionResult(None) => true,
+				Response::ExecutionResult(Some(error)) => {
+					log::info!(
+						target: LOG_TARGET,
+						"Switch with query ID {:?} failed on remote with error {:?}",
+						query_id,
+						error
+					);
+					false
+				}
+				response => {
+					log::error!(
+						target: LOG_TARGET,
+						"Unexpected response {:?} for query {:?}",
+						response,
+						query_id
+					);
+					return Err(Error::<T, I>::Xcm.into());
+				}
+			};
+			Self::settle_pending_switch(switch_pair_key, query_id, pending_switch, succeeded)
+		}
+
+		/// Resolve a local -> remote switch whose outcome has not been reported
+		/// by the remote reserve location within the configured timeout,
+		/// either confirming it or refunding the local tokens.
+		///
+		/// See the crate's README for more.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::force_settle_switch())]
+		pub fn force_settle_switch(
+			origin: OriginFor<T>,
+			remote_asset_id: Box<VersionedAssetId>,
+			query_id: QueryId,
+			succeeded: bool,
+		) -> DispatchResult {
+			T::SwitchOrigin::ensure_origin(origin)?;
+
+			let switch_pair_key = Self::switch_pair_key(&remote_asset_id)?;
+			let pending_switch =
+				PendingSwitches::<T, I>::get(&switch_pair_key, query_id).ok_or(Error::<T, I>::PendingSwitchNotFound)?;
+
+			let timeout = pending_switch
+				.requested_at
+				.saturating_add(T::SwitchResponseTimeout::get());
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > timeout,
+				Error::<T, I>::SwitchResponsePending
+			);
+
+			Self::settle_pending_switch(switch_pair_key, query_id, pending_switch, succeeded)
+		}
+
+		/// Remove the pending switches and the rate limit usage left behind by
+		/// a removed switch pair, up to `limit` entries.
+		///
+		/// See the crate's README for more.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::remove_switch_pair_storage(*limit))]
+		pub fn remove_switch_pair_storage(
+			origin: OriginFor<T>,
+			remote_asset_id: Box<VersionedAssetId>,
+			limit: u32,
+		) -> DispatchResult {
+			T::SubmitterOrigin::ensure_origin(origin)?;
+
+			let switch_pair_key = Self::switch_pair_key(&remote_asset_id)?;
+			ensure!(
+				!SwitchPairs::<T, I>::contains_key(&switch_pair_key),
+				Error::<T, I>::SwitchPairAlreadyExisting
+			);
+
+			// Pending switches are removed first, and the usage only afterwards
+			// with the remaining limit.
+			let pending_switches_removal = PendingSwitches::<T, I>::clear_prefix(&switch_pair_key, limit, None);
+			let (removed_entries, complete) = if pending_switches_removal.maybe_cursor.is_some() {
+				(pending_switches_removal.unique, false)
+			} else {
+				let usage_removal = AccountSwitchUsage::<T, I>::clear_prefix(
+					&switch_pair_key,
+					limit.saturating_sub(pending_switches_removal.unique),
+					None,
+				);
+				(
+					pending_switches_removal.unique.saturating_add(usage_removal.unique),
+					usage_removal.maybe_cursor.is_none(),
+				)
+			};
+
+			Self::deposit_event(Event::<T, I>::SwitchPairStorageRemoved {
+				remote_asset_id: switch_pair_key,
+				removed_entries,
+				complete,
+			});
+
+			Ok(())
+		}
+
+		/// Remove the rate limit usage of an account for a switch pair once it
+		/// no longer counts towards the rate limits of the switch pair.
+		///
+		/// See the crate's README for more.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::remove_expired_switch_usage())]
+		pub fn remove_expired_switch_usage(
+			origin: OriginFor<T>,
+			remote_asset_id: Box<VersionedAssetId>,
+			account: T::AccountId,
+		) -> DispatchResult {
+			T::SubmitterOrigin::ensure_origin(origin)?;
+
+			let switch_pair_key = Self::switch_pair_key(&remote_asset_id)?;
+			ensure!(
+				AccountSwitchUsage::<T, I>::contains_key(&switch_pair_key, &account),
+				Error::<T, I>::SwitchUsageNotFound
+			);
+			let usage = AccountSwitchUsage::<T, I>::get(&switch_pair_key, &account);
+
+			// The usage of switch pairs without rate limits does not count towards
+			// any limit.
+			if let Some(limits) = SwitchPairRateLimits::<T, I>::get(&switch_pair_key) {
+				ensure!(
+					usage.is_expired(frame_system::Pallet::<T>::block_number(), limits.period),
+					Error::<T, I>::SwitchUsageNotExpired
+				);
+			}
+
+			AccountSwitchUsage::<T, I>::remove(&switch_pair_key, &account);
+
+			Self::deposit_event(Event::<T, I>::AccountSwitchUsageRemoved {
+				remote_asset_id: switch_pair_key,
+				account,
+			});
+
+			Ok(())
+		}
+
+		/// Set or remove the rules of the runtime-injected switch hooks for a
+		/// switch pair.
+		///
+		/// See the crate's README for more.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_switch_pair_hook_rules())]
+		pub fn set_switch_pair_hook_rules(
+			origin: OriginFor<T>,
+			remote_asset_id: Box<VersionedAssetId>,
+			rules: Option<SwitchHookRulesOf<T, I>>,
+		) -> DispatchResult {
+			T::SwitchOrigin::ensure_origin(origin)?;
+
+			let switch_pair_key = Self::switch_pair_key(&remote_asset_id)?;
+			let switch_pair = SwitchPairs::<T, I>::get(&switch_pair_key).ok_or(Error::<T, I>::SwitchPairNotFound)?;
+
+			SwitchPairHookRules::<T, I>::set(&switch_pair_key, rules.clone());
+
+			Self::deposit_event(Event::<T, I>::SwitchPairHookRulesUpdated {
+				remote_asset_id: switch_pair.remote_asset_id,
+				rules,
 			});
 
 			Ok(())
@@ -597,7 +1048,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		remote_asset_ed: u128,
 		remote_xcm_fee: VersionedAsset,
 		pool_account: T::AccountId,
-	) {
+	) -> Result<(), Error<T, I>> {
 		debug_assert!(
 			remote_asset_total_supply >= remote_asset_circulating_supply.saturating_add(remote_asset_ed),
 			"Provided total issuance smaller than circulating supply + remote asset ED."
@@ -614,7 +1065,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			status: Default::default(),
 		});
 
-		SwitchPair::<T, I>::set(Some(switch_pair_info));
+		let switch_pair_key = Self::switch_pair_key(&remote_asset_id)?;
+		// Replacing an existing switch pair does not change the number of switch pairs.
+		let is_new_switch_pair = !SwitchPairs::<T, I>::contains_key(&switch_pair_key);
+		ensure!(
+			!is_new_switch_pair || SwitchPairs::<T, I>::count() < T::MaxSwitchPairs::get(),
+			Error::<T, I>::TooManySwitchPairs
+		);
+		SwitchPairs::<T, I>::insert(switch_pair_key, switch_pair_info);
 
 		Self::deposit_event(Event::<T, I>::SwitchPairCreated {
 			remote_asset_circulating_supply,
@@ -625,19 +1083,30 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			remote_xcm_fee: Box::new(remote_xcm_fee),
 			remote_asset_total_supply,
 		});
+
+		Ok(())
 	}
 
-	fn unset_switch_pair_bypass_checks() {
-		let switch_pair = SwitchPair::<T, I>::take();
+	fn unset_switch_pair_bypass_checks(remote_asset_id: &VersionedAssetId) -> Result<(), Error<T, I>> {
+		let switch_pair_key = Self::switch_pair_key(remote_asset_id)?;
+		let switch_pair = SwitchPairs::<T, I>::take(&switch_pair_key);
+		SwitchPairRateLimits::<T, I>::remove(&switch_pair_key);
+		SwitchPairUsage::<T, I>::remove(&switch_pair_key);
+		SwitchPairHookRules::<T, I>::remove(&switch_pair_key);
 		if let Some(switch_pair) = switch_pair {
 			Self::deposit_event(Event::<T, I>::SwitchPairRemoved {
 				remote_asset_id: switch_pair.remote_asset_id,
 			});
 		};
+		Ok(())
 	}
 
-	fn set_switch_pair_status(new_status: SwitchPairStatus) -> Result<(), Error<T, I>> {
-		SwitchPair::<T, I>::try_mutate(|entry| {
+	fn set_switch_pair_status(
+		remote_asset_id: &VersionedAssetId,
+		new_status: SwitchPairStatus,
+	) -> Result<(), Error<T, I>> {
+		let switch_pair_key = Self::switch_pair_key(remote_asset_id)?;
+		SwitchPairs::<T, I>::try_mutate(switch_pair_key, |entry| {
 			let SwitchPairInfoOf::<T> {
 				remote_asset_id,
 				status,
@@ -664,12 +1133,216 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 }
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The key under which the switch pair for `remote_asset_id` is stored,
+	/// i.e., the asset ID converted to the latest XCM version, so that the
+	/// same asset is always matched regardless of the version it is provided
+	/// in.
+	pub(crate) fn switch_pair_key(remote_asset_id: &VersionedAssetId) -> Result<VersionedAssetId, Error<T, I>> {
+		remote_asset_id.clone().into_latest().map_err(|e| {
+			log::info!(
+				target: LOG_TARGET,
+				"Failed to convert remote asset ID {:?} into latest version with error {:?}",
+				remote_asset_id,
+				e
+			);
+			Error::<T, I>::InvalidInput
+		})
+	}
+
+	/// Account for a local -> remote switch of `amount` tokens by `account`
+	/// against the rate limits of the switch pair stored under
+	/// `switch_pair_key`, failing if any of the limits would be exceeded.
+	///
+	/// This is a no-op for switch pairs without rate limits.
+	pub(crate) fn try_record_switch(
+		switch_pair_key: &VersionedAssetId,
+		account: &T::AccountId,
+		amount: u128,
+	) -> Result<(), Error<T, I>> {
+		let Some(limits) = SwitchPairRateLimits::<T, I>::get(switch_pair_key) else {
+			return Ok(());
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+
+		let usage = SwitchPairUsage::<T, I>::get(switch_pair_key)
+			.with_switch(now, limits.period, amount)
+			.ok_or(Error::<T, I>::RateLimitExceeded)?;
+		let account_usage = AccountSwitchUsage::<T, I>::get(switch_pair_key, account)
+			.with_switch(now, limits.period, amount)
+			.ok_or(Error::<T, I>::RateLimitExceeded)?;
+
+		if let Some(limit) = limits.first_exceeded_limit(&usage, &account_usage, now) {
+			log::info!(
+				target: LOG_TARGET,
+				"Switch of {:?} tokens by {:?} exceeds the {:?} limit.",
+				amount,
+				account,
+				limit
+			);
+			return Err(Error::<T, I>::RateLimitExceeded);
+		}
+
+		limits.reached_limits(&usage, &account_usage, now).for_each(|limit| {
+			Self::deposit_event(Event::<T, I>::SwitchPairRateLimitReached {
+				remote_asset_id: switch_pair_key.clone(),
+				account: account.clone(),
+				limit,
+			});
+		});
+
+		SwitchPairUsage::<T, I>::insert(switch_pair_key, usage);
+		AccountSwitchUsage::<T, I>::insert(switch_pair_key, account, account_usage);
+
+		Ok(())
+	}
+
+	/// Account for a remote -> local switch of `amount` tokens to `account`
+	/// against the rate limits of the switch pair stored under
+	/// `switch_pair_key`.
+	///
+	/// Unlike local -> remote switches, the switch is never rejected, and an
+	/// event is emitted for each limit it exceeds instead. This is a no-op for
+	/// switch pairs without rate limits.
+	pub(crate) fn record_incoming_switch(switch_pair_key: &VersionedAssetId, account: &T::AccountId, amount: u128) {
+		let Some(limits) = SwitchPairRateLimits::<T, I>::get(switch_pair_key) else {
+			return;
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+
+		let usage = SwitchPairUsage::<T, I>::get(switch_pair_key).with_saturating_switch(now, limits.period, amount);
+		let account_usage =
+			AccountSwitchUsage::<T, I>::get(switch_pair_key, account).with_saturating_switch(now, limits.period, amount);
+
+		limits.exceeded_limits(&usage, &account_usage, now).for_each(|limit| {
+			log::info!(
+				target: LOG_TARGET,
+				"Incoming switch of {:?} tokens to {:?} exceeds the {:?} limit.",
+				amount,
+				account,
+				limit
+			);
+			Self::deposit_event(Event::<T, I>::IncomingSwitchRateLimitExceeded {
+				remote_asset_id: switch_pair_key.clone(),
+				account: account.clone(),
+				limit,
+			});
+		});
+		limits.reached_limits(&usage, &account_usage, now).for_each(|limit| {
+			Self::deposit_event(Event::<T, I>::SwitchPairRateLimitReached {
+				remote_asset_id: switch_pair_key.clone(),
+				account: account.clone(),
+				limit,
+			});
+		});
+
+		SwitchPairUsage::<T, I>::insert(switch_pair_key, usage);
+		AccountSwitchUsage::<T, I>::insert(switch_pair_key, account, account_usage);
+	}
+
+	/// Remove a pending switch, refunding it if it did not succeed on the remote
+	/// reserve location.
+	fn settle_pending_switch(
+		switch_pair_key: VersionedAssetId,
+		query_id: QueryId,
+		pending_switch: PendingSwitchOf<T, I>,
+		succeeded: bool,
+	) -> DispatchResult
+	where
+		LocalCurrencyBalanceOf<T, I>: Into<u128>,
+	{
+		if succeeded {
+			Self::deposit_event(Event::<T, I>::LocalToRemoteSwitchConfirmed {
+				remote_asset_id: switch_pair_key.clone(),
+				query_id,
+			});
+		} else {
+			Self::refund_switch(&switch_pair_key, &pending_switch)?;
+			Self::deposit_event(Event::<T, I>::LocalToRemoteSwitchRefunded {
+				remote_asset_id: switch_pair_key.clone(),
+				query_id,
+				to: pending_switch.from,
+				amount: pending_switch.amount,
+			});
+		}
+		PendingSwitches::<T, I>::remove(&switch_pair_key, query_id);
+
+		Ok(())
+	}
+
+	/// Move the local tokens of a failed switch from the switch pair pool
+	/// account back to the account that submitted it, account for the
+	/// remote assets that were not transferred out of the chain sovereign
+	/// account, and remove the switch from the rate limits usage.
+	fn refund_switch(
+		switch_pair_key: &VersionedAssetId,
+		pending_switch: &PendingSwitchOf<T, I>,
+	) -> Result<(), DispatchError>
+	where
+		LocalCurrencyBalanceOf<T, I>: Into<u128>,
+	{
+		let amount_as_u128 = pending_switch.amount.into();
+		SwitchPairs::<T, I>::try_mutate(switch_pair_key, |entry| {
+			let switch_pair_info = entry.as_mut().ok_or(Error::<T, I>::SwitchPairNotFound)?;
+			switch_pair_info
+				.try_process_incoming_switch(amount_as_u128)
+				.map_err(|_| {
+					log::error!(
+						target: LOG_TARGET,
+						"Failed to account for refund of {:?} tokens.",
+						pending_switch.amount
+					);
+					Error::<T, I>::Internal
+				})?;
+			T::LocalCurrency::transfer(
+				&switch_pair_info.pool_account,
+				&pending_switch.from,
+				pending_switch.amount,
+				Preservation::Preserve,
+			)?;
+			Ok::<_, DispatchError>(())
+		})?;
+
+		if let Some(limits) = SwitchPairRateLimits::<T, I>::get(switch_pair_key) {
+			let now = frame_system::Pallet::<T>::block_number();
+			SwitchPairUsage::<T, I>::mutate_exists(switch_pair_key, |usage| {
+				*usage = usage
+					.take()
+					.map(|usage| usage.without_switch(now, limits.period, pending_switch.requested_at, amount_as_u128));
+			});
+			AccountSwitchUsage::<T, I>::mutate_exists(switch_pair_key, &pending_switch.from, |usage| {
+				*usage = usage
+					.take()
+					.map(|usage| usage.without_switch(now, limits.period, pending_switch.requested_at, amount_as_u128));
+			});
+		}
+
+		Ok(())
+	}
+
+	/// The switch pair for `remote_asset_id`, if any.
+	pub fn switch_pair(remote_asset_id: &VersionedAssetId) -> Option<SwitchPairInfoOf<T>> {
+		let switch_pair_key = Self::switch_pair_key(remote_asset_id).ok()?;
+		SwitchPairs::<T, I>::get(switch_pair_key)
+	}
+
+	/// The rules of the runtime-injected switch hooks for the switch pair for
+	/// `remote_asset_id`, if any.
+	pub fn switch_pair_hook_rules(remote_asset_id: &VersionedAssetId) -> Option<SwitchHookRulesOf<T, I>> {
+		let switch_pair_key = Self::switch_pair_key(remote_asset_id).ok()?;
+		SwitchPairHookRules::<T, I>::get(switch_pair_key)
+	}
+
 	/// Derive an `AccountId` for the provided `remote_asset_id` and the
 	/// pallet's name as configured in the runtime.
+	///
+	/// The account is derived from the asset ID converted to the latest XCM
+	/// version, so that the same asset always maps to the same account
+	/// regardless of the version it is provided in.
 	pub fn pool_account_id_for_remote_asset(remote_asset_id: &VersionedAssetId) -> Result<T::AccountId, Error<T, I>> {
+		let switch_pair_key = Self::switch_pair_key(remote_asset_id)?;
 		let pallet_name = <Pallet<T, I> as PalletInfoAccess>::name();
 		let pallet_name_hashed = sp_io::hashing::blake2_256(pallet_name.as_bytes());
-		let hash_input = (pallet_name_hashed, b'.', remote_asset_id.clone()).encode();
+		let hash_input = (pallet_name_hashed, b'.', switch_pair_key).encode();
 		let hash_output = sp_io::hashing::blake2_256(hash_input.as_slice());
 		T::AccountId::decode(&mut TrailingZeroInput::new(hash_output.as_slice())).map_err(|e| {
 			log::error!(
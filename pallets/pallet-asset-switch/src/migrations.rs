@@ -0,0 +1,185 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	pallet_prelude::OptionQuery,
+	storage_alias,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_core::Get;
+use sp_std::marker::PhantomData;
+
+use crate::{Config, Pallet, SwitchPairInfoOf, SwitchPairs};
+
+const LOG_TARGET: &str = "migration::pallet-asset-switch::MigrateToMultipleSwitchPairs";
+
+const TARGET_STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+/// The single switch pair, as stored before the pallet supported multiple
+/// switch pairs.
+#[storage_alias]
+pub(crate) type SwitchPair<T: Config<I>, I: 'static> = StorageValue<Pallet<T, I>, SwitchPairInfoOf<T>, OptionQuery>;
+
+/// Moves the single switch pair stored in `SwitchPair`, if any, into the
+/// `SwitchPairs` map, keyed by its remote asset ID converted to the latest XCM
+/// version. Inserting into the counted map also initializes its counter.
+pub struct MigrateToMultipleSwitchPairs<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T, I> OnRuntimeUpgrade for MigrateToMultipleSwitchPairs<T, I>
+where
+	T: Config<I>,
+	I: 'static,
+{
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+		use parity_scale_codec::Encode;
+
+		if Pallet::<T, I>::on_chain_storage_version() < TARGET_STORAGE_VERSION {
+			log::trace!(target: LOG_TARGET, "Switch pair to be migrated to v1.");
+		} else {
+			log::trace!(target: LOG_TARGET, "Switch pairs already on v1. No migration will run.");
+		}
+		Ok(SwitchPair::<T, I>::get().encode())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		log::info!(target: LOG_TARGET, "Initiating migration.");
+
+		if Pallet::<T, I>::on_chain_storage_version() >= TARGET_STORAGE_VERSION {
+			log::info!(target: LOG_TARGET, "Switch pairs already on v1. No migration will run.");
+			return <T as frame_system::Config>::DbWeight::get().reads(1);
+		}
+
+		let Some(switch_pair) = SwitchPair::<T, I>::take() else {
+			log::info!(target: LOG_TARGET, "No switch pair to migrate.");
+			TARGET_STORAGE_VERSION.put::<Pallet<T, I>>();
+			return <T as frame_system::Config>::DbWeight::get().reads_writes(2, 2);
+		};
+
+		match Pallet::<T, I>::switch_pair_key(&switch_pair.remote_asset_id) {
+			Ok(switch_pair_key) => {
+				log::info!(target: LOG_TARGET, "Migrating switch pair for remote asset {:?}.", switch_pair_key);
+				SwitchPairs::<T, I>::insert(switch_pair_key, switch_pair);
+			}
+			Err(_) => {
+				log::error!(
+					target: LOG_TARGET,
+					"Failed to compute storage key for switch pair {:?}. Switch pair dropped.",
+					switch_pair
+				);
+			}
+		}
+		TARGET_STORAGE_VERSION.put::<Pallet<T, I>>();
+
+		// Inserting into the counted map additionally reads and writes its counter.
+		<T as frame_system::Config>::DbWeight::get().reads_writes(3, 4)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		use frame_support::ensure;
+		use parity_scale_codec::Decode;
+
+		ensure!(
+			Pallet::<T, I>::on_chain_storage_version() >= TARGET_STORAGE_VERSION,
+			sp_runtime::TryRuntimeError::Other("pallet-asset-switch storage version was not updated to v1.")
+		);
+		ensure!(
+			!SwitchPair::<T, I>::exists(),
+			sp_runtime::TryRuntimeError::Other("Single switch pair storage was not cleared.")
+		);
+		let Some(old_switch_pair) = Option::<SwitchPairInfoOf<T>>::decode(&mut state.as_slice())
+			.map_err(|_| sp_runtime::TryRuntimeError::Other("Failed to decode pre-upgrade state."))?
+		else {
+			return Ok(());
+		};
+		ensure!(
+			Pallet::<T, I>::switch_pair(&old_switch_pair.remote_asset_id) == Some(old_switch_pair),
+			sp_runtime::TryRuntimeError::Other("Switch pair was not migrated to the switch pairs map.")
+		);
+		ensure!(
+			SwitchPairs::<T, I>::count() == 1,
+			sp_runtime::TryRuntimeError::Other("Switch pairs counter was not initialized.")
+		);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+pub mod test {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+	use xcm::{IntoVersion, VersionedAssetId};
+
+	use crate::{
+		migrations::{MigrateToMultipleSwitchPairs, SwitchPair},
+		mock::{get_asset_hub_location, get_remote_erc20_asset_id, ExtBuilder, MockRuntime, XCM_ASSET_FEE},
+		NewSwitchPairInfoOf, Pallet, SwitchPairInfoOf, SwitchPairStatus, SwitchPairs,
+	};
+
+	#[test]
+	fn migrates_single_switch_pair() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<MockRuntime>>();
+			let switch_pair =
+				SwitchPairInfoOf::<MockRuntime>::from_input_unchecked(NewSwitchPairInfoOf::<MockRuntime> {
+					pool_account: [0; 32].into(),
+					remote_asset_circulating_supply: 0,
+					remote_asset_ed: 0,
+					// Stored with an older XCM version, to verify the key is normalized.
+					remote_asset_id: VersionedAssetId::from(get_remote_erc20_asset_id())
+						.into_version(3)
+						.unwrap(),
+					remote_asset_total_supply: 1_000,
+					remote_reserve_location: get_asset_hub_location().into(),
+					remote_xcm_fee: XCM_ASSET_FEE.into(),
+					status: SwitchPairStatus::Running,
+				});
+			SwitchPair::<MockRuntime, ()>::put(switch_pair.clone());
+
+			MigrateToMultipleSwitchPairs::<MockRuntime>::on_runtime_upgrade();
+
+			assert!(!SwitchPair::<MockRuntime, ()>::exists());
+			assert_eq!(SwitchPairs::<MockRuntime>::iter_values().count(), 1);
+			assert_eq!(SwitchPairs::<MockRuntime>::count(), 1);
+			assert_eq!(
+				Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into()),
+				Some(switch_pair)
+			);
+			assert_eq!(
+				Pallet::<MockRuntime>::on_chain_storage_version(),
+				StorageVersion::new(1)
+			);
+		});
+	}
+
+	#[test]
+	fn migrates_without_switch_pair() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<MockRuntime>>();
+
+			MigrateToMultipleSwitchPairs::<MockRuntime>::on_runtime_upgrade();
+
+			assert_eq!(SwitchPairs::<MockRuntime>::iter_values().count(), 0);
+			assert_eq!(
+				Pallet::<MockRuntime>::on_chain_storage_version(),
+				StorageVersion::new(1)
+			);
+		});
+	}
+}
@@ -17,7 +17,7 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use frame_support::{
-	construct_runtime, storage_alias,
+	construct_runtime, parameter_types, storage_alias,
 	traits::{
 		fungible::{Mutate, MutateFreeze, MutateHold},
 		Everything, VariantCount,
@@ -34,12 +34,15 @@ use sp_runtime::{
 };
 use sp_std::sync::Arc;
 use xcm::v4::{
-	Asset, AssetId, Error as XcmError, Fungibility,
+	Asset, AssetId, Error as XcmError, Fungibility, InteriorLocation,
 	Junction::{AccountId32 as AccountId32Junction, AccountKey20, GlobalConsensus, Parachain},
 	Junctions::{Here, X1, X2},
-	Location, NetworkId, SendError, SendResult, SendXcm, Xcm, XcmContext, XcmHash,
+	Location, NetworkId, QueryId, Response, SendError, SendResult, SendXcm, Xcm, XcmContext, XcmHash,
+};
+use xcm_executor::{
+	traits::{QueryHandler, QueryResponseStatus, TransactAsset},
+	AssetsInHolding,
 };
-use xcm_executor::{traits::TransactAsset, AssetsInHolding};
 
 use crate::{xcm::convert::AccountId32ToAccountId32JunctionConverter, Config, NewSwitchPairInfoOf, Pallet};
 
@@ -159,30 +162,106 @@ impl TransactAsset for MockFungibleAssetTransactor {
 	}
 }
 
+// Used to temporarily store the last message delivered by the mock router.
+#[storage_alias]
+type SentXcmStorage<T: Config> = StorageValue<Pallet<T>, (Location, Xcm<()>)>;
+
 pub struct AlwaysSuccessfulXcmRouter;
 
+impl AlwaysSuccessfulXcmRouter {
+	pub(crate) fn last_sent_xcm() -> Option<(Location, Xcm<()>)> {
+		SentXcmStorage::<MockRuntime>::get()
+	}
+}
+
 impl SendXcm for AlwaysSuccessfulXcmRouter {
-	type Ticket = ();
+	type Ticket = Option<(Location, Xcm<()>)>;
 
-	fn validate(_destination: &mut Option<Location>, _message: &mut Option<Xcm<()>>) -> SendResult<Self::Ticket> {
-		Ok(((), vec![].into()))
+	fn validate(destination: &mut Option<Location>, message: &mut Option<Xcm<()>>) -> SendResult<Self::Ticket> {
+		Ok((destination.take().zip(message.take()), vec![].into()))
 	}
 
-	fn deliver(_ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		if let Some(sent_xcm) = ticket {
+			SentXcmStorage::<MockRuntime>::put(sent_xcm);
+		}
 		Ok(XcmHash::default())
 	}
 }
 
+// Used to temporarily store query responses used in the mock.
+#[storage_alias]
+type QueryResponsesStorage<T: Config> = StorageMap<Pallet<T>, Twox64Concat, QueryId, Response>;
+#[storage_alias]
+type NextQueryIdStorage<T: Config> = StorageValue<Pallet<T>, QueryId, ValueQuery>;
+
+parameter_types! {
+	pub UniversalLocation: InteriorLocation = X2([GlobalConsensus(NetworkId::Polkadot), Parachain(2_086)].into());
+}
+
+pub struct MockQueryHandler;
+
+impl MockQueryHandler {
+	pub(crate) fn set_response(query_id: QueryId, response: Response) {
+		QueryResponsesStorage::<MockRuntime>::insert(query_id, response);
+	}
+}
+
+impl QueryHandler for MockQueryHandler {
+	type QueryId = QueryId;
+	type BlockNumber = u64;
+	type Error = XcmError;
+	type UniversalLocation = UniversalLocation;
+
+	fn new_query(
+		_responder: impl Into<Location>,
+		_timeout: Self::BlockNumber,
+		_match_querier: impl Into<Location>,
+	) -> QueryId {
+		NextQueryIdStorage::<MockRuntime>::mutate(|next_query_id| {
+			let query_id = *next_query_id;
+			*next_query_id = next_query_id.saturating_add(1);
+			query_id
+		})
+	}
+
+	fn report_outcome(
+		_message: &mut Xcm<()>,
+		responder: impl Into<Location>,
+		timeout: Self::BlockNumber,
+	) -> Result<Self::QueryId, Self::Error> {
+		Ok(Self::new_query(responder, timeout, Here))
+	}
+
+	fn take_response(query_id: QueryId) -> QueryResponseStatus<Self::BlockNumber> {
+		if let Some(response) = QueryResponsesStorage::<MockRuntime>::take(query_id) {
+			QueryResponseStatus::Ready { response, at: 0 }
+		} else if query_id < NextQueryIdStorage::<MockRuntime>::get() {
+			QueryResponseStatus::Pending { timeout: 0 }
+		} else {
+			QueryResponseStatus::NotFound
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn expect_response(query_id: QueryId, response: Response) {
+		Self::set_response(query_id, response)
+	}
+}
+
 impl crate::Config for MockRuntime {
 	type AccountIdConverter = AccountId32ToAccountId32JunctionConverter;
 	type AssetTransactor = MockFungibleAssetTransactor;
 	type FeeOrigin = EnsureRoot<Self::AccountId>;
 	type LocalCurrency = Balances;
+	type MaxSwitchPairs = ConstU32<2>;
 	type PauseOrigin = EnsureRoot<Self::AccountId>;
+	type QueryHandler = MockQueryHandler;
 	type RuntimeEvent = RuntimeEvent;
 	type SubmitterOrigin = EnsureSigned<Self::AccountId>;
 	type SwitchHooks = ();
 	type SwitchOrigin = EnsureRoot<Self::AccountId>;
+	type SwitchResponseTimeout = ConstU64<10>;
 	type WeightInfo = ();
 	type XcmRouter = AlwaysSuccessfulXcmRouter;
 
@@ -226,14 +305,19 @@ impl ExtBuilder {
 			if let Some(switch_pair_info) = self.0 {
 				Pallet::<MockRuntime>::set_switch_pair_bypass_checks(
 					switch_pair_info.remote_asset_total_supply,
-					switch_pair_info.remote_asset_id,
+					switch_pair_info.remote_asset_id.clone(),
 					switch_pair_info.remote_asset_circulating_supply,
 					switch_pair_info.remote_reserve_location,
 					switch_pair_info.remote_asset_ed,
 					switch_pair_info.remote_xcm_fee,
 					switch_pair_info.pool_account,
-				);
-				Pallet::<MockRuntime>::set_switch_pair_status(switch_pair_info.status).unwrap();
+				)
+				.unwrap();
+				Pallet::<MockRuntime>::set_switch_pair_status(
+					&switch_pair_info.remote_asset_id,
+					switch_pair_info.status,
+				)
+				.unwrap();
 			}
 			for (account, free, frozen, held) in self.1 {
 				<Balances as Mutate<AccountId32>>::set_balance(&account, free);
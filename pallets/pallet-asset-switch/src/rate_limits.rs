@@ -0,0 +1,272 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, SaturatedConversion, Saturating, Zero},
+	PerThing, Perquintill,
+};
+
+/// The limits applied to the switches of a switch pair within a rolling
+/// window of blocks.
+///
+/// Usage is tracked in consecutive windows of `period` blocks. The usage
+/// within the last `period` blocks is estimated by adding the usage of the
+/// current window to the usage of the previous window, weighted by how much
+/// the previous window still overlaps with the last `period` blocks. A
+/// `period` of zero applies the limits to each switch individually.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, RuntimeDebug, Clone, Default)]
+pub struct SwitchRateLimits<BlockNumber> {
+	/// The number of blocks the limits apply to.
+	pub period: BlockNumber,
+	/// The maximum amount of tokens that can be switched by all accounts
+	/// within the period, if any.
+	pub max_amount: Option<u128>,
+	/// The maximum number of switches that can be performed by all accounts
+	/// within the period, if any.
+	pub max_switches: Option<u32>,
+	/// The maximum amount of tokens that can be switched by a single account
+	/// within the period, if any.
+	pub max_amount_per_account: Option<u128>,
+	/// The maximum number of switches that can be performed by a single
+	/// account within the period, if any.
+	pub max_switches_per_account: Option<u32>,
+}
+
+/// The amount of tokens switched and the number of switches performed within
+/// the current and the previous window.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, RuntimeDebug, Clone, Default)]
+pub struct SwitchUsage<BlockNumber> {
+	/// The block at which the current window started.
+	pub window_start: BlockNumber,
+	/// The amount of tokens switched within the current window.
+	pub amount: u128,
+	/// The number of switches performed within the current window.
+	pub switches: u32,
+	/// The amount of tokens switched within the previous window.
+	pub previous_amount: u128,
+	/// The number of switches performed within the previous window.
+	pub previous_switches: u32,
+}
+
+/// The limits of a `SwitchRateLimits`.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, RuntimeDebug, Clone, Copy)]
+pub enum SwitchRateLimit {
+	/// The maximum amount of tokens switched by all accounts.
+	Amount,
+	/// The maximum number of switches performed by all accounts.
+	Switches,
+	/// The maximum amount of tokens switched by a single account.
+	AmountPerAccount,
+	/// The maximum number of switches performed by a single account.
+	SwitchesPerAccount,
+}
+
+impl<BlockNumber> SwitchUsage<BlockNumber>
+where
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	fn empty_window(window_start: BlockNumber) -> Self {
+		Self {
+			window_start,
+			amount: 0,
+			switches: 0,
+			previous_amount: 0,
+			previous_switches: 0,
+		}
+	}
+
+	/// Returns the usage with the windows moved forward to the one `now`
+	/// falls into, discarding the usage of windows that have ended more than
+	/// `period` blocks ago.
+	pub(crate) fn advanced_to(self, now: BlockNumber, period: BlockNumber) -> Self {
+		if period.is_zero() {
+			return Self::empty_window(now);
+		}
+		let window_start = now.saturating_sub(now % period);
+		if window_start == self.window_start {
+			self
+		} else if window_start == self.window_start.saturating_add(period) {
+			Self {
+				window_start,
+				amount: 0,
+				switches: 0,
+				previous_amount: self.amount,
+				previous_switches: self.switches,
+			}
+		} else {
+			Self::empty_window(window_start)
+		}
+	}
+
+	/// Returns the usage after a switch of `amount` tokens at block `now`.
+	/// Returns `None` on overflow.
+	pub(crate) fn with_switch(self, now: BlockNumber, period: BlockNumber, amount: u128) -> Option<Self> {
+		let usage = self.advanced_to(now, period);
+		Some(Self {
+			amount: usage.amount.checked_add(amount)?,
+			switches: usage.switches.checked_add(1)?,
+			..usage
+		})
+	}
+
+	/// Returns the usage after a switch of `amount` tokens at block `now`,
+	/// saturating on overflow.
+	pub(crate) fn with_saturating_switch(self, now: BlockNumber, period: BlockNumber, amount: u128) -> Self {
+		let usage = self.advanced_to(now, period);
+		Self {
+			amount: usage.amount.saturating_add(amount),
+			switches: usage.switches.saturating_add(1),
+			..usage
+		}
+	}
+
+	/// Returns the usage at block `now` without a switch of `amount` tokens
+	/// performed at block `switched_at`, which only counts towards the usage
+	/// if it was performed within the current or the previous window.
+	pub(crate) fn without_switch(
+		self,
+		now: BlockNumber,
+		period: BlockNumber,
+		switched_at: BlockNumber,
+		amount: u128,
+	) -> Self {
+		let usage = self.advanced_to(now, period);
+		if period.is_zero() {
+			return usage;
+		}
+		let switch_window_start = switched_at.saturating_sub(switched_at % period);
+		if switch_window_start == usage.window_start {
+			Self {
+				amount: usage.amount.saturating_sub(amount),
+				switches: usage.switches.saturating_sub(1),
+				..usage
+			}
+		} else if switch_window_start.saturating_add(period) == usage.window_start {
+			Self {
+				previous_amount: usage.previous_amount.saturating_sub(amount),
+				previous_switches: usage.previous_switches.saturating_sub(1),
+				..usage
+			}
+		} else {
+			usage
+		}
+	}
+
+	/// Returns whether no switch counts towards the usage at block `now`
+	/// anymore.
+	pub(crate) fn is_expired(&self, now: BlockNumber, period: BlockNumber) -> bool {
+		let usage = self.clone().advanced_to(now, period);
+		usage.amount.is_zero()
+			&& usage.switches.is_zero()
+			&& usage.previous_amount.is_zero()
+			&& usage.previous_switches.is_zero()
+	}
+
+	/// Returns the amount of tokens switched and the number of switches
+	/// performed within the `period` blocks up to `now`, rounding the share
+	/// of the previous window up.
+	fn rolling_usage(&self, now: BlockNumber, period: BlockNumber) -> (u128, u128) {
+		let usage = self.clone().advanced_to(now, period);
+		if period.is_zero() {
+			return (usage.amount, usage.switches.into());
+		}
+		let elapsed = now.saturating_sub(usage.window_start);
+		let previous_window_share = Perquintill::from_rational(
+			period.saturating_sub(elapsed).saturated_into::<u128>(),
+			period.saturated_into::<u128>(),
+		);
+		(
+			previous_window_share
+				.mul_ceil(usage.previous_amount)
+				.saturating_add(usage.amount),
+			previous_window_share
+				.mul_ceil(u128::from(usage.previous_switches))
+				.saturating_add(usage.switches.into()),
+		)
+	}
+}
+
+impl<BlockNumber> SwitchRateLimits<BlockNumber>
+where
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	fn limits_with_usage(
+		&self,
+		usage: &SwitchUsage<BlockNumber>,
+		account_usage: &SwitchUsage<BlockNumber>,
+		now: BlockNumber,
+	) -> [(SwitchRateLimit, u128, Option<u128>); 4] {
+		let (amount, switches) = usage.rolling_usage(now, self.period);
+		let (account_amount, account_switches) = account_usage.rolling_usage(now, self.period);
+		[
+			(SwitchRateLimit::Amount, amount, self.max_amount),
+			(SwitchRateLimit::Switches, switches, self.max_switches.map(Into::into)),
+			(
+				SwitchRateLimit::AmountPerAccount,
+				account_amount,
+				self.max_amount_per_account,
+			),
+			(
+				SwitchRateLimit::SwitchesPerAccount,
+				account_switches,
+				self.max_switches_per_account.map(Into::into),
+			),
+		]
+	}
+
+	/// Returns the limits exceeded at block `now` by the provided global and
+	/// per-account usages.
+	pub(crate) fn exceeded_limits(
+		&self,
+		usage: &SwitchUsage<BlockNumber>,
+		account_usage: &SwitchUsage<BlockNumber>,
+		now: BlockNumber,
+	) -> impl Iterator<Item = SwitchRateLimit> {
+		self.limits_with_usage(usage, account_usage, now)
+			.into_iter()
+			.filter_map(|(limit, used, max)| max.filter(|max| used > *max).map(|_| limit))
+	}
+
+	/// Returns the first limit exceeded at block `now` by the provided global
+	/// and per-account usages, if any.
+	pub(crate) fn first_exceeded_limit(
+		&self,
+		usage: &SwitchUsage<BlockNumber>,
+		account_usage: &SwitchUsage<BlockNumber>,
+		now: BlockNumber,
+	) -> Option<SwitchRateLimit> {
+		self.exceeded_limits(usage, account_usage, now).next()
+	}
+
+	/// Returns the limits that the provided global and per-account usages
+	/// have exactly reached at block `now`, so that no further switch is
+	/// possible until some of the usage falls out of the rolling window.
+	pub(crate) fn reached_limits(
+		&self,
+		usage: &SwitchUsage<BlockNumber>,
+		account_usage: &SwitchUsage<BlockNumber>,
+		now: BlockNumber,
+	) -> impl Iterator<Item = SwitchRateLimit> {
+		self.limits_with_usage(usage, account_usage, now)
+			.into_iter()
+			.filter_map(|(limit, used, max)| max.filter(|max| used == *max).map(|_| limit))
+	}
+}
@@ -82,6 +82,19 @@ pub struct SwitchPairInfo<AccountId> {
 	remote_asset_sovereign_total_balance: u128,
 }
 
+/// A local -> remote switch whose outcome on the remote reserve location has
+/// not been processed yet.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, RuntimeDebug, Clone)]
+pub struct PendingSwitch<AccountId, Balance, BlockNumber> {
+	/// The account that submitted the switch, and to which local tokens are
+	/// refunded in case of failure.
+	pub from: AccountId,
+	/// The amount of local tokens that were switched.
+	pub amount: Balance,
+	/// The block at which the switch was performed.
+	pub requested_at: BlockNumber,
+}
+
 /// All statues a switch pool can be in at any given time.
 #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, RuntimeDebug, Clone, Default)]
 pub enum SwitchPairStatus {
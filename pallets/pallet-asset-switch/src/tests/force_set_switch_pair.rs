@@ -23,10 +23,16 @@ use sp_runtime::{
 	DispatchError,
 };
 
+use xcm::v4::{
+	AssetId,
+	Junction::{AccountKey20, GlobalConsensus},
+	Location, NetworkId,
+};
+
 use crate::{
 	mock::{get_asset_hub_location, get_remote_erc20_asset_id, ExtBuilder, MockRuntime, System, XCM_ASSET_FEE},
 	switch::SwitchPairStatus,
-	Event, NewSwitchPairInfoOf, Pallet, SwitchPair, SwitchPairInfoOf,
+	Error, Event, NewSwitchPairInfoOf, Pallet, SwitchPairInfoOf, SwitchPairs,
 };
 
 #[test]
@@ -50,7 +56,7 @@ fn successful() {
 				Box::new(XCM_ASSET_FEE.into()),
 			));
 
-			let switch_pair = SwitchPair::<MockRuntime>::get();
+			let switch_pair = Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into());
 			let expected_switch_pair =
 				SwitchPairInfoOf::<MockRuntime>::from_input_unchecked(NewSwitchPairInfoOf::<MockRuntime> {
 					pool_account: pool_account_address.clone(),
@@ -92,7 +98,7 @@ fn successful() {
 				Box::new(XCM_ASSET_FEE.into()),
 			));
 
-			let switch_pair = SwitchPair::<MockRuntime>::get();
+			let switch_pair = Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into());
 			let expected_switch_pair =
 				SwitchPairInfoOf::<MockRuntime>::from_input_unchecked(NewSwitchPairInfoOf::<MockRuntime> {
 					pool_account: pool_account_address.clone(),
@@ -135,7 +141,7 @@ fn successful() {
 				Box::new(XCM_ASSET_FEE.into()),
 			));
 
-			let switch_pair = SwitchPair::<MockRuntime>::get();
+			let switch_pair = Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into());
 			let expected_switch_pair =
 				SwitchPairInfoOf::<MockRuntime>::from_input_unchecked(NewSwitchPairInfoOf::<MockRuntime> {
 					pool_account: pool_account_address.clone(),
@@ -178,7 +184,7 @@ fn successful() {
 				Box::new(XCM_ASSET_FEE.into()),
 			));
 
-			let switch_pair = SwitchPair::<MockRuntime>::get();
+			let switch_pair = Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into());
 			let expected_switch_pair =
 				SwitchPairInfoOf::<MockRuntime>::from_input_unchecked(NewSwitchPairInfoOf::<MockRuntime> {
 					pool_account: pool_account_address.clone(),
@@ -237,7 +243,7 @@ fn successful_overwrites_existing_pool() {
 				Box::new(XCM_ASSET_FEE.into()),
 			));
 
-			let switch_pair = SwitchPair::<MockRuntime>::get();
+			let switch_pair = Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into());
 			let expected_switch_pair =
 				SwitchPairInfoOf::<MockRuntime>::from_input_unchecked(NewSwitchPairInfoOf::<MockRuntime> {
 					pool_account: pool_account_address.clone(),
@@ -321,7 +327,7 @@ fn successful_on_not_enough_funds_on_pool_balance() {
 				0,
 				Box::new(XCM_ASSET_FEE.into()),
 			),);
-			let switch_pair = SwitchPair::<MockRuntime>::get();
+			let switch_pair = Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into());
 			let expected_switch_pair =
 				SwitchPairInfoOf::<MockRuntime>::from_input_unchecked(NewSwitchPairInfoOf::<MockRuntime> {
 					pool_account: pool_account_address.clone(),
@@ -362,7 +368,7 @@ fn successful_on_not_enough_funds_on_pool_balance() {
 				0,
 				Box::new(XCM_ASSET_FEE.into()),
 			),);
-			let switch_pair = SwitchPair::<MockRuntime>::get();
+			let switch_pair = Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into());
 			let expected_switch_pair =
 				SwitchPairInfoOf::<MockRuntime>::from_input_unchecked(NewSwitchPairInfoOf::<MockRuntime> {
 					pool_account: pool_account_address.clone(),
@@ -403,7 +409,7 @@ fn successful_on_not_enough_funds_on_pool_balance() {
 				0,
 				Box::new(XCM_ASSET_FEE.into()),
 			),);
-			let switch_pair = SwitchPair::<MockRuntime>::get();
+			let switch_pair = Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into());
 			let expected_switch_pair =
 				SwitchPairInfoOf::<MockRuntime>::from_input_unchecked(NewSwitchPairInfoOf::<MockRuntime> {
 					pool_account: pool_account_address.clone(),
@@ -430,3 +436,54 @@ fn successful_on_not_enough_funds_on_pool_balance() {
 				.into()));
 		});
 }
+
+#[test]
+fn fails_with_too_many_switch_pairs() {
+	let remote_asset_id_with_key = |key: [u8; 20]| {
+		AssetId(Location {
+			parents: 2,
+			interior: [
+				GlobalConsensus(NetworkId::Ethereum { chain_id: 1 }),
+				AccountKey20 { network: None, key },
+			]
+			.into(),
+		})
+	};
+	let force_set_switch_pair = |remote_asset_id: AssetId| {
+		Pallet::<MockRuntime>::force_set_switch_pair(
+			RawOrigin::Root.into(),
+			u64::MAX as u128,
+			Box::new(remote_asset_id.into()),
+			0,
+			Box::new(get_asset_hub_location().into()),
+			0,
+			Box::new(XCM_ASSET_FEE.into()),
+		)
+	};
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		// `MaxSwitchPairs` is `2` in the mock.
+		assert_ok!(force_set_switch_pair(get_remote_erc20_asset_id()));
+		assert_ok!(force_set_switch_pair(remote_asset_id_with_key(
+			*b"!!other_eth_addr!!!!"
+		)));
+		assert_eq!(SwitchPairs::<MockRuntime>::count(), 2);
+
+		assert_noop!(
+			force_set_switch_pair(remote_asset_id_with_key(*b"!!third_eth_addr!!!!")),
+			Error::<MockRuntime>::TooManySwitchPairs
+		);
+		// Replacing an existing switch pair is still possible.
+		assert_ok!(force_set_switch_pair(get_remote_erc20_asset_id()));
+		assert_eq!(SwitchPairs::<MockRuntime>::count(), 2);
+
+		// After removing a switch pair, a new one can be set.
+		assert_ok!(Pallet::<MockRuntime>::force_unset_switch_pair(
+			RawOrigin::Root.into(),
+			Box::new(get_remote_erc20_asset_id().into())
+		));
+		assert_ok!(force_set_switch_pair(remote_asset_id_with_key(
+			*b"!!third_eth_addr!!!!"
+		)));
+		assert_eq!(SwitchPairs::<MockRuntime>::count(), 2);
+	});
+}
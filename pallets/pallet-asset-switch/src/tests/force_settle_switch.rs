@@ -0,0 +1,192 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok, traits::fungible::Inspect};
+use frame_system::RawOrigin;
+use sp_runtime::{traits::One, AccountId32, DispatchError};
+use xcm::VersionedAssetId;
+
+use crate::{
+	mock::{
+		get_asset_hub_location, get_remote_erc20_asset_id, Balances, ExtBuilder, MockRuntime, System, XCM_ASSET_FEE,
+	},
+	switch::SwitchPairStatus,
+	Error, Event, NewSwitchPairInfoOf, Pallet, PendingSwitches,
+};
+
+// The first block after the `SwitchResponseTimeout` of the mock runtime has
+// elapsed for switches performed at block `1`.
+const TIMED_OUT_BLOCK: u64 = 12;
+
+fn switch_all_user_balance(user: &AccountId32) {
+	assert_ok!(Pallet::<MockRuntime>::switch(
+		RawOrigin::Signed(user.clone()).into(),
+		Box::new(get_remote_erc20_asset_id().into()),
+		// Cannot switch ED (1 in the mock), so we need to exclude that.
+		99_999,
+		Box::new(get_asset_hub_location().into())
+	));
+}
+
+fn switch_pair_info(pool_account: AccountId32) -> NewSwitchPairInfoOf<MockRuntime> {
+	NewSwitchPairInfoOf::<MockRuntime> {
+		pool_account,
+		remote_asset_circulating_supply: 0,
+		remote_asset_ed: 0,
+		remote_asset_id: get_remote_erc20_asset_id().into(),
+		remote_asset_total_supply: 100_000,
+		remote_reserve_location: get_asset_hub_location().into(),
+		remote_xcm_fee: XCM_ASSET_FEE.into(),
+		status: SwitchPairStatus::Running,
+	}
+}
+
+#[test]
+fn successful_confirm() {
+	let user = AccountId32::from([0; 32]);
+	let pool_account = AccountId32::from([1; 32]);
+	ExtBuilder::default()
+		.with_balances(vec![(user.clone(), 100_000, 0, 0), (pool_account.clone(), 1, 0, 0)])
+		.with_fungibles(vec![(user.clone(), XCM_ASSET_FEE)])
+		.with_switch_pair_info(switch_pair_info(pool_account.clone()))
+		.build_and_execute_with_sanity_tests(|| {
+			switch_all_user_balance(&user);
+
+			System::set_block_number(TIMED_OUT_BLOCK);
+			assert_ok!(Pallet::<MockRuntime>::force_settle_switch(
+				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				0,
+				true
+			));
+			assert!(!PendingSwitches::<MockRuntime>::contains_key(
+				VersionedAssetId::from(get_remote_erc20_asset_id()),
+				0
+			));
+			// Switch outcome is unchanged.
+			assert!(<Balances as Inspect<AccountId32>>::total_balance(&user).is_one());
+			assert_eq!(
+				<Balances as Inspect<AccountId32>>::total_balance(&pool_account),
+				100_000
+			);
+			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
+				== Event::<MockRuntime>::LocalToRemoteSwitchConfirmed {
+					remote_asset_id: get_remote_erc20_asset_id().into(),
+					query_id: 0,
+				}
+				.into()));
+		});
+}
+
+#[test]
+fn successful_refund() {
+	let user = AccountId32::from([0; 32]);
+	let pool_account = AccountId32::from([1; 32]);
+	ExtBuilder::default()
+		.with_balances(vec![(user.clone(), 100_000, 0, 0), (pool_account.clone(), 1, 0, 0)])
+		.with_fungibles(vec![(user.clone(), XCM_ASSET_FEE)])
+		.with_switch_pair_info(switch_pair_info(pool_account.clone()))
+		.build_and_execute_with_sanity_tests(|| {
+			switch_all_user_balance(&user);
+
+			System::set_block_number(TIMED_OUT_BLOCK);
+			assert_ok!(Pallet::<MockRuntime>::force_settle_switch(
+				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				0,
+				false
+			));
+			assert!(!PendingSwitches::<MockRuntime>::contains_key(
+				VersionedAssetId::from(get_remote_erc20_asset_id()),
+				0
+			));
+			// User's currency balance is refunded.
+			assert_eq!(<Balances as Inspect<AccountId32>>::total_balance(&user), 100_000);
+			assert!(<Balances as Inspect<AccountId32>>::total_balance(&pool_account).is_one());
+			assert_eq!(
+				Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into())
+					.unwrap()
+					.reducible_remote_balance(),
+				100_000
+			);
+			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
+				== Event::<MockRuntime>::LocalToRemoteSwitchRefunded {
+					remote_asset_id: get_remote_erc20_asset_id().into(),
+					query_id: 0,
+					to: user.clone(),
+					amount: 99_999,
+				}
+				.into()));
+		});
+}
+
+#[test]
+fn fails_on_invalid_origin() {
+	let user = AccountId32::from([0; 32]);
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_noop!(
+			Pallet::<MockRuntime>::force_settle_switch(
+				RawOrigin::Signed(user.clone()).into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				0,
+				false
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn fails_on_non_existing_pending_switch() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_noop!(
+			Pallet::<MockRuntime>::force_settle_switch(
+				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				0,
+				false
+			),
+			Error::<MockRuntime>::PendingSwitchNotFound
+		);
+	});
+}
+
+#[test]
+fn fails_before_timeout() {
+	let user = AccountId32::from([0; 32]);
+	let pool_account = AccountId32::from([1; 32]);
+	ExtBuilder::default()
+		.with_balances(vec![(user.clone(), 100_000, 0, 0), (pool_account.clone(), 1, 0, 0)])
+		.with_fungibles(vec![(user.clone(), XCM_ASSET_FEE)])
+		.with_switch_pair_info(switch_pair_info(pool_account))
+		.build_and_execute_with_sanity_tests(|| {
+			switch_all_user_balance(&user);
+
+			// The response is still expected at the timeout block.
+			System::set_block_number(TIMED_OUT_BLOCK - 1);
+			assert_noop!(
+				Pallet::<MockRuntime>::force_settle_switch(
+					RawOrigin::Root.into(),
+					Box::new(get_remote_erc20_asset_id().into()),
+					0,
+					false
+				),
+				Error::<MockRuntime>::SwitchResponsePending
+			);
+		});
+}
@@ -22,7 +22,7 @@ use sp_runtime::DispatchError;
 
 use crate::{
 	mock::{get_asset_hub_location, get_remote_erc20_asset_id, ExtBuilder, MockRuntime, System, XCM_ASSET_FEE},
-	Event, NewSwitchPairInfoOf, Pallet, SwitchPair,
+	Event, NewSwitchPairInfoOf, Pallet,
 };
 
 #[test]
@@ -40,8 +40,11 @@ fn successful() {
 			status: Default::default(),
 		})
 		.build_and_execute_with_sanity_tests(|| {
-			assert_ok!(Pallet::<MockRuntime>::force_unset_switch_pair(RawOrigin::Root.into()));
-			assert!(SwitchPair::<MockRuntime>::get().is_none());
+			assert_ok!(Pallet::<MockRuntime>::force_unset_switch_pair(
+				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into())
+			));
+			assert!(Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into()).is_none());
 			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
 				== Event::<MockRuntime>::SwitchPairRemoved {
 					remote_asset_id: get_remote_erc20_asset_id().into(),
@@ -50,8 +53,11 @@ fn successful() {
 		});
 	// Deletes and generates no event if there is no pool
 	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
-		assert_ok!(Pallet::<MockRuntime>::force_unset_switch_pair(RawOrigin::Root.into()));
-		assert!(SwitchPair::<MockRuntime>::get().is_none());
+		assert_ok!(Pallet::<MockRuntime>::force_unset_switch_pair(
+			RawOrigin::Root.into(),
+			Box::new(get_remote_erc20_asset_id().into())
+		));
+		assert!(Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into()).is_none());
 		assert!(System::events().into_iter().map(|e| e.event).all(|e| e
 			!= Event::<MockRuntime>::SwitchPairRemoved {
 				remote_asset_id: get_remote_erc20_asset_id().into(),
@@ -64,7 +70,10 @@ fn successful() {
 fn fails_on_invalid_origin() {
 	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
 		assert_noop!(
-			Pallet::<MockRuntime>::force_unset_switch_pair(RawOrigin::None.into()),
+			Pallet::<MockRuntime>::force_unset_switch_pair(
+				RawOrigin::None.into(),
+				Box::new(get_remote_erc20_asset_id().into())
+			),
 			DispatchError::BadOrigin,
 		);
 	});
@@ -17,9 +17,15 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 mod force_set_switch_pair;
+mod force_settle_switch;
 mod force_unset_switch_pair;
 mod pause_switch_pair;
+mod remove_expired_switch_usage;
+mod remove_switch_pair_storage;
 mod resume_switch_pair;
 mod set_switch_pair;
+mod set_switch_pair_hook_rules;
+mod set_switch_pair_rate_limits;
+mod settle_switch;
 mod switch;
 mod update_remote_xcm_fee;
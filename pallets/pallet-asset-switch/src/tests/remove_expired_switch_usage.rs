@@ -0,0 +1,170 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use sp_runtime::{AccountId32, DispatchError};
+use xcm::VersionedAssetId;
+
+use crate::{
+	mock::{get_asset_hub_location, get_remote_erc20_asset_id, ExtBuilder, MockRuntime, System, XCM_ASSET_FEE},
+	switch::SwitchPairStatus,
+	AccountSwitchUsage, Error, Event, NewSwitchPairInfoOf, Pallet, SwitchPairRateLimits, SwitchRateLimitsOf,
+	SwitchUsageOf,
+};
+
+fn insert_account_switch_usage(account: &AccountId32) {
+	AccountSwitchUsage::<MockRuntime>::insert(
+		VersionedAssetId::from(get_remote_erc20_asset_id()),
+		account,
+		SwitchUsageOf::<MockRuntime> {
+			window_start: 0,
+			amount: 100,
+			switches: 1,
+			previous_amount: 0,
+			previous_switches: 0,
+		},
+	);
+}
+
+fn set_rate_limits() {
+	SwitchPairRateLimits::<MockRuntime>::insert(
+		VersionedAssetId::from(get_remote_erc20_asset_id()),
+		SwitchRateLimitsOf::<MockRuntime> {
+			period: 10,
+			max_amount_per_account: Some(1_000),
+			..Default::default()
+		},
+	);
+}
+
+fn new_switch_pair_info() -> NewSwitchPairInfoOf<MockRuntime> {
+	NewSwitchPairInfoOf::<MockRuntime> {
+		pool_account: AccountId32::from([1; 32]),
+		remote_asset_circulating_supply: 0,
+		remote_asset_ed: 0,
+		remote_asset_id: get_remote_erc20_asset_id().into(),
+		remote_asset_total_supply: 100_000,
+		remote_reserve_location: get_asset_hub_location().into(),
+		remote_xcm_fee: XCM_ASSET_FEE.into(),
+		status: SwitchPairStatus::Running,
+	}
+}
+
+#[test]
+fn successful() {
+	let user = AccountId32::from([0; 32]);
+	let submitter = AccountId32::from([2; 32]);
+	ExtBuilder::default()
+		.with_switch_pair_info(new_switch_pair_info())
+		.build_and_execute_with_sanity_tests(|| {
+			set_rate_limits();
+			insert_account_switch_usage(&user);
+			// The usage of the window starting at block 0 still counts towards the
+			// limits until the end of the following window.
+			System::set_block_number(20);
+
+			assert_ok!(Pallet::<MockRuntime>::remove_expired_switch_usage(
+				RawOrigin::Signed(submitter).into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				user.clone()
+			));
+			assert!(!AccountSwitchUsage::<MockRuntime>::contains_key(
+				VersionedAssetId::from(get_remote_erc20_asset_id()),
+				&user
+			));
+			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
+				== Event::<MockRuntime>::AccountSwitchUsageRemoved {
+					remote_asset_id: get_remote_erc20_asset_id().into(),
+					account: user.clone(),
+				}
+				.into()));
+		});
+}
+
+#[test]
+fn successful_without_rate_limits() {
+	let user = AccountId32::from([0; 32]);
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		// The usage of a removed switch pair does not count towards any limit.
+		insert_account_switch_usage(&user);
+
+		assert_ok!(Pallet::<MockRuntime>::remove_expired_switch_usage(
+			RawOrigin::Signed(user.clone()).into(),
+			Box::new(get_remote_erc20_asset_id().into()),
+			user.clone()
+		));
+		assert!(!AccountSwitchUsage::<MockRuntime>::contains_key(
+			VersionedAssetId::from(get_remote_erc20_asset_id()),
+			&user
+		));
+	});
+}
+
+#[test]
+fn fails_on_invalid_origin() {
+	let user = AccountId32::from([0; 32]);
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		insert_account_switch_usage(&user);
+
+		assert_noop!(
+			Pallet::<MockRuntime>::remove_expired_switch_usage(
+				RawOrigin::None.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				user.clone()
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn fails_on_usage_not_found() {
+	let user = AccountId32::from([0; 32]);
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_noop!(
+			Pallet::<MockRuntime>::remove_expired_switch_usage(
+				RawOrigin::Signed(user.clone()).into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				user.clone()
+			),
+			Error::<MockRuntime>::SwitchUsageNotFound
+		);
+	});
+}
+
+#[test]
+fn fails_on_usage_not_expired() {
+	let user = AccountId32::from([0; 32]);
+	ExtBuilder::default()
+		.with_switch_pair_info(new_switch_pair_info())
+		.build_and_execute_with_sanity_tests(|| {
+			set_rate_limits();
+			insert_account_switch_usage(&user);
+			// The usage of the previous window still counts towards the limits.
+			System::set_block_number(19);
+
+			assert_noop!(
+				Pallet::<MockRuntime>::remove_expired_switch_usage(
+					RawOrigin::Signed(user.clone()).into(),
+					Box::new(get_remote_erc20_asset_id().into()),
+					user.clone()
+				),
+				Error::<MockRuntime>::SwitchUsageNotExpired
+			);
+		});
+}
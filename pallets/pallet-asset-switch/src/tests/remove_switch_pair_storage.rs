@@ -0,0 +1,191 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use sp_runtime::{AccountId32, DispatchError};
+use xcm::VersionedAssetId;
+
+use crate::{
+	mock::{get_asset_hub_location, get_remote_erc20_asset_id, ExtBuilder, MockRuntime, System, XCM_ASSET_FEE},
+	switch::SwitchPairStatus,
+	AccountSwitchUsage, Error, Event, NewSwitchPairInfoOf, Pallet, PendingSwitchOf, PendingSwitches, SwitchUsageOf,
+};
+
+fn insert_pending_switches(count: u64) {
+	(0..count).for_each(|query_id| {
+		PendingSwitches::<MockRuntime>::insert(
+			VersionedAssetId::from(get_remote_erc20_asset_id()),
+			query_id,
+			PendingSwitchOf::<MockRuntime, ()> {
+				from: AccountId32::from([0; 32]),
+				amount: 100,
+				requested_at: 1,
+			},
+		);
+	});
+}
+
+fn insert_account_switch_usages(count: u8) {
+	(0..count).for_each(|account| {
+		AccountSwitchUsage::<MockRuntime>::insert(
+			VersionedAssetId::from(get_remote_erc20_asset_id()),
+			AccountId32::from([account; 32]),
+			SwitchUsageOf::<MockRuntime> {
+				window_start: 0,
+				amount: 100,
+				switches: 1,
+				previous_amount: 0,
+				previous_switches: 0,
+			},
+		);
+	});
+}
+
+#[test]
+fn successful() {
+	let user = AccountId32::from([0; 32]);
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		insert_pending_switches(3);
+
+		// Entries are removed up to the provided limit.
+		assert_ok!(Pallet::<MockRuntime>::remove_switch_pair_storage(
+			RawOrigin::Signed(user.clone()).into(),
+			Box::new(get_remote_erc20_asset_id().into()),
+			2
+		));
+		assert_eq!(
+			PendingSwitches::<MockRuntime>::iter_prefix(VersionedAssetId::from(get_remote_erc20_asset_id())).count(),
+			1
+		);
+		assert!(System::events().into_iter().map(|e| e.event).any(|e| e
+			== Event::<MockRuntime>::SwitchPairStorageRemoved {
+				remote_asset_id: get_remote_erc20_asset_id().into(),
+				removed_entries: 2,
+				complete: false,
+			}
+			.into()));
+
+		assert_ok!(Pallet::<MockRuntime>::remove_switch_pair_storage(
+			RawOrigin::Signed(user.clone()).into(),
+			Box::new(get_remote_erc20_asset_id().into()),
+			2
+		));
+		assert_eq!(
+			PendingSwitches::<MockRuntime>::iter_prefix(VersionedAssetId::from(get_remote_erc20_asset_id())).count(),
+			0
+		);
+		assert!(System::events().into_iter().map(|e| e.event).any(|e| e
+			== Event::<MockRuntime>::SwitchPairStorageRemoved {
+				remote_asset_id: get_remote_erc20_asset_id().into(),
+				removed_entries: 1,
+				complete: true,
+			}
+			.into()));
+	});
+}
+
+#[test]
+fn successful_with_account_switch_usages() {
+	let user = AccountId32::from([0; 32]);
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		insert_pending_switches(2);
+		insert_account_switch_usages(2);
+
+		// Pending switches are removed before the rate limit usage.
+		assert_ok!(Pallet::<MockRuntime>::remove_switch_pair_storage(
+			RawOrigin::Signed(user.clone()).into(),
+			Box::new(get_remote_erc20_asset_id().into()),
+			3
+		));
+		assert_eq!(
+			PendingSwitches::<MockRuntime>::iter_prefix(VersionedAssetId::from(get_remote_erc20_asset_id())).count(),
+			0
+		);
+		assert_eq!(
+			AccountSwitchUsage::<MockRuntime>::iter_prefix(VersionedAssetId::from(get_remote_erc20_asset_id())).count(),
+			1
+		);
+		assert!(System::events().into_iter().map(|e| e.event).any(|e| e
+			== Event::<MockRuntime>::SwitchPairStorageRemoved {
+				remote_asset_id: get_remote_erc20_asset_id().into(),
+				removed_entries: 3,
+				complete: false,
+			}
+			.into()));
+
+		assert_ok!(Pallet::<MockRuntime>::remove_switch_pair_storage(
+			RawOrigin::Signed(user.clone()).into(),
+			Box::new(get_remote_erc20_asset_id().into()),
+			3
+		));
+		assert_eq!(
+			AccountSwitchUsage::<MockRuntime>::iter_prefix(VersionedAssetId::from(get_remote_erc20_asset_id())).count(),
+			0
+		);
+		assert!(System::events().into_iter().map(|e| e.event).any(|e| e
+			== Event::<MockRuntime>::SwitchPairStorageRemoved {
+				remote_asset_id: get_remote_erc20_asset_id().into(),
+				removed_entries: 1,
+				complete: true,
+			}
+			.into()));
+	});
+}
+
+#[test]
+fn fails_on_invalid_origin() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_noop!(
+			Pallet::<MockRuntime>::remove_switch_pair_storage(
+				RawOrigin::None.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				1
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn fails_on_existing_switch_pair() {
+	let user = AccountId32::from([0; 32]);
+	ExtBuilder::default()
+		.with_switch_pair_info(NewSwitchPairInfoOf::<MockRuntime> {
+			pool_account: AccountId32::from([1; 32]),
+			remote_asset_circulating_supply: 0,
+			remote_asset_ed: 0,
+			remote_asset_id: get_remote_erc20_asset_id().into(),
+			remote_asset_total_supply: 100_000,
+			remote_reserve_location: get_asset_hub_location().into(),
+			remote_xcm_fee: XCM_ASSET_FEE.into(),
+			status: SwitchPairStatus::Running,
+		})
+		.build_and_execute_with_sanity_tests(|| {
+			insert_pending_switches(1);
+
+			assert_noop!(
+				Pallet::<MockRuntime>::remove_switch_pair_storage(
+					RawOrigin::Signed(user.clone()).into(),
+					Box::new(get_remote_erc20_asset_id().into()),
+					1
+				),
+				Error::<MockRuntime>::SwitchPairAlreadyExisting
+			);
+		});
+}
@@ -23,7 +23,7 @@ use sp_runtime::DispatchError;
 use crate::{
 	mock::{get_asset_hub_location, get_remote_erc20_asset_id, ExtBuilder, MockRuntime, System, XCM_ASSET_FEE},
 	switch::SwitchPairStatus,
-	Error, Event, NewSwitchPairInfoOf, Pallet, SwitchPair,
+	Error, Event, NewSwitchPairInfoOf, Pallet,
 };
 
 #[test]
@@ -41,9 +41,14 @@ fn successful() {
 			status: SwitchPairStatus::Paused,
 		})
 		.build_and_execute_with_sanity_tests(|| {
-			assert_ok!(Pallet::<MockRuntime>::resume_switch_pair(RawOrigin::Root.into()));
+			assert_ok!(Pallet::<MockRuntime>::resume_switch_pair(
+				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into())
+			));
 			assert_eq!(
-				SwitchPair::<MockRuntime>::get().unwrap().status,
+				Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into())
+					.unwrap()
+					.status,
 				SwitchPairStatus::Running
 			);
 			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
@@ -65,9 +70,14 @@ fn successful() {
 			status: SwitchPairStatus::Running,
 		})
 		.build_and_execute_with_sanity_tests(|| {
-			assert_ok!(Pallet::<MockRuntime>::resume_switch_pair(RawOrigin::Root.into()));
+			assert_ok!(Pallet::<MockRuntime>::resume_switch_pair(
+				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into())
+			));
 			assert_eq!(
-				SwitchPair::<MockRuntime>::get().unwrap().status,
+				Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into())
+					.unwrap()
+					.status,
 				SwitchPairStatus::Running
 			);
 			assert!(System::events().into_iter().map(|e| e.event).all(|e| e
@@ -82,7 +92,10 @@ fn successful() {
 fn fails_on_non_existing_pair() {
 	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
 		assert_noop!(
-			Pallet::<MockRuntime>::resume_switch_pair(RawOrigin::Root.into()),
+			Pallet::<MockRuntime>::resume_switch_pair(
+				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into())
+			),
 			Error::<MockRuntime>::SwitchPairNotFound
 		);
 	});
@@ -92,7 +105,10 @@ fn fails_on_non_existing_pair() {
 fn fails_on_invalid_origin() {
 	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
 		assert_noop!(
-			Pallet::<MockRuntime>::resume_switch_pair(RawOrigin::None.into()),
+			Pallet::<MockRuntime>::resume_switch_pair(
+				RawOrigin::None.into(),
+				Box::new(get_remote_erc20_asset_id().into())
+			),
 			DispatchError::BadOrigin
 		);
 	});
@@ -22,11 +22,16 @@ use sp_runtime::{
 	traits::{One, Zero},
 	DispatchError,
 };
+use xcm::v4::{
+	AssetId,
+	Junction::{AccountKey20, GlobalConsensus},
+	Location, NetworkId,
+};
 
 use crate::{
 	mock::{get_asset_hub_location, get_remote_erc20_asset_id, ExtBuilder, MockRuntime, System, XCM_ASSET_FEE},
 	switch::SwitchPairStatus,
-	Error, Event, NewSwitchPairInfoOf, Pallet, SwitchPair, SwitchPairInfoOf,
+	Error, Event, NewSwitchPairInfoOf, Pallet, SwitchPairInfoOf,
 };
 
 #[test]
@@ -46,7 +51,7 @@ fn successful() {
 				Box::new(XCM_ASSET_FEE.into()),
 			));
 
-			let switch_pair = SwitchPair::<MockRuntime>::get();
+			let switch_pair = Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into());
 			let expected_switch_pair =
 				SwitchPairInfoOf::<MockRuntime>::from_input_unchecked(NewSwitchPairInfoOf::<MockRuntime> {
 					pool_account: pool_account_address.clone(),
@@ -92,7 +97,7 @@ fn successful() {
 				Box::new(XCM_ASSET_FEE.into()),
 			));
 
-			let switch_pair = SwitchPair::<MockRuntime>::get();
+			let switch_pair = Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into());
 			let expected_switch_pair =
 				SwitchPairInfoOf::<MockRuntime>::from_input_unchecked(NewSwitchPairInfoOf::<MockRuntime> {
 					pool_account: pool_account_address.clone(),
@@ -133,7 +138,7 @@ fn successful() {
 				Box::new(XCM_ASSET_FEE.into()),
 			));
 
-			let switch_pair = SwitchPair::<MockRuntime>::get();
+			let switch_pair = Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into());
 			let expected_switch_pair =
 				SwitchPairInfoOf::<MockRuntime>::from_input_unchecked(NewSwitchPairInfoOf::<MockRuntime> {
 					pool_account: pool_account_address.clone(),
@@ -180,7 +185,7 @@ fn successful() {
 				Box::new(XCM_ASSET_FEE.into()),
 			));
 
-			let switch_pair = SwitchPair::<MockRuntime>::get();
+			let switch_pair = Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into());
 			let expected_switch_pair =
 				SwitchPairInfoOf::<MockRuntime>::from_input_unchecked(NewSwitchPairInfoOf::<MockRuntime> {
 					pool_account: pool_account_address.clone(),
@@ -223,7 +228,7 @@ fn successful() {
 				Box::new(XCM_ASSET_FEE.into()),
 			));
 
-			let switch_pair = SwitchPair::<MockRuntime>::get();
+			let switch_pair = Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into());
 			let expected_switch_pair =
 				SwitchPairInfoOf::<MockRuntime>::from_input_unchecked(NewSwitchPairInfoOf::<MockRuntime> {
 					pool_account: pool_account_address.clone(),
@@ -251,6 +256,76 @@ fn successful() {
 		});
 }
 
+#[test]
+fn successful_with_multiple_switch_pairs() {
+	let other_remote_asset_id = AssetId(Location {
+		parents: 2,
+		interior: [
+			GlobalConsensus(NetworkId::Ethereum { chain_id: 1 }),
+			AccountKey20 {
+				network: None,
+				key: *b"!!other_eth_addr!!!!",
+			},
+		]
+		.into(),
+	});
+	let pool_account_address =
+		Pallet::<MockRuntime>::pool_account_id_for_remote_asset(&get_remote_erc20_asset_id().into()).unwrap();
+	let other_pool_account_address =
+		Pallet::<MockRuntime>::pool_account_id_for_remote_asset(&other_remote_asset_id.clone().into()).unwrap();
+	assert_ne!(pool_account_address, other_pool_account_address);
+	ExtBuilder::default()
+		.with_balances(vec![
+			(pool_account_address.clone(), 1_001, 0, 0),
+			(other_pool_account_address.clone(), 501, 0, 0),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<MockRuntime>::set_switch_pair(
+				RawOrigin::Root.into(),
+				u64::MAX as u128,
+				Box::new(get_remote_erc20_asset_id().into()),
+				1_000,
+				Box::new(get_asset_hub_location().into()),
+				0,
+				Box::new(XCM_ASSET_FEE.into()),
+			));
+			assert_ok!(Pallet::<MockRuntime>::set_switch_pair(
+				RawOrigin::Root.into(),
+				u64::MAX as u128,
+				Box::new(other_remote_asset_id.clone().into()),
+				500,
+				Box::new(get_asset_hub_location().into()),
+				0,
+				Box::new(XCM_ASSET_FEE.into()),
+			));
+
+			let switch_pair = Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into()).unwrap();
+			assert_eq!(switch_pair.pool_account, pool_account_address);
+			assert_eq!(switch_pair.remote_asset_circulating_supply, 1_000);
+			let other_switch_pair = Pallet::<MockRuntime>::switch_pair(&other_remote_asset_id.clone().into()).unwrap();
+			assert_eq!(other_switch_pair.pool_account, other_pool_account_address);
+			assert_eq!(other_switch_pair.remote_asset_circulating_supply, 500);
+
+			// Resuming one switch pair does not affect the other one.
+			assert_ok!(Pallet::<MockRuntime>::resume_switch_pair(
+				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into())
+			));
+			assert_eq!(
+				Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into())
+					.unwrap()
+					.status,
+				SwitchPairStatus::Running
+			);
+			assert_eq!(
+				Pallet::<MockRuntime>::switch_pair(&other_remote_asset_id.into())
+					.unwrap()
+					.status,
+				SwitchPairStatus::Paused
+			);
+		});
+}
+
 #[test]
 fn fails_on_invalid_origin() {
 	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
@@ -0,0 +1,143 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use sp_runtime::DispatchError;
+
+use crate::{
+	mock::{get_asset_hub_location, get_remote_erc20_asset_id, ExtBuilder, MockRuntime, System, XCM_ASSET_FEE},
+	Error, Event, NewSwitchPairInfoOf, Pallet, SwitchPairRateLimits, SwitchRateLimitsOf,
+};
+
+#[test]
+fn successful() {
+	let limits = SwitchRateLimitsOf::<MockRuntime> {
+		period: 10,
+		max_amount: Some(1_000),
+		max_switches: Some(10),
+		max_amount_per_account: Some(100),
+		max_switches_per_account: Some(1),
+	};
+	ExtBuilder::default()
+		.with_switch_pair_info(NewSwitchPairInfoOf::<MockRuntime> {
+			pool_account: [0u8; 32].into(),
+			remote_asset_circulating_supply: 0,
+			remote_asset_ed: 0,
+			remote_asset_id: get_remote_erc20_asset_id().into(),
+			remote_asset_total_supply: 1_000,
+			remote_reserve_location: get_asset_hub_location().into(),
+			remote_xcm_fee: XCM_ASSET_FEE.into(),
+			status: Default::default(),
+		})
+		.build_and_execute_with_sanity_tests(|| {
+			// Setting the limits stores them and generates an event.
+			assert_ok!(Pallet::<MockRuntime>::set_switch_pair_rate_limits(
+				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				Some(limits.clone())
+			));
+			assert_eq!(
+				SwitchPairRateLimits::<MockRuntime>::get(
+					Pallet::<MockRuntime>::switch_pair_key(&get_remote_erc20_asset_id().into()).unwrap()
+				),
+				Some(limits.clone())
+			);
+			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
+				== Event::<MockRuntime>::SwitchPairRateLimitsUpdated {
+					remote_asset_id: get_remote_erc20_asset_id().into(),
+					limits: Some(limits.clone())
+				}
+				.into()));
+
+			// Removing the limits deletes them and generates an event.
+			assert_ok!(Pallet::<MockRuntime>::set_switch_pair_rate_limits(
+				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				None
+			));
+			assert!(SwitchPairRateLimits::<MockRuntime>::get(
+				Pallet::<MockRuntime>::switch_pair_key(&get_remote_erc20_asset_id().into()).unwrap()
+			)
+			.is_none());
+			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
+				== Event::<MockRuntime>::SwitchPairRateLimitsUpdated {
+					remote_asset_id: get_remote_erc20_asset_id().into(),
+					limits: None
+				}
+				.into()));
+		});
+}
+
+#[test]
+fn successful_removed_with_switch_pair() {
+	ExtBuilder::default()
+		.with_switch_pair_info(NewSwitchPairInfoOf::<MockRuntime> {
+			pool_account: [0u8; 32].into(),
+			remote_asset_circulating_supply: 0,
+			remote_asset_ed: 0,
+			remote_asset_id: get_remote_erc20_asset_id().into(),
+			remote_asset_total_supply: 1_000,
+			remote_reserve_location: get_asset_hub_location().into(),
+			remote_xcm_fee: XCM_ASSET_FEE.into(),
+			status: Default::default(),
+		})
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<MockRuntime>::set_switch_pair_rate_limits(
+				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				Some(Default::default())
+			));
+			assert_ok!(Pallet::<MockRuntime>::force_unset_switch_pair(
+				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+			));
+			assert!(SwitchPairRateLimits::<MockRuntime>::get(
+				Pallet::<MockRuntime>::switch_pair_key(&get_remote_erc20_asset_id().into()).unwrap()
+			)
+			.is_none());
+		});
+}
+
+#[test]
+fn fails_on_invalid_origin() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_noop!(
+			Pallet::<MockRuntime>::set_switch_pair_rate_limits(
+				RawOrigin::None.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				None,
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn fails_on_non_existing_switch_pair() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_noop!(
+			Pallet::<MockRuntime>::set_switch_pair_rate_limits(
+				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				None,
+			),
+			Error::<MockRuntime>::SwitchPairNotFound
+		);
+	});
+}
@@ -25,16 +25,24 @@ use sp_runtime::{
 	traits::{One, TryConvert, Zero},
 	AccountId32, DispatchError,
 };
-use xcm::v4::{Asset, Fungibility};
+use xcm::{
+	v4::{
+		Asset, Fungibility,
+		Instruction::{
+			BuyExecution, DepositAsset, RefundSurplus, ReportError, SetAppendix, TransferAsset, WithdrawAsset,
+		},
+	},
+	IntoVersion, VersionedAssetId,
+};
 
 use crate::{
 	mock::{
-		get_asset_hub_location, get_remote_erc20_asset_id, Balances, ExtBuilder, MockFungibleAssetTransactor,
-		MockRuntime, System, FREEZE_REASON, HOLD_REASON, XCM_ASSET_FEE,
+		get_asset_hub_location, get_remote_erc20_asset_id, AlwaysSuccessfulXcmRouter, Balances, ExtBuilder,
+		MockFungibleAssetTransactor, MockRuntime, System, FREEZE_REASON, HOLD_REASON, XCM_ASSET_FEE,
 	},
 	switch::SwitchPairStatus,
 	xcm::convert::AccountId32ToAccountId32JunctionConverter,
-	Error, Event, NewSwitchPairInfoOf, Pallet, SwitchPair,
+	Error, Event, NewSwitchPairInfoOf, Pallet, SwitchRateLimit, SwitchRateLimitsOf,
 };
 
 #[test]
@@ -59,6 +67,7 @@ fn successful() {
 			let total_currency_issuance_before = <Balances as Inspect<AccountId32>>::total_issuance();
 			assert_ok!(Pallet::<MockRuntime>::switch(
 				RawOrigin::Signed(user.clone()).into(),
+				Box::new(get_remote_erc20_asset_id().into()),
 				// Cannot switch ED (1 in the mock), so we need to exclude that.
 				99_999,
 				Box::new(get_asset_hub_location().into())
@@ -78,7 +87,7 @@ fn successful() {
 				100_000
 			);
 			// Pool's remote balance is decreased by switch amount
-			assert!(SwitchPair::<MockRuntime>::get()
+			assert!(Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into())
 				.unwrap()
 				.reducible_remote_balance()
 				.is_one());
@@ -99,9 +108,11 @@ fn successful() {
 			.is_zero());
 			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
 				== Event::<MockRuntime>::LocalToRemoteSwitchExecuted {
+					remote_asset_id: get_remote_erc20_asset_id().into(),
 					amount: 99_999,
 					from: user.clone(),
-					to: get_asset_hub_location().into()
+					to: get_asset_hub_location().into(),
+					query_id: 0
 				}
 				.into()));
 		});
@@ -123,6 +134,7 @@ fn successful() {
 			let total_currency_issuance_before = <Balances as Inspect<AccountId32>>::total_issuance();
 			assert_ok!(Pallet::<MockRuntime>::switch(
 				RawOrigin::Signed(user.clone()).into(),
+				Box::new(get_remote_erc20_asset_id().into()),
 				99_999,
 				Box::new(get_asset_hub_location().into())
 			));
@@ -141,7 +153,7 @@ fn successful() {
 				100_000
 			);
 			// Pool's remote balance is decreased by switch amount
-			assert!(SwitchPair::<MockRuntime>::get()
+			assert!(Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into())
 				.unwrap()
 				.reducible_remote_balance()
 				.is_one());
@@ -162,9 +174,11 @@ fn successful() {
 			.is_zero());
 			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
 				== Event::<MockRuntime>::LocalToRemoteSwitchExecuted {
+					remote_asset_id: get_remote_erc20_asset_id().into(),
 					amount: 99_999,
 					from: user.clone(),
-					to: get_asset_hub_location().into()
+					to: get_asset_hub_location().into(),
+					query_id: 0
 				}
 				.into()));
 		});
@@ -187,6 +201,7 @@ fn successful() {
 			let total_currency_issuance_before = <Balances as Inspect<AccountId32>>::total_issuance();
 			assert_ok!(Pallet::<MockRuntime>::switch(
 				RawOrigin::Signed(user.clone()).into(),
+				Box::new(get_remote_erc20_asset_id().into()),
 				99_999,
 				Box::new(get_asset_hub_location().into())
 			));
@@ -205,7 +220,7 @@ fn successful() {
 				100_000
 			);
 			// Pool's remote balance is decreased by switch amount
-			assert!(SwitchPair::<MockRuntime>::get()
+			assert!(Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into())
 				.unwrap()
 				.reducible_remote_balance()
 				.is_one());
@@ -226,19 +241,112 @@ fn successful() {
 			.is_zero());
 			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
 				== Event::<MockRuntime>::LocalToRemoteSwitchExecuted {
+					remote_asset_id: get_remote_erc20_asset_id().into(),
 					amount: 99_999,
 					from: user.clone(),
-					to: get_asset_hub_location().into()
+					to: get_asset_hub_location().into(),
+					query_id: 0
 				}
 				.into()));
 		});
 }
 
+#[test]
+fn successful_with_switch_pair_set_from_remote_asset_id_v3() {
+	let user = AccountId32::from([0; 32]);
+	let remote_asset_id_v3 = VersionedAssetId::from(get_remote_erc20_asset_id())
+		.into_version(3)
+		.unwrap();
+	let pool_account = Pallet::<MockRuntime>::pool_account_id_for_remote_asset(&remote_asset_id_v3).unwrap();
+	// The pool account does not depend on the version of the remote asset ID.
+	assert_eq!(
+		pool_account,
+		Pallet::<MockRuntime>::pool_account_id_for_remote_asset(&get_remote_erc20_asset_id().into()).unwrap()
+	);
+	ExtBuilder::default()
+		.with_balances(vec![(user.clone(), 100_000, 0, 0), (pool_account.clone(), 1, 0, 0)])
+		.with_fungibles(vec![(user.clone(), XCM_ASSET_FEE)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<MockRuntime>::set_switch_pair(
+				RawOrigin::Root.into(),
+				100_000,
+				Box::new(remote_asset_id_v3.clone()),
+				0,
+				Box::new(get_asset_hub_location().into()),
+				0,
+				Box::new(XCM_ASSET_FEE.into()),
+			));
+			assert_ok!(Pallet::<MockRuntime>::switch(
+				RawOrigin::Signed(user.clone()).into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				99_999,
+				Box::new(get_asset_hub_location().into())
+			));
+			// The local tokens are transferred to the same pool account, regardless of
+			// the version of the remote asset ID used to set and to use the switch pair.
+			assert_eq!(
+				<Balances as Inspect<AccountId32>>::total_balance(&pool_account),
+				100_000
+			);
+			assert_eq!(
+				Pallet::<MockRuntime>::switch_pair(&remote_asset_id_v3)
+					.unwrap()
+					.pool_account,
+				pool_account
+			);
+		});
+}
+
+#[test]
+fn sends_outcome_report_before_transfer() {
+	let user = AccountId32::from([0; 32]);
+	let pool_account = AccountId32::from([1; 32]);
+	ExtBuilder::default()
+		.with_balances(vec![(user.clone(), 100_000, 0, 0), (pool_account.clone(), 1, 0, 0)])
+		.with_fungibles(vec![(user.clone(), XCM_ASSET_FEE)])
+		.with_switch_pair_info(NewSwitchPairInfoOf::<MockRuntime> {
+			pool_account: pool_account.clone(),
+			remote_asset_circulating_supply: 0,
+			remote_asset_ed: 0,
+			remote_asset_id: get_remote_erc20_asset_id().into(),
+			remote_asset_total_supply: 100_000,
+			remote_reserve_location: get_asset_hub_location().into(),
+			remote_xcm_fee: XCM_ASSET_FEE.into(),
+			status: SwitchPairStatus::Running,
+		})
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<MockRuntime>::switch(
+				RawOrigin::Signed(user.clone()).into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				99_999,
+				Box::new(get_asset_hub_location().into())
+			));
+			let (destination, message) = AlwaysSuccessfulXcmRouter::last_sent_xcm().unwrap();
+			assert_eq!(destination, get_asset_hub_location());
+			// The appendix is set after paying for execution, as required by the remote
+			// barrier, and before the transfer whose failure it must report.
+			let [WithdrawAsset(_), BuyExecution { .. }, SetAppendix(appendix), TransferAsset { .. }] =
+				message.0.as_slice()
+			else {
+				panic!("Unexpected switch XCM {:?}", message);
+			};
+			let [ReportError(response_info), RefundSurplus, DepositAsset { .. }] = appendix.0.as_slice() else {
+				panic!("Unexpected switch XCM appendix {:?}", appendix);
+			};
+			assert_eq!(response_info.query_id, 0);
+		});
+}
+
 #[test]
 fn fails_on_invalid_origin() {
 	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
 		assert_noop!(
-			Pallet::<MockRuntime>::switch(RawOrigin::Root.into(), 1, Box::new(get_asset_hub_location().into())),
+			Pallet::<MockRuntime>::switch(
+				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				1,
+				Box::new(get_asset_hub_location().into())
+			),
 			DispatchError::BadOrigin
 		);
 	});
@@ -251,6 +359,7 @@ fn fails_on_non_existing_pool() {
 		assert_noop!(
 			Pallet::<MockRuntime>::switch(
 				RawOrigin::Signed(user).into(),
+				Box::new(get_remote_erc20_asset_id().into()),
 				1,
 				Box::new(get_asset_hub_location().into())
 			),
@@ -278,6 +387,7 @@ fn fails_on_pool_not_running() {
 			assert_noop!(
 				Pallet::<MockRuntime>::switch(
 					RawOrigin::Signed(user).into(),
+					Box::new(get_remote_erc20_asset_id().into()),
 					1,
 					Box::new(get_asset_hub_location().into())
 				),
@@ -306,6 +416,7 @@ fn fails_on_not_enough_user_local_balance() {
 			assert_noop!(
 				Pallet::<MockRuntime>::switch(
 					RawOrigin::Signed(user.clone()).into(),
+					Box::new(get_remote_erc20_asset_id().into()),
 					100_000,
 					Box::new(get_asset_hub_location().into())
 				),
@@ -329,6 +440,7 @@ fn fails_on_not_enough_user_local_balance() {
 			assert_noop!(
 				Pallet::<MockRuntime>::switch(
 					RawOrigin::Signed(user.clone()).into(),
+					Box::new(get_remote_erc20_asset_id().into()),
 					100_000,
 					Box::new(get_asset_hub_location().into())
 				),
@@ -352,6 +464,7 @@ fn fails_on_not_enough_user_local_balance() {
 			assert_noop!(
 				Pallet::<MockRuntime>::switch(
 					RawOrigin::Signed(user.clone()).into(),
+					Box::new(get_remote_erc20_asset_id().into()),
 					100_000,
 					Box::new(get_asset_hub_location().into())
 				),
@@ -375,6 +488,7 @@ fn fails_on_not_enough_user_local_balance() {
 			assert_noop!(
 				Pallet::<MockRuntime>::switch(
 					RawOrigin::Signed(user).into(),
+					Box::new(get_remote_erc20_asset_id().into()),
 					100_000,
 					Box::new(get_asset_hub_location().into())
 				),
@@ -404,6 +518,7 @@ fn fails_on_not_enough_remote_balance() {
 			assert_noop!(
 				Pallet::<MockRuntime>::switch(
 					RawOrigin::Signed(user.clone()).into(),
+					Box::new(get_remote_erc20_asset_id().into()),
 					50_001,
 					Box::new(get_asset_hub_location().into())
 				),
@@ -427,6 +542,7 @@ fn fails_on_not_enough_remote_balance() {
 			assert_noop!(
 				Pallet::<MockRuntime>::switch(
 					RawOrigin::Signed(user.clone()).into(),
+					Box::new(get_remote_erc20_asset_id().into()),
 					// Tradeable are only 49_999 because of the remote ED.
 					50_000,
 					Box::new(get_asset_hub_location().into())
@@ -464,6 +580,7 @@ fn fails_on_not_enough_user_xcm_balance() {
 			assert_noop!(
 				Pallet::<MockRuntime>::switch(
 					RawOrigin::Signed(user.clone()).into(),
+					Box::new(get_remote_erc20_asset_id().into()),
 					50_001,
 					Box::new(get_asset_hub_location().into())
 				),
@@ -471,3 +588,94 @@ fn fails_on_not_enough_user_xcm_balance() {
 			);
 		});
 }
+
+#[test]
+fn fails_on_rate_limit_exceeded() {
+	let user = AccountId32::from([0; 32]);
+	let pool_account = AccountId32::from([1; 32]);
+	ExtBuilder::default()
+		.with_balances(vec![(user.clone(), 100_000, 0, 0), (pool_account.clone(), 1, 0, 0)])
+		.with_fungibles(vec![(
+			user.clone(),
+			Asset {
+				fun: Fungibility::Fungible(4_000),
+				..XCM_ASSET_FEE
+			},
+		)])
+		.with_switch_pair_info(NewSwitchPairInfoOf::<MockRuntime> {
+			pool_account: pool_account.clone(),
+			remote_asset_circulating_supply: 0,
+			remote_asset_ed: 0,
+			remote_asset_id: get_remote_erc20_asset_id().into(),
+			remote_asset_total_supply: 100_000,
+			remote_reserve_location: get_asset_hub_location().into(),
+			remote_xcm_fee: XCM_ASSET_FEE.into(),
+			status: SwitchPairStatus::Running,
+		})
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<MockRuntime>::set_switch_pair_rate_limits(
+				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				Some(SwitchRateLimitsOf::<MockRuntime> {
+					period: 10,
+					max_switches: Some(2),
+					max_amount_per_account: Some(200),
+					..Default::default()
+				})
+			));
+			for _ in 0..2 {
+				assert_ok!(Pallet::<MockRuntime>::switch(
+					RawOrigin::Signed(user.clone()).into(),
+					Box::new(get_remote_erc20_asset_id().into()),
+					100,
+					Box::new(get_asset_hub_location().into())
+				));
+			}
+			// Both limits are reached with the second switch.
+			for limit in [SwitchRateLimit::Switches, SwitchRateLimit::AmountPerAccount] {
+				assert!(System::events().into_iter().map(|e| e.event).any(|e| e
+					== Event::<MockRuntime>::SwitchPairRateLimitReached {
+						remote_asset_id: get_remote_erc20_asset_id().into(),
+						account: user.clone(),
+						limit
+					}
+					.into()));
+			}
+			assert_noop!(
+				Pallet::<MockRuntime>::switch(
+					RawOrigin::Signed(user.clone()).into(),
+					Box::new(get_remote_erc20_asset_id().into()),
+					1,
+					Box::new(get_asset_hub_location().into())
+				),
+				Error::<MockRuntime>::RateLimitExceeded
+			);
+			// The switches of the previous window still count towards the limits,
+			// weighted by how much of it is within the last 10 blocks.
+			System::set_block_number(11);
+			assert_noop!(
+				Pallet::<MockRuntime>::switch(
+					RawOrigin::Signed(user.clone()).into(),
+					Box::new(get_remote_erc20_asset_id().into()),
+					1,
+					Box::new(get_asset_hub_location().into())
+				),
+				Error::<MockRuntime>::RateLimitExceeded
+			);
+			System::set_block_number(15);
+			assert_ok!(Pallet::<MockRuntime>::switch(
+				RawOrigin::Signed(user.clone()).into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				100,
+				Box::new(get_asset_hub_location().into())
+			));
+			// Switches are possible again once the previous window is over.
+			System::set_block_number(30);
+			assert_ok!(Pallet::<MockRuntime>::switch(
+				RawOrigin::Signed(user.clone()).into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				100,
+				Box::new(get_asset_hub_location().into())
+			));
+		});
+}
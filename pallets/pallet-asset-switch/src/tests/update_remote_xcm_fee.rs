@@ -23,7 +23,7 @@ use xcm::v4::{Asset, Fungibility};
 
 use crate::{
 	mock::{get_asset_hub_location, get_remote_erc20_asset_id, ExtBuilder, MockRuntime, System, XCM_ASSET_FEE},
-	Error, Event, NewSwitchPairInfoOf, Pallet, SwitchPair, SwitchPairStatus,
+	Error, Event, NewSwitchPairInfoOf, Pallet, SwitchPairStatus,
 };
 
 #[test]
@@ -47,14 +47,18 @@ fn successful() {
 		.build_and_execute_with_sanity_tests(|| {
 			assert_ok!(Pallet::<MockRuntime>::update_remote_xcm_fee(
 				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
 				Box::new(new_fee.clone().into())
 			));
 			assert_eq!(
-				SwitchPair::<MockRuntime>::get().unwrap().remote_xcm_fee,
+				Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into())
+					.unwrap()
+					.remote_xcm_fee,
 				new_fee.clone().into()
 			);
 			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
 				== Event::<MockRuntime>::SwitchPairFeeUpdated {
+					remote_asset_id: get_remote_erc20_asset_id().into(),
 					old: XCM_ASSET_FEE.into(),
 					new: new_fee.clone().into()
 				}
@@ -75,14 +79,18 @@ fn successful() {
 		.build_and_execute_with_sanity_tests(|| {
 			assert_ok!(Pallet::<MockRuntime>::update_remote_xcm_fee(
 				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
 				Box::new(XCM_ASSET_FEE.into())
 			));
 			assert_eq!(
-				SwitchPair::<MockRuntime>::get().unwrap().remote_xcm_fee,
+				Pallet::<MockRuntime>::switch_pair(&get_remote_erc20_asset_id().into())
+					.unwrap()
+					.remote_xcm_fee,
 				XCM_ASSET_FEE.into()
 			);
 			assert!(System::events().into_iter().map(|e| e.event).all(|e| e
 				!= Event::<MockRuntime>::SwitchPairFeeUpdated {
+					remote_asset_id: get_remote_erc20_asset_id().into(),
 					old: XCM_ASSET_FEE.into(),
 					new: XCM_ASSET_FEE.into(),
 				}
@@ -94,7 +102,11 @@ fn successful() {
 fn fails_on_invalid_origin() {
 	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
 		assert_noop!(
-			Pallet::<MockRuntime>::update_remote_xcm_fee(RawOrigin::None.into(), Box::new(XCM_ASSET_FEE.into()),),
+			Pallet::<MockRuntime>::update_remote_xcm_fee(
+				RawOrigin::None.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				Box::new(XCM_ASSET_FEE.into()),
+			),
 			DispatchError::BadOrigin
 		);
 	});
@@ -104,7 +116,11 @@ fn fails_on_invalid_origin() {
 fn fails_on_non_existing_switch_pair() {
 	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
 		assert_noop!(
-			Pallet::<MockRuntime>::update_remote_xcm_fee(RawOrigin::Root.into(), Box::new(XCM_ASSET_FEE.into()),),
+			Pallet::<MockRuntime>::update_remote_xcm_fee(
+				RawOrigin::Root.into(),
+				Box::new(get_remote_erc20_asset_id().into()),
+				Box::new(XCM_ASSET_FEE.into()),
+			),
 			Error::<MockRuntime>::SwitchPairNotFound
 		);
 	});
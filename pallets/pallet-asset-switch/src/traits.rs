@@ -16,21 +16,33 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use xcm::VersionedLocation;
+use frame_support::Parameter;
+use parity_scale_codec::MaxEncodedLen;
+use xcm::{VersionedAssetId, VersionedLocation};
 
 use crate::{Config, LocalCurrencyBalanceOf};
 
 /// Runtime-injected logic into the switch pallet around the time a switch takes
 /// place.
+///
+/// Each hook is called with the ID of the remote asset of the switch pair,
+/// converted to the latest XCM version, and with the rules set for the switch
+/// pair, if any, so that implementations can apply different logic for
+/// different switch pairs.
 pub trait SwitchHooks<T, I>
 where
 	T: Config<I>,
 	I: 'static,
 {
 	type Error: Into<u8>;
+	/// The per-switch pair configuration of the hooks, as set by the
+	/// `SwitchOrigin`.
+	type Rules: Parameter + MaxEncodedLen;
 
 	/// Called before anything related to a switch happens.
 	fn pre_local_to_remote_switch(
+		remote_asset_id: &VersionedAssetId,
+		rules: Option<&Self::Rules>,
 		from: &T::AccountId,
 		to: &VersionedLocation,
 		amount: LocalCurrencyBalanceOf<T, I>,
@@ -39,6 +51,8 @@ where
 	/// Called after the switch takes place and **after** the XCM message has
 	/// been sent to the reserve location.
 	fn post_local_to_remote_switch(
+		remote_asset_id: &VersionedAssetId,
+		rules: Option<&Self::Rules>,
 		from: &T::AccountId,
 		to: &VersionedLocation,
 		amount: LocalCurrencyBalanceOf<T, I>,
@@ -47,11 +61,21 @@ where
 	/// Called upon receiving an XCM message from the reserve location to
 	/// deposit some of the remote assets into a specified account, but before
 	/// the asset is actually deposited by the asset transactor.
-	fn pre_remote_to_local_switch(to: &T::AccountId, amount: u128) -> Result<(), Self::Error>;
+	fn pre_remote_to_local_switch(
+		remote_asset_id: &VersionedAssetId,
+		rules: Option<&Self::Rules>,
+		to: &T::AccountId,
+		amount: u128,
+	) -> Result<(), Self::Error>;
 
 	/// Same as [Self::pre_remote_to_local_switch], but called after the
 	/// transactor has deposited the incoming remote asset.
-	fn post_remote_to_local_switch(to: &T::AccountId, amount: u128) -> Result<(), Self::Error>;
+	fn post_remote_to_local_switch(
+		remote_asset_id: &VersionedAssetId,
+		rules: Option<&Self::Rules>,
+		to: &T::AccountId,
+		amount: u128,
+	) -> Result<(), Self::Error>;
 }
 
 impl<T, I> SwitchHooks<T, I> for ()
@@ -60,8 +84,11 @@ where
 	I: 'static,
 {
 	type Error = u8;
+	type Rules = ();
 
 	fn pre_local_to_remote_switch(
+		_remote_asset_id: &VersionedAssetId,
+		_rules: Option<&Self::Rules>,
 		_from: &T::AccountId,
 		_to: &VersionedLocation,
 		_amount: LocalCurrencyBalanceOf<T, I>,
@@ -70,6 +97,8 @@ where
 	}
 
 	fn post_local_to_remote_switch(
+		_remote_asset_id: &VersionedAssetId,
+		_rules: Option<&Self::Rules>,
 		_from: &T::AccountId,
 		_to: &VersionedLocation,
 		_amount: LocalCurrencyBalanceOf<T, I>,
@@ -77,11 +106,21 @@ where
 		Ok(())
 	}
 
-	fn pre_remote_to_local_switch(_to: &<T>::AccountId, _amount: u128) -> Result<(), Self::Error> {
+	fn pre_remote_to_local_switch(
+		_remote_asset_id: &VersionedAssetId,
+		_rules: Option<&Self::Rules>,
+		_to: &<T>::AccountId,
+		_amount: u128,
+	) -> Result<(), Self::Error> {
 		Ok(())
 	}
 
-	fn post_remote_to_local_switch(_to: &<T>::AccountId, _amount: u128) -> Result<(), Self::Error> {
+	fn post_remote_to_local_switch(
+		_remote_asset_id: &VersionedAssetId,
+		_rules: Option<&Self::Rules>,
+		_to: &<T>::AccountId,
+		_amount: u128,
+	) -> Result<(), Self::Error> {
 		Ok(())
 	}
 }
@@ -16,12 +16,12 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use frame_support::ensure;
+use frame_support::{ensure, traits::Get};
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::TryRuntimeError;
 use sp_std::cmp::Ordering;
 
-use crate::{Config, LocalCurrencyBalanceOf, Pallet, SwitchPair};
+use crate::{Config, LocalCurrencyBalanceOf, Pallet, SwitchPairInfoOf, SwitchPairs};
 
 const LOG_TARGET: &str = "try-state::pallet-asset-switch";
 
@@ -31,9 +31,19 @@ where
 	I: 'static,
 	LocalCurrencyBalanceOf<T, I>: Into<u128>,
 {
-	let Some(switch_pair) = SwitchPair::<T, I>::get() else {
-		return Ok(());
-	};
+	ensure!(
+		SwitchPairs::<T, I>::count() <= T::MaxSwitchPairs::get(),
+		TryRuntimeError::Other("More switch pairs than allowed by `MaxSwitchPairs`.")
+	);
+	SwitchPairs::<T, I>::iter_values().try_for_each(|switch_pair| do_try_state_for_switch_pair::<T, I>(&switch_pair))
+}
+
+fn do_try_state_for_switch_pair<T, I>(switch_pair: &SwitchPairInfoOf<T>) -> Result<(), TryRuntimeError>
+where
+	T: Config<I>,
+	I: 'static,
+	LocalCurrencyBalanceOf<T, I>: Into<u128>,
+{
 	// At all times, the circulating supply must be entirely covered by the
 	// reducible balance of the pool account.
 	ensure!(
@@ -16,12 +16,11 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use frame_support::ensure;
 use sp_std::marker::PhantomData;
 use xcm::v4::{Asset, AssetId, Fungibility, Location};
 use xcm_executor::traits::{Error as XcmExecutorError, MatchesFungibles};
 
-use crate::{Config, SwitchPair};
+use crate::{Config, SwitchPairs};
 
 #[cfg(test)]
 mod mock;
@@ -33,7 +32,7 @@ const LOG_TARGET: &str = "xcm::pallet-asset-switch::MatchesSwitchPairXcmFeeFungi
 /// Type implementing [MatchesFungibles] and returns the provided
 /// fungible amount if the specified `Location` matches the asset used by
 /// the switch pallet to pay for XCM fees at the configured remote location
-/// (`switch_pair_info.remote_xcm_fee`).
+/// (`switch_pair_info.remote_xcm_fee`) of any enabled switch pair.
 pub struct MatchesSwitchPairXcmFeeFungibleAsset<T, I>(PhantomData<(T, I)>);
 
 impl<T, I, FungiblesBalance> MatchesFungibles<Location, FungiblesBalance> for MatchesSwitchPairXcmFeeFungibleAsset<T, I>
@@ -44,34 +43,42 @@ where
 {
 	fn matches_fungibles(a: &Asset) -> Result<(Location, FungiblesBalance), XcmExecutorError> {
 		log::info!(target: LOG_TARGET, "matches_fungibles {:?}", a);
-		// 1. Retrieve switch pair from storage.
-		let switch_pair = SwitchPair::<T, I>::get().ok_or(XcmExecutorError::AssetNotHandled)?;
+		// 1. Find an enabled switch pair whose stored XCM fee asset is a fungible one
+		//    with the same ID as the input asset. There are at most `MaxSwitchPairs`
+		//    switch pairs to check.
+		let Asset { id, .. } = SwitchPairs::<T, I>::iter_values()
+			.filter(|switch_pair| switch_pair.is_enabled())
+			.find_map(|switch_pair| {
+				let stored_asset: Asset = switch_pair
+					.remote_xcm_fee
+					.clone()
+					.try_into()
+					.map_err(|e| {
+						log::error!(
+							target: LOG_TARGET,
+							"Failed to convert stored remote fee asset {:?} into v4 Location with error {:?}.",
+							switch_pair.remote_xcm_fee,
+							e
+						);
+						e
+					})
+					.ok()?;
+				if stored_asset.id != a.id {
+					return None;
+				}
+				let Fungibility::Fungible(_) = stored_asset.fun else {
+					log::info!(target: LOG_TARGET, "Stored remote fee asset {:?} is not a fungible one.", switch_pair.remote_xcm_fee);
+					return None;
+				};
+				Some(stored_asset)
+			})
+			.ok_or(XcmExecutorError::AssetNotHandled)?;
 
-		// 2. Ensure switch pair is enabled
-		ensure!(switch_pair.is_enabled(), XcmExecutorError::AssetNotHandled);
-
-		// 3. Match stored asset ID with input asset ID.
-		let Asset { id, fun } = switch_pair.remote_xcm_fee.clone().try_into().map_err(|e| {
-			log::error!(
-				target: LOG_TARGET,
-				"Failed to convert stored remote fee asset {:?} into v4 Location with error {:?}.",
-				switch_pair.remote_xcm_fee,
-				e
-			);
-			XcmExecutorError::AssetNotHandled
-		})?;
-		ensure!(id == a.id, XcmExecutorError::AssetNotHandled);
-		// 4. Verify the stored asset is a fungible one.
-		let Fungibility::Fungible(_) = fun else {
-			log::info!(target: LOG_TARGET, "Stored remote fee asset {:?} is not a fungible one.", switch_pair.remote_xcm_fee);
-			return Err(XcmExecutorError::AssetNotHandled);
-		};
-
-		// After this ensure, we know we need to be transacting with this asset, so any
+		// After this point, we know we need to be transacting with this asset, so any
 		// errors thrown from here onwards is a `FailedToTransactAsset` error.
 
 		let AssetId(location) = id;
-		// 5. Force input asset as a fungible one and return its amount.
+		// 2. Force input asset as a fungible one and return its amount.
 		let Fungibility::Fungible(amount) = a.fun else {
 			log::info!(target: LOG_TARGET, "Input asset {:?} is supposed to be fungible but it is not.", a);
 			return Err(XcmExecutorError::AmountToBalanceConversionFailed);
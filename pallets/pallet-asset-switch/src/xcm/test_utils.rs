@@ -22,7 +22,7 @@ use xcm::{
 	VersionedAsset, VersionedAssetId, VersionedLocation,
 };
 
-use crate::{Config, NewSwitchPairInfoOf, SwitchPairStatus};
+use crate::{Config, NewSwitchPairInfoOf, SwitchPairInfoOf, SwitchPairStatus, SwitchPairs};
 
 pub(super) fn get_switch_pair_info_for_remote_location_with_pool_usable_balance<Runtime>(
 	location: &Location,
@@ -58,3 +58,12 @@ where
 {
 	get_switch_pair_info_for_remote_location_with_pool_usable_balance::<Runtime>(location, 0, status)
 }
+
+/// Returns the switch pair stored in the mock runtime, if any. Mocks store at
+/// most one switch pair, which is what traders select upon weight purchase.
+pub(super) fn get_stored_switch_pair<Runtime>() -> Option<SwitchPairInfoOf<Runtime>>
+where
+	Runtime: Config,
+{
+	SwitchPairs::<Runtime>::iter_values().next()
+}
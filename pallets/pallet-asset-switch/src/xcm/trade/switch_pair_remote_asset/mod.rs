@@ -27,7 +27,7 @@ use sp_std::marker::PhantomData;
 use xcm::v4::{Asset, AssetId, Error, Weight, XcmContext, XcmHash};
 use xcm_executor::{traits::WeightTrader, AssetsInHolding};
 
-use crate::{Config, LocalCurrencyBalanceOf, SwitchPair, SwitchPairInfoOf};
+use crate::{Config, LocalCurrencyBalanceOf, Pallet, SwitchPairInfoOf, SwitchPairs};
 
 #[cfg(test)]
 mod mock;
@@ -37,7 +37,9 @@ mod tests;
 const LOG_TARGET: &str = "xcm::pallet-asset-switch::UsingComponentsForSwitchPairRemoteAsset";
 
 /// Type implementing [WeightTrader] that allows paying for XCM fees when
-/// reserve transferring the remote asset of the on-chain switch pair.
+/// reserve transferring the remote asset of any on-chain switch pair. The
+/// switch pair is selected upon weight purchase, from the first payment asset
+/// that has a switch pair set.
 ///
 /// This trader is required in case there is no other mechanism to pay for
 /// fees when transferring such an asset to this chain.
@@ -83,12 +85,11 @@ where
 	WeightToFee: WeightToFeeT<Balance = u128>,
 {
 	fn new() -> Self {
-		let switch_pair = SwitchPair::<T, I>::get();
 		Self {
 			consumed_xcm_hash: None,
 			remaining_fungible_balance: Zero::zero(),
 			remaining_weight: Zero::zero(),
-			switch_pair,
+			switch_pair: None,
 			_phantom: PhantomData,
 		}
 	}
@@ -109,8 +110,13 @@ where
 
 		// Prevent re-using the same trader more than once.
 		ensure!(self.consumed_xcm_hash.is_none(), Error::NotWithdrawable);
-		// Asset not relevant if no switch pair is set or if not enabled.
-		let switch_pair = self.switch_pair.as_ref().ok_or(Error::AssetNotFound)?;
+		// Asset not relevant if no switch pair is set for any of the payment assets or
+		// if not enabled.
+		let switch_pair = payment
+			.fungible_assets_iter()
+			.chain(payment.non_fungible_assets_iter())
+			.find_map(|asset| Pallet::<T, I>::switch_pair(&asset.id.into()))
+			.ok_or(Error::AssetNotFound)?;
 		ensure!(switch_pair.is_enabled(), Error::AssetNotFound);
 
 		let amount = WeightToFee::weight_to_fee(&weight);
@@ -133,6 +139,7 @@ where
 		self.consumed_xcm_hash = Some(context.message_id);
 		self.remaining_fungible_balance = self.remaining_fungible_balance.saturating_add(amount);
 		self.remaining_weight = self.remaining_weight.saturating_add(weight);
+		self.switch_pair = Some(switch_pair);
 
 		Ok(unused)
 	}
@@ -245,8 +252,13 @@ where
 					"Transferring from pool account to fee destination failed."
 				);
 
+				let Ok(switch_pair_key) = Pallet::<T, I>::switch_pair_key(&switch_pair.remote_asset_id) else {
+					log::error!(target: LOG_TARGET, "Failed to compute storage key for switch pair with remote asset ID {:?}", switch_pair.remote_asset_id);
+					return;
+				};
+
 				// No error should ever be thrown from inside this block.
-				SwitchPair::<T, I>::mutate(|entry| {
+				SwitchPairs::<T, I>::mutate(switch_pair_key, |entry| {
 					let Some(entry) = entry.as_mut() else {
 						log::error!(target: LOG_TARGET, "Stored switch pair should not be None but it is.");
 						return;
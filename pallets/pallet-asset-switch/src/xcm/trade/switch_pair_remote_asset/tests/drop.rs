@@ -27,7 +27,7 @@ use xcm_executor::traits::WeightTrader;
 
 use crate::{
 	xcm::{
-		test_utils::get_switch_pair_info_for_remote_location_with_pool_usable_balance,
+		test_utils::{get_stored_switch_pair, get_switch_pair_info_for_remote_location_with_pool_usable_balance},
 		trade::{
 			switch_pair_remote_asset::mock::{Balances, ExtBuilder, MockRuntime, ToDestinationAccount},
 			test_utils::SumTimeAndProofValues,
@@ -59,6 +59,7 @@ fn happy_path() {
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = 1;
 				weigher
 			};
@@ -79,6 +80,7 @@ fn no_switch_pair() {
 				SumTimeAndProofValues,
 				ToDestinationAccount,
 			>::new();
+			weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 			weigher.remaining_fungible_balance = 1;
 			weigher
 		};
@@ -107,6 +109,7 @@ fn switch_pair_not_enabled() {
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = 1;
 				weigher
 			};
@@ -138,6 +141,7 @@ fn zero_remaining_balance() {
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = u128::zero();
 				weigher
 			};
@@ -168,6 +172,7 @@ fn fail_to_transfer_from_pool_account() {
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = 1;
 				weigher
 			};
@@ -25,7 +25,7 @@ use xcm_executor::traits::WeightTrader;
 
 use crate::{
 	xcm::{
-		test_utils::get_switch_pair_info_for_remote_location_with_pool_usable_balance,
+		test_utils::{get_stored_switch_pair, get_switch_pair_info_for_remote_location_with_pool_usable_balance},
 		trade::{
 			switch_pair_remote_asset::mock::{ExtBuilder, MockRuntime, ToDestinationAccount},
 			test_utils::SumTimeAndProofValues,
@@ -63,6 +63,7 @@ fn successful_on_stored_remote_asset_latest_with_remaining_balance_and_weight()
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::MAX;
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -111,6 +112,7 @@ fn successful_on_stored_remote_asset_latest_with_zero_remaining_balance() {
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = u128::zero();
 				weigher.remaining_weight = Weight::MAX;
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -153,6 +155,7 @@ fn successful_on_stored_remote_asset_latest_with_zero_remaining_weight() {
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::zero();
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -195,6 +198,7 @@ fn successful_on_stored_remote_asset_latest_with_zero_remaining_balance_and_weig
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = u128::zero();
 				weigher.remaining_weight = Weight::zero();
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -236,6 +240,7 @@ fn successful_on_stored_remote_asset_v4_with_remaining_balance_and_weight() {
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::MAX;
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -284,6 +289,7 @@ fn successful_on_stored_remote_asset_v4_with_zero_remaining_balance() {
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = u128::zero();
 				weigher.remaining_weight = Weight::MAX;
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -326,6 +332,7 @@ fn successful_on_stored_remote_asset_v4_with_zero_remaining_weight() {
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::zero();
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -368,6 +375,7 @@ fn successful_on_stored_remote_asset_v4_with_zero_remaining_balance_and_weight()
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = u128::zero();
 				weigher.remaining_weight = Weight::zero();
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -409,6 +417,7 @@ fn successful_on_stored_remote_asset_v3_with_remaining_balance_and_weight() {
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::MAX;
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -457,6 +466,7 @@ fn successful_on_stored_remote_asset_v3_with_zero_remaining_balance() {
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = u128::zero();
 				weigher.remaining_weight = Weight::MAX;
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -499,6 +509,7 @@ fn successful_on_stored_remote_asset_v3_with_zero_remaining_weight() {
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::zero();
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -541,6 +552,7 @@ fn successful_on_stored_remote_asset_v3_with_zero_remaining_balance_and_weight()
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = u128::zero();
 				weigher.remaining_weight = Weight::zero();
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -579,6 +591,7 @@ fn skips_on_weight_not_previously_purchased() {
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::MAX;
 				// Setting this to 'None' triggers the "not bought with me" condition.
@@ -617,6 +630,7 @@ fn skips_on_weight_not_previously_purchased_different_hash() {
 					SumTimeAndProofValues,
 					ToDestinationAccount,
 				>::new();
+				weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::MAX;
 				// Setting this to a different value than expected also triggers the "not bought
@@ -641,6 +655,7 @@ fn skips_on_switch_pair_not_set() {
 				SumTimeAndProofValues,
 				ToDestinationAccount,
 			>::new();
+			weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 			weigher.remaining_fungible_balance = u128::MAX;
 			weigher.remaining_weight = Weight::MAX;
 			weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -673,6 +688,7 @@ fn skips_on_switch_pair_not_enabled() {
 				SumTimeAndProofValues,
 				ToDestinationAccount,
 			>::new();
+			weigher.switch_pair = get_stored_switch_pair::<MockRuntime>();
 			let initial_weigher = weigher.clone();
 			let amount_refunded =
 				weigher.refund_weight(Weight::from_parts(1, 1), &XcmContext::with_message_id([0u8; 32]));
@@ -127,11 +127,14 @@ impl crate::Config for MockRuntime {
 	type AssetTransactor = ();
 	type FeeOrigin = EnsureRoot<Self::AccountId>;
 	type LocalCurrency = MockCurrency;
+	type MaxSwitchPairs = ConstU32<10>;
 	type PauseOrigin = EnsureRoot<Self::AccountId>;
+	type QueryHandler = crate::mock::MockQueryHandler;
 	type RuntimeEvent = RuntimeEvent;
 	type SubmitterOrigin = EnsureSigned<Self::AccountId>;
 	type SwitchHooks = ();
 	type SwitchOrigin = EnsureRoot<Self::AccountId>;
+	type SwitchResponseTimeout = ConstU64<10>;
 	type XcmRouter = ();
 	type WeightInfo = ();
 
@@ -158,14 +161,19 @@ impl ExtBuilder {
 			if let Some(switch_pair_info) = self.0 {
 				Pallet::<MockRuntime>::set_switch_pair_bypass_checks(
 					switch_pair_info.remote_asset_total_supply,
-					switch_pair_info.remote_asset_id,
+					switch_pair_info.remote_asset_id.clone(),
 					switch_pair_info.remote_asset_circulating_supply,
 					switch_pair_info.remote_reserve_location,
 					switch_pair_info.remote_asset_ed,
 					switch_pair_info.remote_xcm_fee,
 					switch_pair_info.pool_account,
-				);
-				Pallet::<MockRuntime>::set_switch_pair_status(switch_pair_info.status).unwrap();
+				)
+				.unwrap();
+				Pallet::<MockRuntime>::set_switch_pair_status(
+					&switch_pair_info.remote_asset_id,
+					switch_pair_info.status,
+				)
+				.unwrap();
 			}
 
 			System::reset_events()
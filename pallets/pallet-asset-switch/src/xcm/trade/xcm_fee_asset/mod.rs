@@ -19,10 +19,13 @@
 use frame_support::{ensure, weights::WeightToFee as WeightToFeeT};
 use sp_runtime::traits::Zero;
 use sp_std::marker::PhantomData;
-use xcm::v4::{Asset, Error, Fungibility, Weight, XcmContext, XcmHash};
+use xcm::{
+	v4::{Asset, Error, Fungibility, Weight, XcmContext, XcmHash},
+	VersionedAssetId,
+};
 use xcm_executor::{traits::WeightTrader, AssetsInHolding};
 
-use crate::{Config, SwitchPair};
+use crate::{Config, Pallet, SwitchPairs};
 
 #[cfg(test)]
 mod mock;
@@ -33,7 +36,9 @@ const LOG_TARGET: &str = "xcm::pallet-asset-switch::UsingComponentsForXcmFeeAsse
 
 /// Type implementing [WeightTrader] that allows
 /// paying for XCM fees when reserve transferring the XCM fee asset for the
-/// on-chain switch pair.
+/// on-chain switch pairs. The switch pair is selected upon weight purchase,
+/// from the first enabled switch pair whose XCM fee asset is part of the
+/// payment.
 ///
 /// This trader is required in case there is no other mechanism to pay for
 /// fees when transferring such an asset to this chain.
@@ -51,6 +56,7 @@ where
 	remaining_weight: Weight,
 	remaining_fungible_balance: u128,
 	consumed_xcm_hash: Option<XcmHash>,
+	switch_pair_remote_asset_id: Option<VersionedAssetId>,
 	_phantom: PhantomData<(T, I, WeightToFee)>,
 }
 
@@ -63,6 +69,7 @@ where
 		self.remaining_weight == other.remaining_weight
 			&& self.remaining_fungible_balance == other.remaining_fungible_balance
 			&& self.consumed_xcm_hash == other.consumed_xcm_hash
+			&& self.switch_pair_remote_asset_id == other.switch_pair_remote_asset_id
 	}
 }
 
@@ -78,6 +85,7 @@ where
 			consumed_xcm_hash: None,
 			remaining_fungible_balance: Zero::zero(),
 			remaining_weight: Zero::zero(),
+			switch_pair_remote_asset_id: None,
 			_phantom: PhantomData,
 		}
 	}
@@ -98,27 +106,41 @@ where
 
 		// Prevent re-using the same trader more than once.
 		ensure!(self.consumed_xcm_hash.is_none(), Error::NotWithdrawable);
-		// Asset not relevant if no switch pair is set, or not enabled.
-		let switch_pair = SwitchPair::<T, I>::get().ok_or(Error::AssetNotFound)?;
-		ensure!(switch_pair.is_enabled(), Error::AssetNotFound);
+		// Asset not relevant if no enabled switch pair has a fungible XCM fee asset
+		// that is part of the payment. There are at most `MaxSwitchPairs` switch pairs
+		// to check.
+		let (switch_pair_remote_asset_id, xcm_fee_asset_v4) = SwitchPairs::<T, I>::iter_values()
+			.filter(|switch_pair| switch_pair.is_enabled())
+			.find_map(|switch_pair| {
+				let xcm_fee_asset_v4: Asset = switch_pair
+					.remote_xcm_fee
+					.clone()
+					.try_into()
+					.map_err(|e| {
+						log::error!(
+							target: LOG_TARGET,
+							"Failed to convert stored asset ID {:?} into v4 Asset with error {:?}",
+							switch_pair.remote_xcm_fee,
+							e
+						);
+						e
+					})
+					.ok()?;
+				// Asset not relevant if the stored XCM fee asset is not fungible.
+				let Fungibility::Fungible(_) = xcm_fee_asset_v4.fun else {
+					log::info!(target: LOG_TARGET, "Stored XCM fee asset is not fungible.");
+					return None;
+				};
+				let is_in_payment = payment
+					.fungible_assets_iter()
+					.chain(payment.non_fungible_assets_iter())
+					.any(|asset| asset.id == xcm_fee_asset_v4.id);
+				is_in_payment.then_some((switch_pair.remote_asset_id, xcm_fee_asset_v4))
+			})
+			.ok_or(Error::AssetNotFound)?;
 
 		let amount = WeightToFee::weight_to_fee(&weight);
 
-		let xcm_fee_asset_v4: Asset = switch_pair.remote_xcm_fee.clone().try_into().map_err(|e| {
-			log::error!(
-				target: LOG_TARGET,
-				"Failed to convert stored asset ID {:?} into v4 Asset with error {:?}",
-				switch_pair.remote_xcm_fee,
-				e
-			);
-			Error::FailedToTransactAsset("Failed to convert switch pair asset ID into required version.")
-		})?;
-		// Asset not relevant if the stored XCM fee asset is not fungible.
-		let Fungibility::Fungible(_) = xcm_fee_asset_v4.fun else {
-			log::info!(target: LOG_TARGET, "Stored XCM fee asset is not fungible.");
-			return Err(Error::AssetNotFound);
-		};
-
 		let required: Asset = (xcm_fee_asset_v4.id, amount).into();
 		let unused = payment.checked_sub(required.clone()).map_err(|_| Error::TooExpensive)?;
 
@@ -127,6 +149,7 @@ where
 		self.consumed_xcm_hash = Some(context.message_id);
 		self.remaining_fungible_balance = self.remaining_fungible_balance.saturating_add(amount);
 		self.remaining_weight = self.remaining_weight.saturating_add(weight);
+		self.switch_pair_remote_asset_id = Some(switch_pair_remote_asset_id);
 
 		Ok(unused)
 	}
@@ -139,7 +162,11 @@ where
 			return None;
 		};
 
-		let Some(switch_pair) = SwitchPair::<T, I>::get() else {
+		let Some(switch_pair) = self
+			.switch_pair_remote_asset_id
+			.as_ref()
+			.and_then(Pallet::<T, I>::switch_pair)
+		else {
 			log::error!(target: LOG_TARGET, "Stored switch pair should not be None, but it is.");
 			return None;
 		};
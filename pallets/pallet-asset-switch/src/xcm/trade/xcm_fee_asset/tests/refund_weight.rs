@@ -26,7 +26,7 @@ use xcm_executor::traits::WeightTrader;
 
 use crate::{
 	xcm::{
-		test_utils::get_switch_pair_info_for_remote_location,
+		test_utils::{get_stored_switch_pair, get_switch_pair_info_for_remote_location},
 		trade::{
 			test_utils::SumTimeAndProofValues,
 			xcm_fee_asset::mock::{ExtBuilder, MockRuntime},
@@ -58,6 +58,8 @@ fn successful_on_stored_fungible_xcm_fee_asset_latest_with_remaining_balance_and
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::MAX;
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -100,6 +102,8 @@ fn successful_on_stored_fungible_xcm_fee_asset_latest_with_zero_remaining_balanc
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::zero();
 				weigher.remaining_weight = Weight::MAX;
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -136,6 +140,8 @@ fn successful_on_stored_fungible_xcm_fee_asset_latest_with_zero_remaining_weight
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::zero();
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -172,6 +178,8 @@ fn successful_on_stored_fungible_xcm_fee_asset_latest_with_zero_remaining_balanc
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::zero();
 				weigher.remaining_weight = Weight::zero();
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -208,6 +216,8 @@ fn successful_on_stored_fungible_xcm_fee_asset_v4_with_remaining_balance_and_wei
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::MAX;
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -250,6 +260,8 @@ fn successful_on_stored_fungible_xcm_fee_asset_v4_with_zero_remaining_balance()
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::zero();
 				weigher.remaining_weight = Weight::MAX;
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -286,6 +298,8 @@ fn successful_on_stored_fungible_xcm_fee_asset_v4_with_zero_remaining_weight() {
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::zero();
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -322,6 +336,8 @@ fn successful_on_stored_fungible_xcm_fee_asset_v4_with_zero_remaining_balance_an
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::zero();
 				weigher.remaining_weight = Weight::zero();
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -360,6 +376,8 @@ fn successful_on_stored_fungible_xcm_fee_asset_v3_with_remaining_balance_and_wei
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::MAX;
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -404,6 +422,8 @@ fn successful_on_stored_fungible_xcm_fee_asset_v3_with_zero_remaining_balance()
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::zero();
 				weigher.remaining_weight = Weight::MAX;
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -442,6 +462,8 @@ fn successful_on_stored_fungible_xcm_fee_asset_v3_with_zero_remaining_weight() {
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::zero();
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -480,6 +502,8 @@ fn successful_on_stored_fungible_xcm_fee_asset_v3_with_zero_remaining_balance_an
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::zero();
 				weigher.remaining_weight = Weight::zero();
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -519,6 +543,8 @@ fn successful_on_stored_fungible_xcm_fee_asset_v2() {
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::MAX;
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -543,6 +569,8 @@ fn successful_on_stored_fungible_xcm_fee_asset_v2() {
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::zero();
 				weigher.remaining_weight = Weight::MAX;
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -562,6 +590,8 @@ fn successful_on_stored_fungible_xcm_fee_asset_v2() {
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::zero();
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -581,6 +611,8 @@ fn successful_on_stored_fungible_xcm_fee_asset_v2() {
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::zero();
 				weigher.remaining_weight = Weight::zero();
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -617,6 +649,8 @@ fn skips_on_weight_not_previously_purchased() {
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::MAX;
 				// Setting this to 'None' triggers the "not bought with me" condition.
@@ -635,6 +669,8 @@ fn skips_on_weight_not_previously_purchased() {
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::MAX;
 				// Setting this to a different value than expected also triggers the "not bought
@@ -654,6 +690,8 @@ fn skips_on_switch_pair_not_set() {
 	ExtBuilder::default().build().execute_with(|| {
 		let mut weigher = {
 			let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+			weigher.switch_pair_remote_asset_id =
+				get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 			weigher.remaining_fungible_balance = u128::MAX;
 			weigher.remaining_weight = Weight::MAX;
 			weigher.consumed_xcm_hash = Some([0u8; 32]);
@@ -679,6 +717,8 @@ fn skips_on_switch_pair_not_enabled() {
 		.build()
 		.execute_with(|| {
 			let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+			weigher.switch_pair_remote_asset_id =
+				get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 			assert!(weigher
 				.refund_weight(Weight::from_parts(1, 1), &XcmContext::with_message_id([0u8; 32]))
 				.is_none());
@@ -711,6 +751,8 @@ fn skips_on_stored_non_fungible_xcm_fee_asset() {
 		.execute_with(|| {
 			let mut weigher = {
 				let mut weigher = UsingComponentsForXcmFeeAsset::<MockRuntime, _, SumTimeAndProofValues>::new();
+				weigher.switch_pair_remote_asset_id =
+					get_stored_switch_pair::<MockRuntime>().map(|switch_pair| switch_pair.remote_asset_id);
 				weigher.remaining_fungible_balance = u128::MAX;
 				weigher.remaining_weight = Weight::MAX;
 				weigher.consumed_xcm_hash = Some([0u8; 32]);
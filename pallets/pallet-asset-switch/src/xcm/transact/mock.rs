@@ -38,7 +38,7 @@ use sp_runtime::{
 use xcm::v4::Location;
 use xcm_executor::traits::ConvertLocation;
 
-use crate::{NewSwitchPairInfoOf, Pallet, SwitchPair, SwitchPairInfoOf};
+use crate::{NewSwitchPairInfoOf, Pallet, SwitchPairInfoOf, SwitchPairs};
 
 construct_runtime!(
 	pub enum MockRuntime {
@@ -103,11 +103,14 @@ impl crate::Config for MockRuntime {
 	type AssetTransactor = ();
 	type FeeOrigin = EnsureRoot<Self::AccountId>;
 	type LocalCurrency = Balances;
+	type MaxSwitchPairs = ConstU32<10>;
 	type PauseOrigin = EnsureRoot<Self::AccountId>;
+	type QueryHandler = crate::mock::MockQueryHandler;
 	type RuntimeEvent = RuntimeEvent;
 	type SubmitterOrigin = EnsureSigned<Self::AccountId>;
 	type SwitchHooks = ();
 	type SwitchOrigin = EnsureRoot<Self::AccountId>;
+	type SwitchResponseTimeout = ConstU64<10>;
 	type XcmRouter = ();
 	type WeightInfo = ();
 
@@ -169,14 +172,19 @@ impl ExtBuilder {
 				.unwrap();
 				Pallet::<MockRuntime>::set_switch_pair_bypass_checks(
 					switch_pair_info.remote_asset_total_supply,
-					switch_pair_info.remote_asset_id,
+					switch_pair_info.remote_asset_id.clone(),
 					switch_pair_info.remote_asset_circulating_supply,
 					switch_pair_info.remote_reserve_location,
 					switch_pair_info.remote_asset_ed,
 					switch_pair_info.remote_xcm_fee,
 					switch_pair_info.pool_account,
-				);
-				Pallet::<MockRuntime>::set_switch_pair_status(switch_pair_info.status).unwrap();
+				)
+				.unwrap();
+				Pallet::<MockRuntime>::set_switch_pair_status(
+					&switch_pair_info.remote_asset_id,
+					switch_pair_info.status,
+				)
+				.unwrap();
 			}
 			for (account, free, held, frozen) in self.1 {
 				<Balances as MutateFungible<AccountId32>>::mint_into(&account, free).unwrap();
@@ -186,16 +194,17 @@ impl ExtBuilder {
 				// If the specified account is the pool account, remove from the registered
 				// circulating supply the amount of tokens that have been marked as held or
 				// frozen, to maintain the invariant.
-				if Some(account)
-					== self
-						.0
-						.as_ref()
-						.map(|new_switch_info| new_switch_info.clone().pool_account)
+				if let Some(new_switch_info) = self
+					.0
+					.as_ref()
+					.filter(|new_switch_info| new_switch_info.pool_account == account)
 				{
 					// ED can be frozen, so we only need to considered only the ones that go BEYOND
 					// the ED.
 					let freezes_more_than_ed = frozen.saturating_sub(local_ed);
-					SwitchPair::<MockRuntime, _>::mutate(|switch_pair| {
+					let switch_pair_key =
+						Pallet::<MockRuntime>::switch_pair_key(&new_switch_info.remote_asset_id).unwrap();
+					SwitchPairs::<MockRuntime, _>::mutate(switch_pair_key, |switch_pair| {
 						if let Some(switch_pair) = switch_pair.as_mut() {
 							// Calculate all held tokens as not available in the pool, hence not available
 							// as circulating supply at destination.
@@ -21,10 +21,13 @@ use frame_support::{
 	traits::{fungible::Mutate, tokens::Preservation},
 };
 use sp_std::marker::PhantomData;
-use xcm::v4::{Asset, AssetId, Error, Fungibility, Location, Result, XcmContext};
+use xcm::{
+	v4::{Asset, Error, Fungibility, Location, Result, XcmContext},
+	VersionedAssetId,
+};
 use xcm_executor::traits::{ConvertLocation, TransactAsset};
 
-use crate::{traits::SwitchHooks, Config, Event, LocalCurrencyBalanceOf, Pallet, SwitchPair};
+use crate::{traits::SwitchHooks, Config, Event, LocalCurrencyBalanceOf, Pallet, SwitchPairHookRules, SwitchPairs};
 
 #[cfg(test)]
 mod mock;
@@ -36,7 +39,8 @@ const LOG_TARGET: &str = "xcm::pallet-asset-switch::SwitchPairRemoteAssetTransac
 /// Type implementing [TransactAsset] that moves from the switch pair pool
 /// account, if present, as many local tokens as remote assets received into
 /// the specified `Location` if the incoming asset ID matches the remote
-/// asset ID as specified in the switch pair and if they are both fungible.
+/// asset ID as specified in one of the switch pairs and if they are both
+/// fungible.
 pub struct SwitchPairRemoteAssetTransactor<AccountIdConverter, T, I>(PhantomData<(AccountIdConverter, T, I)>);
 
 impl<AccountIdConverter, T, I> TransactAsset for SwitchPairRemoteAssetTransactor<AccountIdConverter, T, I>
@@ -47,28 +51,19 @@ where
 {
 	fn deposit_asset(what: &Asset, who: &Location, context: Option<&XcmContext>) -> Result {
 		log::info!(target: LOG_TARGET, "deposit_asset {:?} {:?} {:?}", what, who, context);
-		// 1. Verify the switch pair exists.
-		let switch_pair = SwitchPair::<T, I>::get().ok_or(Error::AssetNotFound)?;
+		// 1. Verify a switch pair exists with the asset as the other side of the pair.
+		let switch_pair_key = Pallet::<T, I>::switch_pair_key(&VersionedAssetId::from(what.id.clone()))
+			.map_err(|_| Error::AssetNotFound)?;
+		let switch_pair = SwitchPairs::<T, I>::get(&switch_pair_key).ok_or(Error::AssetNotFound)?;
 
-		// 2. Verify the asset matches the other side of the switch pair.
-		let remote_asset_id_v4: AssetId = switch_pair.remote_asset_id.clone().try_into().map_err(|e| {
-			log::error!(
-				target: LOG_TARGET,
-				"Failed to convert stored asset ID {:?} into required version with error {:?}.",
-				switch_pair.remote_asset_id,
-				e
-			);
-			Error::AssetNotFound
-		})?;
-		ensure!(remote_asset_id_v4 == what.id, Error::AssetNotFound);
-		// 3. Verify the asset being deposited is fungible.
+		// 2. Verify the asset being deposited is fungible.
 		let Fungibility::Fungible(fungible_amount) = what.fun else {
 			return Err(Error::AssetNotFound);
 		};
 		// After this ensure, we know we need to be transacting with this asset, so any
 		// errors thrown from here onwards is a `FailedToTransactAsset` error.
 
-		// 4. Verify the switch pair is running.
+		// 3. Verify the switch pair is running.
 		ensure!(
 			switch_pair.is_enabled(),
 			Error::FailedToTransactAsset("switch pair is not running.",)
@@ -77,8 +72,15 @@ where
 		let beneficiary = AccountIdConverter::convert_location(who).ok_or(Error::FailedToTransactAsset(
 			"Failed to convert beneficiary to valid account.",
 		))?;
-		// 5. Call into the pre-switch hook
-		T::SwitchHooks::pre_remote_to_local_switch(&beneficiary, fungible_amount).map_err(|e| {
+		// 4. Call into the pre-switch hook
+		let hook_rules = SwitchPairHookRules::<T, I>::get(&switch_pair_key);
+		T::SwitchHooks::pre_remote_to_local_switch(
+			&switch_pair_key,
+			hook_rules.as_ref(),
+			&beneficiary,
+			fungible_amount,
+		)
+		.map_err(|e| {
 			log::error!(
 				target: LOG_TARGET,
 				"Hook pre-switch check failed with error code {:?}",
@@ -87,7 +89,7 @@ where
 			Error::FailedToTransactAsset("Failed to validate preconditions for remote-to-local switch.")
 		})?;
 
-		// 6. Perform the local transfer
+		// 5. Perform the local transfer.
 		let fungible_amount_as_currency_balance: LocalCurrencyBalanceOf<T, I> =
 			fungible_amount.try_into().map_err(|_| {
 				Error::FailedToTransactAsset("Failed to convert fungible amount to balance of local currency.")
@@ -108,7 +110,7 @@ where
 		})?;
 
 		// 6. Increase the balance of the remote asset
-		SwitchPair::<T, I>::try_mutate(|entry| {
+		SwitchPairs::<T, I>::try_mutate(&switch_pair_key, |entry| {
 			let switch_pair_info = entry
 				.as_mut()
 				.ok_or(Error::FailedToTransactAsset("SwitchPair should not be None."))?;
@@ -120,8 +122,19 @@ where
 			Ok::<_, Error>(())
 		})?;
 
-		// 7. Call into the post-switch hook
-		T::SwitchHooks::post_remote_to_local_switch(&beneficiary, fungible_amount).map_err(|e| {
+		// 7. Record the switch against the switch pair rate limits, if any. Exceeded
+		// limits are reported with an event, since failing would trap the remote
+		// assets already moved to the chain sovereign account.
+		Pallet::<T, I>::record_incoming_switch(&switch_pair_key, &beneficiary, fungible_amount);
+
+		// 8. Call into the post-switch hook
+		T::SwitchHooks::post_remote_to_local_switch(
+			&switch_pair_key,
+			hook_rules.as_ref(),
+			&beneficiary,
+			fungible_amount,
+		)
+		.map_err(|e| {
 			log::error!(
 				target: LOG_TARGET,
 				"Hook post-switch check failed with error code {:?}",
@@ -131,6 +144,7 @@ where
 		})?;
 
 		Pallet::<T, I>::deposit_event(Event::<T, I>::RemoteToLocalSwitchExecuted {
+			remote_asset_id: switch_pair.remote_asset_id,
 			amount: fungible_amount,
 			to: beneficiary,
 		});
@@ -17,6 +17,7 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 use frame_support::{assert_noop, assert_ok, traits::fungible::Inspect as InspectFungible};
+use frame_system::RawOrigin;
 use sp_runtime::AccountId32;
 use xcm::{
 	v4::{Asset, AssetId, AssetInstance, Error, Fungibility, Junction, Junctions, Location},
@@ -33,7 +34,7 @@ use crate::{
 		},
 		SwitchPairRemoteAssetTransactor,
 	},
-	Event, SwitchPairStatus,
+	AccountSwitchUsage, Event, Pallet, SwitchPairStatus, SwitchPairUsage, SwitchRateLimit, SwitchRateLimitsOf,
 };
 
 #[test]
@@ -80,6 +81,7 @@ fn successful_with_stored_remote_asset_id_latest() {
 			);
 			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
 				== Event::<MockRuntime>::RemoteToLocalSwitchExecuted {
+					remote_asset_id: new_switch_pair_info.remote_asset_id.clone(),
 					amount: 2,
 					to: SUCCESSFUL_ACCOUNT_ID
 				}
@@ -112,6 +114,7 @@ fn successful_with_stored_remote_asset_id_latest() {
 			);
 			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
 				== Event::<MockRuntime>::RemoteToLocalSwitchExecuted {
+					remote_asset_id: new_switch_pair_info.remote_asset_id.clone(),
 					amount: 2,
 					to: SUCCESSFUL_ACCOUNT_ID
 				}
@@ -161,6 +164,7 @@ fn successful_with_stored_remote_asset_id_v4() {
 			);
 			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
 				== Event::<MockRuntime>::RemoteToLocalSwitchExecuted {
+					remote_asset_id: new_switch_pair_info.remote_asset_id.clone(),
 					amount: 2,
 					to: SUCCESSFUL_ACCOUNT_ID
 				}
@@ -193,6 +197,7 @@ fn successful_with_stored_remote_asset_id_v4() {
 			);
 			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
 				== Event::<MockRuntime>::RemoteToLocalSwitchExecuted {
+					remote_asset_id: new_switch_pair_info.remote_asset_id.clone(),
 					amount: 2,
 					to: SUCCESSFUL_ACCOUNT_ID
 				}
@@ -242,6 +247,7 @@ fn successful_with_stored_remote_asset_id_v3() {
 			);
 			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
 				== Event::<MockRuntime>::RemoteToLocalSwitchExecuted {
+					remote_asset_id: new_switch_pair_info.remote_asset_id.clone(),
 					amount: 2,
 					to: SUCCESSFUL_ACCOUNT_ID
 				}
@@ -274,6 +280,7 @@ fn successful_with_stored_remote_asset_id_v3() {
 			);
 			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
 				== Event::<MockRuntime>::RemoteToLocalSwitchExecuted {
+					remote_asset_id: new_switch_pair_info.remote_asset_id.clone(),
 					amount: 2,
 					to: SUCCESSFUL_ACCOUNT_ID
 				}
@@ -281,6 +288,70 @@ fn successful_with_stored_remote_asset_id_v3() {
 		});
 }
 
+#[test]
+fn successful_with_rate_limits_exceeded() {
+	let location = Location {
+		parents: 1,
+		interior: Junctions::X1([Junction::Parachain(1_000)].into()),
+	};
+	let new_switch_pair_info = get_switch_pair_info_for_remote_location_with_pool_usable_balance::<MockRuntime>(
+		&location,
+		2,
+		SwitchPairStatus::Running,
+	);
+	let who = Location::here();
+
+	ExtBuilder::default()
+		.with_switch_pair_info(new_switch_pair_info.clone())
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<MockRuntime>::set_switch_pair_rate_limits(
+				RawOrigin::Root.into(),
+				Box::new(new_switch_pair_info.remote_asset_id.clone()),
+				Some(SwitchRateLimitsOf::<MockRuntime> {
+					period: 10,
+					max_amount: Some(1),
+					max_switches_per_account: Some(1),
+					..Default::default()
+				})
+			));
+			let asset_to_deposit = Asset {
+				id: new_switch_pair_info.clone().remote_asset_id.try_into().unwrap(),
+				fun: Fungibility::Fungible(2),
+			};
+			// Remote -> local switches are not rejected, as the remote assets have
+			// already been transferred to the chain sovereign account.
+			assert_ok!(SwitchPairRemoteAssetTransactor::<
+				SuccessfulAccountIdConverter,
+				MockRuntime,
+				_,
+			>::deposit_asset(&asset_to_deposit, &who, None));
+			let switch_pair_key =
+				Pallet::<MockRuntime>::switch_pair_key(&new_switch_pair_info.remote_asset_id).unwrap();
+			// The exceeded limit is reported instead.
+			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
+				== Event::<MockRuntime>::IncomingSwitchRateLimitExceeded {
+					remote_asset_id: switch_pair_key.clone(),
+					account: SUCCESSFUL_ACCOUNT_ID,
+					limit: SwitchRateLimit::Amount,
+				}
+				.into()));
+			assert!(System::events().into_iter().map(|e| e.event).any(|e| e
+				== Event::<MockRuntime>::SwitchPairRateLimitReached {
+					remote_asset_id: switch_pair_key.clone(),
+					account: SUCCESSFUL_ACCOUNT_ID,
+					limit: SwitchRateLimit::SwitchesPerAccount,
+				}
+				.into()));
+			// And the switch counts towards the rate limits of the switch pair.
+			assert_eq!(SwitchPairUsage::<MockRuntime>::get(&switch_pair_key).amount, 2);
+			assert_eq!(SwitchPairUsage::<MockRuntime>::get(&switch_pair_key).switches, 1);
+			assert_eq!(
+				AccountSwitchUsage::<MockRuntime>::get(&switch_pair_key, SUCCESSFUL_ACCOUNT_ID).switches,
+				1
+			);
+		});
+}
+
 #[test]
 fn skips_on_switch_pair_not_set() {
 	let who = Location::here();
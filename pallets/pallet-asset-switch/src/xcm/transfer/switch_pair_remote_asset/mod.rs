@@ -18,9 +18,9 @@
 
 use frame_support::traits::ContainsPair;
 use sp_std::marker::PhantomData;
-use xcm::v4::{Asset, AssetId, Location};
+use xcm::v4::{Asset, Location};
 
-use crate::{Config, SwitchPair};
+use crate::{Config, Pallet};
 
 #[cfg(test)]
 mod tests;
@@ -28,8 +28,8 @@ mod tests;
 const LOG_TARGET: &str = "xcm::barriers::pallet-asset-switch::AllowSwitchPairRemoteAsset";
 
 /// Type implementing [ContainsPair] and returns
-/// `true` if the specified asset ID matches the switch pair remote asset ID,
-/// which must be reserve transferred to this chain to be traded back for
+/// `true` if the specified asset ID matches the remote asset ID of a switch
+/// pair, which must be reserve transferred to this chain to be traded back for
 /// the local token. The fungibility of either asset is not checked, and that
 /// logic is delegated to the other XCM components, such as the asset
 /// transactor(s).
@@ -42,9 +42,10 @@ where
 {
 	fn contains(a: &Asset, b: &Location) -> bool {
 		log::info!(target: LOG_TARGET, "contains {:?}, {:?}", a, b);
-		// 1. Verify a switch pair has been set. We don't care if it's enabled at this
-		//    stage, as we still want the assets to move inside this system.
-		let Some(switch_pair) = SwitchPair::<T, I>::get() else {
+		// 1. Verify a switch pair has been set for the asset ID. We don't care if it's
+		//    enabled at this stage, as we still want the assets to move inside this
+		//    system.
+		let Some(switch_pair) = Pallet::<T, I>::switch_pair(&a.id.clone().into()) else {
 			return false;
 		};
 
@@ -63,12 +64,6 @@ where
 			return false;
 		}
 
-		// 3. Verify the asset ID matches the remote asset ID to switch for local ones.
-		let Ok(stored_remote_asset_id): Result<AssetId, _> = switch_pair.remote_asset_id.clone().try_into().map_err(|e| {
-				log::error!(target: LOG_TARGET, "Failed to convert stored remote asset ID {:?} into v4 xcm version with error {:?}.", switch_pair.remote_asset_id, e);
-				e
-			 }) else { return false; };
-
-		a.id == stored_remote_asset_id
+		true
 	}
 }
@@ -20,7 +20,7 @@ use frame_support::traits::ContainsPair;
 use sp_std::marker::PhantomData;
 use xcm::v4::{Asset, Location};
 
-use crate::{Config, SwitchPair};
+use crate::{Config, SwitchPairs};
 
 #[cfg(test)]
 mod tests;
@@ -28,8 +28,8 @@ mod tests;
 const LOG_TARGET: &str = "xcm::pallet-asset-switch::AllowXcmFeeAsset";
 
 /// Type implementing [ContainsPair] and returns
-/// `true` if the specified asset matches the switch pair remote XCM fee
-/// asset, which must be reserve transferred to this chain in order to be
+/// `true` if the specified asset matches the remote XCM fee asset of any
+/// switch pair, which must be reserve transferred to this chain in order to be
 /// withdrawn from the user's balance to pay for XCM fees at destination. The
 /// fungibility of either asset is not checked, and that logic is delegated to
 /// the other XCM components, such as the asset transactor(s).
@@ -43,32 +43,31 @@ where
 	fn contains(a: &Asset, b: &Location) -> bool {
 		log::info!(target: LOG_TARGET, "contains {:?}, {:?}", a, b);
 		// 1. Verify a switch pair has been set. We don't care if it's enabled at this
-		//    stage, as we still want the assets to move inside this system.
-		let Some(switch_pair) = SwitchPair::<T, I>::get() else {
-			return false;
-		};
+		//    stage, as we still want the assets to move inside this system. There are
+		//    at most `MaxSwitchPairs` switch pairs to check.
+		SwitchPairs::<T, I>::iter_values().any(|switch_pair| {
+			// 2. We only trust the EXACT configured remote location (no parent is allowed).
+			let Ok(stored_remote_reserve_location_v4): Result<Location, _> = switch_pair.remote_reserve_location.clone().try_into().map_err(|e| {
+					log::error!(target: LOG_TARGET, "Failed to convert stored remote reserve location {:?} into v4 xcm version with error {:?}.", switch_pair.remote_reserve_location, e);
+					e
+				 }) else { return false; };
+			if stored_remote_reserve_location_v4 != *b {
+				log::trace!(
+					target: LOG_TARGET,
+					"Remote origin {:?} does not match expected origin {:?}",
+					b,
+					stored_remote_reserve_location_v4
+				);
+				return false;
+			}
 
-		// 2. We only trust the EXACT configured remote location (no parent is allowed).
-		let Ok(stored_remote_reserve_location_v4): Result<Location, _> = switch_pair.remote_reserve_location.clone().try_into().map_err(|e| {
-				log::error!(target: LOG_TARGET, "Failed to convert stored remote reserve location {:?} into v4 xcm version with error {:?}.", switch_pair.remote_reserve_location, e);
-				e
-			 }) else { return false; };
-		if stored_remote_reserve_location_v4 != *b {
-			log::trace!(
-				target: LOG_TARGET,
-				"Remote origin {:?} does not match expected origin {:?}",
-				b,
-				stored_remote_reserve_location_v4
-			);
-			return false;
-		}
+			// 3. Verify the asset ID matches the configured XCM fee asset ID.
+			let Ok(stored_remote_asset_fee): Result<Asset, _> = switch_pair.remote_xcm_fee.clone().try_into().map_err(|e| {
+					log::error!(target: LOG_TARGET, "Failed to convert stored remote asset fee {:?} into v4 xcm version with error {:?}.", switch_pair.remote_xcm_fee, e);
+					e
+				 }) else { return false; };
 
-		// 3. Verify the asset ID matches the configured XCM fee asset ID.
-		let Ok(stored_remote_asset_fee): Result<Asset, _> = switch_pair.remote_xcm_fee.clone().try_into().map_err(|e| {
-				log::error!(target: LOG_TARGET, "Failed to convert stored remote asset fee {:?} into v4 xcm version with error {:?}.", switch_pair.remote_xcm_fee, e);
-				e
-			 }) else { return false; };
-
-		a.id == stored_remote_asset_fee.id
+			a.id == stored_remote_asset_fee.id
+		})
 	}
 }
@@ -24,20 +24,22 @@ use frame_benchmarking::v2::*;
 )]
 mod benchmarks {
 	use frame_support::traits::fungible::Mutate;
-	use frame_system::RawOrigin;
+	use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 	use kilt_support::Deposit;
 	use sp_runtime::SaturatedConversion;
 
-	use crate::{traits::BenchmarkHooks, Call, Config, DepositEntryOf, Deposits, HoldReason, Pallet};
+	use crate::{
+		traits::BenchmarkHooks, Call, Config, DepositEntryOf, DepositExpirations, DepositKeyOf, Deposits, HoldReason,
+		Pallet,
+	};
 
 	const KILT: u128 = 10u128.pow(15);
 
-	#[benchmark]
-	fn reclaim_deposit() {
-		let (submitter, namespace, key) = T::BenchmarkHooks::pre_reclaim_deposit();
-
-		assert!(Deposits::<T>::get(&namespace, &key).is_none());
-
+	fn add_funded_deposit<T: Config + pallet_balances::Config>(
+		submitter: &T::AccountId,
+		namespace: &T::Namespace,
+		key: &DepositKeyOf<T>,
+	) {
 		let entry = DepositEntryOf::<T> {
 			deposit: Deposit {
 				amount: KILT.saturated_into(),
@@ -49,11 +51,20 @@ mod benchmarks {
 		let amount = KILT * 100;
 
 		<pallet_balances::Pallet<T> as Mutate<<T as frame_system::Config>::AccountId>>::set_balance(
-			&submitter,
+			submitter,
 			amount.saturated_into(),
 		);
 
 		Pallet::<T>::add_deposit(namespace.clone(), key.clone(), entry).expect("Creating Deposit should not fail.");
+	}
+
+	#[benchmark]
+	fn reclaim_deposit() {
+		let (submitter, namespace, key) = T::BenchmarkHooks::pre_reclaim_deposit();
+
+		assert!(Deposits::<T>::get(&namespace, &key).is_none());
+
+		add_funded_deposit::<T>(&submitter, &namespace, &key);
 
 		assert!(Deposits::<T>::get(&namespace, &key).is_some());
 
@@ -69,6 +80,41 @@ mod benchmarks {
 		T::BenchmarkHooks::post_reclaim_deposit();
 	}
 
+	#[benchmark]
+	fn update_deposit() {
+		let (submitter, namespace, key) = T::BenchmarkHooks::pre_reclaim_deposit();
+		add_funded_deposit::<T>(&submitter, &namespace, &key);
+
+		let origin = RawOrigin::Signed(submitter);
+		let cloned_namespace = namespace.clone();
+		let cloned_key = key.clone();
+
+		#[extrinsic_call]
+		Pallet::<T>::update_deposit(origin, cloned_namespace, cloned_key);
+
+		assert!(Deposits::<T>::get(&namespace, &key).is_some());
+	}
+
+	#[benchmark]
+	fn remove_expired_deposit() {
+		let (submitter, namespace, key) = T::BenchmarkHooks::pre_reclaim_deposit();
+		add_funded_deposit::<T>(&submitter, &namespace, &key);
+		// Force the deposit to be expired regardless of the configured lifetime.
+		DepositExpirations::<T>::insert(&namespace, &key, BlockNumberFor::<T>::default());
+
+		let caller: T::AccountId = whitelisted_caller();
+		let origin = RawOrigin::Signed(caller);
+		let cloned_namespace = namespace.clone();
+		let cloned_key = key.clone();
+
+		#[extrinsic_call]
+		Pallet::<T>::remove_expired_deposit(origin, cloned_namespace, cloned_key);
+
+		assert!(Deposits::<T>::get(&namespace, &key).is_none());
+
+		T::BenchmarkHooks::post_reclaim_deposit();
+	}
+
 	#[cfg(test)]
 	mod benchmarks_tests {
 		use crate::Pallet;
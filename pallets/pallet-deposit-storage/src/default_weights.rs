@@ -31,6 +31,8 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_deposit_storage.
 pub trait WeightInfo {
 	fn reclaim_deposit() -> Weight;
+	fn update_deposit() -> Weight;
+	fn remove_expired_deposit() -> Weight;
 }
 
 /// Weights for pallet_deposit_storage using the Substrate node and recommended hardware.
@@ -42,14 +44,46 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `StorageDeposit::DepositExpirations` (r:0 w:1)
+	/// Proof: `StorageDeposit::DepositExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StorageDeposit::IndexedDeposits` (r:0 w:1)
+	/// Proof: `StorageDeposit::IndexedDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn reclaim_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `907`
-		//  Estimated: `4414`
-		// Minimum execution time: 704_964 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(1_003_107_000, 4414)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `StorageDeposit::Deposits` (r:1 w:1)
+	/// Proof: `StorageDeposit::Deposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `StorageDeposit::DepositExpirations` (r:0 w:1)
+	/// Proof: `StorageDeposit::DepositExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StorageDeposit::IndexedDeposits` (r:0 w:1)
+	/// Proof: `StorageDeposit::IndexedDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_deposit() -> Weight {
+		// Bounded by releasing the deposit and holding it again, each costing at
+		// most `reclaim_deposit`.
+		Self::reclaim_deposit().saturating_mul(2)
+	}
+	/// Storage: `StorageDeposit::DepositExpirations` (r:1 w:1)
+	/// Proof: `StorageDeposit::DepositExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StorageDeposit::Deposits` (r:1 w:1)
+	/// Proof: `StorageDeposit::Deposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `StorageDeposit::IndexedDeposits` (r:0 w:1)
+	/// Proof: `StorageDeposit::IndexedDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_expired_deposit() -> Weight {
+		// Bounded by `reclaim_deposit`, plus the read of the deposit expiration.
+		Self::reclaim_deposit().saturating_add(T::DbWeight::get().reads(1_u64))
 	}
 }
 
@@ -61,13 +95,45 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `StorageDeposit::DepositExpirations` (r:0 w:1)
+	/// Proof: `StorageDeposit::DepositExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StorageDeposit::IndexedDeposits` (r:0 w:1)
+	/// Proof: `StorageDeposit::IndexedDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn reclaim_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `907`
-		//  Estimated: `4414`
-		// Minimum execution time: 704_964 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(1_003_107_000, 4414)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `StorageDeposit::Deposits` (r:1 w:1)
+	/// Proof: `StorageDeposit::Deposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `StorageDeposit::DepositExpirations` (r:0 w:1)
+	/// Proof: `StorageDeposit::DepositExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StorageDeposit::IndexedDeposits` (r:0 w:1)
+	/// Proof: `StorageDeposit::IndexedDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_deposit() -> Weight {
+		// Bounded by releasing the deposit and holding it again, each costing at
+		// most `reclaim_deposit`.
+		Self::reclaim_deposit().saturating_mul(2)
+	}
+	/// Storage: `StorageDeposit::DepositExpirations` (r:1 w:1)
+	/// Proof: `StorageDeposit::DepositExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StorageDeposit::Deposits` (r:1 w:1)
+	/// Proof: `StorageDeposit::Deposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `StorageDeposit::IndexedDeposits` (r:0 w:1)
+	/// Proof: `StorageDeposit::IndexedDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_expired_deposit() -> Weight {
+		// Bounded by `reclaim_deposit`, plus the read of the deposit expiration.
+		Self::reclaim_deposit().saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
 }
@@ -115,11 +115,16 @@ pub(crate) type DepositCollectorHook<Runtime> = FixedDepositCollectorViaDeposits
 >;
 
 impl pallet_dip_provider::Config for TestRuntime {
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHooks = ();
 	type CommitOrigin = AccountId32;
 	type CommitOriginCheck = EnsureSigned<AccountId32>;
 	type Identifier = AccountId32;
 	type IdentityCommitmentGenerator = DefaultIdentityCommitmentGenerator<u32>;
 	type IdentityProvider = DefaultIdentityProvider<u32>;
+	type MaxCredentials = ConstU32<0>;
+	type MaxCommitmentVersions = ConstU32<3>;
+	type MaxVersionsPerCommit = ConstU32<3>;
 	type ProviderHooks = DepositCollectorHook<Self>;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
@@ -128,10 +133,13 @@ impl pallet_dip_provider::Config for TestRuntime {
 impl crate::Config for TestRuntime {
 	type CheckOrigin = EnsureSigned<Self::AccountId>;
 	type Currency = Balances;
+	type DepositAmounts = DepositCollectorHook<Self>;
 	type DepositHooks = ();
+	type DepositLifetime = ();
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type MaxKeyLength = ConstU32<256>;
+	type MaxIndexedKeyLength = ConstU32<256>;
 	type Namespace = DepositNamespaces;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHooks = ();
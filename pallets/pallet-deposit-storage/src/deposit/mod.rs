@@ -30,7 +30,7 @@ use scale_info::TypeInfo;
 use sp_runtime::traits::Get;
 use sp_std::marker::PhantomData;
 
-use crate::{BalanceOf, Config, Error, HoldReason, Pallet};
+use crate::{traits::DepositAmountProvider, BalanceOf, Config, DepositKeyOf, Error, HoldReason, Pallet};
 
 #[cfg(test)]
 mod mock;
@@ -172,6 +172,24 @@ where
 	}
 }
 
+/// Deposits taken under the configured namespace can be updated to the
+/// configured fixed amount.
+impl<Runtime, DepositsNamespace, FixedDepositAmount, DepositKey> DepositAmountProvider<Runtime>
+	for FixedDepositCollectorViaDepositsPallet<DepositsNamespace, FixedDepositAmount, DepositKey>
+where
+	Runtime: Config,
+	DepositsNamespace: Get<Runtime::Namespace>,
+	FixedDepositAmount: Get<BalanceOf<Runtime>>,
+{
+	fn deposit_amount(namespace: &Runtime::Namespace, _key: &DepositKeyOf<Runtime>) -> Option<BalanceOf<Runtime>> {
+		if *namespace == DepositsNamespace::get() {
+			Some(FixedDepositAmount::get())
+		} else {
+			None
+		}
+	}
+}
+
 // Taken from dip_support logic, not to make that pub
 pub(crate) fn reserve_deposit<Account, Currency: Mutate<Account>>(
 	account: Account,
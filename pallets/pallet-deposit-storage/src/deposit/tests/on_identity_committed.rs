@@ -77,6 +77,37 @@ fn on_identity_committed_successful() {
 		});
 }
 
+#[test]
+fn on_identity_committed_multiple_versions() {
+	ExtBuilder::default()
+		.with_balances(vec![(SUBMITTER, 100_000)])
+		.build()
+		.execute_with(|| {
+			let namespace = DepositNamespaces::get();
+
+			for version in [0, 1] {
+				assert_ok!(
+					<DepositCollectorHook::<TestRuntime> as ProviderHooks<TestRuntime>>::on_identity_committed(
+						&SUBJECT,
+						&SUBMITTER,
+						&IdentityCommitmentOf::<TestRuntime>::default(),
+						version
+					)
+				);
+			}
+
+			// A separate deposit is taken for each commitment version.
+			for version in [0 as IdentityCommitmentVersion, 1] {
+				let key: DepositKeyOf<TestRuntime> = (SUBJECT, SUBMITTER, version).encode().try_into().unwrap();
+				assert!(Pallet::<TestRuntime>::deposits(&namespace, &key).is_some());
+			}
+			assert_eq!(
+				pallet_balances::Pallet::<TestRuntime>::balance_on_hold(&HoldReason::Deposit.into(), &SUBMITTER),
+				2_000
+			);
+		});
+}
+
 #[test]
 fn on_identity_committed_existing_deposit() {
 	let key: DepositKeyOf<TestRuntime> = (SUBJECT, SUBMITTER, 0 as IdentityCommitmentVersion)
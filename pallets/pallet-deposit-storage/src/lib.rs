@@ -22,11 +22,15 @@
 //! Each deposit is identified by a namespace and a key. There cannot be two
 //! equal keys under the same namespace, but the same key can be present under
 //! different namespaces.
+//! Deposit owners can update their deposits to the amount currently required,
+//! and deposits can optionally expire after a configurable number of blocks,
+//! after which anyone can remove them.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod default_weights;
 mod deposit;
+pub mod migrations;
 pub mod traits;
 
 #[cfg(test)]
@@ -47,8 +51,9 @@ pub mod pallet {
 	use crate::{
 		default_weights::WeightInfo,
 		deposit::{free_deposit, reserve_deposit, DepositEntry},
-		traits::DepositStorageHooks,
+		traits::{DepositAmountProvider, DepositStorageHooks},
 	};
+	use kilt_support::traits::DepositIndex;
 
 	use super::*;
 
@@ -62,22 +67,26 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 	use parity_scale_codec::FullCodec;
 	use scale_info::TypeInfo;
-	use sp_runtime::DispatchError;
-	use sp_std::fmt::Debug;
+	use sp_runtime::{traits::Saturating, DispatchError};
+	use sp_std::{fmt::Debug, vec::Vec};
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 	pub type BalanceOf<T> = <<T as Config>::Currency as Inspect<AccountIdOf<T>>>::Balance;
 	pub type DepositKeyOf<T> = BoundedVec<u8, <T as Config>::MaxKeyLength>;
 	pub type DepositEntryOf<T> = DepositEntry<AccountIdOf<T>, BalanceOf<T>, <T as Config>::RuntimeHoldReason>;
+	pub type IndexedDepositKeyOf<T> = BoundedVec<u8, <T as Config>::MaxIndexedKeyLength>;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// The maximum length of keys.
 		#[pallet::constant]
 		type MaxKeyLength: Get<u32>;
+		/// The maximum length of the storage keys in the deposit index.
+		#[pallet::constant]
+		type MaxIndexedKeyLength: Get<u32>;
 
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHooks: crate::traits::BenchmarkHooks<Self>;
@@ -86,9 +95,16 @@ pub mod pallet {
 		type CheckOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 		/// The currency from which deposits are to be taken.
 		type Currency: Mutate<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+		/// The deposit amount currently required for each namespace, used when
+		/// deposits are updated.
+		type DepositAmounts: DepositAmountProvider<Self>;
 		/// Additional logic to execute whenever a new deposit a created or a
 		/// deposit is released.
 		type DepositHooks: DepositStorageHooks<Self>;
+		/// The number of blocks after which a deposit, once created or
+		/// updated, can be removed by anyone. If `None`, deposits never
+		/// expire.
+		type DepositLifetime: Get<Option<BlockNumberFor<Self>>>;
 		/// The type of a deposit namespace.
 		type Namespace: Parameter + MaxEncodedLen;
 		/// The aggregated `Event` type.
@@ -121,6 +137,10 @@ pub mod pallet {
 		FailedToRelease,
 		/// The external hook failed.
 		Hook(u16),
+		/// The deposit amount for the specified namespace cannot be retrieved.
+		DepositAmountNotFound,
+		/// The deposit has no expiration or has not expired yet.
+		DepositNotExpired,
 	}
 
 	#[pallet::event]
@@ -144,6 +164,15 @@ pub mod pallet {
 			/// The deposit details.
 			deposit_entry: DepositEntryOf<T>,
 		},
+		/// A deposit has been updated to the amount currently required.
+		DepositUpdated {
+			/// The deposit namespace.
+			namespace: T::Namespace,
+			/// The deposit key.
+			key: DepositKeyOf<T>,
+			/// The updated deposit details.
+			deposit_entry: DepositEntryOf<T>,
+		},
 	}
 
 	/// Storage of all deposits. Its first key is a namespace, and the second
@@ -151,7 +180,7 @@ pub mod pallet {
 	/// deposit instance.
 	#[pallet::storage]
 	#[pallet::getter(fn deposits)]
-	pub(crate) type Deposits<T> = StorageDoubleMap<
+	pub type Deposits<T> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		<T as Config>::Namespace,
@@ -160,6 +189,28 @@ pub mod pallet {
 		DepositEntryOf<T>,
 	>;
 
+	/// Storage of the block number after which each deposit can be removed
+	/// by anyone. Deposits without an entry never expire.
+	#[pallet::storage]
+	#[pallet::getter(fn deposit_expirations)]
+	pub(crate) type DepositExpirations<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as Config>::Namespace,
+		Blake2_128Concat,
+		DepositKeyOf<T>,
+		BlockNumberFor<T>,
+	>;
+
+	/// Secondary index of the deposits held by each account, both by this
+	/// pallet and by all the pallets using this pallet as their
+	/// [`DepositIndex`]. Its first key is the deposit owner, and the second
+	/// the final storage key of the entry the deposit has been taken for.
+	#[pallet::storage]
+	#[pallet::getter(fn indexed_deposits)]
+	pub(crate) type IndexedDeposits<T> =
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Blake2_128Concat, IndexedDepositKeyOf<T>, BalanceOf<T>>;
+
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
@@ -190,6 +241,57 @@ pub mod pallet {
 			})?;
 			Ok(())
 		}
+
+		/// Update a deposit to the amount currently required for its
+		/// namespace, and reset its expiration, if any. Only the deposit owner
+		/// can update it.
+		#[pallet::call_index(1)]
+		#[pallet::weight({
+			<T as Config>::WeightInfo::update_deposit()
+		})]
+		pub fn update_deposit(origin: OriginFor<T>, namespace: T::Namespace, key: DepositKeyOf<T>) -> DispatchResult {
+			let dispatcher = T::CheckOrigin::ensure_origin(origin)?;
+
+			let amount =
+				T::DepositAmounts::deposit_amount(&namespace, &key).ok_or(Error::<T>::DepositAmountNotFound)?;
+			Self::update_deposit_amount(&namespace, &key, amount, &dispatcher)?;
+			Ok(())
+		}
+
+		/// Remove a deposit whose expiration block has passed. Any signed
+		/// origin can remove an expired deposit, which is released to its
+		/// owner. The deposit hooks are invoked after the deposit has been
+		/// removed from the pallet storage.
+		#[pallet::call_index(2)]
+		#[pallet::weight({
+			<T as Config>::WeightInfo::remove_expired_deposit()
+		})]
+		pub fn remove_expired_deposit(
+			origin: OriginFor<T>,
+			namespace: T::Namespace,
+			key: DepositKeyOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let expiration = DepositExpirations::<T>::get(&namespace, &key).ok_or(Error::<T>::DepositNotExpired)?;
+			ensure!(
+				expiration <= frame_system::Pallet::<T>::block_number(),
+				Error::<T>::DepositNotExpired
+			);
+
+			let deposit = Self::remove_deposit(&namespace, &key, None)?;
+			T::DepositHooks::on_deposit_reclaimed(&namespace, &key, deposit).map_err(|e| {
+				log::info!(
+					target: LOG_TARGET,
+					"Failed to invoke `DepositHooks::on_deposit_reclaimed` for {:#?} and key {:#?} with error {:#?}",
+					namespace,
+					key,
+					e
+				);
+				Error::<T>::Hook(e.into())
+			})?;
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -212,7 +314,13 @@ pub mod pallet {
 						key: key.clone(),
 						deposit_entry: entry.clone(),
 					});
+					Self::on_deposit_held(
+						&entry.deposit.owner,
+						&Deposits::<T>::hashed_key_for(&namespace, &key),
+						&entry.deposit.amount,
+					);
 					*deposit_entry = Some(entry);
+					Self::reset_expiration(&namespace, &key);
 					Ok(())
 				}
 			})?;
@@ -236,6 +344,11 @@ pub mod pallet {
 					Error::<T>::Unauthorized
 				);
 			}
+			DepositExpirations::<T>::remove(namespace, key);
+			Self::on_deposit_released(
+				&existing_entry.deposit.owner,
+				&Deposits::<T>::hashed_key_for(namespace, key),
+			);
 			free_deposit::<AccountIdOf<T>, T::Currency>(&existing_entry.deposit, &existing_entry.reason)
 				.map_err(|_| Error::<T>::FailedToRelease)?;
 			Self::deposit_event(Event::<T>::DepositReclaimed {
@@ -245,5 +358,89 @@ pub mod pallet {
 			});
 			Ok(existing_entry)
 		}
+
+		/// Release a deposit identified by the given key under the given
+		/// namespace and hold the provided amount instead, resetting the
+		/// deposit expiration. If there is no deposit under the provided
+		/// namespace with the provided key, or if its owner is not the
+		/// expected one, it returns an error. It also returns an error if the
+		/// new amount cannot be held on the pallet's `Currency`.
+		pub fn update_deposit_amount(
+			namespace: &T::Namespace,
+			key: &DepositKeyOf<T>,
+			amount: BalanceOf<T>,
+			expected_owner: &AccountIdOf<T>,
+		) -> Result<DepositEntryOf<T>, DispatchError> {
+			let updated_entry = Deposits::<T>::try_mutate(namespace, key, |deposit_entry| {
+				let entry = deposit_entry.as_mut().ok_or(Error::<T>::DepositNotFound)?;
+				ensure!(entry.deposit.owner == *expected_owner, Error::<T>::Unauthorized);
+
+				free_deposit::<AccountIdOf<T>, T::Currency>(&entry.deposit, &entry.reason)
+					.map_err(|_| Error::<T>::FailedToRelease)?;
+				entry.deposit =
+					reserve_deposit::<AccountIdOf<T>, T::Currency>(entry.deposit.owner.clone(), amount, &entry.reason)
+						.map_err(|_| Error::<T>::FailedToHold)?;
+				Ok::<_, DispatchError>(entry.clone())
+			})?;
+			Self::on_deposit_held(
+				&updated_entry.deposit.owner,
+				&Deposits::<T>::hashed_key_for(namespace, key),
+				&updated_entry.deposit.amount,
+			);
+			Self::reset_expiration(namespace, key);
+			Self::deposit_event(Event::<T>::DepositUpdated {
+				namespace: namespace.clone(),
+				key: key.clone(),
+				deposit_entry: updated_entry.clone(),
+			});
+			Ok(updated_entry)
+		}
+
+		/// Return all the deposits owned by the provided account, across all
+		/// namespaces.
+		///
+		/// This function iterates over the whole deposit storage and is meant
+		/// to be called off-chain only, e.g., by runtime APIs.
+		pub fn deposits_for_owner(owner: &AccountIdOf<T>) -> Vec<(T::Namespace, DepositKeyOf<T>, DepositEntryOf<T>)> {
+			Deposits::<T>::iter()
+				.filter(|(_, _, entry)| entry.deposit.owner == *owner)
+				.collect()
+		}
+
+		/// Return all the deposits held by the provided account as recorded
+		/// in the deposit index, identified by the final storage key of the
+		/// entry they have been taken for.
+		pub fn indexed_deposits_for_owner(owner: &AccountIdOf<T>) -> Vec<(Vec<u8>, BalanceOf<T>)> {
+			IndexedDeposits::<T>::iter_prefix(owner)
+				.map(|(key, amount)| (key.into_inner(), amount))
+				.collect()
+		}
+
+		fn reset_expiration(namespace: &T::Namespace, key: &DepositKeyOf<T>) {
+			if let Some(lifetime) = T::DepositLifetime::get() {
+				let expiration = frame_system::Pallet::<T>::block_number().saturating_add(lifetime);
+				DepositExpirations::<T>::insert(namespace, key, expiration);
+			}
+		}
+	}
+
+	impl<T: Config> DepositIndex<AccountIdOf<T>, BalanceOf<T>> for Pallet<T> {
+		fn on_deposit_held(owner: &AccountIdOf<T>, hashed_key: &[u8], amount: &BalanceOf<T>) {
+			let Ok(indexed_key) = IndexedDepositKeyOf::<T>::try_from(hashed_key.to_vec()) else {
+				log::error!(
+					target: LOG_TARGET,
+					"Storage key {:#?} exceeds the maximum length of indexed deposit keys.",
+					hashed_key
+				);
+				return;
+			};
+			IndexedDeposits::<T>::insert(owner, indexed_key, amount);
+		}
+
+		fn on_deposit_released(owner: &AccountIdOf<T>, hashed_key: &[u8]) {
+			if let Ok(indexed_key) = IndexedDepositKeyOf::<T>::try_from(hashed_key.to_vec()) {
+				IndexedDeposits::<T>::remove(owner, indexed_key);
+			}
+		}
 	}
 }
@@ -0,0 +1,165 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use kilt_support::traits::DepositIndex;
+use sp_std::marker::PhantomData;
+
+use crate::{Config, Deposits, Pallet};
+
+const LOG_TARGET: &str = "migration::pallet-deposit-storage::IndexExistingDeposits";
+
+const TARGET_STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+/// Adds all the deposits stored in `Deposits` to the deposit index.
+///
+/// The deposits held by this pallet are one per DIP identity commitment, so
+/// they are all indexed in a single block. The deposits held by other pallets
+/// are indexed via the `index_deposits` extrinsic of the migration pallet.
+pub struct IndexExistingDeposits<T>(PhantomData<T>);
+
+impl<T> OnRuntimeUpgrade for IndexExistingDeposits<T>
+where
+	T: Config,
+{
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+		if Pallet::<T>::on_chain_storage_version() < TARGET_STORAGE_VERSION {
+			log::trace!(target: LOG_TARGET, "Deposits to be indexed.");
+		} else {
+			log::trace!(target: LOG_TARGET, "Deposits already on v1. No migration will run.");
+		}
+		Ok([].into())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		log::info!(target: LOG_TARGET, "Initiating migration.");
+
+		if Pallet::<T>::on_chain_storage_version() >= TARGET_STORAGE_VERSION {
+			log::info!(target: LOG_TARGET, "Deposits already on v1. No migration will run.");
+			return <T as frame_system::Config>::DbWeight::get().reads(1);
+		}
+
+		let mut count = 0u64;
+		Deposits::<T>::iter().for_each(|(namespace, key, entry)| {
+			Pallet::<T>::on_deposit_held(
+				&entry.deposit.owner,
+				&Deposits::<T>::hashed_key_for(&namespace, &key),
+				&entry.deposit.amount,
+			);
+			count = count.saturating_add(1);
+		});
+		log::info!(target: LOG_TARGET, "Indexed {:?} deposits.", count);
+		TARGET_STORAGE_VERSION.put::<Pallet<T>>();
+
+		<T as frame_system::Config>::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		use frame_support::ensure;
+
+		ensure!(
+			Pallet::<T>::on_chain_storage_version() >= TARGET_STORAGE_VERSION,
+			sp_runtime::TryRuntimeError::Other("pallet-deposit-storage storage version was not updated to v1.")
+		);
+		ensure!(
+			Deposits::<T>::iter().all(|(namespace, key, entry)| {
+				let hashed_key = Deposits::<T>::hashed_key_for(&namespace, &key);
+				Pallet::<T>::indexed_deposits_for_owner(&entry.deposit.owner)
+					.into_iter()
+					.any(|(indexed_key, amount)| indexed_key == hashed_key && amount == entry.deposit.amount)
+			}),
+			sp_runtime::TryRuntimeError::Other("Not all deposits were indexed.")
+		);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+pub mod test {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+	use kilt_support::Deposit;
+
+	use crate::{
+		migrations::v1::IndexExistingDeposits,
+		mock::{DepositNamespace, ExtBuilder, TestRuntime, OWNER},
+		DepositEntryOf, DepositKeyOf, Deposits, HoldReason, IndexedDeposits, Pallet,
+	};
+
+	#[test]
+	fn indexes_existing_deposits() {
+		let deposit = DepositEntryOf::<TestRuntime> {
+			reason: HoldReason::Deposit.into(),
+			deposit: Deposit {
+				amount: 10_000,
+				owner: OWNER,
+			},
+		};
+		let namespace = DepositNamespace::ExampleNamespace;
+		let key = DepositKeyOf::<TestRuntime>::default();
+		ExtBuilder::default()
+			.with_deposits(vec![(namespace.clone(), key.clone(), deposit)])
+			.build()
+			.execute_with(|| {
+				// Simulate deposits taken before the deposit index existed.
+				let _ = IndexedDeposits::<TestRuntime>::clear(u32::MAX, None);
+				StorageVersion::new(0).put::<Pallet<TestRuntime>>();
+
+				IndexExistingDeposits::<TestRuntime>::on_runtime_upgrade();
+
+				assert_eq!(
+					Pallet::<TestRuntime>::indexed_deposits_for_owner(&OWNER),
+					vec![(Deposits::<TestRuntime>::hashed_key_for(&namespace, &key), 10_000)]
+				);
+				assert_eq!(
+					Pallet::<TestRuntime>::on_chain_storage_version(),
+					StorageVersion::new(1)
+				);
+			});
+	}
+
+	#[test]
+	fn does_not_run_twice() {
+		let deposit = DepositEntryOf::<TestRuntime> {
+			reason: HoldReason::Deposit.into(),
+			deposit: Deposit {
+				amount: 10_000,
+				owner: OWNER,
+			},
+		};
+		ExtBuilder::default()
+			.with_deposits(vec![(
+				DepositNamespace::ExampleNamespace,
+				DepositKeyOf::<TestRuntime>::default(),
+				deposit,
+			)])
+			.build()
+			.execute_with(|| {
+				let _ = IndexedDeposits::<TestRuntime>::clear(u32::MAX, None);
+				StorageVersion::new(1).put::<Pallet<TestRuntime>>();
+
+				IndexExistingDeposits::<TestRuntime>::on_runtime_upgrade();
+
+				assert!(Pallet::<TestRuntime>::indexed_deposits_for_owner(&OWNER).is_empty());
+			});
+	}
+}
@@ -0,0 +1,178 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_runtime::traits::Saturating;
+use sp_std::marker::PhantomData;
+
+use crate::{Config, DepositExpirations, Deposits, Pallet};
+
+const LOG_TARGET: &str = "migration::pallet-deposit-storage::SetDepositExpirations";
+
+const TARGET_STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+/// Sets an expiration for all the deposits stored in `Deposits` that were
+/// taken before deposits could expire.
+///
+/// The configured `DepositLifetime` is counted from the block of the upgrade,
+/// so that existing deposits cannot be removed by anyone right after the
+/// upgrade. If deposits do not expire, only the storage version is updated.
+pub struct SetDepositExpirations<T>(PhantomData<T>);
+
+impl<T> OnRuntimeUpgrade for SetDepositExpirations<T>
+where
+	T: Config,
+{
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+		if Pallet::<T>::on_chain_storage_version() < TARGET_STORAGE_VERSION {
+			log::trace!(target: LOG_TARGET, "Deposit expirations to be set.");
+		} else {
+			log::trace!(target: LOG_TARGET, "Deposits already on v2. No migration will run.");
+		}
+		Ok([].into())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		log::info!(target: LOG_TARGET, "Initiating migration.");
+
+		if Pallet::<T>::on_chain_storage_version() >= TARGET_STORAGE_VERSION {
+			log::info!(target: LOG_TARGET, "Deposits already on v2. No migration will run.");
+			return <T as frame_system::Config>::DbWeight::get().reads(1);
+		}
+
+		let Some(lifetime) = T::DepositLifetime::get() else {
+			log::info!(target: LOG_TARGET, "Deposits do not expire. No expiration will be set.");
+			TARGET_STORAGE_VERSION.put::<Pallet<T>>();
+			return <T as frame_system::Config>::DbWeight::get().reads_writes(1, 1);
+		};
+		let expiration = frame_system::Pallet::<T>::block_number().saturating_add(lifetime);
+
+		let mut read_count = 0u64;
+		let mut write_count = 0u64;
+		Deposits::<T>::iter_keys().for_each(|(namespace, key)| {
+			// One read for the deposit key and one for its expiration.
+			read_count = read_count.saturating_add(2);
+			if !DepositExpirations::<T>::contains_key(&namespace, &key) {
+				DepositExpirations::<T>::insert(&namespace, &key, expiration);
+				write_count = write_count.saturating_add(1);
+			}
+		});
+		log::info!(target: LOG_TARGET, "Set the expiration of {:?} deposits.", write_count);
+		TARGET_STORAGE_VERSION.put::<Pallet<T>>();
+
+		<T as frame_system::Config>::DbWeight::get()
+			.reads_writes(read_count.saturating_add(2), write_count.saturating_add(1))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		use frame_support::ensure;
+
+		ensure!(
+			Pallet::<T>::on_chain_storage_version() >= TARGET_STORAGE_VERSION,
+			sp_runtime::TryRuntimeError::Other("pallet-deposit-storage storage version was not updated to v2.")
+		);
+		if T::DepositLifetime::get().is_some() {
+			ensure!(
+				Deposits::<T>::iter_keys()
+					.all(|(namespace, key)| DepositExpirations::<T>::contains_key(&namespace, &key)),
+				sp_runtime::TryRuntimeError::Other("Not all deposits have an expiration.")
+			);
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+pub mod test {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+	use kilt_support::Deposit;
+
+	use crate::{
+		migrations::v2::SetDepositExpirations,
+		mock::{DepositNamespace, ExtBuilder, TestRuntime, DEPOSIT_LIFETIME, OWNER},
+		DepositEntryOf, DepositExpirations, DepositKeyOf, HoldReason, Pallet,
+	};
+
+	fn deposit_entry() -> DepositEntryOf<TestRuntime> {
+		DepositEntryOf::<TestRuntime> {
+			reason: HoldReason::Deposit.into(),
+			deposit: Deposit {
+				amount: 10_000,
+				owner: OWNER,
+			},
+		}
+	}
+
+	#[test]
+	fn sets_expiration_of_existing_deposits() {
+		let namespace = DepositNamespace::ExampleNamespace;
+		let key = DepositKeyOf::<TestRuntime>::default();
+		let other_key = DepositKeyOf::<TestRuntime>::try_from(vec![1]).unwrap();
+		ExtBuilder::default()
+			.with_deposits(vec![
+				(namespace.clone(), key.clone(), deposit_entry()),
+				(namespace.clone(), other_key.clone(), deposit_entry()),
+			])
+			.build()
+			.execute_with(|| {
+				// Simulate a deposit taken before deposits could expire.
+				DepositExpirations::<TestRuntime>::remove(&namespace, &key);
+				StorageVersion::new(1).put::<Pallet<TestRuntime>>();
+				frame_system::Pallet::<TestRuntime>::set_block_number(100);
+
+				SetDepositExpirations::<TestRuntime>::on_runtime_upgrade();
+
+				// The lifetime is counted from the block of the upgrade.
+				assert_eq!(
+					Pallet::<TestRuntime>::deposit_expirations(&namespace, &key),
+					Some(100 + DEPOSIT_LIFETIME)
+				);
+				// Existing expirations are left untouched.
+				assert_eq!(
+					Pallet::<TestRuntime>::deposit_expirations(&namespace, &other_key),
+					Some(DEPOSIT_LIFETIME)
+				);
+				assert_eq!(
+					Pallet::<TestRuntime>::on_chain_storage_version(),
+					StorageVersion::new(2)
+				);
+			});
+	}
+
+	#[test]
+	fn does_not_run_twice() {
+		let namespace = DepositNamespace::ExampleNamespace;
+		let key = DepositKeyOf::<TestRuntime>::default();
+		ExtBuilder::default()
+			.with_deposits(vec![(namespace.clone(), key.clone(), deposit_entry())])
+			.build()
+			.execute_with(|| {
+				DepositExpirations::<TestRuntime>::remove(&namespace, &key);
+				StorageVersion::new(2).put::<Pallet<TestRuntime>>();
+
+				SetDepositExpirations::<TestRuntime>::on_runtime_upgrade();
+
+				assert!(Pallet::<TestRuntime>::deposit_expirations(&namespace, &key).is_none());
+			});
+	}
+}
@@ -30,7 +30,9 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
 
-use crate::{self as storage_deposit_pallet, DepositEntryOf, DepositKeyOf, Pallet};
+use crate::{
+	self as storage_deposit_pallet, traits::DepositAmountProvider, BalanceOf, DepositEntryOf, DepositKeyOf, Pallet,
+};
 
 pub(crate) type Balance = u128;
 
@@ -98,13 +100,36 @@ impl pallet_balances::Config for TestRuntime {
 	type ReserveIdentifier = [u8; 8];
 }
 
+pub(crate) const UPDATED_DEPOSIT_AMOUNT: Balance = 20_000;
+pub(crate) const DEPOSIT_LIFETIME: u64 = 100;
+
+pub struct MockDepositAmounts;
+
+impl DepositAmountProvider<TestRuntime> for MockDepositAmounts {
+	fn deposit_amount(
+		namespace: &DepositNamespace,
+		_key: &DepositKeyOf<TestRuntime>,
+	) -> Option<BalanceOf<TestRuntime>> {
+		match namespace {
+			DepositNamespace::ExampleNamespace => Some(UPDATED_DEPOSIT_AMOUNT),
+		}
+	}
+}
+
+parameter_types! {
+	pub const DepositLifetime: Option<u64> = Some(DEPOSIT_LIFETIME);
+}
+
 impl crate::Config for TestRuntime {
 	type CheckOrigin = EnsureSigned<Self::AccountId>;
 	type Currency = Balances;
+	type DepositAmounts = MockDepositAmounts;
 	type DepositHooks = ();
+	type DepositLifetime = DepositLifetime;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type MaxKeyLength = ConstU32<256>;
+	type MaxIndexedKeyLength = ConstU32<256>;
 	type Namespace = DepositNamespace;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHooks = ();
@@ -0,0 +1,90 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+use kilt_support::{traits::DepositIndex, Deposit};
+
+use crate::{
+	mock::{DepositNamespace, ExtBuilder, TestRuntime, OTHER_ACCOUNT, OWNER},
+	DepositEntryOf, DepositKeyOf, Deposits, HoldReason, Pallet,
+};
+
+#[test]
+fn add_and_reclaim_deposit_update_index() {
+	let deposit = DepositEntryOf::<TestRuntime> {
+		reason: HoldReason::Deposit.into(),
+		deposit: Deposit {
+			amount: 10_000,
+			owner: OWNER,
+		},
+	};
+	let namespace = DepositNamespace::ExampleNamespace;
+	let key = DepositKeyOf::<TestRuntime>::default();
+	ExtBuilder::default()
+		.with_deposits(vec![(namespace.clone(), key.clone(), deposit)])
+		.build()
+		.execute_with(|| {
+			let hashed_key = Deposits::<TestRuntime>::hashed_key_for(&namespace, &key);
+			assert_eq!(
+				Pallet::<TestRuntime>::indexed_deposits_for_owner(&OWNER),
+				vec![(hashed_key, 10_000)]
+			);
+
+			assert_ok!(Pallet::<TestRuntime>::reclaim_deposit(
+				RawOrigin::Signed(OWNER).into(),
+				namespace.clone(),
+				key.clone()
+			));
+
+			assert!(Pallet::<TestRuntime>::indexed_deposits_for_owner(&OWNER).is_empty());
+		});
+}
+
+#[test]
+fn deposit_index_hooks() {
+	ExtBuilder::default().build().execute_with(|| {
+		let hashed_key = b"hashed_key".to_vec();
+
+		Pallet::<TestRuntime>::on_deposit_held(&OWNER, &hashed_key, &100);
+		assert_eq!(
+			Pallet::<TestRuntime>::indexed_deposits_for_owner(&OWNER),
+			vec![(hashed_key.clone(), 100)]
+		);
+		assert!(Pallet::<TestRuntime>::indexed_deposits_for_owner(&OTHER_ACCOUNT).is_empty());
+
+		// Holding again for the same entry replaces the previous amount.
+		Pallet::<TestRuntime>::on_deposit_held(&OWNER, &hashed_key, &200);
+		assert_eq!(
+			Pallet::<TestRuntime>::indexed_deposits_for_owner(&OWNER),
+			vec![(hashed_key.clone(), 200)]
+		);
+
+		Pallet::<TestRuntime>::on_deposit_released(&OWNER, &hashed_key);
+		assert!(Pallet::<TestRuntime>::indexed_deposits_for_owner(&OWNER).is_empty());
+	});
+}
+
+#[test]
+fn deposit_index_skips_keys_too_long() {
+	ExtBuilder::default().build().execute_with(|| {
+		let hashed_key = vec![0u8; 257];
+
+		Pallet::<TestRuntime>::on_deposit_held(&OWNER, &hashed_key, &100);
+		assert!(Pallet::<TestRuntime>::indexed_deposits_for_owner(&OWNER).is_empty());
+	});
+}
@@ -17,4 +17,7 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 mod add_deposit;
+mod deposit_index;
 mod reclaim_deposit;
+mod remove_expired_deposit;
+mod update_deposit;
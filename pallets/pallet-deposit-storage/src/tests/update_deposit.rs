@@ -0,0 +1,145 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungible::InspectHold, Currency},
+};
+use frame_system::RawOrigin;
+use kilt_support::Deposit;
+
+use crate::{
+	mock::{
+		Balances, DepositNamespace, ExtBuilder, System, TestRuntime, DEPOSIT_LIFETIME, OTHER_ACCOUNT, OWNER,
+		UPDATED_DEPOSIT_AMOUNT,
+	},
+	DepositEntryOf, DepositKeyOf, Error, HoldReason, Pallet,
+};
+
+#[test]
+fn update_deposit_successful() {
+	let deposit = DepositEntryOf::<TestRuntime> {
+		reason: HoldReason::Deposit.into(),
+		deposit: Deposit {
+			amount: 10_000,
+			owner: OWNER,
+		},
+	};
+	let namespace = DepositNamespace::ExampleNamespace;
+	let key = DepositKeyOf::<TestRuntime>::default();
+	ExtBuilder::default()
+		.with_deposits(vec![(namespace.clone(), key.clone(), deposit)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(10);
+			// Fund the owner with enough balance to cover the updated deposit.
+			Balances::make_free_balance_be(&OWNER, 100_000);
+			assert_eq!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &OWNER), 10_000);
+			assert_eq!(
+				Pallet::<TestRuntime>::deposit_expirations(&namespace, &key),
+				Some(DEPOSIT_LIFETIME)
+			);
+
+			assert_ok!(Pallet::<TestRuntime>::update_deposit(
+				RawOrigin::Signed(OWNER).into(),
+				namespace.clone(),
+				key.clone()
+			));
+
+			assert_eq!(
+				Pallet::<TestRuntime>::deposits(&namespace, &key),
+				Some(DepositEntryOf::<TestRuntime> {
+					reason: HoldReason::Deposit.into(),
+					deposit: Deposit {
+						amount: UPDATED_DEPOSIT_AMOUNT,
+						owner: OWNER,
+					},
+				})
+			);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &OWNER),
+				UPDATED_DEPOSIT_AMOUNT
+			);
+			assert_eq!(
+				Pallet::<TestRuntime>::deposit_expirations(&namespace, &key),
+				Some(10 + DEPOSIT_LIFETIME)
+			);
+		});
+}
+
+#[test]
+fn update_deposit_not_found() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Pallet::<TestRuntime>::update_deposit(
+				RawOrigin::Signed(OWNER).into(),
+				DepositNamespace::ExampleNamespace,
+				DepositKeyOf::<TestRuntime>::default()
+			),
+			Error::<TestRuntime>::DepositNotFound
+		);
+	});
+}
+
+#[test]
+fn update_deposit_unauthorized() {
+	let deposit = DepositEntryOf::<TestRuntime> {
+		reason: HoldReason::Deposit.into(),
+		deposit: Deposit {
+			amount: 10_000,
+			owner: OWNER,
+		},
+	};
+	let namespace = DepositNamespace::ExampleNamespace;
+	let key = DepositKeyOf::<TestRuntime>::default();
+	ExtBuilder::default()
+		.with_deposits(vec![(namespace.clone(), key.clone(), deposit)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Pallet::<TestRuntime>::update_deposit(
+					RawOrigin::Signed(OTHER_ACCOUNT).into(),
+					namespace.clone(),
+					key.clone()
+				),
+				Error::<TestRuntime>::Unauthorized
+			);
+		});
+}
+
+#[test]
+fn update_deposit_insufficient_balance() {
+	let deposit = DepositEntryOf::<TestRuntime> {
+		reason: HoldReason::Deposit.into(),
+		deposit: Deposit {
+			amount: 10_000,
+			owner: OWNER,
+		},
+	};
+	let namespace = DepositNamespace::ExampleNamespace;
+	let key = DepositKeyOf::<TestRuntime>::default();
+	ExtBuilder::default()
+		.with_deposits(vec![(namespace.clone(), key.clone(), deposit)])
+		.build()
+		.execute_with(|| {
+			// The owner only has enough balance for the original deposit.
+			assert_noop!(
+				Pallet::<TestRuntime>::update_deposit(RawOrigin::Signed(OWNER).into(), namespace.clone(), key.clone()),
+				Error::<TestRuntime>::FailedToHold
+			);
+		});
+}
@@ -18,7 +18,7 @@
 
 use sp_std::fmt::Debug;
 
-use crate::{Config, DepositEntryOf, DepositKeyOf};
+use crate::{BalanceOf, Config, DepositEntryOf, DepositKeyOf};
 
 /// A trait to configure additional custom logic whenever a deposit-related
 /// operation takes place.
@@ -52,6 +52,26 @@ where
 	}
 }
 
+/// A trait to retrieve the deposit amount currently required to store an
+/// entry with a given key under a given namespace.
+pub trait DepositAmountProvider<Runtime>
+where
+	Runtime: Config,
+{
+	/// Return the deposit amount currently required, or `None` if deposits
+	/// under the provided namespace cannot be updated.
+	fn deposit_amount(namespace: &Runtime::Namespace, key: &DepositKeyOf<Runtime>) -> Option<BalanceOf<Runtime>>;
+}
+
+impl<Runtime> DepositAmountProvider<Runtime> for ()
+where
+	Runtime: Config,
+{
+	fn deposit_amount(_namespace: &Runtime::Namespace, _key: &DepositKeyOf<Runtime>) -> Option<BalanceOf<Runtime>> {
+		None
+	}
+}
+
 // Could be expanded to include traits to set up stuff before all benchmarks,
 // and before each benchmark case specifically.
 #[cfg(feature = "runtime-benchmarks")]
@@ -18,33 +18,52 @@
 
 use crate::{
 	account::{AccountId20, EthereumSignature},
+	bitcoin::{BitcoinAccountId, BitcoinSignature},
+	cosmos::{CosmosAccountId, CosmosSignature},
 	linkable_account::LinkableAccountId,
 	signature::get_wrapped_payload,
+	solana::{SolanaAccountId, SolanaSignature},
+	Config,
 };
 
 use base58::ToBase58;
 use blake2::{Blake2b512, Digest};
+use frame_support::traits::Get;
+use frame_system::pallet_prelude::BlockNumberFor;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::{
 	prelude::{format, string::String},
 	TypeInfo,
 };
-use sp_runtime::{traits::Verify, AccountId32, MultiSignature};
+use sp_runtime::{
+	traits::{UniqueSaturatedInto, Verify},
+	AccountId32, MultiSignature,
+};
 use sp_std::{fmt::Debug, vec, vec::Vec};
 
+/// The maximum length of the challenge returned by [`get_challenge`].
+///
+/// Some wallets (e.g., the Solana off-chain message format) limit the length
+/// of the messages they sign, so requests for longer challenges are rejected.
+pub const MAX_CHALLENGE_LENGTH: usize = 256;
+
 #[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub enum AssociateAccountRequest {
 	Polkadot(AccountId32, MultiSignature),
 	Ethereum(AccountId20, EthereumSignature),
+	Bitcoin(BitcoinAccountId, BitcoinSignature),
+	Solana(SolanaAccountId, SolanaSignature),
+	Cosmos(CosmosAccountId, CosmosSignature),
+	/// An EIP-712 typed data signature of an externally owned account.
+	EthereumTypedData(AccountId20, EthereumSignature),
 }
 
 impl AssociateAccountRequest {
-	pub fn verify<DidIdentifier: AsRef<[u8]>, BlockNumber: Debug>(
-		&self,
-		did_identifier: &DidIdentifier,
-		expiration: BlockNumber,
-	) -> bool {
+	pub fn verify<T: Config>(&self, did_identifier: &T::DidIdentifier, expiration: BlockNumberFor<T>) -> bool {
 		let encoded_payload = get_challenge(did_identifier, expiration).into_bytes();
+		if encoded_payload.len() > MAX_CHALLENGE_LENGTH {
+			return false;
+		}
 		match self {
 			AssociateAccountRequest::Polkadot(acc, proof) => proof.verify(
 				&get_wrapped_payload(&encoded_payload[..], crate::signature::WrapType::Substrate)[..],
@@ -54,6 +73,13 @@ impl AssociateAccountRequest {
 				&get_wrapped_payload(&encoded_payload[..], crate::signature::WrapType::Ethereum)[..],
 				acc,
 			),
+			// The non-Substrate signatures take care of their own wrapping.
+			AssociateAccountRequest::Bitcoin(acc, proof) => proof.verify(&encoded_payload[..], acc),
+			AssociateAccountRequest::Solana(acc, proof) => proof.verify(&encoded_payload[..], acc),
+			AssociateAccountRequest::Cosmos(acc, proof) => proof.verify(&encoded_payload[..], acc),
+			AssociateAccountRequest::EthereumTypedData(acc, proof) => {
+				proof.verify(&get_typed_data_payload::<T>(did_identifier, expiration)[..], acc)
+			}
 		}
 	}
 
@@ -61,23 +87,46 @@ impl AssociateAccountRequest {
 		match self {
 			AssociateAccountRequest::Polkadot(acc, _) => LinkableAccountId::AccountId32(acc.clone()),
 			AssociateAccountRequest::Ethereum(acc, _) => LinkableAccountId::AccountId20(*acc),
+			AssociateAccountRequest::Bitcoin(acc, _) => LinkableAccountId::Bitcoin(*acc),
+			AssociateAccountRequest::Solana(acc, _) => LinkableAccountId::Solana(*acc),
+			AssociateAccountRequest::Cosmos(acc, _) => LinkableAccountId::Cosmos(*acc),
+			AssociateAccountRequest::EthereumTypedData(acc, _) => LinkableAccountId::AccountId20(*acc),
 		}
 	}
 }
 
 /// Build the challenge that must be signed to prove the consent for an
 /// account to be linked to a DID.
+///
+/// The challenge is ASCII-only, so that it can be signed by all the supported
+/// wallets, and it is rejected if longer than [`MAX_CHALLENGE_LENGTH`].
 pub fn get_challenge<DidIdentifier: AsRef<[u8]>, BlockNumber: Debug>(
 	did_identifier: &DidIdentifier,
 	expiration: BlockNumber,
 ) -> String {
 	format!(
-		"Publicly link the signing address to did:kilt:{} before block number {:?}",
-		to_ss58(did_identifier.as_ref(), 38),
+		"Publicly link the signing address to {} before block number {:?}",
+		get_did_uri(did_identifier),
 		expiration
 	)
 }
 
+/// Build the EIP-712 typed data payload that must be signed to prove the
+/// consent for an Ethereum account to be linked to a DID.
+///
+/// The payload is bound to the chain via the `EthereumChainId` of the pallet.
+pub fn get_typed_data_payload<T: Config>(did_identifier: &T::DidIdentifier, expiration: BlockNumberFor<T>) -> Vec<u8> {
+	crate::eip712::get_typed_data_payload(
+		get_did_uri(did_identifier).as_bytes(),
+		expiration.unique_saturated_into(),
+		T::EthereumChainId::get(),
+	)
+}
+
+fn get_did_uri<DidIdentifier: AsRef<[u8]>>(did_identifier: &DidIdentifier) -> String {
+	format!("did:kilt:{}", to_ss58(did_identifier.as_ref(), 38))
+}
+
 // Copied from https://github.com/paritytech/substrate/blob/ad5399644aebc54e32a107ac37ae08e6cd1f0cfb/primitives/core/src/crypto.rs#L324
 // Copyright (C) Parity Technologies (UK) Ltd.
 // SPDX-License-Identifier: Apache-2.0
@@ -114,7 +163,7 @@ fn ss58hash(data: &[u8]) -> Vec<u8> {
 
 #[cfg(test)]
 mod tests {
-	use super::get_challenge;
+	use super::{get_challenge, MAX_CHALLENGE_LENGTH};
 
 	#[test]
 	fn test_get_challenge() {
@@ -123,4 +172,10 @@ mod tests {
 			"Publicly link the signing address to did:kilt:4nwPAmtsK5toZfBM9WvmAe4Fa3LyZ3X3JHt7EUFfrcPPAZAm before block number 5"
 		);
 	}
+
+	#[test]
+	fn test_challenge_length() {
+		assert!(get_challenge(&[u8::MAX; 32], u64::MAX).len() <= MAX_CHALLENGE_LENGTH);
+		assert!(get_challenge(&[u8::MAX; 32], u128::MAX).len() <= MAX_CHALLENGE_LENGTH);
+	}
 }
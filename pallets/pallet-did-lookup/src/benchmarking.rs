@@ -40,9 +40,13 @@ use kilt_support::{traits::GenerateBenchmarkOrigin, Deposit};
 
 use crate::{
 	account::AccountId20,
-	associate_account_request::{get_challenge, AssociateAccountRequest},
+	associate_account_request::{get_challenge, get_typed_data_payload, AssociateAccountRequest},
+	bitcoin::{bip322_sighash, BitcoinAccountId, BitcoinSignature},
+	cosmos::{CosmosAccountId, CosmosSignature},
+	encoding::hash160,
 	linkable_account::LinkableAccountId,
 	signature::get_wrapped_payload,
+	solana::SolanaAccountId,
 	AccountIdOf, Call, Config, ConnectedAccounts, ConnectedDids, CurrencyOf, Pallet,
 };
 
@@ -202,6 +206,149 @@ benchmarks! {
 		assert!(ConnectedAccounts::<T>::get(did, LinkableAccountId::from(eth_account)).is_some());
 	}
 
+	associate_bitcoin_account {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		let previous_did: T::DidIdentifier = account("prev", 0, SEED + 1);
+		let expire_at: BlockNumberFor<T> = 500_u32.into();
+
+		let btc_public_key = ecdsa_generate(KeyTypeId(*b"aura"), None);
+		let btc_account = BitcoinAccountId(hash160(&btc_public_key.0));
+
+		// BIP-322 signatures are verified within the runtime, so they are more
+		// expensive than legacy ones.
+		let sig = sp_io::crypto::ecdsa_sign_prehashed(
+			KeyTypeId(*b"aura"),
+			&btc_public_key,
+			&bip322_sighash(get_challenge(&did, expire_at).as_bytes(), &btc_account),
+		).ok_or("Error while building signature.")?;
+		let der_sig = libsecp256k1::Signature::parse_standard_slice(&sig.0[..64])
+			.map_err(|_| "Error while encoding signature.")?
+			.serialize_der();
+		let bip322_sig = BitcoinSignature::Bip322 {
+			signature: [der_sig.as_ref(), &[0x01]].concat().try_into().map_err(|_| "Signature too long.")?,
+			public_key: btc_public_key.0,
+		};
+
+		make_free_for_did::<T>(&caller);
+
+		// Add existing connected_acc -> previous_did connection that will be replaced
+		Pallet::<T>::add_association(caller.clone(), previous_did.clone(), btc_account.into()).expect("should create previous association");
+		assert!(ConnectedAccounts::<T>::get(&previous_did, LinkableAccountId::from(btc_account)).is_some());
+		let origin = T::EnsureOrigin::generate_origin(caller, did.clone());
+		let req = AssociateAccountRequest::Bitcoin(btc_account, bip322_sig);
+	}: associate_account<T::RuntimeOrigin>(origin, req, expire_at)
+	verify {
+		assert!(ConnectedDids::<T>::get(LinkableAccountId::from(btc_account)).is_some());
+		assert!(ConnectedAccounts::<T>::get(&previous_did, LinkableAccountId::from(btc_account)).is_none());
+		assert!(ConnectedAccounts::<T>::get(did, LinkableAccountId::from(btc_account)).is_some());
+	}
+
+	associate_solana_account {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		let previous_did: T::DidIdentifier = account("prev", 0, SEED + 1);
+		let expire_at: BlockNumberFor<T> = 500_u32.into();
+
+		let sol_public_key = ed25519_generate(KeyTypeId(*b"aura"), None);
+		let sol_account = SolanaAccountId::from(sol_public_key);
+
+		let sig = sp_io::crypto::ed25519_sign(
+			KeyTypeId(*b"aura"),
+			&sol_public_key,
+			&get_wrapped_payload(
+				get_challenge(&did, expire_at).as_bytes(),
+				crate::signature::WrapType::Solana,
+			),
+		).ok_or("Error while building signature.")?;
+
+		make_free_for_did::<T>(&caller);
+
+		// Add existing connected_acc -> previous_did connection that will be replaced
+		Pallet::<T>::add_association(caller.clone(), previous_did.clone(), sol_account.into()).expect("should create previous association");
+		assert!(ConnectedAccounts::<T>::get(&previous_did, LinkableAccountId::from(sol_account)).is_some());
+		let origin = T::EnsureOrigin::generate_origin(caller, did.clone());
+		let req = AssociateAccountRequest::Solana(sol_account, sig.into());
+	}: associate_account<T::RuntimeOrigin>(origin, req, expire_at)
+	verify {
+		assert!(ConnectedDids::<T>::get(LinkableAccountId::from(sol_account)).is_some());
+		assert!(ConnectedAccounts::<T>::get(&previous_did, LinkableAccountId::from(sol_account)).is_none());
+		assert!(ConnectedAccounts::<T>::get(did, LinkableAccountId::from(sol_account)).is_some());
+	}
+
+	associate_cosmos_account {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		let previous_did: T::DidIdentifier = account("prev", 0, SEED + 1);
+		let expire_at: BlockNumberFor<T> = 500_u32.into();
+
+		let cosmos_public_key = ecdsa_generate(KeyTypeId(*b"aura"), None);
+		let cosmos_account = CosmosAccountId(hash160(&cosmos_public_key.0));
+		// Use the longest supported address prefix.
+		let prefix = [b'a'; crate::cosmos::MAX_ADDRESS_PREFIX_LENGTH as usize];
+		let address = cosmos_account.to_address(&prefix).ok_or("Error while encoding address.")?;
+
+		let sig = sp_io::crypto::ecdsa_sign_prehashed(
+			KeyTypeId(*b"aura"),
+			&cosmos_public_key,
+			&sp_io::hashing::sha2_256(&get_wrapped_payload(
+				get_challenge(&did, expire_at).as_bytes(),
+				crate::signature::WrapType::Cosmos(&address),
+			)),
+		).ok_or("Error while building signature.")?;
+		let mut signature = [0u8; 64];
+		signature.copy_from_slice(&sig.0[..64]);
+		let cosmos_sig = CosmosSignature {
+			prefix: prefix.to_vec().try_into().map_err(|_| "Prefix too long.")?,
+			public_key: cosmos_public_key.0,
+			signature,
+		};
+
+		make_free_for_did::<T>(&caller);
+
+		// Add existing connected_acc -> previous_did connection that will be replaced
+		Pallet::<T>::add_association(caller.clone(), previous_did.clone(), cosmos_account.into()).expect("should create previous association");
+		assert!(ConnectedAccounts::<T>::get(&previous_did, LinkableAccountId::from(cosmos_account)).is_some());
+		let origin = T::EnsureOrigin::generate_origin(caller, did.clone());
+		let req = AssociateAccountRequest::Cosmos(cosmos_account, cosmos_sig);
+	}: associate_account<T::RuntimeOrigin>(origin, req, expire_at)
+	verify {
+		assert!(ConnectedDids::<T>::get(LinkableAccountId::from(cosmos_account)).is_some());
+		assert!(ConnectedAccounts::<T>::get(&previous_did, LinkableAccountId::from(cosmos_account)).is_none());
+		assert!(ConnectedAccounts::<T>::get(did, LinkableAccountId::from(cosmos_account)).is_some());
+	}
+
+	associate_eth_typed_data_account {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		let previous_did: T::DidIdentifier = account("prev", 0, SEED + 1);
+		let expire_at: BlockNumberFor<T> = 500_u32.into();
+
+		let eth_public_key = ecdsa_generate(KeyTypeId(*b"aura"), None);
+		let eth_account = AccountId20(eth_public_key.to_eth_address().unwrap());
+
+		let typed_data_payload = get_typed_data_payload::<T>(&did, expire_at);
+
+		let sig = sp_io::crypto::ecdsa_sign_prehashed(
+			KeyTypeId(*b"aura"),
+			&eth_public_key,
+			&Keccak256::digest(typed_data_payload).try_into().unwrap(),
+		).ok_or("Error while building signature.")?;
+
+		make_free_for_did::<T>(&caller);
+
+		// Add existing connected_acc -> previous_did connection that will be replaced
+		Pallet::<T>::add_association(caller.clone(), previous_did.clone(), eth_account.into()).expect("should create previous association");
+		assert!(ConnectedAccounts::<T>::get(&previous_did, LinkableAccountId::from(eth_account)).is_some());
+		let origin = T::EnsureOrigin::generate_origin(caller, did.clone());
+		let req = AssociateAccountRequest::EthereumTypedData(eth_account, sig.into());
+	}: associate_account<T::RuntimeOrigin>(origin, req, expire_at)
+	verify {
+		assert!(ConnectedDids::<T>::get(LinkableAccountId::from(eth_account)).is_some());
+		assert!(ConnectedAccounts::<T>::get(&previous_did, LinkableAccountId::from(eth_account)).is_none());
+		assert!(ConnectedAccounts::<T>::get(did, LinkableAccountId::from(eth_account)).is_some());
+	}
+
 	associate_sender {
 		let caller: T::AccountId = account("caller", 0, SEED);
 		let linkable_id: LinkableAccountId = caller.clone().into();
@@ -0,0 +1,250 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! The Bitcoin account and signature implementation.
+//!
+//! Both the legacy `signmessage` format (BIP-137) and the simple BIP-322
+//! format for native segwit (P2WPKH) addresses are supported.
+
+use frame_support::{traits::ConstU32, BoundedVec};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+use crate::{
+	encoding::{double_sha256, hash160},
+	signature::{get_wrapped_payload, WrapType},
+};
+
+/// The maximum length of a DER-encoded ECDSA signature, followed by the
+/// sighash type.
+pub const MAX_BIP322_SIGNATURE_LENGTH: u32 = 73;
+
+/// The AccountId for a Bitcoin account.
+/// It is the 20-byte HASH160 of the account public key.
+#[derive(
+	Eq,
+	PartialEq,
+	Copy,
+	Clone,
+	Encode,
+	Decode,
+	TypeInfo,
+	MaxEncodedLen,
+	Default,
+	PartialOrd,
+	Ord,
+	RuntimeDebug,
+	Serialize,
+	Deserialize,
+)]
+pub struct BitcoinAccountId(pub [u8; 20]);
+
+/// Displays the account as a native segwit (P2WPKH) mainnet address.
+#[cfg(feature = "std")]
+impl std::fmt::Display for BitcoinAccountId {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		// Witness version 0, followed by the witness program.
+		let data = [&[0u8][..], &crate::encoding::to_base32(&self.0)[..]].concat();
+		let address = crate::encoding::bech32_encode(b"bc", &data).ok_or(std::fmt::Error)?;
+		write!(f, "{}", String::from_utf8_lossy(&address))
+	}
+}
+
+impl From<[u8; 20]> for BitcoinAccountId {
+	fn from(bytes: [u8; 20]) -> Self {
+		Self(bytes)
+	}
+}
+
+impl From<BitcoinAccountId> for [u8; 20] {
+	fn from(id: BitcoinAccountId) -> Self {
+		id.0
+	}
+}
+
+#[derive(Eq, PartialEq, Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum BitcoinSignature {
+	/// A 65-byte signature as produced by the `signmessage` RPC, i.e., the
+	/// header byte followed by `r` and `s`.
+	Legacy([u8; 65]),
+	/// The witness of a simple BIP-322 signature for a P2WPKH address.
+	Bip322 {
+		/// The DER-encoded signature, followed by the sighash type.
+		signature: BoundedVec<u8, ConstU32<MAX_BIP322_SIGNATURE_LENGTH>>,
+		/// The compressed public key of the signer.
+		public_key: [u8; 33],
+	},
+}
+
+impl BitcoinSignature {
+	/// Verify that the signature was generated by the provided account over
+	/// the provided message.
+	pub fn verify(&self, message: &[u8], signer: &BitcoinAccountId) -> bool {
+		match self {
+			Self::Legacy(signature) => verify_legacy(signature, message, signer),
+			Self::Bip322 { signature, public_key } => verify_bip322(signature, public_key, message, signer),
+		}
+	}
+}
+
+fn verify_legacy(signature: &[u8; 65], message: &[u8], signer: &BitcoinAccountId) -> bool {
+	// Header bytes are 27-30 for uncompressed keys, 31-34 for compressed keys,
+	// 35-38 for P2SH-P2WPKH and 39-42 for P2WPKH addresses.
+	let header = signature[0];
+	if !(27..=42).contains(&header) {
+		return false;
+	}
+	let mut recoverable_signature = [0u8; 65];
+	recoverable_signature[..64].copy_from_slice(&signature[1..]);
+	recoverable_signature[64] = (header - 27) % 4;
+
+	let message_hash = double_sha256(&get_wrapped_payload(message, WrapType::Bitcoin));
+	let recovered_key_hash = if header >= 31 {
+		sp_io::crypto::secp256k1_ecdsa_recover_compressed(&recoverable_signature, &message_hash)
+			.map(|public_key| hash160(&public_key))
+	} else {
+		sp_io::crypto::secp256k1_ecdsa_recover(&recoverable_signature, &message_hash)
+			.map(|public_key| hash160(&[&[0x04][..], &public_key[..]].concat()))
+	};
+	match recovered_key_hash {
+		Ok(key_hash) => key_hash == signer.0,
+		Err(_) => {
+			log::trace!("Error verifying signature");
+			false
+		}
+	}
+}
+
+fn verify_bip322(signature: &[u8], public_key: &[u8; 33], message: &[u8], signer: &BitcoinAccountId) -> bool {
+	// Only SIGHASH_ALL is accepted.
+	let Some((0x01, der_signature)) = signature.split_last() else {
+		return false;
+	};
+	if hash160(public_key) != signer.0 {
+		return false;
+	}
+	let (Ok(signature), Ok(public_key)) = (
+		libsecp256k1::Signature::parse_der(der_signature),
+		libsecp256k1::PublicKey::parse_compressed(public_key),
+	) else {
+		return false;
+	};
+	let sighash = libsecp256k1::Message::parse(&bip322_sighash(message, signer));
+	libsecp256k1::verify(&sighash, &signature, &public_key)
+}
+
+/// The BIP-143 signature hash of the BIP-322 `to_sign` transaction, for the
+/// given message and P2WPKH account.
+pub(crate) fn bip322_sighash(message: &[u8], signer: &BitcoinAccountId) -> [u8; 32] {
+	let tag_hash = sp_io::hashing::sha2_256(b"BIP0322-signed-message");
+	let message_hash = sp_io::hashing::sha2_256(&[&tag_hash[..], &tag_hash[..], message].concat());
+
+	// The virtual `to_spend` transaction, spending from the P2WPKH output script.
+	let to_spend: Vec<u8> = [
+		// version
+		&[0u8; 4][..],
+		// one input, spending the null outpoint
+		&[0x01],
+		&[0u8; 32],
+		&[0xff; 4],
+		// scriptSig: OP_0 PUSH32[message_hash]
+		&[0x22, 0x00, 0x20],
+		&message_hash,
+		// sequence
+		&[0u8; 4],
+		// one output of value 0, locked by the signer script
+		&[0x01],
+		&[0u8; 8],
+		&[0x16, 0x00, 0x14],
+		&signer.0,
+		// lock time
+		&[0u8; 4],
+	]
+	.concat();
+	let to_spend_txid = double_sha256(&to_spend);
+	let to_spend_outpoint = [&to_spend_txid[..], &[0u8; 4]].concat();
+
+	// The BIP-143 preimage of the virtual `to_sign` transaction, spending the
+	// only output of `to_spend` into a single OP_RETURN output.
+	let preimage: Vec<u8> = [
+		// version
+		&[0u8; 4][..],
+		&double_sha256(&to_spend_outpoint),
+		&double_sha256(&[0u8; 4]),
+		&to_spend_outpoint,
+		// scriptCode: OP_DUP OP_HASH160 PUSH20[key_hash] OP_EQUALVERIFY OP_CHECKSIG
+		&[0x19, 0x76, 0xa9, 0x14],
+		&signer.0,
+		&[0x88, 0xac],
+		// amount
+		&[0u8; 8],
+		// sequence
+		&[0u8; 4],
+		&double_sha256(&[&[0u8; 8][..], &[0x01, 0x6a]].concat()),
+		// lock time
+		&[0u8; 4],
+		// sighash type
+		&[0x01, 0x00, 0x00, 0x00],
+	]
+	.concat();
+	double_sha256(&preimage)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	// Test vectors from https://github.com/bitcoin/bips/blob/master/bip-0322.mediawiki#test-vectors
+	const PUBLIC_KEY: &str = "02c7f12003196442943d8588e01aee840423cc54fc1521526a3b85c2b0cbd58872";
+	const KEY_HASH: &str = "2b05d564e6a7a33c087f16e0f730d1440123799d";
+
+	fn bip322_signature(signature: &str) -> BitcoinSignature {
+		let mut public_key = [0u8; 33];
+		hex::decode_to_slice(PUBLIC_KEY, &mut public_key).unwrap();
+		BitcoinSignature::Bip322 {
+			signature: hex::decode(signature).unwrap().try_into().unwrap(),
+			public_key,
+		}
+	}
+
+	fn signer() -> BitcoinAccountId {
+		let mut key_hash = [0u8; 20];
+		hex::decode_to_slice(KEY_HASH, &mut key_hash).unwrap();
+		key_hash.into()
+	}
+
+	#[test]
+	fn test_account_display() {
+		assert_eq!(signer().to_string(), "bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l");
+	}
+
+	#[test]
+	fn test_bip322_vectors() {
+		let empty_message_signature = bip322_signature("30440220336801010aaf657d79662cac98a990a43ac6f376af2c84f8f76401ccb9d0231602201693a4e683db4a91944ca5cb11527840366daf583a2c695fccf8e93483b52e3401");
+		let hello_world_signature = bip322_signature("304402206517c8637a7bfc3a154edcba6196d64bbd5b73955cb7da7d1626bcdde466c364022022bf10d19fc0bb69b4596e306b362acaa835293cf693bb176f7324b531f5afec01");
+
+		assert!(empty_message_signature.verify(b"", &signer()));
+		assert!(hello_world_signature.verify(b"Hello World", &signer()));
+
+		assert!(!empty_message_signature.verify(b"Hello World", &signer()));
+		assert!(!hello_world_signature.verify(b"Hello World", &[0u8; 20].into()));
+	}
+}
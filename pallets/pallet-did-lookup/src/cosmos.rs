@@ -0,0 +1,194 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! The Cosmos account and signature implementation.
+//!
+//! Signatures are verified over ADR-036 sign documents, as produced by the
+//! `signArbitrary` method of Cosmos wallets.
+
+use frame_support::{traits::ConstU32, BoundedVec};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+use crate::{
+	encoding::{bech32_encode, hash160, to_base32},
+	signature::{get_wrapped_payload, WrapType},
+};
+
+/// The maximum length of a bech32 address prefix, e.g., `cosmos` or `osmo`.
+pub const MAX_ADDRESS_PREFIX_LENGTH: u32 = 16;
+
+/// The AccountId for a Cosmos account.
+/// It is the 20-byte HASH160 of the secp256k1 public key of the account, and
+/// it is shared across all the chains using the same key derivation.
+#[derive(
+	Eq,
+	PartialEq,
+	Copy,
+	Clone,
+	Encode,
+	Decode,
+	TypeInfo,
+	MaxEncodedLen,
+	Default,
+	PartialOrd,
+	Ord,
+	RuntimeDebug,
+	Serialize,
+	Deserialize,
+)]
+pub struct CosmosAccountId(pub [u8; 20]);
+
+impl CosmosAccountId {
+	/// The bech32 address of the account on the chain with the given address
+	/// prefix.
+	pub fn to_address(&self, prefix: &[u8]) -> Option<Vec<u8>> {
+		bech32_encode(prefix, &to_base32(&self.0))
+	}
+}
+
+/// Displays the account as a Cosmos Hub address.
+#[cfg(feature = "std")]
+impl std::fmt::Display for CosmosAccountId {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let address = self.to_address(b"cosmos").ok_or(std::fmt::Error)?;
+		write!(f, "{}", String::from_utf8_lossy(&address))
+	}
+}
+
+impl From<[u8; 20]> for CosmosAccountId {
+	fn from(bytes: [u8; 20]) -> Self {
+		Self(bytes)
+	}
+}
+
+impl From<CosmosAccountId> for [u8; 20] {
+	fn from(id: CosmosAccountId) -> Self {
+		id.0
+	}
+}
+
+#[derive(Eq, PartialEq, Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct CosmosSignature {
+	/// The address prefix of the chain the signer address was displayed for,
+	/// which is part of the signed document.
+	pub prefix: BoundedVec<u8, ConstU32<MAX_ADDRESS_PREFIX_LENGTH>>,
+	/// The compressed public key of the signer.
+	pub public_key: [u8; 33],
+	/// The `r` and `s` values of the signature. Only signatures with a low
+	/// `s` value are valid, as required by Cosmos SDK chains.
+	pub signature: [u8; 64],
+}
+
+impl CosmosSignature {
+	/// Verify that the signature was generated by the provided account over
+	/// the provided message, wrapped in an ADR-036 sign document.
+	pub fn verify(&self, message: &[u8], signer: &CosmosAccountId) -> bool {
+		if hash160(&self.public_key) != signer.0 {
+			return false;
+		}
+		let Some(address) = signer.to_address(&self.prefix) else {
+			return false;
+		};
+		let (Ok(signature), Ok(public_key)) = (
+			libsecp256k1::Signature::parse_standard(&self.signature),
+			libsecp256k1::PublicKey::parse_compressed(&self.public_key),
+		) else {
+			return false;
+		};
+		// Reject the malleable high-S counterpart of a valid signature.
+		if signature.s.is_high() {
+			return false;
+		}
+		let sign_doc_hash = sp_io::hashing::sha2_256(&get_wrapped_payload(message, WrapType::Cosmos(&address)));
+		libsecp256k1::verify(&libsecp256k1::Message::parse(&sign_doc_hash), &signature, &public_key)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::{ecdsa, Pair};
+
+	#[test]
+	fn test_account_display() {
+		// Key hash of the BIP-322 test vector, as a Cosmos Hub address.
+		let mut key_hash = [0u8; 20];
+		hex::decode_to_slice("2b05d564e6a7a33c087f16e0f730d1440123799d", &mut key_hash).unwrap();
+		assert_eq!(
+			CosmosAccountId(key_hash).to_string(),
+			"cosmos19vza2e8x573nczrlzms0wvx3gsqjx7va6jgdtj"
+		);
+	}
+
+	#[test]
+	fn test_adr036_signature() {
+		let pair = ecdsa::Pair::from_seed(&[1u8; 32]);
+		let signer = CosmosAccountId(hash160(&pair.public().0));
+		let message = b"Hello Cosmos";
+		let address = signer.to_address(b"osmo").unwrap();
+		let sign_doc_hash = sp_io::hashing::sha2_256(&get_wrapped_payload(message, WrapType::Cosmos(&address)));
+		let mut signature = [0u8; 64];
+		signature.copy_from_slice(&pair.sign_prehashed(&sign_doc_hash).0[..64]);
+
+		let cosmos_signature = CosmosSignature {
+			prefix: b"osmo".to_vec().try_into().unwrap(),
+			public_key: pair.public().0,
+			signature,
+		};
+		assert!(cosmos_signature.verify(message, &signer));
+		assert!(!cosmos_signature.verify(b"Hello Osmosis", &signer));
+
+		let wrong_prefix_signature = CosmosSignature {
+			prefix: b"cosmos".to_vec().try_into().unwrap(),
+			..cosmos_signature
+		};
+		assert!(!wrong_prefix_signature.verify(message, &signer));
+	}
+
+	#[test]
+	fn test_high_s_signature() {
+		let pair = ecdsa::Pair::from_seed(&[1u8; 32]);
+		let signer = CosmosAccountId(hash160(&pair.public().0));
+		let message = b"Hello Cosmos";
+		let address = signer.to_address(b"cosmos").unwrap();
+		let sign_doc_hash = sp_io::hashing::sha2_256(&get_wrapped_payload(message, WrapType::Cosmos(&address)));
+		let mut signature = [0u8; 64];
+		signature.copy_from_slice(&pair.sign_prehashed(&sign_doc_hash).0[..64]);
+
+		let mut high_s_signature = libsecp256k1::Signature::parse_standard(&signature).unwrap();
+		assert!(!high_s_signature.s.is_high());
+		high_s_signature.s = -high_s_signature.s;
+
+		let cosmos_signature = CosmosSignature {
+			prefix: b"cosmos".to_vec().try_into().unwrap(),
+			public_key: pair.public().0,
+			signature: high_s_signature.serialize(),
+		};
+		// The high-S signature is still mathematically valid.
+		assert!(libsecp256k1::verify(
+			&libsecp256k1::Message::parse(&sign_doc_hash),
+			&high_s_signature,
+			&libsecp256k1::PublicKey::parse_compressed(&pair.public().0).unwrap()
+		));
+		assert!(!cosmos_signature.verify(message, &signer));
+	}
+}
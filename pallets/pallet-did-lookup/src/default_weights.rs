@@ -54,6 +54,10 @@ pub trait WeightInfo {
 	fn associate_account_multisig_ed25519() -> Weight;
 	fn associate_account_multisig_ecdsa() -> Weight;
 	fn associate_eth_account() -> Weight;
+	fn associate_bitcoin_account() -> Weight;
+	fn associate_solana_account() -> Weight;
+	fn associate_cosmos_account() -> Weight;
+	fn associate_eth_typed_data_account() -> Weight;
 	fn associate_sender() -> Weight;
 	fn remove_sender_association() -> Weight;
 	fn remove_account_association() -> Weight;
@@ -70,14 +74,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
 	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn associate_account_multisig_sr25519() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 64_706 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(66_077_000, 5211)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
@@ -85,14 +90,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
 	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn associate_account_multisig_ed25519() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 63_377 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(64_358_000, 5211)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
@@ -100,14 +106,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
 	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn associate_account_multisig_ecdsa() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 59_043 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(63_304_000, 5211)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
@@ -115,14 +122,31 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
 	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn associate_eth_account() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `391`
-		//  Estimated: `5211`
-		// Minimum execution time: 59_169 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(63_871_000, 5211)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	fn associate_bitcoin_account() -> Weight {
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
+		Weight::from_parts(68_980_000, 5211)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
@@ -130,14 +154,63 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
 	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	fn associate_solana_account() -> Weight {
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
+		Weight::from_parts(59_400_000, 5211)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	fn associate_cosmos_account() -> Weight {
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
+		Weight::from_parts(67_064_000, 5211)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	fn associate_eth_typed_data_account() -> Weight {
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
+		Weight::from_parts(64_381_000, 5211)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn associate_sender() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 28_354 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(29_272_000, 5211)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
@@ -145,14 +218,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:1)
 	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn remove_sender_association() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 17_432 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(17_906_000, 5211)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
@@ -160,40 +234,43 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:1)
 	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn remove_account_association() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 18_685 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(19_115_000, 5211)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `816`
-		//  Estimated: `7818`
-		// Minimum execution time: 25_338 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(25_951_000, 7818)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 21_996 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(22_991_000, 5211)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 }
 
@@ -205,14 +282,15 @@ impl WeightInfo for () {
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
 	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn associate_account_multisig_sr25519() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 64_706 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(66_077_000, 5211)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
@@ -220,14 +298,15 @@ impl WeightInfo for () {
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
 	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn associate_account_multisig_ed25519() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 63_377 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(64_358_000, 5211)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
@@ -235,14 +314,15 @@ impl WeightInfo for () {
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
 	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn associate_account_multisig_ecdsa() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 59_043 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(63_304_000, 5211)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
@@ -250,14 +330,31 @@ impl WeightInfo for () {
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
 	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn associate_eth_account() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `391`
-		//  Estimated: `5211`
-		// Minimum execution time: 59_169 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(63_871_000, 5211)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	fn associate_bitcoin_account() -> Weight {
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
+		Weight::from_parts(68_980_000, 5211)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
@@ -265,14 +362,63 @@ impl WeightInfo for () {
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
 	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	fn associate_solana_account() -> Weight {
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
+		Weight::from_parts(59_400_000, 5211)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	fn associate_cosmos_account() -> Weight {
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
+		Weight::from_parts(67_064_000, 5211)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	fn associate_eth_typed_data_account() -> Weight {
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
+		Weight::from_parts(64_381_000, 5211)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn associate_sender() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 28_354 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(29_272_000, 5211)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
@@ -280,14 +426,15 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:1)
 	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn remove_sender_association() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 17_432 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(17_906_000, 5211)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
@@ -295,39 +442,42 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:1)
 	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn remove_account_association() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 18_685 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(19_115_000, 5211)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `816`
-		//  Estimated: `7818`
-		// Minimum execution time: 25_338 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(25_951_000, 7818)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: DidLookup ConnectedDids (r:1 w:1)
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `5211`
-		// Minimum execution time: 21_996 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(22_991_000, 5211)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 }
@@ -0,0 +1,99 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! EIP-712 typed data for Ethereum account association requests.
+
+use sp_io::hashing::keccak_256;
+use sp_std::vec::Vec;
+
+/// EIP-712 domain type, used to compute a chain-specific domain separator.
+const EIP712_DOMAIN_TYPE: &[u8] = b"EIP712Domain(string name,string version,uint256 chainId)";
+/// EIP-712 type of the account linking message.
+const ACCOUNT_LINKING_TYPE: &[u8] = b"AccountLinking(string did,uint256 expiration)";
+const DOMAIN_NAME: &[u8] = b"DID Lookup";
+const DOMAIN_VERSION: &[u8] = b"1";
+
+fn to_uint256(value: u128) -> [u8; 32] {
+	let mut output = [0u8; 32];
+	output[16..].copy_from_slice(&value.to_be_bytes());
+	output
+}
+
+/// Compute the EIP-712 domain separator for the provided chain ID.
+pub fn compute_domain_separator(chain_id: u64) -> [u8; 32] {
+	let domain_separator_inner = [
+		keccak_256(EIP712_DOMAIN_TYPE),
+		keccak_256(DOMAIN_NAME),
+		keccak_256(DOMAIN_VERSION),
+		to_uint256(chain_id.into()),
+	]
+	.concat();
+
+	keccak_256(&domain_separator_inner)
+}
+
+/// Build the EIP-712 payload that must be signed to link an Ethereum account
+/// to the provided DID URI, i.e., `0x1901 ‖ domainSeparator ‖ hashStruct`.
+///
+/// The digest of the payload is the value signed by the account.
+pub fn get_typed_data_payload(did_uri: &[u8], expiration: u128, chain_id: u64) -> Vec<u8> {
+	let message_inner = [
+		keccak_256(ACCOUNT_LINKING_TYPE),
+		// strings are encoded as the keccak_256 of the content
+		keccak_256(did_uri),
+		to_uint256(expiration),
+	]
+	.concat();
+
+	[
+		&b"\x19\x01"[..],
+		&compute_domain_separator(chain_id),
+		&keccak_256(&message_inner),
+	]
+	.concat()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn hash_from_hex(input: &str) -> [u8; 32] {
+		let mut output = [0u8; 32];
+		hex::decode_to_slice(input, &mut output).unwrap();
+		output
+	}
+
+	#[test]
+	fn test_domain_separator() {
+		assert_eq!(
+			compute_domain_separator(1),
+			hash_from_hex("d261f5a221ceb5298e04886ed4546795c84315d2963eca8c73f63e71332509ec")
+		);
+		// The separator commits to the chain ID.
+		assert_ne!(compute_domain_separator(1), compute_domain_separator(2));
+	}
+
+	#[test]
+	fn test_typed_data_digest() {
+		let payload = get_typed_data_payload(b"did:kilt:4nwPAmtsK5toZfBM9WvmAe4Fa3LyZ3X3JHt7EUFfrcPPAZAm", 5, 1);
+		assert_eq!(
+			keccak_256(&payload),
+			hash_from_hex("28e8839c1ee4abeede8d0314e663cbbb0983dbd56c102515db2c5eacd3b1cb57")
+		);
+	}
+}
@@ -0,0 +1,160 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Encodings and hashes shared by the non-Substrate account kinds.
+
+use ripemd::{Digest, Ripemd160};
+use sp_std::vec::Vec;
+
+/// RIPEMD160(SHA256(input)), used by Bitcoin and Cosmos to derive an account
+/// from its public key.
+pub(crate) fn hash160(input: &[u8]) -> [u8; 20] {
+	Ripemd160::digest(sp_io::hashing::sha2_256(input)).into()
+}
+
+/// SHA256(SHA256(input)), used by Bitcoin for message and transaction hashes.
+pub(crate) fn double_sha256(input: &[u8]) -> [u8; 32] {
+	sp_io::hashing::sha2_256(&sp_io::hashing::sha2_256(input))
+}
+
+/// The Bitcoin variable length integer encoding.
+pub(crate) fn compact_size(value: usize) -> Vec<u8> {
+	match value as u64 {
+		0..=0xfc => [value as u8].to_vec(),
+		0xfd..=0xffff => [&[0xfd][..], &(value as u16).to_le_bytes()[..]].concat(),
+		0x10000..=0xffff_ffff => [&[0xfe][..], &(value as u32).to_le_bytes()[..]].concat(),
+		_ => [&[0xff][..], &(value as u64).to_le_bytes()[..]].concat(),
+	}
+}
+
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+fn bech32_polymod(values: impl Iterator<Item = u8>) -> u32 {
+	values.fold(1u32, |checksum, value| {
+		let top = checksum >> 25;
+		BECH32_GENERATOR
+			.iter()
+			.enumerate()
+			.filter(|(i, _)| (top >> i) & 1 == 1)
+			.fold(((checksum & 0x1ff_ffff) << 5) ^ u32::from(value), |c, (_, g)| c ^ g)
+	})
+}
+
+/// Regroup the bits of the input from 8-bit bytes to 5-bit words, padding
+/// the last word with zeros.
+pub(crate) fn to_base32(input: &[u8]) -> Vec<u8> {
+	let mut output = Vec::with_capacity((input.len() * 8 + 4) / 5);
+	let (mut accumulator, mut bits) = (0u32, 0u32);
+	for byte in input {
+		accumulator = (accumulator << 8) | u32::from(*byte);
+		bits += 8;
+		while bits >= 5 {
+			bits -= 5;
+			output.push(((accumulator >> bits) & 0x1f) as u8);
+		}
+	}
+	if bits > 0 {
+		output.push(((accumulator << (5 - bits)) & 0x1f) as u8);
+	}
+	output
+}
+
+/// Encode the provided 5-bit words as a BIP-173 bech32 string with the given
+/// human-readable part.
+///
+/// Returns `None` if the human-readable part is empty or contains characters
+/// other than lowercase ASCII letters and digits.
+pub(crate) fn bech32_encode(hrp: &[u8], data: &[u8]) -> Option<Vec<u8>> {
+	if hrp.is_empty() || !hrp.iter().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()) {
+		return None;
+	}
+	let expanded_hrp = hrp
+		.iter()
+		.map(|c| c >> 5)
+		.chain([0])
+		.chain(hrp.iter().map(|c| c & 0x1f));
+	let checksum = bech32_polymod(expanded_hrp.chain(data.iter().copied()).chain([0u8; 6])) ^ 1;
+	let checksum_words = (0..6).map(|i| ((checksum >> (5 * (5 - i))) & 0x1f) as u8);
+
+	Some(
+		hrp.iter()
+			.copied()
+			.chain([b'1'])
+			.chain(
+				data.iter()
+					.copied()
+					.chain(checksum_words)
+					.map(|word| BECH32_CHARSET[usize::from(word)]),
+			)
+			.collect(),
+	)
+}
+
+const BASE64_CHARSET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Standard (RFC 4648) base64 encoding, with padding.
+pub(crate) fn base64_encode(input: &[u8]) -> Vec<u8> {
+	let mut output = Vec::with_capacity((input.len() + 2) / 3 * 4);
+	for chunk in input.chunks(3) {
+		let bytes = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+		let group = (u32::from(bytes[0]) << 16) | (u32::from(bytes[1]) << 8) | u32::from(bytes[2]);
+		(0..4).for_each(|i| {
+			if i <= chunk.len() {
+				output.push(BASE64_CHARSET[((group >> (18 - 6 * i)) & 0x3f) as usize]);
+			} else {
+				output.push(b'=');
+			}
+		});
+	}
+	output
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn bech32_segwit_address() {
+		// Test vector from BIP-322.
+		let key_hash = hex::decode("2b05d564e6a7a33c087f16e0f730d1440123799d").unwrap();
+		let data = [&[0u8][..], &to_base32(&key_hash)[..]].concat();
+		assert_eq!(
+			bech32_encode(b"bc", &data),
+			Some(b"bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l".to_vec())
+		);
+		assert_eq!(bech32_encode(b"", &data), None);
+		assert_eq!(bech32_encode(b"Bc", &data), None);
+	}
+
+	#[test]
+	fn base64() {
+		assert_eq!(base64_encode(b""), b"".to_vec());
+		assert_eq!(base64_encode(b"f"), b"Zg==".to_vec());
+		assert_eq!(base64_encode(b"fo"), b"Zm8=".to_vec());
+		assert_eq!(base64_encode(b"foo"), b"Zm9v".to_vec());
+		assert_eq!(base64_encode(b"foobar"), b"Zm9vYmFy".to_vec());
+	}
+
+	#[test]
+	fn bitcoin_compact_size() {
+		assert_eq!(compact_size(0xfc), vec![0xfc]);
+		assert_eq!(compact_size(0xfd), vec![0xfd, 0xfd, 0x00]);
+		assert_eq!(compact_size(0x1_0000), vec![0xfe, 0x00, 0x00, 0x01, 0x00]);
+	}
+}
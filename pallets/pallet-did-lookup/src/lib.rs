@@ -26,11 +26,16 @@
 
 pub mod account;
 pub mod associate_account_request;
+pub mod bitcoin;
+pub mod cosmos;
 pub mod default_weights;
+pub mod eip712;
 pub mod linkable_account;
 pub mod migrations;
+pub mod solana;
 
 mod connection_record;
+mod encoding;
 mod signature;
 
 #[cfg(all(test, feature = "std"))]
@@ -63,7 +68,7 @@ pub mod pallet {
 	};
 	use frame_system::pallet_prelude::*;
 	use kilt_support::{
-		traits::{BalanceMigrationManager, CallSources, StorageDepositCollector},
+		traits::{BalanceMigrationManager, CallSources, DepositIndex, OnIdentityChange, StorageDepositCollector},
 		Deposit,
 	};
 	use sp_runtime::traits::{BlockNumberProvider, MaybeSerializeDeserialize};
@@ -120,6 +125,18 @@ pub mod pallet {
 
 		/// Migration manager to handle new created entries
 		type BalanceMigrationManager: BalanceMigrationManager<AccountIdOf<Self>, BalanceOf<Self>>;
+
+		/// The index of deposits by owner, updated whenever a linking deposit
+		/// is taken, changed or released.
+		type DepositIndex: DepositIndex<AccountIdOf<Self>, BalanceOf<Self>>;
+
+		/// The chain ID included in the EIP-712 domain of typed data
+		/// association requests, to prevent replaying them on other chains.
+		#[pallet::constant]
+		type EthereumChainId: Get<u64>;
+
+		/// The hook called whenever the accounts linked to a DID change.
+		type IdentityChangeHook: OnIdentityChange<DidIdentifierOf<Self>, AccountIdOf<Self>>;
 	}
 
 	#[pallet::pallet]
@@ -237,8 +254,15 @@ pub mod pallet {
 			<T as Config>::WeightInfo::associate_account_multisig_sr25519().max(
 			<T as Config>::WeightInfo::associate_account_multisig_ed25519().max(
 			<T as Config>::WeightInfo::associate_account_multisig_ecdsa().max(
-			<T as Config>::WeightInfo::associate_eth_account()
-		))))]
+			<T as Config>::WeightInfo::associate_eth_account().max(
+			<T as Config>::WeightInfo::associate_bitcoin_account().max(
+			<T as Config>::WeightInfo::associate_solana_account().max(
+			<T as Config>::WeightInfo::associate_cosmos_account().max(
+			<T as Config>::WeightInfo::associate_eth_typed_data_account()
+		)))))))
+			// The hook is called for both the previous and the new DID of the account.
+			.saturating_add(T::IdentityChangeHook::on_identity_changed_weight().saturating_mul(2))
+		)]
 		pub fn associate_account(
 			origin: OriginFor<T>,
 			req: AssociateAccountRequest,
@@ -262,10 +286,7 @@ pub mod pallet {
 				Error::<T>::InsufficientFunds
 			);
 
-			ensure!(
-				req.verify::<T::DidIdentifier, BlockNumberFor<T>>(&did_identifier, expiration),
-				Error::<T>::NotAuthorized
-			);
+			ensure!(req.verify::<T>(&did_identifier, expiration), Error::<T>::NotAuthorized);
 
 			Self::add_association(sender, did_identifier, req.get_linkable_account())?;
 
@@ -284,7 +305,7 @@ pub mod pallet {
 		/// - Writes: ConnectedDids + ConnectedAccounts
 		/// # </weight>
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::associate_sender())]
+		#[pallet::weight(<T as Config>::WeightInfo::associate_sender().saturating_add(T::IdentityChangeHook::on_identity_changed_weight().saturating_mul(2)))]
 		pub fn associate_sender(origin: OriginFor<T>) -> DispatchResult {
 			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
 
@@ -312,7 +333,7 @@ pub mod pallet {
 		/// - Writes: ConnectedDids + ConnectedAccounts
 		/// # </weight>
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as Config>::WeightInfo::remove_sender_association())]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_sender_association().saturating_add(T::IdentityChangeHook::on_identity_changed_weight()))]
 		pub fn remove_sender_association(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -331,7 +352,7 @@ pub mod pallet {
 		/// - Writes: ConnectedDids + ConnectedAccounts
 		/// # </weight>
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::remove_account_association())]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_account_association().saturating_add(T::IdentityChangeHook::on_identity_changed_weight()))]
 		pub fn remove_account_association(origin: OriginFor<T>, account: LinkableAccountId) -> DispatchResult {
 			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
 
@@ -353,7 +374,7 @@ pub mod pallet {
 		/// - Writes: ConnectedDids
 		/// # </weight>
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::remove_sender_association())]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_sender_association().saturating_add(T::IdentityChangeHook::on_identity_changed_weight()))]
 		pub fn reclaim_deposit(origin: OriginFor<T>, account: LinkableAccountId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -422,16 +443,21 @@ pub mod pallet {
 			<T as Config>::BalanceMigrationManager::exclude_key_from_migration(&ConnectedDids::<T>::hashed_key_for(
 				&account,
 			));
+			let new_deposit = record.deposit.clone();
 
 			ConnectedDids::<T>::mutate(&account, |did_entry| -> DispatchResult {
 				if let Some(old_connection) = did_entry.replace(record) {
 					ConnectedAccounts::<T>::remove(&old_connection.did, &account);
+					T::IdentityChangeHook::on_identity_changed(&old_connection.did);
 					Self::deposit_event(Event::<T>::AssociationRemoved(account.clone(), old_connection.did));
+					LinkableAccountDepositCollector::<T>::unindex_deposit(&account, &old_connection.deposit.owner)?;
 					LinkableAccountDepositCollector::<T>::free_deposit(old_connection.deposit)?;
 				}
 				Ok(())
 			})?;
+			LinkableAccountDepositCollector::<T>::index_deposit(&account, &new_deposit)?;
 			ConnectedAccounts::<T>::insert(&did_identifier, &account, ());
+			T::IdentityChangeHook::on_identity_changed(&did_identifier);
 			Self::deposit_event(Event::AssociationEstablished(account, did_identifier));
 
 			Ok(())
@@ -443,6 +469,7 @@ pub mod pallet {
 					&ConnectedDids::<T>::hashed_key_for(&account),
 				);
 
+				LinkableAccountDepositCollector::<T>::unindex_deposit(&account, &connection.deposit.owner)?;
 				if is_key_migrated {
 					LinkableAccountDepositCollector::<T>::free_deposit(connection.deposit)?;
 				} else {
@@ -453,6 +480,7 @@ pub mod pallet {
 				}
 
 				ConnectedAccounts::<T>::remove(&connection.did, &account);
+				T::IdentityChangeHook::on_identity_changed(&connection.did);
 				Self::deposit_event(Event::AssociationRemoved(account, connection.did));
 				Ok(())
 			} else {
@@ -466,6 +494,7 @@ pub mod pallet {
 		for LinkableAccountDepositCollector<T>
 	{
 		type Currency = T::Currency;
+		type DepositIndex = T::DepositIndex;
 		type Reason = HoldReason;
 
 		fn reason() -> Self::Reason {
@@ -21,7 +21,7 @@ use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sp_runtime::AccountId32;
 
-use crate::account::AccountId20;
+use crate::{account::AccountId20, bitcoin::BitcoinAccountId, cosmos::CosmosAccountId, solana::SolanaAccountId};
 
 #[derive(
 	Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, MaxEncodedLen, TypeInfo, Serialize, Deserialize,
@@ -29,6 +29,9 @@ use crate::account::AccountId20;
 pub enum LinkableAccountId {
 	AccountId20(AccountId20),
 	AccountId32(AccountId32),
+	Bitcoin(BitcoinAccountId),
+	Solana(SolanaAccountId),
+	Cosmos(CosmosAccountId),
 }
 
 impl From<AccountId20> for LinkableAccountId {
@@ -43,6 +46,24 @@ impl From<AccountId32> for LinkableAccountId {
 	}
 }
 
+impl From<BitcoinAccountId> for LinkableAccountId {
+	fn from(account_id: BitcoinAccountId) -> Self {
+		Self::Bitcoin(account_id)
+	}
+}
+
+impl From<SolanaAccountId> for LinkableAccountId {
+	fn from(account_id: SolanaAccountId) -> Self {
+		Self::Solana(account_id)
+	}
+}
+
+impl From<CosmosAccountId> for LinkableAccountId {
+	fn from(account_id: CosmosAccountId) -> Self {
+		Self::Cosmos(account_id)
+	}
+}
+
 impl From<[u8; 20]> for LinkableAccountId {
 	fn from(account_id: [u8; 20]) -> Self {
 		Self::AccountId20(account_id.into())
@@ -55,11 +76,19 @@ impl From<[u8; 32]> for LinkableAccountId {
 	}
 }
 
+/// The raw bytes of the account, without the tag of its type.
+///
+/// The bytes are not unique across account types, e.g., Ethereum, Bitcoin and
+/// Cosmos accounts are all 20 bytes long, so they must not be used to identify
+/// an account. Use the SCALE encoding of the [`LinkableAccountId`] instead.
 impl AsRef<[u8]> for LinkableAccountId {
 	fn as_ref(&self) -> &[u8] {
 		match self {
 			LinkableAccountId::AccountId20(value) => &value.0,
 			LinkableAccountId::AccountId32(value) => value.as_ref(),
+			LinkableAccountId::Bitcoin(value) => &value.0,
+			LinkableAccountId::Solana(value) => &value.0,
+			LinkableAccountId::Cosmos(value) => &value.0,
 		}
 	}
 }
@@ -70,6 +99,9 @@ impl std::fmt::Display for LinkableAccountId {
 		match self {
 			Self::AccountId20(account_id) => write!(f, "{}", account_id),
 			Self::AccountId32(account_id) => write!(f, "{}", account_id),
+			Self::Bitcoin(account_id) => write!(f, "{}", account_id),
+			Self::Solana(account_id) => write!(f, "{}", account_id),
+			Self::Cosmos(account_id) => write!(f, "{}", account_id),
 		}
 	}
 }
@@ -20,7 +20,7 @@ use frame_support::{
 	pallet_prelude::DispatchResult,
 	traits::{fungible::Inspect, ReservableCurrency},
 };
-use kilt_support::migration::switch_reserved_to_hold;
+use kilt_support::{migration::switch_reserved_to_hold, traits::DepositIndex};
 
 use crate::{linkable_account::LinkableAccountId, AccountIdOf, Config, ConnectedDids, CurrencyOf, Error, HoldReason};
 
@@ -37,6 +37,18 @@ where
 	)
 }
 
+/// Add the deposit of the account link behind the provided key to the deposit
+/// index. Used to index deposits taken before the deposit index existed.
+pub fn index_deposit_for_did_lookup<T: Config>(key: &LinkableAccountId) -> DispatchResult {
+	let details = ConnectedDids::<T>::get(key).ok_or(Error::<T>::NotFound)?;
+	<T as Config>::DepositIndex::on_deposit_held(
+		&details.deposit.owner,
+		&ConnectedDids::<T>::hashed_key_for(key),
+		&details.deposit.amount,
+	);
+	Ok(())
+}
+
 #[cfg(test)]
 pub mod test {
 	use frame_support::{
@@ -107,10 +107,12 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
 	pub const DidLookupDeposit: Balance = 10;
+	pub storage EthereumChainId: u64 = 1;
 }
 
 impl pallet_did_lookup::Config for Test {
 	type BalanceMigrationManager = ();
+	type DepositIndex = ();
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type Currency = Balances;
@@ -119,6 +121,8 @@ impl pallet_did_lookup::Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type DidIdentifier = SubjectId;
 	type WeightInfo = ();
+	type EthereumChainId = EthereumChainId;
+	type IdentityChangeHook = ();
 }
 
 impl mock_origin::Config for Test {
@@ -19,13 +19,31 @@
 use parity_scale_codec::alloc::string::ToString;
 use sp_std::vec::Vec;
 
+use crate::encoding::{base64_encode, compact_size};
+
 // According to https://github.com/polkadot-js/common/blob/5d5c7e4c0ace06e3301ccadfd3c3351955f1e251/packages/util/src/u8a/wrap.ts#L13
 const PAYLOAD_BYTES_WRAPPER_PREFIX: &[u8; 7] = b"<Bytes>";
 const PAYLOAD_BYTES_WRAPPER_POSTFIX: &[u8; 8] = b"</Bytes>";
 const ETHEREUM_SIGNATURE_PREFIX: &[u8; 26] = b"\x19Ethereum Signed Message:\n";
-pub(crate) enum WrapType {
+// According to https://github.com/bitcoin/bitcoin/blob/v26.0/src/common/signmessage.cpp#L17
+const BITCOIN_SIGNATURE_PREFIX: &[u8; 25] = b"\x18Bitcoin Signed Message:\n";
+// According to https://github.com/solana-labs/solana/blob/v1.17.0/sdk/src/offchain_message.rs
+// (version 0, restricted ASCII format).
+const SOLANA_OFFCHAIN_MESSAGE_PREFIX: &[u8; 18] = b"\xffsolana offchain\x00\x00";
+// According to https://github.com/cosmos/cosmos-sdk/blob/v0.50.0/docs/architecture/adr-036-arbitrary-signature.md
+const COSMOS_SIGN_DOC_PREFIX: &[u8] =
+	br#"{"account_number":"0","chain_id":"","fee":{"amount":[],"gas":"0"},"memo":"","msgs":[{"type":"sign/MsgSignData","value":{"data":""#;
+const COSMOS_SIGN_DOC_SIGNER: &[u8] = br#"","signer":""#;
+const COSMOS_SIGN_DOC_POSTFIX: &[u8] = br#""}}],"sequence":"0"}"#;
+
+pub(crate) enum WrapType<'a> {
 	Substrate,
 	Ethereum,
+	Bitcoin,
+	Solana,
+	/// The ADR-036 sign document also contains the bech32 address of the
+	/// signer.
+	Cosmos(&'a [u8]),
 }
 
 pub(crate) fn get_wrapped_payload(payload: &[u8], wrap_type: WrapType) -> Vec<u8> {
@@ -43,5 +61,27 @@ pub(crate) fn get_wrapped_payload(payload: &[u8], wrap_type: WrapType) -> Vec<u8
 			.chain(payload.iter())
 			.copied()
 			.collect(),
+		WrapType::Bitcoin => BITCOIN_SIGNATURE_PREFIX
+			.iter()
+			// btc wrapping also contains the length of the payload, as a variable length integer
+			.chain(compact_size(payload.len()).iter())
+			.chain(payload.iter())
+			.copied()
+			.collect(),
+		WrapType::Solana => SOLANA_OFFCHAIN_MESSAGE_PREFIX
+			.iter()
+			// the challenge is bounded, so its length always fits in the two bytes of the header
+			.chain((payload.len() as u16).to_le_bytes().iter())
+			.chain(payload.iter())
+			.copied()
+			.collect(),
+		WrapType::Cosmos(signer) => COSMOS_SIGN_DOC_PREFIX
+			.iter()
+			.chain(base64_encode(payload).iter())
+			.chain(COSMOS_SIGN_DOC_SIGNER.iter())
+			.chain(signer.iter())
+			.chain(COSMOS_SIGN_DOC_POSTFIX.iter())
+			.copied()
+			.collect(),
 	}
 }
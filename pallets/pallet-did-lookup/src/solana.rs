@@ -0,0 +1,125 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! The Solana account and signature implementation.
+//!
+//! Signatures are verified over the Solana off-chain message format.
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_core::ed25519;
+use sp_runtime::RuntimeDebug;
+
+use crate::signature::{get_wrapped_payload, WrapType};
+
+/// The AccountId for a Solana account.
+/// It is the 32-byte ed25519 public key of the account.
+#[derive(
+	Eq,
+	PartialEq,
+	Copy,
+	Clone,
+	Encode,
+	Decode,
+	TypeInfo,
+	MaxEncodedLen,
+	Default,
+	PartialOrd,
+	Ord,
+	RuntimeDebug,
+	Serialize,
+	Deserialize,
+)]
+pub struct SolanaAccountId(pub [u8; 32]);
+
+/// Displays the account as a base58-encoded address.
+#[cfg(feature = "std")]
+impl std::fmt::Display for SolanaAccountId {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		use base58::ToBase58;
+
+		write!(f, "{}", self.0.to_base58())
+	}
+}
+
+impl From<[u8; 32]> for SolanaAccountId {
+	fn from(bytes: [u8; 32]) -> Self {
+		Self(bytes)
+	}
+}
+
+impl From<SolanaAccountId> for [u8; 32] {
+	fn from(id: SolanaAccountId) -> Self {
+		id.0
+	}
+}
+
+impl From<ed25519::Public> for SolanaAccountId {
+	fn from(public_key: ed25519::Public) -> Self {
+		Self(public_key.0)
+	}
+}
+
+#[derive(Eq, PartialEq, Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct SolanaSignature(ed25519::Signature);
+
+impl From<ed25519::Signature> for SolanaSignature {
+	fn from(x: ed25519::Signature) -> Self {
+		SolanaSignature(x)
+	}
+}
+
+impl SolanaSignature {
+	/// Verify that the signature was generated by the provided account over
+	/// the provided message, wrapped as a Solana off-chain message.
+	pub fn verify(&self, message: &[u8], signer: &SolanaAccountId) -> bool {
+		sp_io::crypto::ed25519_verify(
+			&self.0,
+			&get_wrapped_payload(message, WrapType::Solana),
+			&ed25519::Public::from_raw(signer.0),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::Pair;
+
+	#[test]
+	fn test_account_display() {
+		assert_eq!(
+			SolanaAccountId([0u8; 32]).to_string(),
+			"11111111111111111111111111111111"
+		);
+	}
+
+	#[test]
+	fn test_signature_over_offchain_message() {
+		let pair = ed25519::Pair::from_seed(&[1u8; 32]);
+		let signer = SolanaAccountId::from(pair.public());
+		let message = b"Hello Solana";
+
+		let signature = SolanaSignature::from(pair.sign(&get_wrapped_payload(message, WrapType::Solana)));
+		assert!(signature.verify(message, &signer));
+
+		let unwrapped_signature = SolanaSignature::from(pair.sign(message));
+		assert!(!unwrapped_signature.verify(message, &signer));
+	}
+}
@@ -22,17 +22,21 @@ use kilt_support::{mock::mock_origin, Deposit};
 use parity_scale_codec::Encode;
 use sha3::{Digest, Keccak256};
 use sp_runtime::{
-	app_crypto::{ecdsa, sr25519, Pair},
+	app_crypto::{ecdsa, ed25519, sr25519, Pair},
 	traits::IdentifyAccount,
 	MultiSignature, MultiSigner,
 };
 
 use crate::{
 	account::{AccountId20, EthereumSignature},
-	associate_account_request::{get_challenge, AssociateAccountRequest},
+	associate_account_request::{get_challenge, get_typed_data_payload, AssociateAccountRequest},
+	bitcoin::{bip322_sighash, BitcoinAccountId, BitcoinSignature},
+	cosmos::{CosmosAccountId, CosmosSignature},
+	encoding::{double_sha256, hash160},
 	linkable_account::LinkableAccountId,
 	mock::*,
 	signature::get_wrapped_payload,
+	solana::{SolanaAccountId, SolanaSignature},
 	ConnectedAccounts, ConnectedDids, ConnectionRecord, Error, HoldReason,
 };
 
@@ -230,6 +234,223 @@ fn test_add_eth_association() {
 		});
 }
 
+#[test]
+fn test_add_eth_typed_data_association() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let eth_pair = ecdsa::Pair::generate().0;
+			let eth_account = AccountId20(eth_pair.public().to_eth_address().unwrap());
+
+			// A personal_sign signature is not a valid typed data signature.
+			let personal_sig = eth_pair.sign_prehashed(
+				&Keccak256::digest(get_wrapped_payload(
+					get_challenge(&DID_00, expire_at).as_bytes(),
+					crate::signature::WrapType::Ethereum,
+				))
+				.try_into()
+				.unwrap(),
+			);
+			assert_noop!(
+				DidLookup::associate_account(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					AssociateAccountRequest::EthereumTypedData(eth_account, EthereumSignature::from(personal_sig)),
+					expire_at,
+				),
+				Error::<Test>::NotAuthorized
+			);
+
+			let sig = eth_pair.sign_prehashed(
+				&Keccak256::digest(get_typed_data_payload::<Test>(&DID_00, expire_at))
+					.try_into()
+					.unwrap(),
+			);
+			assert_ok!(DidLookup::associate_account(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				AssociateAccountRequest::EthereumTypedData(eth_account, EthereumSignature::from(sig)),
+				expire_at,
+			));
+			assert!(ConnectedAccounts::<Test>::get(DID_00, LinkableAccountId::from(eth_account)).is_some());
+		});
+}
+
+#[test]
+fn test_add_eth_typed_data_association_other_chain() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let eth_pair = ecdsa::Pair::generate().0;
+			let eth_account = AccountId20(eth_pair.public().to_eth_address().unwrap());
+			let sig = eth_pair.sign_prehashed(
+				&Keccak256::digest(get_typed_data_payload::<Test>(&DID_00, expire_at))
+					.try_into()
+					.unwrap(),
+			);
+
+			// The typed data is bound to the chain, so it cannot be replayed on another one.
+			EthereumChainId::set(&2);
+			assert_noop!(
+				DidLookup::associate_account(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					AssociateAccountRequest::EthereumTypedData(eth_account, EthereumSignature::from(sig)),
+					expire_at,
+				),
+				Error::<Test>::NotAuthorized
+			);
+
+			let sig = eth_pair.sign_prehashed(
+				&Keccak256::digest(get_typed_data_payload::<Test>(&DID_00, expire_at))
+					.try_into()
+					.unwrap(),
+			);
+			assert_ok!(DidLookup::associate_account(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				AssociateAccountRequest::EthereumTypedData(eth_account, EthereumSignature::from(sig)),
+				expire_at,
+			));
+			assert!(ConnectedAccounts::<Test>::get(DID_00, LinkableAccountId::from(eth_account)).is_some());
+		});
+}
+
+#[test]
+fn test_add_bitcoin_legacy_association() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let btc_pair = ecdsa::Pair::generate().0;
+			let btc_account = BitcoinAccountId(hash160(&btc_pair.public().0));
+
+			let wrapped_payload = get_wrapped_payload(
+				get_challenge(&DID_00, expire_at).as_bytes(),
+				crate::signature::WrapType::Bitcoin,
+			);
+			let sig = btc_pair.sign_prehashed(&double_sha256(&wrapped_payload));
+			// Header byte for a P2PKH address with a compressed public key.
+			let mut legacy_sig = [0u8; 65];
+			legacy_sig[0] = 31 + sig.0[64];
+			legacy_sig[1..].copy_from_slice(&sig.0[..64]);
+
+			assert_ok!(DidLookup::associate_account(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				AssociateAccountRequest::Bitcoin(btc_account, BitcoinSignature::Legacy(legacy_sig)),
+				expire_at,
+			));
+			assert_eq!(
+				ConnectedDids::<Test>::get(LinkableAccountId::from(btc_account)).map(|record| record.did),
+				Some(DID_00)
+			);
+			assert!(ConnectedAccounts::<Test>::get(DID_00, LinkableAccountId::from(btc_account)).is_some());
+		});
+}
+
+#[test]
+fn test_add_bitcoin_bip322_association() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let btc_pair = ecdsa::Pair::generate().0;
+			let btc_account = BitcoinAccountId(hash160(&btc_pair.public().0));
+
+			let sig = btc_pair.sign_prehashed(&bip322_sighash(
+				get_challenge(&DID_00, expire_at).as_bytes(),
+				&btc_account,
+			));
+			let der_sig = libsecp256k1::Signature::parse_standard_slice(&sig.0[..64])
+				.unwrap()
+				.serialize_der();
+			let bip322_sig = BitcoinSignature::Bip322 {
+				signature: [der_sig.as_ref(), &[0x01]].concat().try_into().unwrap(),
+				public_key: btc_pair.public().0,
+			};
+
+			// The signature is only valid for the account it commits to.
+			assert_noop!(
+				DidLookup::associate_account(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					AssociateAccountRequest::Bitcoin(BitcoinAccountId([0u8; 20]), bip322_sig.clone()),
+					expire_at,
+				),
+				Error::<Test>::NotAuthorized
+			);
+			assert_ok!(DidLookup::associate_account(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				AssociateAccountRequest::Bitcoin(btc_account, bip322_sig),
+				expire_at,
+			));
+			assert!(ConnectedAccounts::<Test>::get(DID_00, LinkableAccountId::from(btc_account)).is_some());
+		});
+}
+
+#[test]
+fn test_add_solana_association() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let sol_pair = ed25519::Pair::generate().0;
+			let sol_account = SolanaAccountId::from(sol_pair.public());
+
+			// Signing the challenge without the off-chain message header is rejected.
+			let unwrapped_sig = sol_pair.sign(get_challenge(&DID_00, expire_at).as_bytes());
+			assert_noop!(
+				DidLookup::associate_account(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					AssociateAccountRequest::Solana(sol_account, SolanaSignature::from(unwrapped_sig)),
+					expire_at,
+				),
+				Error::<Test>::NotAuthorized
+			);
+
+			let sig = sol_pair.sign(&get_wrapped_payload(
+				get_challenge(&DID_00, expire_at).as_bytes(),
+				crate::signature::WrapType::Solana,
+			));
+			assert_ok!(DidLookup::associate_account(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				AssociateAccountRequest::Solana(sol_account, SolanaSignature::from(sig)),
+				expire_at,
+			));
+			assert!(ConnectedAccounts::<Test>::get(DID_00, LinkableAccountId::from(sol_account)).is_some());
+		});
+}
+
+#[test]
+fn test_add_cosmos_association() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let cosmos_pair = ecdsa::Pair::generate().0;
+			let cosmos_account = CosmosAccountId(hash160(&cosmos_pair.public().0));
+			let address = cosmos_account.to_address(b"cosmos").unwrap();
+
+			let sig = cosmos_pair.sign_prehashed(&sp_io::hashing::sha2_256(&get_wrapped_payload(
+				get_challenge(&DID_00, expire_at).as_bytes(),
+				crate::signature::WrapType::Cosmos(&address),
+			)));
+			let mut signature = [0u8; 64];
+			signature.copy_from_slice(&sig.0[..64]);
+
+			assert_ok!(DidLookup::associate_account(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				AssociateAccountRequest::Cosmos(
+					cosmos_account,
+					CosmosSignature {
+						prefix: b"cosmos".to_vec().try_into().unwrap(),
+						public_key: cosmos_pair.public().0,
+						signature,
+					}
+				),
+				expire_at,
+			));
+			assert!(ConnectedAccounts::<Test>::get(DID_00, LinkableAccountId::from(cosmos_account)).is_some());
+		});
+}
+
 #[test]
 fn test_add_association_account_invalid_signature() {
 	ExtBuilder::default()
@@ -0,0 +1,147 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::ensure;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Saturating, RuntimeDebug};
+use uid_core::DidVerificationKeyRelationship;
+
+use crate::traits::RevealedIdentityDetails;
+
+/// The target an access rule applies to.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AccessTarget {
+	/// All the calls of the pallet with the given index.
+	Pallet(u8),
+	/// The call with the given index of the pallet with the given index.
+	Call(u8, u8),
+}
+
+impl AccessTarget {
+	/// Returns the call-specific target for the provided runtime call, derived
+	/// from its SCALE encoding, which starts with the index of the call's
+	/// pallet followed by the index of the call within the pallet.
+	pub fn from_call<Call: Encode>(call: &Call) -> Option<Self> {
+		call.using_encoded(|encoded_call| match encoded_call {
+			[pallet_index, call_index, ..] => Some(Self::Call(*pallet_index, *call_index)),
+			_ => None,
+		})
+	}
+
+	/// Returns the pallet-wide target a call-specific target falls back to,
+	/// if any.
+	pub fn fallback(&self) -> Option<Self> {
+		match self {
+			Self::Pallet(_) => None,
+			Self::Call(pallet_index, _) => Some(Self::Pallet(*pallet_index)),
+		}
+	}
+}
+
+/// The requirements the details revealed in a DIP proof must satisfy for a
+/// DIP origin to be accepted.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AccessRule<ProviderBlockNumber> {
+	/// The relationship the key that signed the DIP operation must have with
+	/// the subject's DID, if any.
+	pub key_relationship: Option<DidVerificationKeyRelationship>,
+	/// The minimum number of provider blocks that must have passed since the
+	/// oldest DID key revealed in the proof was added to the subject's DID.
+	pub min_did_age: Option<ProviderBlockNumber>,
+	/// Whether the proof must reveal a web3name linked to the subject.
+	pub requires_web3_name: bool,
+	/// Whether the proof must reveal an Ethereum account linked to the
+	/// subject.
+	pub requires_ethereum_account: bool,
+}
+
+/// The reasons a DIP origin can fail to satisfy an [`AccessRule`].
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(test, derive(enum_iterator::Sequence))]
+pub enum AccessRuleError {
+	/// No access rule is set for the call or its pallet, and DIP origins are
+	/// not accepted for targets without a rule.
+	RuleNotFound,
+	/// None of the keys that signed the DIP operation have the required
+	/// relationship.
+	KeyRelationshipNotFound,
+	/// The subject's DID is not old enough, or no DID key has been revealed to
+	/// prove its age.
+	DidTooRecent,
+	/// No web3name has been revealed.
+	Web3NameNotFound,
+	/// No Ethereum account has been revealed.
+	EthereumAccountNotFound,
+}
+
+impl From<AccessRuleError> for u8 {
+	fn from(value: AccessRuleError) -> Self {
+		match value {
+			// DO NOT USE 0
+			// Errors of different sub-parts are separated by a `u8::MAX`.
+			// A value of 0 would make it confusing whether it's the previous sub-part error (u8::MAX)
+			// or the new sub-part error (u8::MAX + 0).
+			AccessRuleError::RuleNotFound => 1,
+			AccessRuleError::KeyRelationshipNotFound => 2,
+			AccessRuleError::DidTooRecent => 3,
+			AccessRuleError::Web3NameNotFound => 4,
+			AccessRuleError::EthereumAccountNotFound => 5,
+		}
+	}
+}
+
+impl<ProviderBlockNumber> AccessRule<ProviderBlockNumber>
+where
+	ProviderBlockNumber: Saturating + PartialOrd + Copy,
+{
+	/// Check whether the provided revealed details satisfy all the
+	/// requirements of this rule, given the current block number of the
+	/// provider chain.
+	pub fn check<Details>(
+		&self,
+		details: &Details,
+		current_provider_block: ProviderBlockNumber,
+	) -> Result<(), AccessRuleError>
+	where
+		Details: RevealedIdentityDetails<BlockNumber = ProviderBlockNumber>,
+	{
+		if let Some(key_relationship) = &self.key_relationship {
+			ensure!(
+				details.has_signing_key_with_relationship(key_relationship),
+				AccessRuleError::KeyRelationshipNotFound
+			);
+		}
+		if let Some(min_did_age) = self.min_did_age {
+			let oldest_key_block = details.oldest_key_block_number().ok_or(AccessRuleError::DidTooRecent)?;
+			ensure!(
+				current_provider_block.saturating_sub(oldest_key_block) >= min_did_age,
+				AccessRuleError::DidTooRecent
+			);
+		}
+		ensure!(
+			!self.requires_web3_name || details.has_web3_name(),
+			AccessRuleError::Web3NameNotFound
+		);
+		ensure!(
+			!self.requires_ethereum_account || details.has_linked_ethereum_account(),
+			AccessRuleError::EthereumAccountNotFound
+		);
+		Ok(())
+	}
+}
@@ -0,0 +1,79 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use crate::{Config, Pallet};
+use frame_benchmarking::v2::*;
+
+#[benchmarks]
+mod benchmarks {
+	use frame_support::traits::EnsureOrigin;
+	use uid_core::DidVerificationKeyRelationship;
+
+	use crate::{AccessRuleOf, AccessRules, AccessTarget};
+
+	use super::*;
+
+	const TARGET: AccessTarget = AccessTarget::Call(u8::MAX, u8::MAX);
+
+	fn worst_case_rule<T: Config>() -> AccessRuleOf<T> {
+		AccessRuleOf::<T> {
+			key_relationship: Some(DidVerificationKeyRelationship::Authentication),
+			min_did_age: Some(u32::MAX.into()),
+			requires_web3_name: true,
+			requires_ethereum_account: true,
+		}
+	}
+
+	#[benchmark]
+	fn set_access_rule() {
+		let origin = T::AccessRuleOrigin::try_successful_origin().expect("Should not fail to generate origin.");
+		let rule = worst_case_rule::<T>();
+
+		#[extrinsic_call]
+		Pallet::<T>::set_access_rule(
+			origin as <T as frame_system::Config>::RuntimeOrigin,
+			TARGET,
+			rule.clone(),
+		);
+
+		assert_eq!(AccessRules::<T>::get(TARGET), Some(rule));
+	}
+
+	#[benchmark]
+	fn remove_access_rule() {
+		let origin = T::AccessRuleOrigin::try_successful_origin().expect("Should not fail to generate origin.");
+		AccessRules::<T>::insert(TARGET, worst_case_rule::<T>());
+
+		#[extrinsic_call]
+		Pallet::<T>::remove_access_rule(origin as <T as frame_system::Config>::RuntimeOrigin, TARGET);
+
+		assert!(AccessRules::<T>::get(TARGET).is_none());
+	}
+
+	#[cfg(test)]
+	mod benchmarks_tests {
+		use crate::Pallet;
+		use frame_benchmarking::impl_benchmark_test_suite;
+
+		impl_benchmark_test_suite!(
+			Pallet,
+			crate::mock::ExtBuilder::default().build(),
+			crate::mock::TestRuntime,
+		);
+	}
+}
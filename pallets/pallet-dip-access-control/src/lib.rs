@@ -0,0 +1,172 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![doc = include_str!("../README.md")]
+
+pub mod access_rule;
+pub mod traits;
+
+mod default_weights;
+mod verifier;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub use crate::{
+	access_rule::{AccessRule, AccessRuleError, AccessTarget},
+	default_weights::WeightInfo,
+	pallet::*,
+	traits::RevealedIdentityDetails,
+	verifier::*,
+};
+
+const LOG_TARGET: &str = "dip::consumer::pallet_dip_access_control";
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AtLeast32BitUnsigned, BlockNumberProvider};
+
+	use crate::access_rule::AccessRuleError;
+
+	pub type ProviderBlockNumberOf<T> = <T as Config>::ProviderBlockNumber;
+	pub type AccessRuleOf<T> = AccessRule<ProviderBlockNumberOf<T>>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::config]
+	pub trait Config: pallet_dip_consumer::Config {
+		/// The origin allowed to set and remove access rules.
+		type AccessRuleOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// Whether DIP origins are accepted for calls for which no access rule
+		/// is set, neither for the call nor for its pallet. Should be `false`
+		/// unless all calls accepting a DIP origin are meant to be dispatched
+		/// by any DIP subject.
+		type AllowWithoutRule: Get<bool>;
+		/// The block number type of the provider chain.
+		type ProviderBlockNumber: Parameter + MaxEncodedLen + AtLeast32BitUnsigned + Copy;
+		/// The source of the current block number of the provider chain, used
+		/// to compute the age of a subject's DID.
+		type ProviderBlockNumberProvider: BlockNumberProvider<BlockNumber = Self::ProviderBlockNumber>;
+		/// The overarching runtime event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Maps from a pallet or a call to the requirements a DIP origin must
+	/// satisfy to be accepted by the [`AccessControlledProofVerifier`].
+	#[pallet::storage]
+	#[pallet::getter(fn access_rule)]
+	pub(crate) type AccessRules<T> = StorageMap<_, Twox64Concat, AccessTarget, AccessRuleOf<T>>;
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A new access rule has been set for the target, replacing any
+		/// previous one.
+		AccessRuleSet {
+			target: AccessTarget,
+			rule: AccessRuleOf<T>,
+		},
+		/// The access rule for the target has been removed.
+		AccessRuleRemoved { target: AccessTarget },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No access rule is set for the specified target.
+		NotFound,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the access rule for a pallet or a call, replacing any previous
+		/// one.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_access_rule())]
+		pub fn set_access_rule(origin: OriginFor<T>, target: AccessTarget, rule: AccessRuleOf<T>) -> DispatchResult {
+			T::AccessRuleOrigin::ensure_origin(origin)?;
+
+			AccessRules::<T>::insert(target, rule.clone());
+
+			Self::deposit_event(Event::<T>::AccessRuleSet { target, rule });
+			Ok(())
+		}
+
+		/// Remove the access rule for a pallet or a call.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_access_rule())]
+		pub fn remove_access_rule(origin: OriginFor<T>, target: AccessTarget) -> DispatchResult {
+			T::AccessRuleOrigin::ensure_origin(origin)?;
+
+			AccessRules::<T>::take(target).ok_or(Error::<T>::NotFound)?;
+
+			Self::deposit_event(Event::<T>::AccessRuleRemoved { target });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Check the provided revealed details against the rule for the
+		/// target, or against the rule for the whole pallet if the target is a
+		/// call without a specific rule. If no rule is found, details are
+		/// accepted only if `AllowWithoutRule` is `true`.
+		pub fn check_access<Details>(target: &AccessTarget, details: &Details) -> Result<(), AccessRuleError>
+		where
+			Details: RevealedIdentityDetails<BlockNumber = ProviderBlockNumberOf<T>>,
+		{
+			let Some(rule) =
+				AccessRules::<T>::get(target).or_else(|| target.fallback().and_then(AccessRules::<T>::get))
+			else {
+				if T::AllowWithoutRule::get() {
+					return Ok(());
+				}
+				log::info!(
+					target: LOG_TARGET,
+					"No access rule found for target {:#?}, and DIP origins are not accepted without one.",
+					target
+				);
+				return Err(AccessRuleError::RuleNotFound);
+			};
+			rule.check(details, T::ProviderBlockNumberProvider::current_block_number())
+				.map_err(|error| {
+					log::info!(
+						target: LOG_TARGET,
+						"DIP origin does not satisfy the access rule for target {:#?} with error {:#?}",
+						target,
+						error
+					);
+					error
+				})
+		}
+	}
+}
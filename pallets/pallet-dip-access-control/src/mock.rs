@@ -0,0 +1,185 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	construct_runtime, parameter_types,
+	sp_runtime::{
+		testing::H256,
+		traits::{BlakeTwo256, IdentityLookup},
+		AccountId32,
+	},
+	traits::{ConstU16, ConstU32, ConstU64, Everything},
+};
+use frame_system::{mocking::MockBlock, EnsureRoot, EnsureSigned};
+use pallet_dip_consumer::{traits::IdentityProofVerifier, RuntimeCallOf};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use uid_core::DidVerificationKeyRelationship;
+
+use crate::{AccessControlledProofVerifier, AccessRuleOf, AccessRules, AccessTarget, RevealedIdentityDetails};
+
+construct_runtime!(
+	pub struct TestRuntime {
+		System: frame_system,
+		DipConsumer: pallet_dip_consumer,
+		DipAccessControl: crate,
+	}
+);
+
+impl frame_system::Config for TestRuntime {
+	type AccountData = ();
+	type AccountId = AccountId32;
+	type BaseCallFilter = Everything;
+	type Block = MockBlock<TestRuntime>;
+	type BlockHashCount = ConstU64<256>;
+	type BlockLength = ();
+	type BlockWeights = ();
+	type DbWeight = ();
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type MaxConsumers = ConstU32<16>;
+	type Nonce = u64;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type PalletInfo = PalletInfo;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeTask = ();
+	type SS58Prefix = ConstU16<1>;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+/// Revealed identity details whose content is fully controlled by the tests.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct MockIdentityDetails {
+	pub signing_key_relationship: Option<DidVerificationKeyRelationship>,
+	pub oldest_key_block_number: Option<u64>,
+	pub web3_name: bool,
+	pub ethereum_account: bool,
+}
+
+impl RevealedIdentityDetails for MockIdentityDetails {
+	type BlockNumber = u64;
+
+	fn has_signing_key_with_relationship(&self, relationship: &DidVerificationKeyRelationship) -> bool {
+		self.signing_key_relationship.as_ref() == Some(relationship)
+	}
+
+	fn oldest_key_block_number(&self) -> Option<Self::BlockNumber> {
+		self.oldest_key_block_number
+	}
+
+	fn has_web3_name(&self) -> bool {
+		self.web3_name
+	}
+
+	fn has_linked_ethereum_account(&self) -> bool {
+		self.ethereum_account
+	}
+}
+
+// Returns the provided details as the verification result.
+pub struct MockProofVerifier;
+impl IdentityProofVerifier<TestRuntime> for MockProofVerifier {
+	type Error = u16;
+	type Proof = MockIdentityDetails;
+	type VerificationResult = MockIdentityDetails;
+
+	fn verify_proof_for_call_against_details(
+		_call: &RuntimeCallOf<TestRuntime>,
+		_subject: &<TestRuntime as pallet_dip_consumer::Config>::Identifier,
+		_submitter: &<TestRuntime as frame_system::Config>::AccountId,
+		_identity_details: &mut Option<<TestRuntime as pallet_dip_consumer::Config>::LocalIdentityInfo>,
+		proof: Self::Proof,
+	) -> Result<Self::VerificationResult, Self::Error> {
+		Ok(proof)
+	}
+}
+
+impl pallet_dip_consumer::Config for TestRuntime {
+	type DipCallOriginFilter = Everything;
+	type DispatchOriginCheck = EnsureSigned<Self::Identifier>;
+	type Identifier = AccountId32;
+	type LocalIdentityInfo = ();
+	type ProofVerifier = AccessControlledProofVerifier<MockProofVerifier>;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeOrigin = RuntimeOrigin;
+	type WeightInfo = ();
+}
+
+impl crate::Config for TestRuntime {
+	type AccessRuleOrigin = EnsureRoot<AccountId32>;
+	type AllowWithoutRule = AllowWithoutRule;
+	type ProviderBlockNumber = u64;
+	// The consumer block number is used as the provider block number in tests.
+	type ProviderBlockNumberProvider = System;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+}
+
+pub(crate) const ACCOUNT_ID: AccountId32 = AccountId32::new([100u8; 32]);
+pub(crate) const SUBJECT: AccountId32 = AccountId32::new([200u8; 32]);
+// The index of the `System` pallet and of its `remark` call.
+pub(crate) const PALLET_INDEX: u8 = 0;
+pub(crate) const CALL_INDEX: u8 = 0;
+
+parameter_types! {
+	pub storage AllowWithoutRule: bool = false;
+}
+
+// A call with target `AccessTarget::Call(PALLET_INDEX, CALL_INDEX)`.
+pub(crate) fn call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+// A call of the same pallet as `call()`, with a different call index.
+pub(crate) fn other_call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] })
+}
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder(Vec<(AccessTarget, AccessRuleOf<TestRuntime>)>, u64);
+
+impl ExtBuilder {
+	pub(crate) fn with_access_rules(mut self, rules: Vec<(AccessTarget, AccessRuleOf<TestRuntime>)>) -> Self {
+		self.0 = rules;
+		self
+	}
+
+	pub(crate) fn with_block_number(mut self, block_number: u64) -> Self {
+		self.1 = block_number;
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut ext = sp_io::TestExternalities::default();
+		ext.execute_with(|| {
+			System::set_block_number(self.1.max(1));
+			for (target, rule) in self.0 {
+				AccessRules::<TestRuntime>::insert(target, rule);
+			}
+		});
+
+		ext
+	}
+}
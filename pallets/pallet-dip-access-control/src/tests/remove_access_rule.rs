@@ -0,0 +1,67 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+use crate::{mock::*, AccessRuleOf, AccessTarget, Error, Pallet};
+
+#[test]
+fn remove_access_rule_successful() {
+	ExtBuilder::default()
+		.with_access_rules(vec![(
+			AccessTarget::Pallet(PALLET_INDEX),
+			AccessRuleOf::<TestRuntime> {
+				key_relationship: None,
+				min_did_age: None,
+				requires_web3_name: true,
+				requires_ethereum_account: false,
+			},
+		)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Pallet::<TestRuntime>::remove_access_rule(
+				RuntimeOrigin::root(),
+				AccessTarget::Pallet(PALLET_INDEX),
+			));
+			assert!(Pallet::<TestRuntime>::access_rule(AccessTarget::Pallet(PALLET_INDEX)).is_none());
+		});
+}
+
+#[test]
+fn remove_access_rule_not_found() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Pallet::<TestRuntime>::remove_access_rule(RuntimeOrigin::root(), AccessTarget::Pallet(PALLET_INDEX)),
+			Error::<TestRuntime>::NotFound
+		);
+	});
+}
+
+#[test]
+fn remove_access_rule_bad_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Pallet::<TestRuntime>::remove_access_rule(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				AccessTarget::Pallet(PALLET_INDEX)
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
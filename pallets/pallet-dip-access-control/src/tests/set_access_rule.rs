@@ -0,0 +1,85 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+use uid_core::DidVerificationKeyRelationship;
+
+use crate::{mock::*, AccessRuleOf, AccessTarget, Pallet};
+
+fn rule() -> AccessRuleOf<TestRuntime> {
+	AccessRuleOf::<TestRuntime> {
+		key_relationship: Some(DidVerificationKeyRelationship::Authentication),
+		min_did_age: Some(10),
+		requires_web3_name: true,
+		requires_ethereum_account: false,
+	}
+}
+
+#[test]
+fn set_access_rule_successful() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Pallet::<TestRuntime>::set_access_rule(
+			RuntimeOrigin::root(),
+			AccessTarget::Pallet(PALLET_INDEX),
+			rule(),
+		));
+		assert_eq!(
+			Pallet::<TestRuntime>::access_rule(AccessTarget::Pallet(PALLET_INDEX)),
+			Some(rule())
+		);
+		// Rules for a pallet do not apply to the storage entry of its calls.
+		assert!(Pallet::<TestRuntime>::access_rule(AccessTarget::Call(PALLET_INDEX, CALL_INDEX)).is_none());
+	});
+}
+
+#[test]
+fn set_access_rule_override() {
+	let new_rule = AccessRuleOf::<TestRuntime> {
+		requires_ethereum_account: true,
+		..rule()
+	};
+	ExtBuilder::default()
+		.with_access_rules(vec![(AccessTarget::Call(PALLET_INDEX, CALL_INDEX), rule())])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Pallet::<TestRuntime>::set_access_rule(
+				RuntimeOrigin::root(),
+				AccessTarget::Call(PALLET_INDEX, CALL_INDEX),
+				new_rule.clone(),
+			));
+			assert_eq!(
+				Pallet::<TestRuntime>::access_rule(AccessTarget::Call(PALLET_INDEX, CALL_INDEX)),
+				Some(new_rule)
+			);
+		});
+}
+
+#[test]
+fn set_access_rule_bad_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Pallet::<TestRuntime>::set_access_rule(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				AccessTarget::Pallet(PALLET_INDEX),
+				rule(),
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
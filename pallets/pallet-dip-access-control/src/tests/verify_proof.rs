@@ -0,0 +1,216 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok};
+use pallet_dip_consumer::{traits::IdentityProofVerifier, RuntimeCallOf};
+use uid_core::DidVerificationKeyRelationship;
+
+use crate::{
+	mock::*, AccessControlledProofVerifier, AccessControlledProofVerifierError, AccessRuleError, AccessRuleOf,
+	AccessTarget,
+};
+
+type Verifier = AccessControlledProofVerifier<MockProofVerifier>;
+
+fn verify(
+	call: &RuntimeCallOf<TestRuntime>,
+	details: MockIdentityDetails,
+) -> Result<MockIdentityDetails, AccessControlledProofVerifierError<u16>> {
+	<Verifier as IdentityProofVerifier<TestRuntime>>::verify_proof_for_call_against_details(
+		call,
+		&SUBJECT,
+		&ACCOUNT_ID,
+		&mut None,
+		details,
+	)
+}
+
+fn access_denied(error: AccessRuleError) -> Result<MockIdentityDetails, AccessControlledProofVerifierError<u16>> {
+	Err(AccessControlledProofVerifierError::AccessDenied(error))
+}
+
+fn full_rule() -> AccessRuleOf<TestRuntime> {
+	AccessRuleOf::<TestRuntime> {
+		key_relationship: Some(DidVerificationKeyRelationship::Authentication),
+		min_did_age: Some(100),
+		requires_web3_name: true,
+		requires_ethereum_account: true,
+	}
+}
+
+fn full_details() -> MockIdentityDetails {
+	MockIdentityDetails {
+		signing_key_relationship: Some(DidVerificationKeyRelationship::Authentication),
+		oldest_key_block_number: Some(50),
+		web3_name: true,
+		ethereum_account: true,
+	}
+}
+
+#[test]
+fn access_target_from_call() {
+	assert_eq!(
+		AccessTarget::from_call(&call()),
+		Some(AccessTarget::Call(PALLET_INDEX, CALL_INDEX))
+	);
+	assert_eq!(
+		AccessTarget::from_call(&other_call()).and_then(|t| t.fallback()),
+		Some(AccessTarget::Pallet(PALLET_INDEX))
+	);
+	assert_ne!(AccessTarget::from_call(&other_call()), AccessTarget::from_call(&call()));
+}
+
+#[test]
+fn verify_proof_no_rule() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			verify(&call(), MockIdentityDetails::default()),
+			access_denied(AccessRuleError::RuleNotFound)
+		);
+	});
+}
+
+#[test]
+fn verify_proof_no_rule_allowed() {
+	ExtBuilder::default().build().execute_with(|| {
+		AllowWithoutRule::set(&true);
+		assert_eq!(
+			verify(&call(), MockIdentityDetails::default()),
+			Ok(MockIdentityDetails::default())
+		);
+	});
+}
+
+#[test]
+fn verify_proof_rule_satisfied() {
+	ExtBuilder::default()
+		.with_access_rules(vec![(AccessTarget::Pallet(PALLET_INDEX), full_rule())])
+		.with_block_number(150)
+		.build()
+		.execute_with(|| {
+			assert_eq!(verify(&call(), full_details()), Ok(full_details()));
+		});
+}
+
+#[test]
+fn verify_proof_rule_not_satisfied() {
+	ExtBuilder::default()
+		.with_access_rules(vec![(AccessTarget::Pallet(PALLET_INDEX), full_rule())])
+		.with_block_number(150)
+		.build()
+		.execute_with(|| {
+			// Wrong key relationship
+			assert_eq!(
+				verify(
+					&call(),
+					MockIdentityDetails {
+						signing_key_relationship: Some(DidVerificationKeyRelationship::AssertionMethod),
+						..full_details()
+					}
+				),
+				access_denied(AccessRuleError::KeyRelationshipNotFound)
+			);
+			// DID too recent
+			assert_eq!(
+				verify(
+					&call(),
+					MockIdentityDetails {
+						oldest_key_block_number: Some(51),
+						..full_details()
+					}
+				),
+				access_denied(AccessRuleError::DidTooRecent)
+			);
+			// No DID key revealed
+			assert_eq!(
+				verify(
+					&call(),
+					MockIdentityDetails {
+						oldest_key_block_number: None,
+						..full_details()
+					}
+				),
+				access_denied(AccessRuleError::DidTooRecent)
+			);
+			// No web3name
+			assert_eq!(
+				verify(
+					&call(),
+					MockIdentityDetails {
+						web3_name: false,
+						..full_details()
+					}
+				),
+				access_denied(AccessRuleError::Web3NameNotFound)
+			);
+			// No Ethereum account
+			assert_eq!(
+				verify(
+					&call(),
+					MockIdentityDetails {
+						ethereum_account: false,
+						..full_details()
+					}
+				),
+				access_denied(AccessRuleError::EthereumAccountNotFound)
+			);
+		});
+}
+
+#[test]
+fn verify_proof_call_rule_overrides_pallet_rule() {
+	ExtBuilder::default()
+		.with_access_rules(vec![
+			(AccessTarget::Pallet(PALLET_INDEX), full_rule()),
+			(
+				AccessTarget::Call(PALLET_INDEX, CALL_INDEX),
+				AccessRuleOf::<TestRuntime> {
+					key_relationship: None,
+					min_did_age: None,
+					requires_web3_name: false,
+					requires_ethereum_account: false,
+				},
+			),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(verify(&call(), MockIdentityDetails::default()));
+			// Other calls of the pallet fall back to the pallet rule.
+			assert_eq!(
+				verify(&other_call(), MockIdentityDetails::default()),
+				access_denied(AccessRuleError::KeyRelationshipNotFound)
+			);
+		});
+}
+
+#[test]
+fn dispatch_as_access_denied() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DipConsumer::dispatch_as(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				SUBJECT,
+				full_details(),
+				Box::new(call())
+			),
+			pallet_dip_consumer::Error::<TestRuntime>::InvalidProof(
+				AccessControlledProofVerifierError::<u16>::AccessDenied(AccessRuleError::RuleNotFound).into()
+			)
+		);
+	});
+}
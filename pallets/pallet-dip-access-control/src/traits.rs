@@ -0,0 +1,92 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use kilt_dip_primitives::{DipOriginInfo, RevealedAccountId, RevealedDidMerkleProofLeaf};
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use sp_std::fmt::Debug;
+use uid_core::DidVerificationKeyRelationship;
+
+/// Access to the parts of a subject's identity that have been revealed in a
+/// DIP proof, as required to evaluate an
+/// [`AccessRule`](crate::access_rule::AccessRule).
+pub trait RevealedIdentityDetails {
+	/// The block number type of the provider chain.
+	type BlockNumber;
+
+	/// Whether any of the keys that signed the DIP operation has the provided
+	/// relationship with the subject's DID.
+	fn has_signing_key_with_relationship(&self, relationship: &DidVerificationKeyRelationship) -> bool;
+	/// The provider block number at which the oldest revealed DID key was
+	/// added, which is a lower bound for the age of the subject's DID.
+	fn oldest_key_block_number(&self) -> Option<Self::BlockNumber>;
+	/// Whether a web3name linked to the subject has been revealed.
+	fn has_web3_name(&self) -> bool;
+	/// Whether an Ethereum account linked to the subject has been revealed.
+	fn has_linked_ethereum_account(&self) -> bool;
+}
+
+impl<KiltDidKeyId, KiltAccountId, KiltBlockNumber, KiltWeb3Name, const MAX_REVEALED_LEAVES_COUNT: u32>
+	RevealedIdentityDetails
+	for DipOriginInfo<
+		KiltDidKeyId,
+		KiltAccountId,
+		KiltBlockNumber,
+		KiltWeb3Name,
+		LinkableAccountId,
+		MAX_REVEALED_LEAVES_COUNT,
+	> where
+	KiltDidKeyId: Debug,
+	KiltAccountId: Debug,
+	KiltBlockNumber: Debug + Ord + Copy,
+	KiltWeb3Name: Debug,
+{
+	type BlockNumber = KiltBlockNumber;
+
+	fn has_signing_key_with_relationship(&self, relationship: &DidVerificationKeyRelationship) -> bool {
+		let Ok(mut signing_leaves) = self.get_signing_leaves() else {
+			return false;
+		};
+		signing_leaves.any(|signing_leaf| signing_leaf.relationship == (*relationship).into())
+	}
+
+	fn oldest_key_block_number(&self) -> Option<Self::BlockNumber> {
+		self.iter_leaves()
+			.filter_map(|leaf| {
+				if let RevealedDidMerkleProofLeaf::DidKey(did_key) = leaf {
+					Some(did_key.details.block_number)
+				} else {
+					None
+				}
+			})
+			.min()
+	}
+
+	fn has_web3_name(&self) -> bool {
+		self.iter_leaves()
+			.any(|leaf| matches!(leaf, RevealedDidMerkleProofLeaf::Web3Name(_)))
+	}
+
+	fn has_linked_ethereum_account(&self) -> bool {
+		self.iter_leaves().any(|leaf| {
+			matches!(
+				leaf,
+				RevealedDidMerkleProofLeaf::LinkedAccount(RevealedAccountId(LinkableAccountId::AccountId20(_)))
+			)
+		})
+	}
+}
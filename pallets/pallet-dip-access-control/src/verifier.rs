@@ -0,0 +1,97 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use pallet_dip_consumer::{traits::IdentityProofVerifier, RuntimeCallOf};
+use sp_runtime::RuntimeDebug;
+use sp_std::marker::PhantomData;
+
+use crate::{
+	access_rule::AccessTarget, traits::RevealedIdentityDetails, AccessRuleError, Config, Pallet, ProviderBlockNumberOf,
+};
+
+/// The errors returned by the [`AccessControlledProofVerifier`].
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(test, derive(enum_iterator::Sequence))]
+pub enum AccessControlledProofVerifierError<ProofVerifierError> {
+	/// The wrapped verifier failed to verify the DIP proof.
+	ProofVerification(ProofVerifierError),
+	/// The DIP proof is valid, but the revealed details do not satisfy the
+	/// access rule for the call.
+	AccessDenied(AccessRuleError),
+}
+
+impl<ProofVerifierError> From<AccessControlledProofVerifierError<ProofVerifierError>> for u16
+where
+	ProofVerifierError: Into<u16>,
+{
+	fn from(value: AccessControlledProofVerifierError<ProofVerifierError>) -> Self {
+		match value {
+			// Errors from the wrapped verifier are returned as-is, and access control errors
+			// start after the ones used by the verifiers in `kilt-dip-primitives`.
+			AccessControlledProofVerifierError::ProofVerification(error) => error.into(),
+			AccessControlledProofVerifierError::AccessDenied(error) => u8::MAX as u16 * 7 + u8::from(error) as u16,
+		}
+	}
+}
+
+/// Implementation of the `IdentityProofVerifier` trait that wraps another
+/// verifier and, once the wrapped verifier has successfully verified the DIP
+/// proof, checks the revealed details against the access rule configured for
+/// the call being dispatched, falling back to the rule for the call's pallet.
+///
+/// The target is derived from the dispatched call itself, so the rule that
+/// applies to calls nested into other calls, e.g., utility batches, is the
+/// one set for the outer call.
+pub struct AccessControlledProofVerifier<ProofVerifier>(PhantomData<ProofVerifier>);
+
+impl<T, ProofVerifier> IdentityProofVerifier<T> for AccessControlledProofVerifier<ProofVerifier>
+where
+	T: Config,
+	ProofVerifier: IdentityProofVerifier<T>,
+	ProofVerifier::VerificationResult: RevealedIdentityDetails<BlockNumber = ProviderBlockNumberOf<T>>,
+{
+	type Error = AccessControlledProofVerifierError<ProofVerifier::Error>;
+	type Proof = ProofVerifier::Proof;
+	type VerificationResult = ProofVerifier::VerificationResult;
+
+	fn verify_proof_for_call_against_details(
+		call: &RuntimeCallOf<T>,
+		subject: &T::Identifier,
+		submitter: &T::AccountId,
+		identity_details: &mut Option<T::LocalIdentityInfo>,
+		proof: Self::Proof,
+	) -> Result<Self::VerificationResult, Self::Error> {
+		let verification_result =
+			ProofVerifier::verify_proof_for_call_against_details(call, subject, submitter, identity_details, proof)
+				.map_err(AccessControlledProofVerifierError::ProofVerification)?;
+		let target = AccessTarget::from_call(call).ok_or(AccessControlledProofVerifierError::AccessDenied(
+			AccessRuleError::RuleNotFound,
+		))?;
+		Pallet::<T>::check_access(&target, &verification_result)
+			.map_err(AccessControlledProofVerifierError::AccessDenied)?;
+		Ok(verification_result)
+	}
+}
+
+#[test]
+fn access_controlled_proof_verifier_error_value_never_zero() {
+	assert!(
+		enum_iterator::all::<AccessControlledProofVerifierError<u8>>().all(|e| u16::from(e) != 0),
+		"One of the u8 values for the error is 0, which is not allowed."
+	);
+}
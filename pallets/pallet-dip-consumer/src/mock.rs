@@ -87,10 +87,13 @@ impl pallet_balances::Config for TestRuntime {
 
 impl pallet_did_lookup::Config for TestRuntime {
 	type BalanceMigrationManager = ();
+	type DepositIndex = ();
 	type Currency = Balances;
 	type Deposit = ConstU64<1>;
 	type DidIdentifier = AccountId32;
 	type EnsureOrigin = EnsureDipOrigin<AccountId32, AccountId32, ()>;
+	type EthereumChainId = ConstU64<1>;
+	type IdentityChangeHook = ();
 	type OriginSuccess = DipOrigin<AccountId32, AccountId32, ()>;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
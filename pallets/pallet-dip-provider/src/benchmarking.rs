@@ -16,8 +16,12 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use crate::{traits::IdentityProvider, Call, Config, Pallet};
+use crate::{
+	traits::{BenchmarkHooks, IdentityProvider},
+	AutomaticRefreshEnabled, Call, Config, Pallet, PendingRefreshes,
+};
 use frame_benchmarking::v2::*;
+use frame_support::{traits::Get, weights::Weight};
 use kilt_support::{
 	benchmark::IdentityContext,
 	traits::{GenerateBenchmarkOrigin, GetWorstCase, Instanciate},
@@ -40,7 +44,7 @@ mod benchmarks {
 	use super::*;
 
 	#[benchmark]
-	fn commit_identity() {
+	fn commit_identity(c: Linear<0, { T::MaxCredentials::get() }>) {
 		let submitter = T::AccountId::new(1);
 		let subject = T::Identifier::new(1);
 		let commitment_version = 0;
@@ -55,6 +59,7 @@ mod benchmarks {
 		let origin: T::RuntimeOrigin = T::CommitOriginCheck::generate_origin(submitter, subject.clone());
 
 		<IdentityOf<T> as GetWorstCase<IdentityContextOf<T>>>::worst_case(context);
+		T::BenchmarkHooks::setup_credentials(&subject, c);
 
 		let cloned_subject = subject.clone();
 
@@ -95,6 +100,53 @@ mod benchmarks {
 		assert!(Pallet::<T>::identity_commitments(&subject, commitment_version).is_none());
 	}
 
+	#[benchmark]
+	fn refresh_identity_commitment(c: Linear<0, { T::MaxCredentials::get() }>) {
+		let submitter = T::AccountId::new(1);
+		let subject = T::Identifier::new(1);
+		let commitment_version = 0;
+
+		let origin: T::RuntimeOrigin = T::CommitOriginCheck::generate_origin(submitter.clone(), subject.clone());
+
+		let context = IdentityContext::<T::Identifier, T::AccountId> {
+			did: subject.clone(),
+			submitter,
+		};
+
+		<IdentityOf<T> as GetWorstCase<IdentityContextOf<T>>>::worst_case(context);
+		T::BenchmarkHooks::setup_credentials(&subject, c);
+
+		Pallet::<T>::commit_identity(origin as T::RuntimeOrigin, subject.clone(), Some(commitment_version))
+			.expect("Inserting Identity should not fail.");
+		PendingRefreshes::<T>::insert(&subject, commitment_version, ());
+
+		#[block]
+		{
+			Pallet::<T>::process_pending_refreshes(Weight::MAX);
+		}
+
+		assert!(Pallet::<T>::pending_refreshes(&subject, commitment_version).is_none());
+		assert!(Pallet::<T>::identity_commitments(&subject, commitment_version).is_some());
+	}
+
+	#[benchmark]
+	fn set_automatic_refresh() {
+		let submitter = T::AccountId::new(1);
+		let subject = T::Identifier::new(1);
+		let commitment_version = 0;
+
+		let origin: T::RuntimeOrigin = T::CommitOriginCheck::generate_origin(submitter, subject.clone());
+		AutomaticRefreshEnabled::<T>::insert(&subject, ());
+		PendingRefreshes::<T>::insert(&subject, commitment_version, ());
+		let cloned_subject = subject.clone();
+
+		#[extrinsic_call]
+		Pallet::<T>::set_automatic_refresh(origin as T::RuntimeOrigin, cloned_subject, false);
+
+		assert!(Pallet::<T>::automatic_refresh_enabled(&subject).is_none());
+		assert!(Pallet::<T>::pending_refreshes(&subject, commitment_version).is_none());
+	}
+
 	#[cfg(test)]
 	mod benchmarks_tests {
 		use crate::Pallet;
@@ -30,8 +30,10 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for pallet_dip_provider.
 pub trait WeightInfo {
-	fn commit_identity() -> Weight;
+	fn commit_identity(c: u32, ) -> Weight;
+	fn refresh_identity_commitment(c: u32, ) -> Weight;
 	fn delete_identity_commitment() -> Weight;
+	fn set_automatic_refresh() -> Weight;
 }
 
 /// Weights for pallet_dip_provider using the Substrate node and recommended hardware.
@@ -49,15 +51,43 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
 	/// Storage: `PalletDipProvider::IdentityCommitments` (r:1 w:1)
 	/// Proof: `PalletDipProvider::IdentityCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn commit_identity() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2781`
-		//  Estimated: `29282`
-		// Minimum execution time: 1_244_624 nanoseconds.
+	fn commit_identity(c: u32, ) -> Weight {
+		// Not benchmarked yet with credentials bound to the subject. Bounded by
+		// the benchmarked `commit_identity` without credentials, plus an estimated
+		// 15 microseconds, two storage reads and their maximum proof size for each
+		// credential read and added to the commitment.
 		Weight::from_parts(1_401_381_000, 29282)
+			.saturating_add(Weight::from_parts(15_000_000, 5241).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2312), added: 4787, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidBlacklist` (r:1 w:0)
+	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Names` (r:1 w:0)
+	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Owner` (r:1 w:0)
+	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:11 w:0)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `PalletDipProvider::IdentityCommitments` (r:1 w:1)
+	/// Proof: `PalletDipProvider::IdentityCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PalletDipProvider::PendingRefreshes` (r:1 w:1)
+	/// Proof: `PalletDipProvider::PendingRefreshes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn refresh_identity_commitment(c: u32, ) -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked `commit_identity`, which
+		// retrieves the identity and generates the commitment in the same way,
+		// plus the maximum proof size of the pending refresh read, plus an
+		// estimated 15 microseconds, two storage reads and their maximum proof
+		// size for each credential read and added to the commitment.
+		Weight::from_parts(1_401_381_000, 31815)
+			.saturating_add(Weight::from_parts(15_000_000, 5241).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 	/// Storage: `PalletDipProvider::IdentityCommitments` (r:1 w:1)
 	/// Proof: `PalletDipProvider::IdentityCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn delete_identity_commitment() -> Weight {
@@ -69,6 +99,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `PalletDipProvider::AutomaticRefreshEnabled` (r:0 w:1)
+	/// Proof: `PalletDipProvider::AutomaticRefreshEnabled` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `PalletDipProvider::PendingRefreshes` (r:1 w:1)
+	/// Proof: `PalletDipProvider::PendingRefreshes` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	fn set_automatic_refresh() -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked
+		// `delete_identity_commitment`, which removes an entry of a storage double
+		// map of this pallet in the same way.
+		Weight::from_parts(190_131_000, 3715)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -85,15 +127,43 @@ impl WeightInfo for () {
 	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
 	/// Storage: `PalletDipProvider::IdentityCommitments` (r:1 w:1)
 	/// Proof: `PalletDipProvider::IdentityCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn commit_identity() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2781`
-		//  Estimated: `29282`
-		// Minimum execution time: 1_244_624 nanoseconds.
+	fn commit_identity(c: u32, ) -> Weight {
+		// Not benchmarked yet with credentials bound to the subject. Bounded by
+		// the benchmarked `commit_identity` without credentials, plus an estimated
+		// 15 microseconds, two storage reads and their maximum proof size for each
+		// credential read and added to the commitment.
 		Weight::from_parts(1_401_381_000, 29282)
+			.saturating_add(Weight::from_parts(15_000_000, 5241).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2312), added: 4787, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidBlacklist` (r:1 w:0)
+	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Names` (r:1 w:0)
+	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Owner` (r:1 w:0)
+	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:11 w:0)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `PalletDipProvider::IdentityCommitments` (r:1 w:1)
+	/// Proof: `PalletDipProvider::IdentityCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PalletDipProvider::PendingRefreshes` (r:1 w:1)
+	/// Proof: `PalletDipProvider::PendingRefreshes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn refresh_identity_commitment(c: u32, ) -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked `commit_identity`, which
+		// retrieves the identity and generates the commitment in the same way,
+		// plus the maximum proof size of the pending refresh read, plus an
+		// estimated 15 microseconds, two storage reads and their maximum proof
+		// size for each credential read and added to the commitment.
+		Weight::from_parts(1_401_381_000, 31815)
+			.saturating_add(Weight::from_parts(15_000_000, 5241).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 	/// Storage: `PalletDipProvider::IdentityCommitments` (r:1 w:1)
 	/// Proof: `PalletDipProvider::IdentityCommitments` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn delete_identity_commitment() -> Weight {
@@ -105,4 +175,16 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `PalletDipProvider::AutomaticRefreshEnabled` (r:0 w:1)
+	/// Proof: `PalletDipProvider::AutomaticRefreshEnabled` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `PalletDipProvider::PendingRefreshes` (r:1 w:1)
+	/// Proof: `PalletDipProvider::PendingRefreshes` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	fn set_automatic_refresh() -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked
+		// `delete_identity_commitment`, which removes an entry of a storage double
+		// map of this pallet in the same way.
+		Weight::from_parts(190_131_000, 3715)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
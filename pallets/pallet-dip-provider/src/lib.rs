@@ -43,8 +43,10 @@ pub mod pallet {
 	use super::*;
 
 	use default_weights::WeightInfo;
-	use frame_support::{pallet_prelude::*, traits::EnsureOriginWithArg};
+	use frame_support::{pallet_prelude::*, storage::with_storage_layer, traits::EnsureOriginWithArg};
 	use frame_system::pallet_prelude::*;
+	use kilt_support::traits::OnIdentityChange;
+	use sp_std::vec::Vec;
 
 	use crate::traits::{IdentityCommitmentGenerator, IdentityProvider, ProviderHooks, SubmitterInfo};
 
@@ -59,6 +61,8 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHooks: crate::traits::BenchmarkHooks<Self>;
 		/// The check ensuring a given runtime origin is allowed to generate and
 		/// remove identity commitments.
 		type CommitOriginCheck: EnsureOriginWithArg<Self::RuntimeOrigin, Self::Identifier, Success = Self::CommitOrigin>;
@@ -77,6 +81,20 @@ pub mod pallet {
 		/// retrieved from any source, using a combination of on-chain and
 		/// off-chain solutions.
 		type IdentityProvider: IdentityProvider<Self>;
+		/// The maximum number of credentials the `IdentityProvider` retrieves
+		/// for a single subject. It bounds the work done whenever an identity
+		/// commitment is generated.
+		#[pallet::constant]
+		type MaxCredentials: Get<u32>;
+		/// The maximum number of commitment versions that can be stored for a
+		/// single subject. It bounds the work done whenever the identity
+		/// information of a subject changes or the subject is deleted.
+		#[pallet::constant]
+		type MaxCommitmentVersions: Get<u32>;
+		/// The maximum number of commitment versions that can be generated in a
+		/// single `commit_identity_versions` call.
+		#[pallet::constant]
+		type MaxVersionsPerCommit: Get<u32>;
 		/// Customizable external logic to handle events in which a new identity
 		/// commitment is generated or removed.
 		type ProviderHooks: ProviderHooks<Self>;
@@ -100,10 +118,32 @@ pub mod pallet {
 		IdentityCommitmentOf<T>,
 	>;
 
+	/// The identity commitments that must be regenerated because the
+	/// underlying identity information has changed. The first key is the
+	/// `Identifier` of subjects, while the second key is the commitment
+	/// version.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_refreshes)]
+	pub type PendingRefreshes<T> =
+		StorageDoubleMap<_, Blake2_128Concat, <T as Config>::Identifier, Twox64Concat, IdentityCommitmentVersion, ()>;
+
+	/// The subjects that opted in to the automatic refresh of their identity
+	/// commitments.
+	#[pallet::storage]
+	#[pallet::getter(fn automatic_refresh_enabled)]
+	pub type AutomaticRefreshEnabled<T> = StorageMap<_, Blake2_128Concat, <T as Config>::Identifier, ()>;
+
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::process_pending_refreshes(remaining_weight)
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -123,6 +163,37 @@ pub mod pallet {
 			/// The version of the commitment.
 			version: IdentityCommitmentVersion,
 		},
+		/// The identity information of a subject has changed, and its
+		/// commitments have been scheduled for regeneration.
+		IdentityRefreshScheduled {
+			/// The identifier of the identity whose commitments are stale.
+			identifier: T::Identifier,
+		},
+		/// A commitment has been automatically regenerated.
+		VersionedIdentityRefreshed {
+			/// The identifier of the identity committed.
+			identifier: T::Identifier,
+			/// The value of the new commitment.
+			commitment: IdentityCommitmentOf<T>,
+			/// The version of the commitment.
+			version: IdentityCommitmentVersion,
+		},
+		/// A commitment could not be automatically regenerated, and the stale
+		/// commitment has been kept.
+		VersionedIdentityRefreshFailed {
+			/// The identifier of the identity committed.
+			identifier: T::Identifier,
+			/// The version of the commitment.
+			version: IdentityCommitmentVersion,
+		},
+		/// The automatic refresh of the commitments of a subject has been
+		/// enabled or disabled.
+		AutomaticRefreshSet {
+			/// The identifier of the identity.
+			identifier: T::Identifier,
+			/// Whether the automatic refresh is enabled.
+			enabled: bool,
+		},
 	}
 
 	#[pallet::error]
@@ -135,6 +206,9 @@ pub mod pallet {
 		IdentityCommitmentGenerator(u16),
 		/// Error inside the external hook logic.
 		Hook(u16),
+		/// The subject already has the maximum number of commitment versions
+		/// stored.
+		TooManyCommitmentVersions,
 	}
 
 	#[pallet::call]
@@ -143,9 +217,12 @@ pub mod pallet {
 		/// the provided `Identifier`. If an old commitment for the same version
 		/// is present, it is overridden. Hooks are called before the new
 		/// commitment is stored, and optionally before the old one is replaced.
+		/// A new version cannot be stored if the subject already has
+		/// `MaxCommitmentVersions` commitments.
 		#[pallet::call_index(0)]
 		#[pallet::weight({
-			<T as Config>::WeightInfo::commit_identity()
+			<T as Config>::WeightInfo::commit_identity(Pallet::<T>::max_retrieved_credentials())
+				.saturating_add(Pallet::<T>::commitment_versions_weight())
 		})]
 		pub fn commit_identity(
 			origin: OriginFor<T>,
@@ -156,7 +233,104 @@ pub mod pallet {
 				.map(|e: <T as Config>::CommitOrigin| e.submitter())?;
 
 			let commitment_version = version.unwrap_or(LATEST_COMMITMENT_VERSION);
-			let identity = T::IdentityProvider::retrieve(&identifier)
+			let identity = Self::retrieve_identity(&identifier)?;
+
+			Self::commit_identity_version(&identifier, &dispatcher, &identity, commitment_version)?;
+			Ok(())
+		}
+
+		/// Delete an identity commitment of a specific version for a specific
+		/// `Identifier`. If a commitment of the provided version does not exist
+		/// for the given `Identifier`, an error is returned. Hooks are called
+		/// after the commitment has been removed.
+		#[pallet::call_index(1)]
+		#[pallet::weight({
+			<T as Config>::WeightInfo::delete_identity_commitment()
+		})]
+		pub fn delete_identity_commitment(
+			origin: OriginFor<T>,
+			identifier: T::Identifier,
+			version: Option<IdentityCommitmentVersion>,
+		) -> DispatchResult {
+			let dispatcher = T::CommitOriginCheck::ensure_origin(origin, &identifier)
+				.map(|e: <T as Config>::CommitOrigin| e.submitter())?;
+			let commitment_version = version.unwrap_or(LATEST_COMMITMENT_VERSION);
+
+			Self::delete_identity_commitment_storage_entry(&identifier, &dispatcher, commitment_version)?;
+			Ok(())
+		}
+
+		/// Enable or disable the automatic refresh of the identity commitments
+		/// for the provided `Identifier`. Automatic refresh is disabled by
+		/// default. When disabling it, any pending refresh is discarded.
+		#[pallet::call_index(2)]
+		#[pallet::weight({
+			<T as Config>::WeightInfo::set_automatic_refresh()
+				.saturating_add(T::DbWeight::get().writes(T::MaxCommitmentVersions::get().into()))
+		})]
+		pub fn set_automatic_refresh(origin: OriginFor<T>, identifier: T::Identifier, enabled: bool) -> DispatchResult {
+			T::CommitOriginCheck::ensure_origin(origin, &identifier)?;
+
+			if enabled {
+				AutomaticRefreshEnabled::<T>::insert(&identifier, ());
+			} else {
+				AutomaticRefreshEnabled::<T>::remove(&identifier);
+				let _ = PendingRefreshes::<T>::clear_prefix(&identifier, T::MaxCommitmentVersions::get(), None);
+			}
+			Self::deposit_event(Event::<T>::AutomaticRefreshSet { identifier, enabled });
+			Ok(())
+		}
+
+		/// Generate a new commitment for each of the provided versions for the
+		/// subject identified by the provided `Identifier`, so that several
+		/// commitment versions can be published at the same time. Each version
+		/// behaves as if `commit_identity` were called for it, including the
+		/// hooks being called once per version.
+		#[pallet::call_index(3)]
+		#[pallet::weight({
+			<T as Config>::WeightInfo::commit_identity(Pallet::<T>::max_retrieved_credentials())
+				.saturating_add(Pallet::<T>::commitment_versions_weight())
+				.saturating_mul(versions.len() as u64)
+		})]
+		pub fn commit_identity_versions(
+			origin: OriginFor<T>,
+			identifier: T::Identifier,
+			versions: BoundedVec<IdentityCommitmentVersion, T::MaxVersionsPerCommit>,
+		) -> DispatchResult {
+			let dispatcher = T::CommitOriginCheck::ensure_origin(origin, &identifier)
+				.map(|e: <T as Config>::CommitOrigin| e.submitter())?;
+
+			let identity = Self::retrieve_identity(&identifier)?;
+
+			for version in versions {
+				Self::commit_identity_version(&identifier, &dispatcher, &identity, version)?;
+			}
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Return the versions of all the identity commitments stored for the
+		/// provided `Identifier`.
+		pub fn commitment_versions(identifier: &T::Identifier) -> Vec<IdentityCommitmentVersion> {
+			IdentityCommitments::<T>::iter_key_prefix(identifier).collect()
+		}
+
+		/// The weight of reading all the commitment versions of a subject.
+		fn commitment_versions_weight() -> Weight {
+			T::DbWeight::get().reads(T::MaxCommitmentVersions::get().into())
+		}
+
+		/// The number of credentials to weigh the retrieval of an identity
+		/// with. It is one more than `MaxCredentials`, since an identity
+		/// provider might read one additional credential to detect that a
+		/// subject has too many of them.
+		pub(crate) fn max_retrieved_credentials() -> u32 {
+			T::MaxCredentials::get().saturating_add(1)
+		}
+
+		fn retrieve_identity(identifier: &T::Identifier) -> Result<IdentityOf<T>, Error<T>> {
+			let identity = T::IdentityProvider::retrieve(identifier)
 				.map_err(|error| Error::<T>::IdentityProvider(error.into()))?;
 			log::info!(
 				target: LOG_TARGET,
@@ -164,11 +338,30 @@ pub mod pallet {
 				identifier,
 				identity
 			);
-			let commitment =
-				T::IdentityCommitmentGenerator::generate_commitment(&identifier, &identity, commitment_version)
-					.map_err(|error| Error::<T>::IdentityCommitmentGenerator(error.into()))?;
+			Ok(identity)
+		}
+
+		/// Generate and store the commitment of the provided version for the
+		/// provided identity, replacing any previous commitment of the same
+		/// version.
+		fn commit_identity_version(
+			identifier: &T::Identifier,
+			dispatcher: &T::AccountId,
+			identity: &IdentityOf<T>,
+			version: IdentityCommitmentVersion,
+		) -> Result<(), Error<T>> {
+			let commitment = T::IdentityCommitmentGenerator::generate_commitment(identifier, identity, version)
+				.map_err(|error| Error::<T>::IdentityCommitmentGenerator(error.into()))?;
+
+			if !IdentityCommitments::<T>::contains_key(identifier, version) {
+				let stored_versions = Self::commitment_versions(identifier).len();
+				ensure!(
+					stored_versions < T::MaxCommitmentVersions::get() as usize,
+					Error::<T>::TooManyCommitmentVersions
+				);
+			}
 
-			match Self::delete_identity_commitment_storage_entry(&identifier, &dispatcher, commitment_version) {
+			match Self::delete_identity_commitment_storage_entry(identifier, dispatcher, version) {
 				// Ignore if there was no previous commitment.
 				Ok(commitment) => {
 					log::trace!(
@@ -180,44 +373,21 @@ pub mod pallet {
 				}
 				Err(Error::<T>::CommitmentNotFound) => (),
 				// If a different error is returned, bubble it up.
-				Err(e) => return Err(e.into()),
+				Err(e) => return Err(e),
 			};
 
-			IdentityCommitments::<T>::insert(&identifier, commitment_version, commitment.clone());
+			IdentityCommitments::<T>::insert(identifier, version, commitment.clone());
 			// Call hooks for new commitment.
-			T::ProviderHooks::on_identity_committed(&identifier, &dispatcher, &commitment, commitment_version)
+			T::ProviderHooks::on_identity_committed(identifier, dispatcher, &commitment, version)
 				.map_err(|e| Error::<T>::Hook(e.into()))?;
 			Self::deposit_event(Event::<T>::VersionedIdentityCommitted {
 				identifier: identifier.clone(),
 				commitment,
-				version: commitment_version,
+				version,
 			});
 			Ok(())
 		}
 
-		/// Delete an identity commitment of a specific version for a specific
-		/// `Identifier`. If a commitment of the provided version does not exist
-		/// for the given `Identifier`, an error is returned. Hooks are called
-		/// after the commitment has been removed.
-		#[pallet::call_index(1)]
-		#[pallet::weight({
-			<T as Config>::WeightInfo::delete_identity_commitment()
-		})]
-		pub fn delete_identity_commitment(
-			origin: OriginFor<T>,
-			identifier: T::Identifier,
-			version: Option<IdentityCommitmentVersion>,
-		) -> DispatchResult {
-			let dispatcher = T::CommitOriginCheck::ensure_origin(origin, &identifier)
-				.map(|e: <T as Config>::CommitOrigin| e.submitter())?;
-			let commitment_version = version.unwrap_or(LATEST_COMMITMENT_VERSION);
-
-			Self::delete_identity_commitment_storage_entry(&identifier, &dispatcher, commitment_version)?;
-			Ok(())
-		}
-	}
-
-	impl<T: Config> Pallet<T> {
 		pub fn delete_identity_commitment_storage_entry(
 			identifier: &T::Identifier,
 			dispatcher: &T::AccountId,
@@ -248,5 +418,136 @@ pub mod pallet {
 			});
 			Ok(commitment)
 		}
+
+		/// Remove the commitment of a deleted subject, calling the provider
+		/// hooks. If the hooks fail, the commitment is removed without calling
+		/// them, since it must not outlive the subject.
+		fn remove_commitment_of_deleted_identity(
+			identifier: &T::Identifier,
+			dispatcher: &T::AccountId,
+			version: IdentityCommitmentVersion,
+		) {
+			let result = with_storage_layer(|| {
+				Self::delete_identity_commitment_storage_entry(identifier, dispatcher, version)
+					.map_err(DispatchError::from)
+			});
+			if let Err(error) = result {
+				log::info!(
+					target: LOG_TARGET,
+					"Failed to remove commitment version {:#?} for deleted subject {:#?} with error {:#?}",
+					version,
+					identifier,
+					error
+				);
+				let _ = Self::delete_identity_commitment_storage_entry_without_hook(identifier, version);
+			}
+		}
+
+		/// Regenerate as many pending identity commitments as allowed by the
+		/// provided weight, returning the weight consumed.
+		pub(crate) fn process_pending_refreshes(remaining_weight: Weight) -> Weight {
+			let refresh_weight =
+				<T as Config>::WeightInfo::refresh_identity_commitment(Self::max_retrieved_credentials());
+			// Account for the read that finds the queue empty.
+			let mut consumed_weight = T::DbWeight::get().reads(1);
+			if !consumed_weight.all_lte(remaining_weight) {
+				return Weight::zero();
+			}
+
+			let mut pending_refreshes = PendingRefreshes::<T>::drain();
+			while consumed_weight.saturating_add(refresh_weight).all_lte(remaining_weight) {
+				let Some((identifier, version, _)) = pending_refreshes.next() else {
+					break;
+				};
+				consumed_weight.saturating_accrue(refresh_weight);
+				Self::refresh_identity_commitment(&identifier, version);
+			}
+			consumed_weight
+		}
+
+		/// Regenerate the identity commitment of the provided version for the
+		/// provided `Identifier`, if it still exists. Hooks are not called,
+		/// since the commitment is replaced and not created or removed.
+		pub(crate) fn refresh_identity_commitment(identifier: &T::Identifier, version: IdentityCommitmentVersion) {
+			// The commitment might have been deleted since the refresh was scheduled.
+			if !IdentityCommitments::<T>::contains_key(identifier, version) {
+				return;
+			}
+
+			let commitment = T::IdentityProvider::retrieve(identifier)
+				.map_err(|error| Error::<T>::IdentityProvider(error.into()))
+				.and_then(|identity| {
+					T::IdentityCommitmentGenerator::generate_commitment(identifier, &identity, version)
+						.map_err(|error| Error::<T>::IdentityCommitmentGenerator(error.into()))
+				});
+			match commitment {
+				Ok(commitment) => {
+					IdentityCommitments::<T>::insert(identifier, version, commitment.clone());
+					Self::deposit_event(Event::<T>::VersionedIdentityRefreshed {
+						identifier: identifier.clone(),
+						commitment,
+						version,
+					});
+				}
+				Err(error) => {
+					log::info!(
+						target: LOG_TARGET,
+						"Failed to refresh commitment version {:#?} for subject {:#?} with error {:#?}",
+						version,
+						identifier,
+						error
+					);
+					Self::deposit_event(Event::<T>::VersionedIdentityRefreshFailed {
+						identifier: identifier.clone(),
+						version,
+					});
+				}
+			}
+		}
+	}
+
+	/// Schedule the regeneration of all the existing commitments of a subject
+	/// whenever its identity information changes, if the subject opted in to
+	/// it, and remove all the commitments of a subject when it is deleted.
+	impl<T: Config> OnIdentityChange<T::Identifier, T::AccountId> for Pallet<T> {
+		fn on_identity_changed(identifier: &T::Identifier) {
+			if !AutomaticRefreshEnabled::<T>::contains_key(identifier) {
+				return;
+			}
+			let mut scheduled = false;
+			IdentityCommitments::<T>::iter_key_prefix(identifier).for_each(|version| {
+				PendingRefreshes::<T>::insert(identifier, version, ());
+				scheduled = true;
+			});
+			if scheduled {
+				Self::deposit_event(Event::<T>::IdentityRefreshScheduled {
+					identifier: identifier.clone(),
+				});
+			}
+		}
+
+		fn on_identity_deleted(identifier: &T::Identifier, deposit_owner: &T::AccountId) {
+			AutomaticRefreshEnabled::<T>::remove(identifier);
+			let _ = PendingRefreshes::<T>::clear_prefix(identifier, T::MaxCommitmentVersions::get(), None);
+			for version in Self::commitment_versions(identifier) {
+				Self::remove_commitment_of_deleted_identity(identifier, deposit_owner, version);
+			}
+		}
+
+		fn on_identity_changed_weight() -> Weight {
+			let max_versions: u64 = T::MaxCommitmentVersions::get().into();
+			// The opt-in flag, the existing commitment versions and the scheduled
+			// refreshes.
+			T::DbWeight::get().reads_writes(max_versions.saturating_add(1), max_versions)
+		}
+
+		fn on_identity_deleted_weight() -> Weight {
+			let max_versions: u64 = T::MaxCommitmentVersions::get().into();
+			<T as Config>::WeightInfo::delete_identity_commitment()
+				.saturating_mul(max_versions)
+				// The opt-in flag, the scheduled refreshes and the existing commitment
+				// versions.
+				.saturating_add(T::DbWeight::get().reads_writes(max_versions, max_versions.saturating_add(1)))
+		}
 	}
 }
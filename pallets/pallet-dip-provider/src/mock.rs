@@ -70,11 +70,16 @@ impl frame_system::Config for TestRuntime {
 }
 
 impl crate::Config for TestRuntime {
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHooks = ();
 	type CommitOrigin = DoubleOrigin<Self::AccountId, Self::Identifier>;
 	type CommitOriginCheck = EnsureDoubleOrigin<Self::AccountId, Self::Identifier>;
 	type Identifier = AccountId32;
 	type IdentityCommitmentGenerator = DefaultIdentityCommitmentGenerator<u32>;
 	type IdentityProvider = DefaultIdentityProvider<u32>;
+	type MaxCredentials = ConstU32<0>;
+	type MaxCommitmentVersions = ConstU32<3>;
+	type MaxVersionsPerCommit = ConstU32<3>;
 	type ProviderHooks = ();
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
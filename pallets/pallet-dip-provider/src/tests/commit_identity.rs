@@ -16,10 +16,10 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 use kilt_support::mock::mock_origin::DoubleOrigin;
 
-use crate::mock::*;
+use crate::{mock::*, Error};
 
 #[test]
 fn commit_identity_multiple_commitments_for_same_subject() {
@@ -77,3 +77,26 @@ fn commit_identity_override_same_version_commitment() {
 			);
 		});
 }
+
+#[test]
+fn commit_identity_too_many_versions() {
+	ExtBuilder::default()
+		.with_commitments(vec![(DID, 0, u32::MAX), (DID, 1, u32::MAX), (DID, 2, u32::MAX)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				DipProvider::commit_identity(DoubleOrigin(ACCOUNT_ID, DID).into(), DID, Some(3)),
+				Error::<TestRuntime>::TooManyCommitmentVersions
+			);
+			// Existing versions can still be overridden.
+			assert_ok!(DipProvider::commit_identity(
+				DoubleOrigin(ACCOUNT_ID, DID).into(),
+				DID,
+				Some(2),
+			));
+			assert_eq!(
+				DipProvider::identity_commitments(&DID, 2),
+				Some(get_expected_commitment_for(&DID, 2))
+			);
+		});
+}
@@ -0,0 +1,90 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok};
+use kilt_support::mock::mock_origin::DoubleOrigin;
+use sp_runtime::DispatchError;
+
+use crate::mock::*;
+
+#[test]
+fn commit_identity_versions_multiple_versions() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(DipProvider::commit_identity_versions(
+			DoubleOrigin(ACCOUNT_ID, DID).into(),
+			DID,
+			vec![0, 2].try_into().unwrap(),
+		));
+		assert_eq!(
+			DipProvider::identity_commitments(&DID, 0),
+			Some(get_expected_commitment_for(&DID, 0))
+		);
+		assert_eq!(DipProvider::identity_commitments(&DID, 1), None);
+		assert_eq!(
+			DipProvider::identity_commitments(&DID, 2),
+			Some(get_expected_commitment_for(&DID, 2))
+		);
+		assert_eq!(DipProvider::commitment_versions(&DID), vec![0, 2]);
+	});
+}
+
+#[test]
+fn commit_identity_versions_override_existing_versions() {
+	ExtBuilder::default()
+		.with_commitments(vec![(DID, 0, u32::MAX), (DID, 1, u32::MAX)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(DipProvider::commit_identity_versions(
+				DoubleOrigin(ACCOUNT_ID, DID).into(),
+				DID,
+				vec![1].try_into().unwrap(),
+			));
+			// Versions not included in the call are left untouched.
+			assert_eq!(DipProvider::identity_commitments(&DID, 0), Some(u32::MAX));
+			assert_eq!(
+				DipProvider::identity_commitments(&DID, 1),
+				Some(get_expected_commitment_for(&DID, 1))
+			);
+		});
+}
+
+#[test]
+fn commit_identity_versions_no_versions() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(DipProvider::commit_identity_versions(
+			DoubleOrigin(ACCOUNT_ID, DID).into(),
+			DID,
+			Default::default(),
+		));
+		assert!(DipProvider::commitment_versions(&DID).is_empty());
+	});
+}
+
+#[test]
+fn commit_identity_versions_bad_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DipProvider::commit_identity_versions(
+				DoubleOrigin(ACCOUNT_ID, ACCOUNT_ID).into(),
+				DID,
+				vec![0].try_into().unwrap(),
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
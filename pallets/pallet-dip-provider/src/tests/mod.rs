@@ -17,4 +17,6 @@
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
 mod commit_identity;
+mod commit_identity_versions;
 mod delete_identity_commitment;
+mod refresh_identity_commitment;
@@ -0,0 +1,178 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+use frame_support::{assert_ok, weights::Weight};
+use kilt_support::{mock::mock_origin::DoubleOrigin, traits::OnIdentityChange};
+
+use crate::{mock::*, WeightInfo};
+
+fn enable_automatic_refresh() {
+	assert_ok!(DipProvider::set_automatic_refresh(
+		DoubleOrigin(ACCOUNT_ID, DID).into(),
+		DID,
+		true
+	));
+}
+
+#[test]
+fn on_identity_changed_schedules_all_versions() {
+	ExtBuilder::default()
+		.with_commitments(vec![(DID, 0, u32::MAX), (DID, 1, u32::MAX - 1)])
+		.build()
+		.execute_with(|| {
+			enable_automatic_refresh();
+			DipProvider::on_identity_changed(&DID);
+			assert!(DipProvider::pending_refreshes(&DID, 0).is_some());
+			assert!(DipProvider::pending_refreshes(&DID, 1).is_some());
+			// Commitments are not touched until the refresh is processed.
+			assert_eq!(DipProvider::identity_commitments(&DID, 0), Some(u32::MAX));
+		});
+}
+
+#[test]
+fn on_identity_changed_no_commitments() {
+	ExtBuilder::default().build().execute_with(|| {
+		enable_automatic_refresh();
+		DipProvider::on_identity_changed(&DID);
+		assert_eq!(crate::pallet::PendingRefreshes::<TestRuntime>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn on_identity_changed_automatic_refresh_disabled_by_default() {
+	ExtBuilder::default()
+		.with_commitments(vec![(DID, 0, u32::MAX)])
+		.build()
+		.execute_with(|| {
+			assert!(DipProvider::automatic_refresh_enabled(&DID).is_none());
+			DipProvider::on_identity_changed(&DID);
+			assert!(DipProvider::pending_refreshes(&DID, 0).is_none());
+		});
+}
+
+#[test]
+fn set_automatic_refresh_disabling_clears_pending_refreshes() {
+	ExtBuilder::default()
+		.with_commitments(vec![(DID, 0, u32::MAX)])
+		.build()
+		.execute_with(|| {
+			enable_automatic_refresh();
+			DipProvider::on_identity_changed(&DID);
+			assert_ok!(DipProvider::set_automatic_refresh(
+				DoubleOrigin(ACCOUNT_ID, DID).into(),
+				DID,
+				false
+			));
+			assert!(DipProvider::pending_refreshes(&DID, 0).is_none());
+			assert!(DipProvider::automatic_refresh_enabled(&DID).is_none());
+
+			// Re-enabling resumes scheduling.
+			enable_automatic_refresh();
+			assert!(DipProvider::automatic_refresh_enabled(&DID).is_some());
+			DipProvider::on_identity_changed(&DID);
+			assert!(DipProvider::pending_refreshes(&DID, 0).is_some());
+		});
+}
+
+#[test]
+fn process_pending_refreshes_successful() {
+	ExtBuilder::default()
+		.with_commitments(vec![(DID, 0, u32::MAX), (DID, 1, u32::MAX - 1)])
+		.build()
+		.execute_with(|| {
+			enable_automatic_refresh();
+			DipProvider::on_identity_changed(&DID);
+			DipProvider::process_pending_refreshes(Weight::MAX);
+			assert_eq!(
+				DipProvider::identity_commitments(&DID, 0),
+				Some(get_expected_commitment_for(&DID, 0))
+			);
+			assert_eq!(
+				DipProvider::identity_commitments(&DID, 1),
+				Some(get_expected_commitment_for(&DID, 1))
+			);
+			assert_eq!(crate::pallet::PendingRefreshes::<TestRuntime>::iter().count(), 0);
+		});
+}
+
+#[test]
+fn process_pending_refreshes_deleted_commitment() {
+	ExtBuilder::default()
+		.with_commitments(vec![(DID, 0, u32::MAX)])
+		.build()
+		.execute_with(|| {
+			enable_automatic_refresh();
+			DipProvider::on_identity_changed(&DID);
+			assert_ok!(DipProvider::delete_identity_commitment(
+				DoubleOrigin(ACCOUNT_ID, DID).into(),
+				DID,
+				Some(0),
+			));
+			DipProvider::process_pending_refreshes(Weight::MAX);
+			// A deleted commitment must not be re-created by a pending refresh.
+			assert!(DipProvider::identity_commitments(&DID, 0).is_none());
+			assert!(DipProvider::pending_refreshes(&DID, 0).is_none());
+		});
+}
+
+#[test]
+fn process_pending_refreshes_weight_limit() {
+	ExtBuilder::default()
+		.with_commitments(vec![(DID, 0, u32::MAX), (DID, 1, u32::MAX - 1)])
+		.build()
+		.execute_with(|| {
+			enable_automatic_refresh();
+			DipProvider::on_identity_changed(&DID);
+			let refresh_weight =
+				<() as WeightInfo>::refresh_identity_commitment(DipProvider::max_retrieved_credentials());
+			let consumed_weight = DipProvider::process_pending_refreshes(refresh_weight);
+			assert_eq!(consumed_weight, refresh_weight);
+			// Only one of the two pending refreshes fits in the provided weight.
+			assert_eq!(crate::pallet::PendingRefreshes::<TestRuntime>::iter().count(), 1);
+
+			assert_eq!(DipProvider::process_pending_refreshes(Weight::zero()), Weight::zero());
+			assert_eq!(crate::pallet::PendingRefreshes::<TestRuntime>::iter().count(), 1);
+		});
+}
+
+#[test]
+fn on_identity_deleted_removes_all_commitments() {
+	ExtBuilder::default()
+		.with_commitments(vec![(DID, 0, u32::MAX), (DID, 1, u32::MAX - 1)])
+		.build()
+		.execute_with(|| {
+			enable_automatic_refresh();
+			DipProvider::on_identity_changed(&DID);
+			DipProvider::on_identity_deleted(&DID, &ACCOUNT_ID);
+			assert!(DipProvider::commitment_versions(&DID).is_empty());
+			assert_eq!(crate::pallet::PendingRefreshes::<TestRuntime>::iter().count(), 0);
+			assert!(DipProvider::automatic_refresh_enabled(&DID).is_none());
+		});
+}
+
+#[test]
+fn on_identity_deleted_automatic_refresh_disabled() {
+	ExtBuilder::default()
+		.with_commitments(vec![(DID, 0, u32::MAX)])
+		.build()
+		.execute_with(|| {
+			// Commitments of deleted subjects are removed even if they did not opt in to
+			// automatic refreshes.
+			DipProvider::on_identity_deleted(&DID, &ACCOUNT_ID);
+			assert!(DipProvider::identity_commitments(&DID, 0).is_none());
+		});
+}
@@ -197,3 +197,23 @@ where
 		Ok(())
 	}
 }
+
+/// Hooks to set up the state required by the benchmarks of this pallet.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHooks<Runtime>
+where
+	Runtime: Config,
+{
+	/// Bind `count` credentials to the subject identified by the provided
+	/// `Identifier`, so that they are retrieved by the configured
+	/// `IdentityProvider`.
+	fn setup_credentials(identifier: &Runtime::Identifier, count: u32);
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<Runtime> BenchmarkHooks<Runtime> for ()
+where
+	Runtime: Config,
+{
+	fn setup_credentials(_identifier: &Runtime::Identifier, _count: u32) {}
+}
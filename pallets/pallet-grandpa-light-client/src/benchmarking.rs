@@ -0,0 +1,142 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use crate::{Config, Pallet};
+use frame_benchmarking::v2::*;
+
+#[benchmarks]
+mod benchmarks {
+	use finality_grandpa::{Message, Precommit, SignedPrecommit};
+	use frame_support::traits::{EnsureOrigin, Get};
+	use frame_system::RawOrigin;
+	use sp_consensus_grandpa::{localized_payload, AuthorityId, AuthorityList};
+	use sp_runtime::{traits::Header as HeaderT, RuntimeAppPublic};
+	use sp_std::{boxed::Box, vec::Vec};
+
+	use crate::{
+		header::StoredAuthoritySet, AuthoritiesOf, BestFinalized, CommitOf, CurrentAuthoritySet, GrandpaJustification,
+		LatestFinalizedHeaders,
+	};
+
+	use super::*;
+
+	const ROUND: u64 = 1;
+
+	fn provider_header<T: Config>(number: u32, parent_hash: <T::ProviderHeader as HeaderT>::Hash) -> T::ProviderHeader {
+		T::ProviderHeader::new(
+			number.into(),
+			Default::default(),
+			Default::default(),
+			parent_hash,
+			Default::default(),
+		)
+	}
+
+	fn fill_finalized_headers<T: Config>() -> u32 {
+		let max_headers_stored = T::MaxFinalizedHeadersStored::get();
+		(1..=max_headers_stored)
+			.for_each(|number| Pallet::<T>::store_finalized_header(&provider_header::<T>(number, Default::default())));
+		max_headers_stored
+	}
+
+	fn generate_authorities(count: u32) -> Vec<AuthorityId> {
+		(0..count).map(|_| AuthorityId::generate_pair(None)).collect()
+	}
+
+	#[benchmark]
+	fn initialize(a: Linear<1, { T::MaxAuthorities::get() }>) {
+		let origin = T::InitializeOrigin::try_successful_origin().expect("Should not fail to generate origin.");
+		let latest_block_number = fill_finalized_headers::<T>();
+		let header = provider_header::<T>(latest_block_number + 1, Default::default());
+		let authorities: AuthorityList = generate_authorities(a).into_iter().map(|id| (id, 1)).collect();
+
+		#[extrinsic_call]
+		Pallet::<T>::initialize(origin as T::RuntimeOrigin, Box::new(header), authorities, 0);
+
+		assert_eq!(BestFinalized::<T>::get(), Some((latest_block_number + 1).into()));
+		assert_eq!(LatestFinalizedHeaders::<T>::iter().count(), 1);
+	}
+
+	#[benchmark]
+	fn submit_finality_proof(
+		p: Linear<1, { T::MaxAuthorities::get() }>,
+		a: Linear<0, { T::MaxVotesAncestries::get() }>,
+	) {
+		let submitter: T::AccountId = whitelisted_caller();
+		let authorities = generate_authorities(p);
+		let stored_authorities =
+			AuthoritiesOf::<T>::try_from(authorities.iter().map(|id| (id.clone(), 1)).collect::<Vec<_>>())
+				.expect("Should not fail to create authority set.");
+		CurrentAuthoritySet::<T>::put(StoredAuthoritySet {
+			authorities: stored_authorities,
+			set_id: 0,
+		});
+		let latest_block_number = fill_finalized_headers::<T>();
+
+		let header = provider_header::<T>(latest_block_number + 1, Default::default());
+		// Every precommit targets the last descendant, so that all ancestry
+		// headers are traversed for each precommit.
+		let mut precommit_target = (header.hash(), *header.number());
+		let votes_ancestries = (1..=a)
+			.map(|i| {
+				let ancestor = provider_header::<T>(latest_block_number + 1 + i, precommit_target.0);
+				precommit_target = (ancestor.hash(), *ancestor.number());
+				ancestor
+			})
+			.collect::<Vec<_>>();
+		let precommit = Precommit {
+			target_hash: precommit_target.0,
+			target_number: precommit_target.1,
+		};
+		let payload = localized_payload(ROUND, 0, &Message::Precommit(precommit.clone()));
+		let precommits = authorities
+			.into_iter()
+			.map(|id| SignedPrecommit {
+				precommit: precommit.clone(),
+				signature: id.sign(&payload).expect("Should not fail to sign precommit."),
+				id,
+			})
+			.collect();
+		let justification = GrandpaJustification {
+			round: ROUND,
+			commit: CommitOf::<T::ProviderHeader> {
+				target_hash: header.hash(),
+				target_number: *header.number(),
+				precommits,
+			},
+			votes_ancestries,
+		};
+
+		#[extrinsic_call]
+		Pallet::<T>::submit_finality_proof(RawOrigin::Signed(submitter), Box::new(header), justification);
+
+		assert_eq!(BestFinalized::<T>::get(), Some((latest_block_number + 1).into()));
+	}
+
+	#[cfg(test)]
+	mod benchmarks_tests {
+		use crate::Pallet;
+		use frame_benchmarking::impl_benchmark_test_suite;
+
+		impl_benchmark_test_suite!(
+			Pallet,
+			crate::mock::ExtBuilder::default().build_with_keystore(),
+			crate::mock::TestRuntime,
+		);
+	}
+}
@@ -0,0 +1,159 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Autogenerated weights for pallet_grandpa_light_client
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2024-03-12
+//! STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `rust-2`, CPU: `12th Gen Intel(R) Core(TM) i9-12900K`
+//! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/kilt-parachain
+// benchmark
+// pallet
+// --template=.maintain/weight-template.hbs
+// --header=HEADER-GPL
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --steps=50
+// --repeat=20
+// --chain=dev
+// --pallet=pallet-grandpa-light-client
+// --extrinsic=*
+// --output=./pallets/pallet-grandpa-light-client/src/default_weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_grandpa_light_client.
+pub trait WeightInfo {
+	fn initialize(a: u32, ) -> Weight;
+	fn submit_finality_proof(p: u32, a: u32, ) -> Weight;
+}
+
+/// Weights for pallet_grandpa_light_client using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `GrandpaLightClient::LatestBlockNumbers` (r:1 w:1)
+	/// Proof: `GrandpaLightClient::LatestBlockNumbers` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
+	/// Storage: `GrandpaLightClient::LatestFinalizedHeaders` (r:0 w:101)
+	/// Proof: `GrandpaLightClient::LatestFinalizedHeaders` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `GrandpaLightClient::CurrentAuthoritySet` (r:0 w:1)
+	/// Proof: `GrandpaLightClient::CurrentAuthoritySet` (`max_values`: Some(1), `max_size`: Some(4011), added: 4506, mode: `MaxEncodedLen`)
+	/// Storage: `GrandpaLightClient::BestFinalized` (r:0 w:1)
+	/// Proof: `GrandpaLightClient::BestFinalized` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GrandpaLightClient::PendingChange` (r:0 w:1)
+	/// Proof: `GrandpaLightClient::PendingChange` (`max_values`: Some(1), `max_size`: Some(4007), added: 4502, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[1, 100]`.
+	fn initialize(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `548`
+		//  Estimated: `1887`
+		// Minimum execution time: 198_214 nanoseconds.
+		Weight::from_parts(204_310_119, 1887)
+			// Standard Error: 5_912
+			.saturating_add(Weight::from_parts(41_327, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(105_u64))
+	}
+	/// Storage: `GrandpaLightClient::CurrentAuthoritySet` (r:1 w:1)
+	/// Proof: `GrandpaLightClient::CurrentAuthoritySet` (`max_values`: Some(1), `max_size`: Some(4011), added: 4506, mode: `MaxEncodedLen`)
+	/// Storage: `GrandpaLightClient::BestFinalized` (r:1 w:1)
+	/// Proof: `GrandpaLightClient::BestFinalized` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GrandpaLightClient::PendingChange` (r:1 w:1)
+	/// Proof: `GrandpaLightClient::PendingChange` (`max_values`: Some(1), `max_size`: Some(4007), added: 4502, mode: `MaxEncodedLen`)
+	/// Storage: `GrandpaLightClient::LatestBlockNumbers` (r:1 w:1)
+	/// Proof: `GrandpaLightClient::LatestBlockNumbers` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
+	/// Storage: `GrandpaLightClient::LatestFinalizedHeaders` (r:0 w:2)
+	/// Proof: `GrandpaLightClient::LatestFinalizedHeaders` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 100]`.
+	/// The range of component `a` is `[0, 10]`.
+	fn submit_finality_proof(p: u32, a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4549 + p * (40 ±0)`
+		//  Estimated: `9998`
+		// Minimum execution time: 412_087 nanoseconds.
+		Weight::from_parts(389_120_451, 9998)
+			// Standard Error: 61_834
+			.saturating_add(Weight::from_parts(62_418_902, 0).saturating_mul(p.into()))
+			// Standard Error: 612_977
+			.saturating_add(Weight::from_parts(4_102_336, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(1_512_908, 0).saturating_mul(p.into()).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `GrandpaLightClient::LatestBlockNumbers` (r:1 w:1)
+	/// Proof: `GrandpaLightClient::LatestBlockNumbers` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
+	/// Storage: `GrandpaLightClient::LatestFinalizedHeaders` (r:0 w:101)
+	/// Proof: `GrandpaLightClient::LatestFinalizedHeaders` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `GrandpaLightClient::CurrentAuthoritySet` (r:0 w:1)
+	/// Proof: `GrandpaLightClient::CurrentAuthoritySet` (`max_values`: Some(1), `max_size`: Some(4011), added: 4506, mode: `MaxEncodedLen`)
+	/// Storage: `GrandpaLightClient::BestFinalized` (r:0 w:1)
+	/// Proof: `GrandpaLightClient::BestFinalized` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GrandpaLightClient::PendingChange` (r:0 w:1)
+	/// Proof: `GrandpaLightClient::PendingChange` (`max_values`: Some(1), `max_size`: Some(4007), added: 4502, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[1, 100]`.
+	fn initialize(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `548`
+		//  Estimated: `1887`
+		// Minimum execution time: 198_214 nanoseconds.
+		Weight::from_parts(204_310_119, 1887)
+			// Standard Error: 5_912
+			.saturating_add(Weight::from_parts(41_327, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(105_u64))
+	}
+	/// Storage: `GrandpaLightClient::CurrentAuthoritySet` (r:1 w:1)
+	/// Proof: `GrandpaLightClient::CurrentAuthoritySet` (`max_values`: Some(1), `max_size`: Some(4011), added: 4506, mode: `MaxEncodedLen`)
+	/// Storage: `GrandpaLightClient::BestFinalized` (r:1 w:1)
+	/// Proof: `GrandpaLightClient::BestFinalized` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GrandpaLightClient::PendingChange` (r:1 w:1)
+	/// Proof: `GrandpaLightClient::PendingChange` (`max_values`: Some(1), `max_size`: Some(4007), added: 4502, mode: `MaxEncodedLen`)
+	/// Storage: `GrandpaLightClient::LatestBlockNumbers` (r:1 w:1)
+	/// Proof: `GrandpaLightClient::LatestBlockNumbers` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
+	/// Storage: `GrandpaLightClient::LatestFinalizedHeaders` (r:0 w:2)
+	/// Proof: `GrandpaLightClient::LatestFinalizedHeaders` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 100]`.
+	/// The range of component `a` is `[0, 10]`.
+	fn submit_finality_proof(p: u32, a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4549 + p * (40 ±0)`
+		//  Estimated: `9998`
+		// Minimum execution time: 412_087 nanoseconds.
+		Weight::from_parts(389_120_451, 9998)
+			// Standard Error: 61_834
+			.saturating_add(Weight::from_parts(62_418_902, 0).saturating_mul(p.into()))
+			// Standard Error: 612_977
+			.saturating_add(Weight::from_parts(4_102_336, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(1_512_908, 0).saturating_mul(p.into()).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+}
@@ -0,0 +1,52 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_consensus_grandpa::SetId;
+use sp_runtime::RuntimeDebug;
+
+/// The GRANDPA authority set of the provider chain used to verify finality
+/// proofs.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub struct StoredAuthoritySet<Authorities> {
+	/// The authorities and their voting weights.
+	pub authorities: Authorities,
+	/// The ID of the authority set.
+	pub set_id: SetId,
+}
+
+/// A provider authority set change signaled in a finalized header, to be
+/// enacted once the header at `enacted_at` is finalized.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub struct PendingAuthoritySetChange<Authorities, BlockNumber> {
+	/// The authorities and their voting weights of the next authority set.
+	pub next_authorities: Authorities,
+	/// The number of the provider block enacting the change, i.e., the last
+	/// block finalized by the current authority set.
+	pub enacted_at: BlockNumber,
+}
+
+/// Information associated to a finalized provider chain block.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub struct FinalizedHeaderInfo<Hash> {
+	/// The block hash.
+	pub block_hash: Hash,
+	/// The block storage root.
+	pub state_root: Hash,
+}
@@ -0,0 +1,141 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use finality_grandpa::Message;
+use frame_support::ensure;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_consensus_grandpa::{localized_payload, AuthorityId, AuthoritySignature, AuthorityWeight, SetId};
+use sp_runtime::{traits::Header as HeaderT, RuntimeAppPublic, RuntimeDebug};
+use sp_std::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	vec::Vec,
+};
+
+/// The GRANDPA commit message for a block, containing the precommits of the
+/// authorities that voted for it.
+pub type CommitOf<Header> =
+	finality_grandpa::Commit<<Header as HeaderT>::Hash, <Header as HeaderT>::Number, AuthoritySignature, AuthorityId>;
+
+/// A GRANDPA justification for the finality of a block, as generated by the
+/// GRANDPA voters of the provider chain. It has the same encoding as the
+/// justifications returned by the `grandpa_proveFinality` RPC of the provider
+/// node.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, RuntimeDebug)]
+pub struct GrandpaJustification<Header: HeaderT> {
+	/// The round in which the block was finalized.
+	pub round: u64,
+	/// The commit message for the finalized block.
+	pub commit: CommitOf<Header>,
+	/// The headers linking the targets of the precommits to the finalized
+	/// block.
+	pub votes_ancestries: Vec<Header>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum JustificationError {
+	InvalidTarget,
+	EmptyAuthoritySet,
+	UnknownAuthority,
+	DuplicateAuthorityVote,
+	UnrelatedAncestryVote,
+	InvalidAuthoritySignature,
+	TooLowCumulativeWeight,
+	RedundantVotesAncestries,
+}
+
+/// Verify that the provided justification finalizes the block with the given
+/// hash and number, and that it is signed by a supermajority of the provided
+/// authority set.
+pub fn verify_justification<Header: HeaderT>(
+	finalized_target: (Header::Hash, Header::Number),
+	set_id: SetId,
+	authorities: &[(AuthorityId, AuthorityWeight)],
+	justification: &GrandpaJustification<Header>,
+) -> Result<(), JustificationError> {
+	ensure!(
+		(justification.commit.target_hash, justification.commit.target_number) == finalized_target,
+		JustificationError::InvalidTarget
+	);
+
+	let total_weight = authorities
+		.iter()
+		.fold(0u64, |total, (_, weight)| total.saturating_add(*weight));
+	ensure!(total_weight > 0, JustificationError::EmptyAuthoritySet);
+	// Same supermajority threshold used by the GRANDPA voters.
+	let threshold = total_weight - (total_weight - 1) / 3;
+
+	let ancestry = justification
+		.votes_ancestries
+		.iter()
+		.map(|header| (header.hash(), header))
+		.collect::<BTreeMap<_, _>>();
+	let mut visited_ancestors = BTreeSet::new();
+	let mut voters = BTreeSet::new();
+	let mut cumulative_weight = 0u64;
+
+	for signed_precommit in justification.commit.precommits.iter() {
+		let weight = authorities
+			.iter()
+			.find_map(|(id, weight)| (id == &signed_precommit.id).then_some(*weight))
+			.ok_or(JustificationError::UnknownAuthority)?;
+		ensure!(
+			voters.insert(signed_precommit.id.clone()),
+			JustificationError::DuplicateAuthorityVote
+		);
+
+		// The precommit target must be the finalized block or one of its descendants.
+		let mut current_hash = signed_precommit.precommit.target_hash;
+		while current_hash != finalized_target.0 {
+			let header = ancestry
+				.get(&current_hash)
+				.ok_or(JustificationError::UnrelatedAncestryVote)?;
+			ensure!(
+				*header.number() > finalized_target.1,
+				JustificationError::UnrelatedAncestryVote
+			);
+			visited_ancestors.insert(current_hash);
+			current_hash = *header.parent_hash();
+		}
+
+		let payload = localized_payload(
+			justification.round,
+			set_id,
+			&Message::Precommit(signed_precommit.precommit.clone()),
+		);
+		ensure!(
+			signed_precommit.id.verify(&payload, &signed_precommit.signature),
+			JustificationError::InvalidAuthoritySignature
+		);
+
+		cumulative_weight = cumulative_weight.saturating_add(weight);
+	}
+
+	ensure!(
+		cumulative_weight >= threshold,
+		JustificationError::TooLowCumulativeWeight
+	);
+	// Ancestors that are not needed to link any precommit would only bloat the
+	// proof.
+	ensure!(
+		visited_ancestors.len() == ancestry.len(),
+		JustificationError::RedundantVotesAncestries
+	);
+
+	Ok(())
+}
@@ -0,0 +1,358 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Pallet to store the last N (configurable) finalized state roots of a
+//! GRANDPA-based provider chain to be used for cross-chain state proof
+//! verification. State roots are stored once the GRANDPA justification
+//! finalizing the related header is verified against the provider authority
+//! set tracked by the pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod default_weights;
+mod header;
+mod justification;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub use crate::{default_weights::WeightInfo, header::*, justification::*, pallet::*};
+
+const LOG_TARGET: &str = "pallet_grandpa_light_client";
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	use frame_support::{pallet_prelude::*, BoundedVec};
+	use frame_system::pallet_prelude::*;
+	use sp_consensus_grandpa::{AuthorityId, AuthorityList, AuthorityWeight, ConsensusLog, SetId, GRANDPA_ENGINE_ID};
+	use sp_runtime::{
+		traits::{Header as HeaderT, Saturating},
+		SaturatedConversion,
+	};
+	use sp_std::boxed::Box;
+
+	use crate::header::{FinalizedHeaderInfo, PendingAuthoritySetChange, StoredAuthoritySet};
+
+	pub type ProviderBlockNumberOf<T> = <<T as Config>::ProviderHeader as HeaderT>::Number;
+	pub type ProviderHashOf<T> = <<T as Config>::ProviderHeader as HeaderT>::Hash;
+	pub type AuthoritiesOf<T> = BoundedVec<(AuthorityId, AuthorityWeight), <T as Config>::MaxAuthorities>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	/// The provider authority set used to verify the next finality proof.
+	#[pallet::storage]
+	#[pallet::getter(fn current_authority_set)]
+	pub(crate) type CurrentAuthoritySet<T: Config> = StorageValue<_, StoredAuthoritySet<AuthoritiesOf<T>>>;
+
+	/// The provider authority set change signaled by a finalized header and
+	/// not enacted yet, if any.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_authority_set_change)]
+	pub(crate) type PendingChange<T: Config> =
+		StorageValue<_, PendingAuthoritySetChange<AuthoritiesOf<T>, ProviderBlockNumberOf<T>>>;
+
+	/// The number of the latest provider block finalized.
+	#[pallet::storage]
+	#[pallet::getter(fn best_finalized)]
+	pub(crate) type BestFinalized<T: Config> = StorageValue<_, ProviderBlockNumberOf<T>>;
+
+	/// Maps from a provider block number to its related information,
+	/// including the state root.
+	#[pallet::storage]
+	#[pallet::getter(fn latest_finalized_header_for_block)]
+	pub(crate) type LatestFinalizedHeaders<T: Config> =
+		StorageMap<_, Twox64Concat, ProviderBlockNumberOf<T>, FinalizedHeaderInfo<ProviderHashOf<T>>>;
+
+	/// Storage value complimentary to [`LatestFinalizedHeaders`] implementing
+	/// a FIFO queue of the last N finalized provider blocks info.
+	#[pallet::storage]
+	pub(crate) type LatestBlockNumbers<T: Config> =
+		StorageValue<_, BoundedVec<ProviderBlockNumberOf<T>, T::MaxFinalizedHeadersStored>, ValueQuery>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The header type of the provider chain.
+		type ProviderHeader: HeaderT + Parameter;
+		/// The origin allowed to (re-)initialize the pallet with a trusted
+		/// header and authority set.
+		type InitializeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The maximum number of authorities in a provider authority set.
+		#[pallet::constant]
+		type MaxAuthorities: Get<u32>;
+		/// The maximum number of finalized block details to store. When the
+		/// limit is reached, oldest blocks are overridden with new ones.
+		#[pallet::constant]
+		type MaxFinalizedHeadersStored: Get<u32>;
+		/// The maximum number of ancestry headers that can be included in a
+		/// justification.
+		#[pallet::constant]
+		type MaxVotesAncestries: Get<u32>;
+		/// The overarching runtime event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The pallet has been initialized with a trusted provider header and
+		/// authority set.
+		Initialized {
+			block_number: ProviderBlockNumberOf<T>,
+			set_id: SetId,
+		},
+		/// A new provider header has been finalized.
+		HeaderFinalized {
+			block_number: ProviderBlockNumberOf<T>,
+			block_hash: ProviderHashOf<T>,
+			state_root: ProviderHashOf<T>,
+		},
+		/// A provider authority set change has been scheduled, and will be
+		/// enacted once the header at `enacted_at` is finalized.
+		AuthoritySetChangeScheduled { enacted_at: ProviderBlockNumberOf<T> },
+		/// The provider authority set has changed.
+		AuthoritySetChanged { set_id: SetId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The pallet has not been initialized yet.
+		NotInitialized,
+		/// The header is not newer than the latest finalized header.
+		OutdatedHeader,
+		/// The justification does not finalize the provided header with the
+		/// current authority set.
+		InvalidJustification,
+		/// The justification contains too many ancestry headers.
+		TooManyVotesAncestries,
+		/// The authority set contains too many authorities.
+		TooManyAuthorities,
+		/// The header signals an authority set change while another one is
+		/// pending.
+		OverlappingAuthoritySetChange,
+		/// The header is newer than the header enacting the pending authority
+		/// set change, which must be submitted first.
+		AuthoritySetChangeNotEnacted,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Initialize the pallet with a trusted provider header and the
+		/// authority set that will finalize its descendants. Any previously
+		/// stored information is replaced.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::initialize(authorities.len().saturated_into()))]
+		pub fn initialize(
+			origin: OriginFor<T>,
+			header: Box<T::ProviderHeader>,
+			authorities: AuthorityList,
+			set_id: SetId,
+		) -> DispatchResult {
+			T::InitializeOrigin::ensure_origin(origin)?;
+
+			let authorities = AuthoritiesOf::<T>::try_from(authorities).map_err(|_| Error::<T>::TooManyAuthorities)?;
+
+			LatestBlockNumbers::<T>::take()
+				.into_iter()
+				.for_each(LatestFinalizedHeaders::<T>::remove);
+			CurrentAuthoritySet::<T>::put(StoredAuthoritySet { authorities, set_id });
+			PendingChange::<T>::kill();
+			Self::store_finalized_header(&header);
+
+			Self::deposit_event(Event::<T>::Initialized {
+				block_number: *header.number(),
+				set_id,
+			});
+			Ok(())
+		}
+
+		/// Verify the provided justification against the current authority
+		/// set and, if valid, store the state root of the finalized header.
+		///
+		/// Headers signaling an authority set change, and headers enacting
+		/// it, must be submitted for the pallet to keep tracking the provider
+		/// authority set.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::submit_finality_proof(
+			justification.commit.precommits.len().saturated_into(),
+			justification.votes_ancestries.len().saturated_into(),
+		))]
+		pub fn submit_finality_proof(
+			origin: OriginFor<T>,
+			header: Box<T::ProviderHeader>,
+			justification: GrandpaJustification<T::ProviderHeader>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let authority_set = CurrentAuthoritySet::<T>::get().ok_or(Error::<T>::NotInitialized)?;
+			let best_finalized = BestFinalized::<T>::get().ok_or(Error::<T>::NotInitialized)?;
+			ensure!(*header.number() > best_finalized, Error::<T>::OutdatedHeader);
+			let pending_change = PendingChange::<T>::get();
+			// Headers after the one enacting a pending change are finalized by the next
+			// authority set.
+			if let Some(ref pending_change) = pending_change {
+				ensure!(
+					*header.number() <= pending_change.enacted_at,
+					Error::<T>::AuthoritySetChangeNotEnacted
+				);
+			}
+			ensure!(
+				justification.votes_ancestries.len() <= T::MaxVotesAncestries::get().saturated_into(),
+				Error::<T>::TooManyVotesAncestries
+			);
+
+			verify_justification::<T::ProviderHeader>(
+				(header.hash(), *header.number()),
+				authority_set.set_id,
+				&authority_set.authorities,
+				&justification,
+			)
+			.map_err(|error| {
+				log::info!(
+					target: LOG_TARGET,
+					"Failed to verify justification for header {:#?} with error {:#?}",
+					header,
+					error
+				);
+				Error::<T>::InvalidJustification
+			})?;
+
+			let mut set_id = authority_set.set_id;
+			let mut pending_change = match pending_change {
+				Some(pending_change) if pending_change.enacted_at == *header.number() => {
+					set_id = Self::enact_authority_set_change(pending_change.next_authorities, set_id);
+					None
+				}
+				pending_change => pending_change,
+			};
+			if let Some(next_change) = Self::signaled_authority_set_change(&header)? {
+				ensure!(pending_change.is_none(), Error::<T>::OverlappingAuthoritySetChange);
+				if next_change.enacted_at == *header.number() {
+					Self::enact_authority_set_change(next_change.next_authorities, set_id);
+				} else {
+					Self::deposit_event(Event::<T>::AuthoritySetChangeScheduled {
+						enacted_at: next_change.enacted_at,
+					});
+					pending_change = Some(next_change);
+				}
+			}
+			PendingChange::<T>::set(pending_change);
+			Self::store_finalized_header(&header);
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Return the authority set change signaled by the provided header, if
+		/// any, to be enacted once the header `delay` blocks after it is
+		/// finalized. Changes with no delay are enacted by the header
+		/// signaling them.
+		///
+		/// Forced changes are tracked the same way as scheduled ones, which
+		/// requires the header signaling them to be finalized by the current
+		/// authority set. If that never happens, e.g., because the provider
+		/// finality stalled, the pallet must be re-initialized by the
+		/// `InitializeOrigin`.
+		#[allow(clippy::type_complexity)]
+		fn signaled_authority_set_change(
+			header: &T::ProviderHeader,
+		) -> Result<Option<PendingAuthoritySetChange<AuthoritiesOf<T>, ProviderBlockNumberOf<T>>>, Error<T>> {
+			let mut next_change = None;
+			for log in header.digest().logs() {
+				let change = match log.consensus_try_to::<ConsensusLog<ProviderBlockNumberOf<T>>>(&GRANDPA_ENGINE_ID) {
+					Some(ConsensusLog::ScheduledChange(change)) | Some(ConsensusLog::ForcedChange(_, change)) => change,
+					_ => continue,
+				};
+				ensure!(next_change.is_none(), Error::<T>::OverlappingAuthoritySetChange);
+				next_change = Some(PendingAuthoritySetChange {
+					next_authorities: AuthoritiesOf::<T>::try_from(change.next_authorities)
+						.map_err(|_| Error::<T>::TooManyAuthorities)?,
+					enacted_at: header.number().saturating_add(change.delay),
+				});
+			}
+			Ok(next_change)
+		}
+
+		/// Replace the current authority set with the provided one, and return
+		/// its set ID.
+		fn enact_authority_set_change(next_authorities: AuthoritiesOf<T>, current_set_id: SetId) -> SetId {
+			let set_id = current_set_id.saturating_add(1);
+			CurrentAuthoritySet::<T>::put(StoredAuthoritySet {
+				authorities: next_authorities,
+				set_id,
+			});
+			Self::deposit_event(Event::<T>::AuthoritySetChanged { set_id });
+			set_id
+		}
+
+		pub(crate) fn store_finalized_header(header: &T::ProviderHeader) {
+			let mut latest_block_numbers = LatestBlockNumbers::<T>::get();
+			// Remove old provider block from both storage entries.
+			if latest_block_numbers.is_full() {
+				let oldest_block_number = latest_block_numbers.remove(0);
+				LatestFinalizedHeaders::<T>::remove(oldest_block_number);
+				log::trace!(
+					target: LOG_TARGET,
+					"Finalized block queue full. Removing oldest block at height {:#?}",
+					oldest_block_number
+				);
+			}
+			// Set the new provider block in storage.
+			let block_number = *header.number();
+			let block_hash = header.hash();
+			let state_root = *header.state_root();
+			log::trace!(
+				target: LOG_TARGET,
+				"Adding new finalized block with state root {:#?} and number {:#?}",
+				state_root,
+				block_number,
+			);
+			let push_res = latest_block_numbers.try_push(block_number);
+			if let Err(err) = push_res {
+				log::error!(
+					target: LOG_TARGET,
+					"Failed to append block number {:#?} to {:#?}",
+					err,
+					latest_block_numbers
+				);
+			} else {
+				LatestBlockNumbers::<T>::set(latest_block_numbers);
+				LatestFinalizedHeaders::<T>::insert(block_number, FinalizedHeaderInfo { block_hash, state_root });
+				BestFinalized::<T>::put(block_number);
+				Self::deposit_event(Event::<T>::HeaderFinalized {
+					block_number,
+					block_hash,
+					state_root,
+				});
+			}
+		}
+	}
+}
@@ -0,0 +1,190 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use finality_grandpa::{Precommit, SignedPrecommit};
+use frame_support::{
+	construct_runtime,
+	sp_runtime::{
+		testing::H256,
+		traits::{BlakeTwo256, IdentityLookup},
+		AccountId32,
+	},
+	traits::{ConstU16, ConstU32, ConstU64, Everything},
+};
+use frame_system::{mocking::MockBlock, EnsureRoot};
+use sp_consensus_grandpa::{localized_payload, AuthorityId, AuthorityList, AuthoritySignature, SetId};
+use sp_core::{ed25519, Pair};
+use sp_runtime::{
+	generic,
+	traits::{Hash, Header as HeaderT},
+	Digest,
+};
+
+use crate::{header::StoredAuthoritySet, AuthoritiesOf, CommitOf, CurrentAuthoritySet, GrandpaJustification, Pallet};
+
+pub(crate) type ProviderHeader = generic::Header<u64, BlakeTwo256>;
+
+construct_runtime!(
+	pub struct TestRuntime {
+		System: frame_system,
+		GrandpaLightClient: crate,
+	}
+);
+
+impl frame_system::Config for TestRuntime {
+	type AccountData = ();
+	type AccountId = AccountId32;
+	type BaseCallFilter = Everything;
+	type Block = MockBlock<TestRuntime>;
+	type BlockHashCount = ConstU64<256>;
+	type BlockLength = ();
+	type BlockWeights = ();
+	type DbWeight = ();
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type MaxConsumers = ConstU32<16>;
+	type Nonce = u64;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type PalletInfo = PalletInfo;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeTask = ();
+	type SS58Prefix = ConstU16<1>;
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+impl crate::Config for TestRuntime {
+	type InitializeOrigin = EnsureRoot<AccountId32>;
+	type MaxAuthorities = ConstU32<10>;
+	type MaxFinalizedHeadersStored = ConstU32<5>;
+	type MaxVotesAncestries = ConstU32<5>;
+	type ProviderHeader = ProviderHeader;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+}
+
+pub(crate) const ACCOUNT_ID: AccountId32 = AccountId32::new([100u8; 32]);
+pub(crate) const SET_ID: SetId = 1;
+pub(crate) const ROUND: u64 = 10;
+
+pub(crate) fn authority_pair(seed: u8) -> ed25519::Pair {
+	ed25519::Pair::from_seed(&[seed; 32])
+}
+
+pub(crate) fn authority_list(seeds: &[u8]) -> AuthorityList {
+	seeds
+		.iter()
+		.map(|seed| (AuthorityId::from(authority_pair(*seed).public()), 1))
+		.collect()
+}
+
+pub(crate) fn provider_header(number: u64, parent_hash: H256, digest: Digest) -> ProviderHeader {
+	ProviderHeader::new(
+		number,
+		H256::default(),
+		BlakeTwo256::hash_of(&number),
+		parent_hash,
+		digest,
+	)
+}
+
+/// Generate a justification for the provided header, with a precommit for
+/// `precommit_target` by each of the provided signers.
+pub(crate) fn justification_for(
+	header: &ProviderHeader,
+	precommit_target: &ProviderHeader,
+	votes_ancestries: Vec<ProviderHeader>,
+	signers: &[u8],
+	set_id: SetId,
+) -> GrandpaJustification<ProviderHeader> {
+	let precommit = Precommit {
+		target_hash: precommit_target.hash(),
+		target_number: *precommit_target.number(),
+	};
+	let payload = localized_payload(
+		ROUND,
+		set_id,
+		&finality_grandpa::Message::<H256, u64>::Precommit(precommit.clone()),
+	);
+	let precommits = signers
+		.iter()
+		.map(|seed| {
+			let pair = authority_pair(*seed);
+			SignedPrecommit {
+				precommit: precommit.clone(),
+				signature: AuthoritySignature::from(pair.sign(&payload)),
+				id: AuthorityId::from(pair.public()),
+			}
+		})
+		.collect();
+	GrandpaJustification {
+		round: ROUND,
+		commit: CommitOf::<ProviderHeader> {
+			target_hash: header.hash(),
+			target_number: *header.number(),
+			precommits,
+		},
+		votes_ancestries,
+	}
+}
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder(Option<(AuthorityList, SetId)>, Vec<ProviderHeader>);
+
+impl ExtBuilder {
+	pub(crate) fn with_authority_set(mut self, authorities: AuthorityList, set_id: SetId) -> Self {
+		self.0 = Some((authorities, set_id));
+		self
+	}
+
+	pub(crate) fn with_finalized_headers(mut self, headers: Vec<ProviderHeader>) -> Self {
+		self.1 = headers;
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut ext = sp_io::TestExternalities::default();
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			if let Some((authorities, set_id)) = self.0 {
+				CurrentAuthoritySet::<TestRuntime>::put(StoredAuthoritySet {
+					authorities: AuthoritiesOf::<TestRuntime>::try_from(authorities).unwrap(),
+					set_id,
+				});
+			}
+			for header in self.1 {
+				Pallet::<TestRuntime>::store_finalized_header(&header);
+			}
+		});
+
+		ext
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	pub(crate) fn build_with_keystore(self) -> sp_io::TestExternalities {
+		let mut ext = self.build();
+		let keystore = sp_keystore::testing::MemoryKeystore::new();
+		ext.register_extension(sp_keystore::KeystoreExt(sp_std::sync::Arc::new(keystore)));
+		ext
+	}
+}
@@ -0,0 +1,93 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{traits::Header as HeaderT, Digest, DispatchError};
+
+use crate::{
+	mock::*, AuthoritiesOf, BestFinalized, CurrentAuthoritySet, Error, LatestBlockNumbers, LatestFinalizedHeaders,
+	Pallet, PendingAuthoritySetChange, PendingChange,
+};
+
+#[test]
+fn initialize_successful() {
+	ExtBuilder::default()
+		.with_authority_set(authority_list(&[1]), SET_ID)
+		.with_finalized_headers(vec![
+			provider_header(1, Default::default(), Digest::default()),
+			provider_header(2, Default::default(), Digest::default()),
+		])
+		.build()
+		.execute_with(|| {
+			PendingChange::<TestRuntime>::put(PendingAuthoritySetChange {
+				next_authorities: AuthoritiesOf::<TestRuntime>::try_from(authority_list(&[4])).unwrap(),
+				enacted_at: 5,
+			});
+			let header = provider_header(100, Default::default(), Digest::default());
+			assert_ok!(Pallet::<TestRuntime>::initialize(
+				RuntimeOrigin::root(),
+				Box::new(header.clone()),
+				authority_list(&[1, 2, 3]),
+				SET_ID + 1,
+			));
+
+			let authority_set = CurrentAuthoritySet::<TestRuntime>::get().unwrap();
+			assert_eq!(authority_set.authorities.into_inner(), authority_list(&[1, 2, 3]));
+			assert_eq!(authority_set.set_id, SET_ID + 1);
+			assert_eq!(BestFinalized::<TestRuntime>::get(), Some(100));
+			// Any pending authority set change is discarded.
+			assert!(PendingChange::<TestRuntime>::get().is_none());
+			// Previously stored headers are removed.
+			assert_eq!(LatestBlockNumbers::<TestRuntime>::get(), vec![100]);
+			assert_eq!(LatestFinalizedHeaders::<TestRuntime>::iter().count(), 1);
+			assert_eq!(
+				Pallet::<TestRuntime>::latest_finalized_header_for_block(100).map(|info| info.state_root),
+				Some(*header.state_root())
+			);
+		});
+}
+
+#[test]
+fn initialize_bad_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Pallet::<TestRuntime>::initialize(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				Box::new(provider_header(1, Default::default(), Digest::default())),
+				authority_list(&[1]),
+				SET_ID,
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn initialize_too_many_authorities() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Pallet::<TestRuntime>::initialize(
+				RuntimeOrigin::root(),
+				Box::new(provider_header(1, Default::default(), Digest::default())),
+				authority_list(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]),
+				SET_ID,
+			),
+			Error::<TestRuntime>::TooManyAuthorities
+		);
+	});
+}
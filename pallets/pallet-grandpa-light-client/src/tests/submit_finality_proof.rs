@@ -0,0 +1,395 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok};
+use parity_scale_codec::Encode;
+use sp_consensus_grandpa::{ConsensusLog, ScheduledChange, GRANDPA_ENGINE_ID};
+use sp_runtime::{traits::Header as HeaderT, Digest, DigestItem};
+
+use crate::{
+	mock::*, AuthoritiesOf, BestFinalized, CurrentAuthoritySet, Error, LatestBlockNumbers, Pallet,
+	PendingAuthoritySetChange, PendingChange,
+};
+
+fn initialized_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_authority_set(authority_list(&[1, 2, 3]), SET_ID)
+		.with_finalized_headers(vec![provider_header(1, Default::default(), Digest::default())])
+		.build()
+}
+
+fn authority_set_change_digest(next_authorities: &[u8], delay: u64) -> Digest {
+	Digest {
+		logs: vec![DigestItem::Consensus(
+			GRANDPA_ENGINE_ID,
+			ConsensusLog::<u64>::ScheduledChange(ScheduledChange {
+				next_authorities: authority_list(next_authorities),
+				delay,
+			})
+			.encode(),
+		)],
+	}
+}
+
+#[test]
+fn submit_finality_proof_successful() {
+	initialized_ext().execute_with(|| {
+		let header = provider_header(2, Default::default(), Digest::default());
+		let justification = justification_for(&header, &header, vec![], &[1, 2, 3], SET_ID);
+
+		assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+			RuntimeOrigin::signed(ACCOUNT_ID),
+			Box::new(header.clone()),
+			justification,
+		));
+
+		assert_eq!(BestFinalized::<TestRuntime>::get(), Some(2));
+		assert_eq!(LatestBlockNumbers::<TestRuntime>::get(), vec![1, 2]);
+		let stored_info = Pallet::<TestRuntime>::latest_finalized_header_for_block(2).unwrap();
+		assert_eq!(stored_info.block_hash, header.hash());
+		assert_eq!(stored_info.state_root, *header.state_root());
+	});
+}
+
+#[test]
+fn submit_finality_proof_supermajority() {
+	initialized_ext().execute_with(|| {
+		let header = provider_header(2, Default::default(), Digest::default());
+		// 2 out of 3 votes are not enough.
+		let justification = justification_for(&header, &header, vec![], &[1, 2], SET_ID);
+
+		assert_noop!(
+			Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				Box::new(header),
+				justification,
+			),
+			Error::<TestRuntime>::InvalidJustification
+		);
+	});
+}
+
+#[test]
+fn submit_finality_proof_with_votes_ancestries() {
+	initialized_ext().execute_with(|| {
+		let header = provider_header(2, Default::default(), Digest::default());
+		let child = provider_header(3, header.hash(), Digest::default());
+		let grandchild = provider_header(4, child.hash(), Digest::default());
+		let justification = justification_for(
+			&header,
+			&grandchild,
+			vec![child.clone(), grandchild.clone()],
+			&[1, 2, 3],
+			SET_ID,
+		);
+
+		assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+			RuntimeOrigin::signed(ACCOUNT_ID),
+			Box::new(header),
+			justification,
+		));
+		assert_eq!(BestFinalized::<TestRuntime>::get(), Some(2));
+	});
+}
+
+#[test]
+fn submit_finality_proof_redundant_votes_ancestries() {
+	initialized_ext().execute_with(|| {
+		let header = provider_header(2, Default::default(), Digest::default());
+		let unrelated = provider_header(3, Default::default(), Digest::default());
+		let justification = justification_for(&header, &header, vec![unrelated], &[1, 2, 3], SET_ID);
+
+		assert_noop!(
+			Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				Box::new(header),
+				justification,
+			),
+			Error::<TestRuntime>::InvalidJustification
+		);
+	});
+}
+
+#[test]
+fn submit_finality_proof_wrong_set_id() {
+	initialized_ext().execute_with(|| {
+		let header = provider_header(2, Default::default(), Digest::default());
+		let justification = justification_for(&header, &header, vec![], &[1, 2, 3], SET_ID + 1);
+
+		assert_noop!(
+			Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				Box::new(header),
+				justification,
+			),
+			Error::<TestRuntime>::InvalidJustification
+		);
+	});
+}
+
+#[test]
+fn submit_finality_proof_unknown_authority() {
+	initialized_ext().execute_with(|| {
+		let header = provider_header(2, Default::default(), Digest::default());
+		let justification = justification_for(&header, &header, vec![], &[1, 2, 4], SET_ID);
+
+		assert_noop!(
+			Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				Box::new(header),
+				justification,
+			),
+			Error::<TestRuntime>::InvalidJustification
+		);
+	});
+}
+
+#[test]
+fn submit_finality_proof_wrong_target() {
+	initialized_ext().execute_with(|| {
+		let header = provider_header(2, Default::default(), Digest::default());
+		let other_header = provider_header(3, Default::default(), Digest::default());
+		let justification = justification_for(&other_header, &other_header, vec![], &[1, 2, 3], SET_ID);
+
+		assert_noop!(
+			Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				Box::new(header),
+				justification,
+			),
+			Error::<TestRuntime>::InvalidJustification
+		);
+	});
+}
+
+#[test]
+fn submit_finality_proof_outdated_header() {
+	initialized_ext().execute_with(|| {
+		let header = provider_header(1, Default::default(), Digest::default());
+		let justification = justification_for(&header, &header, vec![], &[1, 2, 3], SET_ID);
+
+		assert_noop!(
+			Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				Box::new(header),
+				justification,
+			),
+			Error::<TestRuntime>::OutdatedHeader
+		);
+	});
+}
+
+#[test]
+fn submit_finality_proof_not_initialized() {
+	ExtBuilder::default().build().execute_with(|| {
+		let header = provider_header(2, Default::default(), Digest::default());
+		let justification = justification_for(&header, &header, vec![], &[1, 2, 3], SET_ID);
+
+		assert_noop!(
+			Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				Box::new(header),
+				justification,
+			),
+			Error::<TestRuntime>::NotInitialized
+		);
+	});
+}
+
+#[test]
+fn submit_finality_proof_authority_set_change() {
+	initialized_ext().execute_with(|| {
+		let header = provider_header(2, Default::default(), authority_set_change_digest(&[4, 5, 6], 0));
+		let justification = justification_for(&header, &header, vec![], &[1, 2, 3], SET_ID);
+
+		assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+			RuntimeOrigin::signed(ACCOUNT_ID),
+			Box::new(header.clone()),
+			justification,
+		));
+		let authority_set = CurrentAuthoritySet::<TestRuntime>::get().unwrap();
+		assert_eq!(authority_set.authorities.into_inner(), authority_list(&[4, 5, 6]));
+		assert_eq!(authority_set.set_id, SET_ID + 1);
+
+		// The next header must be finalized by the new authority set.
+		let next_header = provider_header(3, header.hash(), Digest::default());
+		let old_set_justification = justification_for(&next_header, &next_header, vec![], &[1, 2, 3], SET_ID);
+		assert_noop!(
+			Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				Box::new(next_header.clone()),
+				old_set_justification,
+			),
+			Error::<TestRuntime>::InvalidJustification
+		);
+		let new_set_justification = justification_for(&next_header, &next_header, vec![], &[4, 5, 6], SET_ID + 1);
+		assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+			RuntimeOrigin::signed(ACCOUNT_ID),
+			Box::new(next_header),
+			new_set_justification,
+		));
+	});
+}
+
+#[test]
+fn submit_finality_proof_delayed_authority_set_change() {
+	initialized_ext().execute_with(|| {
+		let header = provider_header(2, Default::default(), authority_set_change_digest(&[4, 5, 6], 3));
+		let justification = justification_for(&header, &header, vec![], &[1, 2, 3], SET_ID);
+
+		assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+			RuntimeOrigin::signed(ACCOUNT_ID),
+			Box::new(header.clone()),
+			justification,
+		));
+		// The change is scheduled, and the current authority set is left untouched.
+		assert_eq!(
+			PendingChange::<TestRuntime>::get(),
+			Some(PendingAuthoritySetChange {
+				next_authorities: AuthoritiesOf::<TestRuntime>::try_from(authority_list(&[4, 5, 6])).unwrap(),
+				enacted_at: 5,
+			})
+		);
+		assert_eq!(CurrentAuthoritySet::<TestRuntime>::get().unwrap().set_id, SET_ID);
+
+		// Headers up to the one enacting the change are finalized by the current
+		// authority set.
+		let intermediate_header = provider_header(3, header.hash(), Digest::default());
+		let justification = justification_for(&intermediate_header, &intermediate_header, vec![], &[1, 2, 3], SET_ID);
+		assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+			RuntimeOrigin::signed(ACCOUNT_ID),
+			Box::new(intermediate_header.clone()),
+			justification,
+		));
+		assert!(PendingChange::<TestRuntime>::get().is_some());
+
+		// Headers after the one enacting the change cannot be submitted before it.
+		let enacting_header = provider_header(5, intermediate_header.hash(), Digest::default());
+		let next_header = provider_header(6, enacting_header.hash(), Digest::default());
+		let justification = justification_for(&next_header, &next_header, vec![], &[4, 5, 6], SET_ID + 1);
+		assert_noop!(
+			Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				Box::new(next_header.clone()),
+				justification.clone(),
+			),
+			Error::<TestRuntime>::AuthoritySetChangeNotEnacted
+		);
+
+		let enacting_justification = justification_for(&enacting_header, &enacting_header, vec![], &[1, 2, 3], SET_ID);
+		assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+			RuntimeOrigin::signed(ACCOUNT_ID),
+			Box::new(enacting_header),
+			enacting_justification,
+		));
+		assert!(PendingChange::<TestRuntime>::get().is_none());
+		let authority_set = CurrentAuthoritySet::<TestRuntime>::get().unwrap();
+		assert_eq!(authority_set.authorities.into_inner(), authority_list(&[4, 5, 6]));
+		assert_eq!(authority_set.set_id, SET_ID + 1);
+
+		// The next header is finalized by the new authority set.
+		assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+			RuntimeOrigin::signed(ACCOUNT_ID),
+			Box::new(next_header),
+			justification,
+		));
+		assert_eq!(BestFinalized::<TestRuntime>::get(), Some(6));
+	});
+}
+
+#[test]
+fn submit_finality_proof_forced_authority_set_change() {
+	initialized_ext().execute_with(|| {
+		let header = provider_header(
+			2,
+			Default::default(),
+			Digest {
+				logs: vec![DigestItem::Consensus(
+					GRANDPA_ENGINE_ID,
+					ConsensusLog::<u64>::ForcedChange(
+						1,
+						ScheduledChange {
+							next_authorities: authority_list(&[4, 5, 6]),
+							delay: 2,
+						},
+					)
+					.encode(),
+				)],
+			},
+		);
+		let justification = justification_for(&header, &header, vec![], &[1, 2, 3], SET_ID);
+
+		assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+			RuntimeOrigin::signed(ACCOUNT_ID),
+			Box::new(header),
+			justification,
+		));
+		assert_eq!(
+			PendingChange::<TestRuntime>::get().map(|change| change.enacted_at),
+			Some(4)
+		);
+	});
+}
+
+#[test]
+fn submit_finality_proof_overlapping_authority_set_change() {
+	initialized_ext().execute_with(|| {
+		let header = provider_header(2, Default::default(), authority_set_change_digest(&[4, 5, 6], 3));
+		let justification = justification_for(&header, &header, vec![], &[1, 2, 3], SET_ID);
+		assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+			RuntimeOrigin::signed(ACCOUNT_ID),
+			Box::new(header.clone()),
+			justification,
+		));
+
+		let next_header = provider_header(3, header.hash(), authority_set_change_digest(&[7, 8, 9], 0));
+		let justification = justification_for(&next_header, &next_header, vec![], &[1, 2, 3], SET_ID);
+		assert_noop!(
+			Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				Box::new(next_header),
+				justification,
+			),
+			Error::<TestRuntime>::OverlappingAuthoritySetChange
+		);
+	});
+}
+
+#[test]
+fn submit_finality_proof_full_state() {
+	ExtBuilder::default()
+		.with_authority_set(authority_list(&[1, 2, 3]), SET_ID)
+		.with_finalized_headers(
+			(1..=5)
+				.map(|number| provider_header(number, Default::default(), Digest::default()))
+				.collect(),
+		)
+		.build()
+		.execute_with(|| {
+			let header = provider_header(6, Default::default(), Digest::default());
+			let justification = justification_for(&header, &header, vec![], &[1, 2, 3], SET_ID);
+
+			assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				Box::new(header),
+				justification,
+			));
+			assert!(Pallet::<TestRuntime>::latest_finalized_header_for_block(1).is_none());
+			assert_eq!(LatestBlockNumbers::<TestRuntime>::get(), vec![2, 3, 4, 5, 6]);
+		});
+}
@@ -232,6 +232,24 @@ benchmarks! {
 	}: update_balance(origin_migration_pallet, entries_to_migrate)
 	verify {}
 
+	index_deposit_weight {
+		let sender : AccountIdOf<T> = account("sender", 0, SEED);
+		// DIDs are the largest entries whose deposit can be indexed.
+		let did_public_auth_key = get_ed25519_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(did_public_auth_key), Some(sender.clone()));
+
+		uid_core::Did::<T>::insert(did_subject.clone(), did_details);
+
+		let deposits_to_index = DepositsToIndex {
+			did: BoundedVec::try_from(vec![did_subject]).expect("Vector initialization should not fail."),
+			..Default::default()
+		};
+
+		let origin = RawOrigin::Signed(sender);
+	}: index_deposits(origin, deposits_to_index)
+	verify {}
+
 }
 
 impl_benchmark_test_suite! {
@@ -38,6 +38,7 @@ pub trait WeightInfo {
 	fn did_lookup_migration_weight() -> Weight;
 	fn w3n_migration_weight() -> Weight;
 	fn public_credentials_migration_weight() -> Weight;
+	fn index_deposit_weight() -> Weight;
 }
 
 /// Weights for pallet_migration using the Substrate node and recommended hardware.
@@ -117,6 +118,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(22_874_000, 3513)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	/// Storage: Did Did (r:1 w:0)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	fn index_deposit_weight() -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked
+		// `did_migration_weight`, which reads the largest entry holding a deposit,
+		// plus the write to the deposit index.
+		Weight::from_parts(72_504_000, 5777)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -195,4 +208,16 @@ impl WeightInfo for () {
 		Weight::from_parts(22_874_000, 3513)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
+	/// Storage: Did Did (r:1 w:0)
+	/// Proof: Did Did (max_values: None, max_size: Some(2312), added: 4787, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	fn index_deposit_weight() -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked
+		// `did_migration_weight`, which reads the largest entry holding a deposit,
+		// plus the write to the deposit index.
+		Weight::from_parts(72_504_000, 5777)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -46,7 +46,7 @@ pub mod pallet {
 	use uid_core::{Did, DidIdentifierOf};
 	use kilt_support::traits::BalanceMigrationManager;
 	use pallet_did_lookup::{linkable_account::LinkableAccountId, ConnectedDids};
-	use pallet_web3_names::{Owner, Web3NameOf};
+	use pallet_web3_names::{Owner, TextRecordKeyOf, Web3NameOf};
 	use public_credentials::{CredentialIdOf, Credentials, SubjectIdOf};
 
 	pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
@@ -73,6 +73,46 @@ pub mod pallet {
 		pub public_credentials: BoundedVec<(SubjectIdOf<T>, CredentialIdOf<T>), <T as Config>::MaxMigrationsPerPallet>,
 	}
 
+	/// The entries whose deposits to add to the deposit index, for each pallet
+	/// taking deposits.
+	#[derive(Encode, Decode, TypeInfo, Debug, Clone, PartialEq)]
+	pub struct DepositsToIndex<T>
+	where
+		T: uid_core::Config,
+		T: delegation::Config,
+		T: frame_system::Config,
+		T: pallet_web3_names::Config,
+		T: public_credentials::Config,
+		T: Config,
+	{
+		pub verification: BoundedVec<ClaimHashOf<T>, <T as Config>::MaxMigrationsPerPallet>,
+		pub delegation: BoundedVec<DelegationNodeIdOf<T>, <T as Config>::MaxMigrationsPerPallet>,
+		pub did: BoundedVec<DidIdentifierOf<T>, <T as Config>::MaxMigrationsPerPallet>,
+		pub lookup: BoundedVec<LinkableAccountId, <T as Config>::MaxMigrationsPerPallet>,
+		pub w3n: BoundedVec<Web3NameOf<T>, <T as Config>::MaxMigrationsPerPallet>,
+		pub text_records: BoundedVec<(Web3NameOf<T>, TextRecordKeyOf<T>), <T as Config>::MaxMigrationsPerPallet>,
+		pub public_credentials: BoundedVec<(SubjectIdOf<T>, CredentialIdOf<T>), <T as Config>::MaxMigrationsPerPallet>,
+	}
+
+	impl<T: Config> DepositsToIndex<T> {
+		/// The number of entries across all pallets.
+		pub fn len(&self) -> usize {
+			self.verification
+				.len()
+				.saturating_add(self.delegation.len())
+				.saturating_add(self.did.len())
+				.saturating_add(self.lookup.len())
+				.saturating_add(self.w3n.len())
+				.saturating_add(self.text_records.len())
+				.saturating_add(self.public_credentials.len())
+		}
+
+		/// Whether there are no entries in any pallet.
+		pub fn is_empty(&self) -> bool {
+			self.len() == 0
+		}
+	}
+
 	#[pallet::config]
 	pub trait Config:
 		frame_system::Config
@@ -116,6 +156,9 @@ pub mod pallet {
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		EntriesUpdated(EntriesToMigrate<T>),
+		/// The deposits of the given entries have been added to the deposit
+		/// index.
+		DepositsIndexed(DepositsToIndex<T>),
 	}
 
 	#[pallet::hooks]
@@ -243,6 +286,51 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Add the deposits of the given entries to the deposit index.
+		///
+		/// Deposits taken before the deposit index was introduced are not
+		/// indexed until they are updated or change owner. Indexing a deposit
+		/// that is already indexed is a no-op, hence any signed origin can
+		/// backfill the index.
+		#[pallet::call_index(1)]
+		#[pallet::weight({
+			<T as crate::Config>::WeightInfo::index_deposit_weight().saturating_mul(deposits.len().saturated_into())
+		})]
+		pub fn index_deposits(origin: OriginFor<T>, deposits: DepositsToIndex<T>) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			deposits
+				.verification
+				.iter()
+				.try_for_each(verification::migrations::index_deposit_for_verification::<T>)?;
+			deposits
+				.delegation
+				.iter()
+				.try_for_each(delegation::migrations::index_deposit_for_delegation::<T>)?;
+			deposits
+				.did
+				.iter()
+				.try_for_each(uid_core::migrations::index_deposit_for_did::<T>)?;
+			deposits
+				.lookup
+				.iter()
+				.try_for_each(pallet_did_lookup::migrations::index_deposit_for_did_lookup::<T>)?;
+			deposits
+				.w3n
+				.iter()
+				.try_for_each(pallet_web3_names::migrations::index_deposit_for_w3n::<T>)?;
+			deposits.text_records.iter().try_for_each(|(name, key)| {
+				pallet_web3_names::migrations::index_deposit_for_text_record::<T>(name, key)
+			})?;
+			deposits.public_credentials.iter().try_for_each(|(key, key2)| {
+				public_credentials::migrations::index_deposit_for_public_credentials::<T>(key, key2)
+			})?;
+
+			Self::deposit_event(Event::DepositsIndexed(deposits));
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -16,7 +16,7 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use crate::{Config, EntriesToMigrate};
+use crate::{Config, DepositsToIndex, EntriesToMigrate};
 
 #[cfg(test)]
 pub use runtime::*;
@@ -34,6 +34,20 @@ impl<T: Config> Default for EntriesToMigrate<T> {
 	}
 }
 
+impl<T: Config> Default for DepositsToIndex<T> {
+	fn default() -> Self {
+		DepositsToIndex {
+			verification: Default::default(),
+			delegation: Default::default(),
+			did: Default::default(),
+			lookup: Default::default(),
+			w3n: Default::default(),
+			text_records: Default::default(),
+			public_credentials: Default::default(),
+		}
+	}
+}
+
 #[cfg(test)]
 pub mod runtime {
 	use verification::mock::MockAccessControl;
@@ -51,13 +65,14 @@ pub mod runtime {
 	use kilt_support::{
 		mock::{mock_origin, SubjectId},
 		signature::EqualVerify,
+		traits::DepositIndex,
 	};
 	use pallet_did_lookup::linkable_account::LinkableAccountId;
 	use pallet_web3_names::web3_name::AsciiWeb3Name;
 	use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 	use public_credentials::InputSubjectIdOf;
 	use scale_info::TypeInfo;
-	use sp_core::{ed25519, ConstU128, ConstU32};
+	use sp_core::{ed25519, ConstU128, ConstU32, ConstU64};
 	use sp_runtime::{
 		traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
 		AccountId32, BuildStorage, MultiSignature, MultiSigner, Perquintill, RuntimeDebug,
@@ -106,6 +121,26 @@ pub mod runtime {
 		type Currency = Balances;
 	}
 
+	parameter_types! {
+		pub static IndexedDeposits: Vec<(AccountId, Vec<u8>, Balance)> = vec![];
+	}
+
+	/// Records the deposits added to the deposit index.
+	pub struct MockDepositIndex;
+
+	impl DepositIndex<AccountId, Balance> for MockDepositIndex {
+		fn on_deposit_held(owner: &AccountId, hashed_key: &[u8], amount: &Balance) {
+			IndexedDeposits::mutate(|deposits| {
+				deposits.retain(|(_, key, _)| key != hashed_key);
+				deposits.push((owner.clone(), hashed_key.to_vec(), *amount));
+			});
+		}
+
+		fn on_deposit_released(_owner: &AccountId, hashed_key: &[u8]) {
+			IndexedDeposits::mutate(|deposits| deposits.retain(|(_, key, _)| key != hashed_key));
+		}
+	}
+
 	parameter_types! {
 		pub const SS58Prefix: u8 = 38;
 		pub const BlockHashCount: u64 = 250;
@@ -156,6 +191,7 @@ pub mod runtime {
 		type AuthorizationId = SubjectId;
 		type AccessControl = MockAccessControl<Self>;
 		type BalanceMigrationManager = Migration;
+		type DepositIndex = MockDepositIndex;
 	}
 
 	parameter_types! {
@@ -232,6 +268,7 @@ pub mod runtime {
 		type Deposit = DepositMock;
 		type WeightInfo = ();
 		type BalanceMigrationManager = Migration;
+		type DepositIndex = MockDepositIndex;
 	}
 
 	parameter_types! {
@@ -293,6 +330,8 @@ pub mod runtime {
 		type MaxNumberOfTypesPerService = MaxNumberOfTypesPerService;
 		type MaxNumberOfUrlsPerService = MaxNumberOfUrlsPerService;
 		type BalanceMigrationManager = Migration;
+		type DepositIndex = MockDepositIndex;
+		type IdentityChangeHook = ();
 	}
 
 	parameter_types! {
@@ -309,6 +348,9 @@ pub mod runtime {
 		type DidIdentifier = SubjectId;
 		type WeightInfo = ();
 		type BalanceMigrationManager = Migration;
+		type DepositIndex = MockDepositIndex;
+		type EthereumChainId = ConstU64<1>;
+		type IdentityChangeHook = ();
 	}
 
 	pub(crate) type TestWeb3Name = AsciiWeb3Name<Test>;
@@ -321,12 +363,17 @@ pub mod runtime {
 	parameter_types! {
 		pub const MaxNameLength: u32 = 32;
 		pub const MinNameLength: u32 = 3;
+		pub const MaxReservedPatterns: u32 = 5;
+		pub const MaxTextRecords: u32 = 3;
+		pub const MaxTextRecordKeyLength: u32 = 16;
+		pub const MaxTextRecordValueLength: u32 = 32;
 		// Easier to setup insufficient funds for deposit but still above existential deposit
 		pub const Web3NameDeposit: Balance = MICRO_KILT;
 	}
 
 	impl pallet_web3_names::Config for Test {
 		type BanOrigin = TestBanOrigin;
+		type ReserveOrigin = TestBanOrigin;
 		type OwnerOrigin = TestOwnerOrigin;
 		type OriginSuccess = TestOriginSuccess;
 		type Currency = Balances;
@@ -335,10 +382,17 @@ pub mod runtime {
 		type RuntimeEvent = ();
 		type MaxNameLength = MaxNameLength;
 		type MinNameLength = MinNameLength;
+		type MaxReservedPatterns = MaxReservedPatterns;
+		type TextRecordDeposit = Web3NameDeposit;
+		type MaxTextRecords = MaxTextRecords;
+		type MaxTextRecordKeyLength = MaxTextRecordKeyLength;
+		type MaxTextRecordValueLength = MaxTextRecordValueLength;
 		type Web3Name = TestWeb3Name;
 		type Web3NameOwner = TestWeb3NameOwner;
 		type WeightInfo = ();
 		type BalanceMigrationManager = Migration;
+		type DepositIndex = MockDepositIndex;
+		type IdentityChangeHook = ();
 	}
 
 	#[derive(
@@ -412,6 +466,7 @@ pub mod runtime {
 		type SubjectId = TestSubjectId;
 		type WeightInfo = ();
 		type BalanceMigrationManager = Migration;
+		type DepositIndex = MockDepositIndex;
 	}
 
 	pub(crate) type BlockNumber = u64;
@@ -33,7 +33,7 @@ use uid_core::{
 	mock_utils::{generate_base_did_creation_details, generate_base_did_details},
 };
 use frame_support::{
-	assert_ok, assert_storage_noop,
+	assert_noop, assert_ok, assert_storage_noop,
 	traits::{
 		fungible::{Inspect, InspectHold},
 		tokens::{Fortitude, Preservation},
@@ -54,7 +54,7 @@ use public_credentials::{
 use sp_core::{ed25519, sr25519, Pair};
 use sp_runtime::{traits::IdentifyAccount, BoundedVec, MultiSignature, MultiSigner};
 
-use crate::{mock::*, EntriesToMigrate, MigratedKeys, Pallet};
+use crate::{mock::*, DepositsToIndex, EntriesToMigrate, MigratedKeys, Pallet};
 
 #[test]
 fn check_succesful_migration() {
@@ -799,3 +799,83 @@ fn migrate_key_by_update_deposit_verification() {
 			assert_eq!(hold_balance, MICRO_KILT);
 		});
 }
+
+#[test]
+fn index_deposits() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_12);
+	let mut verification = generate_base_verification::<Test>(attester.clone(), ACCOUNT_00);
+	verification.deposit.amount = MICRO_KILT;
+
+	let auth_key = ed25519::Pair::from_seed(&ALICE_SEED);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let mut did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()), Some(ACCOUNT_00));
+	did_details.deposit.amount = MICRO_KILT;
+
+	let web3_name_00 = AsciiWeb3Name::try_from(WEB3_NAME_00_INPUT.to_vec()).expect("W3n name creation should not fail");
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, KILT)])
+		.with_ctypes(vec![(verification.ctype_hash, attester)])
+		.with_verifications(vec![(claim_hash, verification)])
+		.with_dids(vec![(alice_did.clone(), did_details)])
+		.with_connections(vec![(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build()
+		.execute_with(|| {
+			// Simulate deposits taken before the deposit index existed.
+			IndexedDeposits::set(vec![]);
+
+			let deposits = DepositsToIndex {
+				verification: BoundedVec::try_from([claim_hash].to_vec()).expect("Vec init should not fail"),
+				did: BoundedVec::try_from([alice_did.clone()].to_vec()).expect("Vec init should not fail"),
+				lookup: BoundedVec::try_from([LINKABLE_ACCOUNT_00].to_vec()).expect("Vec init should not fail"),
+				w3n: BoundedVec::try_from([web3_name_00.clone()].to_vec()).expect("Vec init should not fail"),
+				..Default::default()
+			};
+			assert_ok!(Migration::index_deposits(
+				RuntimeOrigin::signed(ACCOUNT_00),
+				deposits.clone()
+			));
+
+			let indexed_deposits = IndexedDeposits::get();
+			assert_eq!(indexed_deposits.len(), 4);
+			[
+				Verifications::<Test>::hashed_key_for(claim_hash),
+				uid_core::Did::<Test>::hashed_key_for(&alice_did),
+				ConnectedDids::<Test>::hashed_key_for(LINKABLE_ACCOUNT_00),
+				Owner::<Test>::hashed_key_for(&web3_name_00),
+			]
+			.into_iter()
+			.for_each(|hashed_key| {
+				let (owner, _, amount) = indexed_deposits
+					.iter()
+					.find(|(_, key, _)| *key == hashed_key)
+					.expect("Deposit should be indexed.");
+				assert_eq!(*owner, ACCOUNT_00);
+				assert!(*amount > 0);
+			});
+
+			// Indexing the same deposits again is a no-op.
+			assert_ok!(Migration::index_deposits(RuntimeOrigin::signed(ACCOUNT_00), deposits));
+			assert_eq!(IndexedDeposits::get(), indexed_deposits);
+		});
+}
+
+#[test]
+fn index_deposits_entry_not_found() {
+	let auth_key = ed25519::Pair::from_seed(&ALICE_SEED);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+
+	ExtBuilder::default().build().execute_with(|| {
+		let deposits = DepositsToIndex {
+			did: BoundedVec::try_from([alice_did].to_vec()).expect("Vec init should not fail"),
+			..Default::default()
+		};
+		assert_noop!(
+			Migration::index_deposits(RuntimeOrigin::signed(ACCOUNT_00), deposits),
+			uid_core::Error::<Test>::NotFound
+		);
+	});
+}
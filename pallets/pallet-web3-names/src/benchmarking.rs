@@ -34,8 +34,9 @@ use sp_std::{vec, vec::Vec};
 use kilt_support::{traits::GenerateBenchmarkOrigin, Deposit};
 
 use crate::{
-	mock::insert_raw_w3n, AccountIdOf, Banned, Call, Config, CurrencyOf, Names, Owner, Pallet, Web3NameOf,
-	Web3NameOwnerOf,
+	mock::insert_raw_w3n, AccountIdOf, Assigned, Banned, Call, Config, CurrencyOf, Names, Owner, Pallet, Reserved,
+	ReservedNamePatternOf, ReservedPatterns, TextRecordKeyOf, TextRecordValueOf, TextRecords, TextRecordsCount,
+	Web3NameOf, Web3NameOwnerOf,
 };
 
 const CALLER_SEED: u32 = 0;
@@ -47,7 +48,8 @@ where
 {
 	let balance = <CurrencyOf<T> as Inspect<AccountIdOf<T>>>::minimum_balance()
 		+ <T as Config>::Deposit::get()
-		+ <T as Config>::Deposit::get();
+		+ <T as Config>::Deposit::get()
+		+ <T as Config>::TextRecordDeposit::get();
 	CurrencyOf::<T>::set_balance(account, balance);
 }
 
@@ -55,6 +57,26 @@ fn generate_web3_name_input(length: usize) -> Vec<u8> {
 	vec![b'1'; length]
 }
 
+fn generate_text_record_key<T: Config>(length: usize) -> TextRecordKeyOf<T> {
+	BoundedVec::try_from(vec![b'a'; length]).expect("BoundedVec creation should not fail.")
+}
+
+fn generate_text_record_value<T: Config>(length: usize) -> TextRecordValueOf<T> {
+	BoundedVec::try_from(vec![b'v'; length]).expect("BoundedVec creation should not fail.")
+}
+
+// Fills the reserved patterns with `count` patterns that never match the names
+// generated by `generate_web3_name_input`, so that all of them are checked.
+fn fill_reserved_patterns<T: Config>(count: u32) {
+	let pattern_bytes: BoundedVec<u8, T::MaxNameLength> =
+		BoundedVec::try_from(vec![b'a'; T::MaxNameLength::get().saturated_into()])
+			.expect("BoundedVec creation should not fail.");
+	let patterns = (0..count)
+		.map(|_| ReservedNamePatternOf::<T>::Contains(pattern_bytes.clone()))
+		.collect::<Vec<_>>();
+	ReservedPatterns::<T>::put(BoundedVec::try_from(patterns).expect("BoundedVec creation should not fail."));
+}
+
 benchmarks! {
 	where_clause {
 		where
@@ -67,6 +89,7 @@ benchmarks! {
 
 	claim {
 		let n in (T::MinNameLength::get()) .. (T::MaxNameLength::get());
+		let p in 1 .. (T::MaxReservedPatterns::get());
 		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
 		let owner: Web3NameOwnerOf<T> = account("owner", 0, OWNER_SEED);
 		let web3_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
@@ -74,11 +97,18 @@ benchmarks! {
 		let origin = T::OwnerOrigin::generate_origin(caller.clone(), owner.clone());
 
 		make_free_for_did::<T>(&caller);
+		// The name is only reserved by the last pattern and assigned to the owner, so that all the patterns are checked.
+		fill_reserved_patterns::<T>(p - 1);
+		ReservedPatterns::<T>::mutate(|patterns| {
+			patterns.try_push(ReservedNamePatternOf::<T>::Prefix(web3_name_input.clone())).expect("Should have room for the pattern.");
+		});
+		Pallet::<T>::assign_reserved(RawOrigin::Root.into(), web3_name_input.clone(), owner.clone()).expect("Should assign the reserved web3 name.");
 	}: _<T::RuntimeOrigin>(origin, web3_name_input_clone)
 	verify {
 		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
 		assert!(Names::<T>::get(&owner).is_some());
 		assert!(Owner::<T>::get(&web3_name).is_some());
+		assert!(Assigned::<T>::get(&web3_name).is_none());
 	}
 
 	release_by_owner {
@@ -201,6 +231,168 @@ benchmarks! {
 		});
 	}
 
+	reserve {
+		let n in (T::MinNameLength::get()) .. (T::MaxNameLength::get());
+		let web3_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
+		let web3_name_input_clone = web3_name_input.clone();
+		let reserve_origin = RawOrigin::Root;
+	}: _(reserve_origin, web3_name_input_clone)
+	verify {
+		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
+		assert!(Reserved::<T>::get(&web3_name).is_some());
+	}
+
+	unreserve {
+		let n in (T::MinNameLength::get()) .. (T::MaxNameLength::get());
+		let owner: Web3NameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let web3_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
+		let web3_name_input_clone = web3_name_input.clone();
+		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
+		let reserve_origin = RawOrigin::Root;
+
+		// A name only assigned because of a pattern is the worst case, since both the
+		// reserved names and the assignments are checked.
+		Assigned::<T>::insert(&web3_name, owner);
+	}: _(reserve_origin, web3_name_input_clone)
+	verify {
+		assert!(Reserved::<T>::get(&web3_name).is_none());
+		assert!(Assigned::<T>::get(&web3_name).is_none());
+	}
+
+	reserve_pattern {
+		let p in 1 .. (T::MaxReservedPatterns::get());
+		let pattern_bytes: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let pattern = ReservedNamePatternOf::<T>::Prefix(pattern_bytes);
+		let pattern_clone = pattern.clone();
+		let reserve_origin = RawOrigin::Root;
+
+		// Leave room for the new pattern, which is compared with all the others.
+		fill_reserved_patterns::<T>(p - 1);
+	}: _(reserve_origin, pattern_clone)
+	verify {
+		assert!(ReservedPatterns::<T>::get().contains(&pattern));
+	}
+
+	unreserve_pattern {
+		let p in 1 .. (T::MaxReservedPatterns::get());
+		let pattern_bytes: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let pattern = ReservedNamePatternOf::<T>::Prefix(pattern_bytes);
+		let pattern_clone = pattern.clone();
+		let reserve_origin = RawOrigin::Root;
+
+		// The pattern to remove is the last one, so that all the others are checked first.
+		fill_reserved_patterns::<T>(p - 1);
+		ReservedPatterns::<T>::mutate(|patterns| {
+			patterns.try_push(pattern.clone()).expect("Should have room for the pattern.");
+		});
+	}: _(reserve_origin, pattern_clone)
+	verify {
+		assert!(!ReservedPatterns::<T>::get().contains(&pattern));
+	}
+
+	assign_reserved {
+		let n in (T::MinNameLength::get()) .. (T::MaxNameLength::get());
+		let p in 1 .. (T::MaxReservedPatterns::get());
+		let owner: Web3NameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let web3_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
+		let web3_name_input_clone = web3_name_input.clone();
+		let reserve_origin = RawOrigin::Root;
+
+		// The name is only reserved by the last pattern, so that all the patterns are checked.
+		fill_reserved_patterns::<T>(p - 1);
+		ReservedPatterns::<T>::mutate(|patterns| {
+			patterns.try_push(ReservedNamePatternOf::<T>::Prefix(web3_name_input.clone())).expect("Should have room for the pattern.");
+		});
+	}: _(reserve_origin, web3_name_input_clone, owner.clone())
+	verify {
+		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
+		assert_eq!(Assigned::<T>::get(&web3_name), Some(owner));
+	}
+
+	set_text_record {
+		let k in 1 .. (T::MaxTextRecordKeyLength::get());
+		let v in 0 .. (T::MaxTextRecordValueLength::get());
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: Web3NameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let web3_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let key = generate_text_record_key::<T>(k.saturated_into());
+		let value = generate_text_record_value::<T>(v.saturated_into());
+		let origin = T::OwnerOrigin::generate_origin(caller.clone(), owner.clone());
+
+		make_free_for_did::<T>(&caller);
+		Pallet::<T>::claim(origin.clone(), web3_name_input.clone()).expect("Should register the claimed web3 name.");
+	}: _<T::RuntimeOrigin>(origin, key.clone(), value.clone())
+	verify {
+		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
+		assert_eq!(TextRecords::<T>::get(&web3_name, &key).expect("Text record should exist.").value, value);
+		assert_eq!(TextRecordsCount::<T>::get(&web3_name), 1);
+	}
+
+	remove_text_record {
+		let k in 1 .. (T::MaxTextRecordKeyLength::get());
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: Web3NameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let web3_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let key = generate_text_record_key::<T>(k.saturated_into());
+		let value = generate_text_record_value::<T>(T::MaxTextRecordValueLength::get().saturated_into());
+		let origin = T::OwnerOrigin::generate_origin(caller.clone(), owner.clone());
+
+		make_free_for_did::<T>(&caller);
+		Pallet::<T>::claim(origin.clone(), web3_name_input.clone()).expect("Should register the claimed web3 name.");
+		Pallet::<T>::set_text_record(origin.clone(), key.clone(), value).expect("Should set the text record.");
+	}: _<T::RuntimeOrigin>(origin, key.clone())
+	verify {
+		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
+		assert!(TextRecords::<T>::get(&web3_name, &key).is_none());
+		assert!(!TextRecordsCount::<T>::contains_key(&web3_name));
+	}
+
+	change_text_record_deposit_owner {
+		let deposit_owner_old: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let deposit_owner_new: AccountIdOf<T> = account("caller", 1, CALLER_SEED);
+		let owner: Web3NameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let web3_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let key = generate_text_record_key::<T>(T::MaxTextRecordKeyLength::get().saturated_into());
+		let value = generate_text_record_value::<T>(T::MaxTextRecordValueLength::get().saturated_into());
+		let origin_create = T::OwnerOrigin::generate_origin(deposit_owner_old.clone(), owner.clone());
+
+		make_free_for_did::<T>(&deposit_owner_old);
+		make_free_for_did::<T>(&deposit_owner_new);
+		Pallet::<T>::claim(origin_create.clone(), web3_name_input.clone()).expect("Should register the claimed web3 name.");
+		Pallet::<T>::set_text_record(origin_create, key.clone(), value).expect("Should set the text record.");
+
+		let origin = T::OwnerOrigin::generate_origin(deposit_owner_new.clone(), owner);
+	}: _<T::RuntimeOrigin>(origin, key.clone())
+	verify {
+		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
+		assert_eq!(TextRecords::<T>::get(&web3_name, &key).expect("Text record should exist.").deposit, Deposit {
+			owner: deposit_owner_new,
+			amount: <T as Config>::TextRecordDeposit::get(),
+		});
+	}
+
+	update_text_record_deposit {
+		let deposit_owner: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: Web3NameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let web3_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_web3_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let key = generate_text_record_key::<T>(T::MaxTextRecordKeyLength::get().saturated_into());
+		let value = generate_text_record_value::<T>(T::MaxTextRecordValueLength::get().saturated_into());
+		let origin_create = T::OwnerOrigin::generate_origin(deposit_owner.clone(), owner);
+
+		make_free_for_did::<T>(&deposit_owner);
+		Pallet::<T>::claim(origin_create.clone(), web3_name_input.clone()).expect("Should register the claimed web3 name.");
+		Pallet::<T>::set_text_record(origin_create, key.clone(), value).expect("Should set the text record.");
+
+		let origin = RawOrigin::Signed(deposit_owner.clone());
+	}: _(origin, web3_name_input.clone(), key.clone())
+	verify {
+		let web3_name = Web3NameOf::<T>::try_from(web3_name_input.to_vec()).unwrap();
+		assert_eq!(TextRecords::<T>::get(&web3_name, &key).expect("Text record should exist.").deposit, Deposit {
+			owner: deposit_owner,
+			amount: <T as Config>::TextRecordDeposit::get(),
+		});
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::ExtBuilder::default().build_with_keystore(),
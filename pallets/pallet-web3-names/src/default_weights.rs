@@ -50,13 +50,22 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for pallet_web3_names.
 pub trait WeightInfo {
-	fn claim(n: u32, ) -> Weight;
+	fn claim(n: u32, p: u32, ) -> Weight;
 	fn release_by_owner() -> Weight;
 	fn reclaim_deposit(n: u32, ) -> Weight;
 	fn ban(n: u32, ) -> Weight;
 	fn unban(n: u32, ) -> Weight;
 	fn change_deposit_owner() -> Weight;
 	fn update_deposit() -> Weight;
+	fn reserve(n: u32, ) -> Weight;
+	fn unreserve(n: u32, ) -> Weight;
+	fn reserve_pattern(p: u32, ) -> Weight;
+	fn unreserve_pattern(p: u32, ) -> Weight;
+	fn assign_reserved(n: u32, p: u32, ) -> Weight;
+	fn set_text_record(k: u32, v: u32, ) -> Weight;
+	fn remove_text_record(k: u32, ) -> Weight;
+	fn change_text_record_deposit_owner() -> Weight;
+	fn update_text_record_deposit() -> Weight;
 }
 
 /// Weights for pallet_web3_names using the Substrate node and recommended hardware.
@@ -68,19 +77,29 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	/// Storage: Web3Names Banned (r:1 w:0)
 	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names Reserved (r:2 w:1)
+	/// Proof: Web3Names Reserved (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names ReservedPatterns (r:1 w:0)
+	/// Proof: Web3Names ReservedPatterns (max_values: Some(1), max_size: Some(1701), added: 2196, mode: MaxEncodedLen)
+	/// Storage: Web3Names Assigned (r:1 w:1)
+	/// Proof: Web3Names Assigned (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `n` is `[3, 32]`.
-	fn claim(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `144`
-		//  Estimated: `10299`
-		// Minimum execution time: 19_822 nanoseconds.
-		Weight::from_parts(21_345_315, 10299)
-			// Standard Error: 17_787
+	/// The range of component `p` is `[1, 50]`.
+	fn claim(n: u32, p: u32, ) -> Weight {
+		// Not benchmarked yet with reserved names. Bounded by the benchmarked
+		// `claim` of a name that is not reserved, plus the maximum proof size of
+		// the reserved names, patterns and assignments read, plus an estimated
+		// 5 microseconds for each reserved pattern matched against the name and
+		// its skeleton.
+		Weight::from_parts(21_345_315, 20099)
 			.saturating_add(Weight::from_parts(23_241, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Web3Names Names (r:1 w:1)
 	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
@@ -88,14 +107,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn release_by_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `476`
-		//  Estimated: `7775`
-		// Minimum execution time: 18_311 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(19_202_000, 7775)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Web3Names Owner (r:1 w:1)
 	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
@@ -103,17 +123,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Web3Names Names (r:0 w:1)
 	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `n` is `[3, 32]`.
 	fn reclaim_deposit(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `342 + n * (1 ±0)`
-		//  Estimated: `5219`
-		// Minimum execution time: 16_811 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(18_600_543, 5219)
-			// Standard Error: 15_740
 			.saturating_add(Weight::from_parts(12_826, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Web3Names Banned (r:1 w:1)
 	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
@@ -123,17 +143,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Web3Names Names (r:0 w:1)
 	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `n` is `[3, 32]`.
 	fn ban(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `342 + n * (1 ±0)`
-		//  Estimated: `7743`
-		// Minimum execution time: 18_069 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(19_324_271, 7743)
-			// Standard Error: 12_698
 			.saturating_add(Weight::from_parts(62_295, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Web3Names Banned (r:1 w:1)
 	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
@@ -155,28 +175,182 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `889`
-		//  Estimated: `10382`
-		// Minimum execution time: 25_771 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(26_681_000, 10382)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Web3Names Owner (r:1 w:1)
 	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `374`
-		//  Estimated: `5219`
-		// Minimum execution time: 22_468 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(23_034_000, 5219)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Web3Names Reserved (r:1 w:1)
+	/// Proof: Web3Names Reserved (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names Owner (r:1 w:0)
+	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// The range of component `n` is `[3, 32]`.
+	fn reserve(n: u32, ) -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked `unban`,
+		// which also writes a single name to a map, plus the maximum proof size of
+		// the storage items read.
+		Weight::from_parts(8_240_564, 7660)
+			.saturating_add(Weight::from_parts(47_283, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Web3Names Reserved (r:1 w:1)
+	/// Proof: Web3Names Reserved (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names Assigned (r:1 w:1)
+	/// Proof: Web3Names Assigned (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// The range of component `n` is `[3, 32]`.
+	fn unreserve(n: u32, ) -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked `unban`,
+		// which also removes a single name from a map, plus the maximum proof size
+		// of the storage items read.
+		Weight::from_parts(8_240_564, 7604)
+			.saturating_add(Weight::from_parts(47_283, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: Web3Names ReservedPatterns (r:1 w:1)
+	/// Proof: Web3Names ReservedPatterns (max_values: Some(1), max_size: Some(1701), added: 2196, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 50]`.
+	fn reserve_pattern(p: u32, ) -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked `unban`,
+		// plus the maximum proof size of the reserved patterns, plus an estimated
+		// 5 microseconds for each reserved pattern compared with the given one.
+		Weight::from_parts(8_240_564, 4720)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Web3Names ReservedPatterns (r:1 w:1)
+	/// Proof: Web3Names ReservedPatterns (max_values: Some(1), max_size: Some(1701), added: 2196, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 50]`.
+	fn unreserve_pattern(p: u32, ) -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked `unban`,
+		// plus the maximum proof size of the reserved patterns, plus an estimated
+		// 5 microseconds for each reserved pattern compared with the given one.
+		Weight::from_parts(8_240_564, 4720)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Web3Names Reserved (r:2 w:0)
+	/// Proof: Web3Names Reserved (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names ReservedPatterns (r:1 w:0)
+	/// Proof: Web3Names ReservedPatterns (max_values: Some(1), max_size: Some(1701), added: 2196, mode: MaxEncodedLen)
+	/// Storage: Web3Names Names (r:1 w:0)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names Owner (r:1 w:0)
+	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Web3Names Banned (r:1 w:0)
+	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names Assigned (r:0 w:1)
+	/// Proof: Web3Names Assigned (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// The range of component `n` is `[3, 32]`.
+	/// The range of component `p` is `[1, 50]`.
+	fn assign_reserved(n: u32, p: u32, ) -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked `claim`,
+		// which checks the same names and also holds a deposit, plus the maximum
+		// proof size of the storage items read, plus an estimated 5 microseconds
+		// for each reserved pattern matched against the name and its skeleton.
+		Weight::from_parts(21_345_315, 14936)
+			.saturating_add(Weight::from_parts(23_241, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Web3Names Names (r:1 w:0)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names TextRecords (r:1 w:1)
+	/// Proof: Web3Names TextRecords (max_values: None, max_size: Some(404), added: 2879, mode: MaxEncodedLen)
+	/// Storage: Web3Names TextRecordsCount (r:1 w:1)
+	/// Proof: Web3Names TextRecordsCount (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `k` is `[1, 32]`.
+	/// The range of component `v` is `[0, 256]`.
+	fn set_text_record(k: u32, v: u32, ) -> Weight {
+		// Not benchmarked yet. The base is bounded by the benchmarked `claim`, which
+		// also holds and indexes a deposit, and the proof size by the maximum size
+		// of the storage items read. The cost of each byte of the key and the value
+		// is estimated.
+		Weight::from_parts(21_345_315, 10570)
+			.saturating_add(Weight::from_parts(25_000, 0).saturating_mul(k.into()))
+			.saturating_add(Weight::from_parts(25_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Web3Names Names (r:1 w:0)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names TextRecords (r:1 w:1)
+	/// Proof: Web3Names TextRecords (max_values: None, max_size: Some(404), added: 2879, mode: MaxEncodedLen)
+	/// Storage: Web3Names TextRecordsCount (r:1 w:1)
+	/// Proof: Web3Names TextRecordsCount (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `k` is `[1, 32]`.
+	fn remove_text_record(k: u32, ) -> Weight {
+		// Not benchmarked yet. The base is bounded by the benchmarked
+		// `release_by_owner`, which also releases and unindexes a deposit, plus the
+		// estimated cost of decoding the largest value, and the proof size by the
+		// maximum size of the storage items read. The cost of each byte of the key
+		// is estimated.
+		Weight::from_parts(25_602_000, 10570)
+			.saturating_add(Weight::from_parts(25_000, 0).saturating_mul(k.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Web3Names Names (r:1 w:0)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names TextRecords (r:1 w:1)
+	/// Proof: Web3Names TextRecords (max_values: None, max_size: Some(404), added: 2879, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	fn change_text_record_deposit_owner() -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked `change_deposit_owner`,
+		// which changes the owner of the deposit of a name in the same way, plus the
+		// maximum proof size of the text record read.
+		Weight::from_parts(26_681_000, 13261)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Web3Names TextRecords (r:1 w:1)
+	/// Proof: Web3Names TextRecords (max_values: None, max_size: Some(404), added: 2879, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	fn update_text_record_deposit() -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked `update_deposit`, which
+		// updates the deposit of a name in the same way, plus the maximum proof size
+		// of the text record read.
+		Weight::from_parts(23_034_000, 8098)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -187,19 +361,29 @@ impl WeightInfo for () {
 	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	/// Storage: Web3Names Banned (r:1 w:0)
 	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names Reserved (r:2 w:1)
+	/// Proof: Web3Names Reserved (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names ReservedPatterns (r:1 w:0)
+	/// Proof: Web3Names ReservedPatterns (max_values: Some(1), max_size: Some(1701), added: 2196, mode: MaxEncodedLen)
+	/// Storage: Web3Names Assigned (r:1 w:1)
+	/// Proof: Web3Names Assigned (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `n` is `[3, 32]`.
-	fn claim(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `144`
-		//  Estimated: `10299`
-		// Minimum execution time: 19_822 nanoseconds.
-		Weight::from_parts(21_345_315, 10299)
-			// Standard Error: 17_787
+	/// The range of component `p` is `[1, 50]`.
+	fn claim(n: u32, p: u32, ) -> Weight {
+		// Not benchmarked yet with reserved names. Bounded by the benchmarked
+		// `claim` of a name that is not reserved, plus the maximum proof size of
+		// the reserved names, patterns and assignments read, plus an estimated
+		// 5 microseconds for each reserved pattern matched against the name and
+		// its skeleton.
+		Weight::from_parts(21_345_315, 20099)
 			.saturating_add(Weight::from_parts(23_241, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Web3Names Names (r:1 w:1)
 	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
@@ -207,14 +391,15 @@ impl WeightInfo for () {
 	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn release_by_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `476`
-		//  Estimated: `7775`
-		// Minimum execution time: 18_311 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(19_202_000, 7775)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: Web3Names Owner (r:1 w:1)
 	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
@@ -222,17 +407,17 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Web3Names Names (r:0 w:1)
 	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `n` is `[3, 32]`.
 	fn reclaim_deposit(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `342 + n * (1 ±0)`
-		//  Estimated: `5219`
-		// Minimum execution time: 16_811 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(18_600_543, 5219)
-			// Standard Error: 15_740
 			.saturating_add(Weight::from_parts(12_826, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: Web3Names Banned (r:1 w:1)
 	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
@@ -242,17 +427,17 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: Web3Names Names (r:0 w:1)
 	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `n` is `[3, 32]`.
 	fn ban(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `342 + n * (1 ±0)`
-		//  Estimated: `7743`
-		// Minimum execution time: 18_069 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(19_324_271, 7743)
-			// Standard Error: 12_698
 			.saturating_add(Weight::from_parts(62_295, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Web3Names Banned (r:1 w:1)
 	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
@@ -274,26 +459,180 @@ impl WeightInfo for () {
 	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `889`
-		//  Estimated: `10382`
-		// Minimum execution time: 25_771 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(26_681_000, 10382)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Web3Names Owner (r:1 w:1)
 	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `374`
-		//  Estimated: `5219`
-		// Minimum execution time: 22_468 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(23_034_000, 5219)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Web3Names Reserved (r:1 w:1)
+	/// Proof: Web3Names Reserved (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names Owner (r:1 w:0)
+	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// The range of component `n` is `[3, 32]`.
+	fn reserve(n: u32, ) -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked `unban`,
+		// which also writes a single name to a map, plus the maximum proof size of
+		// the storage items read.
+		Weight::from_parts(8_240_564, 7660)
+			.saturating_add(Weight::from_parts(47_283, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Web3Names Reserved (r:1 w:1)
+	/// Proof: Web3Names Reserved (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names Assigned (r:1 w:1)
+	/// Proof: Web3Names Assigned (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// The range of component `n` is `[3, 32]`.
+	fn unreserve(n: u32, ) -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked `unban`,
+		// which also removes a single name from a map, plus the maximum proof size
+		// of the storage items read.
+		Weight::from_parts(8_240_564, 7604)
+			.saturating_add(Weight::from_parts(47_283, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: Web3Names ReservedPatterns (r:1 w:1)
+	/// Proof: Web3Names ReservedPatterns (max_values: Some(1), max_size: Some(1701), added: 2196, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 50]`.
+	fn reserve_pattern(p: u32, ) -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked `unban`,
+		// plus the maximum proof size of the reserved patterns, plus an estimated
+		// 5 microseconds for each reserved pattern compared with the given one.
+		Weight::from_parts(8_240_564, 4720)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Web3Names ReservedPatterns (r:1 w:1)
+	/// Proof: Web3Names ReservedPatterns (max_values: Some(1), max_size: Some(1701), added: 2196, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 50]`.
+	fn unreserve_pattern(p: u32, ) -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked `unban`,
+		// plus the maximum proof size of the reserved patterns, plus an estimated
+		// 5 microseconds for each reserved pattern compared with the given one.
+		Weight::from_parts(8_240_564, 4720)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Web3Names Reserved (r:2 w:0)
+	/// Proof: Web3Names Reserved (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names ReservedPatterns (r:1 w:0)
+	/// Proof: Web3Names ReservedPatterns (max_values: Some(1), max_size: Some(1701), added: 2196, mode: MaxEncodedLen)
+	/// Storage: Web3Names Names (r:1 w:0)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names Owner (r:1 w:0)
+	/// Proof: Web3Names Owner (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Web3Names Banned (r:1 w:0)
+	/// Proof: Web3Names Banned (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Web3Names Assigned (r:0 w:1)
+	/// Proof: Web3Names Assigned (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// The range of component `n` is `[3, 32]`.
+	/// The range of component `p` is `[1, 50]`.
+	fn assign_reserved(n: u32, p: u32, ) -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked `claim`,
+		// which checks the same names and also holds a deposit, plus the maximum
+		// proof size of the storage items read, plus an estimated 5 microseconds
+		// for each reserved pattern matched against the name and its skeleton.
+		Weight::from_parts(21_345_315, 14936)
+			.saturating_add(Weight::from_parts(23_241, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Web3Names Names (r:1 w:0)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names TextRecords (r:1 w:1)
+	/// Proof: Web3Names TextRecords (max_values: None, max_size: Some(404), added: 2879, mode: MaxEncodedLen)
+	/// Storage: Web3Names TextRecordsCount (r:1 w:1)
+	/// Proof: Web3Names TextRecordsCount (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `k` is `[1, 32]`.
+	/// The range of component `v` is `[0, 256]`.
+	fn set_text_record(k: u32, v: u32, ) -> Weight {
+		// Not benchmarked yet. The base is bounded by the benchmarked `claim`, which
+		// also holds and indexes a deposit, and the proof size by the maximum size
+		// of the storage items read. The cost of each byte of the key and the value
+		// is estimated.
+		Weight::from_parts(21_345_315, 10570)
+			.saturating_add(Weight::from_parts(25_000, 0).saturating_mul(k.into()))
+			.saturating_add(Weight::from_parts(25_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Web3Names Names (r:1 w:0)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names TextRecords (r:1 w:1)
+	/// Proof: Web3Names TextRecords (max_values: None, max_size: Some(404), added: 2879, mode: MaxEncodedLen)
+	/// Storage: Web3Names TextRecordsCount (r:1 w:1)
+	/// Proof: Web3Names TextRecordsCount (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `k` is `[1, 32]`.
+	fn remove_text_record(k: u32, ) -> Weight {
+		// Not benchmarked yet. The base is bounded by the benchmarked
+		// `release_by_owner`, which also releases and unindexes a deposit, plus the
+		// estimated cost of decoding the largest value, and the proof size by the
+		// maximum size of the storage items read. The cost of each byte of the key
+		// is estimated.
+		Weight::from_parts(25_602_000, 10570)
+			.saturating_add(Weight::from_parts(25_000, 0).saturating_mul(k.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Web3Names Names (r:1 w:0)
+	/// Proof: Web3Names Names (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Web3Names TextRecords (r:1 w:1)
+	/// Proof: Web3Names TextRecords (max_values: None, max_size: Some(404), added: 2879, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	fn change_text_record_deposit_owner() -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked `change_deposit_owner`,
+		// which changes the owner of the deposit of a name in the same way, plus the
+		// maximum proof size of the text record read.
+		Weight::from_parts(26_681_000, 13261)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Web3Names TextRecords (r:1 w:1)
+	/// Proof: Web3Names TextRecords (max_values: None, max_size: Some(404), added: 2879, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	fn update_text_record_deposit() -> Weight {
+		// Not benchmarked yet. Bounded by the benchmarked `update_deposit`, which
+		// updates the deposit of a name in the same way, plus the maximum proof size
+		// of the text record read.
+		Weight::from_parts(23_034_000, 8098)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
@@ -0,0 +1,508 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Support for internationalised web3 names.
+//!
+//! Internationalised names are stored on chain in their ASCII-compatible
+//! encoding (ACE), i.e., the `xn--` prefix followed by the punycode
+//! encoding (RFC 3492) of the Unicode name. The validation performed here is a
+//! conservative subset of IDNA2008: only lowercase letters from a fixed set of
+//! scripts are allowed, a name cannot mix scripts (except for the script
+//! combinations used by Japanese and Korean), and names written entirely with
+//! Cyrillic or Greek letters that look like Latin ones are rejected to prevent
+//! homoglyph attacks.
+
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+
+/// The prefix of names in ASCII-compatible encoding.
+pub const ACE_PREFIX: &[u8] = b"xn--";
+
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+const DELIMITER: u8 = b'-';
+
+/// The scripts that internationalised names can be written with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Script {
+	/// Digits, `-` and `_`, which can be used with any other script.
+	Common,
+	Latin,
+	Greek,
+	Cyrillic,
+	Arabic,
+	Devanagari,
+	Thai,
+	Javanese,
+	Hiragana,
+	Katakana,
+	Han,
+	Hangul,
+}
+
+/// Returns the script of an allowed character, or `None` if the character
+/// cannot be part of a web3 name.
+fn script_of(c: char) -> Option<Script> {
+	match c {
+		'0'..='9' | '-' | '_' => Some(Script::Common),
+		'a'..='z' => Some(Script::Latin),
+		// Latin-1 Supplement, Latin Extended-A and B, Latin Extended Additional.
+		'\u{00DF}'..='\u{00F6}' | '\u{00F8}'..='\u{00FF}' => Some(Script::Latin),
+		'\u{0100}'..='\u{024F}' | '\u{1E00}'..='\u{1EFF}' if c.is_lowercase() => Some(Script::Latin),
+		'\u{03AC}'..='\u{03CE}' => Some(Script::Greek),
+		'\u{0430}'..='\u{045F}' => Some(Script::Cyrillic),
+		'\u{0620}'..='\u{064A}' | '\u{0660}'..='\u{0669}' => Some(Script::Arabic),
+		'\u{0900}'..='\u{0963}' | '\u{0966}'..='\u{097F}' => Some(Script::Devanagari),
+		'\u{0E01}'..='\u{0E3A}' | '\u{0E40}'..='\u{0E4E}' | '\u{0E50}'..='\u{0E59}' => Some(Script::Thai),
+		'\u{A980}'..='\u{A9C0}' | '\u{A9D0}'..='\u{A9D9}' => Some(Script::Javanese),
+		'\u{3041}'..='\u{3096}' | '\u{309D}'..='\u{309F}' => Some(Script::Hiragana),
+		'\u{30A1}'..='\u{30FA}' | '\u{30FC}'..='\u{30FF}' => Some(Script::Katakana),
+		'\u{4E00}'..='\u{9FFF}' => Some(Script::Han),
+		'\u{AC00}'..='\u{D7A3}' => Some(Script::Hangul),
+		_ => None,
+	}
+}
+
+/// Returns `true` if the character is a Cyrillic or Greek letter that can be
+/// confused with a Latin one.
+fn is_latin_confusable(c: char) -> bool {
+	matches!(
+		c,
+		// Cyrillic
+		'а' | 'е' | 'о' | 'р' | 'с' | 'у' | 'х' | 'і' | 'ј' | 'ѕ' | 'һ' | 'ԁ' | 'ӏ'
+		// Greek
+		| 'α' | 'ι' | 'κ' | 'ν' | 'ο' | 'ρ' | 'υ'
+	)
+}
+
+/// Returns `true` if the provided set of scripts can be used together in the
+/// same name.
+fn is_allowed_script_combination(scripts: &BTreeSet<Script>) -> bool {
+	if scripts.len() <= 1 {
+		return true;
+	}
+	let is_japanese = scripts
+		.iter()
+		.all(|s| matches!(s, Script::Han | Script::Hiragana | Script::Katakana));
+	let is_korean = scripts.iter().all(|s| matches!(s, Script::Han | Script::Hangul));
+	is_japanese || is_korean
+}
+
+/// Verify that the provided Unicode characters can be used as an
+/// internationalised web3 name.
+fn is_valid_unicode_name(chars: &[char]) -> bool {
+	// Names without any non-ASCII character must not be encoded.
+	if chars.iter().all(char::is_ascii) {
+		return false;
+	}
+	let mut scripts = BTreeSet::new();
+	for c in chars {
+		match script_of(*c) {
+			Some(Script::Common) => {}
+			Some(script) => {
+				scripts.insert(script);
+			}
+			None => return false,
+		}
+	}
+	if !is_allowed_script_combination(&scripts) {
+		return false;
+	}
+	// Reject names that are entirely made of letters that look like Latin ones,
+	// e.g., Cyrillic "аре" which would be displayed like the Latin "ape".
+	let is_whole_script_confusable = scripts.iter().all(|s| matches!(s, Script::Cyrillic | Script::Greek))
+		&& chars
+			.iter()
+			.filter(|c| script_of(**c) != Some(Script::Common))
+			.all(|c| is_latin_confusable(*c));
+	!is_whole_script_confusable
+}
+
+/// Verify that the provided input is the canonical ASCII-compatible encoding
+/// of a valid internationalised web3 name.
+pub fn is_valid_ace_name(input: &[u8]) -> bool {
+	let Some(encoded) = input.strip_prefix(ACE_PREFIX) else {
+		return false;
+	};
+	let Some(decoded) = punycode_decode(encoded) else {
+		return false;
+	};
+	if !is_valid_unicode_name(&decoded) {
+		return false;
+	}
+	// Only the canonical encoding is accepted, so that each Unicode name maps to
+	// exactly one stored name.
+	punycode_encode(&decoded).as_deref() == Some(encoded)
+}
+
+/// Return the UTF-8 encoded form of a name to be shown to users.
+///
+/// Names in ASCII-compatible encoding are decoded, while all other names are
+/// returned unchanged. Returns `None` if the name starts with the ACE prefix
+/// but is not valid punycode.
+pub fn to_display_form(name: &[u8]) -> Option<Vec<u8>> {
+	let Some(encoded) = name.strip_prefix(ACE_PREFIX) else {
+		return Some(name.to_vec());
+	};
+	let decoded = punycode_decode(encoded)?;
+	let mut output = Vec::with_capacity(decoded.len());
+	let mut buffer = [0u8; 4];
+	for c in decoded {
+		output.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+	}
+	Some(output)
+}
+
+/// Return the ASCII form of a UTF-8 encoded name, i.e., the form that is
+/// stored on chain.
+///
+/// Names only containing ASCII characters are returned unchanged, while all
+/// other names are converted to their ASCII-compatible encoding. Returns
+/// `None` if the input is not valid UTF-8.
+pub fn to_ascii_form(name: &[u8]) -> Option<Vec<u8>> {
+	if name.is_ascii() {
+		return Some(name.to_vec());
+	}
+	let chars = core::str::from_utf8(name).ok()?.chars().collect::<Vec<_>>();
+	let encoded = punycode_encode(&chars)?;
+	Some([ACE_PREFIX, &encoded[..]].concat())
+}
+
+/// The Latin letters that the characters of the Latin Extended-A block
+/// (U+0100 to U+017F) look like, or `0` if a character has no single-letter
+/// lookalike.
+const LATIN_EXTENDED_A_SKELETONS: &[u8; 128] = b"\
+aaaaaaccccccccddddeeeeeeeeeegggggggghhhhiiiiiiiiii\0\0jjkkklllllll\
+lllnnnnnnnnnoooooo\0\0rrrrrrssssssssttttttuuuuuuuuuuuuwwyyyzzzzzz\0";
+
+/// Returns the Latin letter that the provided character can be confused
+/// with, if any.
+fn latin_lookalike(c: char) -> Option<char> {
+	let lookalike = match c {
+		// Latin-1 Supplement
+		'à'..='å' => 'a',
+		'ç' => 'c',
+		'è'..='ë' => 'e',
+		'ì'..='ï' => 'i',
+		'ð' => 'd',
+		'ñ' => 'n',
+		'ò'..='ö' | 'ø' => 'o',
+		'ù'..='ü' => 'u',
+		'ý' | 'ÿ' => 'y',
+		// Latin Extended-A
+		'\u{0100}'..='\u{017F}' => match LATIN_EXTENDED_A_SKELETONS[c as usize - 0x0100] {
+			0 => return None,
+			letter => char::from(letter),
+		},
+		// Greek
+		'α' | 'ά' => 'a',
+		'ε' | 'έ' => 'e',
+		'ι' | 'ί' | 'ϊ' | 'ΐ' => 'i',
+		'κ' => 'k',
+		'ν' => 'v',
+		'ο' | 'ό' => 'o',
+		'ρ' => 'p',
+		'τ' => 't',
+		'υ' | 'ύ' | 'ϋ' | 'ΰ' => 'u',
+		'χ' => 'x',
+		// Cyrillic
+		'а' => 'a',
+		'в' => 'b',
+		'е' | 'ё' => 'e',
+		'і' | 'ї' => 'i',
+		'ј' => 'j',
+		'к' => 'k',
+		'м' => 'm',
+		'н' => 'h',
+		'о' => 'o',
+		'р' => 'p',
+		'с' => 'c',
+		'т' => 't',
+		'у' => 'y',
+		'х' => 'x',
+		'ѕ' => 's',
+		'һ' => 'h',
+		_ => return None,
+	};
+	Some(lookalike)
+}
+
+/// Return the skeleton of the provided text, by replacing each character
+/// with the Latin letter it can be confused with. Characters without a
+/// lookalike are kept unchanged, UTF-8 encoded. Returns the input unchanged
+/// if it is not valid UTF-8.
+pub fn text_skeleton(text: &[u8]) -> Vec<u8> {
+	match core::str::from_utf8(text) {
+		Ok(text) => chars_skeleton(text.chars()),
+		Err(_) => text.to_vec(),
+	}
+}
+
+/// Return the skeleton of a name, i.e., the form used to detect names that
+/// look like each other.
+///
+/// Names in ASCII-compatible encoding are decoded first, so that, e.g.,
+/// `xn--mnchen-3ya` ("münchen") has the same skeleton as `munchen`, after
+/// its characters are replaced as in [`text_skeleton`].
+pub fn skeleton(name: &[u8]) -> Vec<u8> {
+	match name.strip_prefix(ACE_PREFIX).and_then(punycode_decode) {
+		Some(decoded) => chars_skeleton(decoded.into_iter()),
+		None => text_skeleton(name),
+	}
+}
+
+fn chars_skeleton(chars: impl Iterator<Item = char>) -> Vec<u8> {
+	let mut output = Vec::new();
+	let mut buffer = [0u8; 4];
+	for c in chars {
+		let c = latin_lookalike(c).unwrap_or(c);
+		output.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+	}
+	output
+}
+
+fn threshold(k: u32, bias: u32) -> u32 {
+	if k <= bias {
+		T_MIN
+	} else if k >= bias + T_MAX {
+		T_MAX
+	} else {
+		k - bias
+	}
+}
+
+fn adapt(delta: u32, num_points: u32, is_first_time: bool) -> u32 {
+	let mut delta = if is_first_time { delta / DAMP } else { delta / 2 };
+	delta += delta / num_points;
+	let mut k = 0;
+	while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+		delta /= BASE - T_MIN;
+		k += BASE;
+	}
+	k + (((BASE - T_MIN + 1) * delta) / (delta + SKEW))
+}
+
+// Only lowercase digits are accepted, as they are the only ones produced by
+// the encoder.
+fn decode_digit(c: u8) -> Option<u32> {
+	match c {
+		b'a'..=b'z' => Some(u32::from(c - b'a')),
+		b'0'..=b'9' => Some(u32::from(c - b'0') + 26),
+		_ => None,
+	}
+}
+
+fn encode_digit(d: u32) -> u8 {
+	debug_assert!(d < BASE);
+	if d < 26 {
+		b'a' + d as u8
+	} else {
+		b'0' + (d - 26) as u8
+	}
+}
+
+/// Decode a punycode string (without the ACE prefix) into Unicode characters.
+pub fn punycode_decode(input: &[u8]) -> Option<Vec<char>> {
+	let (basic, extended) = match input.iter().rposition(|c| *c == DELIMITER) {
+		Some(position) => (&input[..position], &input[position + 1..]),
+		None => (&[][..], input),
+	};
+	if !basic.is_ascii() {
+		return None;
+	}
+	let mut output = basic.iter().map(|c| u32::from(*c)).collect::<Vec<_>>();
+
+	let mut n = INITIAL_N;
+	let mut i: u32 = 0;
+	let mut bias = INITIAL_BIAS;
+	let mut extended = extended.iter();
+	while !extended.as_slice().is_empty() {
+		let old_i = i;
+		let mut weight: u32 = 1;
+		let mut k = BASE;
+		loop {
+			let digit = decode_digit(*extended.next()?)?;
+			i = i.checked_add(digit.checked_mul(weight)?)?;
+			let t = threshold(k, bias);
+			if digit < t {
+				break;
+			}
+			weight = weight.checked_mul(BASE - t)?;
+			k = k.checked_add(BASE)?;
+		}
+		let length = u32::try_from(output.len()).ok()?.checked_add(1)?;
+		bias = adapt(i - old_i, length, old_i == 0);
+		n = n.checked_add(i / length)?;
+		i %= length;
+		// Basic code points must be encoded literally.
+		if n < INITIAL_N {
+			return None;
+		}
+		output.insert(i as usize, n);
+		i += 1;
+	}
+
+	output.into_iter().map(char::from_u32).collect()
+}
+
+/// Encode Unicode characters into a punycode string (without the ACE prefix).
+pub fn punycode_encode(input: &[char]) -> Option<Vec<u8>> {
+	let mut output = input
+		.iter()
+		.filter(|c| c.is_ascii())
+		.map(|c| *c as u8)
+		.collect::<Vec<_>>();
+	let basic_length = u32::try_from(output.len()).ok()?;
+	let input_length = u32::try_from(input.len()).ok()?;
+	if basic_length > 0 {
+		output.push(DELIMITER);
+	}
+
+	let mut n = INITIAL_N;
+	let mut delta: u32 = 0;
+	let mut bias = INITIAL_BIAS;
+	let mut handled = basic_length;
+	while handled < input_length {
+		let m = input.iter().map(|c| u32::from(*c)).filter(|c| *c >= n).min()?;
+		delta = delta.checked_add((m - n).checked_mul(handled + 1)?)?;
+		n = m;
+		for c in input.iter().map(|c| u32::from(*c)) {
+			if c < n {
+				delta = delta.checked_add(1)?;
+			}
+			if c == n {
+				let mut q = delta;
+				let mut k = BASE;
+				loop {
+					let t = threshold(k, bias);
+					if q < t {
+						break;
+					}
+					output.push(encode_digit(t + (q - t) % (BASE - t)));
+					q = (q - t) / (BASE - t);
+					k += BASE;
+				}
+				output.push(encode_digit(q));
+				bias = adapt(delta, handled + 1, handled == basic_length);
+				delta = 0;
+				handled += 1;
+			}
+		}
+		delta = delta.checked_add(1)?;
+		n = n.checked_add(1)?;
+	}
+
+	Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::idna::*;
+
+	fn chars(input: &str) -> Vec<char> {
+		input.chars().collect()
+	}
+
+	#[test]
+	fn punycode_round_trip() {
+		let vectors = [
+			("münchen", "mnchen-3ya"),
+			("bücher", "bcher-kva"),
+			("пример", "e1afmkfd"),
+			("日本", "wgv71a"),
+			("bükü_123", "bk_123-3yab"),
+		];
+		for (unicode, encoded) in vectors {
+			assert_eq!(punycode_encode(&chars(unicode)), Some(encoded.as_bytes().to_vec()));
+			assert_eq!(punycode_decode(encoded.as_bytes()), Some(chars(unicode)));
+		}
+	}
+
+	#[test]
+	fn punycode_decode_invalid() {
+		// Uppercase digits are not accepted.
+		assert!(punycode_decode(b"MNCHEN-3YA").is_none());
+		// Truncated input.
+		assert!(punycode_decode(b"mnchen-3").is_none());
+		// Invalid digit.
+		assert!(punycode_decode(b"mnchen-3y!").is_none());
+	}
+
+	#[test]
+	fn ace_names() {
+		let valid_names = [
+			// Latin with diacritics
+			"münchen",
+			// Cyrillic
+			"пример",
+			// Japanese mixing Han and Hiragana
+			"日本ご",
+			// Korean
+			"한국",
+			// Common characters are allowed with any script
+			"ελλάδα_2024",
+		];
+		for name in valid_names {
+			let ascii = to_ascii_form(name.as_bytes()).unwrap();
+			assert!(ascii.starts_with(ACE_PREFIX));
+			assert!(is_valid_ace_name(&ascii), "{name} should be valid");
+			assert_eq!(to_display_form(&ascii), Some(name.as_bytes().to_vec()));
+		}
+
+		let invalid_names = [
+			("pаypal", "mixed Latin and Cyrillic"),
+			("аре", "whole-script confusable"),
+			("München", "uppercase"),
+			("bücher!", "disallowed symbol"),
+			("web3😂", "emoji"),
+		];
+		for (name, reason) in invalid_names {
+			let ascii = to_ascii_form(name.as_bytes()).unwrap();
+			assert!(!is_valid_ace_name(&ascii), "{name} should be invalid: {reason}");
+		}
+
+		// ASCII-only names must not be encoded.
+		assert!(!is_valid_ace_name(b"xn--abc-"));
+		// Non-canonical encodings are rejected.
+		assert!(!is_valid_ace_name(b"xn--MNCHEN-3YA"));
+		// Not in ACE form.
+		assert!(!is_valid_ace_name(b"mnchen-3ya"));
+	}
+
+	#[test]
+	fn skeletons() {
+		assert_eq!(skeleton(b"xn--mnchen-3ya"), b"munchen".to_vec());
+		assert_eq!(skeleton(b"web3_name"), b"web3_name".to_vec());
+		// Cyrillic letters mixed with a Latin "l"
+		assert_eq!(text_skeleton("раураl".as_bytes()), b"paypal".to_vec());
+		// Characters without a Latin lookalike are kept
+		assert_eq!(skeleton(b"xn--wgv71a"), "日本".as_bytes().to_vec());
+		// Invalid punycode is treated as plain text
+		assert_eq!(skeleton(b"xn--MNCHEN"), b"xn--MNCHEN".to_vec());
+	}
+
+	#[test]
+	fn ascii_names_are_unchanged() {
+		assert_eq!(to_ascii_form(b"web3_name"), Some(b"web3_name".to_vec()));
+		assert_eq!(to_display_form(b"web3_name"), Some(b"web3_name".to_vec()));
+	}
+}
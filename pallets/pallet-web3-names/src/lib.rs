@@ -23,7 +23,10 @@
 
 mod default_weights;
 
+pub mod idna;
 pub mod migrations;
+pub mod reservation;
+pub mod text_record;
 pub mod web3_name;
 
 #[cfg(any(test, feature = "runtime-benchmarks"))]
@@ -56,12 +59,17 @@ pub mod pallet {
 	use sp_std::{fmt::Debug, vec::Vec};
 
 	use kilt_support::{
-		traits::{BalanceMigrationManager, CallSources, StorageDepositCollector},
+		traits::{BalanceMigrationManager, CallSources, DepositIndex, OnIdentityChange, StorageDepositCollector},
 		Deposit,
 	};
 
 	use super::WeightInfo;
-	use crate::web3_name::Web3NameOwnership;
+	use crate::{
+		idna,
+		reservation::ReservedNamePattern,
+		text_record::{is_valid_text_record_key, TextRecord},
+		web3_name::Web3NameOwnership,
+	};
 
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
@@ -71,6 +79,11 @@ pub mod pallet {
 	pub type Web3NameOf<T> = <T as Config>::Web3Name;
 	pub type Web3OwnershipOf<T> =
 		Web3NameOwnership<Web3NameOwnerOf<T>, Deposit<AccountIdOf<T>, BalanceOf<T>>, BlockNumberFor<T>>;
+	pub type ReservedNamePatternOf<T> = ReservedNamePattern<<T as Config>::MaxNameLength>;
+	pub type ReservedNamePatternsOf<T> = BoundedVec<ReservedNamePatternOf<T>, <T as Config>::MaxReservedPatterns>;
+	pub type TextRecordKeyOf<T> = BoundedVec<u8, <T as Config>::MaxTextRecordKeyLength>;
+	pub type TextRecordValueOf<T> = BoundedVec<u8, <T as Config>::MaxTextRecordValueLength>;
+	pub type TextRecordOf<T> = TextRecord<TextRecordValueOf<T>, Deposit<AccountIdOf<T>, BalanceOf<T>>>;
 
 	pub(crate) type BalanceMigrationManagerOf<T> = <T as Config>::BalanceMigrationManager;
 	pub(crate) type CurrencyOf<T> = <T as Config>::Currency;
@@ -97,6 +110,38 @@ pub mod pallet {
 	#[pallet::getter(fn is_banned)]
 	pub type Banned<T> = StorageMap<_, Blake2_128Concat, Web3NameOf<T>, ()>;
 
+	/// Map of name -> ().
+	///
+	/// If a name key is present, the name is currently reserved and can only
+	/// be assigned by the reserve origin.
+	#[pallet::storage]
+	#[pallet::getter(fn is_reserved)]
+	pub type Reserved<T> = StorageMap<_, Blake2_128Concat, Web3NameOf<T>, ()>;
+
+	/// List of patterns reserving all the names that match any of them.
+	#[pallet::storage]
+	#[pallet::getter(fn reserved_patterns)]
+	pub type ReservedPatterns<T> = StorageValue<_, ReservedNamePatternsOf<T>, ValueQuery>;
+
+	/// Map of name -> owner.
+	///
+	/// If a name key is present, the reserved name has been assigned by the
+	/// reserve origin and can only be claimed by the specified owner.
+	#[pallet::storage]
+	#[pallet::getter(fn assignee)]
+	pub type Assigned<T> = StorageMap<_, Blake2_128Concat, Web3NameOf<T>, Web3NameOwnerOf<T>>;
+
+	/// Map of (name, record key) -> text record.
+	#[pallet::storage]
+	#[pallet::getter(fn text_record)]
+	pub type TextRecords<T> =
+		StorageDoubleMap<_, Blake2_128Concat, Web3NameOf<T>, Blake2_128Concat, TextRecordKeyOf<T>, TextRecordOf<T>>;
+
+	/// Map of name -> number of text records attached to it.
+	#[pallet::storage]
+	#[pallet::getter(fn text_records_count)]
+	pub type TextRecordsCount<T> = StorageMap<_, Blake2_128Concat, Web3NameOf<T>, u32, ValueQuery>;
+
 	#[pallet::composite_enum]
 	pub enum HoldReason {
 		Deposit,
@@ -106,6 +151,8 @@ pub mod pallet {
 	pub trait Config: frame_system::Config {
 		/// The origin allowed to ban names.
 		type BanOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The origin allowed to reserve names and assign reserved names.
+		type ReserveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// The origin allowed to perform regular operations.
 		type OwnerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin, Success = Self::OriginSuccess>;
 		/// The type of origin after a successful origin check.
@@ -125,6 +172,21 @@ pub mod pallet {
 		/// The max encoded length of a name.
 		#[pallet::constant]
 		type MaxNameLength: Get<u32>;
+		/// The max number of patterns that can be reserved at the same time.
+		#[pallet::constant]
+		type MaxReservedPatterns: Get<u32>;
+		/// The amount of KILT to deposit for each text record of a name.
+		#[pallet::constant]
+		type TextRecordDeposit: Get<BalanceOf<Self>>;
+		/// The max number of text records that can be attached to a name.
+		#[pallet::constant]
+		type MaxTextRecords: Get<u32>;
+		/// The max encoded length of a text record key.
+		#[pallet::constant]
+		type MaxTextRecordKeyLength: Get<u32>;
+		/// The max encoded length of a text record value.
+		#[pallet::constant]
+		type MaxTextRecordValueLength: Get<u32>;
 		// FIXME: Refactor the definition of AsciiWeb3Name so that we don't need to
 		// require `Ord` here
 		/// The type of a name.
@@ -143,6 +205,14 @@ pub mod pallet {
 
 		/// Migration manager to handle new created entries
 		type BalanceMigrationManager: BalanceMigrationManager<AccountIdOf<Self>, BalanceOf<Self>>;
+
+		/// The index of deposits by owner, updated whenever a web3name or text record deposit is
+		/// taken, changed or released.
+		type DepositIndex: DepositIndex<AccountIdOf<Self>, BalanceOf<Self>>;
+
+		/// The hook called whenever a name is assigned to or removed from an
+		/// owner.
+		type IdentityChangeHook: OnIdentityChange<Web3NameOwnerOf<Self>, AccountIdOf<Self>>;
 	}
 
 	#[pallet::event]
@@ -162,6 +232,31 @@ pub mod pallet {
 		Web3NameBanned { name: Web3NameOf<T> },
 		/// A name has been unbanned.
 		Web3NameUnbanned { name: Web3NameOf<T> },
+		/// A name has been reserved.
+		Web3NameReserved { name: Web3NameOf<T> },
+		/// A name has been unreserved.
+		Web3NameUnreserved { name: Web3NameOf<T> },
+		/// A pattern has been added to the list of reserved patterns.
+		Web3NamePatternReserved { pattern: ReservedNamePatternOf<T> },
+		/// A pattern has been removed from the list of reserved patterns.
+		Web3NamePatternUnreserved { pattern: ReservedNamePatternOf<T> },
+		/// A reserved name has been assigned to an owner, who can now claim
+		/// it.
+		Web3NameAssigned {
+			owner: Web3NameOwnerOf<T>,
+			name: Web3NameOf<T>,
+		},
+		/// A text record has been set for a name.
+		Web3NameTextRecordSet {
+			name: Web3NameOf<T>,
+			key: TextRecordKeyOf<T>,
+			value: TextRecordValueOf<T>,
+		},
+		/// A text record has been removed from a name.
+		Web3NameTextRecordRemoved {
+			name: Web3NameOf<T>,
+			key: TextRecordKeyOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -191,6 +286,26 @@ pub mod pallet {
 		TooLong,
 		/// A name that contains not allowed characters is being claimed.
 		InvalidCharacter,
+		/// The specified name has been reserved and cannot be claimed.
+		Reserved,
+		/// The specified name is not currently reserved.
+		NotReserved,
+		/// The specified name has already been previously reserved.
+		AlreadyReserved,
+		/// The specified pattern is empty.
+		InvalidPattern,
+		/// The specified pattern has already been previously reserved.
+		PatternAlreadyReserved,
+		/// The specified pattern is not currently reserved.
+		PatternNotFound,
+		/// The maximum number of reserved patterns has been reached.
+		TooManyPatterns,
+		/// The specified text record key contains not allowed characters.
+		InvalidTextRecordKey,
+		/// The specified text record does not exist.
+		TextRecordNotFound,
+		/// The maximum number of text records for the name has been reached.
+		TooManyTextRecords,
 	}
 
 	#[pallet::hooks]
@@ -207,19 +322,25 @@ pub mod pallet {
 		/// origin.
 		///
 		/// The name must not have already been claimed by someone else and the
-		/// owner must not already own another name.
+		/// owner must not already own another name. A reserved name can only
+		/// be claimed by the owner it has been assigned to, and is no longer
+		/// reserved once claimed.
 		///
 		/// Emits `Web3NameClaimed` if the operation is carried out
 		/// successfully.
 		///
 		/// # <weight>
-		/// Weight: O(1)
-		/// - Reads: Names, Owner, Banned storage entries + available currency
-		///   check + origin check
-		/// - Writes: Names, Owner storage entries + currency deposit reserve
+		/// Weight: O(P) where P is the maximum number of reserved patterns
+		/// - Reads: Names, Owner, Banned, Reserved, ReservedPatterns, Assigned
+		///   storage entries + available currency check + origin check
+		/// - Writes: Names, Owner, Reserved, Assigned storage entries +
+		///   currency deposit reserve
 		/// # </weight>
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::claim(name.len().saturated_into()))]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::claim(name.len().saturated_into(), T::MaxReservedPatterns::get())
+				.saturating_add(T::IdentityChangeHook::on_identity_changed_weight())
+		)]
 		pub fn claim(origin: OriginFor<T>, name: Web3NameInput<T>) -> DispatchResult {
 			let origin = T::OwnerOrigin::ensure_origin(origin)?;
 			let payer = origin.sender();
@@ -227,6 +348,8 @@ pub mod pallet {
 
 			let decoded_name = Self::check_claiming_preconditions(name, &owner, &payer)?;
 
+			Reserved::<T>::remove(&decoded_name);
+			Assigned::<T>::remove(&decoded_name);
 			Self::register_name(decoded_name.clone(), owner.clone(), payer)?;
 			Self::deposit_event(Event::<T>::Web3NameClaimed {
 				owner,
@@ -238,18 +361,24 @@ pub mod pallet {
 
 		/// Release the provided name from its owner.
 		///
-		/// The origin must be the owner of the specified name.
+		/// The origin must be the owner of the specified name. All the text
+		/// records of the name are removed and their deposits returned.
 		///
 		/// Emits `Web3NameReleased` if the operation is carried out
 		/// successfully.
 		///
 		/// # <weight>
-		/// Weight: O(1)
+		/// Weight: O(R) where R is the max number of text records
 		/// - Reads: Names storage entry + origin check
-		/// - Writes: Names, Owner storage entries + currency deposit release
+		/// - Writes: Names, Owner, TextRecords, TextRecordsCount storage entries
+		///   + currency deposits release
 		/// # </weight>
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::release_by_owner())]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::release_by_owner()
+				.saturating_add(Pallet::<T>::clear_text_records_weight())
+				.saturating_add(T::IdentityChangeHook::on_identity_changed_weight())
+		)]
 		pub fn release_by_owner(origin: OriginFor<T>) -> DispatchResult {
 			let origin = T::OwnerOrigin::ensure_origin(origin)?;
 			let owner = origin.subject();
@@ -268,17 +397,24 @@ pub mod pallet {
 		/// Release the provided name from its owner.
 		///
 		/// The origin must be the account that paid for the name's deposit.
+		/// All the text records of the name are removed and their deposits
+		/// returned.
 		///
 		/// Emits `Web3NameReleased` if the operation is carried out
 		/// successfully.
 		///
 		/// # <weight>
-		/// Weight: O(1)
+		/// Weight: O(R) where R is the max number of text records
 		/// - Reads: Owner storage entry + origin check
-		/// - Writes: Names, Owner storage entries + currency deposit release
+		/// - Writes: Names, Owner, TextRecords, TextRecordsCount storage entries
+		///   + currency deposits release
 		/// # </weight>
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as Config>::WeightInfo::reclaim_deposit(name.len().saturated_into()))]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::reclaim_deposit(name.len().saturated_into())
+				.saturating_add(Pallet::<T>::clear_text_records_weight())
+				.saturating_add(T::IdentityChangeHook::on_identity_changed_weight())
+		)]
 		pub fn reclaim_deposit(origin: OriginFor<T>, name: Web3NameInput<T>) -> DispatchResult {
 			let caller = ensure_signed(origin)?;
 
@@ -296,7 +432,8 @@ pub mod pallet {
 		/// Ban a name.
 		///
 		/// A banned name cannot be claimed by anyone. The name's deposit
-		/// is returned to the original payer.
+		/// is returned to the original payer, and so are the deposits of its
+		/// text records, which are removed.
 		///
 		/// The origin must be the ban origin.
 		///
@@ -304,13 +441,17 @@ pub mod pallet {
 		/// successfully.
 		///
 		/// # <weight>
-		/// Weight: O(1)
+		/// Weight: O(R) where R is the max number of text records
 		/// - Reads: Banned, Owner, Names storage entries + origin check
-		/// - Writes: Names, Owner, Banned storage entries + currency deposit
-		///   release
+		/// - Writes: Names, Owner, Banned, TextRecords, TextRecordsCount storage
+		///   entries + currency deposits release
 		/// # </weight>
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::ban(name.len().saturated_into()))]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::ban(name.len().saturated_into())
+				.saturating_add(Pallet::<T>::clear_text_records_weight())
+				.saturating_add(T::IdentityChangeHook::on_identity_changed_weight())
+		)]
 		pub fn ban(origin: OriginFor<T>, name: Web3NameInput<T>) -> DispatchResult {
 			T::BanOrigin::ensure_origin(origin)?;
 
@@ -389,6 +530,329 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Reserve a name.
+		///
+		/// A reserved name cannot be claimed by anyone, and can only be
+		/// assigned to an owner by the reserve origin.
+		///
+		/// The origin must be the reserve origin.
+		///
+		/// Emits `Web3NameReserved` if the operation is carried out
+		/// successfully.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: Reserved, Owner storage entries + origin check
+		/// - Writes: Reserved storage entry
+		/// # </weight>
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::reserve(name.len().saturated_into()))]
+		pub fn reserve(origin: OriginFor<T>, name: Web3NameInput<T>) -> DispatchResult {
+			T::ReserveOrigin::ensure_origin(origin)?;
+
+			let decoded_name = Self::check_reserving_preconditions(name)?;
+
+			Reserved::<T>::insert(&decoded_name, ());
+			Self::deposit_event(Event::<T>::Web3NameReserved { name: decoded_name });
+
+			Ok(())
+		}
+
+		/// Unreserve a name.
+		///
+		/// Make a name claimable again, unless it is matched by any of the
+		/// reserved patterns. Any previous assignment of the name is removed.
+		/// Names only reserved by a pattern stay reserved, and only their
+		/// assignment is removed.
+		///
+		/// The origin must be the reserve origin.
+		///
+		/// Emits `Web3NameUnreserved` if the operation is carried out
+		/// successfully.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: Reserved, Assigned storage entries + origin check
+		/// - Writes: Reserved, Assigned storage entries
+		/// # </weight>
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::unreserve(name.len().saturated_into()))]
+		pub fn unreserve(origin: OriginFor<T>, name: Web3NameInput<T>) -> DispatchResult {
+			T::ReserveOrigin::ensure_origin(origin)?;
+
+			let decoded_name = Web3NameOf::<T>::try_from(name.into_inner()).map_err(DispatchError::from)?;
+			ensure!(
+				Reserved::<T>::contains_key(&decoded_name) || Assigned::<T>::contains_key(&decoded_name),
+				Error::<T>::NotReserved
+			);
+
+			Reserved::<T>::remove(&decoded_name);
+			Assigned::<T>::remove(&decoded_name);
+			Self::deposit_event(Event::<T>::Web3NameUnreserved { name: decoded_name });
+
+			Ok(())
+		}
+
+		/// Add a pattern to the list of reserved patterns.
+		///
+		/// Any name matching the pattern cannot be claimed by anyone, and can
+		/// only be assigned to an owner by the reserve origin. Names that have
+		/// already been claimed are not affected.
+		///
+		/// The origin must be the reserve origin.
+		///
+		/// Emits `Web3NamePatternReserved` if the operation is carried out
+		/// successfully.
+		///
+		/// # <weight>
+		/// Weight: O(P) where P is the maximum number of reserved patterns
+		/// - Reads: ReservedPatterns storage entry + origin check
+		/// - Writes: ReservedPatterns storage entry
+		/// # </weight>
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::reserve_pattern(T::MaxReservedPatterns::get()))]
+		pub fn reserve_pattern(origin: OriginFor<T>, pattern: ReservedNamePatternOf<T>) -> DispatchResult {
+			T::ReserveOrigin::ensure_origin(origin)?;
+
+			ensure!(!pattern.bytes().is_empty(), Error::<T>::InvalidPattern);
+
+			ReservedPatterns::<T>::try_mutate(|patterns| -> DispatchResult {
+				ensure!(!patterns.contains(&pattern), Error::<T>::PatternAlreadyReserved);
+				patterns
+					.try_push(pattern.clone())
+					.map_err(|_| Error::<T>::TooManyPatterns)?;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::<T>::Web3NamePatternReserved { pattern });
+
+			Ok(())
+		}
+
+		/// Remove a pattern from the list of reserved patterns.
+		///
+		/// The origin must be the reserve origin.
+		///
+		/// Emits `Web3NamePatternUnreserved` if the operation is carried out
+		/// successfully.
+		///
+		/// # <weight>
+		/// Weight: O(P) where P is the maximum number of reserved patterns
+		/// - Reads: ReservedPatterns storage entry + origin check
+		/// - Writes: ReservedPatterns storage entry
+		/// # </weight>
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::unreserve_pattern(T::MaxReservedPatterns::get()))]
+		pub fn unreserve_pattern(origin: OriginFor<T>, pattern: ReservedNamePatternOf<T>) -> DispatchResult {
+			T::ReserveOrigin::ensure_origin(origin)?;
+
+			ReservedPatterns::<T>::try_mutate(|patterns| -> DispatchResult {
+				let position = patterns
+					.iter()
+					.position(|reserved_pattern| reserved_pattern == &pattern)
+					.ok_or(Error::<T>::PatternNotFound)?;
+				patterns.remove(position);
+				Ok(())
+			})?;
+			Self::deposit_event(Event::<T>::Web3NamePatternUnreserved { pattern });
+
+			Ok(())
+		}
+
+		/// Assign a reserved name to the specified owner.
+		///
+		/// The name must be reserved, either explicitly or by matching any of
+		/// the reserved patterns. Once assigned, the name can be claimed by the
+		/// owner with `claim`, and the deposit is then reserved from the sender
+		/// of that call. Any previous assignment of the name is replaced.
+		///
+		/// The origin must be the reserve origin.
+		///
+		/// Emits `Web3NameAssigned` if the operation is carried out
+		/// successfully.
+		///
+		/// # <weight>
+		/// Weight: O(P) where P is the maximum number of reserved patterns
+		/// - Reads: Names, Owner, Banned, Reserved, ReservedPatterns storage
+		///   entries + origin check
+		/// - Writes: Assigned storage entry
+		/// # </weight>
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::assign_reserved(
+			name.len().saturated_into(),
+			T::MaxReservedPatterns::get()
+		))]
+		pub fn assign_reserved(
+			origin: OriginFor<T>,
+			name: Web3NameInput<T>,
+			owner: Web3NameOwnerOf<T>,
+		) -> DispatchResult {
+			T::ReserveOrigin::ensure_origin(origin)?;
+
+			let decoded_name = Self::check_assigning_preconditions(name, &owner)?;
+
+			Assigned::<T>::insert(&decoded_name, owner.clone());
+			Self::deposit_event(Event::<T>::Web3NameAssigned {
+				owner,
+				name: decoded_name,
+			});
+
+			Ok(())
+		}
+
+		/// Set a text record for the name owned by the subject of the origin.
+		///
+		/// If a record with the same key already exists, its value is
+		/// replaced and its deposit is left untouched. Otherwise, the deposit
+		/// for the new record is reserved from the sender of the call.
+		///
+		/// Emits `Web3NameTextRecordSet` if the operation is carried out
+		/// successfully.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: Names, TextRecords, TextRecordsCount storage entries +
+		///   available currency check + origin check
+		/// - Writes: TextRecords, TextRecordsCount storage entries + currency
+		///   deposit reserve
+		/// # </weight>
+		#[pallet::call_index(12)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::set_text_record(key.len().saturated_into(), value.len().saturated_into())
+		)]
+		pub fn set_text_record(
+			origin: OriginFor<T>,
+			key: TextRecordKeyOf<T>,
+			value: TextRecordValueOf<T>,
+		) -> DispatchResult {
+			let origin = T::OwnerOrigin::ensure_origin(origin)?;
+			let payer = origin.sender();
+			let owner = origin.subject();
+
+			let name = Names::<T>::get(&owner).ok_or(Error::<T>::OwnerNotFound)?;
+			ensure!(is_valid_text_record_key(&key), Error::<T>::InvalidTextRecordKey);
+
+			if let Some(record) = TextRecords::<T>::get(&name, &key) {
+				TextRecords::<T>::insert(
+					&name,
+					&key,
+					TextRecordOf::<T> {
+						value: value.clone(),
+						..record
+					},
+				);
+			} else {
+				ensure!(
+					TextRecordsCount::<T>::get(&name) < T::MaxTextRecords::get(),
+					Error::<T>::TooManyTextRecords
+				);
+				ensure!(
+					<T::Currency as InspectHold<AccountIdOf<T>>>::can_hold(
+						&HoldReason::Deposit.into(),
+						&payer,
+						T::TextRecordDeposit::get()
+					),
+					Error::<T>::InsufficientFunds
+				);
+
+				let deposit =
+					TextRecordStorageDepositCollector::<T>::create_deposit(payer, T::TextRecordDeposit::get())?;
+				<T as Config>::BalanceMigrationManager::exclude_key_from_migration(&TextRecords::<T>::hashed_key_for(
+					&name, &key,
+				));
+				TextRecordStorageDepositCollector::<T>::index_deposit(&(name.clone(), key.clone()), &deposit)?;
+
+				TextRecords::<T>::insert(
+					&name,
+					&key,
+					TextRecordOf::<T> {
+						value: value.clone(),
+						deposit,
+					},
+				);
+				TextRecordsCount::<T>::mutate(&name, |count| *count = count.saturating_add(1));
+			}
+			Self::deposit_event(Event::<T>::Web3NameTextRecordSet { name, key, value });
+
+			Ok(())
+		}
+
+		/// Remove a text record from the name owned by the subject of the
+		/// origin, returning the record deposit to its payer.
+		///
+		/// Emits `Web3NameTextRecordRemoved` if the operation is carried out
+		/// successfully.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: Names, TextRecords storage entries + origin check
+		/// - Writes: TextRecords, TextRecordsCount storage entries + currency
+		///   deposit release
+		/// # </weight>
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_text_record(key.len().saturated_into()))]
+		pub fn remove_text_record(origin: OriginFor<T>, key: TextRecordKeyOf<T>) -> DispatchResult {
+			let origin = T::OwnerOrigin::ensure_origin(origin)?;
+			let owner = origin.subject();
+
+			let name = Names::<T>::get(&owner).ok_or(Error::<T>::OwnerNotFound)?;
+			let record = TextRecords::<T>::take(&name, &key).ok_or(Error::<T>::TextRecordNotFound)?;
+
+			TextRecordStorageDepositCollector::<T>::unindex_deposit(
+				&(name.clone(), key.clone()),
+				&record.deposit.owner,
+			)?;
+			TextRecordStorageDepositCollector::<T>::free_deposit(record.deposit)?;
+			TextRecordsCount::<T>::mutate_exists(&name, |count| {
+				*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0)
+			});
+			Self::deposit_event(Event::<T>::Web3NameTextRecordRemoved { name, key });
+
+			Ok(())
+		}
+
+		/// Changes the deposit owner of a text record.
+		///
+		/// The balance that is reserved by the current deposit owner will be
+		/// freed and balance of the new deposit owner will get reserved.
+		///
+		/// The subject of the call must be the owner of the web3name the
+		/// record is attached to. The sender of the call will be the new
+		/// deposit owner.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::change_text_record_deposit_owner())]
+		pub fn change_text_record_deposit_owner(origin: OriginFor<T>, key: TextRecordKeyOf<T>) -> DispatchResult {
+			let source = <T as Config>::OwnerOrigin::ensure_origin(origin)?;
+			let w3n_owner = source.subject();
+			let name = Names::<T>::get(&w3n_owner).ok_or(Error::<T>::OwnerNotFound)?;
+			TextRecordStorageDepositCollector::<T>::change_deposit_owner::<BalanceMigrationManagerOf<T>>(
+				&(name, key),
+				source.sender(),
+			)?;
+
+			Ok(())
+		}
+
+		/// Updates the deposit amount of a text record to the current deposit
+		/// rate.
+		///
+		/// The sender must be the deposit owner.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_text_record_deposit())]
+		pub fn update_text_record_deposit(
+			origin: OriginFor<T>,
+			name_input: Web3NameInput<T>,
+			key: TextRecordKeyOf<T>,
+		) -> DispatchResult {
+			let source = ensure_signed(origin)?;
+			let name = Web3NameOf::<T>::try_from(name_input.into_inner()).map_err(DispatchError::from)?;
+			let record = TextRecords::<T>::get(&name, &key).ok_or(Error::<T>::TextRecordNotFound)?;
+			ensure!(record.deposit.owner == source, Error::<T>::NotAuthorized);
+
+			TextRecordStorageDepositCollector::<T>::update_deposit::<BalanceMigrationManagerOf<T>>(&(name, key))?;
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -397,17 +861,23 @@ pub mod pallet {
 		/// - The name does not already exist
 		/// - The owner does not already own a name
 		/// - The name has not been banned
+		/// - The name has not been reserved, either explicitly or by a pattern,
+		///   unless it has been assigned to the owner
 		/// - The tx submitter has enough funds to pay the deposit
 		fn check_claiming_preconditions(
 			name_input: Web3NameInput<T>,
 			owner: &Web3NameOwnerOf<T>,
 			deposit_payer: &AccountIdOf<T>,
 		) -> Result<Web3NameOf<T>, DispatchError> {
-			let name = Web3NameOf::<T>::try_from(name_input.into_inner()).map_err(DispatchError::from)?;
+			let name = Web3NameOf::<T>::try_from(name_input.to_vec()).map_err(DispatchError::from)?;
 
 			ensure!(!Names::<T>::contains_key(owner), Error::<T>::OwnerAlreadyExists);
 			ensure!(!Owner::<T>::contains_key(&name), Error::<T>::AlreadyExists);
 			ensure!(!Banned::<T>::contains_key(&name), Error::<T>::Banned);
+			ensure!(
+				!Self::is_name_reserved(&name, &name_input) || Assigned::<T>::get(&name).as_ref() == Some(owner),
+				Error::<T>::Reserved
+			);
 
 			ensure!(
 				<T::Currency as InspectHold<AccountIdOf<T>>>::can_hold(
@@ -434,8 +904,10 @@ pub mod pallet {
 
 			let deposit = Web3NameStorageDepositCollector::<T>::create_deposit(deposit_payer, T::Deposit::get())?;
 			<T as Config>::BalanceMigrationManager::exclude_key_from_migration(&Owner::<T>::hashed_key_for(&name));
+			Web3NameStorageDepositCollector::<T>::index_deposit(&name, &deposit)?;
 
 			Names::<T>::insert(&owner, name.clone());
+			T::IdentityChangeHook::on_identity_changed(&owner);
 			Owner::<T>::insert(
 				&name,
 				Web3OwnershipOf::<T> {
@@ -480,10 +952,12 @@ pub mod pallet {
 		fn unregister_name(name: &Web3NameOf<T>) -> Result<Web3OwnershipOf<T>, DispatchError> {
 			let name_ownership = Owner::<T>::take(name).unwrap();
 			Names::<T>::remove(&name_ownership.owner);
+			T::IdentityChangeHook::on_identity_changed(&name_ownership.owner);
 
 			let is_key_migrated =
 				<T as Config>::BalanceMigrationManager::is_key_migrated(&Owner::<T>::hashed_key_for(name));
 
+			Web3NameStorageDepositCollector::<T>::unindex_deposit(name, &name_ownership.deposit.owner)?;
 			if is_key_migrated {
 				Web3NameStorageDepositCollector::<T>::free_deposit(name_ownership.clone().deposit)?;
 			} else {
@@ -495,9 +969,35 @@ pub mod pallet {
 
 			// Should never fail since we checked in the preconditions
 
+			Self::clear_text_records(name)?;
+
 			Ok(name_ownership)
 		}
 
+		/// Remove all the text records of the provided name, returning their
+		/// deposits to the respective payers.
+		fn clear_text_records(name: &Web3NameOf<T>) -> DispatchResult {
+			TextRecordsCount::<T>::remove(name);
+			TextRecords::<T>::drain_prefix(name).try_for_each(|(key, record)| {
+				TextRecordStorageDepositCollector::<T>::unindex_deposit(&(name.clone(), key), &record.deposit.owner)?;
+				TextRecordStorageDepositCollector::<T>::free_deposit(record.deposit).map(|_| ())
+			})
+		}
+
+		/// The worst-case weight of removing all the text records of a name.
+		pub(crate) fn clear_text_records_weight() -> Weight {
+			<T as Config>::WeightInfo::remove_text_record(T::MaxTextRecordKeyLength::get())
+				.saturating_mul(T::MaxTextRecords::get().into())
+		}
+
+		/// Returns all the text records of the provided name as raw key-value
+		/// pairs.
+		pub fn raw_text_records(name: &Web3NameOf<T>) -> Vec<(Vec<u8>, Vec<u8>)> {
+			TextRecords::<T>::iter_prefix(name)
+				.map(|(key, record)| (key.into_inner(), record.value.into_inner()))
+				.collect()
+		}
+
 		/// Verify that the banning preconditions are verified.
 		/// Specifically:
 		/// - The name input data can be decoded as a valid name
@@ -541,6 +1041,58 @@ pub mod pallet {
 		fn unban_name(name: &Web3NameOf<T>) {
 			Banned::<T>::remove(name);
 		}
+
+		/// Returns `true` if the provided name is reserved, either explicitly
+		/// or because it matches any of the reserved patterns.
+		///
+		/// Both the raw input bytes and the skeleton of the name are checked,
+		/// so that internationalised names and names using lookalike
+		/// characters cannot be used to get around a reservation.
+		pub fn is_name_reserved(name: &Web3NameOf<T>, name_input: &[u8]) -> bool {
+			let skeleton = idna::skeleton(name_input);
+			Reserved::<T>::contains_key(name)
+				|| (skeleton != name_input
+					&& Web3NameOf::<T>::try_from(skeleton.clone())
+						.is_ok_and(|skeleton_name| Reserved::<T>::contains_key(skeleton_name)))
+				|| ReservedPatterns::<T>::get()
+					.iter()
+					.any(|pattern| pattern.matches(name_input) || pattern.matches_skeleton(&skeleton))
+		}
+
+		/// Verify that the reserving preconditions are verified.
+		/// Specifically:
+		/// - The name input data can be decoded as a valid name
+		/// - The name must not be already reserved
+		/// - The name must not be currently claimed
+		fn check_reserving_preconditions(name_input: Web3NameInput<T>) -> Result<Web3NameOf<T>, DispatchError> {
+			let name = Web3NameOf::<T>::try_from(name_input.into_inner()).map_err(DispatchError::from)?;
+
+			ensure!(!Reserved::<T>::contains_key(&name), Error::<T>::AlreadyReserved);
+			ensure!(!Owner::<T>::contains_key(&name), Error::<T>::AlreadyExists);
+
+			Ok(name)
+		}
+
+		/// Verify that the assigning preconditions are verified.
+		/// Specifically:
+		/// - The name input data can be decoded as a valid name
+		/// - The name is reserved, either explicitly or by a pattern
+		/// - The name does not already exist
+		/// - The owner does not already own a name
+		/// - The name has not been banned
+		fn check_assigning_preconditions(
+			name_input: Web3NameInput<T>,
+			owner: &Web3NameOwnerOf<T>,
+		) -> Result<Web3NameOf<T>, DispatchError> {
+			let name = Web3NameOf::<T>::try_from(name_input.to_vec()).map_err(DispatchError::from)?;
+
+			ensure!(Self::is_name_reserved(&name, &name_input), Error::<T>::NotReserved);
+			ensure!(!Names::<T>::contains_key(owner), Error::<T>::OwnerAlreadyExists);
+			ensure!(!Owner::<T>::contains_key(&name), Error::<T>::AlreadyExists);
+			ensure!(!Banned::<T>::contains_key(&name), Error::<T>::Banned);
+
+			Ok(name)
+		}
 	}
 
 	pub(crate) struct Web3NameStorageDepositCollector<T: Config>(PhantomData<T>);
@@ -548,6 +1100,7 @@ pub mod pallet {
 		for Web3NameStorageDepositCollector<T>
 	{
 		type Currency = T::Currency;
+		type DepositIndex = T::DepositIndex;
 		type Reason = HoldReason;
 
 		fn get_hashed_key(key: &T::Web3Name) -> Result<sp_std::vec::Vec<u8>, DispatchError> {
@@ -579,4 +1132,44 @@ pub mod pallet {
 			Ok(())
 		}
 	}
+
+	pub(crate) struct TextRecordStorageDepositCollector<T: Config>(PhantomData<T>);
+	impl<T: Config> StorageDepositCollector<AccountIdOf<T>, (T::Web3Name, TextRecordKeyOf<T>), T::RuntimeHoldReason>
+		for TextRecordStorageDepositCollector<T>
+	{
+		type Currency = T::Currency;
+		type DepositIndex = T::DepositIndex;
+		type Reason = HoldReason;
+
+		fn get_hashed_key(key: &(T::Web3Name, TextRecordKeyOf<T>)) -> Result<sp_std::vec::Vec<u8>, DispatchError> {
+			Ok(TextRecords::<T>::hashed_key_for(&key.0, &key.1))
+		}
+
+		fn reason() -> Self::Reason {
+			HoldReason::Deposit
+		}
+		fn deposit(
+			key: &(T::Web3Name, TextRecordKeyOf<T>),
+		) -> Result<Deposit<AccountIdOf<T>, <Self::Currency as Inspect<AccountIdOf<T>>>::Balance>, DispatchError> {
+			let record = TextRecords::<T>::get(&key.0, &key.1).ok_or(Error::<T>::TextRecordNotFound)?;
+
+			Ok(record.deposit)
+		}
+
+		fn deposit_amount(
+			_key: &(T::Web3Name, TextRecordKeyOf<T>),
+		) -> <Self::Currency as Inspect<AccountIdOf<T>>>::Balance {
+			T::TextRecordDeposit::get()
+		}
+
+		fn store_deposit(
+			key: &(T::Web3Name, TextRecordKeyOf<T>),
+			deposit: Deposit<AccountIdOf<T>, <Self::Currency as Inspect<AccountIdOf<T>>>::Balance>,
+		) -> Result<(), DispatchError> {
+			let record = TextRecords::<T>::get(&key.0, &key.1).ok_or(Error::<T>::TextRecordNotFound)?;
+			TextRecords::<T>::insert(&key.0, &key.1, TextRecordOf::<T> { deposit, ..record });
+
+			Ok(())
+		}
+	}
 }
@@ -20,9 +20,9 @@ use frame_support::{
 	pallet_prelude::DispatchResult,
 	traits::{fungible::Inspect, ReservableCurrency},
 };
-use kilt_support::migration::switch_reserved_to_hold;
+use kilt_support::{migration::switch_reserved_to_hold, traits::DepositIndex};
 
-use crate::{AccountIdOf, Config, CurrencyOf, Error, HoldReason, Owner, Web3NameOf};
+use crate::{AccountIdOf, Config, CurrencyOf, Error, HoldReason, Owner, TextRecordKeyOf, TextRecords, Web3NameOf};
 
 pub fn update_balance_for_w3n<T: Config>(key: &Web3NameOf<T>) -> DispatchResult
 where
@@ -37,6 +37,31 @@ where
 	)
 }
 
+/// Add the deposit of the web3name behind the provided key to the deposit
+/// index. Used to index deposits taken before the deposit index existed.
+pub fn index_deposit_for_w3n<T: Config>(key: &Web3NameOf<T>) -> DispatchResult {
+	let details = Owner::<T>::get(key).ok_or(Error::<T>::NotFound)?;
+	<T as Config>::DepositIndex::on_deposit_held(
+		&details.deposit.owner,
+		&Owner::<T>::hashed_key_for(key),
+		&details.deposit.amount,
+	);
+	Ok(())
+}
+
+/// Add the deposit of the text record behind the provided name and key to the
+/// deposit index. Used to index deposits taken before the deposit index
+/// existed.
+pub fn index_deposit_for_text_record<T: Config>(name: &Web3NameOf<T>, key: &TextRecordKeyOf<T>) -> DispatchResult {
+	let record = TextRecords::<T>::get(name, key).ok_or(Error::<T>::TextRecordNotFound)?;
+	<T as Config>::DepositIndex::on_deposit_held(
+		&record.deposit.owner,
+		&TextRecords::<T>::hashed_key_for(name, key),
+		&record.deposit.amount,
+	);
+	Ok(())
+}
+
 #[cfg(test)]
 pub mod test {
 	use frame_support::{
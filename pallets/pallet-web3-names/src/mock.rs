@@ -148,12 +148,18 @@ pub(crate) mod runtime {
 	parameter_types! {
 		pub const MaxNameLength: u32 = 32;
 		pub const MinNameLength: u32 = 3;
+		pub const MaxReservedPatterns: u32 = 5;
+		pub const MaxTextRecords: u32 = 2;
+		pub const MaxTextRecordKeyLength: u32 = 16;
+		pub const MaxTextRecordValueLength: u32 = 32;
 		// Easier to setup insufficient funds for deposit but still above existential deposit
 		pub const Web3NameDeposit: Balance = 2 * ExistentialDeposit::get();
+		pub const TextRecordDeposit: Balance = 2 * ExistentialDeposit::get();
 	}
 
 	impl pallet_web3_names::Config for Test {
 		type BanOrigin = TestBanOrigin;
+		type ReserveOrigin = TestBanOrigin;
 		type OwnerOrigin = TestOwnerOrigin;
 		type OriginSuccess = TestOriginSuccess;
 		type Currency = Balances;
@@ -162,10 +168,17 @@ pub(crate) mod runtime {
 		type RuntimeEvent = RuntimeEvent;
 		type MaxNameLength = MaxNameLength;
 		type MinNameLength = MinNameLength;
+		type MaxReservedPatterns = MaxReservedPatterns;
+		type TextRecordDeposit = TextRecordDeposit;
+		type MaxTextRecords = MaxTextRecords;
+		type MaxTextRecordKeyLength = MaxTextRecordKeyLength;
+		type MaxTextRecordValueLength = MaxTextRecordValueLength;
 		type Web3Name = TestWeb3Name;
 		type Web3NameOwner = TestWeb3NameOwner;
 		type WeightInfo = ();
 		type BalanceMigrationManager = ();
+		type DepositIndex = ();
+		type IdentityChangeHook = ();
 	}
 
 	impl mock_origin::Config for Test {
@@ -190,6 +203,8 @@ pub(crate) mod runtime {
 		balances: Vec<(TestWeb3NamePayer, Balance)>,
 		claimed_web3_names: Vec<(TestWeb3NameOwner, TestWeb3Name, TestWeb3NamePayer)>,
 		banned_web3_names: Vec<TestWeb3Name>,
+		reserved_web3_names: Vec<TestWeb3Name>,
+		text_records: Vec<(TestWeb3NameOwner, Vec<u8>, Vec<u8>, TestWeb3NamePayer)>,
 	}
 
 	impl ExtBuilder {
@@ -214,6 +229,21 @@ pub(crate) mod runtime {
 			self
 		}
 
+		#[must_use]
+		pub fn with_reserved_web3_names(mut self, web3_names: Vec<TestWeb3Name>) -> Self {
+			self.reserved_web3_names = web3_names;
+			self
+		}
+
+		#[must_use]
+		pub fn with_text_records(
+			mut self,
+			text_records: Vec<(TestWeb3NameOwner, Vec<u8>, Vec<u8>, TestWeb3NamePayer)>,
+		) -> Self {
+			self.text_records = text_records;
+			self
+		}
+
 		pub fn build(self) -> sp_io::TestExternalities {
 			let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 			pallet_balances::GenesisConfig::<Test> {
@@ -233,6 +263,20 @@ pub(crate) mod runtime {
 					assert!(pallet_web3_names::Owner::<Test>::get(&web3_name).is_none());
 					pallet_web3_names::Pallet::<Test>::ban_name(&web3_name);
 				}
+
+				for web3_name in self.reserved_web3_names {
+					assert!(pallet_web3_names::Owner::<Test>::get(&web3_name).is_none());
+					pallet_web3_names::Reserved::<Test>::insert(&web3_name, ());
+				}
+
+				for (owner, key, value, payer) in self.text_records {
+					pallet_web3_names::Pallet::<Test>::set_text_record(
+						mock_origin::DoubleOrigin(payer, owner).into(),
+						key.try_into().expect("Text record key too long."),
+						value.try_into().expect("Text record value too long."),
+					)
+					.expect("Could not set text record");
+				}
 			});
 			ext
 		}
@@ -0,0 +1,113 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{traits::Get, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+use crate::idna;
+
+/// A pattern reserving all the web3 names that match it.
+///
+/// Patterns are matched against the raw bytes of a name at claiming time, so
+/// a single pattern can reserve a whole family of names (e.g., all names
+/// starting with `gov-`) without having to list each of them.
+#[derive(CloneNoBound, Encode, Decode, RuntimeDebugNoBound, EqNoBound, PartialEqNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxLength))]
+#[codec(mel_bound())]
+pub enum ReservedNamePattern<MaxLength: Get<u32>> {
+	/// Matches all the names starting with the given bytes.
+	Prefix(BoundedVec<u8, MaxLength>),
+	/// Matches all the names ending with the given bytes.
+	Suffix(BoundedVec<u8, MaxLength>),
+	/// Matches all the names containing the given bytes anywhere.
+	Contains(BoundedVec<u8, MaxLength>),
+}
+
+impl<MaxLength: Get<u32>> ReservedNamePattern<MaxLength> {
+	/// The bytes the pattern is matched with.
+	pub fn bytes(&self) -> &[u8] {
+		match self {
+			Self::Prefix(bytes) | Self::Suffix(bytes) | Self::Contains(bytes) => bytes,
+		}
+	}
+
+	/// Returns `true` if the provided name is matched by the pattern.
+	pub fn matches(&self, name: &[u8]) -> bool {
+		self.matches_with(name, self.bytes())
+	}
+
+	/// Returns `true` if the provided name skeleton is matched by the
+	/// skeleton of the pattern, i.e., if the name looks like a name matched by
+	/// the pattern. See [`idna::skeleton`].
+	pub fn matches_skeleton(&self, name_skeleton: &[u8]) -> bool {
+		self.matches_with(name_skeleton, &idna::text_skeleton(self.bytes()))
+	}
+
+	fn matches_with(&self, name: &[u8], bytes: &[u8]) -> bool {
+		match self {
+			Self::Prefix(_) => name.starts_with(bytes),
+			Self::Suffix(_) => name.ends_with(bytes),
+			Self::Contains(_) => bytes.is_empty() || name.windows(bytes.len()).any(|window| window == bytes),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use frame_support::{traits::ConstU32, BoundedVec};
+
+	use crate::{idna, reservation::ReservedNamePattern};
+
+	type TestPattern = ReservedNamePattern<ConstU32<32>>;
+
+	fn bounded(input: &[u8]) -> BoundedVec<u8, ConstU32<32>> {
+		BoundedVec::try_from(input.to_vec()).expect("Pattern input should fit.")
+	}
+
+	#[test]
+	fn pattern_matching() {
+		let prefix = TestPattern::Prefix(bounded(b"gov-"));
+		assert!(prefix.matches(b"gov-tax"));
+		assert!(prefix.matches(b"gov-"));
+		assert!(!prefix.matches(b"my-gov-tax"));
+		assert!(!prefix.matches(b"gov"));
+
+		let suffix = TestPattern::Suffix(bounded(b"_official"));
+		assert!(suffix.matches(b"brand_official"));
+		assert!(!suffix.matches(b"brand_official_fan"));
+
+		let contains = TestPattern::Contains(bounded(b"bank"));
+		assert!(contains.matches(b"bank"));
+		assert!(contains.matches(b"mybank"));
+		assert!(contains.matches(b"bank-of-idchain"));
+		assert!(contains.matches(b"central_bank_00"));
+		assert!(!contains.matches(b"ban-k"));
+	}
+
+	#[test]
+	fn skeleton_matching() {
+		let contains = TestPattern::Contains(bounded(b"bank"));
+		// "bänk" in ASCII-compatible encoding
+		assert!(!contains.matches(b"xn--bnk-qla"));
+		assert!(contains.matches_skeleton(&idna::skeleton(b"xn--bnk-qla")));
+		// Cyrillic "а" in place of the Latin one
+		assert!(contains.matches_skeleton(&idna::skeleton("bаnk".as_bytes())));
+		assert!(!contains.matches_skeleton(&idna::skeleton(b"bunk")));
+	}
+}
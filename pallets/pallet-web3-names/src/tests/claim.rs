@@ -104,6 +104,8 @@ fn claiming_invalid() {
 		BoundedVec::try_from(b"    ".to_vec()).unwrap(),
 		// Non-ASCII character name
 		BoundedVec::try_from(String::from("notascii😁").as_bytes().to_owned()).unwrap(),
+		// Mixed-script internationalised name ("pаypal" with a Cyrillic "а")
+		BoundedVec::try_from(b"xn--pypal-4ve".to_vec()).unwrap(),
 	];
 	ExtBuilder::default()
 		.with_balances(vec![(ACCOUNT_00, 100)])
@@ -126,6 +128,23 @@ fn claiming_invalid() {
 		})
 }
 
+#[test]
+fn claiming_internationalised() {
+	// "münchen" in ASCII-compatible encoding
+	let web3_name_00 = get_web3_name(b"xn--mnchen-3ya");
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::claim(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				web3_name_00.clone().0,
+			));
+			let web3_name = Names::<Test>::get(&DID_00).expect("Web3 name should be stored.");
+			assert_eq!(web3_name, web3_name_00);
+			assert_eq!(web3_name.display_form(), String::from("münchen").into_bytes());
+		})
+}
+
 #[test]
 fn claiming_banned() {
 	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
@@ -20,3 +20,5 @@ mod ban;
 mod claim;
 mod deposit;
 mod release;
+mod reserve;
+mod text_records;
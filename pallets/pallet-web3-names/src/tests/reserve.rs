@@ -0,0 +1,499 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold, BoundedVec};
+
+use frame_system::RawOrigin;
+use kilt_support::{mock::mock_origin, Deposit};
+use sp_runtime::{traits::Zero, DispatchError};
+
+use crate::{
+	mock::*, Assigned, Error, HoldReason, Names, Owner, Pallet, Reserved, ReservedNamePatternOf, ReservedPatterns,
+	Web3OwnershipOf,
+};
+
+fn prefix_pattern(prefix: &[u8]) -> ReservedNamePatternOf<Test> {
+	ReservedNamePatternOf::<Test>::Prefix(BoundedVec::try_from(prefix.to_vec()).expect("Pattern should fit."))
+}
+
+#[test]
+fn reserving_successful() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::reserve(RawOrigin::Root.into(), web3_name_00.clone().0));
+			assert!(Reserved::<Test>::get(&web3_name_00).is_some());
+
+			// Test that claiming is not possible anymore
+			assert_noop!(
+				Pallet::<Test>::claim(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					web3_name_00.clone().0
+				),
+				Error::<Test>::Reserved
+			);
+		})
+}
+
+#[test]
+fn reserving_already_reserved() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_reserved_web3_names(vec![web3_name_00.clone()])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Pallet::<Test>::reserve(RawOrigin::Root.into(), web3_name_00.clone().0),
+				Error::<Test>::AlreadyReserved
+			);
+		})
+}
+
+#[test]
+fn reserving_claimed() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Pallet::<Test>::reserve(RawOrigin::Root.into(), web3_name_00.clone().0),
+				Error::<Test>::AlreadyExists
+			);
+		})
+}
+
+#[test]
+fn reserving_unauthorized_origin() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Pallet::<Test>::reserve(RawOrigin::Signed(ACCOUNT_00).into(), web3_name_00.clone().0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Pallet::<Test>::reserve(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				web3_name_00.clone().0
+			),
+			DispatchError::BadOrigin
+		);
+	})
+}
+
+#[test]
+fn unreserving_successful() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_reserved_web3_names(vec![web3_name_00.clone()])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::unreserve(
+				RawOrigin::Root.into(),
+				web3_name_00.clone().0
+			));
+			assert!(Reserved::<Test>::get(&web3_name_00).is_none());
+
+			// Test that claiming is possible again
+			assert_ok!(Pallet::<Test>::claim(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				web3_name_00.clone().0,
+			));
+		})
+}
+
+#[test]
+fn unreserving_not_reserved() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Pallet::<Test>::unreserve(RawOrigin::Root.into(), web3_name_00.clone().0),
+			Error::<Test>::NotReserved
+		);
+	})
+}
+
+#[test]
+fn reserving_pattern_successful() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let web3_name_01 = get_web3_name(b"gov-web3_name");
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::reserve_pattern(
+				RawOrigin::Root.into(),
+				prefix_pattern(b"gov-")
+			));
+			assert_eq!(
+				ReservedPatterns::<Test>::get().into_inner(),
+				vec![prefix_pattern(b"gov-")]
+			);
+
+			// Test that a matching name cannot be claimed
+			assert_noop!(
+				Pallet::<Test>::claim(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					web3_name_01.clone().0
+				),
+				Error::<Test>::Reserved
+			);
+			// Test that a non-matching name can still be claimed
+			assert_ok!(Pallet::<Test>::claim(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				web3_name_00.clone().0
+			));
+		})
+}
+
+#[test]
+fn reserving_pattern_invalid() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_noop!(
+			Pallet::<Test>::reserve_pattern(RawOrigin::Root.into(), prefix_pattern(b"")),
+			Error::<Test>::InvalidPattern
+		);
+
+		assert_ok!(Pallet::<Test>::reserve_pattern(
+			RawOrigin::Root.into(),
+			prefix_pattern(b"gov-")
+		));
+		assert_noop!(
+			Pallet::<Test>::reserve_pattern(RawOrigin::Root.into(), prefix_pattern(b"gov-")),
+			Error::<Test>::PatternAlreadyReserved
+		);
+	})
+}
+
+#[test]
+fn reserving_pattern_too_many() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		for i in 0..MaxReservedPatterns::get() {
+			assert_ok!(Pallet::<Test>::reserve_pattern(
+				RawOrigin::Root.into(),
+				prefix_pattern(format!("prefix{i}").as_bytes())
+			));
+		}
+		assert_noop!(
+			Pallet::<Test>::reserve_pattern(RawOrigin::Root.into(), prefix_pattern(b"one-too-many")),
+			Error::<Test>::TooManyPatterns
+		);
+	})
+}
+
+#[test]
+fn unreserving_pattern_successful() {
+	let web3_name_01 = get_web3_name(b"gov-web3_name");
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::reserve_pattern(
+				RawOrigin::Root.into(),
+				prefix_pattern(b"gov-")
+			));
+			assert_ok!(Pallet::<Test>::unreserve_pattern(
+				RawOrigin::Root.into(),
+				prefix_pattern(b"gov-")
+			));
+			assert!(ReservedPatterns::<Test>::get().is_empty());
+
+			// Test that claiming is possible again
+			assert_ok!(Pallet::<Test>::claim(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				web3_name_01.clone().0
+			));
+		})
+}
+
+#[test]
+fn unreserving_pattern_not_found() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_noop!(
+			Pallet::<Test>::unreserve_pattern(RawOrigin::Root.into(), prefix_pattern(b"gov-")),
+			Error::<Test>::PatternNotFound
+		);
+	})
+}
+
+#[test]
+fn reserving_covers_lookalike_names() {
+	// "wéb3_name_00"
+	let reserved_name_lookalike = get_web3_name(b"xn--wb3_name_00-bbb");
+	// "gòv-web3_name"
+	let prefix_lookalike = get_web3_name(b"xn--gv-web3_name-nhb");
+	// "my-bänk"
+	let contains_lookalike = get_web3_name(b"xn--my-bnk-eua");
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_reserved_web3_names(vec![get_web3_name(WEB3_NAME_00_INPUT)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::reserve_pattern(
+				RawOrigin::Root.into(),
+				prefix_pattern(b"gov-")
+			));
+			assert_ok!(Pallet::<Test>::reserve_pattern(
+				RawOrigin::Root.into(),
+				ReservedNamePatternOf::<Test>::Contains(BoundedVec::try_from(b"bank".to_vec()).unwrap())
+			));
+
+			for name in [reserved_name_lookalike, prefix_lookalike, contains_lookalike] {
+				assert_noop!(
+					Pallet::<Test>::claim(mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(), name.0),
+					Error::<Test>::Reserved
+				);
+			}
+		})
+}
+
+#[test]
+fn assigning_reserved_successful() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_01, 100)])
+		.with_reserved_web3_names(vec![web3_name_00.clone()])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::assign_reserved(
+				RawOrigin::Root.into(),
+				web3_name_00.clone().0,
+				DID_00
+			));
+
+			assert_eq!(Assigned::<Test>::get(&web3_name_00), Some(DID_00));
+			assert!(Reserved::<Test>::get(&web3_name_00).is_some());
+			assert!(Names::<Test>::get(&DID_00).is_none());
+			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01).is_zero());
+
+			// Test that the name can only be claimed by the assigned owner
+			assert_noop!(
+				Pallet::<Test>::claim(
+					mock_origin::DoubleOrigin(ACCOUNT_01, DID_01).into(),
+					web3_name_00.clone().0
+				),
+				Error::<Test>::Reserved
+			);
+			assert_ok!(Pallet::<Test>::claim(
+				mock_origin::DoubleOrigin(ACCOUNT_01, DID_00).into(),
+				web3_name_00.clone().0
+			));
+
+			assert!(Reserved::<Test>::get(&web3_name_00).is_none());
+			assert!(Assigned::<Test>::get(&web3_name_00).is_none());
+			assert_eq!(Names::<Test>::get(&DID_00), Some(web3_name_00.clone()));
+			assert_eq!(
+				Owner::<Test>::get(&web3_name_00),
+				Some(Web3OwnershipOf::<Test> {
+					owner: DID_00,
+					claimed_at: 0,
+					deposit: Deposit {
+						owner: ACCOUNT_01,
+						amount: Web3NameDeposit::get(),
+					},
+				})
+			);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01),
+				Web3NameDeposit::get()
+			);
+		})
+}
+
+#[test]
+fn assigning_pattern_reserved_successful() {
+	let web3_name_01 = get_web3_name(b"gov-web3_name");
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::reserve_pattern(
+				RawOrigin::Root.into(),
+				prefix_pattern(b"gov-")
+			));
+			assert_ok!(Pallet::<Test>::assign_reserved(
+				RawOrigin::Root.into(),
+				web3_name_01.clone().0,
+				DID_00
+			));
+			assert_ok!(Pallet::<Test>::claim(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				web3_name_01.clone().0
+			));
+
+			assert_eq!(Names::<Test>::get(&DID_00), Some(web3_name_01.clone()));
+			assert!(Assigned::<Test>::get(&web3_name_01).is_none());
+			// The pattern still reserves other names
+			assert_eq!(ReservedPatterns::<Test>::get().len(), 1);
+		})
+}
+
+#[test]
+fn assigning_replaces_previous_assignment() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_reserved_web3_names(vec![web3_name_00.clone()])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::assign_reserved(
+				RawOrigin::Root.into(),
+				web3_name_00.clone().0,
+				DID_00
+			));
+			assert_ok!(Pallet::<Test>::assign_reserved(
+				RawOrigin::Root.into(),
+				web3_name_00.clone().0,
+				DID_01
+			));
+
+			assert_eq!(Assigned::<Test>::get(&web3_name_00), Some(DID_01));
+			assert_noop!(
+				Pallet::<Test>::claim(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					web3_name_00.clone().0
+				),
+				Error::<Test>::Reserved
+			);
+		})
+}
+
+#[test]
+fn unreserving_removes_assignment() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_reserved_web3_names(vec![web3_name_00.clone()])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::assign_reserved(
+				RawOrigin::Root.into(),
+				web3_name_00.clone().0,
+				DID_00
+			));
+			assert_ok!(Pallet::<Test>::unreserve(
+				RawOrigin::Root.into(),
+				web3_name_00.clone().0
+			));
+
+			assert!(Assigned::<Test>::get(&web3_name_00).is_none());
+		})
+}
+
+#[test]
+fn unreserving_removes_pattern_assignment() {
+	let web3_name_01 = get_web3_name(b"gov-web3_name");
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_ok!(Pallet::<Test>::reserve_pattern(
+			RawOrigin::Root.into(),
+			prefix_pattern(b"gov-")
+		));
+		assert_ok!(Pallet::<Test>::assign_reserved(
+			RawOrigin::Root.into(),
+			web3_name_01.clone().0,
+			DID_00
+		));
+		assert_ok!(Pallet::<Test>::unreserve(
+			RawOrigin::Root.into(),
+			web3_name_01.clone().0
+		));
+
+		assert!(Assigned::<Test>::get(&web3_name_01).is_none());
+		// The name is still reserved by the pattern.
+		assert_noop!(
+			Pallet::<Test>::claim(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				web3_name_01.clone().0
+			),
+			Error::<Test>::Reserved
+		);
+		// Once the assignment is removed, the name is no longer unreservable.
+		assert_noop!(
+			Pallet::<Test>::unreserve(RawOrigin::Root.into(), web3_name_01.clone().0),
+			Error::<Test>::NotReserved
+		);
+	})
+}
+
+#[test]
+fn assigning_not_reserved() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_noop!(
+			Pallet::<Test>::assign_reserved(RawOrigin::Root.into(), web3_name_00.clone().0, DID_00),
+			Error::<Test>::NotReserved
+		);
+	})
+}
+
+#[test]
+fn assigning_to_existing_owner() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let web3_name_01 = get_web3_name(WEB3_NAME_01_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.with_reserved_web3_names(vec![web3_name_01.clone()])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Pallet::<Test>::assign_reserved(RawOrigin::Root.into(), web3_name_01.clone().0, DID_00),
+				Error::<Test>::OwnerAlreadyExists
+			);
+		})
+}
+
+#[test]
+fn assigning_banned() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_banned_web3_names(vec![web3_name_00.clone()])
+		.with_reserved_web3_names(vec![web3_name_00.clone()])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Pallet::<Test>::assign_reserved(RawOrigin::Root.into(), web3_name_00.clone().0, DID_00),
+				Error::<Test>::Banned
+			);
+		})
+}
+
+#[test]
+fn claiming_assigned_not_enough_funds() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, Web3NameDeposit::get() - 1)])
+		.with_reserved_web3_names(vec![web3_name_00.clone()])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::assign_reserved(
+				RawOrigin::Root.into(),
+				web3_name_00.clone().0,
+				DID_00
+			));
+			assert_noop!(
+				Pallet::<Test>::claim(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					web3_name_00.clone().0
+				),
+				Error::<Test>::InsufficientFunds
+			);
+		})
+}
+
+#[test]
+fn assigning_unauthorized_origin() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_reserved_web3_names(vec![web3_name_00.clone()])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Pallet::<Test>::assign_reserved(RawOrigin::Signed(ACCOUNT_00).into(), web3_name_00.clone().0, DID_00),
+				DispatchError::BadOrigin
+			);
+		})
+}
@@ -0,0 +1,425 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::fungible::{Inspect, InspectHold, MutateHold},
+};
+
+use frame_system::RawOrigin;
+use kilt_support::{mock::mock_origin, Deposit};
+use sp_runtime::traits::Zero;
+
+use crate::{
+	mock::*, Error, HoldReason, Pallet, TextRecordKeyOf, TextRecordOf, TextRecordValueOf, TextRecords, TextRecordsCount,
+};
+
+fn key(input: &[u8]) -> TextRecordKeyOf<Test> {
+	input.to_vec().try_into().expect("Text record key should fit.")
+}
+
+fn value(input: &[u8]) -> TextRecordValueOf<Test> {
+	input.to_vec().try_into().expect("Text record value should fit.")
+}
+
+#[test]
+fn setting_text_record_successful() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100), (ACCOUNT_01, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::set_text_record(
+				// Submitter != name deposit payer
+				mock_origin::DoubleOrigin(ACCOUNT_01, DID_00).into(),
+				key(b"avatar"),
+				value(b"https://example.com/avatar.png")
+			));
+
+			assert_eq!(
+				TextRecords::<Test>::get(&web3_name_00, key(b"avatar")),
+				Some(TextRecordOf::<Test> {
+					value: value(b"https://example.com/avatar.png"),
+					deposit: Deposit {
+						owner: ACCOUNT_01,
+						amount: TextRecordDeposit::get(),
+					}
+				})
+			);
+			assert_eq!(TextRecordsCount::<Test>::get(&web3_name_00), 1);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01),
+				TextRecordDeposit::get()
+			);
+			assert_eq!(
+				Pallet::<Test>::raw_text_records(&web3_name_00),
+				vec![(b"avatar".to_vec(), b"https://example.com/avatar.png".to_vec())]
+			);
+		})
+}
+
+#[test]
+fn updating_text_record_keeps_deposit() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100), (ACCOUNT_01, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.with_text_records(vec![(DID_00, b"display".to_vec(), b"Alice".to_vec(), ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::set_text_record(
+				mock_origin::DoubleOrigin(ACCOUNT_01, DID_00).into(),
+				key(b"display"),
+				value(b"Alice Liddell")
+			));
+
+			let record = TextRecords::<Test>::get(&web3_name_00, key(b"display")).expect("Record should exist.");
+			assert_eq!(record.value, value(b"Alice Liddell"));
+			assert_eq!(record.deposit.owner, ACCOUNT_00);
+			assert_eq!(TextRecordsCount::<Test>::get(&web3_name_00), 1);
+			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01).is_zero());
+		})
+}
+
+#[test]
+fn setting_text_record_owner_not_found() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Pallet::<Test>::set_text_record(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					key(b"avatar"),
+					value(b"https://example.com/avatar.png")
+				),
+				Error::<Test>::OwnerNotFound
+			);
+		})
+}
+
+#[test]
+fn setting_text_record_invalid_key() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00, ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			for invalid_key in [b"".to_vec(), b"Avatar".to_vec(), b"com twitter".to_vec()] {
+				assert_noop!(
+					Pallet::<Test>::set_text_record(
+						mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+						key(&invalid_key),
+						value(b"value")
+					),
+					Error::<Test>::InvalidTextRecordKey
+				);
+			}
+		})
+}
+
+#[test]
+fn setting_text_record_too_many() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00, ACCOUNT_00)])
+		.with_text_records(vec![
+			(DID_00, b"avatar".to_vec(), b"ipfs://avatar".to_vec(), ACCOUNT_00),
+			(DID_00, b"com.twitter".to_vec(), b"alice".to_vec(), ACCOUNT_00),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Pallet::<Test>::set_text_record(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					key(b"email-hash"),
+					value(b"0x00")
+				),
+				Error::<Test>::TooManyTextRecords
+			);
+			// Updating an existing record is still possible.
+			assert_ok!(Pallet::<Test>::set_text_record(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				key(b"com.twitter"),
+				value(b"bob")
+			));
+		})
+}
+
+#[test]
+fn setting_text_record_not_enough_funds() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100), (ACCOUNT_01, TextRecordDeposit::get() - 1)])
+		.with_web3_names(vec![(DID_00, web3_name_00, ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Pallet::<Test>::set_text_record(
+					mock_origin::DoubleOrigin(ACCOUNT_01, DID_00).into(),
+					key(b"avatar"),
+					value(b"ipfs://avatar")
+				),
+				Error::<Test>::InsufficientFunds
+			);
+		})
+}
+
+#[test]
+fn removing_text_record_successful() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let initial_balance: Balance = 100;
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, initial_balance), (ACCOUNT_01, initial_balance)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.with_text_records(vec![(
+			DID_00,
+			b"avatar".to_vec(),
+			b"ipfs://avatar".to_vec(),
+			ACCOUNT_01,
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::remove_text_record(
+				// Submitter != record deposit payer
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				key(b"avatar")
+			));
+
+			assert!(TextRecords::<Test>::get(&web3_name_00, key(b"avatar")).is_none());
+			assert!(!TextRecordsCount::<Test>::contains_key(&web3_name_00));
+			// Test that the deposit was returned to the payer correctly.
+			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01).is_zero());
+			assert_eq!(Balances::balance(&ACCOUNT_01), initial_balance);
+		})
+}
+
+#[test]
+fn removing_text_record_not_found() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00, ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Pallet::<Test>::remove_text_record(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					key(b"avatar")
+				),
+				Error::<Test>::TextRecordNotFound
+			);
+			assert_noop!(
+				Pallet::<Test>::remove_text_record(
+					mock_origin::DoubleOrigin(ACCOUNT_01, DID_01).into(),
+					key(b"avatar")
+				),
+				Error::<Test>::OwnerNotFound
+			);
+		})
+}
+
+#[test]
+fn releasing_clears_text_records() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let initial_balance: Balance = 100;
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, initial_balance), (ACCOUNT_01, initial_balance)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.with_text_records(vec![
+			(DID_00, b"avatar".to_vec(), b"ipfs://avatar".to_vec(), ACCOUNT_00),
+			(DID_00, b"com.twitter".to_vec(), b"alice".to_vec(), ACCOUNT_01),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::release_by_owner(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			));
+
+			assert_eq!(TextRecords::<Test>::iter_prefix(&web3_name_00).count(), 0);
+			assert!(!TextRecordsCount::<Test>::contains_key(&web3_name_00));
+			// Test that all the deposits were returned to the payers correctly.
+			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00).is_zero());
+			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01).is_zero());
+			assert_eq!(Balances::balance(&ACCOUNT_00), initial_balance);
+			assert_eq!(Balances::balance(&ACCOUNT_01), initial_balance);
+		})
+}
+
+#[test]
+fn banning_clears_text_records() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	let initial_balance: Balance = 100;
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, initial_balance), (ACCOUNT_01, initial_balance)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.with_text_records(vec![(
+			DID_00,
+			b"avatar".to_vec(),
+			b"ipfs://avatar".to_vec(),
+			ACCOUNT_01,
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Pallet::<Test>::ban(RawOrigin::Root.into(), web3_name_00.clone().0));
+
+			assert_eq!(TextRecords::<Test>::iter_prefix(&web3_name_00).count(), 0);
+			assert!(!TextRecordsCount::<Test>::contains_key(&web3_name_00));
+			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01).is_zero());
+			assert_eq!(Balances::balance(&ACCOUNT_01), initial_balance);
+		})
+}
+
+#[test]
+fn changing_text_record_deposit_owner_successful() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100), (ACCOUNT_01, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.with_text_records(vec![(
+			DID_00,
+			b"avatar".to_vec(),
+			b"ipfs://avatar".to_vec(),
+			ACCOUNT_00,
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			let name_deposit =
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00) - TextRecordDeposit::get();
+
+			assert_ok!(Pallet::<Test>::change_text_record_deposit_owner(
+				mock_origin::DoubleOrigin(ACCOUNT_01, DID_00).into(),
+				key(b"avatar")
+			));
+
+			assert_eq!(
+				TextRecords::<Test>::get(&web3_name_00, key(b"avatar"))
+					.expect("Record should exist.")
+					.deposit,
+				Deposit {
+					owner: ACCOUNT_01,
+					amount: TextRecordDeposit::get(),
+				}
+			);
+			// The deposit of the name is not affected.
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				name_deposit
+			);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01),
+				TextRecordDeposit::get()
+			);
+		})
+}
+
+#[test]
+fn changing_text_record_deposit_owner_not_found() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100), (ACCOUNT_01, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00, ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Pallet::<Test>::change_text_record_deposit_owner(
+					mock_origin::DoubleOrigin(ACCOUNT_01, DID_00).into(),
+					key(b"avatar")
+				),
+				Error::<Test>::TextRecordNotFound
+			);
+			assert_noop!(
+				Pallet::<Test>::change_text_record_deposit_owner(
+					mock_origin::DoubleOrigin(ACCOUNT_01, DID_01).into(),
+					key(b"avatar")
+				),
+				Error::<Test>::OwnerNotFound
+			);
+		})
+}
+
+#[test]
+fn updating_text_record_deposit_successful() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100), (ACCOUNT_01, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
+		.with_text_records(vec![(
+			DID_00,
+			b"avatar".to_vec(),
+			b"ipfs://avatar".to_vec(),
+			ACCOUNT_01,
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			// Simulate a deposit taken at a higher rate.
+			assert_ok!(Balances::hold(
+				&HoldReason::Deposit.into(),
+				&ACCOUNT_01,
+				TextRecordDeposit::get()
+			));
+			TextRecords::<Test>::mutate(&web3_name_00, key(b"avatar"), |record| {
+				if let Some(record) = record {
+					record.deposit.amount = TextRecordDeposit::get() * 2;
+				}
+			});
+
+			assert_ok!(Pallet::<Test>::update_text_record_deposit(
+				RuntimeOrigin::signed(ACCOUNT_01),
+				WEB3_NAME_00_INPUT.to_vec().try_into().unwrap(),
+				key(b"avatar")
+			));
+
+			assert_eq!(
+				TextRecords::<Test>::get(&web3_name_00, key(b"avatar"))
+					.expect("Record should exist.")
+					.deposit,
+				Deposit {
+					owner: ACCOUNT_01,
+					amount: TextRecordDeposit::get(),
+				}
+			);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01),
+				TextRecordDeposit::get()
+			);
+		})
+}
+
+#[test]
+fn updating_text_record_deposit_unauthorized() {
+	let web3_name_00 = get_web3_name(WEB3_NAME_00_INPUT);
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, 100), (ACCOUNT_01, 100)])
+		.with_web3_names(vec![(DID_00, web3_name_00, ACCOUNT_00)])
+		.with_text_records(vec![(
+			DID_00,
+			b"avatar".to_vec(),
+			b"ipfs://avatar".to_vec(),
+			ACCOUNT_01,
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			// The name owner is not the deposit owner of the record.
+			assert_noop!(
+				Pallet::<Test>::update_text_record_deposit(
+					RuntimeOrigin::signed(ACCOUNT_00),
+					WEB3_NAME_00_INPUT.to_vec().try_into().unwrap(),
+					key(b"avatar")
+				),
+				Error::<Test>::NotAuthorized
+			);
+			assert_noop!(
+				Pallet::<Test>::update_text_record_deposit(
+					RuntimeOrigin::signed(ACCOUNT_01),
+					WEB3_NAME_00_INPUT.to_vec().try_into().unwrap(),
+					key(b"display")
+				),
+				Error::<Test>::TextRecordNotFound
+			);
+		})
+}
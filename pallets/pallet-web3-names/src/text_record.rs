@@ -0,0 +1,73 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// A key-value record attached to a web3 name by its owner, e.g., an avatar
+/// URL or a social handle.
+#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct TextRecord<Value, Deposit> {
+	/// The value of the record.
+	pub value: Value,
+	/// The deposit that was taken to store the record.
+	pub deposit: Deposit,
+}
+
+/// Verify that a given slice can be used as a text record key.
+///
+/// Keys follow the ENS conventions (e.g., `avatar`, `com.twitter`), so they
+/// must be non-empty and only contain lowercase ASCII letters, digits, `.`,
+/// `-` and `_`.
+pub(crate) fn is_valid_text_record_key(input: &[u8]) -> bool {
+	!input.is_empty()
+		&& input
+			.iter()
+			.all(|c| matches!(c, b'a'..=b'z' | b'0'..=b'9' | b'.' | b'-' | b'_'))
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::text_record::is_valid_text_record_key;
+
+	#[test]
+	fn valid_text_record_keys() {
+		let valid_keys = [
+			b"avatar".to_vec(),
+			b"display".to_vec(),
+			b"email-hash".to_vec(),
+			b"com.twitter".to_vec(),
+			b"org.telegram_2".to_vec(),
+		];
+		for key in valid_keys {
+			assert!(is_valid_text_record_key(&key));
+		}
+
+		let invalid_keys = [
+			b"".to_vec(),
+			b"Avatar".to_vec(),
+			b"com twitter".to_vec(),
+			b"url:".to_vec(),
+			"ävatar".as_bytes().to_vec(),
+		];
+		for key in invalid_keys {
+			assert!(!is_valid_text_record_key(&key));
+		}
+	}
+}
@@ -21,7 +21,10 @@ use kilt_support::test_utils::log_and_return_error_message;
 use scale_info::prelude::format;
 use sp_runtime::TryRuntimeError;
 
-use crate::{Banned, Config, Names, Owner, Web3NameOf, Web3NameOwnerOf, Web3OwnershipOf};
+use crate::{
+	Assigned, Banned, Config, Names, Owner, Reserved, TextRecords, TextRecordsCount, Web3NameOf, Web3NameOwnerOf,
+	Web3OwnershipOf,
+};
 
 pub fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 	// check if for each owner there is a name stored.
@@ -55,5 +58,41 @@ pub fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 			log_and_return_error_message(format!("Owner contains banned name {:?}", banned_w3n))
 		);
 		Ok(())
+	})?;
+	// a reserved name should have no owner.
+	Reserved::<T>::iter_keys().try_for_each(|reserved_w3n| -> Result<(), TryRuntimeError> {
+		ensure!(
+			!Owner::<T>::contains_key(&reserved_w3n),
+			log_and_return_error_message(format!("Owner contains reserved name {:?}", reserved_w3n))
+		);
+		Ok(())
+	})?;
+	// an assigned name should have no owner.
+	Assigned::<T>::iter_keys().try_for_each(|assigned_w3n| -> Result<(), TryRuntimeError> {
+		ensure!(
+			!Owner::<T>::contains_key(&assigned_w3n),
+			log_and_return_error_message(format!("Owner contains assigned name {:?}", assigned_w3n))
+		);
+		Ok(())
+	})?;
+	// text records can only be attached to owned names, and their count must
+	// match.
+	TextRecordsCount::<T>::iter().try_for_each(|(w3n, count)| -> Result<(), TryRuntimeError> {
+		ensure!(
+			Owner::<T>::contains_key(&w3n),
+			log_and_return_error_message(format!("Text records attached to unowned name {:?}", w3n))
+		);
+		ensure!(
+			TextRecords::<T>::iter_prefix(&w3n).count() == count as usize,
+			log_and_return_error_message(format!("Text records count mismatch for name {:?}", w3n))
+		);
+		Ok(())
+	})?;
+	TextRecords::<T>::iter_keys().try_for_each(|(w3n, _)| -> Result<(), TryRuntimeError> {
+		ensure!(
+			TextRecordsCount::<T>::contains_key(&w3n),
+			log_and_return_error_message(format!("Text records count missing for name {:?}", w3n))
+		);
+		Ok(())
 	})
 }
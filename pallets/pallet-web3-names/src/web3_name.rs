@@ -23,17 +23,27 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{RuntimeDebug, SaturatedConversion};
 
-use crate::{Config, Error};
+use crate::{idna, Config, Error};
 
 /// A KILT web3 name.
 ///
 /// It is bounded in size (inclusive range [MinLength, MaxLength]) and can only
-/// contain a subset of ASCII characters.
+/// contain a subset of ASCII characters. Internationalised names are stored in
+/// their ASCII-compatible encoding, i.e., prefixed with `xn--`.
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(T, MinLength, MaxLength))]
 #[codec(mel_bound())]
 pub struct AsciiWeb3Name<T: Config>(pub BoundedVec<u8, T::MaxNameLength>, PhantomData<(T, T::MinNameLength)>);
 
+impl<T: Config> AsciiWeb3Name<T> {
+	/// The UTF-8 encoded form of the name to be shown to users.
+	///
+	/// It differs from the stored form only for internationalised names.
+	pub fn display_form(&self) -> Vec<u8> {
+		idna::to_display_form(&self.0).unwrap_or_else(|| self.0.to_vec())
+	}
+}
+
 impl<T: Config> Deref for AsciiWeb3Name<T> {
 	type Target = BoundedVec<u8, T::MaxNameLength>;
 
@@ -73,7 +83,13 @@ impl<T: Config> TryFrom<Vec<u8>> for AsciiWeb3Name<T> {
 }
 
 /// Verify that a given slice can be used as a web3 name.
+///
+/// Names starting with the ACE prefix must be the canonical encoding of a
+/// valid internationalised name.
 fn is_valid_web3_name(input: &[u8]) -> bool {
+	if input.starts_with(idna::ACE_PREFIX) {
+		return idna::is_valid_ace_name(input);
+	}
 	input
 		.iter()
 		.all(|c| matches!(c, b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_'))
@@ -159,6 +175,8 @@ mod tests {
 			b"0123456789".to_vec(),
 			b"---".to_vec(),
 			b"___".to_vec(),
+			// Internationalised name in ASCII-compatible encoding ("münchen")
+			b"xn--mnchen-3ya".to_vec(),
 		];
 
 		let invalid_inputs = vec![
@@ -172,6 +190,14 @@ mod tests {
 			b"almostavalidweb3_name!".to_vec(),
 			// Non-ASCII character
 			String::from("almostavalidweb3_name😂").as_bytes().to_owned(),
+			// Unicode name not in ASCII-compatible encoding
+			String::from("münchen").as_bytes().to_owned(),
+			// Invalid punycode
+			b"xn--mnchen-3y!".to_vec(),
+			// Non-canonical encoding of an ASCII-only name
+			b"xn--munchen-".to_vec(),
+			// Mixed-script name ("pаypal" with a Cyrillic "а")
+			b"xn--pypal-4ve".to_vec(),
 		];
 
 		for valid in valid_inputs {
@@ -182,4 +208,13 @@ mod tests {
 			assert!(AsciiWeb3Name::<Test>::try_from(invalid).is_err());
 		}
 	}
+
+	#[test]
+	fn display_form() {
+		let ascii_name = AsciiWeb3Name::<Test>::try_from(b"web3_name".to_vec()).unwrap();
+		assert_eq!(ascii_name.display_form(), b"web3_name".to_vec());
+
+		let idna_name = AsciiWeb3Name::<Test>::try_from(b"xn--mnchen-3ya".to_vec()).unwrap();
+		assert_eq!(idna_name.display_form(), String::from("münchen").into_bytes());
+	}
 }
@@ -50,6 +50,8 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for public_credentials.
 pub trait WeightInfo {
+	/// Storage: PublicCredentials CredentialsByCtypeAndAttester (r:0 w:1)
+	/// Proof: PublicCredentials CredentialsByCtypeAndAttester (max_values: None, max_size: Some(425), added: 2900, mode: MaxEncodedLen)
 	fn add(c: u32, ) -> Weight;
 	fn revoke() -> Weight;
 	fn unrevoke() -> Weight;
@@ -70,22 +72,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: PublicCredentials CredentialSubjects (r:0 w:1)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `c` is `[1, 100000]`.
 	fn add(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `829`
-		//  Estimated: `8120`
-		// Minimum execution time: 27_323 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(27_065_888, 8120)
-			// Standard Error: 15
 			.saturating_add(Weight::from_parts(1_595, 0 ).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: PublicCredentials CredentialSubjects (r:1 w:0)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
 	/// Storage: PublicCredentials Credentials (r:1 w:1)
 	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials CredentialsByCtypeAndAttester (r:0 w:2)
+	/// Proof: PublicCredentials CredentialsByCtypeAndAttester (max_values: None, max_size: Some(425), added: 2900, mode: MaxEncodedLen)
 	fn revoke() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `914`
@@ -93,12 +97,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 15_690 nanoseconds.
 		Weight::from_parts(16_193_000, 5737)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: PublicCredentials CredentialSubjects (r:1 w:0)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
 	/// Storage: PublicCredentials Credentials (r:1 w:1)
 	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials CredentialsByCtypeAndAttester (r:0 w:2)
+	/// Proof: PublicCredentials CredentialsByCtypeAndAttester (max_values: None, max_size: Some(425), added: 2900, mode: MaxEncodedLen)
 	fn unrevoke() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `914`
@@ -106,7 +112,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 17_962 nanoseconds.
 		Weight::from_parts(29_462_000, 5737)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: PublicCredentials CredentialSubjects (r:1 w:1)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
@@ -114,14 +120,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PublicCredentials CredentialsByCtypeAndAttester (r:0 w:1)
+	/// Proof: PublicCredentials CredentialsByCtypeAndAttester (max_values: None, max_size: Some(425), added: 2900, mode: MaxEncodedLen)
 	fn remove() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1592`
-		//  Estimated: `8344`
-		// Minimum execution time: 27_101 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(29_244_000, 8344)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: PublicCredentials CredentialSubjects (r:1 w:1)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
@@ -129,14 +138,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PublicCredentials CredentialsByCtypeAndAttester (r:0 w:1)
+	/// Proof: PublicCredentials CredentialsByCtypeAndAttester (max_values: None, max_size: Some(425), added: 2900, mode: MaxEncodedLen)
 	fn reclaim_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1592`
-		//  Estimated: `8344`
-		// Minimum execution time: 27_519 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(28_728_000, 8344)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: PublicCredentials CredentialSubjects (r:1 w:0)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
@@ -144,14 +156,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1465`
-		//  Estimated: `10951`
-		// Minimum execution time: 36_253 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(38_070_000, 10951)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: PublicCredentials CredentialSubjects (r:1 w:0)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
@@ -159,14 +172,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1052`
-		//  Estimated: `8344`
-		// Minimum execution time: 33_132 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(34_010_000, 8344)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 }
 
@@ -180,22 +194,26 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
 	/// Storage: PublicCredentials CredentialSubjects (r:0 w:1)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PublicCredentials CredentialsByCtypeAndAttester (r:0 w:1)
+	/// Proof: PublicCredentials CredentialsByCtypeAndAttester (max_values: None, max_size: Some(425), added: 2900, mode: MaxEncodedLen)
 	/// The range of component `c` is `[1, 100000]`.
 	fn add(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `829`
-		//  Estimated: `8120`
-		// Minimum execution time: 27_323 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(27_065_888, 8120)
-			// Standard Error: 15
 			.saturating_add(Weight::from_parts(1_595, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: PublicCredentials CredentialSubjects (r:1 w:0)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
 	/// Storage: PublicCredentials Credentials (r:1 w:1)
 	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials CredentialsByCtypeAndAttester (r:0 w:2)
+	/// Proof: PublicCredentials CredentialsByCtypeAndAttester (max_values: None, max_size: Some(425), added: 2900, mode: MaxEncodedLen)
 	fn revoke() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `914`
@@ -203,12 +221,14 @@ impl WeightInfo for () {
 		// Minimum execution time: 15_690 nanoseconds.
 		Weight::from_parts(16_193_000, 5737)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: PublicCredentials CredentialSubjects (r:1 w:0)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
 	/// Storage: PublicCredentials Credentials (r:1 w:1)
 	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
+	/// Storage: PublicCredentials CredentialsByCtypeAndAttester (r:0 w:2)
+	/// Proof: PublicCredentials CredentialsByCtypeAndAttester (max_values: None, max_size: Some(425), added: 2900, mode: MaxEncodedLen)
 	fn unrevoke() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `914`
@@ -216,7 +236,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 17_962 nanoseconds.
 		Weight::from_parts(29_462_000, 5737)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: PublicCredentials CredentialSubjects (r:1 w:1)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
@@ -224,14 +244,17 @@ impl WeightInfo for () {
 	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PublicCredentials CredentialsByCtypeAndAttester (r:0 w:1)
+	/// Proof: PublicCredentials CredentialsByCtypeAndAttester (max_values: None, max_size: Some(425), added: 2900, mode: MaxEncodedLen)
 	fn remove() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1592`
-		//  Estimated: `8344`
-		// Minimum execution time: 27_101 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(29_244_000, 8344)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: PublicCredentials CredentialSubjects (r:1 w:1)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
@@ -239,14 +262,17 @@ impl WeightInfo for () {
 	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PublicCredentials CredentialsByCtypeAndAttester (r:0 w:1)
+	/// Proof: PublicCredentials CredentialsByCtypeAndAttester (max_values: None, max_size: Some(425), added: 2900, mode: MaxEncodedLen)
 	fn reclaim_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1592`
-		//  Estimated: `8344`
-		// Minimum execution time: 27_519 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(28_728_000, 8344)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: PublicCredentials CredentialSubjects (r:1 w:0)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
@@ -254,14 +280,15 @@ impl WeightInfo for () {
 	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:2)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1465`
-		//  Estimated: `10951`
-		// Minimum execution time: 36_253 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(38_070_000, 10951)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: PublicCredentials CredentialSubjects (r:1 w:0)
 	/// Proof: PublicCredentials CredentialSubjects (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
@@ -269,13 +296,14 @@ impl WeightInfo for () {
 	/// Proof: PublicCredentials Credentials (max_values: None, max_size: Some(475), added: 2950, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: StorageDeposit IndexedDeposits (r:0 w:1)
+	/// Proof: StorageDeposit IndexedDeposits (max_values: None, max_size: None, mode: Measured)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1052`
-		//  Estimated: `8344`
-		// Minimum execution time: 33_132 nanoseconds.
+		// Not benchmarked yet with the deposit index. Bounded by the benchmark of
+		// this call before the deposit index was introduced, plus the writes to
+		// the deposit index.
 		Weight::from_parts(34_010_000, 8344)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 }
@@ -73,12 +73,12 @@ pub mod pallet {
 
 	pub use uid_credential::CtypeHashOf;
 	use kilt_support::{
-		traits::{CallSources, StorageDepositCollector},
+		traits::{CallSources, DepositIndex, StorageDepositCollector},
 		Deposit,
 	};
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	// No easy way to check whether the two currencies are the same and check for
 	// `can_withdraw` conditions. Maybe with #[transactional] we could stop caring
@@ -174,6 +174,10 @@ pub mod pallet {
 
 		/// Migration manager to handle new created entries
 		type BalanceMigrationManager: BalanceMigrationManager<AccountIdOf<Self>, BalanceOf<Self>>;
+
+		/// The index of deposits by owner, updated whenever a public credential deposit is
+		/// taken, changed or released.
+		type DepositIndex: DepositIndex<AccountIdOf<Self>, BalanceOf<Self>>;
 	}
 
 	#[pallet::pallet]
@@ -196,6 +200,26 @@ pub mod pallet {
 	#[pallet::getter(fn get_credential_subject)]
 	pub type CredentialSubjects<T> = StorageMap<_, Blake2_128Concat, CredentialIdOf<T>, SubjectIdOf<T>>;
 
+	/// An index of the credentials of each subject by CType, attester and
+	/// revocation status.
+	///
+	/// It is used to find a credential issued to a subject for a given CType
+	/// by a given attester without iterating over all the subject's
+	/// credentials.
+	#[pallet::storage]
+	pub type CredentialsByCtypeAndAttester<T> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, SubjectIdOf<T>>,
+			NMapKey<Blake2_128Concat, CtypeHashOf<T>>,
+			NMapKey<Blake2_128Concat, AttesterOf<T>>,
+			// Whether the credential is revoked.
+			NMapKey<Twox64Concat, bool>,
+			NMapKey<Blake2_128Concat, CredentialIdOf<T>>,
+		),
+		(),
+	>;
+
 	/// The events generated by this pallet.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -319,19 +343,21 @@ pub mod pallet {
 
 			let block_number = frame_system::Pallet::<T>::block_number();
 
+			CredentialsByCtypeAndAttester::<T>::insert((&subject, &ctype_hash, &attester, false, &credential_id), ());
 			Credentials::<T>::insert(
 				&subject,
 				&credential_id,
 				CredentialEntryOf::<T> {
 					revoked: false,
 					attester,
-					deposit,
+					deposit: deposit.clone(),
 					block_number,
 					ctype_hash,
 					authorization_id,
 				},
 			);
 			CredentialSubjects::<T>::insert(&credential_id, subject.clone());
+			PublicCredentialDepositCollector::<T>::index_deposit(&credential_id, &deposit)?;
 
 			Self::deposit_event(Event::CredentialStored {
 				subject_id: subject,
@@ -562,8 +588,16 @@ pub mod pallet {
 			credential_id: CredentialIdOf<T>,
 			credential: CredentialEntryOf<T>,
 		) -> DispatchResult {
+			PublicCredentialDepositCollector::<T>::unindex_deposit(&credential_id, &credential.deposit.owner)?;
 			let details = Credentials::<T>::take(&credential_subject, &credential_id).ok_or(Error::<T>::NotFound)?;
 			CredentialSubjects::<T>::remove(&credential_id);
+			CredentialsByCtypeAndAttester::<T>::remove((
+				&credential_subject,
+				&details.ctype_hash,
+				&details.attester,
+				details.revoked,
+				&credential_id,
+			));
 
 			let is_key_migrated = <T as Config>::BalanceMigrationManager::is_key_migrated(
 				&Credentials::<T>::hashed_key_for(&credential_subject, &credential_id),
@@ -584,6 +618,21 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Find a credential issued to the subject for the given CType by the
+		/// given attester, returning its ID and whether it is revoked. A
+		/// revoked credential is only returned if no valid one exists.
+		pub fn find_credential(
+			subject: &SubjectIdOf<T>,
+			ctype_hash: &CtypeHashOf<T>,
+			attester: &AttesterOf<T>,
+		) -> Option<(CredentialIdOf<T>, bool)> {
+			[false, true].into_iter().find_map(|revoked| {
+				CredentialsByCtypeAndAttester::<T>::iter_key_prefix((subject, ctype_hash, attester, revoked))
+					.next()
+					.map(|credential_id| (credential_id, revoked))
+			})
+		}
+
 		fn retrieve_credential_entry(
 			credential_id: &CredentialIdOf<T>,
 		) -> Result<(T::SubjectId, CredentialEntryOf<T>), Error<T>> {
@@ -620,7 +669,25 @@ pub mod pallet {
 							.can_revoke(caller, &credential.ctype_hash, credential_id, credential_auth_id)
 							.map_err(|_| Error::<T>::NotAuthorized)?
 					};
-					// If authorization checks are ok, update the revocation status.
+					// If authorization checks are ok, update the revocation status and move the
+					// credential to the right place in the index.
+					CredentialsByCtypeAndAttester::<T>::remove((
+						credential_subject,
+						&credential.ctype_hash,
+						&credential.attester,
+						credential.revoked,
+						credential_id,
+					));
+					CredentialsByCtypeAndAttester::<T>::insert(
+						(
+							credential_subject,
+							&credential.ctype_hash,
+							&credential.attester,
+							revocation,
+							credential_id,
+						),
+						(),
+					);
 					credential.revoked = revocation;
 					Ok(additional_weight)
 				} else {
@@ -636,6 +703,7 @@ pub mod pallet {
 		for PublicCredentialDepositCollector<T>
 	{
 		type Currency = <T as Config>::Currency;
+		type DepositIndex = T::DepositIndex;
 		type Reason = HoldReason;
 
 		fn reason() -> Self::Reason {
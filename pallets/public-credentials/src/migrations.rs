@@ -16,11 +16,22 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use frame_support::traits::{fungible::Inspect, ReservableCurrency};
-use kilt_support::migration::switch_reserved_to_hold;
+use frame_support::{
+	traits::{fungible::Inspect, Get, GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency, StorageVersion},
+	weights::Weight,
+};
+use kilt_support::{migration::switch_reserved_to_hold, traits::DepositIndex};
 use sp_runtime::DispatchResult;
+use sp_std::marker::PhantomData;
 
-use crate::{AccountIdOf, Config, CredentialIdOf, Credentials, CurrencyOf, Error, HoldReason, SubjectIdOf};
+use crate::{
+	AccountIdOf, Config, CredentialIdOf, Credentials, CredentialsByCtypeAndAttester, CurrencyOf, Error, HoldReason,
+	Pallet, SubjectIdOf,
+};
+
+const LOG_TARGET: &str = "migration::public-credentials::IndexCredentialsByCtypeAndAttester";
+
+const TARGET_STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 pub fn update_balance_for_public_credentials<T: Config>(
 	key: &SubjectIdOf<T>,
@@ -38,20 +49,107 @@ where
 	)
 }
 
+/// Add the deposit of the credential behind the provided subject and ID to the
+/// deposit index. Used to index deposits taken before the deposit index
+/// existed.
+pub fn index_deposit_for_public_credentials<T: Config>(
+	key: &SubjectIdOf<T>,
+	key2: &CredentialIdOf<T>,
+) -> DispatchResult {
+	let details = Credentials::<T>::get(key, key2).ok_or(Error::<T>::NotFound)?;
+	<T as Config>::DepositIndex::on_deposit_held(
+		&details.deposit.owner,
+		&Credentials::<T>::hashed_key_for(key, key2),
+		&details.deposit.amount,
+	);
+	Ok(())
+}
+
+/// Adds all the credentials stored in `Credentials` to the
+/// `CredentialsByCtypeAndAttester` index.
+pub struct IndexCredentialsByCtypeAndAttester<T>(PhantomData<T>);
+
+impl<T> OnRuntimeUpgrade for IndexCredentialsByCtypeAndAttester<T>
+where
+	T: Config,
+{
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+		if Pallet::<T>::on_chain_storage_version() < TARGET_STORAGE_VERSION {
+			log::trace!(target: LOG_TARGET, "Credentials to be indexed.");
+		} else {
+			log::trace!(target: LOG_TARGET, "Public credentials already on v2. No migration will run.");
+		}
+		Ok([].into())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		log::info!(target: LOG_TARGET, "Initiating migration.");
+
+		if Pallet::<T>::on_chain_storage_version() >= TARGET_STORAGE_VERSION {
+			log::info!(target: LOG_TARGET, "Public credentials already on v2. No migration will run.");
+			return <T as frame_system::Config>::DbWeight::get().reads(1);
+		}
+
+		let mut count = 0u64;
+		Credentials::<T>::iter().for_each(|(subject_id, credential_id, entry)| {
+			CredentialsByCtypeAndAttester::<T>::insert(
+				(
+					&subject_id,
+					&entry.ctype_hash,
+					&entry.attester,
+					entry.revoked,
+					&credential_id,
+				),
+				(),
+			);
+			count = count.saturating_add(1);
+		});
+		log::info!(target: LOG_TARGET, "Indexed {:?} credentials.", count);
+		TARGET_STORAGE_VERSION.put::<Pallet<T>>();
+
+		<T as frame_system::Config>::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		use frame_support::ensure;
+
+		ensure!(
+			Pallet::<T>::on_chain_storage_version() >= TARGET_STORAGE_VERSION,
+			sp_runtime::TryRuntimeError::Other("public-credentials storage version was not updated to v2.")
+		);
+		ensure!(
+			Credentials::<T>::iter().all(|(subject_id, credential_id, entry)| {
+				CredentialsByCtypeAndAttester::<T>::contains_key((
+					&subject_id,
+					&entry.ctype_hash,
+					&entry.attester,
+					entry.revoked,
+					&credential_id,
+				))
+			}),
+			sp_runtime::TryRuntimeError::Other("Not all credentials were indexed.")
+		);
+		Ok(())
+	}
+}
+
 #[cfg(test)]
 pub mod test {
 
 	use ctype::mock::get_ctype_hash;
 	use frame_support::{
 		assert_noop,
-		traits::{fungible::InspectHold, ReservableCurrency},
+		traits::{fungible::InspectHold, GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency, StorageVersion},
 	};
 	use sp_core::Get;
 	use sp_runtime::traits::Zero;
 
 	use crate::{
-		migrations::update_balance_for_public_credentials, mock::*, AccountIdOf, Config, CredentialIdOf, Credentials,
-		Error, HoldReason,
+		migrations::{update_balance_for_public_credentials, IndexCredentialsByCtypeAndAttester},
+		mock::*,
+		AccountIdOf, Config, CredentialIdOf, Credentials, CredentialsByCtypeAndAttester, Error, HoldReason, Pallet,
 	};
 
 	#[test]
@@ -166,4 +264,35 @@ pub mod test {
 				);
 			})
 	}
+
+	#[test]
+	fn indexes_existing_credentials() {
+		let attester = sr25519_did_from_seed(&ALICE_SEED);
+		let ctype_hash = get_ctype_hash::<Test>(true);
+		let subject_id: <Test as Config>::SubjectId = SUBJECT_ID_00;
+		let mut new_credential =
+			generate_base_credential_entry::<Test>(ACCOUNT_00, 0, attester.clone(), Some(ctype_hash), None);
+		new_credential.revoked = true;
+		let credential_id: CredentialIdOf<Test> = CredentialIdOf::<Test>::default();
+		let deposit: Balance = <Test as Config>::Deposit::get();
+
+		ExtBuilder::default()
+			.with_balances(vec![(ACCOUNT_00, deposit + MIN_BALANCE)])
+			.with_public_credentials(vec![(subject_id, credential_id, new_credential)])
+			.with_ctypes(vec![(ctype_hash, attester.clone())])
+			.build_and_execute_with_sanity_tests(|| {
+				// Simulate credentials issued before the index existed.
+				let _ = CredentialsByCtypeAndAttester::<Test>::clear(u32::MAX, None);
+				StorageVersion::new(1).put::<Pallet<Test>>();
+				assert!(Pallet::<Test>::find_credential(&subject_id, &ctype_hash, &attester).is_none());
+
+				IndexCredentialsByCtypeAndAttester::<Test>::on_runtime_upgrade();
+
+				assert_eq!(
+					Pallet::<Test>::find_credential(&subject_id, &ctype_hash, &attester),
+					Some((credential_id, true))
+				);
+				assert_eq!(Pallet::<Test>::on_chain_storage_version(), StorageVersion::new(2));
+			})
+	}
 }
@@ -25,9 +25,9 @@ use sp_runtime::{traits::Hash, DispatchError};
 use kilt_support::{traits::StorageDepositCollector, Deposit};
 
 use crate::{
-	AttesterOf, BalanceOf, Config, CredentialEntryOf, CredentialIdOf, CredentialSubjects, Credentials, CtypeHashOf,
-	InputClaimsContentOf, InputCredentialOf, InputSubjectIdOf, PublicCredentialDepositCollector,
-	PublicCredentialsAccessControl,
+	AttesterOf, BalanceOf, Config, CredentialEntryOf, CredentialIdOf, CredentialSubjects, Credentials,
+	CredentialsByCtypeAndAttester, CtypeHashOf, InputClaimsContentOf, InputCredentialOf, InputSubjectIdOf,
+	PublicCredentialDepositCollector, PublicCredentialsAccessControl,
 };
 
 // Generate a public credential using a many Default::default() as possible.
@@ -85,6 +85,16 @@ pub fn insert_public_credentials<T: Config>(
 	)
 	.expect("Attester should have enough balance");
 
+	CredentialsByCtypeAndAttester::<T>::insert(
+		(
+			&subject_id,
+			&credential_entry.ctype_hash,
+			&credential_entry.attester,
+			credential_entry.revoked,
+			&credential_id,
+		),
+		(),
+	);
 	Credentials::<T>::insert(&subject_id, &credential_id, credential_entry);
 	CredentialSubjects::<T>::insert(credential_id, subject_id);
 }
@@ -351,6 +361,7 @@ pub(crate) mod runtime {
 		type SubjectId = TestSubjectId;
 		type WeightInfo = ();
 		type BalanceMigrationManager = ();
+		type DepositIndex = ();
 	}
 
 	pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
@@ -69,6 +69,10 @@ fn add_successful_without_authorization() {
 			assert_eq!(stored_public_credential_details.ctype_hash, ctype_hash_1);
 			assert_eq!(stored_public_credential_details.authorization_id, None);
 			assert_eq!(CredentialSubjects::<Test>::get(credential_id_1), Some(subject_id));
+			assert_eq!(
+				PublicCredentials::find_credential(&subject_id, &ctype_hash_1, &attester),
+				Some((credential_id_1, false))
+			);
 
 			// Check deposit reservation logic
 			assert_eq!(
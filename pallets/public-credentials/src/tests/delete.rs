@@ -25,7 +25,9 @@ use sp_runtime::traits::Zero;
 use ctype::mock::get_ctype_hash;
 use kilt_support::mock::mock_origin::DoubleOrigin;
 
-use crate::{mock::*, Config, CredentialIdOf, CredentialSubjects, Credentials, Error, HoldReason};
+use crate::{
+	mock::*, Config, CredentialIdOf, CredentialSubjects, Credentials, CredentialsByCtypeAndAttester, Error, HoldReason,
+};
 
 #[test]
 fn remove_successful() {
@@ -48,6 +50,7 @@ fn remove_successful() {
 			// Test this pallet logic
 			assert!(Credentials::<Test>::get(subject_id, credential_id).is_none());
 			assert!(CredentialSubjects::<Test>::get(credential_id).is_none());
+			assert_eq!(CredentialsByCtypeAndAttester::<Test>::iter_keys().count(), 0);
 
 			// Check deposit release logic
 			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00).is_zero());
@@ -49,6 +49,10 @@ fn revoke_successful() {
 
 			// Test this pallet logic
 			assert!(stored_public_credential_details.revoked);
+			assert_eq!(
+				PublicCredentials::find_credential(&subject_id, &ctype_hash_1, &attester),
+				Some((credential_id, true))
+			);
 
 			// Revoking the same credential does nothing
 			assert_ok!(PublicCredentials::revoke(
@@ -196,6 +200,10 @@ fn unrevoke_successful() {
 
 			// Test this pallet logic
 			assert!(!stored_public_credential_details.revoked);
+			assert_eq!(
+				PublicCredentials::find_credential(&subject_id, &ctype_hash_1, &attester),
+				Some((credential_id, false))
+			);
 
 			// Unrevoking the same credential does nothing
 			assert_ok!(PublicCredentials::unrevoke(
@@ -316,3 +324,50 @@ fn unrevoke_credential_not_found() {
 			);
 		});
 }
+
+#[test]
+fn find_credential_prefers_valid_credential() {
+	let attester = sr25519_did_from_seed(&ALICE_SEED);
+	let subject_id: <Test as Config>::SubjectId = SUBJECT_ID_00;
+	let ctype_hash_1 = get_ctype_hash::<Test>(true);
+	let ctype_hash_2 = get_ctype_hash::<Test>(false);
+	let mut revoked_credential =
+		generate_base_credential_entry::<Test>(ACCOUNT_00, 0, attester.clone(), Some(ctype_hash_1), None);
+	revoked_credential.revoked = true;
+	let valid_credential =
+		generate_base_credential_entry::<Test>(ACCOUNT_00, 0, attester.clone(), Some(ctype_hash_1), None);
+	let revoked_credential_id: CredentialIdOf<Test> = CredentialIdOf::<Test>::default();
+	let valid_credential_id: CredentialIdOf<Test> = CredentialIdOf::<Test>::repeat_byte(1);
+	let deposit: Balance = <Test as Config>::Deposit::get();
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, deposit * 2 + MIN_BALANCE)])
+		.with_public_credentials(vec![
+			(subject_id, revoked_credential_id, revoked_credential),
+			(subject_id, valid_credential_id, valid_credential),
+		])
+		.with_ctypes(vec![(ctype_hash_1, attester.clone())])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_eq!(
+				PublicCredentials::find_credential(&subject_id, &ctype_hash_1, &attester),
+				Some((valid_credential_id, false))
+			);
+			// No credential for a different CType or attester
+			assert!(PublicCredentials::find_credential(&subject_id, &ctype_hash_2, &attester).is_none());
+			assert!(
+				PublicCredentials::find_credential(&subject_id, &ctype_hash_1, &sr25519_did_from_seed(&BOB_SEED))
+					.is_none()
+			);
+
+			assert_ok!(PublicCredentials::revoke(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				valid_credential_id,
+				None,
+			));
+			// Only revoked credentials left
+			assert!(matches!(
+				PublicCredentials::find_credential(&subject_id, &ctype_hash_1, &attester),
+				Some((_, true))
+			));
+		});
+}
@@ -16,7 +16,7 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use crate::{Config, CredentialSubjects, Credentials};
+use crate::{Config, CredentialSubjects, Credentials, CredentialsByCtypeAndAttester};
 use frame_support::ensure;
 use kilt_support::test_utils::log_and_return_error_message;
 use scale_info::prelude::format;
@@ -37,9 +37,33 @@ pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 			log_and_return_error_message(format!("Unknown Ctype: {:?}", entry.ctype_hash))
 		);
 
+		ensure!(
+			CredentialsByCtypeAndAttester::<T>::contains_key((
+				&subject_id,
+				&entry.ctype_hash,
+				&entry.attester,
+				entry.revoked,
+				&credential_id
+			)),
+			log_and_return_error_message(format!("Credential not indexed {:?}", credential_id))
+		);
+
 		Ok(())
 	})?;
 
+	CredentialsByCtypeAndAttester::<T>::iter_keys().try_for_each(
+		|(subject_id, ctype_hash, attester, revoked, credential_id)| -> Result<(), TryRuntimeError> {
+			let entry = Credentials::<T>::get(&subject_id, &credential_id).ok_or_else(|| {
+				log_and_return_error_message(format!("Indexed credential not found {:?}", credential_id))
+			})?;
+			ensure!(
+				entry.ctype_hash == ctype_hash && entry.attester == attester && entry.revoked == revoked,
+				log_and_return_error_message(format!("Credential wrongly indexed {:?}", credential_id))
+			);
+			Ok(())
+		},
+	)?;
+
 	CredentialSubjects::<T>::iter().try_for_each(|(credential_id, subject_id)| -> Result<(), TryRuntimeError> {
 		ensure!(
 			Credentials::<T>::contains_key(subject_id, &credential_id),
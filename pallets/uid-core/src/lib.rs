@@ -149,7 +149,7 @@ pub mod pallet {
 	};
 	use frame_system::pallet_prelude::*;
 	use kilt_support::{
-		traits::{BalanceMigrationManager, CallSources, StorageDepositCollector},
+		traits::{BalanceMigrationManager, CallSources, DepositIndex, OnIdentityChange, StorageDepositCollector},
 		Deposit,
 	};
 	use service_endpoints::DidEndpoint;
@@ -318,6 +318,14 @@ pub mod pallet {
 
 		/// Migration manager to handle new created entries
 		type BalanceMigrationManager: BalanceMigrationManager<AccountIdOf<Self>, BalanceOf<Self>>;
+
+		/// The index of deposits by owner, updated whenever a DID deposit is
+		/// taken, changed or released.
+		type DepositIndex: DepositIndex<AccountIdOf<Self>, BalanceOf<Self>>;
+
+		/// The hook called whenever the keys of a DID change or a DID is
+		/// deleted.
+		type IdentityChangeHook: OnIdentityChange<DidIdentifierOf<Self>, AccountIdOf<Self>>;
 	}
 
 	#[pallet::pallet]
@@ -343,7 +351,7 @@ pub mod pallet {
 	///
 	/// It maps from (DID identifier) to a 32-bit counter.
 	#[pallet::storage]
-	pub(crate) type DidEndpointsCount<T> = StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, u32, ValueQuery>;
+	pub type DidEndpointsCount<T> = StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, u32, ValueQuery>;
 
 	/// The set of DIDs that have been deleted and cannot therefore be created
 	/// again for security reasons.
@@ -618,7 +626,7 @@ pub mod pallet {
 		/// - Writes: Did
 		/// # </weight>
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_ed25519_authentication_key().max(<T as pallet::Config>::WeightInfo::set_sr25519_authentication_key()).max(<T as pallet::Config>::WeightInfo::set_ecdsa_authentication_key()))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_ed25519_authentication_key().max(<T as pallet::Config>::WeightInfo::set_sr25519_authentication_key()).max(<T as pallet::Config>::WeightInfo::set_ecdsa_authentication_key()).saturating_add(T::IdentityChangeHook::on_identity_changed_weight()))]
 		pub fn set_authentication_key(
 			origin: OriginFor<T>,
 			new_key: DidVerificationKey<AccountIdOf<T>>,
@@ -639,6 +647,7 @@ pub mod pallet {
 			Self::try_update_did(&did_subject, did_details)?;
 			log::debug!("Authentication key set");
 
+			T::IdentityChangeHook::on_identity_changed(&did_subject);
 			Self::deposit_event(Event::DidUpdated(did_subject));
 			Ok(())
 		}
@@ -660,7 +669,7 @@ pub mod pallet {
 		/// - Writes: Did
 		/// # </weight>
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_ed25519_delegation_key().max(<T as pallet::Config>::WeightInfo::set_sr25519_delegation_key()).max(<T as pallet::Config>::WeightInfo::set_ecdsa_delegation_key()))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_ed25519_delegation_key().max(<T as pallet::Config>::WeightInfo::set_sr25519_delegation_key()).max(<T as pallet::Config>::WeightInfo::set_ecdsa_delegation_key()).saturating_add(T::IdentityChangeHook::on_identity_changed_weight()))]
 		pub fn set_delegation_key(origin: OriginFor<T>, new_key: DidVerificationKey<AccountIdOf<T>>) -> DispatchResult {
 			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
 			let mut did_details = Did::<T>::get(&did_subject).ok_or(Error::<T>::NotFound)?;
@@ -673,6 +682,7 @@ pub mod pallet {
 			Self::try_update_did(&did_subject, did_details)?;
 			log::debug!("Delegation key set");
 
+			T::IdentityChangeHook::on_identity_changed(&did_subject);
 			Self::deposit_event(Event::DidUpdated(did_subject));
 			Ok(())
 		}
@@ -693,7 +703,7 @@ pub mod pallet {
 		/// - Writes: Did
 		/// # </weight>
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_ed25519_delegation_key().max(<T as pallet::Config>::WeightInfo::remove_sr25519_delegation_key()).max(<T as pallet::Config>::WeightInfo::remove_ecdsa_delegation_key()))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_ed25519_delegation_key().max(<T as pallet::Config>::WeightInfo::remove_sr25519_delegation_key()).max(<T as pallet::Config>::WeightInfo::remove_ecdsa_delegation_key()).saturating_add(T::IdentityChangeHook::on_identity_changed_weight()))]
 		pub fn remove_delegation_key(origin: OriginFor<T>) -> DispatchResult {
 			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
 			let mut did_details = Did::<T>::get(&did_subject).ok_or(Error::<T>::NotFound)?;
@@ -704,6 +714,7 @@ pub mod pallet {
 			Self::try_update_did(&did_subject, did_details)?;
 			log::debug!("Delegation key removed");
 
+			T::IdentityChangeHook::on_identity_changed(&did_subject);
 			Self::deposit_event(Event::DidUpdated(did_subject));
 			Ok(())
 		}
@@ -725,7 +736,7 @@ pub mod pallet {
 		/// - Writes: Did
 		/// # </weight>
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_ed25519_attestation_key().max(<T as pallet::Config>::WeightInfo::set_sr25519_attestation_key()).max(<T as pallet::Config>::WeightInfo::set_ecdsa_attestation_key()))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_ed25519_attestation_key().max(<T as pallet::Config>::WeightInfo::set_sr25519_attestation_key()).max(<T as pallet::Config>::WeightInfo::set_ecdsa_attestation_key()).saturating_add(T::IdentityChangeHook::on_identity_changed_weight()))]
 		pub fn set_attestation_key(
 			origin: OriginFor<T>,
 			new_key: DidVerificationKey<AccountIdOf<T>>,
@@ -741,6 +752,7 @@ pub mod pallet {
 			Self::try_update_did(&did_subject, did_details)?;
 			log::debug!("Attestation key set");
 
+			T::IdentityChangeHook::on_identity_changed(&did_subject);
 			Self::deposit_event(Event::DidUpdated(did_subject));
 			Ok(())
 		}
@@ -761,7 +773,7 @@ pub mod pallet {
 		/// - Writes: Did
 		/// # </weight>
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_ed25519_attestation_key().max(<T as pallet::Config>::WeightInfo::remove_sr25519_attestation_key()).max(<T as pallet::Config>::WeightInfo::remove_ecdsa_attestation_key()))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_ed25519_attestation_key().max(<T as pallet::Config>::WeightInfo::remove_sr25519_attestation_key()).max(<T as pallet::Config>::WeightInfo::remove_ecdsa_attestation_key()).saturating_add(T::IdentityChangeHook::on_identity_changed_weight()))]
 		pub fn remove_attestation_key(origin: OriginFor<T>) -> DispatchResult {
 			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
 			let mut did_details = Did::<T>::get(&did_subject).ok_or(Error::<T>::NotFound)?;
@@ -772,6 +784,7 @@ pub mod pallet {
 			Self::try_update_did(&did_subject, did_details)?;
 			log::debug!("Attestation key removed");
 
+			T::IdentityChangeHook::on_identity_changed(&did_subject);
 			Self::deposit_event(Event::DidUpdated(did_subject));
 			Ok(())
 		}
@@ -791,7 +804,7 @@ pub mod pallet {
 		/// - Writes: Did
 		/// # </weight>
 		#[pallet::call_index(6)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_ed25519_key_agreement_key().max(<T as pallet::Config>::WeightInfo::add_sr25519_key_agreement_key()).max(<T as pallet::Config>::WeightInfo::add_ecdsa_key_agreement_key()))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_ed25519_key_agreement_key().max(<T as pallet::Config>::WeightInfo::add_sr25519_key_agreement_key()).max(<T as pallet::Config>::WeightInfo::add_ecdsa_key_agreement_key()).saturating_add(T::IdentityChangeHook::on_identity_changed_weight()))]
 		pub fn add_key_agreement_key(origin: OriginFor<T>, new_key: DidEncryptionKey) -> DispatchResult {
 			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
 			let mut did_details = Did::<T>::get(&did_subject).ok_or(Error::<T>::NotFound)?;
@@ -804,6 +817,7 @@ pub mod pallet {
 			Self::try_update_did(&did_subject, did_details)?;
 			log::debug!("Key agreement key set");
 
+			T::IdentityChangeHook::on_identity_changed(&did_subject);
 			Self::deposit_event(Event::DidUpdated(did_subject));
 			Ok(())
 		}
@@ -822,7 +836,7 @@ pub mod pallet {
 		/// - Writes: Did
 		/// # </weight>
 		#[pallet::call_index(7)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_ed25519_key_agreement_key().max(<T as pallet::Config>::WeightInfo::remove_sr25519_key_agreement_key()).max(<T as pallet::Config>::WeightInfo::remove_ecdsa_key_agreement_key()))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_ed25519_key_agreement_key().max(<T as pallet::Config>::WeightInfo::remove_sr25519_key_agreement_key()).max(<T as pallet::Config>::WeightInfo::remove_ecdsa_key_agreement_key()).saturating_add(T::IdentityChangeHook::on_identity_changed_weight()))]
 		pub fn remove_key_agreement_key(origin: OriginFor<T>, key_id: KeyIdOf<T>) -> DispatchResult {
 			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
 			let mut did_details = Did::<T>::get(&did_subject).ok_or(Error::<T>::NotFound)?;
@@ -833,6 +847,7 @@ pub mod pallet {
 			Self::try_update_did(&did_subject, did_details)?;
 			log::debug!("Key agreement key removed");
 
+			T::IdentityChangeHook::on_identity_changed(&did_subject);
 			Self::deposit_event(Event::DidUpdated(did_subject));
 			Ok(())
 		}
@@ -953,7 +968,7 @@ pub mod pallet {
 		/// - Kills: Did entry associated to the DID identifier
 		/// # </weight>
 		#[pallet::call_index(10)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::delete(*endpoints_to_remove))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::delete(*endpoints_to_remove).saturating_add(T::IdentityChangeHook::on_identity_deleted_weight()))]
 		pub fn delete(origin: OriginFor<T>, endpoints_to_remove: u32) -> DispatchResult {
 			let source = T::EnsureOrigin::ensure_origin(origin)?;
 			let did_subject = source.subject();
@@ -983,7 +998,7 @@ pub mod pallet {
 		/// - Kills: Did entry associated to the DID identifier
 		/// # </weight>
 		#[pallet::call_index(11)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::reclaim_deposit(*endpoints_to_remove))]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reclaim_deposit(*endpoints_to_remove).saturating_add(T::IdentityChangeHook::on_identity_deleted_weight()))]
 		pub fn reclaim_deposit(
 			origin: OriginFor<T>,
 			did_subject: DidIdentifierOf<T>,
@@ -1275,6 +1290,7 @@ pub mod pallet {
 			<T as Config>::BalanceMigrationManager::exclude_key_from_migration(&Did::<T>::hashed_key_for(
 				&did_identifier,
 			));
+			DidDepositCollector::<T>::index_deposit(&did_identifier, &did_entry.deposit)?;
 
 			Did::<T>::insert(&did_identifier, did_entry);
 
@@ -1325,6 +1341,7 @@ pub mod pallet {
 						<T as Config>::BalanceMigrationManager::exclude_key_from_migration(&hashed_key);
 						did_details.deposit.amount = new_required_deposit;
 					}
+					DidDepositCollector::<T>::index_deposit(did_subject, &did_details.deposit)?;
 				}
 				core::cmp::Ordering::Less => {
 					let deposit_to_release = did_details.deposit.amount.saturating_sub(new_required_deposit);
@@ -1340,6 +1357,7 @@ pub mod pallet {
 						);
 					}
 					did_details.deposit.amount = did_details.deposit.amount.saturating_sub(deposit_to_release);
+					DidDepositCollector::<T>::index_deposit(did_subject, &did_details.deposit)?;
 				}
 				_ => (),
 			};
@@ -1486,8 +1504,10 @@ pub mod pallet {
 
 			DidEndpointsCount::<T>::remove(&did_subject);
 
+			let deposit_owner = did_entry.deposit.owner.clone();
 			let is_key_migrated =
 				<T as Config>::BalanceMigrationManager::is_key_migrated(&Did::<T>::hashed_key_for(did_subject.clone()));
+			DidDepositCollector::<T>::unindex_deposit(&did_subject, &deposit_owner)?;
 			if is_key_migrated {
 				DidDepositCollector::<T>::free_deposit(did_entry.deposit)?;
 			} else {
@@ -1502,6 +1522,7 @@ pub mod pallet {
 
 			log::debug!("Deleting DID {:?}", did_subject);
 
+			T::IdentityChangeHook::on_identity_deleted(&did_subject, &deposit_owner);
 			Self::deposit_event(Event::DidDeleted(did_subject));
 
 			Ok(())
@@ -1513,6 +1534,7 @@ pub mod pallet {
 		for DidDepositCollector<T>
 	{
 		type Currency = <T as Config>::Currency;
+		type DepositIndex = T::DepositIndex;
 		type Reason = HoldReason;
 
 		fn reason() -> Self::Reason {
@@ -175,6 +175,8 @@ impl Config for Test {
 	type MaxNumberOfTypesPerService = MaxNumberOfTypesPerService;
 	type MaxNumberOfUrlsPerService = MaxNumberOfUrlsPerService;
 	type BalanceMigrationManager = ();
+	type DepositIndex = ();
+	type IdentityChangeHook = ();
 }
 
 parameter_types! {
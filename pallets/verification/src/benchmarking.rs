@@ -197,6 +197,27 @@ benchmarks! {
 		}));
 	}
 
+	bind_subject {
+		let attester: T::AttesterId = account("attester", 0, SEED);
+		let subject: T::AttesterId = account("subject", 0, SEED);
+		let sender: T::AccountId = account("sender", 0, SEED);
+		let claim_hash: T::Hash = T::Hashing::hash(b"claim");
+		let ctype_hash: T::Hash = T::Hash::default();
+
+		ctype::Ctypes::<T>::insert(ctype_hash, CtypeEntryOf::<T> {
+			creator: attester.clone(),
+			created_at: 0u64.into()
+		});
+		<T as Config>::Currency::set_balance(&sender, <T as Config>::Deposit::get() + <T as Config>::Deposit::get());
+
+		let origin = <T as Config>::EnsureOrigin::generate_origin(sender, attester);
+		Pallet::<T>::add(origin.clone(), claim_hash, ctype_hash, None)?;
+	}: _<T::RuntimeOrigin>(origin, claim_hash, subject.clone())
+	verify {
+		assert_eq!(VerificationSubjects::<T>::get(claim_hash), Some(subject.clone()));
+		assert!(SubjectVerifications::<T>::contains_key(subject, claim_hash));
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::ExtBuilder::default().build_with_keystore(),
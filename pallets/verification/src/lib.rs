@@ -98,7 +98,7 @@ pub mod pallet {
 
 	use uid_credential::CtypeHashOf;
 	use kilt_support::{
-		traits::{BalanceMigrationManager, CallSources, StorageDepositCollector},
+		traits::{BalanceMigrationManager, CallSources, DepositIndex, StorageDepositCollector},
 		Deposit,
 	};
 
@@ -111,6 +111,10 @@ pub mod pallet {
 	/// Type of an attester identifier.
 	pub type AttesterOf<T> = <T as Config>::AttesterId;
 
+	/// Type of a verification subject identifier. Subjects are identified the
+	/// same way attesters are.
+	pub type SubjectOf<T> = <T as Config>::AttesterId;
+
 	/// Authorization id type
 	pub(crate) type AuthorizationIdOf<T> = <T as Config>::AuthorizationId;
 
@@ -166,6 +170,10 @@ pub mod pallet {
 
 		/// Migration manager to handle new created entries
 		type BalanceMigrationManager: BalanceMigrationManager<AccountIdOf<Self>, BalanceOf<Self>>;
+
+		/// The index of deposits by owner, updated whenever a verification deposit is
+		/// taken, changed or released.
+		type DepositIndex: DepositIndex<AccountIdOf<Self>, BalanceOf<Self>>;
 	}
 
 	#[pallet::pallet]
@@ -195,6 +203,21 @@ pub mod pallet {
 	pub type ExternalVerifications<T> =
 		StorageDoubleMap<_, Twox64Concat, AuthorizationIdOf<T>, Blake2_128Concat, ClaimHashOf<T>, bool, ValueQuery>;
 
+	/// The subjects verifications have been bound to by their attesters.
+	///
+	/// It maps from a claim hash to the subject of the verification.
+	#[pallet::storage]
+	#[pallet::getter(fn verification_subjects)]
+	pub type VerificationSubjects<T> = StorageMap<_, Blake2_128Concat, ClaimHashOf<T>, SubjectOf<T>>;
+
+	/// A reverse index of [`VerificationSubjects`].
+	///
+	/// It maps from a subject to the claim hashes of the verifications bound to
+	/// it.
+	#[pallet::storage]
+	pub type SubjectVerifications<T> =
+		StorageDoubleMap<_, Blake2_128Concat, SubjectOf<T>, Blake2_128Concat, ClaimHashOf<T>, ()>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -215,6 +238,9 @@ pub mod pallet {
 		/// The deposit owner reclaimed a deposit by removing a verification credential.
 		/// \[account id, claim hash\]
 		DepositReclaimed(AccountIdOf<T>, ClaimHashOf<T>),
+		/// A verification credential has been bound to its subject.
+		/// \[attester ID, claim hash, subject ID\]
+		VerificationSubjectBound(AttesterOf<T>, ClaimHashOf<T>, SubjectOf<T>),
 	}
 
 	#[pallet::error]
@@ -235,6 +261,8 @@ pub mod pallet {
 		/// reached for the corresponding delegation id such that another one
 		/// cannot be added.
 		MaxDelegatedVerificationsExceeded,
+		/// The verification credential has already been bound to a subject.
+		SubjectAlreadyBound,
 	}
 
 	#[pallet::call]
@@ -296,6 +324,7 @@ pub mod pallet {
 			<T as Config>::BalanceMigrationManager::exclude_key_from_migration(&Verifications::<T>::hashed_key_for(
 				claim_hash,
 			));
+			VerificationStorageDepositCollector::<T>::index_deposit(&claim_hash, &deposit)?;
 
 			log::debug!("insert Verification");
 
@@ -389,9 +418,11 @@ pub mod pallet {
 		/// Weight: O(P) where P is the number of steps required to verify that
 		/// the dispatch Origin controls the delegation entitled to revoke the
 		/// verification credential. It is bounded by `max_parent_checks`.
-		/// - Reads: [Origin Account], Verifications, delegation::Roots
+		/// - Reads: [Origin Account], Verifications, VerificationSubjects,
+		///   delegation::Roots
 		/// - Reads per delegation step P: delegation::Delegations
-		/// - Writes: Verifications, DelegatedVerifications
+		/// - Writes: Verifications, DelegatedVerifications, VerificationSubjects,
+		///   SubjectVerifications
 		/// # </weight>
 		#[pallet::call_index(2)]
 		#[pallet::weight(
@@ -432,8 +463,10 @@ pub mod pallet {
 		///
 		/// # <weight>
 		/// Weight: O(1)
-		/// - Reads: [Origin Account], Verifications, DelegatedVerifications
-		/// - Writes: Verifications, DelegatedVerifications
+		/// - Reads: [Origin Account], Verifications, DelegatedVerifications,
+		///   VerificationSubjects
+		/// - Writes: Verifications, DelegatedVerifications, VerificationSubjects,
+		///   SubjectVerifications
 		/// # </weight>
 		#[pallet::call_index(3)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::reclaim_deposit())]
@@ -491,12 +524,50 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Bind an existing verification credential to the subject it was
+		/// issued to, so that the verifications held by a subject can be
+		/// looked up on chain.
+		///
+		/// The subject of the call must be the attester who issued the
+		/// verification credential, and a verification credential can only be
+		/// bound once. The binding is removed together with the verification
+		/// credential.
+		///
+		/// Emits `VerificationSubjectBound`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: [Origin Account], Verifications, VerificationSubjects
+		/// - Writes: VerificationSubjects, SubjectVerifications
+		/// # </weight>
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::bind_subject())]
+		pub fn bind_subject(origin: OriginFor<T>, claim_hash: ClaimHashOf<T>, subject: SubjectOf<T>) -> DispatchResult {
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let who = source.subject();
+
+			let verification = Verifications::<T>::get(claim_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(verification.attester == who, Error::<T>::NotAuthorized);
+			ensure!(
+				!VerificationSubjects::<T>::contains_key(claim_hash),
+				Error::<T>::SubjectAlreadyBound
+			);
+
+			VerificationSubjects::<T>::insert(claim_hash, subject.clone());
+			SubjectVerifications::<T>::insert(&subject, claim_hash, ());
+
+			Self::deposit_event(Event::VerificationSubjectBound(who, claim_hash, subject));
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		fn remove_verification(verification: VerificationDetailsOf<T>, claim_hash: ClaimHashOf<T>) -> DispatchResult {
 			let is_key_migrated =
 				<T as Config>::BalanceMigrationManager::is_key_migrated(&Verifications::<T>::hashed_key_for(claim_hash));
+			VerificationStorageDepositCollector::<T>::unindex_deposit(&claim_hash, &verification.deposit.owner)?;
 			if is_key_migrated {
 				VerificationStorageDepositCollector::<T>::free_deposit(verification.deposit)?;
 			} else {
@@ -510,6 +581,9 @@ pub mod pallet {
 			if let Some(authorization_id) = &verification.authorization_id {
 				ExternalVerifications::<T>::remove(authorization_id, claim_hash);
 			}
+			if let Some(subject) = VerificationSubjects::<T>::take(claim_hash) {
+				SubjectVerifications::<T>::remove(subject, claim_hash);
+			}
 			Ok(())
 		}
 	}
@@ -519,6 +593,7 @@ pub mod pallet {
 		for VerificationStorageDepositCollector<T>
 	{
 		type Currency = <T as Config>::Currency;
+		type DepositIndex = T::DepositIndex;
 		type Reason = HoldReason;
 
 		fn reason() -> Self::Reason {
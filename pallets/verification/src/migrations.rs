@@ -20,7 +20,7 @@ use frame_support::{
 	pallet_prelude::DispatchResult,
 	traits::{fungible::Inspect, ReservableCurrency},
 };
-use kilt_support::migration::switch_reserved_to_hold;
+use kilt_support::{migration::switch_reserved_to_hold, traits::DepositIndex};
 
 use crate::{AccountIdOf, Verifications, ClaimHashOf, Config, CurrencyOf, Error, HoldReason};
 
@@ -37,6 +37,18 @@ where
 	)
 }
 
+/// Add the deposit of the verification behind the provided key to the deposit
+/// index. Used to index deposits taken before the deposit index existed.
+pub fn index_deposit_for_verification<T: Config>(key: &ClaimHashOf<T>) -> DispatchResult {
+	let details = Verifications::<T>::get(key).ok_or(Error::<T>::NotFound)?;
+	<T as Config>::DepositIndex::on_deposit_held(
+		&details.deposit.owner,
+		&Verifications::<T>::hashed_key_for(key),
+		&details.deposit.amount,
+	);
+	Ok(())
+}
+
 #[cfg(test)]
 pub mod test {
 	use ctype::mock::get_ctype_hash;
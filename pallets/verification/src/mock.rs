@@ -315,6 +315,7 @@ pub(crate) mod runtime {
 		type AuthorizationId = SubjectId;
 		type AccessControl = MockAccessControl<Self>;
 		type BalanceMigrationManager = ();
+		type DepositIndex = ();
 	}
 
 	pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
@@ -0,0 +1,138 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::{assert_noop, assert_ok};
+use kilt_support::mock::mock_origin::DoubleOrigin;
+
+use crate::{self as verification, mock::*, AttesterOf, Config, SubjectVerifications};
+
+#[test]
+fn test_bind_subject() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let subject: AttesterOf<Test> = sr25519_did_from_public_key(&BOB_SEED);
+	let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+	let verification = generate_base_verification::<Test>(attester.clone(), ACCOUNT_00);
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.with_ctypes(vec![(verification.ctype_hash, attester.clone())])
+		.with_verifications(vec![(claim_hash, verification)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Verification::bind_subject(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash,
+				subject.clone()
+			));
+			assert_eq!(Verification::verification_subjects(claim_hash), Some(subject.clone()));
+			assert!(SubjectVerifications::<Test>::contains_key(&subject, claim_hash));
+		});
+}
+
+#[test]
+fn test_bind_subject_already_bound() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let subject: AttesterOf<Test> = sr25519_did_from_public_key(&BOB_SEED);
+	let other_subject: AttesterOf<Test> = sr25519_did_from_public_key(&CHARLIE_SEED);
+	let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+	let verification = generate_base_verification::<Test>(attester.clone(), ACCOUNT_00);
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.with_ctypes(vec![(verification.ctype_hash, attester.clone())])
+		.with_verifications(vec![(claim_hash, verification)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Verification::bind_subject(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash,
+				subject.clone()
+			));
+			assert_noop!(
+				Verification::bind_subject(
+					DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+					claim_hash,
+					other_subject.clone()
+				),
+				verification::Error::<Test>::SubjectAlreadyBound
+			);
+			assert!(!SubjectVerifications::<Test>::contains_key(&other_subject, claim_hash));
+		});
+}
+
+#[test]
+fn test_bind_subject_unauthorized() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let evil: AttesterOf<Test> = sr25519_did_from_public_key(&BOB_SEED);
+	let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+	let verification = generate_base_verification::<Test>(attester.clone(), ACCOUNT_00);
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.with_ctypes(vec![(verification.ctype_hash, attester)])
+		.with_verifications(vec![(claim_hash, verification)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Verification::bind_subject(DoubleOrigin(ACCOUNT_00, evil.clone()).into(), claim_hash, evil.clone()),
+				verification::Error::<Test>::NotAuthorized
+			);
+		});
+}
+
+#[test]
+fn test_bind_subject_not_found() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				Verification::bind_subject(
+					DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+					claim_hash,
+					attester.clone()
+				),
+				verification::Error::<Test>::NotFound
+			);
+		});
+}
+
+#[test]
+fn test_remove_unbinds_subject() {
+	let attester: AttesterOf<Test> = sr25519_did_from_public_key(&ALICE_SEED);
+	let subject: AttesterOf<Test> = sr25519_did_from_public_key(&BOB_SEED);
+	let claim_hash = claim_hash_from_seed(CLAIM_HASH_SEED_01);
+	let verification = generate_base_verification::<Test>(attester.clone(), ACCOUNT_00);
+
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as Config>::Deposit::get() * 100)])
+		.with_ctypes(vec![(verification.ctype_hash, attester.clone())])
+		.with_verifications(vec![(claim_hash, verification)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(Verification::bind_subject(
+				DoubleOrigin(ACCOUNT_00, attester.clone()).into(),
+				claim_hash,
+				subject.clone()
+			));
+			assert_ok!(Verification::reclaim_deposit(
+				RuntimeOrigin::signed(ACCOUNT_00),
+				claim_hash
+			));
+			assert!(Verification::verification_subjects(claim_hash).is_none());
+			assert!(!SubjectVerifications::<Test>::contains_key(&subject, claim_hash));
+		});
+}
@@ -16,6 +16,7 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
+mod bind_subject;
 mod claim;
 mod delete;
 mod deposit;
@@ -21,7 +21,7 @@ use kilt_support::test_utils::log_and_return_error_message;
 use scale_info::prelude::format;
 use sp_runtime::TryRuntimeError;
 
-use crate::{Verifications, Config, ExternalVerifications};
+use crate::{Config, ExternalVerifications, SubjectVerifications, VerificationSubjects, Verifications};
 
 pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 	Verifications::<T>::iter().try_for_each(|(claim_hash, verification_details)| -> Result<(), TryRuntimeError> {
@@ -35,5 +35,22 @@ pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 			);
 		}
 		Ok(())
+	})?;
+	VerificationSubjects::<T>::iter().try_for_each(|(claim_hash, subject)| -> Result<(), TryRuntimeError> {
+		ensure!(
+			Verifications::<T>::contains_key(claim_hash),
+			log_and_return_error_message(format!(
+				"Verification subject binding for claim_hash {:?} has no verification",
+				claim_hash
+			))
+		);
+		ensure!(
+			SubjectVerifications::<T>::contains_key(&subject, claim_hash),
+			log_and_return_error_message(format!(
+				"Subject verification with subject {:?} and claim_hash {:?} does not exist",
+				subject, claim_hash
+			))
+		);
+		Ok(())
 	})
 }
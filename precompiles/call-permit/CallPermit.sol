@@ -41,12 +41,153 @@ interface CallPermit {
         bytes32 s
     ) external returns (bytes memory output);
 
+    /// @dev Dispatch a call on the behalf of an other user with a EIP712 permit using an unordered nonce.
+    /// The permit is of type
+    /// UnorderedCallPermit(address from,address to,uint256 value,bytes data,uint64 gaslimit,uint256 nonce,uint256 deadline)
+    /// Unordered nonces can be used in any order, allowing several permits to be issued in parallel.
+    /// Will revert if the permit is not valid, if the nonce has already been used or if the
+    /// dispatched call reverts or errors (such as out of gas).
+    /// If successful the nonce is marked as used to prevent this permit to be replayed.
+    /// @param from Who made the permit and want its call to be dispatched on their behalf.
+    /// @param to Which address the call is made to.
+    /// @param value Value being transfered from the "from" account.
+    /// @param data Call data
+    /// @param gaslimit Gaslimit the dispatched call requires.
+    ///     Providing it prevents the dispatcher to manipulate the gaslimit.
+    /// @param nonce Unordered nonce of the permit.
+    /// @param deadline Deadline in UNIX seconds after which the permit will no longer be valid.
+    /// @param v V part of the signature.
+    /// @param r R part of the signature.
+    /// @param s S part of the signature.
+    /// @return output Output of the call.
+    /// @custom:selector 21efed4a
+    function dispatchUnordered(
+        address from,
+        address to,
+        uint256 value,
+        bytes memory data,
+        uint64 gaslimit,
+        uint256 nonce,
+        uint256 deadline,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    ) external returns (bytes memory output);
+
+    /// @dev Dispatch a batch of calls on the behalf of an other user with a single EIP712 permit.
+    /// The permit is of type
+    /// BatchCallPermit(address from,address[] to,uint256[] value,bytes[] data,uint64[] gaslimit,uint8 mode,uint256 nonce,uint256 deadline)
+    /// and consumes a single nonce for the whole batch.
+    /// Will revert if the permit is not valid, if the arrays have different lengths or if any
+    /// subcall errors fatally.
+    /// @param from Who made the permit and want its calls to be dispatched on their behalf.
+    /// @param to Which addresses the calls are made to.
+    /// @param value Values being transfered from the "from" account, one per call.
+    /// @param data Call data, one per call.
+    /// @param gaslimit Gaslimit each dispatched call requires.
+    ///     Providing it prevents the dispatcher to manipulate the gaslimit.
+    /// @param mode How failing calls are handled:
+    ///     0 = all-or-nothing, the batch reverts with the revert reason of the first failing call.
+    ///     1 = continue-on-failure, the remaining calls are still dispatched.
+    /// @param deadline Deadline in UNIX seconds after which the permit will no longer be valid.
+    /// @param v V part of the signature.
+    /// @param r R part of the signature.
+    /// @param s S part of the signature.
+    /// @return success Whether each call succeeded.
+    /// @return output Output of each call, or its revert reason if it failed.
+    /// @custom:selector 55de2203
+    function batchDispatch(
+        address from,
+        address[] memory to,
+        uint256[] memory value,
+        bytes[] memory data,
+        uint64[] memory gaslimit,
+        uint8 mode,
+        uint256 deadline,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    ) external returns (bool[] memory success, bytes[] memory output);
+
     /// @dev Returns the current nonce for given owner.
     /// A permit must have this nonce to be consumed, which will
     /// increase the nonce by one.
     /// @custom:selector 7ecebe00
     function nonces(address owner) external view returns (uint256);
 
+    /// @dev Consumes the current nonce of the caller, invalidating any permit signed with it.
+    /// Reverts when called via DELEGATECALL or CALLCODE.
+    /// @return The consumed nonce.
+    /// @custom:selector 69615a4c
+    function useNonce() external returns (uint256);
+
+    /// @dev Increases the nonce of the caller, invalidating all permits signed with a lower nonce.
+    /// The nonce can be increased by at most 65536 at once.
+    /// Reverts when called via DELEGATECALL or CALLCODE.
+    /// @param newNonce The new nonce of the caller.
+    /// @custom:selector 22f888e7
+    function invalidateNonces(uint256 newNonce) external;
+
+    /// @dev Marks unordered nonces of the caller as used, invalidating the permits signed with them.
+    /// The nonce n is bit (n % 256) of word (n / 256).
+    /// Reverts when called via DELEGATECALL or CALLCODE.
+    /// @param wordPos Position of the word in the nonce bitmap.
+    /// @param mask Bits of the word to mark as used.
+    /// @custom:selector 3ff9dcb1
+    function invalidateUnorderedNonces(uint256 wordPos, uint256 mask) external;
+
+    /// @dev Returns a word of the unordered nonce bitmap of given owner.
+    /// @custom:selector 4fe02b44
+    function nonceBitmap(address owner, uint256 wordPos) external view returns (uint256);
+
+    /// @dev Returns whether a permit signed with the current nonce of "from" can still be dispatched,
+    /// i.e. it has not expired and its signature is valid.
+    /// @custom:selector dd552ac6
+    function isPermitExecutable(
+        address from,
+        address to,
+        uint256 value,
+        bytes memory data,
+        uint64 gaslimit,
+        uint256 deadline,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    ) external view returns (bool);
+
+    /// @dev Returns whether a permit with an unordered nonce can still be dispatched,
+    /// i.e. its nonce is unused, it has not expired and its signature is valid.
+    /// @custom:selector 57569223
+    function isUnorderedPermitExecutable(
+        address from,
+        address to,
+        uint256 value,
+        bytes memory data,
+        uint64 gaslimit,
+        uint256 nonce,
+        uint256 deadline,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    ) external view returns (bool);
+
+    /// @dev Returns whether a batch permit signed with the current nonce of "from" can still be
+    /// dispatched, i.e. it has not expired, its signature is valid, its arrays have the same length
+    /// and its mode is valid. Whether the subcalls will succeed is not checked.
+    /// @custom:selector fe74aa3b
+    function isBatchPermitExecutable(
+        address from,
+        address[] memory to,
+        uint256[] memory value,
+        bytes[] memory data,
+        uint64[] memory gaslimit,
+        uint8 mode,
+        uint256 deadline,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    ) external view returns (bool);
+
     /// @dev Returns the EIP712 domain separator. It is used to avoid replay
     /// attacks accross assets or other similar EIP712 message structures.
     /// @custom:selector 3644e515
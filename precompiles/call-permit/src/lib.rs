@@ -16,15 +16,15 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use pallet_timestamp as timestamp;
 use core::marker::PhantomData;
+use pallet_timestamp as timestamp;
 // use evm::ExitReason;
-use fp_evm::{Context, ExitRevert, ExitReason, PrecompileFailure, PrecompileHandle, Transfer};
+use fp_evm::{Context, ExitReason, ExitRevert, PrecompileFailure, PrecompileHandle, Transfer};
 use frame_support::{
-    ensure,
-    storage::types::{StorageMap, ValueQuery},
-    traits::{ConstU32, Get, StorageInstance},
-    Blake2_128Concat,
+	ensure,
+	storage::types::{StorageDoubleMap, StorageMap, ValueQuery},
+	traits::{ConstU32, Get, StorageInstance},
+	Blake2_128Concat,
 };
 use precompile_utils::{evm::costs::call_cost, prelude::*};
 use sp_core::{H160, H256, U256};
@@ -41,36 +41,97 @@ mod test;
 pub struct Nonces;
 
 impl StorageInstance for Nonces {
-    const STORAGE_PREFIX: &'static str = "Nonces";
+	const STORAGE_PREFIX: &'static str = "Nonces";
 
-    fn pallet_prefix() -> &'static str {
-        "PrecompileCallPermit"
-    }
+	fn pallet_prefix() -> &'static str {
+		"PrecompileCallPermit"
+	}
 }
 
 /// Storage type used to store EIP2612 nonces.
 pub type NoncesStorage = StorageMap<
-    Nonces,
-    // From
-    Blake2_128Concat,
-    H160,
-    // Nonce
-    U256,
-    ValueQuery,
+	Nonces,
+	// From
+	Blake2_128Concat,
+	H160,
+	// Nonce
+	U256,
+	ValueQuery,
+>;
+
+/// Storage prefix for unordered nonce bitmaps.
+pub struct NonceBitmaps;
+
+impl StorageInstance for NonceBitmaps {
+	const STORAGE_PREFIX: &'static str = "NonceBitmaps";
+
+	fn pallet_prefix() -> &'static str {
+		"PrecompileCallPermit"
+	}
+}
+
+/// Storage type used to store the bitmaps of used unordered nonces.
+/// Each word stores 256 nonces, the nonce `n` being bit `n % 256` of word `n / 256`.
+pub type NonceBitmapsStorage = StorageDoubleMap<
+	NonceBitmaps,
+	// From
+	Blake2_128Concat,
+	H160,
+	// Word position
+	Blake2_128Concat,
+	U256,
+	// Bitmap
+	U256,
+	ValueQuery,
 >;
 
 /// EIP712 permit typehash.
 pub const PERMIT_TYPEHASH: [u8; 32] = keccak256!(
-    "CallPermit(address from,address to,uint256 value,bytes data,uint64 gaslimit\
+	"CallPermit(address from,address to,uint256 value,bytes data,uint64 gaslimit\
 ,uint256 nonce,uint256 deadline)"
 );
 
-/// EIP712 permit domain used to compute an individualized domain separator.
-const PERMIT_DOMAIN: [u8; 32] = keccak256!(
-    "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+/// EIP712 unordered permit typehash.
+pub const UNORDERED_PERMIT_TYPEHASH: [u8; 32] = keccak256!(
+	"UnorderedCallPermit(address from,address to,uint256 value,bytes data,uint64 gaslimit\
+,uint256 nonce,uint256 deadline)"
+);
+
+/// EIP712 batch permit typehash.
+pub const BATCH_PERMIT_TYPEHASH: [u8; 32] = keccak256!(
+	"BatchCallPermit(address from,address[] to,uint256[] value,bytes[] data,uint64[] gaslimit\
+,uint8 mode,uint256 nonce,uint256 deadline)"
 );
 
+/// EIP712 permit domain used to compute an individualized domain separator.
+const PERMIT_DOMAIN: [u8; 32] =
+	keccak256!("EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)");
+
 pub const CALL_DATA_LIMIT: u32 = 2u32.pow(16);
+pub const BATCH_SIZE_LIMIT: u32 = 2u32.pow(6);
+/// Maximum amount by which a nonce can be increased at once, so that it can never overflow.
+pub const MAX_NONCE_INCREASE: u32 = 2u32.pow(16);
+
+/// How a batch permit handles subcalls that do not succeed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatchMode {
+	/// Revert the whole batch with the reason of the first failing subcall.
+	AllOrNothing,
+	/// Keep dispatching the remaining subcalls and report which ones failed.
+	ContinueOnFailure,
+}
+
+impl TryFrom<u8> for BatchMode {
+	type Error = ();
+
+	fn try_from(mode: u8) -> Result<Self, Self::Error> {
+		match mode {
+			0 => Ok(BatchMode::AllOrNothing),
+			1 => Ok(BatchMode::ContinueOnFailure),
+			_ => Err(()),
+		}
+	}
+}
 
 /// Precompile allowing to issue and dispatch call permits for gasless transactions.
 /// A user can sign a permit for a call that can be dispatched and paid by another user or
@@ -80,186 +141,695 @@ pub struct CallPermitPrecompile<Runtime>(PhantomData<Runtime>);
 #[precompile_utils::precompile]
 impl<Runtime> CallPermitPrecompile<Runtime>
 where
-    Runtime: pallet_evm::Config + pallet_timestamp::Config,
+	Runtime: pallet_evm::Config + pallet_timestamp::Config,
 {
-    fn compute_domain_separator(address: H160) -> [u8; 32] {
-        let name: H256 = keccak_256(b"Call Permit Precompile").into();
-        let version: H256 = keccak256!("1").into();
-        let chain_id: U256 = Runtime::ChainId::get().into();
-
-        let domain_separator_inner = solidity::encode_arguments((
-            H256::from(PERMIT_DOMAIN),
-            name,
-            version,
-            chain_id,
-            Address(address),
-        ));
-
-        keccak_256(&domain_separator_inner).into()
-    }
-
-    pub fn generate_permit(
-        address: H160,
-        from: H160,
-        to: H160,
-        value: U256,
-        data: Vec<u8>,
-        gaslimit: u64,
-        nonce: U256,
-        deadline: U256,
-    ) -> [u8; 32] {
-        let domain_separator = Self::compute_domain_separator(address);
-
-        let permit_content = solidity::encode_arguments((
-            H256::from(PERMIT_TYPEHASH),
-            Address(from),
-            Address(to),
-            value,
-            // bytes are encoded as the keccak_256 of the content
-            H256::from(keccak_256(&data)),
-            gaslimit,
-            nonce,
-            deadline,
-        ));
-        let permit_content = keccak_256(&permit_content);
-        let mut pre_digest = Vec::with_capacity(2 + 32 + 32);
-        pre_digest.extend_from_slice(b"\x19\x01");
-        pre_digest.extend_from_slice(&domain_separator);
-        pre_digest.extend_from_slice(&permit_content);
-        keccak_256(&pre_digest)
-    }
-
-    pub fn dispatch_inherent_cost() -> u64 {
-        3_000 // cost of ECRecover precompile for reference
+	fn compute_domain_separator(address: H160) -> [u8; 32] {
+		let name: H256 = keccak_256(b"Call Permit Precompile").into();
+		let version: H256 = keccak256!("1").into();
+		let chain_id: U256 = Runtime::ChainId::get().into();
+
+		let domain_separator_inner =
+			solidity::encode_arguments((H256::from(PERMIT_DOMAIN), name, version, chain_id, Address(address)));
+
+		keccak_256(&domain_separator_inner).into()
+	}
+
+	pub fn generate_permit(
+		address: H160,
+		from: H160,
+		to: H160,
+		value: U256,
+		data: Vec<u8>,
+		gaslimit: u64,
+		nonce: U256,
+		deadline: U256,
+	) -> [u8; 32] {
+		Self::generate_permit_of_type(
+			PERMIT_TYPEHASH,
+			address,
+			from,
+			to,
+			value,
+			data,
+			gaslimit,
+			nonce,
+			deadline,
+		)
+	}
+
+	pub fn generate_unordered_permit(
+		address: H160,
+		from: H160,
+		to: H160,
+		value: U256,
+		data: Vec<u8>,
+		gaslimit: u64,
+		nonce: U256,
+		deadline: U256,
+	) -> [u8; 32] {
+		Self::generate_permit_of_type(
+			UNORDERED_PERMIT_TYPEHASH,
+			address,
+			from,
+			to,
+			value,
+			data,
+			gaslimit,
+			nonce,
+			deadline,
+		)
+	}
+
+	fn generate_permit_of_type(
+		typehash: [u8; 32],
+		address: H160,
+		from: H160,
+		to: H160,
+		value: U256,
+		data: Vec<u8>,
+		gaslimit: u64,
+		nonce: U256,
+		deadline: U256,
+	) -> [u8; 32] {
+		let domain_separator = Self::compute_domain_separator(address);
+
+		let permit_content = solidity::encode_arguments((
+			H256::from(typehash),
+			Address(from),
+			Address(to),
+			value,
+			// bytes are encoded as the keccak_256 of the content
+			H256::from(keccak_256(&data)),
+			gaslimit,
+			nonce,
+			deadline,
+		));
+		let permit_content = keccak_256(&permit_content);
+		let mut pre_digest = Vec::with_capacity(2 + 32 + 32);
+		pre_digest.extend_from_slice(b"\x19\x01");
+		pre_digest.extend_from_slice(&domain_separator);
+		pre_digest.extend_from_slice(&permit_content);
+		keccak_256(&pre_digest)
+	}
+
+	/// Hash an array of static values as specified by EIP712, i.e. the keccak_256 of the
+	/// concatenation of their encodings.
+	fn hash_static_array<T: solidity::Codec + Clone>(items: &[T]) -> H256 {
+		let encoded: Vec<u8> = items
+			.iter()
+			.flat_map(|item| solidity::encode_arguments(item.clone()))
+			.collect();
+		keccak_256(&encoded).into()
+	}
+
+	pub fn generate_batch_permit(
+		address: H160,
+		from: H160,
+		to: &[H160],
+		value: &[U256],
+		data: &[Vec<u8>],
+		gaslimit: &[u64],
+		mode: u8,
+		nonce: U256,
+		deadline: U256,
+	) -> [u8; 32] {
+		let domain_separator = Self::compute_domain_separator(address);
+
+		let to: Vec<Address> = to.iter().copied().map(Address).collect();
+		// bytes[] are encoded as the keccak_256 of the concatenated keccak_256 of each item
+		let data: Vec<H256> = data.iter().map(|data| H256::from(keccak_256(data))).collect();
+
+		let permit_content = solidity::encode_arguments((
+			H256::from(BATCH_PERMIT_TYPEHASH),
+			Address(from),
+			Self::hash_static_array(&to),
+			Self::hash_static_array(value),
+			Self::hash_static_array(&data),
+			Self::hash_static_array(gaslimit),
+			mode,
+			nonce,
+			deadline,
+		));
+		let permit_content = keccak_256(&permit_content);
+		let mut pre_digest = Vec::with_capacity(2 + 32 + 32);
+		pre_digest.extend_from_slice(b"\x19\x01");
+		pre_digest.extend_from_slice(&domain_separator);
+		pre_digest.extend_from_slice(&permit_content);
+		keccak_256(&pre_digest)
+	}
+
+	/// Whether the deadline of a permit has passed.
+	fn is_expired(deadline: U256) -> bool {
+		// Blockchain time is in ms while Ethereum use second timestamps.
+		let timestamp: u128 = timestamp::Pallet::<Runtime>::now().unique_saturated_into();
+		let timestamp: U256 = U256::from(timestamp / 1000);
+
+		deadline < timestamp
+	}
+
+	/// Whether the permit was signed by `from`.
+	fn is_signed_by(permit: [u8; 32], from: H160, v: u8, r: H256, s: H256) -> bool {
+		let mut sig = [0u8; 65];
+		sig[0..32].copy_from_slice(&r.as_bytes());
+		sig[32..64].copy_from_slice(&s.as_bytes());
+		sig[64] = v;
+
+		let Ok(signer) = sp_io::crypto::secp256k1_ecdsa_recover(&sig, &permit) else {
+			return false;
+		};
+		let signer = H160::from(H256::from_slice(keccak_256(&signer).as_slice()));
+
+		signer != H160::zero() && signer == from
+	}
+
+	/// Ensure the permit has not expired and was signed by `from`.
+	fn verify_permit(permit: [u8; 32], from: H160, deadline: U256, v: u8, r: H256, s: H256) -> EvmResult {
+		ensure!(!Self::is_expired(deadline), revert("Permit expired"));
+		ensure!(Self::is_signed_by(permit, from, v, r, s), revert("Invalid permit"));
+
+		Ok(())
+	}
+
+	/// Ensure the remaining gas covers the gas limit of a call transferring `value`.
+	fn ensure_sufficient_gas(handle: &mut impl PrecompileHandle, value: U256, gas_limit: u64) -> EvmResult {
+		let call_cost = call_cost(value, <Runtime as pallet_evm::Config>::config());
+
+		let total_cost = gas_limit
+			.checked_add(call_cost)
+			.ok_or_else(|| revert("Call require too much gas (uint64 overflow)"))?;
+
+		if total_cost > handle.remaining_gas() {
+			return Err(revert("Gaslimit is too low to dispatch provided call"));
+		}
+
+		Ok(())
+	}
+
+	/// Word position and bit mask of an unordered nonce in the nonce bitmaps.
+	fn nonce_bitmap_position(nonce: U256) -> (U256, U256) {
+		let word_pos = nonce >> 8;
+		let bit = U256::one() << (nonce.low_u32() & 0xff);
+		(word_pos, bit)
+	}
+
+	/// Whether an unordered nonce of `from` has already been used or invalidated.
+	fn is_unordered_nonce_used(from: H160, nonce: U256) -> bool {
+		let (word_pos, bit) = Self::nonce_bitmap_position(nonce);
+		!(NonceBitmapsStorage::get(from, word_pos) & bit).is_zero()
+	}
+
+	/// Return the caller whose nonces are managed by the call, ensuring the precompile is not
+	/// executed via DELEGATECALL or CALLCODE, in which case the caller would be the caller of
+	/// the delegating contract.
+	fn nonce_owner(handle: &mut impl PrecompileHandle) -> EvmResult<H160> {
+		ensure!(
+			handle.code_address() == handle.context().address,
+			revert("Cannot be called with DELEGATECALL or CALLCODE")
+		);
+
+		Ok(handle.context().caller)
+	}
+
+	/// Call `to` on behalf of `from`.
+	fn call_as(
+		handle: &mut impl PrecompileHandle,
+		from: H160,
+		to: H160,
+		value: U256,
+		data: Vec<u8>,
+		gas_limit: u64,
+	) -> (ExitReason, Vec<u8>) {
+		let sub_context = Context {
+			caller: from,
+			address: to.clone(),
+			apparent_value: value,
+		};
+
+		let transfer = if value.is_zero() {
+			None
+		} else {
+			Some(Transfer {
+				source: from,
+				target: to.clone(),
+				value,
+			})
+		};
+
+		handle.call(to, transfer, data, Some(gas_limit), false, &sub_context)
+	}
+
+	pub fn dispatch_inherent_cost() -> u64 {
+		3_000 // cost of ECRecover precompile for reference
 			+ RuntimeHelper::<Runtime>::db_write_gas_cost() // we write nonce
-    }
-
-    #[precompile::public(
-        "dispatch(address,address,uint256,bytes,uint64,uint256,uint8,bytes32,bytes32)"
-    )]
-    fn dispatch(
-        handle: &mut impl PrecompileHandle,
-        from: Address,
-        to: Address,
-        value: U256,
-        data: BoundedBytes<ConstU32<CALL_DATA_LIMIT>>,
-        gas_limit: u64,
-        deadline: U256,
-        v: u8,
-        r: H256,
-        s: H256,
-    ) -> EvmResult<UnboundedBytes> {
-        // Now: 8
-        handle.record_db_read::<Runtime>(8)?;
-        // NoncesStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20) + nonce(32)
-        handle.record_db_read::<Runtime>(104)?;
-
-        handle.record_cost(Self::dispatch_inherent_cost())?;
-
-        let from: H160 = from.into();
-        let to: H160 = to.into();
-        let data: Vec<u8> = data.into();
-
-        // ENSURE GASLIMIT IS SUFFICIENT
-        let call_cost = call_cost(value, <Runtime as pallet_evm::Config>::config());
-
-        let total_cost = gas_limit
-            .checked_add(call_cost)
-            .ok_or_else(|| revert("Call require too much gas (uint64 overflow)"))?;
-
-        if total_cost > handle.remaining_gas() {
-            return Err(revert("Gaslimit is too low to dispatch provided call"));
-        }
-
-        // VERIFY PERMIT
-
-        // Blockchain time is in ms while Ethereum use second timestamps.
-        let timestamp: u128 = timestamp::Pallet::<Runtime>::now().unique_saturated_into();
-        let timestamp: U256 = U256::from(timestamp / 1000);
-
-        ensure!(deadline >= timestamp, revert("Permit expired"));
-
-        let nonce = NoncesStorage::get(from);
-
-        let permit = Self::generate_permit(
-            handle.context().address,
-            from,
-            to,
-            value,
-            data.clone(),
-            gas_limit,
-            nonce,
-            deadline,
-        );
-
-        let mut sig = [0u8; 65];
-        sig[0..32].copy_from_slice(&r.as_bytes());
-        sig[32..64].copy_from_slice(&s.as_bytes());
-        sig[64] = v;
-
-        let signer = sp_io::crypto::secp256k1_ecdsa_recover(&sig, &permit)
-            .map_err(|_| revert("Invalid permit"))?;
-        let signer = H160::from(H256::from_slice(keccak_256(&signer).as_slice()));
-
-        ensure!(
-            signer != H160::zero() && signer == from,
-            revert("Invalid permit")
-        );
-
-        NoncesStorage::insert(from, nonce + U256::one());
-
-        // DISPATCH CALL
-        let sub_context = Context {
-            caller: from,
-            address: to.clone(),
-            apparent_value: value,
-        };
-
-        let transfer = if value.is_zero() {
-            None
-        } else {
-            Some(Transfer {
-                source: from,
-                target: to.clone(),
-                value,
-            })
-        };
-
-        let (reason, output) =
-            handle.call(to, transfer, data, Some(gas_limit), false, &sub_context);
-        match reason {
-            ExitReason::Error(exit_status) => Err(PrecompileFailure::Error { exit_status }),
-            ExitReason::Fatal(exit_status) => Err(PrecompileFailure::Fatal { exit_status }),
-            ExitReason::Revert(_) => Err(PrecompileFailure::Revert {
-                exit_status: ExitRevert::Reverted,
-                output,
-            }),
-            ExitReason::Succeed(_) => Ok(output.into()),
-        }
-    }
-
-    #[precompile::public("nonces(address)")]
-    #[precompile::view]
-    fn nonces(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
-        // NoncesStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20) + nonce(32)
-        handle.record_db_read::<Runtime>(104)?;
-
-        let owner: H160 = owner.into();
-
-        let nonce = NoncesStorage::get(owner);
-
-        Ok(nonce)
-    }
-
-    #[precompile::public("DOMAIN_SEPARATOR()")]
-    #[precompile::view]
-    fn domain_separator(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
-        // ChainId
-        handle.record_db_read::<Runtime>(8)?;
-
-        let domain_separator: H256 =
-            Self::compute_domain_separator(handle.context().address).into();
-
-        Ok(domain_separator)
-    }
+	}
+
+	#[precompile::public("dispatch(address,address,uint256,bytes,uint64,uint256,uint8,bytes32,bytes32)")]
+	fn dispatch(
+		handle: &mut impl PrecompileHandle,
+		from: Address,
+		to: Address,
+		value: U256,
+		data: BoundedBytes<ConstU32<CALL_DATA_LIMIT>>,
+		gas_limit: u64,
+		deadline: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> EvmResult<UnboundedBytes> {
+		// Now: 8
+		handle.record_db_read::<Runtime>(8)?;
+		// NoncesStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20) + nonce(32)
+		handle.record_db_read::<Runtime>(104)?;
+
+		handle.record_cost(Self::dispatch_inherent_cost())?;
+
+		let from: H160 = from.into();
+		let to: H160 = to.into();
+		let data: Vec<u8> = data.into();
+
+		// ENSURE GASLIMIT IS SUFFICIENT
+		Self::ensure_sufficient_gas(handle, value, gas_limit)?;
+
+		// VERIFY PERMIT
+
+		let nonce = NoncesStorage::get(from);
+
+		let permit = Self::generate_permit(
+			handle.context().address,
+			from,
+			to,
+			value,
+			data.clone(),
+			gas_limit,
+			nonce,
+			deadline,
+		);
+
+		Self::verify_permit(permit, from, deadline, v, r, s)?;
+
+		NoncesStorage::insert(from, nonce + U256::one());
+
+		// DISPATCH CALL
+		let (reason, output) = Self::call_as(handle, from, to, value, data, gas_limit);
+		Self::into_dispatch_result(reason, output)
+	}
+
+	/// Map the exit reason of a dispatched call to the precompile result.
+	fn into_dispatch_result(reason: ExitReason, output: Vec<u8>) -> EvmResult<UnboundedBytes> {
+		match reason {
+			ExitReason::Error(exit_status) => Err(PrecompileFailure::Error { exit_status }),
+			ExitReason::Fatal(exit_status) => Err(PrecompileFailure::Fatal { exit_status }),
+			ExitReason::Revert(_) => Err(PrecompileFailure::Revert {
+				exit_status: ExitRevert::Reverted,
+				output,
+			}),
+			ExitReason::Succeed(_) => Ok(output.into()),
+		}
+	}
+
+	#[precompile::public(
+		"dispatchUnordered(address,address,uint256,bytes,uint64,uint256,uint256,uint8,bytes32,bytes32)"
+	)]
+	fn dispatch_unordered(
+		handle: &mut impl PrecompileHandle,
+		from: Address,
+		to: Address,
+		value: U256,
+		data: BoundedBytes<ConstU32<CALL_DATA_LIMIT>>,
+		gas_limit: u64,
+		nonce: U256,
+		deadline: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> EvmResult<UnboundedBytes> {
+		// Now: 8
+		handle.record_db_read::<Runtime>(8)?;
+		// NonceBitmapsStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20)
+		//   + Blake2_128(16) + word(32) + bitmap(32)
+		handle.record_db_read::<Runtime>(152)?;
+
+		handle.record_cost(Self::dispatch_inherent_cost())?;
+
+		let from: H160 = from.into();
+		let to: H160 = to.into();
+		let data: Vec<u8> = data.into();
+
+		// ENSURE GASLIMIT IS SUFFICIENT
+		Self::ensure_sufficient_gas(handle, value, gas_limit)?;
+
+		// VERIFY PERMIT
+
+		ensure!(
+			!Self::is_unordered_nonce_used(from, nonce),
+			revert("Nonce already used")
+		);
+
+		let permit = Self::generate_unordered_permit(
+			handle.context().address,
+			from,
+			to,
+			value,
+			data.clone(),
+			gas_limit,
+			nonce,
+			deadline,
+		);
+
+		Self::verify_permit(permit, from, deadline, v, r, s)?;
+
+		let (word_pos, bit) = Self::nonce_bitmap_position(nonce);
+		NonceBitmapsStorage::mutate(from, word_pos, |bitmap| *bitmap |= bit);
+
+		// DISPATCH CALL
+		let (reason, output) = Self::call_as(handle, from, to, value, data, gas_limit);
+		Self::into_dispatch_result(reason, output)
+	}
+
+	#[precompile::public(
+		"batchDispatch(address,address[],uint256[],bytes[],uint64[],uint8,uint256,uint8,bytes32,bytes32)"
+	)]
+	fn batch_dispatch(
+		handle: &mut impl PrecompileHandle,
+		from: Address,
+		to: BoundedVec<Address, ConstU32<BATCH_SIZE_LIMIT>>,
+		value: BoundedVec<U256, ConstU32<BATCH_SIZE_LIMIT>>,
+		data: BoundedVec<BoundedBytes<ConstU32<CALL_DATA_LIMIT>>, ConstU32<BATCH_SIZE_LIMIT>>,
+		gas_limit: BoundedVec<u64, ConstU32<BATCH_SIZE_LIMIT>>,
+		mode: u8,
+		deadline: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> EvmResult<(Vec<bool>, Vec<UnboundedBytes>)> {
+		// Now: 8
+		handle.record_db_read::<Runtime>(8)?;
+		// NoncesStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20) + nonce(32)
+		handle.record_db_read::<Runtime>(104)?;
+
+		handle.record_cost(Self::dispatch_inherent_cost())?;
+
+		let from: H160 = from.into();
+		let to: Vec<Address> = to.into();
+		let to: Vec<H160> = to.into_iter().map(Into::into).collect();
+		let value: Vec<U256> = value.into();
+		let data: Vec<BoundedBytes<ConstU32<CALL_DATA_LIMIT>>> = data.into();
+		let data: Vec<Vec<u8>> = data.into_iter().map(Into::into).collect();
+		let gas_limit: Vec<u64> = gas_limit.into();
+		let batch_mode = BatchMode::try_from(mode).map_err(|_| revert("Invalid batch mode"))?;
+
+		ensure!(
+			to.len() == value.len() && to.len() == data.len() && to.len() == gas_limit.len(),
+			revert("Batch arrays must have the same length")
+		);
+
+		// ENSURE GASLIMIT IS SUFFICIENT FOR ALL SUBCALLS
+		let mut total_cost: u64 = 0;
+		for (value, gas_limit) in value.iter().zip(gas_limit.iter()) {
+			let call_cost = call_cost(*value, <Runtime as pallet_evm::Config>::config());
+			total_cost = total_cost
+				.checked_add(*gas_limit)
+				.and_then(|cost| cost.checked_add(call_cost))
+				.ok_or_else(|| revert("Call require too much gas (uint64 overflow)"))?;
+		}
+
+		if total_cost > handle.remaining_gas() {
+			return Err(revert("Gaslimit is too low to dispatch provided calls"));
+		}
+
+		// VERIFY PERMIT
+
+		let nonce = NoncesStorage::get(from);
+
+		let permit = Self::generate_batch_permit(
+			handle.context().address,
+			from,
+			&to,
+			&value,
+			&data,
+			&gas_limit,
+			mode,
+			nonce,
+			deadline,
+		);
+
+		Self::verify_permit(permit, from, deadline, v, r, s)?;
+
+		// The whole batch consumes a single nonce.
+		NoncesStorage::insert(from, nonce + U256::one());
+
+		// DISPATCH CALLS
+		let mut success = Vec::with_capacity(to.len());
+		let mut outputs = Vec::with_capacity(to.len());
+		let subcalls = to.into_iter().zip(value).zip(data).zip(gas_limit);
+		for (((to, value), data), gas_limit) in subcalls {
+			let (reason, output) = Self::call_as(handle, from, to, value, data, gas_limit);
+			match (reason, batch_mode) {
+				(ExitReason::Succeed(_), _) => {
+					success.push(true);
+					outputs.push(output.into());
+				}
+				(ExitReason::Fatal(exit_status), _) => return Err(PrecompileFailure::Fatal { exit_status }),
+				(ExitReason::Error(exit_status), BatchMode::AllOrNothing) => {
+					return Err(PrecompileFailure::Error { exit_status })
+				}
+				(ExitReason::Revert(_), BatchMode::AllOrNothing) => {
+					return Err(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output,
+					})
+				}
+				(ExitReason::Error(_) | ExitReason::Revert(_), BatchMode::ContinueOnFailure) => {
+					success.push(false);
+					outputs.push(output.into());
+				}
+			}
+		}
+
+		Ok((success, outputs))
+	}
+
+	#[precompile::public("nonces(address)")]
+	#[precompile::view]
+	fn nonces(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+		// NoncesStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20) + nonce(32)
+		handle.record_db_read::<Runtime>(104)?;
+
+		let owner: H160 = owner.into();
+
+		let nonce = NoncesStorage::get(owner);
+
+		Ok(nonce)
+	}
+
+	#[precompile::public("useNonce()")]
+	fn use_nonce(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		// NoncesStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20) + nonce(32)
+		handle.record_db_read::<Runtime>(104)?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+		let owner = Self::nonce_owner(handle)?;
+
+		let nonce = NoncesStorage::get(owner);
+		NoncesStorage::insert(owner, nonce + U256::one());
+
+		Ok(nonce)
+	}
+
+	#[precompile::public("invalidateNonces(uint256)")]
+	fn invalidate_nonces(handle: &mut impl PrecompileHandle, new_nonce: U256) -> EvmResult {
+		// NoncesStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20) + nonce(32)
+		handle.record_db_read::<Runtime>(104)?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+		let owner = Self::nonce_owner(handle)?;
+
+		let nonce = NoncesStorage::get(owner);
+		ensure!(new_nonce > nonce, revert("Nonce can only be increased"));
+		ensure!(
+			new_nonce - nonce <= U256::from(MAX_NONCE_INCREASE),
+			revert("Nonce increase too large")
+		);
+
+		NoncesStorage::insert(owner, new_nonce);
+
+		Ok(())
+	}
+
+	#[precompile::public("invalidateUnorderedNonces(uint256,uint256)")]
+	fn invalidate_unordered_nonces(handle: &mut impl PrecompileHandle, word_pos: U256, mask: U256) -> EvmResult {
+		// NonceBitmapsStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20)
+		//   + Blake2_128(16) + word(32) + bitmap(32)
+		handle.record_db_read::<Runtime>(152)?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+		let owner = Self::nonce_owner(handle)?;
+
+		NonceBitmapsStorage::mutate(owner, word_pos, |bitmap| *bitmap |= mask);
+
+		Ok(())
+	}
+
+	#[precompile::public("nonceBitmap(address,uint256)")]
+	#[precompile::view]
+	fn nonce_bitmap(handle: &mut impl PrecompileHandle, owner: Address, word_pos: U256) -> EvmResult<U256> {
+		// NonceBitmapsStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20)
+		//   + Blake2_128(16) + word(32) + bitmap(32)
+		handle.record_db_read::<Runtime>(152)?;
+
+		let owner: H160 = owner.into();
+
+		Ok(NonceBitmapsStorage::get(owner, word_pos))
+	}
+
+	#[precompile::public("isPermitExecutable(address,address,uint256,bytes,uint64,uint256,uint8,bytes32,bytes32)")]
+	#[precompile::view]
+	fn is_permit_executable(
+		handle: &mut impl PrecompileHandle,
+		from: Address,
+		to: Address,
+		value: U256,
+		data: BoundedBytes<ConstU32<CALL_DATA_LIMIT>>,
+		gas_limit: u64,
+		deadline: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> EvmResult<bool> {
+		// Now: 8
+		handle.record_db_read::<Runtime>(8)?;
+		// NoncesStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20) + nonce(32)
+		handle.record_db_read::<Runtime>(104)?;
+		// cost of ECRecover precompile for reference
+		handle.record_cost(3_000)?;
+
+		let from: H160 = from.into();
+		let nonce = NoncesStorage::get(from);
+
+		let permit = Self::generate_permit(
+			handle.context().address,
+			from,
+			to.into(),
+			value,
+			data.into(),
+			gas_limit,
+			nonce,
+			deadline,
+		);
+
+		Ok(!Self::is_expired(deadline) && Self::is_signed_by(permit, from, v, r, s))
+	}
+
+	#[precompile::public(
+		"isUnorderedPermitExecutable(address,address,uint256,bytes,uint64,uint256,uint256,uint8,bytes32,bytes32)"
+	)]
+	#[precompile::view]
+	fn is_unordered_permit_executable(
+		handle: &mut impl PrecompileHandle,
+		from: Address,
+		to: Address,
+		value: U256,
+		data: BoundedBytes<ConstU32<CALL_DATA_LIMIT>>,
+		gas_limit: u64,
+		nonce: U256,
+		deadline: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> EvmResult<bool> {
+		// Now: 8
+		handle.record_db_read::<Runtime>(8)?;
+		// NonceBitmapsStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20)
+		//   + Blake2_128(16) + word(32) + bitmap(32)
+		handle.record_db_read::<Runtime>(152)?;
+		// cost of ECRecover precompile for reference
+		handle.record_cost(3_000)?;
+
+		let from: H160 = from.into();
+		if Self::is_unordered_nonce_used(from, nonce) {
+			return Ok(false);
+		}
+
+		let permit = Self::generate_unordered_permit(
+			handle.context().address,
+			from,
+			to.into(),
+			value,
+			data.into(),
+			gas_limit,
+			nonce,
+			deadline,
+		);
+
+		Ok(!Self::is_expired(deadline) && Self::is_signed_by(permit, from, v, r, s))
+	}
+
+	#[precompile::public(
+		"isBatchPermitExecutable(address,address[],uint256[],bytes[],uint64[],uint8,uint256,uint8,bytes32,bytes32)"
+	)]
+	#[precompile::view]
+	fn is_batch_permit_executable(
+		handle: &mut impl PrecompileHandle,
+		from: Address,
+		to: BoundedVec<Address, ConstU32<BATCH_SIZE_LIMIT>>,
+		value: BoundedVec<U256, ConstU32<BATCH_SIZE_LIMIT>>,
+		data: BoundedVec<BoundedBytes<ConstU32<CALL_DATA_LIMIT>>, ConstU32<BATCH_SIZE_LIMIT>>,
+		gas_limit: BoundedVec<u64, ConstU32<BATCH_SIZE_LIMIT>>,
+		mode: u8,
+		deadline: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> EvmResult<bool> {
+		// Now: 8
+		handle.record_db_read::<Runtime>(8)?;
+		// NoncesStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20) + nonce(32)
+		handle.record_db_read::<Runtime>(104)?;
+		// cost of ECRecover precompile for reference
+		handle.record_cost(3_000)?;
+
+		let from: H160 = from.into();
+		let to: Vec<Address> = to.into();
+		let to: Vec<H160> = to.into_iter().map(Into::into).collect();
+		let value: Vec<U256> = value.into();
+		let data: Vec<BoundedBytes<ConstU32<CALL_DATA_LIMIT>>> = data.into();
+		let data: Vec<Vec<u8>> = data.into_iter().map(Into::into).collect();
+		let gas_limit: Vec<u64> = gas_limit.into();
+
+		// A batch that `batchDispatch` would reject is never executable.
+		if BatchMode::try_from(mode).is_err()
+			|| to.len() != value.len()
+			|| to.len() != data.len()
+			|| to.len() != gas_limit.len()
+		{
+			return Ok(false);
+		}
+
+		let nonce = NoncesStorage::get(from);
+
+		let permit = Self::generate_batch_permit(
+			handle.context().address,
+			from,
+			&to,
+			&value,
+			&data,
+			&gas_limit,
+			mode,
+			nonce,
+			deadline,
+		);
+
+		Ok(!Self::is_expired(deadline) && Self::is_signed_by(permit, from, v, r, s))
+	}
+
+	#[precompile::public("DOMAIN_SEPARATOR()")]
+	#[precompile::view]
+	fn domain_separator(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+		// ChainId
+		handle.record_db_read::<Runtime>(8)?;
+
+		let domain_separator: H256 = Self::compute_domain_separator(handle.context().address).into();
+
+		Ok(domain_separator)
+	}
 }
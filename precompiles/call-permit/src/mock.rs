@@ -1,196 +1,157 @@
-// // Copyright 2019-2022 PureStake Inc.
-// // This file is part of Moonbeam.
-
-// // Moonbeam is free software: you can redistribute it and/or modify
-// // it under the terms of the GNU General Public License as published by
-// // the Free Software Foundation, either version 3 of the License, or
-// // (at your option) any later version.
-
-// // Moonbeam is distributed in the hope that it will be useful,
-// // but WITHOUT ANY WARRANTY; without even the implied warranty of
-// // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
-// // GNU General Public License for more details.
-
-// // You should have received a copy of the GNU General Public License
-// // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
-
-//  comments as something leaks std
-
-// //! Test utilities
-// use super::*;
-
-// use frame_support::traits::Everything;
-// use frame_support::{construct_runtime, pallet_prelude::*, parameter_types};
-// use pallet_evm::{EnsureAddressNever, EnsureAddressRoot};
-// use precompile_utils::{mock_account, precompile_set::*, testing::MockAccount};
-// use sp_core::H256;
-// use sp_runtime::BuildStorage;
-// use sp_runtime::{
-// 	traits::{BlakeTwo256, IdentityLookup},
-// 	Perbill,
-// };
-
-// pub type AccountId = MockAccount;
-// pub type Balance = u128;
-
-// type Block = frame_system::mocking::MockBlockU32<Runtime>;
-
-// construct_runtime!(
-// 	pub enum Runtime	{
-// 		System: frame_system,
-// 		Balances: pallet_balances,
-// 		Evm: pallet_evm,
-// 		Timestamp: pallet_timestamp,
-// 	}
-// );
-
-// parameter_types! {
-// 	pub const BlockHashCount: u32 = 250;
-// 	pub const MaximumBlockWeight: Weight = Weight::from_parts(1024, 1);
-// 	pub const MaximumBlockLength: u32 = 2 * 1024;
-// 	pub const AvailableBlockRatio: Perbill = Perbill::one();
-// 	pub const SS58Prefix: u8 = 42;
-// }
-
-// impl frame_system::Config for Runtime {
-// 	type BaseCallFilter = Everything;
-// 	type DbWeight = ();
-// 	type RuntimeOrigin = RuntimeOrigin;
-// 	type RuntimeTask = RuntimeTask;
-// 	type Nonce = u64;
-// 	type Block = Block;
-// 	type RuntimeCall = RuntimeCall;
-// 	type Hash = H256;
-// 	type Hashing = BlakeTwo256;
-// 	type AccountId = AccountId;
-// 	type Lookup = IdentityLookup<Self::AccountId>;
-// 	type RuntimeEvent = RuntimeEvent;
-// 	type BlockHashCount = BlockHashCount;
-// 	type Version = ();
-// 	type PalletInfo = PalletInfo;
-// 	type AccountData = pallet_balances::AccountData<Balance>;
-// 	type OnNewAccount = ();
-// 	type OnKilledAccount = ();
-// 	type SystemWeightInfo = ();
-// 	type BlockWeights = ();
-// 	type BlockLength = ();
-// 	type SS58Prefix = SS58Prefix;
-// 	type OnSetCode = ();
-// 	type MaxConsumers = frame_support::traits::ConstU32<16>;
-// }
-// parameter_types! {
-// 	pub const ExistentialDeposit: u128 = 0;
-// }
-// impl pallet_balances::Config for Runtime {
-// 	type MaxReserves = ();
-// 	type ReserveIdentifier = [u8; 4];
-// 	type MaxLocks = ();
-// 	type Balance = Balance;
-// 	type RuntimeEvent = RuntimeEvent;
-// 	type DustRemoval = ();
-// 	type ExistentialDeposit = ExistentialDeposit;
-// 	type AccountStore = System;
-// 	type WeightInfo = ();
-// 	type RuntimeHoldReason = ();
-// 	type FreezeIdentifier = ();
-// 	type MaxFreezes = ();
-// 	type RuntimeFreezeReason = ();
-// }
-
-// mock_account!(CallPermit, |_| MockAccount::from_u64(1));
-// mock_account!(Revert, |_| MockAccount::from_u64(2));
-
-// pub type Precompiles<R> = PrecompileSetBuilder<
-// 	R,
-// 	(
-// 		PrecompileAt<AddressU64<1>, CallPermitPrecompile<R>, SubcallWithMaxNesting<0>>,
-// 		RevertPrecompile<AddressU64<2>>,
-// 	),
-// >;
-
-// pub type PCall = CallPermitPrecompileCall<Runtime>;
-
-// parameter_types! {
-// 	pub PrecompilesValue: Precompiles<Runtime> = Precompiles::new();
-// 	pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
-// 	pub const SuicideQuickClearLimit: u32 = 0;
-// }
-
-// impl pallet_evm::Config for Runtime {
-// 	type FeeCalculator = ();
-// 	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
-// 	type WeightPerGas = WeightPerGas;
-// 	type CallOrigin = EnsureAddressRoot<AccountId>;
-// 	type WithdrawOrigin = EnsureAddressNever<AccountId>;
-// 	type AddressMapping = AccountId;
-// 	type Currency = Balances;
-// 	type RuntimeEvent = RuntimeEvent;
-// 	type Runner = pallet_evm::runner::stack::Runner<Self>;
-// 	type PrecompilesType = Precompiles<Runtime>;
-// 	type PrecompilesValue = PrecompilesValue;
-// 	type ChainId = ();
-// 	type OnChargeTransaction = ();
-// 	type BlockGasLimit = ();
-// 	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
-// 	type FindAuthor = ();
-// 	type OnCreate = ();
-// 	type GasLimitPovSizeRatio = ();
-// 	type GasLimitStorageGrowthRatio = ();
-// 	type SuicideQuickClearLimit = SuicideQuickClearLimit;
-// 	type Timestamp = Timestamp;
-// 	type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
-// }
-
-// parameter_types! {
-// 	pub const MinimumPeriod: u64 = 5;
-// }
-// impl pallet_timestamp::Config for Runtime {
-// 	type Moment = u64;
-// 	type OnTimestampSet = ();
-// 	type MinimumPeriod = MinimumPeriod;
-// 	type WeightInfo = ();
-// }
-
-// pub(crate) struct ExtBuilder {
-// 	// endowed accounts with balances
-// 	balances: Vec<(AccountId, Balance)>,
-// }
-
-// impl Default for ExtBuilder {
-// 	fn default() -> ExtBuilder {
-// 		ExtBuilder { balances: vec![] }
-// 	}
-// }
-
-// impl ExtBuilder {
-// 	pub(crate) fn with_balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
-// 		self.balances = balances;
-// 		self
-// 	}
-
-// 	pub(crate) fn build(self) -> sp_io::TestExternalities {
-// 		let mut t = frame_system::GenesisConfig::<Runtime>::default()
-// 			.build_storage()
-// 			.expect("Frame system builds valid default genesis config");
-
-// 		pallet_balances::GenesisConfig::<Runtime> {
-// 			balances: self.balances,
-// 		}
-// 		.assimilate_storage(&mut t)
-// 		.expect("Pallet balances storage can be assimilated");
-
-// 		let mut ext = sp_io::TestExternalities::new(t);
-// 		ext.execute_with(|| {
-// 			System::set_block_number(1);
-// 			pallet_evm::Pallet::<Runtime>::create_account(
-// 				Revert.into(),
-// 				hex_literal::hex!("1460006000fd").to_vec(),
-// 			);
-// 		});
-// 		ext
-// 	}
-// }
-
-// // pub fn balance(account: impl Into<Account>) -> Balance {
-// // 	pallet_balances::Pallet::<Runtime>::usable_balance(account.into())
-// // }
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test utilities
+
+use super::*;
+
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU128, ConstU32, ConstU64, Everything},
+	weights::{constants::RocksDbWeight, Weight},
+};
+use pallet_evm::EnsureAddressRoot;
+use precompile_utils::{mock_account, precompile_set::*, testing::MockAccount};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+
+pub(crate) type AccountId = MockAccount;
+pub(crate) type Balance = u128;
+
+type Block = frame_system::mocking::MockBlockU32<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
+		Evm: pallet_evm,
+	}
+);
+
+impl frame_system::Config for Runtime {
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type AccountId = AccountId;
+	type BaseCallFilter = Everything;
+	type Block = Block;
+	type BlockHashCount = ConstU32<250>;
+	type BlockLength = ();
+	type BlockWeights = ();
+	type DbWeight = RocksDbWeight;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type MaxConsumers = ConstU32<16>;
+	type Nonce = u64;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type PalletInfo = PalletInfo;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeTask = ();
+	type SS58Prefix = ();
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+impl pallet_balances::Config for Runtime {
+	type AccountStore = System;
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxFreezes = ConstU32<50>;
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type WeightInfo = ();
+}
+
+impl pallet_timestamp::Config for Runtime {
+	type MinimumPeriod = ConstU64<5>;
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type WeightInfo = ();
+}
+
+mock_account!(CallPermit, |_| MockAccount::from_u64(1));
+mock_account!(Revert, |_| MockAccount::from_u64(2));
+
+pub type Precompiles<R> = PrecompileSetBuilder<
+	R,
+	(
+		PrecompileAt<AddressU64<1>, CallPermitPrecompile<R>, SubcallWithMaxNesting<0>>,
+		RevertPrecompile<AddressU64<2>>,
+	),
+>;
+
+pub type PCall = CallPermitPrecompileCall<Runtime>;
+
+parameter_types! {
+	pub PrecompilesValue: Precompiles<Runtime> = Precompiles::new();
+	pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+	pub const SuicideQuickClearLimit: u32 = 0;
+}
+
+impl pallet_evm::Config for Runtime {
+	type AddressMapping = AccountId;
+	type BlockGasLimit = ();
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type CallOrigin = EnsureAddressRoot<AccountId>;
+	type ChainId = ();
+	type Currency = Balances;
+	type FeeCalculator = ();
+	type FindAuthor = ();
+	type GasLimitPovSizeRatio = ();
+	type GasLimitStorageGrowthRatio = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type OnChargeTransaction = ();
+	type OnCreate = ();
+	type PrecompilesType = Precompiles<Runtime>;
+	type PrecompilesValue = PrecompilesValue;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type RuntimeEvent = RuntimeEvent;
+	type SuicideQuickClearLimit = SuicideQuickClearLimit;
+	type Timestamp = Timestamp;
+	type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
+	type WeightPerGas = WeightPerGas;
+}
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder;
+
+impl ExtBuilder {
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let storage = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.expect("Frame system builds valid default genesis config");
+
+		let mut ext = sp_io::TestExternalities::new(storage);
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			// Dummy code, so that the revert precompile is seen as a contract.
+			pallet_evm::Pallet::<Runtime>::create_account(Revert.into(), vec![0x14, 0x60, 0x00, 0x60, 0x00, 0xfd]);
+		});
+		ext
+	}
+}
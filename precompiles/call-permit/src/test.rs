@@ -1,196 +1,561 @@
-// // Copyright 2019-2022 PureStake Inc.
-// // This file is part of Moonbeam.
-
-// // Moonbeam is free software: you can redistribute it and/or modify
-// // it under the terms of the GNU General Public License as published by
-// // the Free Software Foundation, either version 3 of the License, or
-// // (at your option) any later version.
-
-// // Moonbeam is distributed in the hope that it will be useful,
-// // but WITHOUT ANY WARRANTY; without even the implied warranty of
-// // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
-// // GNU General Public License for more details.
-
-// // You should have received a copy of the GNU General Public License
-// // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
-
-//  comments as something leaks std
-
-// //! Test utilities
-// use super::*;
-
-// use frame_support::traits::Everything;
-// use frame_support::{construct_runtime, pallet_prelude::*, parameter_types};
-// use pallet_evm::{EnsureAddressNever, EnsureAddressRoot};
-// use precompile_utils::{mock_account, precompile_set::*, testing::MockAccount};
-// use sp_core::H256;
-// use sp_runtime::BuildStorage;
-// use sp_runtime::{
-// 	traits::{BlakeTwo256, IdentityLookup},
-// 	Perbill,
-// };
-
-// pub type AccountId = MockAccount;
-// pub type Balance = u128;
-
-// type Block = frame_system::mocking::MockBlockU32<Runtime>;
-
-// construct_runtime!(
-// 	pub enum Runtime	{
-// 		System: frame_system,
-// 		Balances: pallet_balances,
-// 		Evm: pallet_evm,
-// 		Timestamp: pallet_timestamp,
-// 	}
-// );
-
-// parameter_types! {
-// 	pub const BlockHashCount: u32 = 250;
-// 	pub const MaximumBlockWeight: Weight = Weight::from_parts(1024, 1);
-// 	pub const MaximumBlockLength: u32 = 2 * 1024;
-// 	pub const AvailableBlockRatio: Perbill = Perbill::one();
-// 	pub const SS58Prefix: u8 = 42;
-// }
-
-// impl frame_system::Config for Runtime {
-// 	type BaseCallFilter = Everything;
-// 	type DbWeight = ();
-// 	type RuntimeOrigin = RuntimeOrigin;
-// 	type RuntimeTask = RuntimeTask;
-// 	type Nonce = u64;
-// 	type Block = Block;
-// 	type RuntimeCall = RuntimeCall;
-// 	type Hash = H256;
-// 	type Hashing = BlakeTwo256;
-// 	type AccountId = AccountId;
-// 	type Lookup = IdentityLookup<Self::AccountId>;
-// 	type RuntimeEvent = RuntimeEvent;
-// 	type BlockHashCount = BlockHashCount;
-// 	type Version = ();
-// 	type PalletInfo = PalletInfo;
-// 	type AccountData = pallet_balances::AccountData<Balance>;
-// 	type OnNewAccount = ();
-// 	type OnKilledAccount = ();
-// 	type SystemWeightInfo = ();
-// 	type BlockWeights = ();
-// 	type BlockLength = ();
-// 	type SS58Prefix = SS58Prefix;
-// 	type OnSetCode = ();
-// 	type MaxConsumers = frame_support::traits::ConstU32<16>;
-// }
-// parameter_types! {
-// 	pub const ExistentialDeposit: u128 = 0;
-// }
-// impl pallet_balances::Config for Runtime {
-// 	type MaxReserves = ();
-// 	type ReserveIdentifier = [u8; 4];
-// 	type MaxLocks = ();
-// 	type Balance = Balance;
-// 	type RuntimeEvent = RuntimeEvent;
-// 	type DustRemoval = ();
-// 	type ExistentialDeposit = ExistentialDeposit;
-// 	type AccountStore = System;
-// 	type WeightInfo = ();
-// 	type RuntimeHoldReason = ();
-// 	type FreezeIdentifier = ();
-// 	type MaxFreezes = ();
-// 	type RuntimeFreezeReason = ();
-// }
-
-// mock_account!(CallPermit, |_| MockAccount::from_u64(1));
-// mock_account!(Revert, |_| MockAccount::from_u64(2));
-
-// pub type Precompiles<R> = PrecompileSetBuilder<
-// 	R,
-// 	(
-// 		PrecompileAt<AddressU64<1>, CallPermitPrecompile<R>, SubcallWithMaxNesting<0>>,
-// 		RevertPrecompile<AddressU64<2>>,
-// 	),
-// >;
-
-// pub type PCall = CallPermitPrecompileCall<Runtime>;
-
-// parameter_types! {
-// 	pub PrecompilesValue: Precompiles<Runtime> = Precompiles::new();
-// 	pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
-// 	pub const SuicideQuickClearLimit: u32 = 0;
-// }
-
-// impl pallet_evm::Config for Runtime {
-// 	type FeeCalculator = ();
-// 	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
-// 	type WeightPerGas = WeightPerGas;
-// 	type CallOrigin = EnsureAddressRoot<AccountId>;
-// 	type WithdrawOrigin = EnsureAddressNever<AccountId>;
-// 	type AddressMapping = AccountId;
-// 	type Currency = Balances;
-// 	type RuntimeEvent = RuntimeEvent;
-// 	type Runner = pallet_evm::runner::stack::Runner<Self>;
-// 	type PrecompilesType = Precompiles<Runtime>;
-// 	type PrecompilesValue = PrecompilesValue;
-// 	type ChainId = ();
-// 	type OnChargeTransaction = ();
-// 	type BlockGasLimit = ();
-// 	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
-// 	type FindAuthor = ();
-// 	type OnCreate = ();
-// 	type GasLimitPovSizeRatio = ();
-// 	type GasLimitStorageGrowthRatio = ();
-// 	type SuicideQuickClearLimit = SuicideQuickClearLimit;
-// 	type Timestamp = Timestamp;
-// 	type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
-// }
-
-// parameter_types! {
-// 	pub const MinimumPeriod: u64 = 5;
-// }
-// impl pallet_timestamp::Config for Runtime {
-// 	type Moment = u64;
-// 	type OnTimestampSet = ();
-// 	type MinimumPeriod = MinimumPeriod;
-// 	type WeightInfo = ();
-// }
-
-// pub(crate) struct ExtBuilder {
-// 	// endowed accounts with balances
-// 	balances: Vec<(AccountId, Balance)>,
-// }
-
-// impl Default for ExtBuilder {
-// 	fn default() -> ExtBuilder {
-// 		ExtBuilder { balances: vec![] }
-// 	}
-// }
-
-// impl ExtBuilder {
-// 	pub(crate) fn with_balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
-// 		self.balances = balances;
-// 		self
-// 	}
-
-// 	pub(crate) fn build(self) -> sp_io::TestExternalities {
-// 		let mut t = frame_system::GenesisConfig::<Runtime>::default()
-// 			.build_storage()
-// 			.expect("Frame system builds valid default genesis config");
-
-// 		pallet_balances::GenesisConfig::<Runtime> {
-// 			balances: self.balances,
-// 		}
-// 		.assimilate_storage(&mut t)
-// 		.expect("Pallet balances storage can be assimilated");
-
-// 		let mut ext = sp_io::TestExternalities::new(t);
-// 		ext.execute_with(|| {
-// 			System::set_block_number(1);
-// 			pallet_evm::Pallet::<Runtime>::create_account(
-// 				Revert.into(),
-// 				hex_literal::hex!("1460006000fd").to_vec(),
-// 			);
-// 		});
-// 		ext
-// 	}
-// }
-
-// // pub fn balance(account: impl Into<Account>) -> Balance {
-// // 	pallet_balances::Pallet::<Runtime>::usable_balance(account.into())
-// // }
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+use fp_evm::Context;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+
+use crate::{mock::*, CallPermitPrecompile, NonceBitmapsStorage, NoncesStorage, BATCH_PERMIT_TYPEHASH};
+
+type Precompile = CallPermitPrecompile<Runtime>;
+
+const DOMAIN_SEPARATOR: &str = "3e1db4cddcf120596216bbd4249c9e38469d63f94c238607b04f80ef16b26336";
+
+fn h256(input: &str) -> H256 {
+	let mut output = [0u8; 32];
+	hex::decode_to_slice(input, &mut output).unwrap();
+	output.into()
+}
+
+/// Compute the EIP-712 digest of a struct hash for the precompile domain.
+fn typed_data_digest(struct_hash: H256) -> H256 {
+	keccak_256(
+		&[
+			&b"\x19\x01"[..],
+			h256(DOMAIN_SEPARATOR).as_bytes(),
+			struct_hash.as_bytes(),
+		]
+		.concat(),
+	)
+	.into()
+}
+
+// The expected values below were computed with an EIP-712 encoder independent
+// of the precompile, following `encodeType`, `encodeData` and `hashStruct` of
+// the specification.
+
+#[test]
+fn batch_permit_typehash() {
+	assert_eq!(
+		H256::from(BATCH_PERMIT_TYPEHASH),
+		h256("a9fa155d379044b64735d22fec0e8dd67373df6cb830ddaf587529c6fde559c0")
+	);
+}
+
+#[test]
+fn domain_separator() {
+	assert_eq!(
+		H256::from(Precompile::compute_domain_separator(CallPermit.into())),
+		h256(DOMAIN_SEPARATOR)
+	);
+}
+
+#[test]
+fn batch_permit_digest() {
+	let permit = Precompile::generate_batch_permit(
+		CallPermit.into(),
+		H160::repeat_byte(0x11),
+		&[H160::repeat_byte(0x22), H160::repeat_byte(0x33)],
+		&[U256::from(1), U256::from(2)],
+		&[vec![0xde, 0xad, 0xbe, 0xef], vec![]],
+		&[100_000, 21_000],
+		1,
+		U256::from(7),
+		U256::from(1_000_000_000),
+	);
+
+	assert_eq!(
+		H256::from(permit),
+		typed_data_digest(h256("fefa5ed2c4456a7ba20e4528833b26c075ccc6e9dff917692a97988bccd847ce"))
+	);
+	assert_eq!(
+		H256::from(permit),
+		h256("b4a464f5d7cf149fb318c442703338bfc95890ff6ac247aec65a4b4ee9ff714a")
+	);
+}
+
+#[test]
+fn empty_batch_permit_digest() {
+	let permit = Precompile::generate_batch_permit(
+		CallPermit.into(),
+		H160::repeat_byte(0x11),
+		&[],
+		&[],
+		&[],
+		&[],
+		0,
+		U256::zero(),
+		U256::zero(),
+	);
+
+	assert_eq!(
+		H256::from(permit),
+		typed_data_digest(h256("74a07492a71f91dd1633107930e1495b7b19357776e0b9738a8a9d0d9054ce5f"))
+	);
+	assert_eq!(
+		H256::from(permit),
+		h256("175a6e16904aeb8f7062c860261614e7d5bec47d606896e275d96bd24b680217")
+	);
+}
+
+#[test]
+fn batch_permit_binds_arrays() {
+	let permit = |data: &[Vec<u8>], gaslimit: &[u64]| {
+		Precompile::generate_batch_permit(
+			CallPermit.into(),
+			H160::repeat_byte(0x11),
+			&[H160::repeat_byte(0x22), H160::repeat_byte(0x33)],
+			&[U256::from(1), U256::from(2)],
+			data,
+			gaslimit,
+			1,
+			U256::from(7),
+			U256::from(1_000_000_000),
+		)
+	};
+	let reference = permit(&[vec![0xde, 0xad, 0xbe, 0xef], vec![]], &[100_000, 21_000]);
+
+	// Moving bytes between items changes the digest.
+	assert_ne!(
+		reference,
+		permit(&[vec![0xde, 0xad, 0xbe], vec![0xef]], &[100_000, 21_000])
+	);
+	// Reordering items changes the digest.
+	assert_ne!(
+		reference,
+		permit(&[vec![], vec![0xde, 0xad, 0xbe, 0xef]], &[100_000, 21_000])
+	);
+	assert_ne!(
+		reference,
+		permit(&[vec![0xde, 0xad, 0xbe, 0xef], vec![]], &[21_000, 100_000])
+	);
+}
+
+const SECRET_KEY: [u8; 32] = [0x42; 32];
+const DEADLINE: u64 = u64::MAX;
+const GAS_LIMIT: u64 = 100_000;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn signer() -> H160 {
+	let secret_key = libsecp256k1::SecretKey::parse(&SECRET_KEY).unwrap();
+	let public_key = libsecp256k1::PublicKey::from_secret_key(&secret_key);
+	H160::from(H256::from(keccak_256(&public_key.serialize()[1..])))
+}
+
+/// Sign an unordered permit to call Bob without value nor data.
+fn sign_unordered_permit(nonce: U256) -> (u8, H256, H256) {
+	let permit = Precompile::generate_unordered_permit(
+		CallPermit.into(),
+		signer(),
+		Bob.into(),
+		U256::zero(),
+		Vec::new(),
+		GAS_LIMIT,
+		nonce,
+		U256::from(DEADLINE),
+	);
+	let secret_key = libsecp256k1::SecretKey::parse(&SECRET_KEY).unwrap();
+	let (signature, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&permit), &secret_key);
+	(
+		recovery_id.serialize() + 27,
+		H256::from(signature.r.b32()),
+		H256::from(signature.s.b32()),
+	)
+}
+
+fn dispatch_unordered_call(nonce: U256, (v, r, s): (u8, H256, H256)) -> PCall {
+	PCall::dispatch_unordered {
+		from: Address(signer()),
+		to: Address(Bob.into()),
+		value: U256::zero(),
+		data: Vec::new().into(),
+		gas_limit: GAS_LIMIT,
+		nonce,
+		deadline: U256::from(DEADLINE),
+		v,
+		r,
+		s,
+	}
+}
+
+/// Execute a signed unordered permit, checking that the call is dispatched
+/// on behalf of the signer.
+fn execute_unordered_permit(nonce: U256) {
+	precompiles()
+		.prepare_test(
+			Charlie,
+			CallPermit,
+			dispatch_unordered_call(nonce, sign_unordered_permit(nonce)),
+		)
+		.with_subcall_handle(|Subcall { address, context, .. }| {
+			assert_eq!(address, Bob.into());
+			assert_eq!(context.caller, signer());
+			SubcallOutput::succeed()
+		})
+		.execute_returns(UnboundedBytes::from(Vec::new()));
+}
+
+#[test]
+fn unordered_nonce_is_consumed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let nonce = U256::from(5);
+		let signature = sign_unordered_permit(nonce);
+
+		precompiles()
+			.prepare_test(
+				Charlie,
+				CallPermit,
+				PCall::is_unordered_permit_executable {
+					from: Address(signer()),
+					to: Address(Bob.into()),
+					value: U256::zero(),
+					data: Vec::new().into(),
+					gas_limit: GAS_LIMIT,
+					nonce,
+					deadline: U256::from(DEADLINE),
+					v: signature.0,
+					r: signature.1,
+					s: signature.2,
+				},
+			)
+			.execute_returns(true);
+
+		execute_unordered_permit(nonce);
+
+		assert_eq!(NonceBitmapsStorage::get(signer(), U256::zero()), U256::one() << 5);
+		// Unordered permits do not use the sequential nonce.
+		assert_eq!(NoncesStorage::get(signer()), U256::zero());
+
+		precompiles()
+			.prepare_test(
+				Charlie,
+				CallPermit,
+				PCall::is_unordered_permit_executable {
+					from: Address(signer()),
+					to: Address(Bob.into()),
+					value: U256::zero(),
+					data: Vec::new().into(),
+					gas_limit: GAS_LIMIT,
+					nonce,
+					deadline: U256::from(DEADLINE),
+					v: signature.0,
+					r: signature.1,
+					s: signature.2,
+				},
+			)
+			.execute_returns(false);
+	});
+}
+
+#[test]
+fn unordered_nonces_can_be_used_in_any_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		execute_unordered_permit(U256::from(300));
+		execute_unordered_permit(U256::from(5));
+		execute_unordered_permit(U256::from(6));
+
+		assert_eq!(
+			NonceBitmapsStorage::get(signer(), U256::zero()),
+			(U256::one() << 5) | (U256::one() << 6)
+		);
+		assert_eq!(NonceBitmapsStorage::get(signer(), U256::one()), U256::one() << 44);
+		precompiles()
+			.prepare_test(
+				Charlie,
+				CallPermit,
+				PCall::nonce_bitmap {
+					owner: Address(signer()),
+					word_pos: U256::one(),
+				},
+			)
+			.execute_returns(U256::one() << 44);
+	});
+}
+
+#[test]
+fn unordered_permit_cannot_be_replayed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let nonce = U256::from(5);
+		execute_unordered_permit(nonce);
+
+		precompiles()
+			.prepare_test(
+				Charlie,
+				CallPermit,
+				dispatch_unordered_call(nonce, sign_unordered_permit(nonce)),
+			)
+			.with_subcall_handle(|_| panic!("Replayed permit must not be dispatched"))
+			.execute_reverts(|output| output == b"Nonce already used");
+	});
+}
+
+#[test]
+fn unordered_permit_is_bound_to_nonce() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Charlie,
+				CallPermit,
+				dispatch_unordered_call(U256::from(6), sign_unordered_permit(U256::from(5))),
+			)
+			.with_subcall_handle(|_| panic!("Invalid permit must not be dispatched"))
+			.execute_reverts(|output| output == b"Invalid permit");
+
+		// The nonce of a rejected permit is not consumed.
+		assert_eq!(NonceBitmapsStorage::get(signer(), U256::zero()), U256::zero());
+	});
+}
+
+#[test]
+fn invalidated_unordered_nonce_cannot_be_used() {
+	ExtBuilder::default().build().execute_with(|| {
+		let nonce = U256::from(5);
+
+		precompiles()
+			.prepare_test(
+				MockAccount::from(signer()),
+				CallPermit,
+				PCall::invalidate_unordered_nonces {
+					word_pos: U256::zero(),
+					mask: U256::one() << 5,
+				},
+			)
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Charlie,
+				CallPermit,
+				dispatch_unordered_call(nonce, sign_unordered_permit(nonce)),
+			)
+			.with_subcall_handle(|_| panic!("Invalidated permit must not be dispatched"))
+			.execute_reverts(|output| output == b"Nonce already used");
+	});
+}
+
+#[test]
+fn nonces_cannot_be_managed_via_delegatecall() {
+	ExtBuilder::default().build().execute_with(|| {
+		// A contract delegating to the precompile, so that the caller in the
+		// context is the caller of the contract.
+		let delegatecall_handle = || {
+			MockHandle::new(
+				CallPermit.into(),
+				Context {
+					address: Alice.into(),
+					caller: signer(),
+					apparent_value: U256::zero(),
+				},
+			)
+		};
+		let expected_error = Err(revert("Cannot be called with DELEGATECALL or CALLCODE"));
+
+		assert_eq!(Precompile::use_nonce(&mut delegatecall_handle()), expected_error);
+		assert_eq!(
+			Precompile::invalidate_nonces(&mut delegatecall_handle(), U256::one()),
+			expected_error
+		);
+		assert_eq!(
+			Precompile::invalidate_unordered_nonces(&mut delegatecall_handle(), U256::zero(), U256::one()),
+			expected_error
+		);
+
+		assert_eq!(NoncesStorage::get(signer()), U256::zero());
+		assert_eq!(NonceBitmapsStorage::get(signer(), U256::zero()), U256::zero());
+	});
+}
+
+const ALL_OR_NOTHING: u8 = 0;
+const CONTINUE_ON_FAILURE: u8 = 1;
+
+/// Sign a batch permit, with the current nonce of the signer, calling Bob and
+/// then Charlie without value nor data.
+fn sign_batch_permit(mode: u8) -> (u8, H256, H256) {
+	let permit = Precompile::generate_batch_permit(
+		CallPermit.into(),
+		signer(),
+		&[Bob.into(), Charlie.into()],
+		&[U256::zero(), U256::zero()],
+		&[Vec::new(), Vec::new()],
+		&[GAS_LIMIT, GAS_LIMIT],
+		mode,
+		NoncesStorage::get(signer()),
+		U256::from(DEADLINE),
+	);
+	let secret_key = libsecp256k1::SecretKey::parse(&SECRET_KEY).unwrap();
+	let (signature, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&permit), &secret_key);
+	(
+		recovery_id.serialize() + 27,
+		H256::from(signature.r.b32()),
+		H256::from(signature.s.b32()),
+	)
+}
+
+fn batch_dispatch_call(mode: u8, (v, r, s): (u8, H256, H256)) -> PCall {
+	PCall::batch_dispatch {
+		from: Address(signer()),
+		to: vec![Address(Bob.into()), Address(Charlie.into())].into(),
+		value: vec![U256::zero(), U256::zero()].into(),
+		data: vec![Vec::new().into(), Vec::new().into()].into(),
+		gas_limit: vec![GAS_LIMIT, GAS_LIMIT].into(),
+		mode,
+		deadline: U256::from(DEADLINE),
+		v,
+		r,
+		s,
+	}
+}
+
+fn is_batch_permit_executable_call(mode: u8, (v, r, s): (u8, H256, H256)) -> PCall {
+	PCall::is_batch_permit_executable {
+		from: Address(signer()),
+		to: vec![Address(Bob.into()), Address(Charlie.into())].into(),
+		value: vec![U256::zero(), U256::zero()].into(),
+		data: vec![Vec::new().into(), Vec::new().into()].into(),
+		gas_limit: vec![GAS_LIMIT, GAS_LIMIT].into(),
+		mode,
+		deadline: U256::from(DEADLINE),
+		v,
+		r,
+		s,
+	}
+}
+
+fn charlie_revert_output() -> Vec<u8> {
+	precompile_utils::solidity::revert::revert_as_bytes("Charlie reverted")
+}
+
+/// Subcall handler for which the call to Bob succeeds and the call to Charlie
+/// reverts.
+fn charlie_reverts(Subcall { address, context, .. }: Subcall) -> SubcallOutput {
+	assert_eq!(context.caller, signer());
+	if address == Bob.into() {
+		SubcallOutput::succeed()
+	} else {
+		assert_eq!(address, Charlie.into());
+		SubcallOutput {
+			output: charlie_revert_output(),
+			..SubcallOutput::revert()
+		}
+	}
+}
+
+#[test]
+fn batch_permit_all_or_nothing_reverts_on_failure() {
+	ExtBuilder::default().build().execute_with(|| {
+		let signature = sign_batch_permit(ALL_OR_NOTHING);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				CallPermit,
+				is_batch_permit_executable_call(ALL_OR_NOTHING, signature),
+			)
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(Alice, CallPermit, batch_dispatch_call(ALL_OR_NOTHING, signature))
+			.with_subcall_handle(charlie_reverts)
+			.execute_reverts(|output| output == b"Charlie reverted");
+	});
+}
+
+#[test]
+fn batch_permit_all_or_nothing_succeeds() {
+	ExtBuilder::default().build().execute_with(|| {
+		let signature = sign_batch_permit(ALL_OR_NOTHING);
+
+		precompiles()
+			.prepare_test(Alice, CallPermit, batch_dispatch_call(ALL_OR_NOTHING, signature))
+			.with_subcall_handle(|Subcall { context, .. }| {
+				assert_eq!(context.caller, signer());
+				SubcallOutput::succeed()
+			})
+			.execute_returns((
+				vec![true, true],
+				vec![UnboundedBytes::from(Vec::new()), UnboundedBytes::from(Vec::new())],
+			));
+
+		// The whole batch consumes a single nonce.
+		assert_eq!(NoncesStorage::get(signer()), U256::one());
+	});
+}
+
+#[test]
+fn batch_permit_continue_on_failure() {
+	ExtBuilder::default().build().execute_with(|| {
+		let signature = sign_batch_permit(CONTINUE_ON_FAILURE);
+
+		precompiles()
+			.prepare_test(Alice, CallPermit, batch_dispatch_call(CONTINUE_ON_FAILURE, signature))
+			.with_subcall_handle(charlie_reverts)
+			.execute_returns((
+				vec![true, false],
+				vec![
+					UnboundedBytes::from(Vec::new()),
+					UnboundedBytes::from(charlie_revert_output()),
+				],
+			));
+
+		assert_eq!(NoncesStorage::get(signer()), U256::one());
+		// The permit cannot be executed again once its nonce is consumed.
+		precompiles()
+			.prepare_test(
+				Alice,
+				CallPermit,
+				is_batch_permit_executable_call(CONTINUE_ON_FAILURE, signature),
+			)
+			.execute_returns(false);
+	});
+}
+
+#[test]
+fn batch_permit_is_bound_to_mode() {
+	ExtBuilder::default().build().execute_with(|| {
+		let signature = sign_batch_permit(ALL_OR_NOTHING);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				CallPermit,
+				is_batch_permit_executable_call(CONTINUE_ON_FAILURE, signature),
+			)
+			.execute_returns(false);
+		precompiles()
+			.prepare_test(Alice, CallPermit, batch_dispatch_call(CONTINUE_ON_FAILURE, signature))
+			.with_subcall_handle(|_| panic!("Invalid permit must not be dispatched"))
+			.execute_reverts(|output| output == b"Invalid permit");
+	});
+}
+
+#[test]
+fn batch_permit_with_invalid_mode_is_not_executable() {
+	ExtBuilder::default().build().execute_with(|| {
+		let signature = sign_batch_permit(2);
+
+		precompiles()
+			.prepare_test(Alice, CallPermit, is_batch_permit_executable_call(2, signature))
+			.execute_returns(false);
+		precompiles()
+			.prepare_test(Alice, CallPermit, batch_dispatch_call(2, signature))
+			.with_subcall_handle(|_| panic!("Invalid batch must not be dispatched"))
+			.execute_reverts(|output| output == b"Invalid batch mode");
+	});
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The CredentialStatus contract's address.
+address constant CREDENTIAL_STATUS_ADDRESS = 0x00000000000000000000000000000000000007d2;
+
+/// @dev The CredentialStatus contract's instance.
+CredentialStatus constant CREDENTIAL_STATUS_CONTRACT = CredentialStatus(CREDENTIAL_STATUS_ADDRESS);
+
+/// @title Credential Status Interface
+/// @dev Checks the verifications and public credentials held by the DID linked to an address,
+/// e.g., to gate contract functionality behind a KYC credential.
+/// Statuses are returned as: 0 = not found, 1 = valid, 2 = revoked.
+/// CTypes are identified by their hash, attesters by their 32-byte DID subject identifier.
+/// @custom:address 0x00000000000000000000000000000000000007d2
+interface CredentialStatus {
+    /// @dev The status of a verification bound to the DID linked to an address.
+    /// Verifications not bound to that DID by their attester are reported as not found.
+    /// @param account The address linked to the verification subject.
+    /// @param claimHash The hash of the verified claim.
+    /// @return status The verification status, 0 if the address is not linked to a DID.
+    /// @return ctypeHash The CType of the verification, zero if not found.
+    /// @return attester The attester of the verification, zero if not found.
+    /// @custom:selector 0245980e
+    function verificationStatus(address account, bytes32 claimHash)
+        external
+        view
+        returns (uint8 status, bytes32 ctypeHash, bytes32 attester);
+
+    /// @dev The status of a public credential issued to the DID linked to an address.
+    /// @param account The address linked to the credential subject.
+    /// @param credentialId The ID of the credential.
+    /// @return status The credential status, 0 if the address is not linked to a DID.
+    /// @return ctypeHash The CType of the credential, zero if not found.
+    /// @return attester The attester of the credential, zero if not found.
+    /// @return blockNumber The block in which the credential was issued, 0 if not found.
+    /// @custom:selector e9d126d4
+    function credentialStatus(address account, bytes32 credentialId)
+        external
+        view
+        returns (uint8 status, bytes32 ctypeHash, bytes32 attester, uint64 blockNumber);
+
+    /// @dev Find a public credential of a CType issued by an attester to the DID linked to an address.
+    /// A valid credential is returned in favour of a revoked one.
+    /// @param account The address linked to the credential subject.
+    /// @param ctypeHash The required CType.
+    /// @param attester The required attester.
+    /// @return status The credential status, 0 if the address is not linked to a DID.
+    /// @return credentialId The ID of the credential found, zero if not found.
+    /// @return blockNumber The block in which the credential was issued, 0 if not found.
+    /// @custom:selector e1245b44
+    function findCredential(address account, bytes32 ctypeHash, bytes32 attester)
+        external
+        view
+        returns (uint8 status, bytes32 credentialId, uint64 blockNumber);
+}
@@ -0,0 +1,243 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Precompile exposing the status of the verifications and public credentials
+//! held by the DID linked to an EVM address.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use fp_evm::PrecompileHandle;
+use pallet_did_lookup::{account::AccountId20, linkable_account::LinkableAccountId, ConnectionRecord};
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use public_credentials::{CredentialEntryOf, CredentialIdOf, CtypeHashOf, SubjectIdOf};
+use sp_core::{H160, H256};
+use sp_runtime::traits::{Convert, UniqueSaturatedInto};
+use verification::{SubjectOf, VerificationDetailsOf};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod test;
+
+/// Status returned when no matching verification or credential exists.
+pub const STATUS_NOT_FOUND: u8 = 0;
+/// Status of a verification or credential that has not been revoked.
+pub const STATUS_VALID: u8 = 1;
+/// Status of a verification or credential that has been revoked.
+pub const STATUS_REVOKED: u8 = 2;
+
+/// Size of the `Twox64Concat` hash prepended to a map key.
+const TWOX_64_HASH_LEN: usize = 8;
+/// Size of the `Blake2_128Concat` hash prepended to a map key.
+const BLAKE2_128_HASH_LEN: usize = 16;
+
+type DidIdentifierOf<Runtime> = <Runtime as pallet_did_lookup::Config>::DidIdentifier;
+
+/// Precompile allowing EVM contracts to check whether the DID linked to an
+/// address holds an unrevoked verification or public credential.
+///
+/// * `SubjectIdOfDid`: Converts the DID linked to an address into the subject
+///   ID under which its public credentials are stored, if any.
+pub struct CredentialStatusPrecompile<Runtime, SubjectIdOfDid>(PhantomData<(Runtime, SubjectIdOfDid)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, SubjectIdOfDid> CredentialStatusPrecompile<Runtime, SubjectIdOfDid>
+where
+	Runtime: pallet_evm::Config
+		+ frame_system::Config<Hash = H256>
+		+ pallet_did_lookup::Config
+		+ verification::Config
+		+ public_credentials::Config,
+	SubjectIdOfDid: Convert<DidIdentifierOf<Runtime>, Option<SubjectIdOf<Runtime>>>,
+	DidIdentifierOf<Runtime>: Into<SubjectOf<Runtime>>,
+	verification::AttesterOf<Runtime>: AsRef<[u8; 32]>,
+	public_credentials::AttesterOf<Runtime>: From<[u8; 32]> + AsRef<[u8; 32]>,
+	CredentialIdOf<Runtime>: From<H256> + Into<H256>,
+{
+	/// The maximum size of a `ConnectedDids` storage entry, including its
+	/// key.
+	fn connected_did_entry_max_len() -> usize {
+		BLAKE2_128_HASH_LEN
+			.saturating_add(LinkableAccountId::max_encoded_len())
+			.saturating_add(ConnectionRecord::<
+				DidIdentifierOf<Runtime>,
+				<Runtime as frame_system::Config>::AccountId,
+				pallet_did_lookup::BalanceOf<Runtime>,
+			>::max_encoded_len())
+	}
+
+	/// The maximum size of a `VerificationSubjects` storage entry, including
+	/// its key.
+	fn verification_subject_entry_max_len() -> usize {
+		BLAKE2_128_HASH_LEN
+			.saturating_add(H256::max_encoded_len())
+			.saturating_add(SubjectOf::<Runtime>::max_encoded_len())
+	}
+
+	/// The maximum size of a `Verifications` storage entry, including its
+	/// key.
+	fn verification_entry_max_len() -> usize {
+		BLAKE2_128_HASH_LEN
+			.saturating_add(H256::max_encoded_len())
+			.saturating_add(VerificationDetailsOf::<Runtime>::max_encoded_len())
+	}
+
+	/// The maximum size of a `Credentials` storage entry, including its keys.
+	fn credential_entry_max_len() -> usize {
+		TWOX_64_HASH_LEN
+			.saturating_add(SubjectIdOf::<Runtime>::max_encoded_len())
+			.saturating_add(BLAKE2_128_HASH_LEN)
+			.saturating_add(CredentialIdOf::<Runtime>::max_encoded_len())
+			.saturating_add(CredentialEntryOf::<Runtime>::max_encoded_len())
+	}
+
+	/// The maximum size of a `CredentialsByCtypeAndAttester` storage entry,
+	/// including its keys.
+	fn credential_index_entry_max_len() -> usize {
+		TWOX_64_HASH_LEN
+			.saturating_add(SubjectIdOf::<Runtime>::max_encoded_len())
+			.saturating_add(BLAKE2_128_HASH_LEN)
+			.saturating_add(CtypeHashOf::<Runtime>::max_encoded_len())
+			.saturating_add(BLAKE2_128_HASH_LEN)
+			.saturating_add(public_credentials::AttesterOf::<Runtime>::max_encoded_len())
+			.saturating_add(TWOX_64_HASH_LEN)
+			.saturating_add(bool::max_encoded_len())
+			.saturating_add(BLAKE2_128_HASH_LEN)
+			.saturating_add(CredentialIdOf::<Runtime>::max_encoded_len())
+	}
+
+	/// Resolve the DID linked to the given address, charging for the lookup.
+	fn did_for_account(
+		handle: &mut impl PrecompileHandle,
+		account: Address,
+	) -> EvmResult<Option<DidIdentifierOf<Runtime>>> {
+		handle.record_db_read::<Runtime>(Self::connected_did_entry_max_len())?;
+
+		let account: H160 = account.into();
+		let linkable_account = LinkableAccountId::from(AccountId20::from(account));
+		Ok(pallet_did_lookup::ConnectedDids::<Runtime>::get(linkable_account).map(|record| record.did))
+	}
+
+	/// Resolve the public credential subject of the DID linked to the given
+	/// address, charging for the lookup.
+	fn subject_for_account(
+		handle: &mut impl PrecompileHandle,
+		account: Address,
+	) -> EvmResult<Option<SubjectIdOf<Runtime>>> {
+		Ok(Self::did_for_account(handle, account)?.and_then(SubjectIdOfDid::convert))
+	}
+
+	fn credential_status(revoked: bool) -> u8 {
+		if revoked {
+			STATUS_REVOKED
+		} else {
+			STATUS_VALID
+		}
+	}
+
+	#[precompile::public("verificationStatus(address,bytes32)")]
+	#[precompile::view]
+	fn verification_status(
+		handle: &mut impl PrecompileHandle,
+		account: Address,
+		claim_hash: H256,
+	) -> EvmResult<(u8, H256, H256)> {
+		let not_found = (STATUS_NOT_FOUND, H256::zero(), H256::zero());
+		let Some(did) = Self::did_for_account(handle, account)? else {
+			return Ok(not_found);
+		};
+
+		// Only verifications bound to the DID linked to the address are returned.
+		handle.record_db_read::<Runtime>(Self::verification_subject_entry_max_len())?;
+		if verification::VerificationSubjects::<Runtime>::get(claim_hash) != Some(did.into()) {
+			return Ok(not_found);
+		}
+
+		handle.record_db_read::<Runtime>(Self::verification_entry_max_len())?;
+
+		let Some(details) = verification::Verifications::<Runtime>::get(claim_hash) else {
+			return Ok(not_found);
+		};
+		Ok((
+			Self::credential_status(details.revoked),
+			details.ctype_hash,
+			H256::from(*details.attester.as_ref()),
+		))
+	}
+
+	#[precompile::public("credentialStatus(address,bytes32)")]
+	#[precompile::view]
+	fn credential_status_by_id(
+		handle: &mut impl PrecompileHandle,
+		account: Address,
+		credential_id: H256,
+	) -> EvmResult<(u8, H256, H256, u64)> {
+		let Some(subject) = Self::subject_for_account(handle, account)? else {
+			return Ok((STATUS_NOT_FOUND, H256::zero(), H256::zero(), 0));
+		};
+
+		handle.record_db_read::<Runtime>(Self::credential_entry_max_len())?;
+
+		let credential_id = CredentialIdOf::<Runtime>::from(credential_id);
+		let Some(entry) = public_credentials::Credentials::<Runtime>::get(subject, credential_id) else {
+			return Ok((STATUS_NOT_FOUND, H256::zero(), H256::zero(), 0));
+		};
+		Ok((
+			Self::credential_status(entry.revoked),
+			entry.ctype_hash,
+			H256::from(*entry.attester.as_ref()),
+			entry.block_number.unique_saturated_into(),
+		))
+	}
+
+	#[precompile::public("findCredential(address,bytes32,bytes32)")]
+	#[precompile::view]
+	fn find_credential(
+		handle: &mut impl PrecompileHandle,
+		account: Address,
+		ctype_hash: H256,
+		attester: H256,
+	) -> EvmResult<(u8, H256, u64)> {
+		let not_found = (STATUS_NOT_FOUND, H256::zero(), 0);
+		let Some(subject) = Self::subject_for_account(handle, account)? else {
+			return Ok(not_found);
+		};
+		let attester = public_credentials::AttesterOf::<Runtime>::from(attester.0);
+
+		// Looking up a valid credential and, if none is found, a revoked one.
+		handle.record_db_read::<Runtime>(Self::credential_index_entry_max_len())?;
+		handle.record_db_read::<Runtime>(Self::credential_index_entry_max_len())?;
+		let Some((credential_id, _)) =
+			public_credentials::Pallet::<Runtime>::find_credential(&subject, &ctype_hash, &attester)
+		else {
+			return Ok(not_found);
+		};
+
+		handle.record_db_read::<Runtime>(Self::credential_entry_max_len())?;
+		let Some(entry) = public_credentials::Credentials::<Runtime>::get(&subject, &credential_id) else {
+			return Ok(not_found);
+		};
+		Ok((
+			Self::credential_status(entry.revoked),
+			credential_id.into(),
+			entry.block_number.unique_saturated_into(),
+		))
+	}
+}
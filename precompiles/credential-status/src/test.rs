@@ -0,0 +1,347 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::assert_ok;
+use kilt_support::Deposit;
+use pallet_did_lookup::{account::AccountId20, linkable_account::LinkableAccountId};
+use precompile_utils::{prelude::*, testing::*};
+use public_credentials::credentials::CredentialEntry;
+use sp_core::{H160, H256};
+use sp_runtime::AccountId32;
+use verification::verifications::VerificationDetails;
+
+use crate::{mock::*, STATUS_NOT_FOUND, STATUS_REVOKED, STATUS_VALID};
+
+const DID_00: AccountId32 = AccountId32::new([1u8; 32]);
+const ATTESTER_00: AccountId32 = AccountId32::new([2u8; 32]);
+const ATTESTER_01: AccountId32 = AccountId32::new([3u8; 32]);
+const CTYPE_00: H256 = H256::repeat_byte(0x10);
+const CTYPE_01: H256 = H256::repeat_byte(0x11);
+const LINKED_ADDRESS: H160 = H160::repeat_byte(0xaa);
+const UNLINKED_ADDRESS: H160 = H160::repeat_byte(0xbb);
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn to_h256(id: &AccountId32) -> H256 {
+	H256::from(*AsRef::<[u8; 32]>::as_ref(id))
+}
+
+fn ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().with_balances(vec![(DID_00, 100 * KILT)]).build();
+	ext.execute_with(|| {
+		assert_ok!(DidLookup::add_association(
+			DID_00,
+			DID_00,
+			LinkableAccountId::from(AccountId20::from(LINKED_ADDRESS)),
+		));
+	});
+	ext
+}
+
+fn insert_verification(claim_hash: H256, attester: AccountId32, subject: Option<AccountId32>, revoked: bool) {
+	if let Some(subject) = subject {
+		verification::VerificationSubjects::<Runtime>::insert(claim_hash, subject);
+	}
+	verification::Verifications::<Runtime>::insert(
+		claim_hash,
+		VerificationDetails {
+			ctype_hash: CTYPE_00,
+			attester,
+			authorization_id: None,
+			revoked,
+			deposit: Deposit {
+				owner: DID_00,
+				amount: KILT,
+			},
+		},
+	);
+}
+
+fn insert_credential(credential_id: H256, ctype_hash: H256, attester: AccountId32, revoked: bool, block_number: u32) {
+	let subject = TestSubjectId(DID_00.into());
+	public_credentials::CredentialsByCtypeAndAttester::<Runtime>::insert(
+		(&subject, &ctype_hash, &attester, revoked, &credential_id),
+		(),
+	);
+	public_credentials::Credentials::<Runtime>::insert(
+		subject,
+		credential_id,
+		CredentialEntry {
+			ctype_hash,
+			attester,
+			revoked,
+			block_number,
+			deposit: Deposit {
+				owner: DID_00,
+				amount: KILT,
+			},
+			authorization_id: None,
+		},
+	);
+}
+
+fn read_cost(reads: u64) -> u64 {
+	RuntimeHelper::<Runtime>::db_read_gas_cost() * reads
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::verification_status_selectors().contains(&0x0245980e));
+	assert!(PCall::credential_status_by_id_selectors().contains(&0xe9d126d4));
+	assert!(PCall::find_credential_selectors().contains(&0xe1245b44));
+}
+
+#[test]
+fn verification_status() {
+	ext().execute_with(|| {
+		let valid_claim = H256::repeat_byte(1);
+		let revoked_claim = H256::repeat_byte(2);
+		insert_verification(valid_claim, ATTESTER_00, Some(DID_00), false);
+		insert_verification(revoked_claim, ATTESTER_01, Some(DID_00), true);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				CredentialStatus,
+				PCall::verification_status {
+					account: Address(LINKED_ADDRESS),
+					claim_hash: valid_claim,
+				},
+			)
+			.expect_cost(read_cost(3))
+			.expect_no_logs()
+			.execute_returns((STATUS_VALID, CTYPE_00, to_h256(&ATTESTER_00)));
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				CredentialStatus,
+				PCall::verification_status {
+					account: Address(LINKED_ADDRESS),
+					claim_hash: revoked_claim,
+				},
+			)
+			.execute_returns((STATUS_REVOKED, CTYPE_00, to_h256(&ATTESTER_01)));
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				CredentialStatus,
+				PCall::verification_status {
+					account: Address(LINKED_ADDRESS),
+					claim_hash: H256::repeat_byte(3),
+				},
+			)
+			.expect_cost(read_cost(2))
+			.execute_returns((STATUS_NOT_FOUND, H256::zero(), H256::zero()));
+	});
+}
+
+#[test]
+fn verification_status_of_other_subject() {
+	ext().execute_with(|| {
+		let other_subject_claim = H256::repeat_byte(1);
+		let unbound_claim = H256::repeat_byte(2);
+		insert_verification(other_subject_claim, ATTESTER_00, Some(ATTESTER_01), false);
+		insert_verification(unbound_claim, ATTESTER_00, None, false);
+
+		// Verifications bound to a different DID are not returned.
+		precompiles()
+			.prepare_test(
+				Caller,
+				CredentialStatus,
+				PCall::verification_status {
+					account: Address(LINKED_ADDRESS),
+					claim_hash: other_subject_claim,
+				},
+			)
+			.expect_cost(read_cost(2))
+			.execute_returns((STATUS_NOT_FOUND, H256::zero(), H256::zero()));
+
+		// Verifications not bound to any subject are not returned.
+		precompiles()
+			.prepare_test(
+				Caller,
+				CredentialStatus,
+				PCall::verification_status {
+					account: Address(LINKED_ADDRESS),
+					claim_hash: unbound_claim,
+				},
+			)
+			.execute_returns((STATUS_NOT_FOUND, H256::zero(), H256::zero()));
+
+		// The address is not linked to any DID.
+		precompiles()
+			.prepare_test(
+				Caller,
+				CredentialStatus,
+				PCall::verification_status {
+					account: Address(UNLINKED_ADDRESS),
+					claim_hash: other_subject_claim,
+				},
+			)
+			.expect_cost(read_cost(1))
+			.execute_returns((STATUS_NOT_FOUND, H256::zero(), H256::zero()));
+	});
+}
+
+#[test]
+fn credential_status() {
+	ext().execute_with(|| {
+		let credential_id = H256::repeat_byte(1);
+		insert_credential(credential_id, CTYPE_00, ATTESTER_00, false, 5);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				CredentialStatus,
+				PCall::credential_status_by_id {
+					account: Address(LINKED_ADDRESS),
+					credential_id,
+				},
+			)
+			.expect_cost(read_cost(2))
+			.expect_no_logs()
+			.execute_returns((STATUS_VALID, CTYPE_00, to_h256(&ATTESTER_00), 5u64));
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				CredentialStatus,
+				PCall::credential_status_by_id {
+					account: Address(LINKED_ADDRESS),
+					credential_id: H256::repeat_byte(2),
+				},
+			)
+			.execute_returns((STATUS_NOT_FOUND, H256::zero(), H256::zero(), 0u64));
+
+		// The address is not linked to any DID.
+		precompiles()
+			.prepare_test(
+				Caller,
+				CredentialStatus,
+				PCall::credential_status_by_id {
+					account: Address(UNLINKED_ADDRESS),
+					credential_id,
+				},
+			)
+			.expect_cost(read_cost(1))
+			.execute_returns((STATUS_NOT_FOUND, H256::zero(), H256::zero(), 0u64));
+	});
+}
+
+#[test]
+fn find_credential_prefers_valid_credentials() {
+	ext().execute_with(|| {
+		insert_credential(H256::repeat_byte(1), CTYPE_00, ATTESTER_00, true, 5);
+		insert_credential(H256::repeat_byte(2), CTYPE_00, ATTESTER_00, false, 6);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				CredentialStatus,
+				PCall::find_credential {
+					account: Address(LINKED_ADDRESS),
+					ctype_hash: CTYPE_00,
+					attester: to_h256(&ATTESTER_00),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns((STATUS_VALID, H256::repeat_byte(2), 6u64));
+	});
+}
+
+#[test]
+fn find_credential_revoked() {
+	ext().execute_with(|| {
+		insert_credential(H256::repeat_byte(1), CTYPE_00, ATTESTER_00, true, 5);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				CredentialStatus,
+				PCall::find_credential {
+					account: Address(LINKED_ADDRESS),
+					ctype_hash: CTYPE_00,
+					attester: to_h256(&ATTESTER_00),
+				},
+			)
+			// Account lookup, the two index lookups and the matching credential.
+			.expect_cost(read_cost(4))
+			.execute_returns((STATUS_REVOKED, H256::repeat_byte(1), 5u64));
+	});
+}
+
+#[test]
+fn find_credential_not_found() {
+	ext().execute_with(|| {
+		insert_credential(H256::repeat_byte(1), CTYPE_01, ATTESTER_00, false, 5);
+		insert_credential(H256::repeat_byte(2), CTYPE_00, ATTESTER_01, false, 5);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				CredentialStatus,
+				PCall::find_credential {
+					account: Address(LINKED_ADDRESS),
+					ctype_hash: CTYPE_00,
+					attester: to_h256(&ATTESTER_00),
+				},
+			)
+			.expect_cost(read_cost(3))
+			.execute_returns((STATUS_NOT_FOUND, H256::zero(), 0u64));
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				CredentialStatus,
+				PCall::find_credential {
+					account: Address(UNLINKED_ADDRESS),
+					ctype_hash: CTYPE_00,
+					attester: to_h256(&ATTESTER_00),
+				},
+			)
+			.expect_cost(read_cost(1))
+			.execute_returns((STATUS_NOT_FOUND, H256::zero(), 0u64));
+	});
+}
+
+#[test]
+fn find_credential_cost_does_not_depend_on_credential_count() {
+	ext().execute_with(|| {
+		for i in 0..100u8 {
+			insert_credential(H256::repeat_byte(i), CTYPE_01, ATTESTER_00, false, 5);
+		}
+		insert_credential(H256::repeat_byte(100), CTYPE_00, ATTESTER_00, false, 6);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				CredentialStatus,
+				PCall::find_credential {
+					account: Address(LINKED_ADDRESS),
+					ctype_hash: CTYPE_00,
+					attester: to_h256(&ATTESTER_00),
+				},
+			)
+			.expect_cost(read_cost(4))
+			.execute_returns((STATUS_VALID, H256::repeat_byte(100), 6u64));
+	});
+}
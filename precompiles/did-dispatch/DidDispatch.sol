@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The DidDispatch contract's address.
+address constant DID_DISPATCH_ADDRESS = 0x00000000000000000000000000000000000007d3;
+
+/// @dev The DidDispatch contract's instance.
+DidDispatch constant DID_DISPATCH_CONTRACT = DidDispatch(DID_DISPATCH_ADDRESS);
+
+/// @title DID Dispatch Interface
+/// @dev Dispatch DID-authorized runtime calls from an Ethereum address.
+/// Calls are SCALE-encoded runtime calls and must require a DID origin, e.g. adding a service endpoint,
+/// issuing an attestation or claiming a web3 name. They are dispatched on behalf of the DID with the
+/// caller's mapped account as submitter. The gas cost includes the weight of the dispatched call.
+/// None of the functions can be called via DELEGATECALL or CALLCODE.
+/// @custom:address 0x00000000000000000000000000000000000007d3
+interface DidDispatch {
+    /// @dev Allow the caller to dispatch calls on behalf of its currently linked DID.
+    /// The opt-in is bound to the linked DID and lapses if the caller is linked to a different DID.
+    /// @custom:selector b207e20a
+    function enableLinkedDidDispatch() external;
+
+    /// @dev Revoke the caller's opt-in for dispatching calls on behalf of its linked DID.
+    /// @custom:selector 929b07c0
+    function disableLinkedDidDispatch() external;
+
+    /// @dev Whether the account opted in for dispatching calls on behalf of its currently linked DID.
+    /// @param account The account to check.
+    /// @return Whether `dispatchAsLinkedDid` is enabled for the account.
+    /// @custom:selector aef68689
+    function linkedDidDispatchEnabled(address account) external view returns (bool);
+
+    /// @dev Dispatch a call on behalf of the DID linked to the caller.
+    /// The caller must have opted in via `enableLinkedDidDispatch` for the DID it is currently linked to,
+    /// and the DID must have a key for the verification relationship required by the call.
+    /// @param call The SCALE-encoded runtime call.
+    /// @custom:selector 1b5fac98
+    function dispatchAsLinkedDid(bytes memory call) external;
+
+    /// @dev Dispatch a call authorized by a DID signature.
+    /// The signature must be over the SCALE-encoded DID-authorized operation
+    /// (did, call, txCounter, blockNumber, submitter), where the submitter is the caller's mapped account.
+    /// @param did The DID subject identifier.
+    /// @param call The SCALE-encoded runtime call.
+    /// @param txCounter The next transaction counter of the DID.
+    /// @param blockNumber The block number from which the operation is valid.
+    /// @param signature The SCALE-encoded DID signature.
+    /// @custom:selector 5814b7bb
+    function dispatchWithDidSignature(
+        bytes32 did,
+        bytes memory call,
+        uint64 txCounter,
+        uint64 blockNumber,
+        bytes memory signature
+    ) external;
+}
@@ -0,0 +1,293 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Precompile allowing EVM accounts to dispatch DID-authorized calls.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	storage::types::{OptionQuery, StorageMap},
+	traits::{ConstU32, StorageInstance},
+	weights::Weight,
+	Blake2_128Concat,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_did_lookup::{account::AccountId20, linkable_account::LinkableAccountId, ConnectionRecord};
+use pallet_evm::{AddressMapping, GasWeightMapping};
+use parity_scale_codec::{DecodeLimit, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use sp_core::{H160, H256};
+use sp_runtime::traits::Dispatchable;
+use sp_std::vec::Vec;
+use uid_core::{
+	did_details::DidAuthorizedCallOperation, AccountIdOf, DeriveDidCallAuthorizationVerificationKeyRelationship,
+	DidAuthorizedCallOperationWithVerificationRelationship, DidCallableOf, DidIdentifierOf, DidRawOrigin, DidSignature,
+	WeightInfo,
+};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod test;
+
+/// The maximum length of an encoded DID call.
+pub const CALL_DATA_LIMIT: u32 = 2u32.pow(16);
+/// The maximum length of an encoded DID signature.
+pub const SIGNATURE_LIMIT: u32 = 128;
+/// The maximum nesting depth when decoding a DID call.
+pub const CALL_DECODE_DEPTH_LIMIT: u32 = 8;
+
+/// Size of the `Blake2_128Concat` hash prepended to a map key.
+const BLAKE2_128_HASH_LEN: usize = 16;
+
+/// Storage prefix for the linked DID dispatch opt-ins.
+pub struct LinkedDidDispatchOptIns;
+
+impl StorageInstance for LinkedDidDispatchOptIns {
+	const STORAGE_PREFIX: &'static str = "LinkedDidDispatchOptIns";
+
+	fn pallet_prefix() -> &'static str {
+		"PrecompileDidDispatch"
+	}
+}
+
+/// Storage type used to store the DID an address allowed to be dispatched
+/// on behalf of via `dispatchAsLinkedDid`.
+///
+/// The opt-in only applies as long as the address stays linked to that DID,
+/// so re-linking the address to a different DID requires a new opt-in.
+pub type LinkedDidDispatchOptInsStorage<DidIdentifier> = StorageMap<
+	LinkedDidDispatchOptIns,
+	// Address
+	Blake2_128Concat,
+	H160,
+	// DID the address opted in for
+	DidIdentifier,
+	OptionQuery,
+>;
+
+/// Precompile exposing `dispatch_as` and `submit_did_call` of the DID pallet
+/// to EVM accounts.
+///
+/// A DID call can be authorized either by the DID linked to the calling
+/// address, which grants the address the same rights as the DID keys, or by a
+/// DID signature over the call, in which case the caller is the submitter of
+/// the signed operation. In both cases the call is dispatched with a
+/// `DidRawOrigin` whose submitter is the account the caller is mapped to.
+///
+/// Dispatching on behalf of the linked DID requires the address to opt in
+/// first via `enableLinkedDidDispatch`. Neither function can be called with
+/// `DELEGATECALL` or `CALLCODE`, which would let a contract act with the
+/// identity of its caller.
+pub struct DidDispatchPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> DidDispatchPrecompile<Runtime>
+where
+	Runtime: pallet_evm::Config
+		+ uid_core::Config<RuntimeCall = <Runtime as frame_system::Config>::RuntimeCall>
+		+ pallet_did_lookup::Config<DidIdentifier = DidIdentifierOf<Runtime>>,
+	<Runtime as frame_system::Config>::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+		From<DidRawOrigin<DidIdentifierOf<Runtime>, AccountIdOf<Runtime>>>,
+	AccountIdOf<Runtime>: AsRef<[u8; 32]> + From<[u8; 32]>,
+	DidIdentifierOf<Runtime>: From<[u8; 32]>,
+{
+	/// The maximum size of a `ConnectedDids` storage entry, including its
+	/// key.
+	fn connected_did_entry_max_len() -> usize {
+		BLAKE2_128_HASH_LEN
+			.saturating_add(LinkableAccountId::max_encoded_len())
+			.saturating_add(ConnectionRecord::<
+				DidIdentifierOf<Runtime>,
+				AccountIdOf<Runtime>,
+				pallet_did_lookup::BalanceOf<Runtime>,
+			>::max_encoded_len())
+	}
+
+	/// The maximum size of a `LinkedDidDispatchOptIns` storage entry,
+	/// including its key.
+	fn opt_in_entry_max_len() -> usize {
+		BLAKE2_128_HASH_LEN
+			.saturating_add(H160::len_bytes())
+			.saturating_add(DidIdentifierOf::<Runtime>::max_encoded_len())
+	}
+
+	/// Revert if the precompile is executed in the context of another
+	/// address, i.e., via `DELEGATECALL` or `CALLCODE`.
+	fn ensure_not_delegate_call(handle: &impl PrecompileHandle) -> EvmResult {
+		if handle.code_address() != handle.context().address {
+			return Err(revert("Cannot be called with DELEGATECALL or CALLCODE"));
+		}
+		Ok(())
+	}
+
+	/// Return the DID the given address is currently linked to.
+	fn linked_did(address: H160) -> EvmResult<DidIdentifierOf<Runtime>> {
+		let linkable_account = LinkableAccountId::from(AccountId20::from(address));
+		pallet_did_lookup::ConnectedDids::<Runtime>::get(linkable_account)
+			.map(|record| record.did)
+			.ok_or_else(|| revert("Caller is not linked to a DID"))
+	}
+
+	/// Charge the gas equivalent of the given weight.
+	fn record_weight(handle: &mut impl PrecompileHandle, weight: Weight) -> EvmResult {
+		handle.record_cost(<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight))?;
+		handle.record_external_cost(None, Some(weight.proof_size()), None)?;
+		Ok(())
+	}
+
+	/// Decode a SCALE-encoded DID call.
+	fn decode_did_call(call: Vec<u8>) -> EvmResult<DidCallableOf<Runtime>> {
+		DidCallableOf::<Runtime>::decode_with_depth_limit(CALL_DECODE_DEPTH_LIMIT, &mut &call[..])
+			.map_err(|_| revert("Failed to decode call"))
+	}
+
+	/// Dispatch a DID call, charging gas according to its dispatch info.
+	fn dispatch_did_call(
+		handle: &mut impl PrecompileHandle,
+		did: DidIdentifierOf<Runtime>,
+		submitter: AccountIdOf<Runtime>,
+		call: DidCallableOf<Runtime>,
+	) -> EvmResult {
+		let origin = DidRawOrigin { id: did, submitter };
+		RuntimeHelper::<Runtime>::try_dispatch(handle, origin.into(), call, 0)?;
+		Ok(())
+	}
+
+	#[precompile::public("dispatchAsLinkedDid(bytes)")]
+	fn dispatch_as_linked_did(
+		handle: &mut impl PrecompileHandle,
+		call: BoundedBytes<ConstU32<CALL_DATA_LIMIT>>,
+	) -> EvmResult {
+		Self::ensure_not_delegate_call(handle)?;
+		handle.record_db_read::<Runtime>(Self::connected_did_entry_max_len())?;
+		handle.record_db_read::<Runtime>(Self::opt_in_entry_max_len())?;
+		Self::record_weight(handle, <Runtime as uid_core::Config>::WeightInfo::dispatch_as())?;
+
+		let call = Self::decode_did_call(call.into())?;
+		let verification_key_relationship = call
+			.derive_verification_key_relationship()
+			.map_err(|_| revert("Call cannot be dispatched with a DID origin"))?;
+
+		let caller: H160 = handle.context().caller;
+		let did = Self::linked_did(caller)?;
+		if LinkedDidDispatchOptInsStorage::<DidIdentifierOf<Runtime>>::get(caller).as_ref() != Some(&did) {
+			return Err(revert("Linked DID dispatch not enabled"));
+		}
+		// Like `dispatch_as`, the DID must have a key for the relationship required by
+		// the call. The linkage takes the place of the key signature.
+		let did_details = uid_core::Did::<Runtime>::get(&did).ok_or_else(|| revert("DID not found"))?;
+		if did_details
+			.get_verification_key_for_key_type(verification_key_relationship)
+			.is_none()
+		{
+			return Err(revert("DID has no key for the call"));
+		}
+
+		let submitter = <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(caller);
+		Self::dispatch_did_call(handle, did, submitter, call)
+	}
+
+	#[precompile::public("dispatchWithDidSignature(bytes32,bytes,uint64,uint64,bytes)")]
+	fn dispatch_with_did_signature(
+		handle: &mut impl PrecompileHandle,
+		did: H256,
+		call: BoundedBytes<ConstU32<CALL_DATA_LIMIT>>,
+		tx_counter: u64,
+		block_number: u64,
+		signature: BoundedBytes<ConstU32<SIGNATURE_LIMIT>>,
+	) -> EvmResult {
+		Self::ensure_not_delegate_call(handle)?;
+		let max_signature_weight = <Runtime as uid_core::Config>::WeightInfo::submit_did_call_ed25519_key()
+			.max(<Runtime as uid_core::Config>::WeightInfo::submit_did_call_sr25519_key())
+			.max(<Runtime as uid_core::Config>::WeightInfo::submit_did_call_ecdsa_key());
+		Self::record_weight(handle, max_signature_weight)?;
+
+		let call = Self::decode_did_call(call.into())?;
+		let verification_key_relationship = call
+			.derive_verification_key_relationship()
+			.map_err(|_| revert("Call cannot be dispatched with a DID origin"))?;
+		let signature: Vec<u8> = signature.into();
+		let signature = DidSignature::decode_with_depth_limit(1, &mut &signature[..])
+			.map_err(|_| revert("Failed to decode signature"))?;
+		let block_number: BlockNumberFor<Runtime> = block_number
+			.try_into()
+			.map_err(|_| revert("Block number out of bounds"))?;
+
+		let did = DidIdentifierOf::<Runtime>::from(did.0);
+		let submitter = <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(handle.context().caller);
+		let operation = DidAuthorizedCallOperationWithVerificationRelationship::<Runtime> {
+			operation: DidAuthorizedCallOperation {
+				did,
+				tx_counter,
+				call,
+				block_number,
+				submitter,
+			},
+			verification_key_relationship,
+		};
+		uid_core::Pallet::<Runtime>::verify_did_operation_signature_and_increase_nonce(&operation, &signature)
+			.map_err(|_| revert("Invalid DID signature"))?;
+
+		let DidAuthorizedCallOperation {
+			did, call, submitter, ..
+		} = operation.operation;
+		Self::dispatch_did_call(handle, did, submitter, call)
+	}
+
+	#[precompile::public("enableLinkedDidDispatch()")]
+	fn enable_linked_did_dispatch(handle: &mut impl PrecompileHandle) -> EvmResult {
+		Self::ensure_not_delegate_call(handle)?;
+		handle.record_db_read::<Runtime>(Self::connected_did_entry_max_len())?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		handle.record_external_cost(None, None, Some(Self::opt_in_entry_max_len() as u64))?;
+
+		let caller: H160 = handle.context().caller;
+		let did = Self::linked_did(caller)?;
+		LinkedDidDispatchOptInsStorage::<DidIdentifierOf<Runtime>>::insert(caller, did);
+		Ok(())
+	}
+
+	#[precompile::public("disableLinkedDidDispatch()")]
+	fn disable_linked_did_dispatch(handle: &mut impl PrecompileHandle) -> EvmResult {
+		Self::ensure_not_delegate_call(handle)?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+		LinkedDidDispatchOptInsStorage::<DidIdentifierOf<Runtime>>::remove(handle.context().caller);
+		Ok(())
+	}
+
+	#[precompile::public("linkedDidDispatchEnabled(address)")]
+	#[precompile::view]
+	fn linked_did_dispatch_enabled(handle: &mut impl PrecompileHandle, account: Address) -> EvmResult<bool> {
+		handle.record_db_read::<Runtime>(Self::connected_did_entry_max_len())?;
+		handle.record_db_read::<Runtime>(Self::opt_in_entry_max_len())?;
+
+		let account: H160 = account.into();
+		let Some(opted_in_did) = LinkedDidDispatchOptInsStorage::<DidIdentifierOf<Runtime>>::get(account) else {
+			return Ok(false);
+		};
+		let linkable_account = LinkableAccountId::from(AccountId20::from(account));
+		Ok(pallet_did_lookup::ConnectedDids::<Runtime>::get(linkable_account)
+			.is_some_and(|record| record.did == opted_in_did))
+	}
+}
@@ -0,0 +1,244 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Test utilities
+
+use super::*;
+
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU128, ConstU32, ConstU64, Everything},
+	weights::{constants::RocksDbWeight, Weight},
+};
+use frame_system::EnsureSigned;
+use pallet_evm::{EnsureAddressNever, EnsureAddressRoot, HashedAddressMapping};
+use parity_scale_codec::Decode;
+use precompile_utils::{mock_account, precompile_set::*, testing::MockAccount};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
+	BuildStorage, MultiSignature,
+};
+use uid_core::{
+	DeriveDidCallAuthorizationVerificationKeyRelationship, DeriveDidCallKeyRelationshipResult, DidRawOrigin,
+	EnsureDidOrigin, RelationshipDeriveError,
+};
+
+pub(crate) type AccountId = <<MultiSignature as Verify>::Signer as IdentifyAccount>::AccountId;
+pub(crate) type Balance = u128;
+pub(crate) type DidIdentifier = AccountId;
+
+type Block = frame_system::mocking::MockBlockU32<Runtime>;
+
+pub(crate) const KILT: Balance = 10u128.pow(15);
+
+construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
+		Evm: pallet_evm,
+		Did: uid_core,
+		DidLookup: pallet_did_lookup,
+	}
+);
+
+impl frame_system::Config for Runtime {
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type AccountId = AccountId;
+	type BaseCallFilter = Everything;
+	type Block = Block;
+	type BlockHashCount = ConstU32<250>;
+	type BlockLength = ();
+	type BlockWeights = ();
+	type DbWeight = RocksDbWeight;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type MaxConsumers = ConstU32<16>;
+	type Nonce = u64;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type PalletInfo = PalletInfo;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeTask = ();
+	type SS58Prefix = ();
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+impl pallet_balances::Config for Runtime {
+	type AccountStore = System;
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxFreezes = ConstU32<50>;
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type WeightInfo = ();
+}
+
+impl pallet_timestamp::Config for Runtime {
+	type MinimumPeriod = ConstU64<5>;
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type WeightInfo = ();
+}
+
+impl DeriveDidCallAuthorizationVerificationKeyRelationship for RuntimeCall {
+	fn derive_verification_key_relationship(&self) -> DeriveDidCallKeyRelationshipResult {
+		match self {
+			RuntimeCall::Did(_) | RuntimeCall::System(_) => {
+				Ok(uid_core::DidVerificationKeyRelationship::Authentication)
+			}
+			_ => Err(RelationshipDeriveError::NotCallableByDid),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn get_call_for_did_call_benchmark() -> Self {
+		RuntimeCall::System(frame_system::Call::remark { remark: sp_std::vec![] })
+	}
+}
+
+parameter_types! {
+	#[derive(Clone, TypeInfo, Debug, PartialEq, Eq, Encode, Decode)]
+	pub const MaxNewKeyAgreementKeys: u32 = 10;
+	#[derive(Debug, Clone, PartialEq)]
+	pub const MaxTotalKeyAgreementKeys: u32 = 10;
+	#[derive(Debug, Clone)]
+	pub const MaxPublicKeysPerDid: u32 = 13;
+}
+
+impl uid_core::Config for Runtime {
+	type BalanceMigrationManager = ();
+	type BaseDeposit = ConstU128<KILT>;
+	type Currency = Balances;
+	type DepositIndex = ();
+	type DidIdentifier = DidIdentifier;
+	type EnsureOrigin = EnsureDidOrigin<DidIdentifier, AccountId>;
+	type Fee = ConstU128<KILT>;
+	type FeeCollector = ();
+	type IdentityChangeHook = ();
+	type KeyDeposit = ConstU128<KILT>;
+	type MaxBlocksTxValidity = ConstU32<10>;
+	type MaxNewKeyAgreementKeys = MaxNewKeyAgreementKeys;
+	type MaxNumberOfServicesPerDid = ConstU32<1>;
+	type MaxNumberOfTypesPerService = ConstU32<1>;
+	type MaxNumberOfUrlsPerService = ConstU32<1>;
+	type MaxPublicKeysPerDid = MaxPublicKeysPerDid;
+	type MaxServiceIdLength = ConstU32<100>;
+	type MaxServiceTypeLength = ConstU32<100>;
+	type MaxServiceUrlLength = ConstU32<100>;
+	type MaxTotalKeyAgreementKeys = MaxTotalKeyAgreementKeys;
+	type OriginSuccess = DidRawOrigin<DidIdentifier, AccountId>;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeOrigin = RuntimeOrigin;
+	type ServiceEndpointDeposit = ConstU128<KILT>;
+	type WeightInfo = ();
+}
+
+impl pallet_did_lookup::Config for Runtime {
+	type BalanceMigrationManager = ();
+	type Currency = Balances;
+	type Deposit = ConstU128<KILT>;
+	type DepositIndex = ();
+	type DidIdentifier = DidIdentifier;
+	type EnsureOrigin = EnsureSigned<AccountId>;
+	type EthereumChainId = ConstU64<1>;
+	type IdentityChangeHook = ();
+	type OriginSuccess = AccountId;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type WeightInfo = ();
+}
+
+mock_account!(DidDispatch, |_| MockAccount::from_u64(1));
+mock_account!(Caller, |_| MockAccount::from_u64(2));
+
+pub type Precompiles<R> = PrecompileSetBuilder<R, PrecompileAt<AddressU64<1>, DidDispatchPrecompile<R>>>;
+
+pub type PCall = DidDispatchPrecompileCall<Runtime>;
+
+parameter_types! {
+	pub PrecompilesValue: Precompiles<Runtime> = Precompiles::new();
+	pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+	pub const SuicideQuickClearLimit: u32 = 0;
+}
+
+impl pallet_evm::Config for Runtime {
+	type AddressMapping = HashedAddressMapping<BlakeTwo256>;
+	type BlockGasLimit = ();
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type CallOrigin = EnsureAddressRoot<AccountId>;
+	type ChainId = ();
+	type Currency = Balances;
+	type FeeCalculator = ();
+	type FindAuthor = ();
+	type GasLimitPovSizeRatio = ();
+	type GasLimitStorageGrowthRatio = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type OnChargeTransaction = ();
+	type OnCreate = ();
+	type PrecompilesType = Precompiles<Runtime>;
+	type PrecompilesValue = PrecompilesValue;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type RuntimeEvent = RuntimeEvent;
+	type SuicideQuickClearLimit = SuicideQuickClearLimit;
+	type Timestamp = Timestamp;
+	type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
+	type WeightPerGas = WeightPerGas;
+}
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder {
+	balances: Vec<(AccountId, Balance)>,
+}
+
+impl ExtBuilder {
+	pub(crate) fn with_balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut storage = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.expect("Frame system builds valid default genesis config");
+
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut storage)
+		.expect("Pallet balances storage can be assimilated");
+
+		let mut ext = sp_io::TestExternalities::new(storage);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,470 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use fp_evm::{Context, ExitRevert, Precompile, PrecompileFailure};
+use frame_support::assert_ok;
+use pallet_did_lookup::{account::AccountId20, linkable_account::LinkableAccountId};
+use pallet_evm::AddressMapping;
+use parity_scale_codec::Encode;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{sr25519, Pair, H160, H256, U256};
+use sp_runtime::AccountId32;
+use uid_core::{
+	did_details::{DidAuthorizedCallOperation, DidEncryptionKey, DidVerificationKey},
+	DidSignature,
+};
+
+use crate::{mock::*, DidDispatchPrecompile, LinkedDidDispatchOptInsStorage};
+
+const DID_00: AccountId32 = AccountId32::new([1u8; 32]);
+const DID_01: AccountId32 = AccountId32::new([2u8; 32]);
+const KEY_AGREEMENT_KEY: [u8; 32] = [12u8; 32];
+const AUTH_SEED: [u8; 32] = [10u8; 32];
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn did_to_h256(did: &AccountId32) -> H256 {
+	H256::from(*AsRef::<[u8; 32]>::as_ref(did))
+}
+
+fn caller_account() -> AccountId {
+	<Runtime as pallet_evm::Config>::AddressMapping::into_account_id(Caller.into())
+}
+
+fn auth_key() -> sr25519::Pair {
+	sr25519::Pair::from_seed(&AUTH_SEED)
+}
+
+fn create_did(did: &AccountId32) {
+	assert_ok!(Did::create_from_account(
+		RuntimeOrigin::signed(did.clone()),
+		DidVerificationKey::Sr25519(auth_key().public()),
+	));
+}
+
+fn link_caller(did: &AccountId32) {
+	let caller: H160 = Caller.into();
+	assert_ok!(DidLookup::add_association(
+		DID_01,
+		did.clone(),
+		LinkableAccountId::from(AccountId20::from(caller)),
+	));
+}
+
+fn enable_linked_did_dispatch() {
+	precompiles()
+		.prepare_test(Caller, DidDispatch, PCall::enable_linked_did_dispatch {})
+		.expect_no_logs()
+		.execute_returns(());
+}
+
+fn add_key_agreement_key_call() -> RuntimeCall {
+	RuntimeCall::Did(uid_core::Call::add_key_agreement_key {
+		new_key: DidEncryptionKey::X25519(KEY_AGREEMENT_KEY),
+	})
+}
+
+fn sign_operation(did: &AccountId32, call: &RuntimeCall, tx_counter: u64, block_number: u32) -> Vec<u8> {
+	let operation = DidAuthorizedCallOperation {
+		did: did.clone(),
+		tx_counter,
+		call: call.clone(),
+		block_number,
+		submitter: caller_account(),
+	};
+	DidSignature::Sr25519(auth_key().sign(&operation.encode())).encode()
+}
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_balances(vec![(DID_00, 100 * KILT), (DID_01, 100 * KILT)])
+		.build()
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::dispatch_as_linked_did_selectors().contains(&0x1b5fac98));
+	assert!(PCall::dispatch_with_did_signature_selectors().contains(&0x5814b7bb));
+	assert!(PCall::enable_linked_did_dispatch_selectors().contains(&0xb207e20a));
+	assert!(PCall::disable_linked_did_dispatch_selectors().contains(&0x929b07c0));
+	assert!(PCall::linked_did_dispatch_enabled_selectors().contains(&0xaef68689));
+}
+
+#[test]
+fn dispatch_as_linked_did_works() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+		link_caller(&DID_00);
+		enable_linked_did_dispatch();
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidDispatch,
+				PCall::dispatch_as_linked_did {
+					call: add_key_agreement_key_call().encode().into(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(());
+
+		let did_details = Did::get_did(DID_00).expect("DID should exist");
+		assert_eq!(did_details.key_agreement_keys.len(), 1);
+	});
+}
+
+#[test]
+fn dispatch_as_linked_did_without_linked_did_reverts() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidDispatch,
+				PCall::dispatch_as_linked_did {
+					call: add_key_agreement_key_call().encode().into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Caller is not linked to a DID");
+	});
+}
+
+#[test]
+fn dispatch_as_linked_did_with_deleted_did_reverts() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+		link_caller(&DID_00);
+		enable_linked_did_dispatch();
+		assert_ok!(Did::delete_did(DID_00, 0));
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidDispatch,
+				PCall::dispatch_as_linked_did {
+					call: add_key_agreement_key_call().encode().into(),
+				},
+			)
+			.execute_reverts(|output| output == b"DID not found");
+	});
+}
+
+#[test]
+fn dispatch_as_linked_did_with_non_did_call_reverts() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+		link_caller(&DID_00);
+		enable_linked_did_dispatch();
+
+		let call = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+			dest: DID_01,
+			value: KILT,
+		});
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidDispatch,
+				PCall::dispatch_as_linked_did {
+					call: call.encode().into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Call cannot be dispatched with a DID origin");
+	});
+}
+
+#[test]
+fn dispatch_as_linked_did_with_invalid_call_reverts() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+		link_caller(&DID_00);
+		enable_linked_did_dispatch();
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidDispatch,
+				PCall::dispatch_as_linked_did {
+					call: vec![0xff, 0xff].into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Failed to decode call");
+	});
+}
+
+#[test]
+fn dispatch_as_linked_did_without_opt_in_reverts() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+		link_caller(&DID_00);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidDispatch,
+				PCall::dispatch_as_linked_did {
+					call: add_key_agreement_key_call().encode().into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Linked DID dispatch not enabled");
+
+		let did_details = Did::get_did(DID_00).expect("DID should exist");
+		assert!(did_details.key_agreement_keys.is_empty());
+	});
+}
+
+#[test]
+fn dispatch_as_linked_did_after_relinking_reverts() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+		create_did(&DID_01);
+		link_caller(&DID_00);
+		enable_linked_did_dispatch();
+		// The opt-in was given for `DID_00` and does not carry over to `DID_01`.
+		link_caller(&DID_01);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidDispatch,
+				PCall::linked_did_dispatch_enabled {
+					account: Address(Caller.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(false);
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidDispatch,
+				PCall::dispatch_as_linked_did {
+					call: add_key_agreement_key_call().encode().into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Linked DID dispatch not enabled");
+	});
+}
+
+#[test]
+fn dispatch_as_linked_did_after_disabling_reverts() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+		link_caller(&DID_00);
+		enable_linked_did_dispatch();
+
+		precompiles()
+			.prepare_test(Caller, DidDispatch, PCall::disable_linked_did_dispatch {})
+			.expect_no_logs()
+			.execute_returns(());
+		assert!(LinkedDidDispatchOptInsStorage::<DidIdentifier>::get(H160::from(Caller)).is_none());
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidDispatch,
+				PCall::dispatch_as_linked_did {
+					call: add_key_agreement_key_call().encode().into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Linked DID dispatch not enabled");
+	});
+}
+
+#[test]
+fn enable_linked_did_dispatch_works() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+		link_caller(&DID_00);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidDispatch,
+				PCall::linked_did_dispatch_enabled {
+					account: Address(Caller.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(false);
+
+		enable_linked_did_dispatch();
+
+		assert_eq!(
+			LinkedDidDispatchOptInsStorage::<DidIdentifier>::get(H160::from(Caller)),
+			Some(DID_00)
+		);
+		precompiles()
+			.prepare_test(
+				Alice,
+				DidDispatch,
+				PCall::linked_did_dispatch_enabled {
+					account: Address(Caller.into()),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(true);
+	});
+}
+
+#[test]
+fn enable_linked_did_dispatch_without_linked_did_reverts() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(Caller, DidDispatch, PCall::enable_linked_did_dispatch {})
+			.execute_reverts(|output| output == b"Caller is not linked to a DID");
+	});
+}
+
+#[test]
+fn delegate_call_reverts() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+		link_caller(&DID_00);
+		enable_linked_did_dispatch();
+
+		let calls: Vec<Vec<u8>> = vec![
+			PCall::enable_linked_did_dispatch {}.into(),
+			PCall::disable_linked_did_dispatch {}.into(),
+			PCall::dispatch_as_linked_did {
+				call: add_key_agreement_key_call().encode().into(),
+			}
+			.into(),
+			PCall::dispatch_with_did_signature {
+				did: did_to_h256(&DID_00),
+				call: add_key_agreement_key_call().encode().into(),
+				tx_counter: 1,
+				block_number: 1,
+				signature: sign_operation(&DID_00, &add_key_agreement_key_call(), 1, 1).into(),
+			}
+			.into(),
+		];
+		for input in calls {
+			// The precompile code is executed in the context of the `Alice` contract, which
+			// is called by `Caller`.
+			let mut handle = MockHandle::new(
+				DidDispatch.into(),
+				Context {
+					address: Alice.into(),
+					caller: Caller.into(),
+					apparent_value: U256::zero(),
+				},
+			);
+			handle.input = input;
+
+			match DidDispatchPrecompile::<Runtime>::execute(&mut handle) {
+				Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output,
+				}) => assert_eq!(
+					decode_revert_message(&output),
+					b"Cannot be called with DELEGATECALL or CALLCODE"
+				),
+				other => panic!("Unexpected result: {other:?}"),
+			}
+		}
+
+		let did_details = Did::get_did(DID_00).expect("DID should exist");
+		assert!(did_details.key_agreement_keys.is_empty());
+		assert_eq!(did_details.last_tx_counter, 0);
+		assert_eq!(
+			LinkedDidDispatchOptInsStorage::<DidIdentifier>::get(H160::from(Caller)),
+			Some(DID_00)
+		);
+	});
+}
+
+#[test]
+fn dispatch_with_did_signature_works() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+
+		let call = add_key_agreement_key_call();
+		let signature = sign_operation(&DID_00, &call, 1, 1);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidDispatch,
+				PCall::dispatch_with_did_signature {
+					did: did_to_h256(&DID_00),
+					call: call.encode().into(),
+					tx_counter: 1,
+					block_number: 1,
+					signature: signature.into(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(());
+
+		let did_details = Did::get_did(DID_00).expect("DID should exist");
+		assert_eq!(did_details.last_tx_counter, 1);
+		assert_eq!(did_details.key_agreement_keys.len(), 1);
+	});
+}
+
+#[test]
+fn dispatch_with_did_signature_with_invalid_counter_reverts() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+
+		let call = add_key_agreement_key_call();
+		let signature = sign_operation(&DID_00, &call, 2, 1);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidDispatch,
+				PCall::dispatch_with_did_signature {
+					did: did_to_h256(&DID_00),
+					call: call.encode().into(),
+					tx_counter: 2,
+					block_number: 1,
+					signature: signature.into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Invalid DID signature");
+	});
+}
+
+#[test]
+fn dispatch_with_did_signature_for_other_submitter_reverts() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+
+		let call = add_key_agreement_key_call();
+		let signature = sign_operation(&DID_00, &call, 1, 1);
+
+		// The signature commits to the mapped account of `Caller`, not `Alice`.
+		precompiles()
+			.prepare_test(
+				Alice,
+				DidDispatch,
+				PCall::dispatch_with_did_signature {
+					did: did_to_h256(&DID_00),
+					call: call.encode().into(),
+					tx_counter: 1,
+					block_number: 1,
+					signature: signature.into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Invalid DID signature");
+	});
+}
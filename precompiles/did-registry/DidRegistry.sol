@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The DidRegistry contract's address.
+address constant DID_REGISTRY_ADDRESS = 0x00000000000000000000000000000000000007d1;
+
+/// @dev The DidRegistry contract's instance.
+DidRegistry constant DID_REGISTRY_CONTRACT = DidRegistry(DID_REGISTRY_ADDRESS);
+
+/// @title DID Registry Interface
+/// @dev Read-only access to the DIDs stored on chain and to the DIDs linked to Ethereum addresses.
+/// DIDs are identified by their 32-byte subject identifier.
+/// Keys are returned together with a key type:
+///     0 = no key, 1 = Ed25519, 2 = Sr25519, 3 = ECDSA, 4 = account.
+/// @custom:address 0x00000000000000000000000000000000000007d1
+interface DidRegistry {
+    /// @dev Whether a DID currently exists on chain.
+    /// @param did The DID subject identifier.
+    /// @return Whether the DID exists.
+    /// @custom:selector 9d672876
+    function didExists(bytes32 did) external view returns (bool);
+
+    /// @dev Whether a DID has been deleted. Deleted DIDs cannot be created again.
+    /// @param did The DID subject identifier.
+    /// @return Whether the DID has been deleted.
+    /// @custom:selector 67823325
+    function isDeleted(bytes32 did) external view returns (bool);
+
+    /// @dev The authentication key of a DID.
+    /// @param did The DID subject identifier.
+    /// @return keyType The key type, 0 if the DID does not exist.
+    /// @return publicKey The raw public key, empty if the DID does not exist.
+    /// @custom:selector b7f39cec
+    function authenticationKey(bytes32 did) external view returns (uint8 keyType, bytes memory publicKey);
+
+    /// @dev The assertion key of a DID, used to issue attestations.
+    /// @param did The DID subject identifier.
+    /// @return keyType The key type, 0 if the DID does not exist or has no assertion key.
+    /// @return publicKey The raw public key, empty if there is no assertion key.
+    /// @custom:selector bf503e8c
+    function assertionKey(bytes32 did) external view returns (uint8 keyType, bytes memory publicKey);
+
+    /// @dev The X25519 key agreement keys of a DID.
+    /// @param did The DID subject identifier.
+    /// @return The key agreement keys, empty if the DID does not exist.
+    /// @custom:selector 91919cfb
+    function keyAgreementKeys(bytes32 did) external view returns (bytes32[] memory);
+
+    /// @dev The DID linked to an Ethereum address.
+    /// @param account The linked address.
+    /// @return linked Whether the address is linked to a DID.
+    /// @return did The linked DID subject identifier, zero if not linked.
+    /// @custom:selector 3c5ac82a
+    function didForAccount(address account) external view returns (bool linked, bytes32 did);
+}
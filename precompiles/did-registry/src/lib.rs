@@ -0,0 +1,184 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Precompile exposing the on-chain DID registry and the DID lookup to EVM
+//! contracts.
+//!
+//! The precompile is meant to be registered at `0x7d1`, the address declared
+//! in `DidRegistry.sol`. It is not registered in any precompile set yet: the
+//! KILT runtimes do not include `pallet_evm`, and the EVM runtime in
+//! `bak/runtime/evm`, which is not a workspace member, includes neither
+//! `uid_core` nor `pallet_did_lookup`. The address is reserved in the
+//! precompile set of that runtime until it does.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use fp_evm::PrecompileHandle;
+use pallet_did_lookup::{account::AccountId20, linkable_account::LinkableAccountId, ConnectionRecord};
+use parity_scale_codec::{Encode, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use sp_core::{H160, H256};
+use sp_std::vec::Vec;
+use uid_core::{
+	did_details::{DidDetails, DidEncryptionKey, DidPublicKey, DidVerificationKey},
+	AccountIdOf, DidIdentifierOf, KeyIdOf,
+};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod test;
+
+/// Key type returned when no key is set.
+pub const KEY_TYPE_NONE: u8 = 0;
+/// Key type of an Ed25519 verification key.
+pub const KEY_TYPE_ED25519: u8 = 1;
+/// Key type of an Sr25519 verification key.
+pub const KEY_TYPE_SR25519: u8 = 2;
+/// Key type of an ECDSA verification key.
+pub const KEY_TYPE_ECDSA: u8 = 3;
+/// Key type of an account used as verification key.
+pub const KEY_TYPE_ACCOUNT: u8 = 4;
+
+/// Size of the `Blake2_128Concat` hash prepended to every map key.
+const BLAKE2_128_HASH_LEN: usize = 16;
+
+/// Precompile exposing the read functions of the DID pallet and the DID
+/// lookup pallet via a stable Solidity ABI.
+pub struct DidRegistryPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> DidRegistryPrecompile<Runtime>
+where
+	Runtime:
+		pallet_evm::Config + uid_core::Config + pallet_did_lookup::Config<DidIdentifier = DidIdentifierOf<Runtime>>,
+	DidIdentifierOf<Runtime>: From<[u8; 32]> + AsRef<[u8; 32]>,
+{
+	/// The maximum size of a `Did` storage entry, including its key.
+	fn did_entry_max_len() -> usize {
+		BLAKE2_128_HASH_LEN
+			.saturating_add(DidIdentifierOf::<Runtime>::max_encoded_len())
+			.saturating_add(DidDetails::<Runtime>::max_encoded_len())
+	}
+
+	/// The maximum size of a `DidBlacklist` storage entry, including its key.
+	fn deleted_did_entry_max_len() -> usize {
+		BLAKE2_128_HASH_LEN.saturating_add(DidIdentifierOf::<Runtime>::max_encoded_len())
+	}
+
+	/// The maximum size of a `ConnectedDids` storage entry, including its
+	/// key.
+	fn connected_did_entry_max_len() -> usize {
+		BLAKE2_128_HASH_LEN
+			.saturating_add(LinkableAccountId::max_encoded_len())
+			.saturating_add(ConnectionRecord::<
+				DidIdentifierOf<Runtime>,
+				AccountIdOf<Runtime>,
+				pallet_did_lookup::BalanceOf<Runtime>,
+			>::max_encoded_len())
+	}
+
+	/// Read the details of a DID, charging for the storage read.
+	fn read_did(handle: &mut impl PrecompileHandle, did: H256) -> EvmResult<Option<DidDetails<Runtime>>> {
+		handle.record_db_read::<Runtime>(Self::did_entry_max_len())?;
+
+		let did = DidIdentifierOf::<Runtime>::from(did.0);
+		Ok(uid_core::Did::<Runtime>::get(did))
+	}
+
+	/// Encode the verification key with the given ID as a key type and its raw
+	/// bytes.
+	fn encode_verification_key(details: &DidDetails<Runtime>, key_id: &KeyIdOf<Runtime>) -> (u8, UnboundedBytes) {
+		let Some(DidPublicKey::PublicVerificationKey(key)) = details.public_keys.get(key_id).map(|d| &d.key) else {
+			return (KEY_TYPE_NONE, UnboundedBytes::from(Vec::new()));
+		};
+		let (key_type, key_bytes) = match key {
+			DidVerificationKey::Ed25519(key) => (KEY_TYPE_ED25519, key.as_ref().to_vec()),
+			DidVerificationKey::Sr25519(key) => (KEY_TYPE_SR25519, key.as_ref().to_vec()),
+			DidVerificationKey::Ecdsa(key) => (KEY_TYPE_ECDSA, key.as_ref().to_vec()),
+			DidVerificationKey::Account(account) => (KEY_TYPE_ACCOUNT, account.encode()),
+		};
+		(key_type, UnboundedBytes::from(key_bytes))
+	}
+
+	#[precompile::public("didExists(bytes32)")]
+	#[precompile::view]
+	fn did_exists(handle: &mut impl PrecompileHandle, did: H256) -> EvmResult<bool> {
+		Ok(Self::read_did(handle, did)?.is_some())
+	}
+
+	#[precompile::public("isDeleted(bytes32)")]
+	#[precompile::view]
+	fn is_deleted(handle: &mut impl PrecompileHandle, did: H256) -> EvmResult<bool> {
+		handle.record_db_read::<Runtime>(Self::deleted_did_entry_max_len())?;
+
+		let did = DidIdentifierOf::<Runtime>::from(did.0);
+		Ok(uid_core::Pallet::<Runtime>::get_deleted_did(did).is_some())
+	}
+
+	#[precompile::public("authenticationKey(bytes32)")]
+	#[precompile::view]
+	fn authentication_key(handle: &mut impl PrecompileHandle, did: H256) -> EvmResult<(u8, UnboundedBytes)> {
+		let Some(details) = Self::read_did(handle, did)? else {
+			return Ok((KEY_TYPE_NONE, UnboundedBytes::from(Vec::new())));
+		};
+		Ok(Self::encode_verification_key(&details, &details.authentication_key))
+	}
+
+	#[precompile::public("assertionKey(bytes32)")]
+	#[precompile::view]
+	fn assertion_key(handle: &mut impl PrecompileHandle, did: H256) -> EvmResult<(u8, UnboundedBytes)> {
+		let Some((details, key_id)) =
+			Self::read_did(handle, did)?.and_then(|details| details.attestation_key.map(|key_id| (details, key_id)))
+		else {
+			return Ok((KEY_TYPE_NONE, UnboundedBytes::from(Vec::new())));
+		};
+		Ok(Self::encode_verification_key(&details, &key_id))
+	}
+
+	#[precompile::public("keyAgreementKeys(bytes32)")]
+	#[precompile::view]
+	fn key_agreement_keys(handle: &mut impl PrecompileHandle, did: H256) -> EvmResult<Vec<H256>> {
+		let Some(details) = Self::read_did(handle, did)? else {
+			return Ok(Vec::new());
+		};
+		let keys = details
+			.key_agreement_keys
+			.iter()
+			.filter_map(|key_id| match details.public_keys.get(key_id).map(|d| &d.key) {
+				Some(DidPublicKey::PublicEncryptionKey(DidEncryptionKey::X25519(key))) => Some(H256::from(*key)),
+				_ => None,
+			})
+			.collect();
+		Ok(keys)
+	}
+
+	#[precompile::public("didForAccount(address)")]
+	#[precompile::view]
+	fn did_for_account(handle: &mut impl PrecompileHandle, account: Address) -> EvmResult<(bool, H256)> {
+		handle.record_db_read::<Runtime>(Self::connected_did_entry_max_len())?;
+
+		let account: H160 = account.into();
+		let linkable_account = LinkableAccountId::from(AccountId20::from(account));
+		match pallet_did_lookup::ConnectedDids::<Runtime>::get(linkable_account) {
+			Some(record) => Ok((true, H256::from(*record.did.as_ref()))),
+			None => Ok((false, H256::zero())),
+		}
+	}
+}
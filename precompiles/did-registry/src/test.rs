@@ -0,0 +1,266 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use frame_support::assert_ok;
+use pallet_did_lookup::{account::AccountId20, linkable_account::LinkableAccountId};
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{ed25519, sr25519, H160, H256};
+use sp_runtime::AccountId32;
+use uid_core::did_details::{DidEncryptionKey, DidVerificationKey};
+
+use crate::{mock::*, KEY_TYPE_ED25519, KEY_TYPE_NONE, KEY_TYPE_SR25519};
+
+const DID_00: AccountId32 = AccountId32::new([1u8; 32]);
+const DID_01: AccountId32 = AccountId32::new([2u8; 32]);
+const AUTH_KEY: [u8; 32] = [10u8; 32];
+const ASSERTION_KEY: [u8; 32] = [11u8; 32];
+const KEY_AGREEMENT_KEY: [u8; 32] = [12u8; 32];
+const LINKED_ADDRESS: H160 = H160::repeat_byte(0xaa);
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn did_to_h256(did: &AccountId32) -> H256 {
+	H256::from(*AsRef::<[u8; 32]>::as_ref(did))
+}
+
+fn create_did(did: &AccountId32) {
+	assert_ok!(Did::create_from_account(
+		RuntimeOrigin::signed(did.clone()),
+		DidVerificationKey::Sr25519(sr25519::Public::from_raw(AUTH_KEY)),
+	));
+}
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_balances(vec![(DID_00, 100 * KILT), (DID_01, 100 * KILT)])
+		.build()
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::did_exists_selectors().contains(&0x9d672876));
+	assert!(PCall::is_deleted_selectors().contains(&0x67823325));
+	assert!(PCall::authentication_key_selectors().contains(&0xb7f39cec));
+	assert!(PCall::assertion_key_selectors().contains(&0xbf503e8c));
+	assert!(PCall::key_agreement_keys_selectors().contains(&0x91919cfb));
+	assert!(PCall::did_for_account_selectors().contains(&0x3c5ac82a));
+}
+
+#[test]
+fn did_exists() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidRegistry,
+				PCall::did_exists {
+					did: did_to_h256(&DID_00),
+				},
+			)
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+			.expect_no_logs()
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidRegistry,
+				PCall::did_exists {
+					did: did_to_h256(&DID_01),
+				},
+			)
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+			.expect_no_logs()
+			.execute_returns(false);
+	});
+}
+
+#[test]
+fn is_deleted() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidRegistry,
+				PCall::is_deleted {
+					did: did_to_h256(&DID_00),
+				},
+			)
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+			.execute_returns(false);
+
+		assert_ok!(Did::delete(RuntimeOrigin::signed(DID_00), 0));
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidRegistry,
+				PCall::is_deleted {
+					did: did_to_h256(&DID_00),
+				},
+			)
+			.execute_returns(true);
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidRegistry,
+				PCall::did_exists {
+					did: did_to_h256(&DID_00),
+				},
+			)
+			.execute_returns(false);
+	});
+}
+
+#[test]
+fn authentication_key() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidRegistry,
+				PCall::authentication_key {
+					did: did_to_h256(&DID_00),
+				},
+			)
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+			.expect_no_logs()
+			.execute_returns((KEY_TYPE_SR25519, UnboundedBytes::from(AUTH_KEY.to_vec())));
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidRegistry,
+				PCall::authentication_key {
+					did: did_to_h256(&DID_01),
+				},
+			)
+			.execute_returns((KEY_TYPE_NONE, UnboundedBytes::from(Vec::new())));
+	});
+}
+
+#[test]
+fn assertion_key() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidRegistry,
+				PCall::assertion_key {
+					did: did_to_h256(&DID_00),
+				},
+			)
+			.execute_returns((KEY_TYPE_NONE, UnboundedBytes::from(Vec::new())));
+
+		assert_ok!(Did::set_attestation_key(
+			RuntimeOrigin::signed(DID_00),
+			DidVerificationKey::Ed25519(ed25519::Public::from_raw(ASSERTION_KEY)),
+		));
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidRegistry,
+				PCall::assertion_key {
+					did: did_to_h256(&DID_00),
+				},
+			)
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+			.expect_no_logs()
+			.execute_returns((KEY_TYPE_ED25519, UnboundedBytes::from(ASSERTION_KEY.to_vec())));
+	});
+}
+
+#[test]
+fn key_agreement_keys() {
+	ext().execute_with(|| {
+		create_did(&DID_00);
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidRegistry,
+				PCall::key_agreement_keys {
+					did: did_to_h256(&DID_00),
+				},
+			)
+			.execute_returns(Vec::<H256>::new());
+
+		assert_ok!(Did::add_key_agreement_key(
+			RuntimeOrigin::signed(DID_00),
+			DidEncryptionKey::X25519(KEY_AGREEMENT_KEY),
+		));
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidRegistry,
+				PCall::key_agreement_keys {
+					did: did_to_h256(&DID_00),
+				},
+			)
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+			.expect_no_logs()
+			.execute_returns(vec![H256::from(KEY_AGREEMENT_KEY)]);
+	});
+}
+
+#[test]
+fn did_for_account() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidRegistry,
+				PCall::did_for_account {
+					account: Address(LINKED_ADDRESS),
+				},
+			)
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+			.expect_no_logs()
+			.execute_returns((false, H256::zero()));
+
+		assert_ok!(DidLookup::add_association(
+			DID_01,
+			DID_00,
+			LinkableAccountId::from(AccountId20::from(LINKED_ADDRESS)),
+		));
+
+		precompiles()
+			.prepare_test(
+				Caller,
+				DidRegistry,
+				PCall::did_for_account {
+					account: Address(LINKED_ADDRESS),
+				},
+			)
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+			.expect_no_logs()
+			.execute_returns((true, did_to_h256(&DID_00)));
+	});
+}
@@ -0,0 +1,65 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// The components of an asset DID, each in its canonical UTF-8 encoded form.
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, Eq, PartialEq)]
+pub struct AssetDidComponents {
+	/// The namespace of the CAIP-2 chain ID, e.g., `eip155`.
+	pub chain_namespace: Vec<u8>,
+	/// The reference of the CAIP-2 chain ID, e.g., `1`.
+	pub chain_reference: Vec<u8>,
+	/// The namespace of the CAIP-19 asset ID, e.g., `erc721`.
+	pub asset_namespace: Vec<u8>,
+	/// The reference of the CAIP-19 asset ID, e.g., the address of an ERC721
+	/// contract.
+	pub asset_reference: Vec<u8>,
+	/// The identifier of the CAIP-19 asset ID, e.g., the ID of an ERC721
+	/// token, if present.
+	pub asset_identifier: Option<Vec<u8>>,
+}
+
+/// An asset DID as parsed by the runtime, together with the public credentials
+/// issued for it.
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, Eq, PartialEq)]
+pub struct ResolvedAssetDid<CredentialId, CredentialEntry> {
+	/// The canonical UTF-8 encoded form of the asset DID, including the
+	/// `did:asset:` prefix.
+	pub did: Vec<u8>,
+	/// The components of the asset DID.
+	pub components: AssetDidComponents,
+	/// The public credentials issued for the asset DID.
+	pub credentials: Vec<(CredentialId, CredentialEntry)>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API to validate and normalise asset DIDs.
+	pub trait AssetDids<CredentialId, CredentialEntry, Error> where
+		CredentialId: Codec,
+		CredentialEntry: Codec,
+		Error: Codec,
+		{
+			/// Parse the given UTF-8 encoded asset DID, and return its canonical form, its components and the public credentials issued for it.
+			/// It returns an error if the provided input is not a valid asset DID.
+			fn resolve(asset_did: Vec<u8>) -> Result<ResolvedAssetDid<CredentialId, CredentialEntry>, Error>;
+		}
+}
@@ -0,0 +1,48 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// A deposit held by an account.
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, Eq, PartialEq)]
+pub struct DepositInfo<AccountId, Balance> {
+	/// The name of the pallet holding the deposit, as configured in the
+	/// runtime.
+	pub pallet: Vec<u8>,
+	/// The final storage key of the entry the deposit has been taken for.
+	pub key: Vec<u8>,
+	/// The owner of the deposit.
+	pub owner: AccountId,
+	/// The amount of the deposit.
+	pub amount: Balance,
+	/// Whether the deposit owner can reclaim the deposit at the current block.
+	pub reclaimable: bool,
+}
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API to list all the deposits held by a given account.
+	pub trait Deposits<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec,
+		{
+			fn deposits(owner: AccountId) -> Vec<DepositInfo<AccountId, Balance>>;
+		}
+}
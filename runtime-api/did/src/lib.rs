@@ -40,6 +40,30 @@ pub struct DidLinkedInfo<
 	Balance,
 	Key: Ord,
 	BlockNumber,
+> {
+	pub identifier: DidIdentifier,
+	pub accounts: Vec<LinkableAccountId>,
+	pub w3n: Option<Web3Name>,
+	/// The key-value text records attached to the web3name, if any.
+	pub text_records: Vec<(Vec<u8>, Vec<u8>)>,
+	pub service_endpoints: Vec<ServiceEndpoint<Id, Type, Url>>,
+	pub details: DidDetails<Key, BlockNumber, AccountId, Balance>,
+}
+
+/// The DidLinkedInfo as returned by versions of the runtime API prior to 4,
+/// which did not include the text records of the web3name.
+#[derive(Encode, Decode, TypeInfo, Eq, PartialEq)]
+pub struct LegacyDidLinkedInfo<
+	DidIdentifier,
+	AccountId,
+	LinkableAccountId,
+	Web3Name,
+	Id,
+	Type,
+	Url,
+	Balance,
+	Key: Ord,
+	BlockNumber,
 > {
 	pub identifier: DidIdentifier,
 	pub accounts: Vec<LinkableAccountId>,
@@ -65,8 +89,32 @@ pub type RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance,
 	BlockNumber,
 >;
 
+/// The LegacyDidLinkedInfo with a Web3Name represented as a byte array.
+pub type RawLegacyDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber> =
+	LegacyDidLinkedInfo<
+		DidIdentifier,
+		AccountId,
+		LinkableAccountId,
+		Vec<u8>,
+		Vec<u8>,
+		Vec<u8>,
+		Vec<u8>,
+		Balance,
+		Key,
+		BlockNumber,
+	>;
+
+/// The different representations of a web3 name.
+#[derive(Encode, Decode, TypeInfo, Eq, PartialEq, Clone, Debug)]
+pub struct Web3NameForms {
+	/// The ASCII form of the name, as stored on chain.
+	pub ascii: Vec<u8>,
+	/// The UTF-8 encoded form of the name, to be shown to users.
+	pub display: Vec<u8>,
+}
+
 sp_api::decl_runtime_apis! {
-	#[api_version(2)]
+	#[api_version(5)]
 	pub trait Did<DidIdentifier, AccountId, LinkableAccountId, Balance, Key: Ord, BlockNumber: MaxEncodedLen> where
 		DidIdentifier: Codec,
 		AccountId: Codec,
@@ -79,28 +127,48 @@ sp_api::decl_runtime_apis! {
 		/// * the DID
 		/// * public keys stored for the did
 		/// * the web3name (optional)
+		/// * the web3name text records
 		/// * associated accounts
 		/// * service endpoints
 		#[changed_in(2)]
-		fn query_by_web3_name(name: Vec<u8>) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, AccountId, Balance, Key, BlockNumber>>;
+		fn query_by_web3_name(name: Vec<u8>) -> Option<RawLegacyDidLinkedInfo<DidIdentifier, AccountId, AccountId, Balance, Key, BlockNumber>>;
+		#[changed_in(4)]
+		fn query_by_web3_name(name: Vec<u8>) -> Option<RawLegacyDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>;
 		fn query_by_web3_name(name: Vec<u8>) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>;
 		/// Given an account address this returns:
 		/// * the DID
 		/// * public keys stored for the did
 		/// * the web3name (optional)
+		/// * the web3name text records
 		/// * associated accounts
 		/// * service endpoints
 		#[changed_in(2)]
-		fn query_by_account(account: AccountId) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, AccountId, Balance, Key, BlockNumber>>;
+		fn query_by_account(account: AccountId) -> Option<RawLegacyDidLinkedInfo<DidIdentifier, AccountId, AccountId, Balance, Key, BlockNumber>>;
+		#[changed_in(4)]
+		fn query_by_account(account: LinkableAccountId) -> Option<RawLegacyDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>;
 		fn query_by_account(account: LinkableAccountId) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>;
 		/// Given a did this returns:
 		/// * the DID
 		/// * public keys stored for the did
 		/// * the web3name (optional)
+		/// * the web3name text records
 		/// * associated accounts
 		/// * service endpoints
 		#[changed_in(2)]
-		fn query(did: DidIdentifier) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, AccountId, Balance, Key, BlockNumber>>;
+		fn query(did: DidIdentifier) -> Option<RawLegacyDidLinkedInfo<DidIdentifier, AccountId, AccountId, Balance, Key, BlockNumber>>;
+		#[changed_in(4)]
+		fn query(did: DidIdentifier) -> Option<RawLegacyDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>;
 		fn query(did: DidIdentifier) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>;
+		/// Given a web3name, either in its ASCII form or in its UTF-8 encoded
+		/// Unicode form, this returns:
+		/// * the ASCII form, as stored on chain
+		/// * the display form, to be shown to users
+		///
+		/// `None` is returned if the input is not a valid web3name.
+		fn web3_name_forms(name: Vec<u8>) -> Option<Web3NameForms>;
+		/// Given a DID and an expiration block number, this returns the
+		/// challenge that must be signed by an account, regardless of its kind,
+		/// to be linked to the DID until the given block.
+		fn account_linking_challenge(did: DidIdentifier, expiration: BlockNumber) -> Vec<u8>;
 	}
 }
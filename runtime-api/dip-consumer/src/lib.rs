@@ -0,0 +1,38 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API to negotiate the DIP commitment version that proofs for the
+	/// consumer must be generated for.
+	pub trait DipConsumerCommitmentVersions<Version> where
+		Version: Codec,
+		{
+			/// Return the commitment versions the DIP proof verifier supports.
+			fn supported_commitment_versions() -> Vec<Version>;
+
+			/// Return the highest of the provided commitment versions, e.g., the
+			/// ones the provider stores for a subject, that the DIP proof
+			/// verifier supports, if any.
+			fn negotiate_commitment_version(available_versions: Vec<Version>) -> Option<Version>;
+		}
+}
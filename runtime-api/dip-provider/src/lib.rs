@@ -19,6 +19,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
 	/// Runtime API to generate a DIP proof with the provided parameters.
@@ -30,4 +31,26 @@ sp_api::decl_runtime_apis! {
 			/// Generate a DIP proof with the parameters specified in the request.
 			fn generate_proof(request: ProofRequest) -> Result<Success, Error>;
 		}
+
+	/// Runtime API to generate a DIP proof for a salted commitment, which also
+	/// includes the salts of the revealed leaves.
+	pub trait DipSaltedProofProvider<ProofRequest, Success, Error> where
+		ProofRequest: Codec,
+		Success: Codec,
+		Error: Codec,
+		{
+			/// Generate a salted DIP proof with the parameters specified in the
+			/// request.
+			fn generate_salted_proof(request: ProofRequest) -> Result<Success, Error>;
+		}
+
+	/// Runtime API to list the DIP commitment versions stored for a subject.
+	pub trait DipCommitmentVersions<Identifier, Version> where
+		Identifier: Codec,
+		Version: Codec,
+		{
+			/// Return the versions of all the identity commitments stored for the
+			/// provided subject.
+			fn commitment_versions(identifier: Identifier) -> Vec<Version>;
+		}
 }
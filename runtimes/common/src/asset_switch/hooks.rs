@@ -16,14 +16,23 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use frame_support::traits::fungible::Inspect;
+use frame_support::{ensure, traits::fungible::Inspect};
 use pallet_asset_switch::traits::SwitchHooks;
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::RuntimeDebug;
+use sp_std::marker::PhantomData;
 use xcm::{
 	v4::{Junction, Junctions, Location},
-	VersionedLocation,
+	VersionedAssetId, VersionedLocation,
 };
 
+use crate::dip::did::DidCredentialsProvider;
+
 const LOG_TARGET: &str = "runtime::asset-switch::RestrictTransfersToSameUser";
+const DID_CREDENTIAL_LOG_TARGET: &str = "runtime::asset-switch::RequireDidCredential";
 
 type AccountIdOf<R> = <R as frame_system::Config>::AccountId;
 type BalanceOf<R, I> = <<R as pallet_asset_switch::Config<I>>::LocalCurrency as Inspect<AccountIdOf<R>>>::Balance;
@@ -40,8 +49,11 @@ where
 	[u8; 32]: From<AccountIdOf<R>>,
 {
 	type Error = Error;
+	type Rules = ();
 
 	fn pre_local_to_remote_switch(
+		_remote_asset_id: &VersionedAssetId,
+		_rules: Option<&Self::Rules>,
 		from: &AccountIdOf<R>,
 		to: &VersionedLocation,
 		_amount: BalanceOf<R, I>,
@@ -76,6 +88,8 @@ where
 
 	// We don't need to take any actions after the switch is executed
 	fn post_local_to_remote_switch(
+		_remote_asset_id: &VersionedAssetId,
+		_rules: Option<&Self::Rules>,
 		_from: &AccountIdOf<R>,
 		_to: &VersionedLocation,
 		_amount: BalanceOf<R, I>,
@@ -83,18 +97,137 @@ where
 		Ok(())
 	}
 
-	fn pre_remote_to_local_switch(_to: &AccountIdOf<R>, _amount: u128) -> Result<(), Self::Error> {
+	fn pre_remote_to_local_switch(
+		_remote_asset_id: &VersionedAssetId,
+		_rules: Option<&Self::Rules>,
+		_to: &AccountIdOf<R>,
+		_amount: u128,
+	) -> Result<(), Self::Error> {
 		Ok(())
 	}
 
-	fn post_remote_to_local_switch(_to: &AccountIdOf<R>, _amount: u128) -> Result<(), Self::Error> {
+	fn post_remote_to_local_switch(
+		_remote_asset_id: &VersionedAssetId,
+		_rules: Option<&Self::Rules>,
+		_to: &AccountIdOf<R>,
+		_amount: u128,
+	) -> Result<(), Self::Error> {
 		Ok(())
 	}
 }
 
+/// The rules of the [`RequireDidCredential`] hook for a switch pair.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct DidCredentialRules {
+	/// The amount of local tokens a switch must exceed for the checks to
+	/// apply.
+	pub threshold: u128,
+	/// The hash of the CType the submitter must hold a credential of.
+	pub ctype_hash: H256,
+}
+
+/// Check requiring the account on this chain initiating a switch of more than
+/// the configured threshold to be linked to a DID via `pallet-did-lookup`, and
+/// the DID to hold a non-revoked public credential or verification of the
+/// configured CType, as returned by the `Credentials` provider.
+///
+/// The rules are set per switch pair, and switches for switch pairs without
+/// rules are not checked. Remote -> local switches are not checked, since
+/// they are not initiated by an account on this chain. The `Inner` hook is
+/// called before any of the checks, without any rules.
+pub struct RequireDidCredential<Credentials, Inner = RestrictSwitchDestinationToSelf>(
+	PhantomData<(Credentials, Inner)>,
+);
+
+impl<R, I, Credentials, Inner> SwitchHooks<R, I> for RequireDidCredential<Credentials, Inner>
+where
+	R: pallet_asset_switch::Config<I>
+		+ pallet_did_lookup::Config<DidIdentifier = <R as pallet_dip_provider::Config>::Identifier>
+		+ uid_core::Config<DidIdentifier = <R as pallet_dip_provider::Config>::Identifier>
+		+ pallet_dip_provider::Config,
+	I: 'static,
+	AccountIdOf<R>: Into<LinkableAccountId>,
+	BalanceOf<R, I>: Into<u128>,
+	Credentials: DidCredentialsProvider<R>,
+	Inner: SwitchHooks<R, I, Error = Error, Rules = ()>,
+{
+	type Error = Error;
+	type Rules = DidCredentialRules;
+
+	fn pre_local_to_remote_switch(
+		remote_asset_id: &VersionedAssetId,
+		rules: Option<&Self::Rules>,
+		from: &AccountIdOf<R>,
+		to: &VersionedLocation,
+		amount: BalanceOf<R, I>,
+	) -> Result<(), Self::Error> {
+		Inner::pre_local_to_remote_switch(remote_asset_id, None, from, to, amount)?;
+
+		let Some(rules) = rules else {
+			return Ok(());
+		};
+		let amount_as_u128: u128 = amount.into();
+		if amount_as_u128 <= rules.threshold {
+			return Ok(());
+		}
+
+		let linked_account: LinkableAccountId = from.clone().into();
+		let did = pallet_did_lookup::ConnectedDids::<R>::get(linked_account)
+			.ok_or(Error::DidNotLinked)?
+			.did;
+		ensure!(uid_core::Pallet::<R>::get_did(&did).is_some(), Error::DidNotLinked);
+
+		let credentials = Credentials::retrieve(&did).map_err(|e| {
+			log::error!(
+				target: DID_CREDENTIAL_LOG_TARGET,
+				"Failed to retrieve credentials for DID {:?} with error {:?}",
+				did,
+				e
+			);
+			Error::Internal
+		})?;
+		let has_valid_credential = credentials
+			.iter()
+			.any(|credential| credential.ctype_hash == rules.ctype_hash && !credential.revoked);
+		ensure!(has_valid_credential, Error::MissingCredential);
+
+		Ok(())
+	}
+
+	fn post_local_to_remote_switch(
+		remote_asset_id: &VersionedAssetId,
+		_rules: Option<&Self::Rules>,
+		from: &AccountIdOf<R>,
+		to: &VersionedLocation,
+		amount: BalanceOf<R, I>,
+	) -> Result<(), Self::Error> {
+		Inner::post_local_to_remote_switch(remote_asset_id, None, from, to, amount)
+	}
+
+	fn pre_remote_to_local_switch(
+		remote_asset_id: &VersionedAssetId,
+		_rules: Option<&Self::Rules>,
+		to: &AccountIdOf<R>,
+		amount: u128,
+	) -> Result<(), Self::Error> {
+		Inner::pre_remote_to_local_switch(remote_asset_id, None, to, amount)
+	}
+
+	fn post_remote_to_local_switch(
+		remote_asset_id: &VersionedAssetId,
+		_rules: Option<&Self::Rules>,
+		to: &AccountIdOf<R>,
+		amount: u128,
+	) -> Result<(), Self::Error> {
+		Inner::post_remote_to_local_switch(remote_asset_id, None, to, amount)
+	}
+}
+
 #[cfg_attr(test, derive(enum_iterator::Sequence))]
 pub enum Error {
 	NotToSelf,
+	DidNotLinked,
+	MissingCredential,
 	Internal,
 }
 
@@ -102,6 +235,8 @@ impl From<Error> for u8 {
 	fn from(value: Error) -> Self {
 		match value {
 			Error::NotToSelf => 0,
+			Error::DidNotLinked => 1,
+			Error::MissingCredential => 2,
 			Error::Internal => Self::MAX,
 		}
 	}
@@ -122,3 +257,195 @@ fn error_value_not_duplicated() {
 		},
 	);
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use frame_support::{storage_alias, Twox64Concat};
+	use kilt_dip_primitives::{CredentialKind, RevealedCredential};
+	use uid_core::{did_details::DidVerificationKey, mock_utils::generate_base_did_details};
+	use xcm::v4::AssetId;
+
+	use crate::{
+		dip::{
+			did::{LinkedDidInfoProviderError, RevealedCredentialOf},
+			mock::{ExtBuilder, TestRuntime, ACCOUNT, DID_IDENTIFIER, SUBMITTER},
+		},
+		AccountId, DidIdentifier,
+	};
+
+	const CTYPE_HASH: H256 = H256::repeat_byte(1);
+	const THRESHOLD: u128 = 1_000;
+
+	#[storage_alias]
+	type MockCredentialsStorage =
+		StorageMap<HookTests, Twox64Concat, DidIdentifier, Vec<RevealedCredentialOf<TestRuntime>>, ValueQuery>;
+
+	struct MockCredentials;
+
+	impl DidCredentialsProvider<TestRuntime> for MockCredentials {
+		fn retrieve(
+			identifier: &DidIdentifier,
+		) -> Result<Vec<RevealedCredentialOf<TestRuntime>>, LinkedDidInfoProviderError> {
+			Ok(MockCredentialsStorage::get(identifier))
+		}
+	}
+
+	type Hook = RequireDidCredential<MockCredentials>;
+
+	fn credential(ctype_hash: H256, revoked: bool) -> RevealedCredentialOf<TestRuntime> {
+		RevealedCredential {
+			kind: CredentialKind::Verification,
+			id: H256::repeat_byte(2),
+			ctype_hash,
+			attester: SUBMITTER,
+			revoked,
+		}
+	}
+
+	fn rules() -> DidCredentialRules {
+		DidCredentialRules {
+			threshold: THRESHOLD,
+			ctype_hash: CTYPE_HASH,
+		}
+	}
+
+	fn ext_with_linked_did() -> sp_io::TestExternalities {
+		ExtBuilder::default()
+			.with_dids(vec![(
+				DID_IDENTIFIER,
+				generate_base_did_details(DidVerificationKey::Account(ACCOUNT), Some(SUBMITTER)),
+				None,
+				vec![ACCOUNT.into()],
+				SUBMITTER,
+			)])
+			.build()
+	}
+
+	fn pre_switch(rules: Option<&DidCredentialRules>, from: AccountId, amount: u128) -> Result<(), u8> {
+		let remote_asset_id = VersionedAssetId::V4(AssetId(Location::here()));
+		let to = VersionedLocation::V4(Location::new(
+			0,
+			[Junction::AccountId32 {
+				network: None,
+				id: from.clone().into(),
+			}],
+		));
+		<Hook as SwitchHooks<TestRuntime, ()>>::pre_local_to_remote_switch(&remote_asset_id, rules, &from, &to, amount)
+			.map_err(u8::from)
+	}
+
+	#[test]
+	fn no_rules_skips_checks() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_eq!(pre_switch(None, ACCOUNT, THRESHOLD + 1), Ok(()));
+		});
+	}
+
+	#[test]
+	fn amount_below_threshold_skips_checks() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_eq!(pre_switch(Some(&rules()), ACCOUNT, THRESHOLD - 1), Ok(()));
+			assert_eq!(pre_switch(Some(&rules()), ACCOUNT, THRESHOLD), Ok(()));
+		});
+	}
+
+	#[test]
+	fn amount_above_threshold_with_valid_credential() {
+		ext_with_linked_did().execute_with(|| {
+			MockCredentialsStorage::insert(
+				DID_IDENTIFIER,
+				vec![credential(H256::repeat_byte(3), false), credential(CTYPE_HASH, false)],
+			);
+
+			assert_eq!(pre_switch(Some(&rules()), ACCOUNT, THRESHOLD + 1), Ok(()));
+		});
+	}
+
+	#[test]
+	fn amount_above_threshold_unlinked_account() {
+		ExtBuilder::default().build().execute_with(|| {
+			MockCredentialsStorage::insert(DID_IDENTIFIER, vec![credential(CTYPE_HASH, false)]);
+
+			assert_eq!(
+				pre_switch(Some(&rules()), ACCOUNT, THRESHOLD + 1),
+				Err(Error::DidNotLinked.into())
+			);
+		});
+	}
+
+	#[test]
+	fn amount_above_threshold_other_account_linked() {
+		ext_with_linked_did().execute_with(|| {
+			MockCredentialsStorage::insert(DID_IDENTIFIER, vec![credential(CTYPE_HASH, false)]);
+
+			assert_eq!(
+				pre_switch(Some(&rules()), AccountId::new([1u8; 32]), THRESHOLD + 1),
+				Err(Error::DidNotLinked.into())
+			);
+		});
+	}
+
+	#[test]
+	fn amount_above_threshold_no_credentials() {
+		ext_with_linked_did().execute_with(|| {
+			assert_eq!(
+				pre_switch(Some(&rules()), ACCOUNT, THRESHOLD + 1),
+				Err(Error::MissingCredential.into())
+			);
+		});
+	}
+
+	#[test]
+	fn amount_above_threshold_wrong_ctype() {
+		ext_with_linked_did().execute_with(|| {
+			MockCredentialsStorage::insert(DID_IDENTIFIER, vec![credential(H256::repeat_byte(3), false)]);
+
+			assert_eq!(
+				pre_switch(Some(&rules()), ACCOUNT, THRESHOLD + 1),
+				Err(Error::MissingCredential.into())
+			);
+		});
+	}
+
+	#[test]
+	fn amount_above_threshold_revoked_credential() {
+		ext_with_linked_did().execute_with(|| {
+			MockCredentialsStorage::insert(DID_IDENTIFIER, vec![credential(CTYPE_HASH, true)]);
+
+			assert_eq!(
+				pre_switch(Some(&rules()), ACCOUNT, THRESHOLD + 1),
+				Err(Error::MissingCredential.into())
+			);
+		});
+	}
+
+	// The destination check is skipped when benchmarking.
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	#[test]
+	fn wrong_destination_is_rejected_before_credential_checks() {
+		ExtBuilder::default().build().execute_with(|| {
+			let remote_asset_id = VersionedAssetId::V4(AssetId(Location::here()));
+			let to = VersionedLocation::V4(Location::new(
+				0,
+				[Junction::AccountId32 {
+					network: None,
+					id: [1u8; 32],
+				}],
+			));
+
+			assert_eq!(
+				<Hook as SwitchHooks<TestRuntime, ()>>::pre_local_to_remote_switch(
+					&remote_asset_id,
+					Some(&rules()),
+					&ACCOUNT,
+					&to,
+					THRESHOLD - 1
+				)
+				.map_err(u8::from),
+				Err(Error::NotToSelf.into())
+			);
+		});
+	}
+}
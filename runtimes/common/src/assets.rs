@@ -16,17 +16,24 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{alloc::string::ToString, Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::RuntimeDebug;
-use sp_std::vec::Vec;
+use sp_runtime::{
+	traits::{Convert, Zero},
+	RuntimeDebug,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
 
 use kilt_asset_dids::AssetDid as AssetIdentifier;
 
+use kilt_runtime_api_asset_dids::{AssetDidComponents, ResolvedAssetDid};
 use kilt_support::traits::ItemFilter;
-use public_credentials::CredentialEntry;
+use public_credentials::{CredentialEntry, CredentialEntryOf, CredentialIdOf};
 
-use crate::{authorization::AuthorizationId, AccountId, Balance, BlockNumber, Hash};
+use crate::{
+	authorization::AuthorizationId, errors::AssetDidApiError, AccountId, Balance, BlockNumber, DidIdentifier, Hash,
+};
 
 #[cfg(feature = "runtime-benchmarks")]
 pub use benchmarks::*;
@@ -63,6 +70,40 @@ impl TryFrom<String> for AssetDid {
 	}
 }
 
+/// The asset namespace of the asset DIDs KILT DIDs are mapped to by
+/// [`DidAsAssetSubject`].
+pub const DID_ASSET_NAMESPACE: &[u8] = b"did";
+
+/// Maps a KILT DID to the asset DID the public credentials of the DID are
+/// issued to, i.e., `did:asset:polkadot:<genesis>.did:<identifier>`, where
+/// `<genesis>` is the HEX-encoded CAIP-13 reference of this chain (the first 16
+/// bytes of its genesis hash) and `<identifier>` is the HEX-encoded DID
+/// identifier.
+pub struct DidAsAssetSubject<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Convert<DidIdentifier, Option<AssetDid>> for DidAsAssetSubject<Runtime>
+where
+	Runtime: frame_system::Config<Hash = Hash>,
+{
+	fn convert(did: DidIdentifier) -> Option<AssetDid> {
+		let genesis_hash = frame_system::Pallet::<Runtime>::block_hash(BlockNumberFor::<Runtime>::zero());
+		let asset_did = [
+			&b"did:asset:polkadot:"[..],
+			hex::encode(&genesis_hash.as_bytes()[..16]).as_bytes(),
+			b".",
+			DID_ASSET_NAMESPACE,
+			b":",
+			hex::encode(did).as_bytes(),
+		]
+		.concat();
+		AssetDid::try_from(asset_did).ok()
+	}
+}
+
+/// A public credential entry as stored by the runtimes using this crate.
+pub type PublicCredentialEntry =
+	CredentialEntry<Hash, DidIdentifier, BlockNumber, AccountId, Balance, AuthorizationId<Hash>>;
+
 /// Filter for public credentials retrieved for a provided subject as specified
 /// in the runtime API interface.
 #[derive(Encode, Decode, TypeInfo)]
@@ -87,6 +128,46 @@ impl ItemFilter<CredentialEntry<Hash, AccountId, BlockNumber, AccountId, Balance
 	}
 }
 
+/// Parse the provided UTF-8 encoded asset DID, and return its canonical form,
+/// its components and all the public credentials issued for it.
+///
+/// The canonical form is the one generated by the `Display` implementation of
+/// the parsed asset DID, e.g., with EVM addresses in lowercase.
+pub fn resolve_asset_did<Runtime>(
+	input: Vec<u8>,
+) -> Result<ResolvedAssetDid<CredentialIdOf<Runtime>, CredentialEntryOf<Runtime>>, AssetDidApiError>
+where
+	Runtime: public_credentials::Config<SubjectId = AssetDid>,
+{
+	let asset_did = AssetIdentifier::from_utf8_encoded(input)?;
+	let components = asset_did_components(&asset_did);
+	let did = asset_did.to_string().into_bytes();
+	let credentials = public_credentials::Credentials::<Runtime>::iter_prefix(AssetDid(asset_did)).collect();
+
+	Ok(ResolvedAssetDid {
+		did,
+		components,
+		credentials,
+	})
+}
+
+/// Split the canonical forms of the chain ID and of the asset ID of the
+/// provided asset DID into their namespace, reference and optional identifier.
+fn asset_did_components(asset_did: &AssetIdentifier) -> AssetDidComponents {
+	let chain_id = asset_did.chain_id.to_string();
+	let asset_id = asset_did.asset_id.to_string();
+	let mut chain_parts = chain_id.splitn(2, ':').map(|part| part.as_bytes().to_vec());
+	let mut asset_parts = asset_id.splitn(3, ':').map(|part| part.as_bytes().to_vec());
+
+	AssetDidComponents {
+		chain_namespace: chain_parts.next().unwrap_or_default(),
+		chain_reference: chain_parts.next().unwrap_or_default(),
+		asset_namespace: asset_parts.next().unwrap_or_default(),
+		asset_reference: asset_parts.next().unwrap_or_default(),
+		asset_identifier: asset_parts.next(),
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks {
 	use super::*;
@@ -132,3 +213,73 @@ mod benchmarks {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn asset_did_components_fungible() {
+		let asset_did = AssetIdentifier::from_utf8_encoded(
+			b"did:asset:eip155:1.erc20:0x6B175474E89094C44Da98b954EedeAC495271d0F".to_vec(),
+		)
+		.unwrap();
+
+		assert_eq!(
+			asset_did_components(&asset_did),
+			AssetDidComponents {
+				chain_namespace: b"eip155".to_vec(),
+				chain_reference: b"1".to_vec(),
+				asset_namespace: b"erc20".to_vec(),
+				asset_reference: b"0x6b175474e89094c44da98b954eedeac495271d0f".to_vec(),
+				asset_identifier: None,
+			}
+		);
+	}
+
+	#[test]
+	fn asset_did_components_non_fungible() {
+		let asset_did = AssetIdentifier::from_utf8_encoded(
+			b"did:asset:eip155:1.erc721:0x06012c8cf97BEaD5deAe237070F9587f8E7A266d:771769".to_vec(),
+		)
+		.unwrap();
+
+		assert_eq!(
+			asset_did_components(&asset_did),
+			AssetDidComponents {
+				chain_namespace: b"eip155".to_vec(),
+				chain_reference: b"1".to_vec(),
+				asset_namespace: b"erc721".to_vec(),
+				asset_reference: b"0x06012c8cf97bead5deae237070f9587f8e7a266d".to_vec(),
+				asset_identifier: Some(b"771769".to_vec()),
+			}
+		);
+	}
+
+	#[test]
+	fn did_as_asset_subject() {
+		use crate::dip::mock::TestRuntime;
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			frame_system::BlockHash::<TestRuntime>::insert(0, Hash::repeat_byte(1));
+
+			let asset_did = DidAsAssetSubject::<TestRuntime>::convert(DidIdentifier::new([2u8; 32]))
+				.expect("Conversion of a DID to an asset DID should not fail.");
+
+			assert_eq!(
+				asset_did.to_string(),
+				"did:asset:polkadot:01010101010101010101010101010101.did:0202020202020202020202020202020202020202020202020202020202020202"
+			);
+			assert_eq!(
+				asset_did_components(&asset_did),
+				AssetDidComponents {
+					chain_namespace: b"polkadot".to_vec(),
+					chain_reference: b"01010101010101010101010101010101".to_vec(),
+					asset_namespace: DID_ASSET_NAMESPACE.to_vec(),
+					asset_reference: b"0202020202020202020202020202020202020202020202020202020202020202".to_vec(),
+					asset_identifier: None,
+				}
+			);
+		});
+	}
+}
@@ -174,15 +174,25 @@ pub mod deposit_storage {
 	// IdentityCommitmentVersion) which is 32 + 2 = 34 bytes. Adding the
 	// discriminant byte, it totals to 35 bytes.
 	pub const MAX_DEPOSIT_PALLET_KEY_LENGTH: u32 = 35;
+	// Final storage keys are made of a 32-byte prefix, followed by the
+	// `Blake2_128Concat`-hashed keys of the entry, the longest of which are
+	// the text records of web3names.
+	pub const MAX_INDEXED_DEPOSIT_KEY_LENGTH: u32 = 256;
 }
 
 pub mod dip_provider {
 	use super::*;
 
 	pub const MAX_LINKED_ACCOUNTS: u32 = 10;
+	// Maximum number of public credentials and verifications bound to a DID that
+	// are read for a single DID subject.
+	pub const MAX_CREDENTIALS: u32 = 20;
 	// Commitment are 32-byte hashes.
 	pub const MAX_COMMITMENT_BYTE_LENGTH: u32 = 32;
 	pub const COMMITMENT_DEPOSIT: Balance = deposit(1, MAX_COMMITMENT_BYTE_LENGTH);
+	// One for each commitment version generated by the `DidMerkleRootGenerator`.
+	pub const MAX_COMMITMENT_VERSIONS: u32 = 3;
+	pub const MAX_VERSIONS_PER_COMMIT: u32 = 3;
 }
 
 pub mod staking {
@@ -459,15 +469,28 @@ pub mod web3_names {
 
 	pub const MIN_LENGTH: u32 = 3;
 	pub const MAX_LENGTH: u32 = 32;
+	pub const MAX_RESERVED_PATTERNS: u32 = 50;
+	pub const MAX_TEXT_RECORDS: u32 = 16;
+	pub const MAX_TEXT_RECORD_KEY_LENGTH: u32 = 32;
+	pub const MAX_TEXT_RECORD_VALUE_LENGTH: u32 = 256;
 
 	/// The size is checked in the runtime by a test.
 	pub const MAX_NAME_BYTE_LENGTH: u32 = 121;
 	pub const DEPOSIT: Balance = deposit(2, MAX_NAME_BYTE_LENGTH);
 
+	/// The size is checked in the runtime by a test.
+	pub const MAX_TEXT_RECORD_BYTE_LENGTH: u32 = 339;
+	pub const TEXT_RECORD_DEPOSIT: Balance = deposit(1, MAX_TEXT_RECORD_BYTE_LENGTH);
+
 	parameter_types! {
 		pub const Web3NameDeposit: Balance = DEPOSIT;
 		pub const MinNameLength: u32 = MIN_LENGTH;
 		pub const MaxNameLength: u32 = MAX_LENGTH;
+		pub const MaxReservedPatterns: u32 = MAX_RESERVED_PATTERNS;
+		pub const TextRecordDeposit: Balance = TEXT_RECORD_DEPOSIT;
+		pub const MaxTextRecords: u32 = MAX_TEXT_RECORDS;
+		pub const MaxTextRecordKeyLength: u32 = MAX_TEXT_RECORD_KEY_LENGTH;
+		pub const MaxTextRecordValueLength: u32 = MAX_TEXT_RECORD_VALUE_LENGTH;
 	}
 }
 
@@ -490,6 +513,19 @@ pub mod tips {
 	}
 }
 
+pub mod asset_switch {
+	use super::*;
+
+	parameter_types! {
+		/// The number of blocks within which the remote reserve is expected to
+		/// report the outcome of an outgoing switch.
+		pub const SwitchResponseTimeout: BlockNumber = DAYS;
+		/// The maximum number of switch pairs, which bounds the lookup of the
+		/// switch pair for the XCM fee asset of incoming XCM messages.
+		pub const MaxSwitchPairs: u32 = 10;
+	}
+}
+
 pub mod fee {
 	use super::*;
 
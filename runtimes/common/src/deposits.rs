@@ -0,0 +1,214 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+//! Logic for listing the deposits held by an account across all the pallets
+//! that take deposits, backed by the deposit index of the
+//! [`pallet_deposit_storage::Pallet`] pallet.
+
+use frame_support::{
+	storage::{unhashed, with_transaction, StoragePrefixedMap},
+	traits::{Get, PalletInfo},
+};
+use frame_system::RawOrigin;
+use kilt_runtime_api_deposits::DepositInfo;
+use parity_scale_codec::Decode;
+use sp_runtime::{DispatchError, DispatchResult, TransactionOutcome};
+use sp_std::vec::Vec;
+
+/// Reclaims the deposit of a storage entry on behalf of the provided account,
+/// given the part of the entry's final storage key following the storage
+/// item prefix.
+type ReclaimFn<AccountId> = fn(&AccountId, &[u8]) -> DispatchResult;
+
+/// A storage item whose entries are backed by a deposit.
+struct DepositStorageItem<AccountId> {
+	/// The final prefix of the storage item.
+	prefix: [u8; 32],
+	/// The name of the pallet the storage item belongs to.
+	pallet: &'static str,
+	/// The extrinsic the deposit owner can reclaim the deposits of the storage
+	/// item entries with, if any.
+	reclaim: Option<ReclaimFn<AccountId>>,
+}
+
+impl<AccountId> DepositStorageItem<AccountId> {
+	fn new<Runtime, Pallet>(prefix: [u8; 32], reclaim: Option<ReclaimFn<AccountId>>) -> Self
+	where
+		Runtime: frame_system::Config,
+		Pallet: 'static,
+	{
+		Self {
+			prefix,
+			pallet: <Runtime as frame_system::Config>::PalletInfo::name::<Pallet>().unwrap_or_default(),
+			reclaim,
+		}
+	}
+
+	/// Whether `owner` can reclaim the deposit of the storage entry behind
+	/// `key` at the current block.
+	///
+	/// The reclaim extrinsic is dry-run in a storage transaction that is always
+	/// rolled back, so that all the conditions checked by the extrinsic, e.g.,
+	/// the size of a delegation subtree or the deposit hooks, are taken into
+	/// account.
+	fn is_reclaimable(&self, owner: &AccountId, key: &[u8]) -> bool {
+		let (Some(reclaim), Some(map_key)) = (self.reclaim, key.get(self.prefix.len()..)) else {
+			return false;
+		};
+		with_transaction(|| TransactionOutcome::Rollback(Ok::<_, DispatchError>(reclaim(owner, map_key).is_ok())))
+			.unwrap_or(false)
+	}
+}
+
+/// Decode a map key hashed with `Blake2_128Concat`, advancing the input past
+/// it.
+fn blake2_128_concat_key<Key: Decode>(input: &mut &[u8]) -> Result<Key, DispatchError> {
+	decode_concat_key(input, 16)
+}
+
+/// Decode a map key hashed with `Twox64Concat`, advancing the input past it.
+fn twox_64_concat_key<Key: Decode>(input: &mut &[u8]) -> Result<Key, DispatchError> {
+	decode_concat_key(input, 8)
+}
+
+fn decode_concat_key<Key: Decode>(input: &mut &[u8], hash_length: usize) -> Result<Key, DispatchError> {
+	*input = input
+		.get(hash_length..)
+		.ok_or(DispatchError::Other("Storage key too short."))?;
+	Key::decode(input).map_err(|_| DispatchError::Other("Failed to decode storage key."))
+}
+
+/// Return all the deposits held by the provided account, as recorded in the
+/// deposit index.
+///
+/// Index entries whose storage entry does not exist anymore are skipped.
+/// Deposits taken before the deposit index was introduced are only listed
+/// once they are indexed via the `index_deposits` extrinsic of the migration
+/// pallet, or are updated or change owner.
+///
+/// Whether a deposit is reclaimable is computed by dry-running the reclaim
+/// extrinsic of the pallet holding it, so this function is meant to be called
+/// off-chain only, e.g., by runtime APIs.
+pub fn deposits_for_owner<Runtime>(
+	owner: &<Runtime as frame_system::Config>::AccountId,
+) -> Vec<DepositInfo<<Runtime as frame_system::Config>::AccountId, pallet_deposit_storage::BalanceOf<Runtime>>>
+where
+	Runtime: uid_core::Config
+		+ delegation::Config
+		+ verification::Config
+		+ public_credentials::Config
+		+ pallet_did_lookup::Config
+		+ pallet_web3_names::Config
+		+ pallet_deposit_storage::Config,
+{
+	let items: [DepositStorageItem<<Runtime as frame_system::Config>::AccountId>; 8] = [
+		DepositStorageItem::new::<Runtime, uid_core::Pallet<Runtime>>(
+			uid_core::Did::<Runtime>::final_prefix(),
+			Some(|owner, mut key| {
+				let did = blake2_128_concat_key::<uid_core::DidIdentifierOf<Runtime>>(&mut key)?;
+				let endpoints_to_remove = uid_core::DidEndpointsCount::<Runtime>::get(&did);
+				uid_core::Pallet::<Runtime>::reclaim_deposit(
+					RawOrigin::Signed(owner.clone()).into(),
+					did,
+					endpoints_to_remove,
+				)
+			}),
+		),
+		DepositStorageItem::new::<Runtime, delegation::Pallet<Runtime>>(
+			delegation::DelegationNodes::<Runtime>::final_prefix(),
+			Some(|owner, mut key| {
+				let delegation_id = blake2_128_concat_key::<delegation::DelegationNodeIdOf<Runtime>>(&mut key)?;
+				delegation::Pallet::<Runtime>::reclaim_deposit(
+					RawOrigin::Signed(owner.clone()).into(),
+					delegation_id,
+					<Runtime as delegation::Config>::MaxRemovals::get(),
+				)
+				.map(|_| ())
+				.map_err(|e| e.error)
+			}),
+		),
+		DepositStorageItem::new::<Runtime, verification::Pallet<Runtime>>(
+			verification::Verifications::<Runtime>::final_prefix(),
+			Some(|owner, mut key| {
+				let claim_hash = blake2_128_concat_key::<verification::ClaimHashOf<Runtime>>(&mut key)?;
+				verification::Pallet::<Runtime>::reclaim_deposit(RawOrigin::Signed(owner.clone()).into(), claim_hash)
+			}),
+		),
+		DepositStorageItem::new::<Runtime, public_credentials::Pallet<Runtime>>(
+			public_credentials::Credentials::<Runtime>::final_prefix(),
+			Some(|owner, mut key| {
+				twox_64_concat_key::<public_credentials::SubjectIdOf<Runtime>>(&mut key)?;
+				let credential_id = blake2_128_concat_key::<public_credentials::CredentialIdOf<Runtime>>(&mut key)?;
+				public_credentials::Pallet::<Runtime>::reclaim_deposit(
+					RawOrigin::Signed(owner.clone()).into(),
+					credential_id,
+				)
+			}),
+		),
+		DepositStorageItem::new::<Runtime, pallet_did_lookup::Pallet<Runtime>>(
+			pallet_did_lookup::ConnectedDids::<Runtime>::final_prefix(),
+			Some(|owner, mut key| {
+				let account =
+					blake2_128_concat_key::<pallet_did_lookup::linkable_account::LinkableAccountId>(&mut key)?;
+				pallet_did_lookup::Pallet::<Runtime>::reclaim_deposit(RawOrigin::Signed(owner.clone()).into(), account)
+			}),
+		),
+		DepositStorageItem::new::<Runtime, pallet_web3_names::Pallet<Runtime>>(
+			pallet_web3_names::Owner::<Runtime>::final_prefix(),
+			Some(|owner, mut key| {
+				// Names are stored as their byte representation, hence they can be decoded as
+				// the name input of the reclaim extrinsic.
+				let name = blake2_128_concat_key::<pallet_web3_names::Web3NameInput<Runtime>>(&mut key)?;
+				pallet_web3_names::Pallet::<Runtime>::reclaim_deposit(RawOrigin::Signed(owner.clone()).into(), name)
+			}),
+		),
+		// Text record deposits are only released when the name owner removes the
+		// record or the name is released.
+		DepositStorageItem::new::<Runtime, pallet_web3_names::Pallet<Runtime>>(
+			pallet_web3_names::TextRecords::<Runtime>::final_prefix(),
+			None,
+		),
+		DepositStorageItem::new::<Runtime, pallet_deposit_storage::Pallet<Runtime>>(
+			pallet_deposit_storage::Deposits::<Runtime>::final_prefix(),
+			Some(|owner, mut key| {
+				let namespace =
+					blake2_128_concat_key::<<Runtime as pallet_deposit_storage::Config>::Namespace>(&mut key)?;
+				let deposit_key = blake2_128_concat_key::<pallet_deposit_storage::DepositKeyOf<Runtime>>(&mut key)?;
+				pallet_deposit_storage::Pallet::<Runtime>::reclaim_deposit(
+					RawOrigin::Signed(owner.clone()).into(),
+					namespace,
+					deposit_key,
+				)
+			}),
+		),
+	];
+
+	pallet_deposit_storage::Pallet::<Runtime>::indexed_deposits_for_owner(owner)
+		.into_iter()
+		.filter(|(key, _)| unhashed::exists(key))
+		.map(|(key, amount)| {
+			let item = items.iter().find(|item| key.starts_with(&item.prefix));
+			DepositInfo {
+				pallet: item.map(|i| i.pallet.as_bytes().to_vec()).unwrap_or_default(),
+				reclaimable: item.map(|i| i.is_reclaimable(owner, &key)).unwrap_or(false),
+				key,
+				owner: owner.clone(),
+				amount,
+			}
+		})
+		.collect()
+}
@@ -23,7 +23,10 @@ use sp_core::{ConstU128, ConstU32};
 use sp_runtime::traits::IdentityLookup;
 
 use crate::{
-	constants::{deposit_storage::MAX_DEPOSIT_PALLET_KEY_LENGTH, KILT},
+	constants::{
+		deposit_storage::{MAX_DEPOSIT_PALLET_KEY_LENGTH, MAX_INDEXED_DEPOSIT_KEY_LENGTH},
+		KILT,
+	},
 	dip::deposit::{DepositHooks, DepositNamespace},
 	AccountId, Balance, BlockHashCount, BlockLength, BlockWeights, Hash, Hasher, Nonce,
 };
@@ -84,11 +87,16 @@ impl pallet_balances::Config for TestRuntime {
 }
 
 impl pallet_dip_provider::Config for TestRuntime {
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHooks = ();
 	type CommitOrigin = AccountId;
 	type CommitOriginCheck = EnsureSigned<AccountId>;
 	type Identifier = AccountId;
 	type IdentityCommitmentGenerator = DefaultIdentityCommitmentGenerator<u32>;
 	type IdentityProvider = DefaultIdentityProvider<u32>;
+	type MaxCredentials = ConstU32<0>;
+	type MaxCommitmentVersions = ConstU32<3>;
+	type MaxVersionsPerCommit = ConstU32<3>;
 	type ProviderHooks = ();
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
@@ -97,10 +105,13 @@ impl pallet_dip_provider::Config for TestRuntime {
 impl pallet_deposit_storage::Config for TestRuntime {
 	type CheckOrigin = EnsureSigned<Self::AccountId>;
 	type Currency = Balances;
+	type DepositAmounts = ();
 	type DepositHooks = DepositHooks;
+	type DepositLifetime = ();
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type MaxKeyLength = ConstU32<MAX_DEPOSIT_PALLET_KEY_LENGTH>;
+	type MaxIndexedKeyLength = ConstU32<MAX_INDEXED_DEPOSIT_KEY_LENGTH>;
 	type Namespace = DepositNamespace;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHooks = ();
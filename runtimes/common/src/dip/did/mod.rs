@@ -19,14 +19,15 @@
 use uid_core::did_details::DidDetails;
 use frame_support::ensure;
 use frame_system::pallet_prelude::BlockNumberFor;
-use kilt_dip_primitives::RevealedWeb3Name;
+use kilt_dip_primitives::{CredentialKind, RevealedCredential, RevealedWeb3Name};
 use pallet_did_lookup::linkable_account::LinkableAccountId;
 use pallet_dip_provider::traits::IdentityProvider;
 use parity_scale_codec::{Decode, Encode};
+use public_credentials::{CredentialIdOf, SubjectIdOf};
 use scale_info::TypeInfo;
-use sp_core::ConstU32;
-use sp_runtime::{BoundedVec, SaturatedConversion};
-use sp_std::{fmt::Debug, vec::Vec};
+use sp_core::{ConstU32, H256};
+use sp_runtime::{traits::Convert, BoundedVec, SaturatedConversion};
+use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
 
 #[cfg(feature = "runtime-benchmarks")]
 use kilt_support::{benchmark::IdentityContext, traits::GetWorstCase};
@@ -39,6 +40,7 @@ pub enum LinkedDidInfoProviderError {
 	DidNotFound,
 	DidDeleted,
 	TooManyLinkedAccounts,
+	TooManyCredentials,
 	Internal,
 }
 
@@ -52,6 +54,7 @@ impl From<LinkedDidInfoProviderError> for u16 {
 			LinkedDidInfoProviderError::DidNotFound => 1,
 			LinkedDidInfoProviderError::DidDeleted => 2,
 			LinkedDidInfoProviderError::TooManyLinkedAccounts => 3,
+			LinkedDidInfoProviderError::TooManyCredentials => 4,
 			LinkedDidInfoProviderError::Internal => u16::MAX,
 		}
 	}
@@ -60,10 +63,12 @@ impl From<LinkedDidInfoProviderError> for u16 {
 pub type Web3OwnershipOf<Runtime> =
 	RevealedWeb3Name<<Runtime as pallet_web3_names::Config>::Web3Name, BlockNumberFor<Runtime>>;
 
+pub type RevealedCredentialOf<Runtime> = RevealedCredential<<Runtime as frame_system::Config>::AccountId>;
+
 /// Identity information related to a KILT DID relevant for cross-chain
 /// transactions via the DIP protocol.
 #[derive(Debug, Clone, PartialEq)]
-pub struct LinkedDidInfoOf<Runtime, const MAX_LINKED_ACCOUNTS: u32>
+pub struct LinkedDidInfoOf<Runtime, const MAX_LINKED_ACCOUNTS: u32, const MAX_CREDENTIALS: u32 = 0>
 where
 	Runtime: uid_core::Config + pallet_web3_names::Config,
 {
@@ -74,24 +79,159 @@ where
 	/// The list of accounts the subject has previously linked via the linking
 	/// pallet.
 	pub linked_accounts: BoundedVec<LinkableAccountId, ConstU32<MAX_LINKED_ACCOUNTS>>,
+	/// The list of public credentials and verifications bound to the subject.
+	/// They are only committed to starting from version `1` of the DIP
+	/// commitment.
+	pub credentials: BoundedVec<RevealedCredentialOf<Runtime>, ConstU32<MAX_CREDENTIALS>>,
+}
+
+/// Trait to retrieve the public credentials and verifications bound to a DID
+/// subject.
+pub trait DidCredentialsProvider<Runtime>
+where
+	Runtime: pallet_dip_provider::Config,
+{
+	fn retrieve(
+		identifier: &Runtime::Identifier,
+	) -> Result<Vec<RevealedCredentialOf<Runtime>>, LinkedDidInfoProviderError>;
+}
+
+/// Binds no credentials to any DID subject.
+impl<Runtime> DidCredentialsProvider<Runtime> for ()
+where
+	Runtime: pallet_dip_provider::Config,
+{
+	fn retrieve(
+		_identifier: &Runtime::Identifier,
+	) -> Result<Vec<RevealedCredentialOf<Runtime>>, LinkedDidInfoProviderError> {
+		Ok(Vec::new())
+	}
+}
+
+/// Retrieves the verifications bound to a DID subject by their attesters via
+/// the verification pallet, and the public credentials issued to the subject
+/// ID the DID maps to according to `SubjectIdOfDid`.
+///
+/// Verifications are returned before public credentials. If more than
+/// `MAX_CREDENTIALS` credentials are bound to the DID, an error is returned
+/// without reading the remaining ones.
+pub struct DidCredentials<SubjectIdOfDid, const MAX_CREDENTIALS: u32>(PhantomData<SubjectIdOfDid>);
+
+impl<Runtime, SubjectIdOfDid, const MAX_CREDENTIALS: u32> DidCredentialsProvider<Runtime>
+	for DidCredentials<SubjectIdOfDid, MAX_CREDENTIALS>
+where
+	Runtime: pallet_dip_provider::Config<Identifier = <Runtime as frame_system::Config>::AccountId>
+		+ frame_system::Config<Hash = H256>
+		+ verification::Config<AttesterId = <Runtime as frame_system::Config>::AccountId>
+		+ public_credentials::Config<AttesterId = <Runtime as frame_system::Config>::AccountId>,
+	CredentialIdOf<Runtime>: Into<H256>,
+	SubjectIdOfDid: Convert<Runtime::Identifier, Option<SubjectIdOf<Runtime>>>,
+{
+	fn retrieve(
+		identifier: &Runtime::Identifier,
+	) -> Result<Vec<RevealedCredentialOf<Runtime>>, LinkedDidInfoProviderError> {
+		let verifications =
+			verification::SubjectVerifications::<Runtime>::iter_key_prefix(identifier).filter_map(|claim_hash| {
+				let Some(verification) = verification::Verifications::<Runtime>::get(claim_hash) else {
+					log::error!(
+						target: LOG_TARGET,
+						"Inconsistent verification::SubjectVerifications index. Cannot find verification with claim hash {:#?}",
+						claim_hash
+					);
+					return None;
+				};
+				Some(RevealedCredential {
+					kind: CredentialKind::Verification,
+					id: claim_hash,
+					ctype_hash: verification.ctype_hash,
+					attester: verification.attester,
+					revoked: verification.revoked,
+				})
+			});
+		let public_credentials = SubjectIdOfDid::convert(identifier.clone())
+			.into_iter()
+			.flat_map(|subject_id| public_credentials::Credentials::<Runtime>::iter_prefix(subject_id))
+			.map(|(credential_id, credential)| RevealedCredential {
+				kind: CredentialKind::PublicCredential,
+				id: credential_id.into(),
+				ctype_hash: credential.ctype_hash,
+				attester: credential.attester,
+				revoked: credential.revoked,
+			});
+
+		let max_credentials: usize = MAX_CREDENTIALS.saturated_into();
+		let credentials = verifications
+			.chain(public_credentials)
+			.take(max_credentials.saturating_add(1))
+			.collect::<Vec<_>>();
+		ensure!(
+			credentials.len() <= max_credentials,
+			LinkedDidInfoProviderError::TooManyCredentials
+		);
+
+		Ok(credentials)
+	}
+}
+
+/// Benchmark hooks for the [`pallet_dip_provider::Pallet`]. Credentials are
+/// bound to the DID as verifications, since retrieving a verification takes
+/// more storage reads than retrieving a public credential.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct PalletDipProviderBenchmarkHooks;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<Runtime> pallet_dip_provider::traits::BenchmarkHooks<Runtime> for PalletDipProviderBenchmarkHooks
+where
+	Runtime: pallet_dip_provider::Config<Identifier = <Runtime as frame_system::Config>::AccountId>
+		+ frame_system::Config<Hash = H256>
+		+ verification::Config<AttesterId = <Runtime as frame_system::Config>::AccountId>,
+{
+	fn setup_credentials(identifier: &Runtime::Identifier, count: u32) {
+		use sp_runtime::traits::Zero;
+
+		(0..count).for_each(|index| {
+			let claim_hash = H256::from_low_u64_be(index.into());
+			verification::Verifications::<Runtime>::insert(
+				claim_hash,
+				verification::VerificationDetails {
+					ctype_hash: H256::zero(),
+					attester: identifier.clone(),
+					authorization_id: None,
+					revoked: false,
+					deposit: kilt_support::Deposit {
+						owner: identifier.clone(),
+						amount: Zero::zero(),
+					},
+				},
+			);
+			verification::VerificationSubjects::<Runtime>::insert(claim_hash, identifier);
+			verification::SubjectVerifications::<Runtime>::insert(identifier, claim_hash, ());
+		});
+	}
 }
 
 const LOG_TARGET: &str = "dip::provider::LinkedDidInfoProvider";
 
 /// Type implementing the [`IdentityProvider`] trait which is responsible for
 /// collecting the DID information relevant for DIP cross-chain transactions by
-/// interacting with the different pallets involved.
-pub struct LinkedDidInfoProvider<const MAX_LINKED_ACCOUNTS: u32>;
-
-impl<Runtime, const MAX_LINKED_ACCOUNTS: u32> IdentityProvider<Runtime> for LinkedDidInfoProvider<MAX_LINKED_ACCOUNTS>
+/// interacting with the different pallets involved. The public credentials and
+/// verifications bound to the DID are retrieved via the provided `Credentials`
+/// type.
+pub struct LinkedDidInfoProvider<const MAX_LINKED_ACCOUNTS: u32, const MAX_CREDENTIALS: u32 = 0, Credentials = ()>(
+	PhantomData<Credentials>,
+);
+
+impl<Runtime, const MAX_LINKED_ACCOUNTS: u32, const MAX_CREDENTIALS: u32, Credentials> IdentityProvider<Runtime>
+	for LinkedDidInfoProvider<MAX_LINKED_ACCOUNTS, MAX_CREDENTIALS, Credentials>
 where
 	Runtime: uid_core::Config<DidIdentifier = <Runtime as pallet_dip_provider::Config>::Identifier>
 		+ pallet_web3_names::Config<Web3NameOwner = <Runtime as pallet_dip_provider::Config>::Identifier>
 		+ pallet_did_lookup::Config<DidIdentifier = <Runtime as pallet_dip_provider::Config>::Identifier>
 		+ pallet_dip_provider::Config,
+	Credentials: DidCredentialsProvider<Runtime>,
 {
 	type Error = LinkedDidInfoProviderError;
-	type Success = LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNTS>;
+	type Success = LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNTS, MAX_CREDENTIALS>;
 
 	fn retrieve(identifier: &Runtime::Identifier) -> Result<Self::Success, Self::Error> {
 		ensure!(
@@ -104,10 +244,15 @@ where
 
 		let linked_accounts = retrieve_linked_accounts::<Runtime, MAX_LINKED_ACCOUNTS>(identifier)?;
 
+		let credentials = Credentials::retrieve(identifier)?
+			.try_into()
+			.map_err(|_| LinkedDidInfoProviderError::TooManyCredentials)?;
+
 		Ok(LinkedDidInfoOf {
 			did_details,
 			web3_name_details,
 			linked_accounts,
+			credentials,
 		})
 	}
 }
@@ -167,8 +312,9 @@ where
 }
 
 #[cfg(feature = "runtime-benchmarks")]
-impl<Runtime, const MAX_LINKED_ACCOUNTS: u32> GetWorstCase<IdentityContext<Runtime::Identifier, Runtime::AccountId>>
-	for LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNTS>
+impl<Runtime, const MAX_LINKED_ACCOUNTS: u32, const MAX_CREDENTIALS: u32>
+	GetWorstCase<IdentityContext<Runtime::Identifier, Runtime::AccountId>>
+	for LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNTS, MAX_CREDENTIALS>
 where
 	Runtime: uid_core::Config<DidIdentifier = <Runtime as pallet_dip_provider::Config>::Identifier>
 		+ pallet_web3_names::Config<Web3NameOwner = <Runtime as pallet_dip_provider::Config>::Identifier>
@@ -267,6 +413,8 @@ where
 				.try_into()
 				.expect("BoundedVec creation of linked accounts should not fail."),
 			web3_name_details,
+			// Credentials are bound to the DID by the runtime-specific provider.
+			credentials: BoundedVec::default(),
 		}
 	}
 }
@@ -35,6 +35,7 @@ fn linked_did_info_provider_retrieve_max_capacity() {
 		did_details,
 		web3_name_details,
 		linked_accounts,
+		..
 	} = create_linked_info(auth_key, Some(b"ntn_x2"), MAX_LINKED_ACCOUNTS);
 	let web3_name: Option<pallet_web3_names::web3_name::AsciiWeb3Name<TestRuntime>> =
 		web3_name_details.map(|n| n.web3_name);
@@ -50,7 +51,8 @@ fn linked_did_info_provider_retrieve_max_capacity() {
 		.build()
 		.execute_with(|| {
 			let identity: LinkedDidInfoOf<TestRuntime, MAX_LINKED_ACCOUNTS> =
-				LinkedDidInfoProvider::retrieve(&DID_IDENTIFIER).expect("Should not fail to fetch identity details.");
+				LinkedDidInfoProvider::<MAX_LINKED_ACCOUNTS>::retrieve(&DID_IDENTIFIER)
+					.expect("Should not fail to fetch identity details.");
 			assert_eq!(identity.did_details, did_details);
 			assert_eq!(
 				identity.web3_name_details,
@@ -75,7 +77,8 @@ fn linked_did_info_provider_retrieve_only_did_details() {
 		.build()
 		.execute_with(|| {
 			let identity: LinkedDidInfoOf<TestRuntime, MAX_LINKED_ACCOUNTS> =
-				LinkedDidInfoProvider::retrieve(&DID_IDENTIFIER).expect("Should not fail to fetch identity details.");
+				LinkedDidInfoProvider::<MAX_LINKED_ACCOUNTS>::retrieve(&DID_IDENTIFIER)
+					.expect("Should not fail to fetch identity details.");
 			assert_eq!(identity.did_details, did_details);
 			assert_eq!(identity.linked_accounts, vec![]);
 			assert!(identity.web3_name_details.is_none())
@@ -89,7 +92,7 @@ fn linked_did_info_provider_retrieve_delete_did() {
 		.build()
 		.execute_with(|| {
 			assert_noop!(
-				LinkedDidInfoProvider::retrieve(&DID_IDENTIFIER)
+				LinkedDidInfoProvider::<MAX_LINKED_ACCOUNTS>::retrieve(&DID_IDENTIFIER)
 					as Result<LinkedDidInfoOf<TestRuntime, MAX_LINKED_ACCOUNTS>, _>,
 				LinkedDidInfoProviderError::DidDeleted
 			);
@@ -100,7 +103,7 @@ fn linked_did_info_provider_retrieve_delete_did() {
 fn linked_did_info_provider_retrieve_did_not_found() {
 	ExtBuilder::default().build().execute_with(|| {
 		assert_noop!(
-			LinkedDidInfoProvider::retrieve(&DID_IDENTIFIER)
+			LinkedDidInfoProvider::<MAX_LINKED_ACCOUNTS>::retrieve(&DID_IDENTIFIER)
 				as Result<LinkedDidInfoOf<TestRuntime, MAX_LINKED_ACCOUNTS>, _>,
 			LinkedDidInfoProviderError::DidNotFound
 		);
@@ -115,6 +118,7 @@ fn linked_did_info_provider_retrieve_too_many_linked_accounts() {
 		did_details,
 		web3_name_details,
 		linked_accounts,
+		..
 	} = create_linked_info(auth_key, Some(b"ntn_x2"), MAX_LINKED_ACCOUNTS + 1);
 	let web3_name = web3_name_details.map(|n| n.web3_name);
 
@@ -129,7 +133,7 @@ fn linked_did_info_provider_retrieve_too_many_linked_accounts() {
 		.build()
 		.execute_with(|| {
 			assert_noop!(
-				LinkedDidInfoProvider::retrieve(&DID_IDENTIFIER)
+				LinkedDidInfoProvider::<MAX_LINKED_ACCOUNTS>::retrieve(&DID_IDENTIFIER)
 					as Result<LinkedDidInfoOf<TestRuntime, MAX_LINKED_ACCOUNTS>, _>,
 				LinkedDidInfoProviderError::TooManyLinkedAccounts
 			);
@@ -18,7 +18,7 @@
 
 use uid_core::KeyIdOf;
 use frame_system::pallet_prelude::BlockNumberFor;
-use kilt_dip_primitives::DidMerkleProof;
+use kilt_dip_primitives::{DidMerkleProof, RevealedDidMerkleProofLeaf, SaltedProof};
 use pallet_did_lookup::linkable_account::LinkableAccountId;
 use pallet_dip_provider::{
 	traits::{IdentityCommitmentGenerator, IdentityProvider},
@@ -26,12 +26,17 @@ use pallet_dip_provider::{
 };
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
+use sp_core::H256;
 use sp_runtime::RuntimeDebug;
 use sp_std::marker::PhantomData;
 
 use crate::dip::did::LinkedDidInfoOf;
 
 pub mod v0;
+pub mod v1;
+pub mod v2;
+
+pub use kilt_dip_primitives::CredentialKind;
 
 #[cfg(test)]
 mod tests;
@@ -46,6 +51,20 @@ pub type DidMerkleProofOf<T> = DidMerkleProof<
 	LinkableAccountId,
 >;
 
+/// Type of the Merkle proof revealing parts of the salted DIP identity of a
+/// given DID subject, together with the salts of the revealed leaves.
+pub type SaltedDidMerkleProofOf<T> = SaltedProof<DidMerkleProofOf<T>>;
+
+/// Type of a leaf of the Merkle proof revealing parts of the DIP identity of a
+/// given DID subject.
+pub type DidMerkleProofLeafOf<T> = RevealedDidMerkleProofLeaf<
+	KeyIdOf<T>,
+	<T as frame_system::Config>::AccountId,
+	BlockNumberFor<T>,
+	<T as pallet_web3_names::Config>::Web3Name,
+	LinkableAccountId,
+>;
+
 /// Type of a complete DIP Merkle proof.
 #[derive(Encode, Decode, RuntimeDebug, PartialEq, Eq, TypeInfo)]
 pub struct CompleteMerkleProof<Root, Proof> {
@@ -63,6 +82,7 @@ pub enum DidMerkleProofError {
 	LinkedAccountNotFound,
 	Web3NameNotFound,
 	TooManyLeaves,
+	CredentialNotFound,
 	Internal,
 }
 
@@ -78,6 +98,7 @@ impl From<DidMerkleProofError> for u16 {
 			DidMerkleProofError::LinkedAccountNotFound => 3,
 			DidMerkleProofError::Web3NameNotFound => 4,
 			DidMerkleProofError::TooManyLeaves => 5,
+			DidMerkleProofError::CredentialNotFound => 6,
 			DidMerkleProofError::Internal => u16::MAX,
 		}
 	}
@@ -88,10 +109,12 @@ impl From<DidMerkleProofError> for u16 {
 /// provided in the [README.md](./README.md),
 pub struct DidMerkleRootGenerator<T>(PhantomData<T>);
 
-impl<Runtime, const MAX_LINKED_ACCOUNT: u32> IdentityCommitmentGenerator<Runtime> for DidMerkleRootGenerator<Runtime>
+impl<Runtime, const MAX_LINKED_ACCOUNT: u32, const MAX_CREDENTIALS: u32> IdentityCommitmentGenerator<Runtime>
+	for DidMerkleRootGenerator<Runtime>
 where
 	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config + pallet_dip_provider::Config,
-	Runtime::IdentityProvider: IdentityProvider<Runtime, Success = LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>>,
+	Runtime::IdentityProvider:
+		IdentityProvider<Runtime, Success = LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>>,
 {
 	type Error = DidMerkleProofError;
 	type Output = Runtime::Hash;
@@ -102,7 +125,9 @@ where
 		version: IdentityCommitmentVersion,
 	) -> Result<Self::Output, Self::Error> {
 		match version {
-			0 => v0::generate_commitment::<Runtime, MAX_LINKED_ACCOUNT>(identity),
+			0 => v0::generate_commitment::<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>(identity),
+			1 => v1::generate_commitment::<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>(identity),
+			2 => v2::generate_commitment::<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>(identity),
 			_ => Err(DidMerkleProofError::UnsupportedVersion),
 		}
 	}
@@ -112,19 +137,49 @@ impl<Runtime> DidMerkleRootGenerator<Runtime>
 where
 	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
 {
-	pub fn generate_proof<'a, K, A, const MAX_LINKED_ACCOUNT: u32>(
-		identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>,
+	pub fn generate_proof<'a, K, A, C, const MAX_LINKED_ACCOUNT: u32, const MAX_CREDENTIALS: u32>(
+		identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>,
 		version: IdentityCommitmentVersion,
 		key_ids: K,
 		should_include_web3_name: bool,
 		account_ids: A,
+		mut credential_ids: C,
 	) -> Result<CompleteMerkleProof<Runtime::Hash, DidMerkleProofOf<Runtime>>, DidMerkleProofError>
 	where
 		K: Iterator<Item = &'a KeyIdOf<Runtime>>,
 		A: Iterator<Item = &'a LinkableAccountId>,
+		C: Iterator<Item = &'a (CredentialKind, H256)>,
+	{
+		match version {
+			0 => {
+				// Version 0 commitments do not include any credentials.
+				if credential_ids.next().is_some() {
+					return Err(DidMerkleProofError::CredentialNotFound);
+				}
+				v0::generate_proof(identity, key_ids, should_include_web3_name, account_ids)
+			}
+			1 => v1::generate_proof(identity, key_ids, should_include_web3_name, account_ids, credential_ids),
+			// Version 2 proofs also include the leaf salts, and are generated with
+			// `generate_salted_proof`.
+			_ => Err(DidMerkleProofError::UnsupportedVersion),
+		}
+	}
+
+	pub fn generate_salted_proof<'a, K, A, C, const MAX_LINKED_ACCOUNT: u32, const MAX_CREDENTIALS: u32>(
+		identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>,
+		version: IdentityCommitmentVersion,
+		key_ids: K,
+		should_include_web3_name: bool,
+		account_ids: A,
+		credential_ids: C,
+	) -> Result<CompleteMerkleProof<Runtime::Hash, SaltedDidMerkleProofOf<Runtime>>, DidMerkleProofError>
+	where
+		K: Iterator<Item = &'a KeyIdOf<Runtime>>,
+		A: Iterator<Item = &'a LinkableAccountId>,
+		C: Iterator<Item = &'a (CredentialKind, H256)>,
 	{
 		match version {
-			0 => v0::generate_proof(identity, key_ids, should_include_web3_name, account_ids),
+			2 => v2::generate_proof(identity, key_ids, should_include_web3_name, account_ids, credential_ids),
 			_ => Err(DidMerkleProofError::UnsupportedVersion),
 		}
 	}
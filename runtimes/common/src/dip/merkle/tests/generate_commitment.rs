@@ -36,7 +36,22 @@ fn generate_commitment_unsupported_version() {
 		MAX_LINKED_ACCOUNTS,
 	);
 	assert_err!(
-		DidMerkleRootGenerator::<TestRuntime>::generate_commitment(&DID_IDENTIFIER, &linked_info, 1,),
+		DidMerkleRootGenerator::<TestRuntime>::generate_commitment(&DID_IDENTIFIER, &linked_info, 3,),
 		DidMerkleProofError::UnsupportedVersion
 	);
 }
+
+#[test]
+fn generate_commitment_v2() {
+	let linked_info = create_linked_info(
+		DidVerificationKey::Account(ACCOUNT),
+		Some(b"ntn_x2"),
+		MAX_LINKED_ACCOUNTS,
+	);
+	let v1_commitment =
+		DidMerkleRootGenerator::<TestRuntime>::generate_commitment(&DID_IDENTIFIER, &linked_info, 1).unwrap();
+	let v2_commitment =
+		DidMerkleRootGenerator::<TestRuntime>::generate_commitment(&DID_IDENTIFIER, &linked_info, 2).unwrap();
+	// Salted leaves result in a different commitment than version 1.
+	assert_ne!(v1_commitment, v2_commitment);
+}
@@ -18,6 +18,8 @@
 
 use uid_core::did_details::DidVerificationKey;
 use frame_support::assert_err;
+use kilt_dip_primitives::CredentialKind;
+use sp_core::H256;
 
 use crate::{
 	constants::dip_provider::MAX_LINKED_ACCOUNTS,
@@ -35,7 +37,77 @@ fn generate_proof_unsupported_version() {
 		MAX_LINKED_ACCOUNTS,
 	);
 	assert_err!(
-		DidMerkleRootGenerator::<TestRuntime>::generate_proof(&linked_info, 1, [].into_iter(), false, [].into_iter()),
+		DidMerkleRootGenerator::<TestRuntime>::generate_proof(
+			&linked_info,
+			3,
+			[].into_iter(),
+			false,
+			[].into_iter(),
+			[].into_iter()
+		),
+		DidMerkleProofError::UnsupportedVersion
+	);
+}
+
+#[test]
+fn generate_proof_v0_with_credentials() {
+	let linked_info = create_linked_info(
+		DidVerificationKey::Account(ACCOUNT),
+		Some(b"ntn_x2"),
+		MAX_LINKED_ACCOUNTS,
+	);
+	// Version 0 commitments do not include credentials.
+	assert_err!(
+		DidMerkleRootGenerator::<TestRuntime>::generate_proof(
+			&linked_info,
+			0,
+			[].into_iter(),
+			false,
+			[].into_iter(),
+			[(CredentialKind::Verification, H256::default())].iter()
+		),
+		DidMerkleProofError::CredentialNotFound
+	);
+}
+
+#[test]
+fn generate_proof_v2_unsalted() {
+	let linked_info = create_linked_info(
+		DidVerificationKey::Account(ACCOUNT),
+		Some(b"ntn_x2"),
+		MAX_LINKED_ACCOUNTS,
+	);
+	// Version 2 proofs must include the leaf salts.
+	assert_err!(
+		DidMerkleRootGenerator::<TestRuntime>::generate_proof(
+			&linked_info,
+			2,
+			[].into_iter(),
+			false,
+			[].into_iter(),
+			[].into_iter()
+		),
+		DidMerkleProofError::UnsupportedVersion
+	);
+}
+
+#[test]
+fn generate_salted_proof_unsupported_version() {
+	let linked_info = create_linked_info(
+		DidVerificationKey::Account(ACCOUNT),
+		Some(b"ntn_x2"),
+		MAX_LINKED_ACCOUNTS,
+	);
+	// Only version 2 commitments are salted.
+	assert_err!(
+		DidMerkleRootGenerator::<TestRuntime>::generate_salted_proof(
+			&linked_info,
+			1,
+			[].into_iter(),
+			false,
+			[].into_iter(),
+			[].into_iter()
+		),
 		DidMerkleProofError::UnsupportedVersion
 	);
 }
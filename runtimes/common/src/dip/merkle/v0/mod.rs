@@ -29,7 +29,7 @@ use sp_trie::{generate_trie_proof, LayoutV1, MemoryDB, TrieDBMutBuilder, TrieHas
 
 use crate::dip::{
 	did::{LinkedDidInfoOf, Web3OwnershipOf},
-	merkle::{CompleteMerkleProof, DidMerkleProofError, DidMerkleProofOf},
+	merkle::{CompleteMerkleProof, DidMerkleProofError, DidMerkleProofLeafOf, DidMerkleProofOf},
 };
 
 const LOG_TARGET: &str = "dip::provider::DidMerkleRootGeneratorV0";
@@ -174,12 +174,11 @@ where
 	}
 }
 
-/// Given the provided DID info, it calculates the Merkle commitment (root)
-/// using the provided in-memory DB.
-pub(super) fn calculate_root_with_db<Runtime, const MAX_LINKED_ACCOUNT: u32>(
-	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>,
-	db: &mut MemoryDB<Runtime::Hashing>,
-) -> Result<Runtime::Hash, DidMerkleProofError>
+/// Given the provided DID info, it returns all the leaves of the version 0
+/// Merkle commitment.
+pub(super) fn get_leaves<Runtime, const MAX_LINKED_ACCOUNT: u32, const MAX_CREDENTIALS: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>,
+) -> Result<Vec<DidMerkleProofLeafOf<Runtime>>, DidMerkleProofError>
 where
 	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
 {
@@ -187,9 +186,8 @@ where
 		did_details,
 		web3_name_details,
 		linked_accounts,
+		..
 	} = identity;
-	let mut trie = TrieHash::<LayoutV1<Runtime::Hashing>>::default();
-	let mut trie_builder = TrieDBMutBuilder::<LayoutV1<Runtime::Hashing>>::new(db, &mut trie).build();
 
 	// Authentication key.
 	let auth_leaves = get_auth_leaves(did_details)?;
@@ -204,7 +202,6 @@ where
 	// Web3name.
 	let web3_name = web3_name_details.as_ref().map(get_web3name_leaf::<Runtime>);
 
-	// Add all leaves to the proof builder.
 	let keys = auth_leaves
 		.chain(att_leaves)
 		.chain(del_leaves)
@@ -217,9 +214,38 @@ where
 		.into_iter()
 		.map(RevealedDidMerkleProofLeaf::from);
 
-	keys.chain(linked_accounts).chain(web3_names).try_for_each(|leaf| {
+	Ok(keys.chain(linked_accounts).chain(web3_names).collect())
+}
+
+/// Given the provided leaves, it calculates the Merkle commitment (root) using
+/// the provided in-memory DB.
+pub(super) fn calculate_root_for_leaves_with_db<Runtime>(
+	leaves: impl IntoIterator<Item = DidMerkleProofLeafOf<Runtime>>,
+	db: &mut MemoryDB<Runtime::Hashing>,
+) -> Result<Runtime::Hash, DidMerkleProofError>
+where
+	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+{
+	calculate_root_for_keyed_leaves_with_db::<Runtime>(leaves.into_iter().map(|leaf| (leaf.encoded_key(), leaf)), db)
+}
+
+/// Given the provided leaves and the key under which each of them must be
+/// stored, it calculates the Merkle commitment (root) using the provided
+/// in-memory DB.
+pub(super) fn calculate_root_for_keyed_leaves_with_db<Runtime>(
+	leaves: impl IntoIterator<Item = (Vec<u8>, DidMerkleProofLeafOf<Runtime>)>,
+	db: &mut MemoryDB<Runtime::Hashing>,
+) -> Result<Runtime::Hash, DidMerkleProofError>
+where
+	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+{
+	let mut trie = TrieHash::<LayoutV1<Runtime::Hashing>>::default();
+	let mut trie_builder = TrieDBMutBuilder::<LayoutV1<Runtime::Hashing>>::new(db, &mut trie).build();
+
+	// Add all leaves to the proof builder.
+	leaves.into_iter().try_for_each(|(key, leaf)| {
 		trie_builder
-			.insert(leaf.encoded_key().as_slice(), leaf.encoded_value().as_slice())
+			.insert(key.as_slice(), leaf.encoded_value().as_slice())
 			.map_err(|_| {
 				log::error!(
 					target: LOG_TARGET,
@@ -235,17 +261,30 @@ where
 	Ok(trie_builder.root().to_owned())
 }
 
+/// Given the provided DID info, it calculates the Merkle commitment (root)
+/// using the provided in-memory DB.
+pub(super) fn calculate_root_with_db<Runtime, const MAX_LINKED_ACCOUNT: u32, const MAX_CREDENTIALS: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>,
+	db: &mut MemoryDB<Runtime::Hashing>,
+) -> Result<Runtime::Hash, DidMerkleProofError>
+where
+	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+{
+	let leaves = get_leaves(identity)?;
+	calculate_root_for_leaves_with_db::<Runtime>(leaves, db)
+}
+
 /// Given the provided DID info, and a set of DID key IDs, account IDs and a
-/// web3name, generates a Merkle proof that reveals only the provided
-/// identity components. The function fails if no key or account with the
-/// specified ID can be found, or if a web3name is requested to be revealed
-/// in the proof but is not present in the provided identity details.
-pub(super) fn generate_proof<'a, Runtime, K, A, const MAX_LINKED_ACCOUNT: u32>(
-	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>,
+/// web3name, returns the leaves that reveal only the provided identity
+/// components. The function fails if no key or account with the specified ID
+/// can be found, or if a web3name is requested to be revealed but is not
+/// present in the provided identity details.
+pub(super) fn get_revealed_leaves<'a, Runtime, K, A, const MAX_LINKED_ACCOUNT: u32, const MAX_CREDENTIALS: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>,
 	key_ids: K,
 	should_include_web3_name: bool,
 	account_ids: A,
-) -> Result<CompleteMerkleProof<Runtime::Hash, DidMerkleProofOf<Runtime>>, DidMerkleProofError>
+) -> Result<Vec<DidMerkleProofLeafOf<Runtime>>, DidMerkleProofError>
 where
 	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
 	K: Iterator<Item = &'a KeyIdOf<Runtime>>,
@@ -255,11 +294,9 @@ where
 		did_details,
 		web3_name_details,
 		linked_accounts,
+		..
 	} = identity;
 
-	let mut db = MemoryDB::default();
-	let root = calculate_root_with_db(identity, &mut db)?;
-
 	let did_key_leaves_iter = key_ids.map(|key_id| -> Result<_, DidMerkleProofError> {
 		let key_details = did_details
 			.public_keys
@@ -333,8 +370,21 @@ where
 		(false, _) => {}
 	};
 
-	let encoded_keys: Vec<Vec<u8>> = leaves.iter().flatten().map(|l| l.encoded_key()).collect();
-	let proof = generate_trie_proof::<LayoutV1<Runtime::Hashing>, _, _, _>(&db, root, &encoded_keys).map_err(|_| {
+	Ok(leaves.into_iter().flatten().collect())
+}
+
+/// Given the provided in-memory DB containing the trie with the provided root,
+/// generates the blinded part of a Merkle proof that reveals only the leaves
+/// stored under the provided keys.
+pub(super) fn generate_blinded_proof_for_keys<Runtime>(
+	db: &MemoryDB<Runtime::Hashing>,
+	root: Runtime::Hash,
+	encoded_keys: &[Vec<u8>],
+) -> Result<Vec<Vec<u8>>, DidMerkleProofError>
+where
+	Runtime: uid_core::Config,
+{
+	let proof = generate_trie_proof::<LayoutV1<Runtime::Hashing>, _, _, _>(db, root, encoded_keys).map_err(|_| {
 		log::error!(
 			target: LOG_TARGET,
 			"Failed to generate a Merkle proof for the encoded keys: {:#?}",
@@ -343,20 +393,59 @@ where
 		DidMerkleProofError::Internal
 	})?;
 	log::info!(target: LOG_TARGET, "Merkle proof generated: {:#?}", proof);
+	Ok(proof)
+}
+
+/// Given the provided in-memory DB containing the trie with the provided root,
+/// generates a Merkle proof that reveals only the provided leaves.
+pub(super) fn generate_proof_for_leaves<Runtime>(
+	db: &MemoryDB<Runtime::Hashing>,
+	root: Runtime::Hash,
+	leaves: Vec<DidMerkleProofLeafOf<Runtime>>,
+) -> Result<CompleteMerkleProof<Runtime::Hash, DidMerkleProofOf<Runtime>>, DidMerkleProofError>
+where
+	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+{
+	let encoded_keys: Vec<Vec<u8>> = leaves.iter().map(|l| l.encoded_key()).collect();
+	let proof = generate_blinded_proof_for_keys::<Runtime>(db, root, &encoded_keys)?;
 
 	Ok(CompleteMerkleProof {
 		root,
-		proof: DidMerkleProofOf::<Runtime>::new(proof, leaves.into_iter().flatten().collect::<Vec<_>>()),
+		proof: DidMerkleProofOf::<Runtime>::new(proof, leaves),
 	})
 }
 
+/// Given the provided DID info, and a set of DID key IDs, account IDs and a
+/// web3name, generates a Merkle proof that reveals only the provided
+/// identity components. The function fails if no key or account with the
+/// specified ID can be found, or if a web3name is requested to be revealed
+/// in the proof but is not present in the provided identity details.
+pub(super) fn generate_proof<'a, Runtime, K, A, const MAX_LINKED_ACCOUNT: u32, const MAX_CREDENTIALS: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>,
+	key_ids: K,
+	should_include_web3_name: bool,
+	account_ids: A,
+) -> Result<CompleteMerkleProof<Runtime::Hash, DidMerkleProofOf<Runtime>>, DidMerkleProofError>
+where
+	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+	K: Iterator<Item = &'a KeyIdOf<Runtime>>,
+	A: Iterator<Item = &'a LinkableAccountId>,
+{
+	let mut db = MemoryDB::default();
+	let root = calculate_root_with_db(identity, &mut db)?;
+
+	let leaves = get_revealed_leaves(identity, key_ids, should_include_web3_name, account_ids)?;
+	generate_proof_for_leaves::<Runtime>(&db, root, leaves)
+}
+
 /// Given the provided DID info, generates a Merkle commitment (root).
-pub(super) fn generate_commitment<Runtime, const MAX_LINKED_ACCOUNT: u32>(
+pub(super) fn generate_commitment<Runtime, const MAX_LINKED_ACCOUNT: u32, const MAX_CREDENTIALS: u32>(
 	identity: &IdentityOf<Runtime>,
 ) -> Result<Runtime::Hash, DidMerkleProofError>
 where
 	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config + pallet_dip_provider::Config,
-	Runtime::IdentityProvider: IdentityProvider<Runtime, Success = LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT>>,
+	Runtime::IdentityProvider:
+		IdentityProvider<Runtime, Success = LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>>,
 {
 	let mut db = MemoryDB::default();
 	calculate_root_with_db(identity, &mut db)
@@ -0,0 +1,137 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use uid_core::KeyIdOf;
+use kilt_dip_primitives::{CredentialKind, RevealedCredential, RevealedDidMerkleProofLeaf};
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use pallet_dip_provider::{traits::IdentityProvider, IdentityOf};
+use sp_core::H256;
+use sp_std::vec::Vec;
+use sp_trie::MemoryDB;
+
+use crate::dip::{
+	did::LinkedDidInfoOf,
+	merkle::{v0, CompleteMerkleProof, DidMerkleProofError, DidMerkleProofLeafOf, DidMerkleProofOf},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Given the provided DID info, it returns all the leaves of the version 1
+/// Merkle commitment, i.e., the version 0 leaves plus one leaf for each
+/// public credential and verification bound to the subject.
+pub(super) fn get_leaves<Runtime, const MAX_LINKED_ACCOUNT: u32, const MAX_CREDENTIALS: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>,
+) -> Result<Vec<DidMerkleProofLeafOf<Runtime>>, DidMerkleProofError>
+where
+	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+{
+	let mut leaves = v0::get_leaves(identity)?;
+	leaves.extend(
+		identity
+			.credentials
+			.iter()
+			.cloned()
+			.map(RevealedDidMerkleProofLeaf::from),
+	);
+	Ok(leaves)
+}
+
+/// Given the provided DID info, it calculates the Merkle commitment (root)
+/// using the provided in-memory DB.
+pub(super) fn calculate_root_with_db<Runtime, const MAX_LINKED_ACCOUNT: u32, const MAX_CREDENTIALS: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>,
+	db: &mut MemoryDB<Runtime::Hashing>,
+) -> Result<Runtime::Hash, DidMerkleProofError>
+where
+	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+{
+	let leaves = get_leaves(identity)?;
+	v0::calculate_root_for_leaves_with_db::<Runtime>(leaves, db)
+}
+
+/// Given the provided DID info, and a set of DID key IDs, account IDs, a
+/// web3name and credential IDs, returns the leaves that reveal only the
+/// provided identity components. On top of the failure cases of version 0,
+/// the function fails if no credential with the specified kind and ID is
+/// bound to the subject.
+pub(super) fn get_revealed_leaves<'a, Runtime, K, A, C, const MAX_LINKED_ACCOUNT: u32, const MAX_CREDENTIALS: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>,
+	key_ids: K,
+	should_include_web3_name: bool,
+	account_ids: A,
+	credential_ids: C,
+) -> Result<Vec<DidMerkleProofLeafOf<Runtime>>, DidMerkleProofError>
+where
+	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+	K: Iterator<Item = &'a KeyIdOf<Runtime>>,
+	A: Iterator<Item = &'a LinkableAccountId>,
+	C: Iterator<Item = &'a (CredentialKind, H256)>,
+{
+	let mut leaves = v0::get_revealed_leaves(identity, key_ids, should_include_web3_name, account_ids)?;
+	let credential_leaves = credential_ids
+		.map(|(kind, id)| -> Result<_, DidMerkleProofError> {
+			identity
+				.credentials
+				.iter()
+				.find(|RevealedCredential { kind: k, id: i, .. }| k == kind && i == id)
+				.cloned()
+				.map(RevealedDidMerkleProofLeaf::from)
+				.ok_or(DidMerkleProofError::CredentialNotFound)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	leaves.extend(credential_leaves);
+	Ok(leaves)
+}
+
+/// Given the provided DID info, and a set of DID key IDs, account IDs, a
+/// web3name and credential IDs, generates a Merkle proof that reveals only
+/// the provided identity components. The function fails in the same cases as
+/// [`get_revealed_leaves`].
+pub(super) fn generate_proof<'a, Runtime, K, A, C, const MAX_LINKED_ACCOUNT: u32, const MAX_CREDENTIALS: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>,
+	key_ids: K,
+	should_include_web3_name: bool,
+	account_ids: A,
+	credential_ids: C,
+) -> Result<CompleteMerkleProof<Runtime::Hash, DidMerkleProofOf<Runtime>>, DidMerkleProofError>
+where
+	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+	K: Iterator<Item = &'a KeyIdOf<Runtime>>,
+	A: Iterator<Item = &'a LinkableAccountId>,
+	C: Iterator<Item = &'a (CredentialKind, H256)>,
+{
+	let mut db = MemoryDB::default();
+	let root = calculate_root_with_db(identity, &mut db)?;
+
+	let leaves = get_revealed_leaves(identity, key_ids, should_include_web3_name, account_ids, credential_ids)?;
+	v0::generate_proof_for_leaves::<Runtime>(&db, root, leaves)
+}
+
+/// Given the provided DID info, generates a Merkle commitment (root).
+pub(super) fn generate_commitment<Runtime, const MAX_LINKED_ACCOUNT: u32, const MAX_CREDENTIALS: u32>(
+	identity: &IdentityOf<Runtime>,
+) -> Result<Runtime::Hash, DidMerkleProofError>
+where
+	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config + pallet_dip_provider::Config,
+	Runtime::IdentityProvider:
+		IdentityProvider<Runtime, Success = LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>>,
+{
+	let mut db = MemoryDB::default();
+	calculate_root_with_db(identity, &mut db)
+}
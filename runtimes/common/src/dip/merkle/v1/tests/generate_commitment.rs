@@ -0,0 +1,79 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use uid_core::did_details::DidVerificationKey;
+use sp_trie::MemoryDB;
+
+use crate::{
+	constants::dip_provider::MAX_LINKED_ACCOUNTS,
+	dip::{
+		merkle::{
+			v0,
+			v1::{
+				calculate_root_with_db,
+				tests::{create_credentials, with_credentials, MAX_CREDENTIALS},
+			},
+		},
+		mock::{create_linked_info, TestRuntime, ACCOUNT},
+	},
+};
+
+#[test]
+fn calculate_root_without_credentials_matches_v0() {
+	let linked_info = create_linked_info(
+		DidVerificationKey::Account(ACCOUNT),
+		Some(b"ntn_x2"),
+		MAX_LINKED_ACCOUNTS,
+	);
+	let v0_root = v0::calculate_root_with_db(&linked_info, &mut MemoryDB::default()).unwrap();
+	let v1_root = calculate_root_with_db(&linked_info, &mut MemoryDB::default()).unwrap();
+	assert_eq!(v0_root, v1_root);
+}
+
+#[test]
+fn calculate_root_with_credentials() {
+	let linked_info = with_credentials(
+		create_linked_info(
+			DidVerificationKey::Account(ACCOUNT),
+			Some(b"ntn_x2"),
+			MAX_LINKED_ACCOUNTS,
+		),
+		create_credentials(MAX_CREDENTIALS),
+	);
+	let v0_root = v0::calculate_root_with_db(&linked_info, &mut MemoryDB::default()).unwrap();
+	let v1_root = calculate_root_with_db(&linked_info, &mut MemoryDB::default()).unwrap();
+	assert_ne!(v0_root, v1_root);
+
+	// Changing the revocation status of a credential changes the commitment.
+	let mut revoked_credentials = create_credentials(MAX_CREDENTIALS);
+	revoked_credentials.iter_mut().for_each(|c| c.revoked = true);
+	let revoked_linked_info = with_credentials(
+		create_linked_info(
+			DidVerificationKey::Account(ACCOUNT),
+			Some(b"ntn_x2"),
+			MAX_LINKED_ACCOUNTS,
+		),
+		revoked_credentials,
+	);
+	let revoked_root = calculate_root_with_db::<TestRuntime, MAX_LINKED_ACCOUNTS, MAX_CREDENTIALS>(
+		&revoked_linked_info,
+		&mut MemoryDB::default(),
+	)
+	.unwrap();
+	assert_ne!(v1_root, revoked_root);
+}
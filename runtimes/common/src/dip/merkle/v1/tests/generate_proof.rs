@@ -0,0 +1,125 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use uid_core::did_details::DidVerificationKey;
+use frame_support::assert_err;
+use kilt_dip_primitives::{CredentialKind, DipDidProofWithVerifiedSubjectCommitment, TimeBoundDidSignature};
+use parity_scale_codec::Encode;
+use sp_core::{ed25519, Pair, H256};
+
+use crate::{
+	constants::{did::MAX_PUBLIC_KEYS_PER_DID, dip_provider::MAX_LINKED_ACCOUNTS},
+	dip::{
+		merkle::{
+			v1::{
+				generate_proof,
+				tests::{create_credentials, with_credentials, MAX_CREDENTIALS},
+			},
+			CompleteMerkleProof, DidMerkleProofError,
+		},
+		mock::create_linked_info,
+	},
+	Hasher,
+};
+
+const MAX_LEAVES_REVEALED: u32 = MAX_LINKED_ACCOUNTS + MAX_PUBLIC_KEYS_PER_DID + MAX_CREDENTIALS + 1;
+
+#[test]
+fn generate_proof_with_credentials() {
+	let auth_key = ed25519::Pair::from_seed(&[10u8; 32]);
+	let did_auth_key = DidVerificationKey::Ed25519(auth_key.public());
+	let credentials = create_credentials(MAX_CREDENTIALS);
+	let linked_info = with_credentials(
+		create_linked_info(did_auth_key, Some(b"ntn_x2"), MAX_LINKED_ACCOUNTS),
+		credentials.clone(),
+	);
+	let signature = auth_key.sign(&().encode());
+
+	// 1. Generate a proof revealing the authentication key and all the credentials.
+	let credential_ids = credentials.iter().map(|c| (c.kind, c.id)).collect::<Vec<_>>();
+	let CompleteMerkleProof { proof, root } = generate_proof(
+		&linked_info,
+		[linked_info.did_details.authentication_key].iter(),
+		false,
+		[].iter(),
+		credential_ids.iter(),
+	)
+	.unwrap();
+	let cross_chain_proof = DipDidProofWithVerifiedSubjectCommitment::new(
+		root,
+		proof,
+		TimeBoundDidSignature::new(signature.clone().into(), 100),
+	);
+
+	let dip_origin_info = cross_chain_proof
+		.verify_dip_proof::<Hasher, MAX_LEAVES_REVEALED>()
+		.and_then(|r| r.verify_signature_time(&50))
+		.and_then(|r| r.retrieve_signing_leaves_for_payload(&().encode()))
+		.unwrap();
+	// Authentication key, plus all the credentials.
+	assert_eq!(dip_origin_info.iter_leaves().count(), (MAX_CREDENTIALS + 1) as usize);
+	assert_eq!(
+		dip_origin_info.iter_credentials().cloned().collect::<Vec<_>>(),
+		credentials
+	);
+
+	// 2. Generate a proof revealing a single verification.
+	let CompleteMerkleProof { proof, root } = generate_proof(
+		&linked_info,
+		[linked_info.did_details.authentication_key].iter(),
+		false,
+		[].iter(),
+		[(credentials[1].kind, credentials[1].id)].iter(),
+	)
+	.unwrap();
+	let cross_chain_proof =
+		DipDidProofWithVerifiedSubjectCommitment::new(root, proof, TimeBoundDidSignature::new(signature.into(), 100));
+	let dip_origin_info = cross_chain_proof
+		.verify_dip_proof::<Hasher, MAX_LEAVES_REVEALED>()
+		.and_then(|r| r.verify_signature_time(&50))
+		.and_then(|r| r.retrieve_signing_leaves_for_payload(&().encode()))
+		.unwrap();
+	assert_eq!(
+		dip_origin_info.iter_credentials().collect::<Vec<_>>(),
+		vec![&credentials[1]]
+	);
+
+	// 3. Fails to generate the proof for a credential with a different kind.
+	assert_err!(
+		generate_proof(
+			&linked_info,
+			[].iter(),
+			false,
+			[].iter(),
+			[(CredentialKind::PublicCredential, credentials[1].id)].iter(),
+		),
+		DidMerkleProofError::CredentialNotFound
+	);
+
+	// 4. Fails to generate the proof for a credential that does not exist.
+	assert_err!(
+		generate_proof(
+			&linked_info,
+			[].iter(),
+			false,
+			[].iter(),
+			[(CredentialKind::Verification, H256([u8::MAX; 32]))].iter(),
+		),
+		DidMerkleProofError::CredentialNotFound
+	);
+}
@@ -0,0 +1,70 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+mod generate_commitment;
+mod generate_proof;
+
+use kilt_dip_primitives::{CredentialKind, RevealedCredential};
+use sp_core::H256;
+use sp_runtime::{AccountId32, BoundedVec};
+
+use crate::{
+	constants::dip_provider::MAX_LINKED_ACCOUNTS,
+	dip::{did::LinkedDidInfoOf, mock::TestRuntime},
+};
+
+pub(super) const MAX_CREDENTIALS: u32 = 4;
+
+pub(super) fn create_credentials(count: u32) -> Vec<RevealedCredential<AccountId32>> {
+	(0..count)
+		.map(|i| {
+			let mut buffer = <[u8; 32]>::default();
+			buffer[..4].copy_from_slice(&i.to_be_bytes());
+			RevealedCredential {
+				// Alternate between public credentials and verifications.
+				kind: if i % 2 == 0 {
+					CredentialKind::PublicCredential
+				} else {
+					CredentialKind::Verification
+				},
+				id: H256(buffer),
+				ctype_hash: H256([1u8; 32]),
+				attester: AccountId32::new([2u8; 32]),
+				revoked: i % 3 == 0,
+			}
+		})
+		.collect()
+}
+
+pub(super) fn with_credentials(
+	linked_info: LinkedDidInfoOf<TestRuntime, MAX_LINKED_ACCOUNTS>,
+	credentials: Vec<RevealedCredential<AccountId32>>,
+) -> LinkedDidInfoOf<TestRuntime, MAX_LINKED_ACCOUNTS, MAX_CREDENTIALS> {
+	let LinkedDidInfoOf {
+		did_details,
+		web3_name_details,
+		linked_accounts,
+		..
+	} = linked_info;
+	LinkedDidInfoOf {
+		did_details,
+		web3_name_details,
+		linked_accounts,
+		credentials: BoundedVec::try_from(credentials).expect("Should not exceed the maximum number of credentials."),
+	}
+}
@@ -0,0 +1,136 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use uid_core::KeyIdOf;
+use kilt_dip_primitives::{CredentialKind, SaltedProof};
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use pallet_dip_provider::{traits::IdentityProvider, IdentityOf};
+use parity_scale_codec::Encode;
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+use sp_std::vec::Vec;
+use sp_trie::MemoryDB;
+
+use crate::dip::{
+	did::LinkedDidInfoOf,
+	merkle::{
+		v0, v1, CompleteMerkleProof, DidMerkleProofError, DidMerkleProofLeafOf, DidMerkleProofOf,
+		SaltedDidMerkleProofOf,
+	},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Derives the seed of the leaf salts from all the leaves of the commitment,
+/// by hashing their encoded keys and values together.
+///
+/// The seed, and hence each salt, can only be computed by someone who knows
+/// all the identity components of the subject, i.e., not by a recipient of a
+/// proof that reveals only some of them.
+pub(super) fn get_salt_seed<Runtime>(leaves: &[DidMerkleProofLeafOf<Runtime>]) -> H256
+where
+	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+{
+	let encoded_leaves: Vec<(Vec<u8>, Vec<u8>)> = leaves
+		.iter()
+		.map(|leaf| (leaf.encoded_key(), leaf.encoded_value()))
+		.collect();
+	H256(blake2_256(&encoded_leaves.encode()))
+}
+
+/// Derives the salt of the provided leaf from the salt seed, by hashing the
+/// seed together with the encoded leaf key.
+pub(super) fn get_leaf_salt<Runtime>(seed: &H256, leaf: &DidMerkleProofLeafOf<Runtime>) -> H256
+where
+	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+{
+	H256(blake2_256(&(seed, leaf.encoded_key()).encode()))
+}
+
+/// Given the provided DID info, it calculates the Merkle commitment (root)
+/// using the provided in-memory DB. The leaves are the same as in version 1,
+/// each stored under its key salted with a salt derived from all the leaves.
+/// It returns the commitment together with the salt seed.
+pub(super) fn calculate_root_with_db<Runtime, const MAX_LINKED_ACCOUNT: u32, const MAX_CREDENTIALS: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>,
+	db: &mut MemoryDB<Runtime::Hashing>,
+) -> Result<(Runtime::Hash, H256), DidMerkleProofError>
+where
+	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+{
+	let leaves = v1::get_leaves(identity)?;
+	let seed = get_salt_seed::<Runtime>(&leaves);
+	let keyed_leaves = leaves.into_iter().map(|leaf| {
+		let salt = get_leaf_salt::<Runtime>(&seed, &leaf);
+		(leaf.salted_encoded_key(&salt), leaf)
+	});
+	let root = v0::calculate_root_for_keyed_leaves_with_db::<Runtime>(keyed_leaves, db)?;
+	Ok((root, seed))
+}
+
+/// Given the provided DID info, and a set of DID key IDs, account IDs, a
+/// web3name and credential IDs, generates a Merkle proof that reveals only the
+/// provided identity components, together with the salt of each revealed leaf.
+/// The function fails in the same cases as version 1.
+pub(super) fn generate_proof<'a, Runtime, K, A, C, const MAX_LINKED_ACCOUNT: u32, const MAX_CREDENTIALS: u32>(
+	identity: &LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>,
+	key_ids: K,
+	should_include_web3_name: bool,
+	account_ids: A,
+	credential_ids: C,
+) -> Result<CompleteMerkleProof<Runtime::Hash, SaltedDidMerkleProofOf<Runtime>>, DidMerkleProofError>
+where
+	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config,
+	K: Iterator<Item = &'a KeyIdOf<Runtime>>,
+	A: Iterator<Item = &'a LinkableAccountId>,
+	C: Iterator<Item = &'a (CredentialKind, H256)>,
+{
+	let mut db = MemoryDB::default();
+	let (root, seed) = calculate_root_with_db(identity, &mut db)?;
+
+	let leaves = v1::get_revealed_leaves(identity, key_ids, should_include_web3_name, account_ids, credential_ids)?;
+	let salts: Vec<H256> = leaves
+		.iter()
+		.map(|leaf| get_leaf_salt::<Runtime>(&seed, leaf))
+		.collect();
+	let encoded_keys: Vec<Vec<u8>> = leaves
+		.iter()
+		.zip(salts.iter())
+		.map(|(leaf, salt)| leaf.salted_encoded_key(salt))
+		.collect();
+	let proof = v0::generate_blinded_proof_for_keys::<Runtime>(&db, root, &encoded_keys)?;
+
+	Ok(CompleteMerkleProof {
+		root,
+		proof: SaltedProof::new(DidMerkleProofOf::<Runtime>::new(proof, leaves), salts),
+	})
+}
+
+/// Given the provided DID info, generates a Merkle commitment (root).
+pub(super) fn generate_commitment<Runtime, const MAX_LINKED_ACCOUNT: u32, const MAX_CREDENTIALS: u32>(
+	identity: &IdentityOf<Runtime>,
+) -> Result<Runtime::Hash, DidMerkleProofError>
+where
+	Runtime: uid_core::Config + pallet_did_lookup::Config + pallet_web3_names::Config + pallet_dip_provider::Config,
+	Runtime::IdentityProvider:
+		IdentityProvider<Runtime, Success = LinkedDidInfoOf<Runtime, MAX_LINKED_ACCOUNT, MAX_CREDENTIALS>>,
+{
+	let mut db = MemoryDB::default();
+	calculate_root_with_db(identity, &mut db).map(|(root, _)| root)
+}
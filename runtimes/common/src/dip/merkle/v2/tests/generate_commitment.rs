@@ -0,0 +1,57 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use uid_core::did_details::DidVerificationKey;
+use sp_trie::MemoryDB;
+
+use crate::{
+	constants::dip_provider::MAX_LINKED_ACCOUNTS,
+	dip::{
+		merkle::{v1, v2::calculate_root_with_db},
+		mock::{create_linked_info, ACCOUNT},
+	},
+};
+
+#[test]
+fn calculate_root_salted() {
+	let linked_info = create_linked_info(
+		DidVerificationKey::Account(ACCOUNT),
+		Some(b"ntn_x2"),
+		MAX_LINKED_ACCOUNTS,
+	);
+	let v1_root = v1::calculate_root_with_db(&linked_info, &mut MemoryDB::default()).unwrap();
+	let (v2_root, seed) = calculate_root_with_db(&linked_info, &mut MemoryDB::default()).unwrap();
+	// Salted leaves result in a different commitment.
+	assert_ne!(v1_root, v2_root);
+
+	// The same identity always results in the same commitment.
+	let (same_identity_root, same_identity_seed) =
+		calculate_root_with_db(&linked_info, &mut MemoryDB::default()).unwrap();
+	assert_eq!(v2_root, same_identity_root);
+	assert_eq!(seed, same_identity_seed);
+
+	// A change in any identity component changes the salt of every leaf.
+	let other_linked_info = create_linked_info(
+		DidVerificationKey::Account(ACCOUNT),
+		Some(b"ntn_x3"),
+		MAX_LINKED_ACCOUNTS,
+	);
+	let (other_root, other_seed) = calculate_root_with_db(&other_linked_info, &mut MemoryDB::default()).unwrap();
+	assert_ne!(v2_root, other_root);
+	assert_ne!(seed, other_seed);
+}
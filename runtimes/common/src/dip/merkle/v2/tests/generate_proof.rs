@@ -0,0 +1,88 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2024 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+use uid_core::did_details::DidVerificationKey;
+use frame_support::assert_err;
+use kilt_dip_primitives::{DipDidProofWithVerifiedSubjectCommitment, Error, TimeBoundDidSignature};
+use parity_scale_codec::Encode;
+use sp_core::{ed25519, Pair, H256};
+
+use crate::{
+	constants::{did::MAX_PUBLIC_KEYS_PER_DID, dip_provider::MAX_LINKED_ACCOUNTS},
+	dip::{
+		merkle::{v2::generate_proof, CompleteMerkleProof},
+		mock::create_linked_info,
+	},
+	Hasher,
+};
+
+const MAX_LEAVES_REVEALED: u32 = MAX_LINKED_ACCOUNTS + MAX_PUBLIC_KEYS_PER_DID + 1;
+
+#[test]
+fn generate_proof_salted() {
+	let auth_key = ed25519::Pair::from_seed(&[10u8; 32]);
+	let did_auth_key = DidVerificationKey::Ed25519(auth_key.public());
+	let linked_info = create_linked_info(did_auth_key, Some(b"ntn_x2"), MAX_LINKED_ACCOUNTS);
+	let signature = auth_key.sign(&().encode());
+
+	// 1. Generate a proof revealing the authentication key, all linked accounts
+	//    and the web3name.
+	let CompleteMerkleProof { proof, root } = generate_proof(
+		&linked_info,
+		[linked_info.did_details.authentication_key].iter(),
+		true,
+		linked_info.linked_accounts.iter(),
+		[].iter(),
+	)
+	.unwrap();
+	// One salt per revealed leaf.
+	assert_eq!(proof.salts().len(), proof.proof().revealed().len());
+	let (proof, salts) = proof.into_parts();
+	let cross_chain_proof = DipDidProofWithVerifiedSubjectCommitment::new(
+		root,
+		proof.clone(),
+		TimeBoundDidSignature::new(signature.clone().into(), 100),
+	);
+	let dip_origin_info = cross_chain_proof
+		.verify_salted_dip_proof::<Hasher, MAX_LEAVES_REVEALED>(&salts)
+		.and_then(|r| r.verify_signature_time(&50))
+		.and_then(|r| r.retrieve_signing_leaves_for_payload(&().encode()))
+		.unwrap();
+	// Authentication key, all linked accounts and the web3name.
+	assert_eq!(dip_origin_info.iter_leaves().count(), MAX_LINKED_ACCOUNTS as usize + 2);
+
+	// 2. The same proof does not verify without the salts.
+	let cross_chain_proof = DipDidProofWithVerifiedSubjectCommitment::new(
+		root,
+		proof.clone(),
+		TimeBoundDidSignature::new(signature.clone().into(), 100),
+	);
+	assert_err!(
+		cross_chain_proof.verify_dip_proof::<Hasher, MAX_LEAVES_REVEALED>(),
+		Error::InvalidDidMerkleProof
+	);
+
+	// 3. The same proof does not verify with different salts.
+	let wrong_salts = vec![H256::default(); salts.len()];
+	let cross_chain_proof =
+		DipDidProofWithVerifiedSubjectCommitment::new(root, proof, TimeBoundDidSignature::new(signature.into(), 100));
+	assert_err!(
+		cross_chain_proof.verify_salted_dip_proof::<Hasher, MAX_LEAVES_REVEALED>(&wrong_salts),
+		Error::InvalidDidMerkleProof
+	);
+}
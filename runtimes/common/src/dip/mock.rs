@@ -22,16 +22,26 @@ use uid_core::{
 	DeriveDidCallAuthorizationVerificationKeyRelationship,
 };
 use frame_support::{
-	construct_runtime,
+	construct_runtime, parameter_types,
 	traits::{Currency, Everything},
 	Hashable,
 };
 use frame_system::{mocking::MockBlock, pallet_prelude::BlockNumberFor, EnsureRoot, EnsureSigned};
 use kilt_dip_primitives::RevealedWeb3Name;
-use pallet_did_lookup::{account::AccountId20, linkable_account::LinkableAccountId};
+use pallet_did_lookup::{
+	account::AccountId20, bitcoin::BitcoinAccountId, cosmos::CosmosAccountId, linkable_account::LinkableAccountId,
+	solana::SolanaAccountId,
+};
 use pallet_web3_names::{web3_name::AsciiWeb3Name, Web3NameOf};
 use sp_core::{sr25519, ConstU128, ConstU16, ConstU32, ConstU64};
 use sp_runtime::{traits::IdentityLookup, AccountId32, BoundedVec};
+use xcm::v4::{
+	InteriorLocation,
+	Junction::{GlobalConsensus, Parachain},
+	Junctions::X2,
+	Location, NetworkId, QueryId, Xcm, XcmError,
+};
+use xcm_executor::traits::{QueryHandler, QueryResponseStatus};
 
 use crate::{
 	constants::{
@@ -59,6 +69,7 @@ construct_runtime!(
 		Web3Names: pallet_web3_names,
 		DidLookup: pallet_did_lookup,
 		DipProvider: pallet_dip_provider,
+		AssetSwitch: pallet_asset_switch,
 	}
 );
 
@@ -118,12 +129,14 @@ impl DeriveDidCallAuthorizationVerificationKeyRelationship for RuntimeCall {
 
 impl uid_core::Config for TestRuntime {
 	type BalanceMigrationManager = ();
+	type DepositIndex = ();
 	type BaseDeposit = ConstU128<KILT>;
 	type Currency = Balances;
 	type DidIdentifier = DidIdentifier;
 	type EnsureOrigin = EnsureSigned<AccountId>;
 	type Fee = ConstU128<KILT>;
 	type FeeCollector = ();
+	type IdentityChangeHook = ();
 	type KeyDeposit = ConstU128<KILT>;
 	type MaxBlocksTxValidity = ConstU64<10>;
 	type MaxNewKeyAgreementKeys = MaxNewKeyAgreementKeys;
@@ -146,15 +159,23 @@ impl uid_core::Config for TestRuntime {
 
 impl pallet_web3_names::Config for TestRuntime {
 	type BalanceMigrationManager = ();
+	type DepositIndex = ();
+	type IdentityChangeHook = ();
 	type BanOrigin = EnsureRoot<AccountId>;
 	type Currency = Balances;
 	type Deposit = ConstU128<KILT>;
 	type MaxNameLength = MaxNameLength;
+	type MaxReservedPatterns = ConstU32<50>;
+	type MaxTextRecordKeyLength = ConstU32<32>;
+	type MaxTextRecordValueLength = ConstU32<256>;
+	type MaxTextRecords = ConstU32<16>;
 	type MinNameLength = MinNameLength;
 	type OriginSuccess = AccountId;
 	type OwnerOrigin = EnsureSigned<AccountId>;
+	type ReserveOrigin = EnsureRoot<AccountId>;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	type TextRecordDeposit = ConstU128<KILT>;
 	type Web3Name = AsciiWeb3Name<Self>;
 	type Web3NameOwner = DidIdentifier;
 	type WeightInfo = ();
@@ -162,10 +183,13 @@ impl pallet_web3_names::Config for TestRuntime {
 
 impl pallet_did_lookup::Config for TestRuntime {
 	type BalanceMigrationManager = ();
+	type DepositIndex = ();
 	type Currency = Balances;
 	type Deposit = ConstU128<KILT>;
 	type DidIdentifier = DidIdentifier;
 	type EnsureOrigin = EnsureSigned<AccountId>;
+	type EthereumChainId = ConstU64<1>;
+	type IdentityChangeHook = ();
 	type OriginSuccess = AccountId;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
@@ -173,16 +197,79 @@ impl pallet_did_lookup::Config for TestRuntime {
 }
 
 impl pallet_dip_provider::Config for TestRuntime {
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHooks = ();
 	type CommitOrigin = AccountId;
 	type CommitOriginCheck = EnsureSigned<AccountId>;
 	type Identifier = DidIdentifier;
 	type IdentityCommitmentGenerator = DidMerkleRootGenerator<Self>;
 	type IdentityProvider = LinkedDidInfoProvider<MAX_LINKED_ACCOUNTS>;
+	type MaxCredentials = ConstU32<0>;
+	type MaxCommitmentVersions = ConstU32<3>;
+	type MaxVersionsPerCommit = ConstU32<3>;
 	type ProviderHooks = ();
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub UniversalLocation: InteriorLocation = X2([GlobalConsensus(NetworkId::Polkadot), Parachain(2_086)].into());
+}
+
+/// Query handler that never receives any response, for tests that do not
+/// perform any switch.
+pub(crate) struct NoopQueryHandler;
+
+impl QueryHandler for NoopQueryHandler {
+	type BlockNumber = BlockNumberFor<TestRuntime>;
+	type Error = XcmError;
+	type QueryId = QueryId;
+	type UniversalLocation = UniversalLocation;
+
+	fn new_query(
+		_responder: impl Into<Location>,
+		_timeout: Self::BlockNumber,
+		_match_querier: impl Into<Location>,
+	) -> Self::QueryId {
+		QueryId::default()
+	}
+
+	fn report_outcome(
+		_message: &mut Xcm<()>,
+		_responder: impl Into<Location>,
+		_timeout: Self::BlockNumber,
+	) -> Result<Self::QueryId, Self::Error> {
+		Ok(QueryId::default())
+	}
+
+	fn take_response(_query_id: Self::QueryId) -> QueryResponseStatus<Self::BlockNumber> {
+		QueryResponseStatus::NotFound
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn expect_response(_query_id: Self::QueryId, _response: xcm::v4::Response) {}
+}
+
+impl pallet_asset_switch::Config for TestRuntime {
+	type AccountIdConverter = ();
+	type AssetTransactor = ();
+	type FeeOrigin = EnsureRoot<AccountId>;
+	type LocalCurrency = Balances;
+	type MaxSwitchPairs = ConstU32<10>;
+	type PauseOrigin = EnsureRoot<AccountId>;
+	type QueryHandler = NoopQueryHandler;
+	type RuntimeEvent = RuntimeEvent;
+	type SubmitterOrigin = EnsureSigned<AccountId>;
+	type SwitchHooks = ();
+	type SwitchOrigin = EnsureRoot<AccountId>;
+	type SwitchResponseTimeout = ConstU64<10>;
+	type WeightInfo = ();
+	type XcmRouter = ();
+
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
 pub(crate) const ACCOUNT: AccountId = AccountId::new([100u8; 32]);
 pub(crate) const DID_IDENTIFIER: DidIdentifier = DidIdentifier::new([150u8; 32]);
 pub(crate) const SUBMITTER: AccountId = AccountId::new([150u8; 32]);
@@ -224,14 +311,17 @@ pub(crate) fn create_linked_info(
 	};
 	let linked_accounts_iter = (0..linked_accounts).map(|i| {
 		let bytes = i.to_be_bytes();
-		if i % 2 == 0 {
-			let mut buffer = <[u8; 20]>::default();
-			buffer[..4].copy_from_slice(&bytes);
-			LinkableAccountId::AccountId20(AccountId20(buffer))
-		} else {
-			let mut buffer = <[u8; 32]>::default();
-			buffer[..4].copy_from_slice(&bytes);
-			LinkableAccountId::AccountId32(AccountId32::new(buffer))
+		let mut buffer_20 = <[u8; 20]>::default();
+		buffer_20[..4].copy_from_slice(&bytes);
+		let mut buffer_32 = <[u8; 32]>::default();
+		buffer_32[..4].copy_from_slice(&bytes);
+		// Cycle through all the supported account kinds.
+		match i % 5 {
+			0 => LinkableAccountId::AccountId20(AccountId20(buffer_20)),
+			1 => LinkableAccountId::AccountId32(AccountId32::new(buffer_32)),
+			2 => LinkableAccountId::Bitcoin(BitcoinAccountId(buffer_20)),
+			3 => LinkableAccountId::Solana(SolanaAccountId(buffer_32)),
+			_ => LinkableAccountId::Cosmos(CosmosAccountId(buffer_20)),
 		}
 	});
 	let linked_accounts: BoundedVec<LinkableAccountId, ConstU32<MAX_LINKED_ACCOUNTS>> =
@@ -248,6 +338,7 @@ pub(crate) fn create_linked_info(
 		did_details,
 		web3_name_details: web3_name,
 		linked_accounts,
+		credentials: BoundedVec::default(),
 	}
 }
 
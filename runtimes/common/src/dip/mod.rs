@@ -24,6 +24,8 @@ pub mod deposit;
 pub mod did;
 /// Logic for generating Merkle commitments of a KILT DID identity.
 pub mod merkle;
+/// Types used by the DIP provider runtime API.
+pub mod runtime_api;
 
 #[cfg(test)]
-mod mock;
+pub(crate) mod mock;
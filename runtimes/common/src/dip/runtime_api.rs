@@ -16,38 +16,46 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use uid_core::KeyIdOf;
 use pallet_did_lookup::linkable_account::LinkableAccountId;
 use pallet_dip_provider::IdentityCommitmentVersion;
 use parity_scale_codec::{Decode, Encode};
-use runtime_common::{
-	dip::{did::LinkedDidInfoProviderError, merkle::DidMerkleProofError},
-	DidIdentifier,
-};
 use scale_info::TypeInfo;
 use sp_std::{fmt::Debug, vec::Vec};
 
-use crate::Runtime;
+use crate::{
+	dip::{
+		did::LinkedDidInfoProviderError,
+		merkle::{CredentialKind, DidMerkleProofError},
+	},
+	DidIdentifier, Hash,
+};
 
 /// Parameters for a DIP proof request.
-#[derive(Encode, Decode, TypeInfo, Debug)]
+///
+/// The type is shared between the runtimes and the node, which builds
+/// requests to assemble consumer-ready DIP proofs.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone)]
 pub struct DipProofRequest {
 	/// The subject identifier for which to generate the DIP proof.
-	pub(crate) identifier: DidIdentifier,
+	pub identifier: DidIdentifier,
 	/// The DIP version.
-	pub(crate) version: IdentityCommitmentVersion,
+	pub version: IdentityCommitmentVersion,
 	/// The DID key IDs of the subject's DID Document to reveal in the DIP
 	/// proof.
-	pub(crate) keys: Vec<KeyIdOf<Runtime>>,
+	pub keys: Vec<Hash>,
 	/// The list of accounts linked to the subject's DID to reveal in the
 	/// DIP proof.
-	pub(crate) accounts: Vec<LinkableAccountId>,
+	pub accounts: Vec<LinkableAccountId>,
 	/// A flag indicating whether the web3name claimed by the DID subject
 	/// should revealed in the DIP proof.
-	pub(crate) should_include_web3_name: bool,
+	pub should_include_web3_name: bool,
+	/// The list of public credentials and verifications bound to the
+	/// subject's DID to reveal in the DIP proof. Only supported starting from
+	/// version `1`.
+	pub credentials: Vec<(CredentialKind, Hash)>,
 }
 
-#[derive(Encode, Decode, TypeInfo)]
+#[derive(Encode, Decode, TypeInfo, Debug)]
 pub enum DipProofError {
 	IdentityProvider(LinkedDidInfoProviderError),
 	MerkleProof(DidMerkleProofError),
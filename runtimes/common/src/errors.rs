@@ -16,6 +16,7 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
+use kilt_asset_dids::AssetDidError;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 
@@ -23,3 +24,20 @@ use scale_info::TypeInfo;
 pub enum PublicCredentialsApiError {
 	InvalidSubjectId,
 }
+
+#[derive(Encode, Decode, TypeInfo)]
+pub enum AssetDidApiError {
+	InvalidChainId,
+	InvalidAssetId,
+	InvalidFormat,
+}
+
+impl From<AssetDidError> for AssetDidApiError {
+	fn from(value: AssetDidError) -> Self {
+		match value {
+			AssetDidError::ChainId(_) => Self::InvalidChainId,
+			AssetDidError::AssetId(_) => Self::InvalidAssetId,
+			AssetDidError::InvalidFormat => Self::InvalidFormat,
+		}
+	}
+}
@@ -48,6 +48,7 @@ pub mod asset_switch;
 pub mod assets;
 pub mod authorization;
 pub mod constants;
+pub mod deposits;
 pub mod dip;
 pub mod errors;
 pub mod fees;
@@ -19,10 +19,14 @@
 use uid_core::{DidRawOrigin, EnsureDidOrigin};
 use frame_system::EnsureSigned;
 use runtime_common::{
-	constants::{deposit_storage::MAX_DEPOSIT_PALLET_KEY_LENGTH, dip_provider::MAX_LINKED_ACCOUNTS},
+	assets::DidAsAssetSubject,
+	constants::{
+		deposit_storage::{MAX_DEPOSIT_PALLET_KEY_LENGTH, MAX_INDEXED_DEPOSIT_KEY_LENGTH},
+		dip_provider::{MAX_COMMITMENT_VERSIONS, MAX_CREDENTIALS, MAX_LINKED_ACCOUNTS, MAX_VERSIONS_PER_COMMIT},
+	},
 	dip::{
 		deposit::{DepositCollectorHooks, DepositHooks, DepositNamespace},
-		did::LinkedDidInfoProvider,
+		did::{DidCredentials, LinkedDidInfoProvider},
 		merkle::DidMerkleRootGenerator,
 	},
 	AccountId, DidIdentifier,
@@ -31,9 +35,9 @@ use sp_core::ConstU32;
 
 use crate::{weights, Balances, Runtime, RuntimeEvent, RuntimeHoldReason};
 
-pub(crate) mod runtime_api;
-
 impl pallet_dip_provider::Config for Runtime {
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHooks = runtime_common::dip::did::PalletDipProviderBenchmarkHooks;
 	// Only DID origins can submit the commitment identity tx, which will go through
 	// only if the DID in the origin matches the identifier specified in the tx.
 	type CommitOriginCheck = EnsureDidOrigin<DidIdentifier, AccountId>;
@@ -42,9 +46,18 @@ impl pallet_dip_provider::Config for Runtime {
 	// The identity commitment is defined as the Merkle root of the linked identity
 	// info, as specified by the [`LinkedDidInfoProvider`].
 	type IdentityCommitmentGenerator = DidMerkleRootGenerator<Runtime>;
-	// Identity info is defined as the collection of DID keys, linked accounts, and
-	// the optional web3name of a given DID subject.
-	type IdentityProvider = LinkedDidInfoProvider<MAX_LINKED_ACCOUNTS>;
+	// Identity info is defined as the collection of DID keys, linked accounts, the
+	// optional web3name, and the verifications and public credentials bound to a
+	// given DID subject. Public credentials are looked up for the asset DID the DID
+	// is mapped to.
+	type IdentityProvider = LinkedDidInfoProvider<
+		MAX_LINKED_ACCOUNTS,
+		MAX_CREDENTIALS,
+		DidCredentials<DidAsAssetSubject<Runtime>, MAX_CREDENTIALS>,
+	>;
+	type MaxCredentials = ConstU32<MAX_CREDENTIALS>;
+	type MaxCommitmentVersions = ConstU32<MAX_COMMITMENT_VERSIONS>;
+	type MaxVersionsPerCommit = ConstU32<MAX_VERSIONS_PER_COMMIT>;
 	type ProviderHooks = DepositCollectorHooks;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_dip_provider::WeightInfo<Runtime>;
@@ -58,8 +71,13 @@ impl pallet_deposit_storage::Config for Runtime {
 	type CheckOrigin = EnsureSigned<AccountId>;
 	// The balances pallet is used to reserve/unreserve tokens.
 	type Currency = Balances;
+	// Commitment deposits can be updated to the current commitment deposit.
+	type DepositAmounts = DepositCollectorHooks;
 	type DepositHooks = DepositHooks;
+	// Deposits never expire.
+	type DepositLifetime = ();
 	type MaxKeyLength = ConstU32<MAX_DEPOSIT_PALLET_KEY_LENGTH>;
+	type MaxIndexedKeyLength = ConstU32<MAX_INDEXED_DEPOSIT_KEY_LENGTH>;
 	type Namespace = DepositNamespace;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
@@ -22,14 +22,14 @@ use parity_scale_codec::MaxEncodedLen;
 use uid_core::DeriveDidCallAuthorizationVerificationKeyRelationship;
 use pallet_did_lookup::associate_account_request::AssociateAccountRequest;
 use pallet_treasury::BalanceOf;
-use pallet_web3_names::{Web3NameOf, Web3OwnershipOf};
+use pallet_web3_names::{TextRecordKeyOf, TextRecordOf, Web3NameOf, Web3OwnershipOf};
 use runtime_common::{
 	constants::{
 		verification::MAX_VERIFICATION_BYTE_LENGTH,
 		did::{MAX_KEY_LENGTH, MAX_SERVICE_ENDPOINT_BYTE_LENGTH},
 		did_lookup::MAX_CONNECTION_BYTE_LENGTH,
 		public_credentials::MAX_PUBLIC_CREDENTIAL_STORAGE_LENGTH,
-		web3_names::MAX_NAME_BYTE_LENGTH,
+		web3_names::{MAX_NAME_BYTE_LENGTH, MAX_TEXT_RECORD_BYTE_LENGTH},
 		MAX_INDICES_BYTE_LENGTH,
 	},
 	AccountId, BlockNumber,
@@ -96,6 +96,14 @@ fn web3_name_storage_sizes() {
 	assert_eq!(owner_size + name_size, MAX_NAME_BYTE_LENGTH as usize)
 }
 
+#[test]
+fn web3_name_text_record_storage_sizes() {
+	let key_size = TextRecordKeyOf::<Runtime>::max_encoded_len();
+	let record_size = TextRecordOf::<Runtime>::max_encoded_len();
+
+	assert_eq!(key_size + record_size, MAX_TEXT_RECORD_BYTE_LENGTH as usize)
+}
+
 #[test]
 fn indices_storage_sizes() {
 	type Indices = (<Runtime as frame_system::Config>::AccountId, BalanceOf<Runtime>, bool);
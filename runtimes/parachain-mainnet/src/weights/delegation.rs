@@ -61,15 +61,16 @@ impl<T: frame_system::Config> delegation::WeightInfo for WeightInfo<T> {
 	/// Proof: `Delegation::DelegationNodes` (`max_values`: None, `max_size`: Some(32200), added: 34675, mode: `MaxEncodedLen`)
 	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
 	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn create_hierarchy() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `330`
-		//  Estimated: `3658`
-		// Minimum execution time: 53_722_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(54_362_000, 0)
 			.saturating_add(Weight::from_parts(0, 3658))
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `Delegation::DelegationNodes` (r:2 w:2)
 	/// Proof: `Delegation::DelegationNodes` (`max_values`: None, `max_size`: Some(32200), added: 34675, mode: `MaxEncodedLen`)
@@ -79,15 +80,16 @@ impl<T: frame_system::Config> delegation::WeightInfo for WeightInfo<T> {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
 	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn add_delegation() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `469`
-		//  Estimated: `70340`
-		// Minimum execution time: 58_869_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(60_590_000, 0)
 			.saturating_add(Weight::from_parts(0, 70340))
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `Delegation::DelegationNodes` (r:5 w:5)
 	/// Proof: `Delegation::DelegationNodes` (`max_values`: None, `max_size`: Some(32200), added: 34675, mode: `MaxEncodedLen`)
@@ -141,20 +143,20 @@ impl<T: frame_system::Config> delegation::WeightInfo for WeightInfo<T> {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	/// Storage: `Delegation::DelegationHierarchies` (r:1 w:1)
 	/// Proof: `Delegation::DelegationHierarchies` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:5)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 5]`.
 	fn remove_delegation(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `618 + r * (294 ±0)`
-		//  Estimated: `35665 + r * (34675 ±0)`
-		// Minimum execution time: 96_226_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(61_416_909, 0)
 			.saturating_add(Weight::from_parts(0, 35665))
-			// Standard Error: 46_781
 			.saturating_add(Weight::from_parts(37_123_952, 0).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes(4))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 34675).saturating_mul(r.into()))
 	}
 	/// Storage: `Delegation::DelegationNodes` (r:6 w:6)
@@ -167,20 +169,20 @@ impl<T: frame_system::Config> delegation::WeightInfo for WeightInfo<T> {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	/// Storage: `Delegation::DelegationHierarchies` (r:0 w:1)
 	/// Proof: `Delegation::DelegationHierarchies` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:5)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 5]`.
 	fn reclaim_deposit(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `550 + r * (294 ±0)`
-		//  Estimated: `35665 + r * (34675 ±0)`
-		// Minimum execution time: 91_094_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(56_661_382, 0)
 			.saturating_add(Weight::from_parts(0, 35665))
-			// Standard Error: 63_084
 			.saturating_add(Weight::from_parts(37_265_435, 0).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes(4))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 34675).saturating_mul(r.into()))
 	}
 	/// Storage: `Delegation::DelegationNodes` (r:1 w:0)
@@ -236,15 +238,16 @@ impl<T: frame_system::Config> delegation::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:2 w:2)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:2)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `796`
-		//  Estimated: `35665`
-		// Minimum execution time: 81_241_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(82_035_000, 0)
 			.saturating_add(Weight::from_parts(0, 35665))
 			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Delegation::DelegationNodes` (r:1 w:1)
 	/// Proof: `Delegation::DelegationNodes` (`max_values`: None, `max_size`: Some(32200), added: 34675, mode: `MaxEncodedLen`)
@@ -254,15 +257,16 @@ impl<T: frame_system::Config> delegation::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `652`
-		//  Estimated: `35665`
-		// Minimum execution time: 74_814_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(75_794_000, 0)
 			.saturating_add(Weight::from_parts(0, 35665))
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 }
 
@@ -63,21 +63,20 @@ impl<T: frame_system::Config> uid_core::WeightInfo for WeightInfo<T> {
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(4215), added: 6690, mode: `MaxEncodedLen`)
 	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
 	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ed25519_keys(n: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `361`
-		//  Estimated: `6204`
-		// Minimum execution time: 178_923_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(130_318_124, 0)
 			.saturating_add(Weight::from_parts(0, 6204))
-			// Standard Error: 58_043
 			.saturating_add(Weight::from_parts(2_067_742, 0).saturating_mul(n.into()))
-			// Standard Error: 22_441
 			.saturating_add(Weight::from_parts(29_732_737, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(7))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidBlacklist` (r:1 w:0)
@@ -94,21 +93,20 @@ impl<T: frame_system::Config> uid_core::WeightInfo for WeightInfo<T> {
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(4215), added: 6690, mode: `MaxEncodedLen`)
 	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
 	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_sr25519_keys(n: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `361`
-		//  Estimated: `6204`
-		// Minimum execution time: 174_590_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(117_432_138, 0)
 			.saturating_add(Weight::from_parts(0, 6204))
-			// Standard Error: 58_391
 			.saturating_add(Weight::from_parts(2_006_299, 0).saturating_mul(n.into()))
-			// Standard Error: 22_575
 			.saturating_add(Weight::from_parts(37_733_453, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(7))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidBlacklist` (r:1 w:0)
@@ -125,21 +123,20 @@ impl<T: frame_system::Config> uid_core::WeightInfo for WeightInfo<T> {
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(4215), added: 6690, mode: `MaxEncodedLen`)
 	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
 	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ecdsa_keys(n: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `361`
-		//  Estimated: `6204`
-		// Minimum execution time: 162_713_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(121_407_174, 0)
 			.saturating_add(Weight::from_parts(0, 6204))
-			// Standard Error: 53_591
 			.saturating_add(Weight::from_parts(1_837_827, 0).saturating_mul(n.into()))
-			// Standard Error: 20_719
 			.saturating_add(Weight::from_parts(24_947_965, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(7))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidEndpointsCount` (r:1 w:1)
@@ -156,19 +153,19 @@ impl<T: frame_system::Config> uid_core::WeightInfo for WeightInfo<T> {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	/// Storage: `Did::DidBlacklist` (r:0 w:1)
 	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 25]`.
 	fn delete(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `724 + c * (105 ±0)`
-		//  Estimated: `5777 + c * (6690 ±0)`
-		// Minimum execution time: 60_064_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(60_888_305, 0)
 			.saturating_add(Weight::from_parts(0, 5777))
-			// Standard Error: 4_643
 			.saturating_add(Weight::from_parts(1_168_366, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes(6))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 6690).saturating_mul(c.into()))
 	}
@@ -186,19 +183,19 @@ impl<T: frame_system::Config> uid_core::WeightInfo for WeightInfo<T> {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	/// Storage: `Did::DidBlacklist` (r:0 w:1)
 	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 25]`.
 	fn reclaim_deposit(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `724 + c * (105 ±0)`
-		//  Estimated: `5777 + c * (6690 ±0)`
-		// Minimum execution time: 61_984_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(62_765_430, 0)
 			.saturating_add(Weight::from_parts(0, 5777))
-			// Standard Error: 4_594
 			.saturating_add(Weight::from_parts(1_189_727, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes(6))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 6690).saturating_mul(c.into()))
 	}
@@ -752,15 +749,16 @@ impl<T: frame_system::Config> uid_core::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:2)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `519`
-		//  Estimated: `5777`
-		// Minimum execution time: 65_172_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(65_931_000, 0)
 			.saturating_add(Weight::from_parts(0, 5777))
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2312), added: 4787, mode: `MaxEncodedLen`)
@@ -770,15 +768,16 @@ impl<T: frame_system::Config> uid_core::WeightInfo for WeightInfo<T> {
 	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `463`
-		//  Estimated: `5777`
-		// Minimum execution time: 33_498_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(34_524_000, 0)
 			.saturating_add(Weight::from_parts(0, 5777))
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Did::Did` (r:1 w:0)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2312), added: 4787, mode: `MaxEncodedLen`)
@@ -801,15 +800,16 @@ impl<T: frame_system::Config> uid_core::WeightInfo for WeightInfo<T> {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
 	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn create_from_account() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `323`
-		//  Estimated: `6204`
-		// Minimum execution time: 77_298_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(77_975_000, 0)
 			.saturating_add(Weight::from_parts(0, 6204))
 			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 }
 
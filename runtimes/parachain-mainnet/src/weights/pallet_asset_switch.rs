@@ -53,6 +53,8 @@ impl<T: frame_system::Config> pallet_asset_switch::WeightInfo for WeightInfo<T>
 	/// Proof: `AssetSwitchPool1::SwitchPair` (`max_values`: Some(1), `max_size`: Some(1939), added: 2434, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:0)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::CounterForSwitchPairs` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::CounterForSwitchPairs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn set_switch_pair() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `220`
@@ -60,22 +62,27 @@ impl<T: frame_system::Config> pallet_asset_switch::WeightInfo for WeightInfo<T>
 		// Minimum execution time: 20_597_000 picoseconds.
 		Weight::from_parts(20_855_000, 0)
 			.saturating_add(Weight::from_parts(0, 3597))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	/// Storage: `AssetSwitchPool1::SwitchPair` (r:0 w:1)
+	/// Storage: `AssetSwitchPool1::SwitchPair` (r:1 w:1)
 	/// Proof: `AssetSwitchPool1::SwitchPair` (`max_values`: Some(1), `max_size`: Some(1939), added: 2434, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::CounterForSwitchPairs` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::CounterForSwitchPairs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn force_set_switch_pair() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Estimated: `3424`
 		// Minimum execution time: 11_498_000 picoseconds.
 		Weight::from_parts(11_819_000, 0)
-			.saturating_add(Weight::from_parts(0, 0))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 3424))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AssetSwitchPool1::SwitchPair` (r:1 w:1)
 	/// Proof: `AssetSwitchPool1::SwitchPair` (`max_values`: Some(1), `max_size`: Some(1939), added: 2434, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::CounterForSwitchPairs` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::CounterForSwitchPairs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn force_unset_switch_pair() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `218`
@@ -83,8 +90,8 @@ impl<T: frame_system::Config> pallet_asset_switch::WeightInfo for WeightInfo<T>
 		// Minimum execution time: 14_108_000 picoseconds.
 		Weight::from_parts(14_521_000, 0)
 			.saturating_add(Weight::from_parts(0, 3424))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AssetSwitchPool1::SwitchPair` (r:1 w:1)
 	/// Proof: `AssetSwitchPool1::SwitchPair` (`max_values`: Some(1), `max_size`: Some(1939), added: 2434, mode: `MaxEncodedLen`)
@@ -122,8 +129,8 @@ impl<T: frame_system::Config> pallet_asset_switch::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: `AssetSwitchPool1::SwitchPair` (r:1 w:1)
-	/// Proof: `AssetSwitchPool1::SwitchPair` (`max_values`: Some(1), `max_size`: Some(1939), added: 2434, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::SwitchPairs` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairs` (`max_values`: None, `max_size`: Some(1955), added: 4430, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
@@ -142,15 +149,134 @@ impl<T: frame_system::Config> pallet_asset_switch::WeightInfo for WeightInfo<T>
 	/// Proof: `XcmpQueue::OutboundXcmpStatus` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `XcmpQueue::OutboundXcmpMessages` (r:0 w:1)
 	/// Proof: `XcmpQueue::OutboundXcmpMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairRateLimits` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairRateLimits` (`max_values`: None, `max_size`: Some(1632), added: 4107, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::SwitchPairHookRules` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairHookRules` (`max_values`: None, `max_size`: Some(1608), added: 4083, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+	/// Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairUsage` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairUsage` (`max_values`: None, `max_size`: Some(1664), added: 4139, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::AccountSwitchUsage` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::AccountSwitchUsage` (`max_values`: None, `max_size`: Some(1712), added: 4187, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::PendingSwitches` (r:0 w:1)
+	/// Proof: `AssetSwitchPool1::PendingSwitches` (`max_values`: None, `max_size`: Some(1694), added: 4169, mode: `MaxEncodedLen`)
 	fn switch() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1091`
-		//  Estimated: `6204`
-		// Minimum execution time: 150_128_000 picoseconds.
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// execution time and proof size of the switch before rate limits, hook
+		// rules and pending switches were added, plus the maximum proof size of the
+		// storage items they read.
 		Weight::from_parts(150_760_000, 0)
-			.saturating_add(Weight::from_parts(0, 6204))
-			.saturating_add(T::DbWeight::get().reads(10))
-			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(Weight::from_parts(0, 24716))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().writes(13))
+	}
+	/// Storage: `AssetSwitchPool1::SwitchPairs` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairs` (`max_values`: None, `max_size`: Some(1955), added: 4430, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::SwitchPairRateLimits` (r:0 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairRateLimits` (`max_values`: None, `max_size`: Some(1632), added: 4107, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::SwitchPairUsage` (r:0 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairUsage` (`max_values`: None, `max_size`: Some(1664), added: 4139, mode: `MaxEncodedLen`)
+	fn set_switch_pair_rate_limits() -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// execution time of `pause_switch_pair`, which also reads and writes a
+		// single switch pair, plus the maximum proof size of the storage items read.
+		Weight::from_parts(11_473_000, 0)
+			.saturating_add(Weight::from_parts(0, 4430))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `AssetSwitchPool1::PendingSwitches` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::PendingSwitches` (`max_values`: None, `max_size`: Some(1694), added: 4169, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::Queries` (r:1 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetSwitchPool1::SwitchPairs` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairs` (`max_values`: None, `max_size`: Some(1955), added: 4430, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::SwitchPairRateLimits` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairRateLimits` (`max_values`: None, `max_size`: Some(1632), added: 4107, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::SwitchPairUsage` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairUsage` (`max_values`: None, `max_size`: Some(1664), added: 4139, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::AccountSwitchUsage` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::AccountSwitchUsage` (`max_values`: None, `max_size`: Some(1712), added: 4187, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn settle_switch() -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// execution time and proof size of the switch before rate limits were
+		// added, which also transfers local tokens and accesses the XCM storage,
+		// plus the maximum proof size of the storage items read to settle a switch.
+		Weight::from_parts(150_760_000, 0)
+			.saturating_add(Weight::from_parts(0, 32450))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `AssetSwitchPool1::SwitchPairs` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairs` (`max_values`: None, `max_size`: Some(1955), added: 4430, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::SwitchPairHookRules` (r:0 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairHookRules` (`max_values`: None, `max_size`: Some(1608), added: 4083, mode: `MaxEncodedLen`)
+	fn set_switch_pair_hook_rules() -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// execution time of `pause_switch_pair`, which also reads and writes a
+		// single switch pair, plus the maximum proof size of the storage items read.
+		Weight::from_parts(11_473_000, 0)
+			.saturating_add(Weight::from_parts(0, 4430))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AssetSwitchPool1::PendingSwitches` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::PendingSwitches` (`max_values`: None, `max_size`: Some(1694), added: 4169, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::SwitchPairs` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairs` (`max_values`: None, `max_size`: Some(1955), added: 4430, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::SwitchPairRateLimits` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairRateLimits` (`max_values`: None, `max_size`: Some(1632), added: 4107, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::SwitchPairUsage` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::SwitchPairUsage` (`max_values`: None, `max_size`: Some(1664), added: 4139, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::AccountSwitchUsage` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::AccountSwitchUsage` (`max_values`: None, `max_size`: Some(1712), added: 4187, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn force_settle_switch() -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// execution time and proof size of the switch before rate limits were
+		// added, which also transfers local tokens and accesses the XCM storage,
+		// plus the maximum proof size of the storage items read to settle a switch.
+		Weight::from_parts(150_760_000, 0)
+			.saturating_add(Weight::from_parts(0, 32450))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `AssetSwitchPool1::SwitchPairs` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairs` (`max_values`: None, `max_size`: Some(1955), added: 4430, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::PendingSwitches` (r:0 w:1000)
+	/// Proof: `AssetSwitchPool1::PendingSwitches` (`max_values`: None, `max_size`: Some(1694), added: 4169, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::AccountSwitchUsage` (r:0 w:1000)
+	/// Proof: `AssetSwitchPool1::AccountSwitchUsage` (`max_values`: None, `max_size`: Some(1712), added: 4187, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn remove_switch_pair_storage(n: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// execution time of `pause_switch_pair`, which also reads a single switch
+		// pair, plus the maximum proof size of each removed entry.
+		Weight::from_parts(11_473_000, 0)
+			.saturating_add(Weight::from_parts(0, 4430))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 4187).saturating_mul(n.into()))
+	}
+	/// Storage: `AssetSwitchPool1::AccountSwitchUsage` (r:1 w:1)
+	/// Proof: `AssetSwitchPool1::AccountSwitchUsage` (`max_values`: None, `max_size`: Some(1712), added: 4187, mode: `MaxEncodedLen`)
+	/// Storage: `AssetSwitchPool1::SwitchPairRateLimits` (r:1 w:0)
+	/// Proof: `AssetSwitchPool1::SwitchPairRateLimits` (`max_values`: None, `max_size`: Some(1632), added: 4107, mode: `MaxEncodedLen`)
+	fn remove_expired_switch_usage() -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// execution time of `pause_switch_pair`, which also reads and writes a
+		// single switch pair, plus the maximum proof size of the storage items read.
+		Weight::from_parts(11_473_000, 0)
+			.saturating_add(Weight::from_parts(0, 8294))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
 
@@ -169,6 +295,18 @@ mod tests {
 		);
 	}
 	#[test]
+	fn test_force_set_switch_pair() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3424
+		);
+	}
+	#[test]
 	fn test_force_unset_switch_pair() {
 		assert!(
 			<crate::Runtime as frame_system::Config>::BlockWeights::get()
@@ -217,6 +355,42 @@ mod tests {
 		);
 	}
 	#[test]
+	fn test_set_switch_pair_rate_limits() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4430
+		);
+	}
+	#[test]
+	fn test_set_switch_pair_hook_rules() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4430
+		);
+	}
+	#[test]
+	fn test_settle_switch() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 32450
+		);
+	}
+	#[test]
 	fn test_switch() {
 		assert!(
 			<crate::Runtime as frame_system::Config>::BlockWeights::get()
@@ -225,7 +399,43 @@ mod tests {
 				.max_extrinsic
 				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
 				.proof_size()
-				> 6204
+				> 24716
+		);
+	}
+	#[test]
+	fn test_force_settle_switch() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 32450
+		);
+	}
+	#[test]
+	fn test_remove_switch_pair_storage() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4430
+		);
+	}
+	#[test]
+	fn test_remove_expired_switch_usage() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 8294
 		);
 	}
 }
@@ -59,15 +59,16 @@ impl<T: frame_system::Config> pallet_did_lookup::WeightInfo for WeightInfo<T> {
 	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
 	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
 	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:2)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn associate_account_multisig_sr25519() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `462`
-		//  Estimated: `3658`
-		// Minimum execution time: 127_834_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(128_665_000, 0)
 			.saturating_add(Weight::from_parts(0, 3658))
 			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
@@ -79,15 +80,16 @@ impl<T: frame_system::Config> pallet_did_lookup::WeightInfo for WeightInfo<T> {
 	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
 	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
 	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:2)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn associate_account_multisig_ed25519() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `462`
-		//  Estimated: `3658`
-		// Minimum execution time: 140_226_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(140_731_000, 0)
 			.saturating_add(Weight::from_parts(0, 3658))
 			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
@@ -99,15 +101,16 @@ impl<T: frame_system::Config> pallet_did_lookup::WeightInfo for WeightInfo<T> {
 	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
 	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
 	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:2)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn associate_account_multisig_ecdsa() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `462`
-		//  Estimated: `3658`
-		// Minimum execution time: 130_216_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(131_931_000, 0)
 			.saturating_add(Weight::from_parts(0, 3658))
 			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
@@ -119,15 +122,16 @@ impl<T: frame_system::Config> pallet_did_lookup::WeightInfo for WeightInfo<T> {
 	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
 	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
 	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:2)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn associate_eth_account() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `449`
-		//  Estimated: `3658`
-		// Minimum execution time: 131_142_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(133_002_000, 0)
 			.saturating_add(Weight::from_parts(0, 3658))
 			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
@@ -139,15 +143,100 @@ impl<T: frame_system::Config> pallet_did_lookup::WeightInfo for WeightInfo<T> {
 	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
 	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
 	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:2)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	fn associate_bitcoin_account() -> Weight {
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
+		Weight::from_parts(143_642_000, 0)
+			.saturating_add(Weight::from_parts(0, 3658))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedDids` (r:1 w:1)
+	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:0 w:2)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
+	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:2)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	fn associate_solana_account() -> Weight {
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
+		Weight::from_parts(123_691_000, 0)
+			.saturating_add(Weight::from_parts(0, 3658))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedDids` (r:1 w:1)
+	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:0 w:2)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
+	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:2)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	fn associate_cosmos_account() -> Weight {
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
+		Weight::from_parts(139_652_000, 0)
+			.saturating_add(Weight::from_parts(0, 3658))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedDids` (r:1 w:1)
+	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:0 w:2)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
+	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:2)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	fn associate_eth_typed_data_account() -> Weight {
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
+		Weight::from_parts(134_065_000, 0)
+			.saturating_add(Weight::from_parts(0, 3658))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedDids` (r:1 w:1)
+	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:0 w:2)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
+	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:2)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn associate_sender() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `462`
-		//  Estimated: `3658`
-		// Minimum execution time: 82_563_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(83_119_000, 0)
 			.saturating_add(Weight::from_parts(0, 3658))
 			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `DidLookup::ConnectedDids` (r:1 w:1)
 	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
@@ -159,15 +248,16 @@ impl<T: frame_system::Config> pallet_did_lookup::WeightInfo for WeightInfo<T> {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	/// Storage: `DidLookup::ConnectedAccounts` (r:0 w:1)
 	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn remove_sender_association() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `540`
-		//  Estimated: `3658`
-		// Minimum execution time: 47_202_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(47_747_000, 0)
 			.saturating_add(Weight::from_parts(0, 3658))
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `DidLookup::ConnectedDids` (r:1 w:1)
 	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
@@ -179,15 +269,16 @@ impl<T: frame_system::Config> pallet_did_lookup::WeightInfo for WeightInfo<T> {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	/// Storage: `DidLookup::ConnectedAccounts` (r:0 w:1)
 	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn remove_account_association() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `540`
-		//  Estimated: `3658`
-		// Minimum execution time: 48_192_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(48_649_000, 0)
 			.saturating_add(Weight::from_parts(0, 3658))
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `DidLookup::ConnectedDids` (r:1 w:1)
 	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
@@ -197,15 +288,16 @@ impl<T: frame_system::Config> pallet_did_lookup::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:2 w:2)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:2)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `647`
-		//  Estimated: `6326`
-		// Minimum execution time: 76_772_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(77_410_000, 0)
 			.saturating_add(Weight::from_parts(0, 6326))
 			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `DidLookup::ConnectedDids` (r:1 w:1)
 	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
@@ -215,15 +307,16 @@ impl<T: frame_system::Config> pallet_did_lookup::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `540`
-		//  Estimated: `3658`
-		// Minimum execution time: 70_827_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(71_794_000, 0)
 			.saturating_add(Weight::from_parts(0, 3658))
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 }
 
@@ -278,6 +371,54 @@ mod tests {
 		);
 	}
 	#[test]
+	fn test_associate_bitcoin_account() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3658
+		);
+	}
+	#[test]
+	fn test_associate_solana_account() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3658
+		);
+	}
+	#[test]
+	fn test_associate_cosmos_account() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3658
+		);
+	}
+	#[test]
+	fn test_associate_eth_typed_data_account() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3658
+		);
+	}
+	#[test]
 	fn test_associate_sender() {
 		assert!(
 			<crate::Runtime as frame_system::Config>::BlockWeights::get()
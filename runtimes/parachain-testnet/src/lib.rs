@@ -69,8 +69,11 @@ use runtime_common::{
 		self, UnvestedFundsAllowedWithdrawReasons, BLOCK_PROCESSING_VELOCITY, EXISTENTIAL_DEPOSIT, HOURS, KILT,
 		RELAY_CHAIN_SLOT_DURATION_MILLIS, SLOT_DURATION, UNINCLUDED_SEGMENT_CAPACITY,
 	},
-	dip::merkle::{CompleteMerkleProof, DidMerkleProofOf, DidMerkleRootGenerator},
-	errors::PublicCredentialsApiError,
+	dip::{
+		merkle::{CompleteMerkleProof, DidMerkleProofOf, DidMerkleRootGenerator, SaltedDidMerkleProofOf},
+		runtime_api::{DipProofError, DipProofRequest},
+	},
+	errors::{AssetDidApiError, PublicCredentialsApiError},
 	fees::{ToAuthorCredit, WeightToFee},
 	pallet_id,
 	xcm_config::{RelayLocation, RelayOrigin},
@@ -197,10 +200,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("idchain-node"),
 	impl_name: create_runtime_str!("idchain-node"),
 	authoring_version: 4,
-	spec_version: 11401,
+	spec_version: 11500,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 9,
+	transaction_version: 10,
 	state_version: 0,
 };
 
@@ -707,6 +710,7 @@ impl verification::Config for Runtime {
 	type AuthorizationId = AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>;
 	type AccessControl = PalletAuthorize<DelegationAc<Runtime>>;
 	type BalanceMigrationManager = Migration;
+	type DepositIndex = DepositStorage;
 }
 
 impl delegation::Config for Runtime {
@@ -737,6 +741,7 @@ impl delegation::Config for Runtime {
 	type Currency = Balances;
 	type Deposit = constants::delegation::DelegationDeposit;
 	type BalanceMigrationManager = Migration;
+	type DepositIndex = DepositStorage;
 }
 
 impl uid_credential::Config for Runtime {
@@ -788,6 +793,13 @@ impl uid_core::Config for Runtime {
 	type MaxNumberOfUrlsPerService = constants::did::MaxNumberOfUrlsPerService;
 	type WeightInfo = weights::did::WeightInfo<Runtime>;
 	type BalanceMigrationManager = Migration;
+	type DepositIndex = DepositStorage;
+	type IdentityChangeHook = DipProvider;
+}
+
+parameter_types! {
+	/// The chain ID of the EIP-712 domain for linking Ethereum accounts.
+	pub const DidLookupEthereumChainId: u64 = 6025;
 }
 
 impl pallet_did_lookup::Config for Runtime {
@@ -804,11 +816,16 @@ impl pallet_did_lookup::Config for Runtime {
 
 	type WeightInfo = weights::pallet_did_lookup::WeightInfo<Runtime>;
 	type BalanceMigrationManager = Migration;
+	type DepositIndex = DepositStorage;
+
+	type EthereumChainId = DidLookupEthereumChainId;
+	type IdentityChangeHook = DipProvider;
 }
 
 impl pallet_web3_names::Config for Runtime {
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type BanOrigin = EnsureRoot<AccountId>;
+	type ReserveOrigin = EnsureRoot<AccountId>;
 	type OwnerOrigin = uid_core::EnsureDidOrigin<DidIdentifier, AccountId>;
 	type OriginSuccess = uid_core::DidRawOrigin<AccountId, DidIdentifier>;
 	type Currency = Balances;
@@ -816,10 +833,17 @@ impl pallet_web3_names::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxNameLength = constants::web3_names::MaxNameLength;
 	type MinNameLength = constants::web3_names::MinNameLength;
+	type MaxReservedPatterns = constants::web3_names::MaxReservedPatterns;
+	type TextRecordDeposit = constants::web3_names::TextRecordDeposit;
+	type MaxTextRecords = constants::web3_names::MaxTextRecords;
+	type MaxTextRecordKeyLength = constants::web3_names::MaxTextRecordKeyLength;
+	type MaxTextRecordValueLength = constants::web3_names::MaxTextRecordValueLength;
 	type Web3Name = pallet_web3_names::web3_name::AsciiWeb3Name<Runtime>;
 	type Web3NameOwner = DidIdentifier;
 	type WeightInfo = weights::pallet_web3_names::WeightInfo<Runtime>;
 	type BalanceMigrationManager = Migration;
+	type DepositIndex = DepositStorage;
+	type IdentityChangeHook = DipProvider;
 }
 
 impl pallet_utility::Config for Runtime {
@@ -846,6 +870,7 @@ impl public_credentials::Config for Runtime {
 	type SubjectId = runtime_common::assets::AssetDid;
 	type WeightInfo = weights::public_credentials::WeightInfo<Runtime>;
 	type BalanceMigrationManager = Migration;
+	type DepositIndex = DepositStorage;
 }
 
 /// The type used to represent the kinds of proxying allowed.
@@ -924,6 +949,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 							| verification::Call::revoke { .. }
 							| verification::Call::change_deposit_owner { .. }
 							| verification::Call::update_deposit { .. }
+							| verification::Call::bind_subject { .. }
 					)
 					// Excludes `Balances`
 					| RuntimeCall::Council(..)
@@ -1067,11 +1093,22 @@ impl pallet_asset_switch::Config<KiltToEKiltSwitchPallet> for Runtime {
 	>;
 	type FeeOrigin = EnsureRoot<AccountId>;
 	type LocalCurrency = Balances;
+	type MaxSwitchPairs = constants::asset_switch::MaxSwitchPairs;
 	type PauseOrigin = EnsureRoot<AccountId>;
+	type QueryHandler = PolkadotXcm;
 	type RuntimeEvent = RuntimeEvent;
 	type SubmitterOrigin = EnsureSigned<AccountId>;
-	type SwitchHooks = runtime_common::asset_switch::hooks::RestrictSwitchDestinationToSelf;
+	// Switches above the per-pair threshold require a credential among the
+	// verifications bound to the DID linked to the submitter, and the public
+	// credentials issued to the DID's asset DID.
+	type SwitchHooks = runtime_common::asset_switch::hooks::RequireDidCredential<
+		runtime_common::dip::did::DidCredentials<
+			runtime_common::assets::DidAsAssetSubject<Runtime>,
+			{ runtime_common::constants::dip_provider::MAX_CREDENTIALS },
+		>,
+	>;
 	type SwitchOrigin = EnsureRoot<AccountId>;
+	type SwitchResponseTimeout = constants::asset_switch::SwitchResponseTimeout;
 	type WeightInfo = weights::pallet_asset_switch::WeightInfo<Runtime>;
 	type XcmRouter = XcmRouter;
 
@@ -1192,6 +1229,10 @@ pub type Executive = frame_executive::Executive<
 		runtime_common::migrations::BumpStorageVersion<Runtime>,
 		cumulus_pallet_xcmp_queue::migration::v4::MigrationToV4<Runtime>,
 		pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
+		pallet_asset_switch::migrations::MigrateToMultipleSwitchPairs<Runtime, KiltToEKiltSwitchPallet>,
+		pallet_deposit_storage::migrations::IndexExistingDeposits<Runtime>,
+		pallet_deposit_storage::migrations::SetDepositExpirations<Runtime>,
+		public_credentials::migrations::IndexCredentialsByCtypeAndAttester<Runtime>,
 	),
 >;
 
@@ -1462,6 +1503,7 @@ impl_runtime_apis! {
 				BlockNumber
 			>
 		> {
+			let name = pallet_web3_names::idna::to_ascii_form(&name)?;
 			let name: pallet_web3_names::web3_name::AsciiWeb3Name<Runtime> = name.try_into().ok()?;
 			pallet_web3_names::Owner::<Runtime>::get(&name)
 				.and_then(|owner_info| {
@@ -1472,10 +1514,12 @@ impl_runtime_apis! {
 						&owner_info.owner,
 					).collect();
 					let service_endpoints = uid_core::ServiceEndpoints::<Runtime>::iter_prefix(&owner_info.owner).map(|e| From::from(e.1)).collect();
+					let text_records = pallet_web3_names::Pallet::<Runtime>::raw_text_records(&name);
 
 					kilt_runtime_api_did::RawDidLinkedInfo{
 						identifier: owner_info.owner,
 						w3n: Some(name.into()),
+						text_records,
 						accounts,
 						service_endpoints,
 						details: details.into(),
@@ -1498,13 +1542,15 @@ impl_runtime_apis! {
 					uid_core::Did::<Runtime>::get(&owner_info.did).map(|details| (owner_info, details))
 				})
 				.map(|(connection_record, details)| {
-					let w3n = pallet_web3_names::Names::<Runtime>::get(&connection_record.did).map(Into::into);
+					let w3n = pallet_web3_names::Names::<Runtime>::get(&connection_record.did);
+					let text_records = w3n.as_ref().map(pallet_web3_names::Pallet::<Runtime>::raw_text_records).unwrap_or_default();
 					let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&connection_record.did).collect();
 					let service_endpoints = uid_core::ServiceEndpoints::<Runtime>::iter_prefix(&connection_record.did).map(|e| From::from(e.1)).collect();
 
 					kilt_runtime_api_did::RawDidLinkedInfo {
 						identifier: connection_record.did,
-						w3n,
+						w3n: w3n.map(Into::into),
+						text_records,
 						accounts,
 						service_endpoints,
 						details: details.into(),
@@ -1523,18 +1569,34 @@ impl_runtime_apis! {
 			>
 		> {
 			let details = uid_core::Did::<Runtime>::get(&did)?;
-			let w3n = pallet_web3_names::Names::<Runtime>::get(&did).map(Into::into);
+			let w3n = pallet_web3_names::Names::<Runtime>::get(&did);
+			let text_records = w3n.as_ref().map(pallet_web3_names::Pallet::<Runtime>::raw_text_records).unwrap_or_default();
 			let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&did).collect();
 			let service_endpoints = uid_core::ServiceEndpoints::<Runtime>::iter_prefix(&did).map(|e| From::from(e.1)).collect();
 
 			Some(kilt_runtime_api_did::RawDidLinkedInfo {
 				identifier: did,
-				w3n,
+				w3n: w3n.map(Into::into),
+				text_records,
 				accounts,
 				service_endpoints,
 				details: details.into(),
 			})
 		}
+
+		fn web3_name_forms(name: Vec<u8>) -> Option<kilt_runtime_api_did::Web3NameForms> {
+			let name = pallet_web3_names::idna::to_ascii_form(&name)?;
+			let name: pallet_web3_names::web3_name::AsciiWeb3Name<Runtime> = name.try_into().ok()?;
+
+			Some(kilt_runtime_api_did::Web3NameForms {
+				display: name.display_form(),
+				ascii: name.into(),
+			})
+		}
+
+		fn account_linking_challenge(did: DidIdentifier, expiration: BlockNumber) -> Vec<u8> {
+			pallet_did_lookup::associate_account_request::get_challenge(&did, expiration).into_bytes()
+		}
 	}
 
 	impl kilt_runtime_api_public_credentials::PublicCredentials<Block, Vec<u8>, Hash, public_credentials::CredentialEntry<Hash, DidIdentifier, BlockNumber, AccountId, Balance, AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>>, PublicCredentialsFilter<Hash, AccountId>, PublicCredentialsApiError> for Runtime {
@@ -1554,14 +1616,59 @@ impl_runtime_apis! {
 		}
 	}
 
-	impl kilt_runtime_api_dip_provider::DipProvider<Block, dip::runtime_api::DipProofRequest, CompleteMerkleProof<Hash, DidMerkleProofOf<Runtime>>, dip::runtime_api::DipProofError> for Runtime {
-		fn generate_proof(request: dip::runtime_api::DipProofRequest) -> Result<CompleteMerkleProof<Hash, DidMerkleProofOf<Runtime>>, dip::runtime_api::DipProofError> {
+	impl kilt_runtime_api_dip_provider::DipProvider<Block, DipProofRequest, CompleteMerkleProof<Hash, DidMerkleProofOf<Runtime>>, DipProofError> for Runtime {
+		fn generate_proof(request: DipProofRequest) -> Result<CompleteMerkleProof<Hash, DidMerkleProofOf<Runtime>>, DipProofError> {
 			use pallet_dip_provider::traits::IdentityProvider;
 
-			let identity_details = pallet_dip_provider::IdentityProviderOf::<Runtime>::retrieve(&request.identifier).map_err(dip::runtime_api::DipProofError::IdentityProvider)?;
+			let identity_details = pallet_dip_provider::IdentityProviderOf::<Runtime>::retrieve(&request.identifier).map_err(DipProofError::IdentityProvider)?;
 			log::info!(target: "runtime_api::dip_provider", "Identity details retrieved for request {:#?}: {:#?}", request, identity_details);
 
-			DidMerkleRootGenerator::<Runtime>::generate_proof(&identity_details, request.version, request.keys.iter(), request.should_include_web3_name, request.accounts.iter()).map_err(dip::runtime_api::DipProofError::MerkleProof)
+			DidMerkleRootGenerator::<Runtime>::generate_proof(&identity_details, request.version, request.keys.iter(), request.should_include_web3_name, request.accounts.iter(), request.credentials.iter()).map_err(DipProofError::MerkleProof)
+		}
+	}
+
+	impl kilt_runtime_api_dip_provider::DipSaltedProofProvider<Block, DipProofRequest, CompleteMerkleProof<Hash, SaltedDidMerkleProofOf<Runtime>>, DipProofError> for Runtime {
+		fn generate_salted_proof(request: DipProofRequest) -> Result<CompleteMerkleProof<Hash, SaltedDidMerkleProofOf<Runtime>>, DipProofError> {
+			use pallet_dip_provider::traits::IdentityProvider;
+
+			let identity_details = pallet_dip_provider::IdentityProviderOf::<Runtime>::retrieve(&request.identifier).map_err(DipProofError::IdentityProvider)?;
+			log::info!(target: "runtime_api::dip_provider", "Identity details retrieved for request {:#?}: {:#?}", request, identity_details);
+
+			DidMerkleRootGenerator::<Runtime>::generate_salted_proof(&identity_details, request.version, request.keys.iter(), request.should_include_web3_name, request.accounts.iter(), request.credentials.iter()).map_err(DipProofError::MerkleProof)
+		}
+	}
+
+	impl kilt_runtime_api_dip_provider::DipCommitmentVersions<Block, DidIdentifier, pallet_dip_provider::IdentityCommitmentVersion> for Runtime {
+		fn commitment_versions(identifier: DidIdentifier) -> Vec<pallet_dip_provider::IdentityCommitmentVersion> {
+			DipProvider::commitment_versions(&identifier)
+		}
+	}
+
+	impl kilt_runtime_api_asset_dids::AssetDids<Block, Hash, public_credentials::CredentialEntry<Hash, DidIdentifier, BlockNumber, AccountId, Balance, AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>>, AssetDidApiError> for Runtime {
+		fn resolve(asset_did: Vec<u8>) -> Result<kilt_runtime_api_asset_dids::ResolvedAssetDid<Hash, public_credentials::CredentialEntry<Hash, DidIdentifier, BlockNumber, AccountId, Balance, AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>>>, AssetDidApiError> {
+			runtime_common::assets::resolve_asset_did::<Runtime>(asset_did)
+		}
+	}
+
+	impl kilt_runtime_api_deposits::Deposits<Block, AccountId, Balance> for Runtime {
+		fn deposits(owner: AccountId) -> Vec<kilt_runtime_api_deposits::DepositInfo<AccountId, Balance>> {
+			runtime_common::deposits::deposits_for_owner::<Runtime>(&owner)
+		}
+	}
+
+	impl pallet_deposit_storage_runtime_api::DepositStorage<
+		Block,
+		AccountId,
+		<Runtime as pallet_deposit_storage::Config>::Namespace,
+		pallet_deposit_storage::DepositKeyOf<Runtime>,
+		pallet_deposit_storage::DepositEntryOf<Runtime>,
+	> for Runtime {
+		fn deposits_for_owner(owner: AccountId) -> Vec<(
+			<Runtime as pallet_deposit_storage::Config>::Namespace,
+			pallet_deposit_storage::DepositKeyOf<Runtime>,
+			pallet_deposit_storage::DepositEntryOf<Runtime>,
+		)> {
+			DepositStorage::deposits_for_owner(&owner)
 		}
 	}
 
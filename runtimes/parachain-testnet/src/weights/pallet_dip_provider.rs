@@ -59,6 +59,14 @@ impl<T: frame_system::Config> pallet_dip_provider::WeightInfo for WeightInfo<T>
 	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
 	/// Storage: `DidLookup::ConnectedAccounts` (r:11 w:0)
 	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Attestation::SubjectVerifications` (r:21 w:0)
+	/// Proof: `Attestation::SubjectVerifications` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Attestation::Attestations` (r:20 w:0)
+	/// Proof: `Attestation::Attestations` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `PublicCredentials::Credentials` (r:1 w:0)
+	/// Proof: `PublicCredentials::Credentials` (`max_values`: None, `max_size`: Some(475), added: 2950, mode: `MaxEncodedLen`)
 	/// Storage: `DipProvider::IdentityCommitments` (r:1 w:1)
 	/// Proof: `DipProvider::IdentityCommitments` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
 	/// Storage: `DepositStorage::Deposits` (r:1 w:1)
@@ -67,15 +75,57 @@ impl<T: frame_system::Config> pallet_dip_provider::WeightInfo for WeightInfo<T>
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
-	fn commit_identity() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `3027`
-		//  Estimated: `29282`
-		// Minimum execution time: 384_580_000 picoseconds.
+	/// The range of component `c` is `[0, 20]`.
+	fn commit_identity(c: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime with credentials bound to the DID.
+		// Bounded by the benchmarked `commit_identity` without credentials, plus
+		// the maximum proof size of the credential storage items read, plus an
+		// estimated 15 microseconds for each credential read and added to the
+		// commitment.
 		Weight::from_parts(390_321_000, 0)
-			.saturating_add(Weight::from_parts(0, 29282))
-			.saturating_add(T::DbWeight::get().reads(19))
+			.saturating_add(Weight::from_parts(0, 37322))
+			.saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(22))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
 			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(Weight::from_parts(0, 5241).saturating_mul(c.into()))
+	}
+	/// Storage: `Did::DidBlacklist` (r:1 w:0)
+	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2312), added: 4787, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Names` (r:1 w:0)
+	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Owner` (r:1 w:0)
+	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:11 w:0)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Attestation::SubjectVerifications` (r:21 w:0)
+	/// Proof: `Attestation::SubjectVerifications` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Attestation::Attestations` (r:20 w:0)
+	/// Proof: `Attestation::Attestations` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `PublicCredentials::Credentials` (r:1 w:0)
+	/// Proof: `PublicCredentials::Credentials` (`max_values`: None, `max_size`: Some(475), added: 2950, mode: `MaxEncodedLen`)
+	/// Storage: `DipProvider::IdentityCommitments` (r:1 w:1)
+	/// Proof: `DipProvider::IdentityCommitments` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
+	/// Storage: `DipProvider::PendingRefreshes` (r:1 w:1)
+	/// Proof: `DipProvider::PendingRefreshes` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 20]`.
+	fn refresh_identity_commitment(c: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// `commit_identity` without credentials, which retrieves the identity and
+		// generates the commitment in the same way, plus the maximum proof size of
+		// the pending refresh and credential storage items read, plus an estimated
+		// 15 microseconds for each credential read and added to the commitment.
+		Weight::from_parts(390_321_000, 0)
+			.saturating_add(Weight::from_parts(0, 39855))
+			.saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(20))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 5241).saturating_mul(c.into()))
 	}
 	/// Storage: `DipProvider::IdentityCommitments` (r:1 w:1)
 	/// Proof: `DipProvider::IdentityCommitments` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
@@ -95,6 +145,19 @@ impl<T: frame_system::Config> pallet_dip_provider::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: `DipProvider::PendingRefreshes` (r:1 w:1)
+	/// Proof: `DipProvider::PendingRefreshes` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `DipProvider::AutomaticRefreshEnabled` (r:0 w:1)
+	/// Proof: `DipProvider::AutomaticRefreshEnabled` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn set_automatic_refresh() -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// `delete_identity_commitment`, which removes an entry of a storage double
+		// map of this pallet in the same way.
+		Weight::from_parts(56_253_000, 0)
+			.saturating_add(Weight::from_parts(0, 3658))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
 
 #[cfg(test)]
@@ -108,7 +171,19 @@ mod tests {
 				.max_extrinsic
 				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
 				.proof_size()
-				> 29282
+				> 37322
+		);
+	}
+	#[test]
+	fn test_refresh_identity_commitment() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 39855
 		);
 	}
 	#[test]
@@ -123,4 +198,16 @@ mod tests {
 				> 3658
 		);
 	}
+	#[test]
+	fn test_set_automatic_refresh() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3658
+		);
+	}
 }
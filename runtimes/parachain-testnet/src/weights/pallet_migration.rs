@@ -129,6 +129,19 @@ impl<T: frame_system::Config> pallet_migration::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 3513))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2312), added: 4787, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	fn index_deposit_weight() -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// `did_migration_weight`, which reads the largest entry holding a deposit,
+		// plus the write to the deposit index.
+		Weight::from_parts(64_990_000, 0)
+			.saturating_add(Weight::from_parts(0, 5777))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
 
 #[cfg(test)]
@@ -205,4 +218,16 @@ mod tests {
 				> 3513
 		);
 	}
+	#[test]
+	fn test_index_deposit_weight() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 5777
+		);
+	}
 }
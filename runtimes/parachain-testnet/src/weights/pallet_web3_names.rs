@@ -55,24 +55,34 @@ impl<T: frame_system::Config> pallet_web3_names::WeightInfo for WeightInfo<T> {
 	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
 	/// Storage: `Web3Names::Banned` (r:1 w:0)
 	/// Proof: `Web3Names::Banned` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Reserved` (r:2 w:1)
+	/// Proof: `Web3Names::Reserved` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::ReservedPatterns` (r:1 w:0)
+	/// Proof: `Web3Names::ReservedPatterns` (`max_values`: Some(1), `max_size`: Some(1701), added: 2196, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Assigned` (r:1 w:1)
+	/// Proof: `Web3Names::Assigned` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
 	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[3, 32]`.
-	fn claim(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `147`
-		//  Estimated: `3658`
-		// Minimum execution time: 57_425_000 picoseconds.
+	/// The range of component `p` is `[1, 50]`.
+	fn claim(n: u32, p: u32, ) -> Weight {
+		// Not benchmarked yet with reserved names. Bounded by the benchmarked
+		// `claim` of a name that is not reserved, plus the maximum proof size of
+		// the reserved names, patterns and assignments read, plus an estimated
+		// 5 microseconds for each reserved pattern matched against the name and
+		// its skeleton.
 		Weight::from_parts(57_102_531, 0)
-			.saturating_add(Weight::from_parts(0, 3658))
-			// Standard Error: 55_939
+			.saturating_add(Weight::from_parts(0, 13458))
 			.saturating_add(Weight::from_parts(221_485, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `Web3Names::Names` (r:1 w:1)
 	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
@@ -84,15 +94,16 @@ impl<T: frame_system::Config> pallet_web3_names::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn release_by_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `552`
-		//  Estimated: `3658`
-		// Minimum execution time: 50_576_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(51_111_000, 0)
 			.saturating_add(Weight::from_parts(0, 3658))
 			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `Web3Names::Owner` (r:1 w:1)
 	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
@@ -104,18 +115,18 @@ impl<T: frame_system::Config> pallet_web3_names::WeightInfo for WeightInfo<T> {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	/// Storage: `Web3Names::Names` (r:0 w:1)
 	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[3, 32]`.
 	fn reclaim_deposit(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `448 + n * (1 ±0)`
-		//  Estimated: `3658`
-		// Minimum execution time: 48_527_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(49_603_005, 0)
 			.saturating_add(Weight::from_parts(0, 3658))
-			// Standard Error: 1_724
 			.saturating_add(Weight::from_parts(1_899, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `Web3Names::Banned` (r:1 w:1)
 	/// Proof: `Web3Names::Banned` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
@@ -129,16 +140,17 @@ impl<T: frame_system::Config> pallet_web3_names::WeightInfo for WeightInfo<T> {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	/// Storage: `Web3Names::Names` (r:0 w:1)
 	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[3, 32]`.
 	fn ban(_n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `448 + n * (1 ±0)`
-		//  Estimated: `3658`
-		// Minimum execution time: 51_232_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(52_376_237, 0)
 			.saturating_add(Weight::from_parts(0, 3658))
 			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `Web3Names::Banned` (r:1 w:1)
 	/// Proof: `Web3Names::Banned` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
@@ -165,15 +177,16 @@ impl<T: frame_system::Config> pallet_web3_names::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:2 w:2)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:2)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `659`
-		//  Estimated: `6326`
-		// Minimum execution time: 80_203_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(80_936_000, 0)
 			.saturating_add(Weight::from_parts(0, 6326))
 			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Web3Names::Owner` (r:1 w:1)
 	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
@@ -183,15 +196,193 @@ impl<T: frame_system::Config> pallet_web3_names::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `407`
-		//  Estimated: `3658`
-		// Minimum execution time: 65_422_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(66_506_000, 0)
 			.saturating_add(Weight::from_parts(0, 3658))
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Web3Names::Reserved` (r:1 w:1)
+	/// Proof: `Web3Names::Reserved` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Owner` (r:1 w:0)
+	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[3, 32]`.
+	fn reserve(n: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked `unban`,
+		// which also writes a single name to a map, plus the maximum proof size of
+		// the storage items read.
+		Weight::from_parts(13_342_187, 0)
+			.saturating_add(Weight::from_parts(0, 8650))
+			.saturating_add(Weight::from_parts(32_037, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Web3Names::Reserved` (r:1 w:1)
+	/// Proof: `Web3Names::Reserved` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Assigned` (r:1 w:1)
+	/// Proof: `Web3Names::Assigned` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[3, 32]`.
+	fn unreserve(n: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked `unban`,
+		// which also removes a single name from a map, plus the maximum proof size
+		// of the storage items read.
+		Weight::from_parts(13_342_187, 0)
+			.saturating_add(Weight::from_parts(0, 8594))
+			.saturating_add(Weight::from_parts(32_037, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Web3Names::ReservedPatterns` (r:1 w:1)
+	/// Proof: `Web3Names::ReservedPatterns` (`max_values`: Some(1), `max_size`: Some(1701), added: 2196, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 50]`.
+	fn reserve_pattern(p: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked `unban`,
+		// plus the maximum proof size of the reserved patterns, plus an estimated
+		// 5 microseconds for each reserved pattern compared with the given one.
+		Weight::from_parts(13_342_187, 0)
+			.saturating_add(Weight::from_parts(0, 5710))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Web3Names::ReservedPatterns` (r:1 w:1)
+	/// Proof: `Web3Names::ReservedPatterns` (`max_values`: Some(1), `max_size`: Some(1701), added: 2196, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 50]`.
+	fn unreserve_pattern(p: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked `unban`,
+		// plus the maximum proof size of the reserved patterns, plus an estimated
+		// 5 microseconds for each reserved pattern compared with the given one.
+		Weight::from_parts(13_342_187, 0)
+			.saturating_add(Weight::from_parts(0, 5710))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Web3Names::Reserved` (r:2 w:0)
+	/// Proof: `Web3Names::Reserved` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::ReservedPatterns` (r:1 w:0)
+	/// Proof: `Web3Names::ReservedPatterns` (`max_values`: Some(1), `max_size`: Some(1701), added: 2196, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Names` (r:1 w:0)
+	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Owner` (r:1 w:0)
+	/// Proof: `Web3Names::Owner` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Banned` (r:1 w:0)
+	/// Proof: `Web3Names::Banned` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::Assigned` (r:0 w:1)
+	/// Proof: `Web3Names::Assigned` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[3, 32]`.
+	/// The range of component `p` is `[1, 50]`.
+	fn assign_reserved(n: u32, p: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked `claim`,
+		// which checks the same names and also holds a deposit, plus the maximum
+		// proof size of the storage items read, plus an estimated 5 microseconds
+		// for each reserved pattern matched against the name and its skeleton.
+		Weight::from_parts(57_102_531, 0)
+			.saturating_add(Weight::from_parts(0, 14936))
+			.saturating_add(Weight::from_parts(221_485, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Web3Names::Names` (r:1 w:0)
+	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::TextRecords` (r:1 w:1)
+	/// Proof: `Web3Names::TextRecords` (`max_values`: None, `max_size`: Some(404), added: 2879, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::TextRecordsCount` (r:1 w:1)
+	/// Proof: `Web3Names::TextRecordsCount` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
+	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	/// The range of component `k` is `[1, 32]`.
+	/// The range of component `v` is `[0, 256]`.
+	fn set_text_record(k: u32, v: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. The base is bounded by the
+		// benchmarked `claim`, which also holds and indexes a deposit, and the proof
+		// size by the maximum size of the storage items read. The cost of each byte
+		// of the key and the value is estimated.
+		Weight::from_parts(57_102_531, 0)
+			.saturating_add(Weight::from_parts(0, 13238))
+			.saturating_add(Weight::from_parts(25_000, 0).saturating_mul(k.into()))
+			.saturating_add(Weight::from_parts(25_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `Web3Names::Names` (r:1 w:0)
+	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::TextRecords` (r:1 w:1)
+	/// Proof: `Web3Names::TextRecords` (`max_values`: None, `max_size`: Some(404), added: 2879, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::TextRecordsCount` (r:1 w:1)
+	/// Proof: `Web3Names::TextRecordsCount` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Migration::MigratedKeys` (r:1 w:0)
+	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	/// The range of component `k` is `[1, 32]`.
+	fn remove_text_record(k: u32, ) -> Weight {
+		// Not benchmarked yet for this runtime. The base is bounded by the
+		// benchmarked `release_by_owner`, which also releases and unindexes a
+		// deposit, plus the estimated cost of decoding the largest value, and the
+		// proof size by the maximum size of the storage items read. The cost of each
+		// byte of the key is estimated.
+		Weight::from_parts(57_511_000, 0)
+			.saturating_add(Weight::from_parts(0, 15761))
+			.saturating_add(Weight::from_parts(25_000, 0).saturating_mul(k.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Web3Names::Names` (r:1 w:0)
+	/// Proof: `Web3Names::Names` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Migration::MigratedKeys` (r:1 w:0)
+	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Web3Names::TextRecords` (r:1 w:1)
+	/// Proof: `Web3Names::TextRecords` (`max_values`: None, `max_size`: Some(404), added: 2879, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:2)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	fn change_text_record_deposit_owner() -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// `change_deposit_owner`, which changes the owner of the deposit of a name
+		// in the same way, plus the maximum proof size of the text record read.
+		Weight::from_parts(80_936_000, 0)
+			.saturating_add(Weight::from_parts(0, 9205))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `Web3Names::TextRecords` (r:1 w:1)
+	/// Proof: `Web3Names::TextRecords` (`max_values`: None, `max_size`: Some(404), added: 2879, mode: `MaxEncodedLen`)
+	/// Storage: `Migration::MigratedKeys` (r:1 w:1)
+	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	fn update_text_record_deposit() -> Weight {
+		// Not benchmarked yet for this runtime. Bounded by the benchmarked
+		// `update_deposit`, which updates the deposit of a name in the same way,
+		// plus the maximum proof size of the text record read.
+		Weight::from_parts(66_506_000, 0)
+			.saturating_add(Weight::from_parts(0, 6537))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 }
 
@@ -206,7 +397,7 @@ mod tests {
 				.max_extrinsic
 				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
 				.proof_size()
-				> 3658
+				> 13458
 		);
 	}
 	#[test]
@@ -281,4 +472,112 @@ mod tests {
 				> 3658
 		);
 	}
+	#[test]
+	fn test_reserve() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 8650
+		);
+	}
+	#[test]
+	fn test_unreserve() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 8594
+		);
+	}
+	#[test]
+	fn test_reserve_pattern() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 5710
+		);
+	}
+	#[test]
+	fn test_unreserve_pattern() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 5710
+		);
+	}
+	#[test]
+	fn test_assign_reserved() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 14936
+		);
+	}
+	#[test]
+	fn test_set_text_record() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 13238
+		);
+	}
+	#[test]
+	fn test_remove_text_record() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 15761
+		);
+	}
+	#[test]
+	fn test_change_text_record_deposit_owner() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 9205
+		);
+	}
+	#[test]
+	fn test_update_text_record_deposit() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 6537
+		);
+	}
 }
@@ -61,23 +61,27 @@ impl<T: frame_system::Config> public_credentials::WeightInfo for WeightInfo<T> {
 	/// Proof: `PublicCredentials::CredentialSubjects` (`max_values`: None, `max_size`: Some(312), added: 2787, mode: `MaxEncodedLen`)
 	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
 	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	/// Storage: `PublicCredentials::CredentialsByCtypeAndAttester` (r:0 w:1)
+	/// Proof: `PublicCredentials::CredentialsByCtypeAndAttester` (`max_values`: None, `max_size`: Some(425), added: 2900, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 100000]`.
 	fn add(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `363`
-		//  Estimated: `3940`
-		// Minimum execution time: 64_826_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(60_652_895, 0)
 			.saturating_add(Weight::from_parts(0, 3940))
-			// Standard Error: 7
 			.saturating_add(Weight::from_parts(2_335, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `PublicCredentials::CredentialSubjects` (r:1 w:0)
 	/// Proof: `PublicCredentials::CredentialSubjects` (`max_values`: None, `max_size`: Some(312), added: 2787, mode: `MaxEncodedLen`)
 	/// Storage: `PublicCredentials::Credentials` (r:1 w:1)
 	/// Proof: `PublicCredentials::Credentials` (`max_values`: None, `max_size`: Some(475), added: 2950, mode: `MaxEncodedLen`)
+	/// Storage: `PublicCredentials::CredentialsByCtypeAndAttester` (r:0 w:2)
+	/// Proof: `PublicCredentials::CredentialsByCtypeAndAttester` (`max_values`: None, `max_size`: Some(425), added: 2900, mode: `MaxEncodedLen`)
 	fn revoke() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `919`
@@ -86,12 +90,14 @@ impl<T: frame_system::Config> public_credentials::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(24_250_000, 0)
 			.saturating_add(Weight::from_parts(0, 3940))
 			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `PublicCredentials::CredentialSubjects` (r:1 w:0)
 	/// Proof: `PublicCredentials::CredentialSubjects` (`max_values`: None, `max_size`: Some(312), added: 2787, mode: `MaxEncodedLen`)
 	/// Storage: `PublicCredentials::Credentials` (r:1 w:1)
 	/// Proof: `PublicCredentials::Credentials` (`max_values`: None, `max_size`: Some(475), added: 2950, mode: `MaxEncodedLen`)
+	/// Storage: `PublicCredentials::CredentialsByCtypeAndAttester` (r:0 w:2)
+	/// Proof: `PublicCredentials::CredentialsByCtypeAndAttester` (`max_values`: None, `max_size`: Some(425), added: 2900, mode: `MaxEncodedLen`)
 	fn unrevoke() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `919`
@@ -100,7 +106,7 @@ impl<T: frame_system::Config> public_credentials::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(23_711_000, 0)
 			.saturating_add(Weight::from_parts(0, 3940))
 			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `PublicCredentials::CredentialSubjects` (r:1 w:1)
 	/// Proof: `PublicCredentials::CredentialSubjects` (`max_values`: None, `max_size`: Some(312), added: 2787, mode: `MaxEncodedLen`)
@@ -112,15 +118,18 @@ impl<T: frame_system::Config> public_credentials::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	/// Storage: `PublicCredentials::CredentialsByCtypeAndAttester` (r:0 w:1)
+	/// Proof: `PublicCredentials::CredentialsByCtypeAndAttester` (`max_values`: None, `max_size`: Some(425), added: 2900, mode: `MaxEncodedLen`)
 	fn remove() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1160`
-		//  Estimated: `3940`
-		// Minimum execution time: 61_155_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(61_722_000, 0)
 			.saturating_add(Weight::from_parts(0, 3940))
 			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `PublicCredentials::CredentialSubjects` (r:1 w:1)
 	/// Proof: `PublicCredentials::CredentialSubjects` (`max_values`: None, `max_size`: Some(312), added: 2787, mode: `MaxEncodedLen`)
@@ -132,15 +141,18 @@ impl<T: frame_system::Config> public_credentials::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
+	/// Storage: `PublicCredentials::CredentialsByCtypeAndAttester` (r:0 w:1)
+	/// Proof: `PublicCredentials::CredentialsByCtypeAndAttester` (`max_values`: None, `max_size`: Some(425), added: 2900, mode: `MaxEncodedLen`)
 	fn reclaim_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1160`
-		//  Estimated: `3940`
-		// Minimum execution time: 60_920_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(61_645_000, 0)
 			.saturating_add(Weight::from_parts(0, 3940))
 			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `PublicCredentials::CredentialSubjects` (r:1 w:0)
 	/// Proof: `PublicCredentials::CredentialSubjects` (`max_values`: None, `max_size`: Some(312), added: 2787, mode: `MaxEncodedLen`)
@@ -152,15 +164,16 @@ impl<T: frame_system::Config> public_credentials::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:2 w:2)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:2)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn change_deposit_owner() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1265`
-		//  Estimated: `6326`
-		// Minimum execution time: 96_201_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(96_988_000, 0)
 			.saturating_add(Weight::from_parts(0, 6326))
 			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `PublicCredentials::CredentialSubjects` (r:1 w:0)
 	/// Proof: `PublicCredentials::CredentialSubjects` (`max_values`: None, `max_size`: Some(312), added: 2787, mode: `MaxEncodedLen`)
@@ -172,15 +185,16 @@ impl<T: frame_system::Config> public_credentials::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `DepositStorage::IndexedDeposits` (r:0 w:1)
+	/// Proof: `DepositStorage::IndexedDeposits` (`max_values`: None, `max_size`: Some(356), added: 2831, mode: `MaxEncodedLen`)
 	fn update_deposit() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1084`
-		//  Estimated: `3940`
-		// Minimum execution time: 83_241_000 picoseconds.
+		// Not benchmarked yet for this runtime with the deposit index. Bounded by
+		// the benchmark of this call before the deposit index was introduced, plus
+		// the writes to the deposit index.
 		Weight::from_parts(83_935_000, 0)
 			.saturating_add(Weight::from_parts(0, 3940))
 			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 }
 
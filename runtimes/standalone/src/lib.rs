@@ -366,6 +366,7 @@ impl verification::Config for Runtime {
 	type AuthorizationId = AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>;
 	type AccessControl = PalletAuthorize<DelegationAc<Runtime>>;
 	type BalanceMigrationManager = ();
+	type DepositIndex = ();
 }
 
 parameter_types! {
@@ -404,6 +405,7 @@ impl delegation::Config for Runtime {
 	type Currency = Balances;
 	type Deposit = DelegationDeposit;
 	type BalanceMigrationManager = ();
+	type DepositIndex = ();
 }
 
 parameter_types! {
@@ -476,6 +478,13 @@ impl uid_core::Config for Runtime {
 	type MaxNumberOfUrlsPerService = MaxNumberOfUrlsPerService;
 	type WeightInfo = ();
 	type BalanceMigrationManager = ();
+	type DepositIndex = ();
+	type IdentityChangeHook = ();
+}
+
+parameter_types! {
+	/// The chain ID of the EIP-712 domain for linking Ethereum accounts.
+	pub const DidLookupEthereumChainId: u64 = 6026;
 }
 
 impl pallet_did_lookup::Config for Runtime {
@@ -490,12 +499,17 @@ impl pallet_did_lookup::Config for Runtime {
 	type EnsureOrigin = uid_core::EnsureDidOrigin<DidIdentifier, AccountId>;
 	type OriginSuccess = uid_core::DidRawOrigin<AccountId, DidIdentifier>;
 	type BalanceMigrationManager = ();
+	type DepositIndex = ();
 	type WeightInfo = ();
+
+	type EthereumChainId = DidLookupEthereumChainId;
+	type IdentityChangeHook = ();
 }
 
 impl pallet_web3_names::Config for Runtime {
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type BanOrigin = EnsureRoot<AccountId>;
+	type ReserveOrigin = EnsureRoot<AccountId>;
 	type OwnerOrigin = uid_core::EnsureDidOrigin<DidIdentifier, AccountId>;
 	type OriginSuccess = uid_core::DidRawOrigin<AccountId, DidIdentifier>;
 	type Currency = Balances;
@@ -503,10 +517,17 @@ impl pallet_web3_names::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxNameLength = constants::web3_names::MaxNameLength;
 	type MinNameLength = constants::web3_names::MinNameLength;
+	type MaxReservedPatterns = constants::web3_names::MaxReservedPatterns;
+	type TextRecordDeposit = constants::web3_names::TextRecordDeposit;
+	type MaxTextRecords = constants::web3_names::MaxTextRecords;
+	type MaxTextRecordKeyLength = constants::web3_names::MaxTextRecordKeyLength;
+	type MaxTextRecordValueLength = constants::web3_names::MaxTextRecordValueLength;
 	type Web3Name = pallet_web3_names::web3_name::AsciiWeb3Name<Runtime>;
 	type Web3NameOwner = DidIdentifier;
 	type WeightInfo = ();
 	type BalanceMigrationManager = ();
+	type DepositIndex = ();
+	type IdentityChangeHook = ();
 }
 
 parameter_types! {
@@ -559,6 +580,7 @@ impl public_credentials::Config for Runtime {
 	type SubjectId = runtime_common::assets::AssetDid;
 	type WeightInfo = ();
 	type BalanceMigrationManager = ();
+	type DepositIndex = ();
 }
 
 /// The type used to represent the kinds of proxying allowed.
@@ -1033,6 +1055,7 @@ impl_runtime_apis! {
 				BlockNumber
 			>
 		> {
+			let name = pallet_web3_names::idna::to_ascii_form(&name)?;
 			let name: pallet_web3_names::web3_name::AsciiWeb3Name<Runtime> = name.try_into().ok()?;
 			pallet_web3_names::Owner::<Runtime>::get(&name)
 				.and_then(|owner_info| {
@@ -1043,10 +1066,12 @@ impl_runtime_apis! {
 						&owner_info.owner,
 					).collect();
 					let service_endpoints = uid_core::ServiceEndpoints::<Runtime>::iter_prefix(&owner_info.owner).map(|e| From::from(e.1)).collect();
+					let text_records = pallet_web3_names::Pallet::<Runtime>::raw_text_records(&name);
 
 					kilt_runtime_api_did::RawDidLinkedInfo{
 						identifier: owner_info.owner,
 						w3n: Some(name.into()),
+						text_records,
 						accounts,
 						service_endpoints,
 						details: details.into(),
@@ -1069,13 +1094,15 @@ impl_runtime_apis! {
 					uid_core::Did::<Runtime>::get(&owner_info.did).map(|details| (owner_info, details))
 				})
 				.map(|(connection_record, details)| {
-					let w3n = pallet_web3_names::Names::<Runtime>::get(&connection_record.did).map(Into::into);
+					let w3n = pallet_web3_names::Names::<Runtime>::get(&connection_record.did);
+					let text_records = w3n.as_ref().map(pallet_web3_names::Pallet::<Runtime>::raw_text_records).unwrap_or_default();
 					let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&connection_record.did).collect();
 					let service_endpoints = uid_core::ServiceEndpoints::<Runtime>::iter_prefix(&connection_record.did).map(|e| From::from(e.1)).collect();
 
 					kilt_runtime_api_did::RawDidLinkedInfo {
 						identifier: connection_record.did,
-						w3n,
+						w3n: w3n.map(Into::into),
+						text_records,
 						accounts,
 						service_endpoints,
 						details: details.into(),
@@ -1094,18 +1121,34 @@ impl_runtime_apis! {
 			>
 		> {
 			let details = uid_core::Did::<Runtime>::get(&did)?;
-			let w3n = pallet_web3_names::Names::<Runtime>::get(&did).map(Into::into);
+			let w3n = pallet_web3_names::Names::<Runtime>::get(&did);
+			let text_records = w3n.as_ref().map(pallet_web3_names::Pallet::<Runtime>::raw_text_records).unwrap_or_default();
 			let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&did).collect();
 			let service_endpoints = uid_core::ServiceEndpoints::<Runtime>::iter_prefix(&did).map(|e| From::from(e.1)).collect();
 
 			Some(kilt_runtime_api_did::RawDidLinkedInfo {
 				identifier: did,
-				w3n,
+				w3n: w3n.map(Into::into),
+				text_records,
 				accounts,
 				service_endpoints,
 				details: details.into(),
 			})
 		}
+
+		fn web3_name_forms(name: Vec<u8>) -> Option<kilt_runtime_api_did::Web3NameForms> {
+			let name = pallet_web3_names::idna::to_ascii_form(&name)?;
+			let name: pallet_web3_names::web3_name::AsciiWeb3Name<Runtime> = name.try_into().ok()?;
+
+			Some(kilt_runtime_api_did::Web3NameForms {
+				display: name.display_form(),
+				ascii: name.into(),
+			})
+		}
+
+		fn account_linking_challenge(did: DidIdentifier, expiration: BlockNumber) -> Vec<u8> {
+			pallet_did_lookup::associate_account_request::get_challenge(&did, expiration).into_bytes()
+		}
 	}
 
 	impl kilt_runtime_api_public_credentials::PublicCredentials<Block, Vec<u8>, Hash, public_credentials::CredentialEntry<Hash, DidIdentifier, BlockNumber, AccountId, Balance, AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>>, PublicCredentialsFilter<Hash, AccountId>, PublicCredentialsApiError> for Runtime {
@@ -16,9 +16,12 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use frame_support::traits::{
-	fungible::hold::Mutate,
-	tokens::fungible::{Inspect, MutateHold},
+use frame_support::{
+	traits::{
+		fungible::hold::Mutate,
+		tokens::fungible::{Inspect, MutateHold},
+	},
+	weights::Weight,
 };
 use sp_runtime::DispatchError;
 use sp_std::vec::Vec;
@@ -147,8 +150,67 @@ impl<AccountId, Balance> BalanceMigrationManager<AccountId, Balance> for () {
 	fn release_reserved_deposit(_user: &AccountId, _balance: &Balance) {}
 }
 
+/// A trait to notify about changes to the identity information of a subject,
+/// e.g., a key rotation or a new linked account, and about the deletion of a
+/// subject.
+///
+/// Callers must include the weights returned by the `*_weight` functions in
+/// the weight of the calls that trigger the notifications.
+pub trait OnIdentityChange<Identifier, AccountId> {
+	/// Called after the identity information of a subject has changed.
+	fn on_identity_changed(identifier: &Identifier);
+
+	/// Called after a subject has been deleted. The provided account is the
+	/// one that was paying the deposit for the subject.
+	fn on_identity_deleted(identifier: &Identifier, deposit_owner: &AccountId);
+
+	/// The maximum weight of a call to `on_identity_changed`.
+	fn on_identity_changed_weight() -> Weight;
+
+	/// The maximum weight of a call to `on_identity_deleted`.
+	fn on_identity_deleted_weight() -> Weight;
+}
+
+impl<Identifier, AccountId> OnIdentityChange<Identifier, AccountId> for () {
+	fn on_identity_changed(_identifier: &Identifier) {}
+
+	fn on_identity_deleted(_identifier: &Identifier, _deposit_owner: &AccountId) {}
+
+	fn on_identity_changed_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn on_identity_deleted_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// A secondary index of the deposits held by each account, across all the
+/// pallets that take deposits.
+///
+/// Deposits are identified by the final storage key of the entry they have
+/// been taken for.
+pub trait DepositIndex<AccountId, Balance> {
+	/// Record that `owner` holds a deposit of `amount` for the storage entry
+	/// behind `hashed_key`, replacing any previous record for the same entry.
+	fn on_deposit_held(owner: &AccountId, hashed_key: &[u8], amount: &Balance);
+
+	/// Record that `owner` does not hold a deposit for the storage entry
+	/// behind `hashed_key` anymore.
+	fn on_deposit_released(owner: &AccountId, hashed_key: &[u8]);
+}
+
+impl<AccountId, Balance> DepositIndex<AccountId, Balance> for () {
+	fn on_deposit_held(_owner: &AccountId, _hashed_key: &[u8], _amount: &Balance) {}
+
+	fn on_deposit_released(_owner: &AccountId, _hashed_key: &[u8]) {}
+}
+
 pub trait StorageDepositCollector<AccountId, Key, RuntimeHoldReason> {
 	type Currency: MutateHold<AccountId, Reason = RuntimeHoldReason>;
+	/// The index to keep up to date whenever a deposit is taken, changed or
+	/// released.
+	type DepositIndex: DepositIndex<AccountId, <Self::Currency as Inspect<AccountId>>::Balance>;
 	// TODO: This could also be replaced with a `Borrow<RuntimeHoldReason>` or an
 	// `AsRef<RuntimeHoldReason>`, but not sure what trait the runtime composite
 	// enum implements.
@@ -185,6 +247,30 @@ pub trait StorageDepositCollector<AccountId, Key, RuntimeHoldReason> {
 		free_deposit::<AccountId, Self::Currency>(&deposit, &Self::reason().into())
 	}
 
+	/// Adds the deposit of the storage entry behind the key to the deposit
+	/// index.
+	///
+	/// This must be called whenever a new deposit is stored, or the amount of
+	/// an existing deposit changes outside of [`Self::update_deposit`].
+	fn index_deposit(
+		key: &Key,
+		deposit: &Deposit<AccountId, <Self::Currency as Inspect<AccountId>>::Balance>,
+	) -> Result<(), DispatchError> {
+		let hashed_key = Self::get_hashed_key(key)?;
+		Self::DepositIndex::on_deposit_held(&deposit.owner, &hashed_key, &deposit.amount);
+		Ok(())
+	}
+
+	/// Removes the deposit of the storage entry behind the key from the
+	/// deposit index.
+	///
+	/// This must be called whenever a deposit is released.
+	fn unindex_deposit(key: &Key, owner: &AccountId) -> Result<(), DispatchError> {
+		let hashed_key = Self::get_hashed_key(key)?;
+		Self::DepositIndex::on_deposit_released(owner, &hashed_key);
+		Ok(())
+	}
+
 	/// Creates a new deposit for user.
 	///
 	/// # Errors
@@ -221,6 +307,7 @@ pub trait StorageDepositCollector<AccountId, Key, RuntimeHoldReason> {
 			DepositBalanceMigrationManager::release_reserved_deposit(&deposit.owner, &deposit.amount);
 			DepositBalanceMigrationManager::exclude_key_from_migration(&hashed_key);
 		}
+		Self::DepositIndex::on_deposit_released(&deposit.owner, &hashed_key);
 
 		let deposit = Deposit {
 			owner: new_owner,
@@ -228,6 +315,7 @@ pub trait StorageDepositCollector<AccountId, Key, RuntimeHoldReason> {
 		};
 
 		Self::Currency::hold(&reason.into(), &deposit.owner, deposit.amount)?;
+		Self::DepositIndex::on_deposit_held(&deposit.owner, &hashed_key, &deposit.amount);
 
 		Self::store_deposit(key, deposit)?;
 
@@ -262,6 +350,7 @@ pub trait StorageDepositCollector<AccountId, Key, RuntimeHoldReason> {
 			..deposit
 		};
 		Self::Currency::hold(&reason.into(), &deposit.owner, deposit.amount)?;
+		Self::DepositIndex::on_deposit_held(&deposit.owner, &hashed_key, &deposit.amount);
 
 		Self::store_deposit(key, deposit)?;
 